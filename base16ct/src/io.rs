@@ -0,0 +1,152 @@
+use crate::{decode_inner, lower, mixed, upper, Error, Result};
+use std::io;
+
+/// Size of the chunks data is encoded or decoded in.
+const IO_CHUNK_SIZE: usize = 512;
+
+/// Function which encodes bytes as Base16 (hex).
+type EncodeFn = for<'a> fn(&[u8], &'a mut [u8]) -> Result<&'a [u8]>;
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// [`io::Write`] adapter which encodes the data written to it as Base16
+/// (hex), writing the result to an underlying writer.
+///
+/// Encoding is stateless, so no data is buffered by the adapter itself.
+pub struct EncoderWriter<W: io::Write> {
+    /// Underlying writer.
+    writer: W,
+
+    /// Encoding function for the selected case.
+    encode: EncodeFn,
+}
+
+impl<W: io::Write> EncoderWriter<W> {
+    /// Create a new encoder which writes lower Base16 to the given writer.
+    pub fn new_lower(writer: W) -> Self {
+        Self {
+            writer,
+            encode: lower::encode,
+        }
+    }
+
+    /// Create a new encoder which writes upper Base16 to the given writer.
+    pub fn new_upper(writer: W) -> Self {
+        Self {
+            writer,
+            encode: upper::encode,
+        }
+    }
+
+    /// Consume the encoder, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: io::Write> io::Write for EncoderWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let input = &buf[..buf.len().min(IO_CHUNK_SIZE)];
+        let mut out = [0u8; IO_CHUNK_SIZE * 2];
+        let encoded = (self.encode)(input, &mut out)?;
+        self.writer.write_all(encoded)?;
+        Ok(input.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// [`io::Read`] adapter which decodes Base16 (hex) read from an underlying
+/// reader.
+///
+/// Returns an [`io::ErrorKind::InvalidData`] error if the input contains
+/// invalid characters, or ends with an incomplete byte.
+pub struct DecoderReader<R: io::Read> {
+    /// Underlying reader.
+    reader: R,
+
+    /// Nibble decoding function for the selected case.
+    decode_nibble: fn(u8) -> u16,
+
+    /// Character of a byte whose second character hasn't been read yet.
+    pending: Option<u8>,
+}
+
+impl<R: io::Read> DecoderReader<R> {
+    /// Create a new decoder which reads lower Base16 from the given reader.
+    pub fn new_lower(reader: R) -> Self {
+        Self::new(reader, lower::decode_nibble)
+    }
+
+    /// Create a new decoder which reads upper Base16 from the given reader.
+    pub fn new_upper(reader: R) -> Self {
+        Self::new(reader, upper::decode_nibble)
+    }
+
+    /// Create a new decoder which reads mixed case Base16 from the given
+    /// reader.
+    pub fn new_mixed(reader: R) -> Self {
+        Self::new(reader, mixed::decode_nibble)
+    }
+
+    /// Consume the decoder, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn new(reader: R, decode_nibble: fn(u8) -> u16) -> Self {
+        Self {
+            reader,
+            decode_nibble,
+            pending: None,
+        }
+    }
+}
+
+impl<R: io::Read> io::Read for DecoderReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut input = [0u8; IO_CHUNK_SIZE];
+        let input_len = buf.len().saturating_mul(2).min(IO_CHUNK_SIZE);
+
+        loop {
+            let start = match self.pending.take() {
+                Some(char) => {
+                    input[0] = char;
+                    1
+                }
+                None => 0,
+            };
+
+            let len = self.reader.read(&mut input[start..input_len])?;
+
+            if len == 0 {
+                return match start {
+                    0 => Ok(0),
+                    _ => Err(Error::InvalidLength.into()),
+                };
+            }
+
+            let total = start + len;
+            let even = total & !1;
+
+            if even < total {
+                self.pending = Some(input[total - 1]);
+            }
+
+            if even > 0 {
+                let decoded = decode_inner(&input[..even], buf, self.decode_nibble)?;
+                return Ok(decoded.len());
+            }
+        }
+    }
+}
@@ -70,12 +70,19 @@ pub mod upper;
 mod display;
 /// Error types.
 mod error;
+/// `std::io` adapters.
+#[cfg(feature = "std")]
+mod io;
 
 pub use crate::{
     display::HexDisplay,
     error::{Error, Result},
 };
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::io::{DecoderReader, EncoderWriter};
+
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
@@ -53,7 +53,7 @@ pub fn encode_string(input: &[u8]) -> String {
 
 /// Decode a single nibble of upper hex
 #[inline(always)]
-fn decode_nibble(src: u8) -> u16 {
+pub(crate) fn decode_nibble(src: u8) -> u16 {
     // 0-9  0x30-0x39
     // A-F  0x41-0x46 or a-f  0x61-0x66
     let byte = src as i16;
@@ -161,3 +161,63 @@ fn hex_display_lower() {
         assert_eq!(hex.as_bytes(), vector.lower_hex);
     }
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn encoder_writer() {
+    use std::io::Write;
+
+    for vector in HEX_TEST_VECTORS {
+        let mut writer = base16ct::EncoderWriter::new_lower(Vec::new());
+
+        // Write a byte at a time to exercise chunking
+        for byte in vector.raw {
+            writer.write_all(&[*byte]).unwrap();
+        }
+
+        assert_eq!(writer.into_inner(), vector.lower_hex);
+
+        let mut writer = base16ct::EncoderWriter::new_upper(Vec::new());
+        writer.write_all(vector.raw).unwrap();
+        assert_eq!(writer.into_inner(), vector.upper_hex);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn decoder_reader() {
+    use std::io::Read;
+
+    for vector in HEX_TEST_VECTORS {
+        let mut out = Vec::new();
+        base16ct::DecoderReader::new_lower(vector.lower_hex)
+            .read_to_end(&mut out)
+            .unwrap();
+        assert_eq!(out, vector.raw);
+
+        // Read a byte at a time from a reader returning a character at a time
+        let mut reader = base16ct::DecoderReader::new_mixed(
+            vector
+                .upper_hex
+                .chunks(1)
+                .fold(Box::new(std::io::empty()) as Box<dyn Read>, |acc, chunk| {
+                    Box::new(acc.chain(chunk))
+                }),
+        );
+        let mut out = Vec::new();
+        let mut byte = [0u8; 1];
+        while reader.read(&mut byte).unwrap() == 1 {
+            out.push(byte[0]);
+        }
+        assert_eq!(out, vector.raw);
+    }
+
+    let mut out = Vec::new();
+    let mut reader = base16ct::DecoderReader::new_upper(&b"ABC"[..]);
+    let err = reader.read_to_end(&mut out).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut reader = base16ct::DecoderReader::new_lower(&b"ABCD"[..]);
+    let err = reader.read_to_end(&mut out).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
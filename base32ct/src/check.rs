@@ -0,0 +1,163 @@
+//! Crockford's Base32 with a check symbol.
+
+use crate::{
+    encoding::{self, chars},
+    variant::{self, Variant, CROCKFORD_DECODER, CROCKFORD_ENCODER},
+    Base32Crockford, Encoding, Error,
+};
+use core::{ops::RangeInclusive, str};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Modulus of the check symbol.
+const CHECK_MODULUS: u8 = 37;
+
+/// Check symbol encoder: the Base32 alphabet followed by 5 additional
+/// symbols.
+const CHECK_ENCODER: &[RangeInclusive<u8>] = &[
+    b'0'..=b'9',
+    b'A'..=b'H',
+    b'J'..=b'K',
+    b'M'..=b'N',
+    b'P'..=b'T',
+    b'V'..=b'Z',
+    b'*'..=b'*',
+    b'~'..=b'~',
+    b'$'..=b'$',
+    b'='..=b'=',
+    b'U'..=b'U',
+];
+
+/// Check symbol decoder for the additional symbols, which are tried in
+/// addition to the Base32 decoder.
+const CHECK_DECODER: &[(RangeInclusive<u8>, u8)] = &[
+    (b'*'..=b'*', 32),
+    (b'~'..=b'~', 33),
+    (b'$'..=b'$', 34),
+    (b'='..=b'=', 35),
+    (b'U'..=b'U', 36),
+    (b'u'..=b'u', 36),
+];
+
+/// [Crockford's Base32] followed by a check symbol.
+///
+/// The check symbol encodes the number represented by the Base32 digits
+/// (i.e. the data followed by any zero bits padding the final digit) modulo
+/// 37, using the Base32 alphabet followed by the symbols `*~$=U`. Like the
+/// rest of the encoding it's decoded case-insensitively, and is compared in
+/// constant time.
+///
+/// ```
+/// use base32ct::Base32CrockfordCheck;
+///
+/// let mut buf = [0u8; 16];
+/// let encoded = Base32CrockfordCheck::encode(b"foobar", &mut buf).unwrap();
+/// assert_eq!(encoded, "CSQPYRK1E8R");
+///
+/// let mut dec_buf = [0u8; 16];
+/// let decoded = Base32CrockfordCheck::decode("csqp-yrk1-e8r", &mut dec_buf).unwrap();
+/// assert_eq!(decoded, b"foobar");
+/// ```
+///
+/// [Crockford's Base32]: https://www.crockford.com/base32.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32CrockfordCheck;
+
+impl Base32CrockfordCheck {
+    /// Decode a Crockford's Base32 string ending in a check symbol into the
+    /// provided destination buffer, returning the data.
+    ///
+    /// See [`Encoding::decode`] for the size of the buffer.
+    pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = chars::<Base32Crockford>(src.as_ref());
+        let data_len = src
+            .clone()
+            .count()
+            .checked_sub(1)
+            .ok_or(Error::InvalidEncoding)?;
+
+        let data = src.clone().take(data_len);
+        let check_symbol = src.last().ok_or(Error::InvalidEncoding)?;
+        let check = variant::decode_char(CROCKFORD_DECODER, check_symbol)
+            & variant::decode_char(CHECK_DECODER, check_symbol);
+
+        // An invalid check symbol is negative, and so never matches
+        let expected = checksum(data.clone().map(Base32Crockford::decode_char)) as i16;
+        let decoded = encoding::decode_iter::<Base32Crockford, _>(data, dst)?;
+
+        if (check ^ expected) == 0 {
+            Ok(decoded)
+        } else {
+            Err(Error::InvalidCheckSymbol)
+        }
+    }
+
+    /// Decode a Crockford's Base32 string ending in a check symbol into a
+    /// byte vector, returning the data.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; Base32Crockford::decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    /// Encode the input byte slice as Crockford's Base32 followed by a check
+    /// symbol.
+    ///
+    /// See [`Base32CrockfordCheck::encoded_len`] for the size of the buffer.
+    pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        let dst = dst
+            .get_mut(..Self::encoded_len(src.len()))
+            .ok_or(Error::InvalidLength)?;
+
+        let (check_symbol, digits) = dst.split_last_mut().ok_or(Error::InvalidLength)?;
+        encoding::encode_digits(src, digits);
+        *check_symbol = variant::encode_digit(
+            CHECK_ENCODER,
+            checksum(digits.iter().map(|&digit| digit as i16)),
+        );
+
+        for digit in digits.iter_mut() {
+            *digit = variant::encode_digit(CROCKFORD_ENCODER, *digit);
+        }
+
+        Ok(str::from_utf8(dst)?)
+    }
+
+    /// Encode input byte slice into a [`String`] containing Crockford's
+    /// Base32 followed by a check symbol.
+    ///
+    /// # Panics
+    /// If `input` is too large for its encoded length to be allocated.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_string(input: &[u8]) -> String {
+        let mut dst = vec![0u8; Self::encoded_len(input.len())];
+        let len = Self::encode(input, &mut dst).expect("encoding error").len();
+        dst.truncate(len);
+        String::from_utf8(dst).expect("invalid UTF-8")
+    }
+
+    /// Get the length of Crockford's Base32 and check symbol produced by
+    /// encoding the given number of bytes.
+    ///
+    /// Returns `usize::MAX` if the length overflows.
+    pub fn encoded_len(len: usize) -> usize {
+        Base32Crockford::encoded_len(len).saturating_add(1)
+    }
+}
+
+/// Compute the value of the check symbol for the given Base32 digits, which
+/// are masked to 5 bits.
+///
+/// Performs the same operations regardless of the values involved
+/// (division by a constant is compiled to multiplication).
+#[allow(clippy::integer_arithmetic)]
+fn checksum(digits: impl Iterator<Item = i16>) -> u8 {
+    digits.fold(0, |acc, digit| {
+        ((acc as u16 * 32 + (digit & 0x1f) as u16) % CHECK_MODULUS as u16) as u8
+    })
+}
@@ -0,0 +1,246 @@
+//! Base32 encodings
+
+use crate::{variant::Variant, Error};
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(doc)]
+use crate::{Base32, Base32Crockford, Base32Hex, Base32HexUnpadded, Base32Unpadded};
+
+/// Padding character
+const PAD: u8 = b'=';
+
+/// Base32 encoding trait.
+///
+/// This trait must be imported to make use of any Base32 variant defined
+/// in this crate.
+///
+/// The following encoding types impl this trait:
+///
+/// - [`Base32`]: standard Base32 encoding with `=` padding.
+/// - [`Base32Unpadded`]: standard Base32 encoding *without* padding.
+/// - [`Base32Hex`]: "Extended Hex" Base32 encoding with `=` padding.
+/// - [`Base32HexUnpadded`]: "Extended Hex" Base32 encoding *without* padding.
+/// - [`Base32Crockford`]: Crockford's Base32 encoding.
+pub trait Encoding: Variant {
+    /// Decode a Base32 string into the provided destination buffer.
+    ///
+    /// The buffer must be large enough for the decoded data, which is at
+    /// most [`Encoding::decoded_len`] bytes.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
+    /// Decode a Base32 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error>;
+
+    /// Encode the input byte slice as Base32.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base32 string value.
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error>;
+
+    /// Encode input byte slice into a [`String`] containing Base32.
+    ///
+    /// # Panics
+    /// If `input` is too large for its encoded length to be allocated.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode_string(input: &[u8]) -> String;
+
+    /// Get the length of Base32 produced by encoding the given number of
+    /// bytes.
+    ///
+    /// Returns `usize::MAX` if the length overflows.
+    fn encoded_len(len: usize) -> usize;
+
+    /// Get the maximum length of data produced by decoding the given number
+    /// of characters of Base32.
+    ///
+    /// The actual length is smaller if the input is padded or contains
+    /// separators.
+    fn decoded_len(len: usize) -> usize;
+}
+
+impl<T: Variant> Encoding for T {
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = if T::PADDED {
+            strip_padding(src.as_ref())?
+        } else {
+            src.as_ref()
+        };
+
+        decode_iter::<T, _>(chars::<T>(src), dst)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; Self::decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        let dst = dst
+            .get_mut(..Self::encoded_len(src.len()))
+            .ok_or(Error::InvalidLength)?;
+
+        let (digits, padding) = dst.split_at_mut(unpadded_len(src.len()));
+        encode_digits(src, digits);
+
+        for digit in digits.iter_mut() {
+            *digit = Self::encode_digit(*digit);
+        }
+
+        padding.fill(PAD);
+        Ok(str::from_utf8(dst)?)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String {
+        let mut dst = vec![0u8; Self::encoded_len(input.len())];
+        let len = Self::encode(input, &mut dst).expect("encoding error").len();
+        dst.truncate(len);
+        String::from_utf8(dst).expect("invalid UTF-8")
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    fn encoded_len(len: usize) -> usize {
+        if T::PADDED {
+            // Each (partial) block of 5 bytes is encoded as 8 characters
+            (len / 5)
+                .checked_add((len % 5 != 0) as usize)
+                .and_then(|blocks| blocks.checked_mul(8))
+                .unwrap_or(usize::MAX)
+        } else {
+            unpadded_len(len)
+        }
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    fn decoded_len(len: usize) -> usize {
+        // Each block of 8 characters decodes to 5 bytes
+        len / 8 * 5 + len % 8 * 5 / 8
+    }
+}
+
+/// Get the characters of the given Base32 string, skipping any separators.
+pub(crate) fn chars<T: Variant>(src: &[u8]) -> impl Iterator<Item = u8> + Clone + '_ {
+    src.iter()
+        .copied()
+        .filter(|&char| Some(char) != T::SEPARATOR)
+}
+
+/// Decode the given characters of unpadded Base32 into `dst`.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn decode_iter<T, I>(src: I, dst: &mut [u8]) -> Result<&[u8], Error>
+where
+    T: Variant,
+    I: Iterator<Item = u8> + Clone,
+{
+    let src_len = src.clone().count();
+
+    // A final partial block must contain enough characters for at least one
+    // byte, and no more than necessary for the bytes it contains
+    if matches!(src_len % 8, 1 | 3 | 6) {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let dst = dst
+        .get_mut(..T::decoded_len(src_len))
+        .ok_or(Error::InvalidLength)?;
+
+    let mut err = 0;
+    let mut bits = 0u16;
+    let mut bits_len = 0;
+    let mut dst_chunks = dst.iter_mut();
+
+    for char in src {
+        let value = T::decode_char(char);
+        err |= value;
+        bits = (bits << 5) | (value & 0x1f) as u16;
+        bits_len += 5;
+
+        if bits_len >= 8 {
+            bits_len -= 8;
+
+            if let Some(byte) = dst_chunks.next() {
+                *byte = (bits >> bits_len) as u8;
+            }
+
+            bits &= (1 << bits_len) - 1;
+        }
+    }
+
+    // Any invalid characters set the high bits of `err`, and any remaining
+    // bits must be zero for the encoding to be canonical
+    if err & !0x1f != 0 || bits != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(dst)
+}
+
+/// Encode the input as Base32 digits (i.e. values less than 32), filling
+/// `dst`, which must be [`unpadded_len`] bytes.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn encode_digits(src: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(dst.len(), unpadded_len(src.len()));
+
+    let mut bits = 0u16;
+    let mut bits_len = 0;
+    let mut dst_digits = dst.iter_mut();
+
+    for &byte in src {
+        bits = (bits << 8) | byte as u16;
+        bits_len += 8;
+
+        while bits_len >= 5 {
+            bits_len -= 5;
+
+            if let Some(digit) = dst_digits.next() {
+                *digit = ((bits >> bits_len) & 0x1f) as u8;
+            }
+        }
+
+        bits &= (1 << bits_len) - 1;
+    }
+
+    // Pad the final digit with zero bits
+    if let Some(digit) = dst_digits.next() {
+        *digit = ((bits << (5 - bits_len)) & 0x1f) as u8;
+    }
+}
+
+/// Get the length of unpadded Base32 produced by encoding the given number
+/// of bytes.
+///
+/// Returns `usize::MAX` if the length overflows.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn unpadded_len(len: usize) -> usize {
+    (len / 5)
+        .checked_mul(8)
+        .and_then(|full| full.checked_add((len % 5 * 8 + 4) / 5))
+        .unwrap_or(usize::MAX)
+}
+
+/// Validate and remove the padding of a padded Base32 string.
+#[allow(clippy::integer_arithmetic)]
+fn strip_padding(src: &[u8]) -> Result<&[u8], Error> {
+    if src.len() % 8 != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let padding_len = src.iter().rev().take(6).take_while(|&&c| c == PAD).count();
+    let unpadded = &src[..src.len() - padding_len];
+
+    // Padding must complete the final block
+    if (8 - unpadded.len() % 8) % 8 != padding_len {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(unpadded)
+}
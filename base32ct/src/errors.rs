@@ -0,0 +1,45 @@
+//! Error types
+
+use core::fmt;
+
+/// Base32 errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Crockford's Base32 check symbol mismatch.
+    InvalidCheckSymbol,
+
+    /// Invalid encoding of provided Base32 string.
+    InvalidEncoding,
+
+    /// Insufficient output buffer length.
+    InvalidLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidCheckSymbol => "invalid Base32 check symbol",
+            Self::InvalidEncoding => "invalid Base32 encoding",
+            Self::InvalidLength => "invalid Base32 length",
+        })
+    }
+}
+
+impl From<core::str::Utf8Error> for Error {
+    #[inline]
+    fn from(_: core::str::Utf8Error) -> Error {
+        Error::InvalidEncoding
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
@@ -0,0 +1,63 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/base32ct/0.1.0"
+)]
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::integer_arithmetic,
+    clippy::panic,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unsafe_code,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! ```
+//! use base32ct::{Base32, Encoding};
+//!
+//! let bytes = b"example bytestring!";
+//!
+//! let mut enc_buf = [0u8; 32];
+//! let encoded = Base32::encode(bytes, &mut enc_buf).unwrap();
+//! assert_eq!(encoded, "MV4GC3LQNRSSAYTZORSXG5DSNFXGOII=");
+//!
+//! let mut dec_buf = [0u8; 32];
+//! let decoded = Base32::decode(encoded, &mut dec_buf).unwrap();
+//! assert_eq!(decoded, bytes);
+//! ```
+//!
+//! # Implementation
+//!
+//! Characters are encoded and decoded using integer arithmetic alone, in
+//! the same way as the `base64ct` crate, with every step performed
+//! regardless of the values involved.
+//!
+//! Not constant-time with respect to the length of the input, or to the
+//! positions of any hyphens in Crockford's Base32, which are skipped when
+//! decoding.
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod check;
+mod encoding;
+mod errors;
+mod variant;
+
+pub use crate::{
+    check::Base32CrockfordCheck,
+    encoding::Encoding,
+    errors::Error,
+    variant::{Base32, Base32Crockford, Base32Hex, Base32HexUnpadded, Base32Unpadded},
+};
@@ -0,0 +1,264 @@
+//! Base32 variants
+
+// Arithmetic on characters and digits, which are always small
+#![allow(clippy::integer_arithmetic)]
+
+use core::{fmt::Debug, ops::RangeInclusive};
+
+/// Core encoder/decoder functions for a particular Base32 variant.
+pub trait Variant: 'static + Copy + Debug + Eq + Send + Sized + Sync {
+    /// Is this encoding padded?
+    const PADDED: bool;
+
+    /// Character ranges making up the alphabet, in order.
+    const ENCODER: &'static [RangeInclusive<u8>];
+
+    /// Character ranges accepted when decoding, along with the value of the
+    /// first character of each range.
+    const DECODER: &'static [(RangeInclusive<u8>, u8)];
+
+    /// Separator character which is ignored when decoding, if any.
+    const SEPARATOR: Option<u8> = None;
+
+    /// Decode a Base32 character into its value, returning a negative value
+    /// if the character is invalid.
+    fn decode_char(src: u8) -> i16 {
+        decode_char(Self::DECODER, src)
+    }
+
+    /// Encode a Base32 digit (i.e. a value less than 32) as a character.
+    fn encode_digit(src: u8) -> u8 {
+        encode_digit(Self::ENCODER, src)
+    }
+}
+
+/// Standard Base32 encoding with `=` padding, as described in
+/// [RFC 4648 Section 6].
+///
+/// ```text
+/// [A-Z]      [2-7]
+/// 0x41-0x5a, 0x32-0x37
+/// ```
+///
+/// [RFC 4648 Section 6]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32;
+
+impl Variant for Base32 {
+    const PADDED: bool = true;
+    const ENCODER: &'static [RangeInclusive<u8>] = STANDARD_ENCODER;
+    const DECODER: &'static [(RangeInclusive<u8>, u8)] = STANDARD_DECODER;
+}
+
+/// Standard Base32 encoding *without* padding.
+///
+/// ```text
+/// [A-Z]      [2-7]
+/// 0x41-0x5a, 0x32-0x37
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Unpadded;
+
+impl Variant for Base32Unpadded {
+    const PADDED: bool = false;
+    const ENCODER: &'static [RangeInclusive<u8>] = STANDARD_ENCODER;
+    const DECODER: &'static [(RangeInclusive<u8>, u8)] = STANDARD_DECODER;
+}
+
+/// "Extended Hex" Base32 encoding with `=` padding, as described in
+/// [RFC 4648 Section 7].
+///
+/// ```text
+/// [0-9]      [A-V]
+/// 0x30-0x39, 0x41-0x56
+/// ```
+///
+/// [RFC 4648 Section 7]: https://datatracker.ietf.org/doc/html/rfc4648#section-7
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Hex;
+
+impl Variant for Base32Hex {
+    const PADDED: bool = true;
+    const ENCODER: &'static [RangeInclusive<u8>] = HEX_ENCODER;
+    const DECODER: &'static [(RangeInclusive<u8>, u8)] = HEX_DECODER;
+}
+
+/// "Extended Hex" Base32 encoding *without* padding.
+///
+/// ```text
+/// [0-9]      [A-V]
+/// 0x30-0x39, 0x41-0x56
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32HexUnpadded;
+
+impl Variant for Base32HexUnpadded {
+    const PADDED: bool = false;
+    const ENCODER: &'static [RangeInclusive<u8>] = HEX_ENCODER;
+    const DECODER: &'static [(RangeInclusive<u8>, u8)] = HEX_DECODER;
+}
+
+/// [Crockford's Base32] encoding, which is unpadded.
+///
+/// Encodes using upper case characters, but decodes case-insensitively,
+/// decoding `I` and `L` as `1` and `O` as `0`, and ignoring hyphens. See
+/// [`Base32CrockfordCheck`][`crate::Base32CrockfordCheck`] for the optional
+/// check symbol.
+///
+/// ```text
+/// [0-9]      [A-H]      [J-K]      [M-N]      [P-T]      [V-Z]
+/// 0x30-0x39, 0x41-0x48, 0x4a-0x4b, 0x4d-0x4e, 0x50-0x54, 0x56-0x5a
+/// ```
+///
+/// [Crockford's Base32]: https://www.crockford.com/base32.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Crockford;
+
+impl Variant for Base32Crockford {
+    const PADDED: bool = false;
+    const ENCODER: &'static [RangeInclusive<u8>] = CROCKFORD_ENCODER;
+    const DECODER: &'static [(RangeInclusive<u8>, u8)] = CROCKFORD_DECODER;
+    const SEPARATOR: Option<u8> = Some(b'-');
+}
+
+/// Standard Base32 encoder
+const STANDARD_ENCODER: &[RangeInclusive<u8>] = &[b'A'..=b'Z', b'2'..=b'7'];
+
+/// Standard Base32 decoder
+const STANDARD_DECODER: &[(RangeInclusive<u8>, u8)] = &[(b'A'..=b'Z', 0), (b'2'..=b'7', 26)];
+
+/// "Extended Hex" Base32 encoder
+const HEX_ENCODER: &[RangeInclusive<u8>] = &[b'0'..=b'9', b'A'..=b'V'];
+
+/// "Extended Hex" Base32 decoder
+const HEX_DECODER: &[(RangeInclusive<u8>, u8)] = &[(b'0'..=b'9', 0), (b'A'..=b'V', 10)];
+
+/// Crockford's Base32 encoder
+pub(crate) const CROCKFORD_ENCODER: &[RangeInclusive<u8>] = &[
+    b'0'..=b'9',
+    b'A'..=b'H',
+    b'J'..=b'K',
+    b'M'..=b'N',
+    b'P'..=b'T',
+    b'V'..=b'Z',
+];
+
+/// Crockford's Base32 decoder
+pub(crate) const CROCKFORD_DECODER: &[(RangeInclusive<u8>, u8)] = &[
+    (b'0'..=b'9', 0),
+    (b'A'..=b'H', 10),
+    (b'J'..=b'K', 18),
+    (b'M'..=b'N', 20),
+    (b'P'..=b'T', 22),
+    (b'V'..=b'Z', 27),
+    (b'a'..=b'h', 10),
+    (b'j'..=b'k', 18),
+    (b'm'..=b'n', 20),
+    (b'p'..=b't', 22),
+    (b'v'..=b'z', 27),
+    (b'O'..=b'O', 0),
+    (b'o'..=b'o', 0),
+    (b'I'..=b'I', 1),
+    (b'i'..=b'i', 1),
+    (b'L'..=b'L', 1),
+    (b'l'..=b'l', 1),
+];
+
+/// Decode a character using the given decoder ranges, returning a negative
+/// value if the character isn't in any of them.
+///
+/// The ranges must not overlap.
+pub(crate) fn decode_char(decoder: &[(RangeInclusive<u8>, u8)], src: u8) -> i16 {
+    let src = src as i16;
+    let mut res: i16 = -1;
+
+    for (range, value) in decoder {
+        let first = *range.start() as i16;
+        let last = *range.end() as i16;
+
+        // Compute exclusive range from inclusive one
+        let start = first - 1;
+        let end = last + 1;
+        res += (((start - src) & (src - end)) >> 8) & (src - first + *value as i16 + 1);
+    }
+
+    res
+}
+
+/// Encode a digit as a character using the given encoder ranges, which
+/// must contain more characters than the value of the digit.
+pub(crate) fn encode_digit(encoder: &[RangeInclusive<u8>], src: u8) -> u8 {
+    let src = src as i16;
+    let mut res = src;
+    let mut index: i16 = 0;
+    let mut prev_end: i16 = 0;
+
+    for range in encoder {
+        let first = *range.start() as i16;
+        let last = *range.end() as i16;
+
+        // Offset from the end of the previous range to this one, which
+        // applies to all digits from this range onwards
+        res += ((index - 1 - src) >> 8) & (first - prev_end);
+        index += last - first + 1;
+        prev_end = last + 1;
+    }
+
+    res as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Base32, Base32Crockford, Base32Hex, Variant};
+
+    const STANDARD: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    const HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+    const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    fn check_alphabet<V: Variant>(chars: &[u8; 32], aliases: &[(u8, u8)]) {
+        for (digit, &char) in chars.iter().enumerate() {
+            assert_eq!(V::encode_digit(digit as u8), char);
+            assert_eq!(V::decode_char(char), digit as i16);
+        }
+
+        for &(alias, char) in aliases {
+            assert_eq!(V::decode_char(alias), V::decode_char(char));
+        }
+
+        for char in 0..=255u8 {
+            if !chars.contains(&char) && !aliases.iter().any(|&(alias, _)| alias == char) {
+                assert!(V::decode_char(char) < 0, "{:#x}", char);
+            }
+        }
+    }
+
+    #[test]
+    fn standard() {
+        check_alphabet::<Base32>(STANDARD, &[]);
+    }
+
+    #[test]
+    fn hex() {
+        check_alphabet::<Base32Hex>(HEX, &[]);
+    }
+
+    #[test]
+    fn crockford() {
+        let mut aliases = [(0, 0); 28];
+
+        for (alias, &char) in aliases.iter_mut().zip(CROCKFORD.iter().skip(10)) {
+            *alias = (char.to_ascii_lowercase(), char);
+        }
+
+        aliases[22..].copy_from_slice(&[
+            (b'O', b'0'),
+            (b'o', b'0'),
+            (b'I', b'1'),
+            (b'i', b'1'),
+            (b'L', b'1'),
+            (b'l', b'1'),
+        ]);
+
+        check_alphabet::<Base32Crockford>(CROCKFORD, &aliases);
+    }
+}
@@ -0,0 +1,166 @@
+//! Crockford's Base32 tests
+
+use base32ct::{Base32Crockford, Base32CrockfordCheck, Encoding, Error};
+use hex_literal::hex;
+
+/// Crockford's Base32 test vector
+struct TestVector {
+    raw: &'static [u8],
+    b32: &'static str,
+    check: char,
+}
+
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        raw: b"",
+        b32: "",
+        check: '0',
+    },
+    TestVector {
+        raw: b"f",
+        b32: "CR",
+        check: '1',
+    },
+    TestVector {
+        raw: b"fo",
+        b32: "CSQG",
+        check: 'S',
+    },
+    TestVector {
+        raw: b"foo",
+        b32: "CSQPY",
+        check: 'Q',
+    },
+    TestVector {
+        raw: b"foob",
+        b32: "CSQPYRG",
+        check: 'V',
+    },
+    TestVector {
+        raw: b"fooba",
+        b32: "CSQPYRK1",
+        check: 'U',
+    },
+    TestVector {
+        raw: b"foobar",
+        b32: "CSQPYRK1E8",
+        check: 'R',
+    },
+    TestVector {
+        raw: &hex!("ffffffffff"),
+        b32: "ZZZZZZZZ",
+        check: 'F',
+    },
+    TestVector {
+        raw: b"Hello, world!",
+        b32: "91JPRV3F5GG7EVVJDHJ22",
+        check: 'J',
+    },
+];
+
+#[test]
+fn encode() {
+    for vector in TEST_VECTORS {
+        let mut buf = [0u8; 32];
+        assert_eq!(
+            Base32Crockford::encode(vector.raw, &mut buf).unwrap(),
+            vector.b32
+        );
+
+        let encoded = Base32CrockfordCheck::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(encoded.strip_suffix(vector.check), Some(vector.b32));
+    }
+}
+
+#[test]
+fn decode() {
+    for vector in TEST_VECTORS {
+        let mut buf = [0u8; 32];
+        assert_eq!(
+            Base32Crockford::decode(vector.b32, &mut buf).unwrap(),
+            vector.raw
+        );
+
+        let mut checked = [0u8; 32];
+        let len = vector.b32.len();
+        checked[..len].copy_from_slice(vector.b32.as_bytes());
+        checked[len] = vector.check as u8;
+        assert_eq!(
+            Base32CrockfordCheck::decode(&checked[..=len], &mut buf).unwrap(),
+            vector.raw
+        );
+    }
+}
+
+#[test]
+fn decode_aliases() {
+    let mut buf = [0u8; 16];
+
+    // Case-insensitive, with `I`/`L` as `1`, `O` as `0`, and hyphens ignored
+    assert_eq!(
+        Base32Crockford::decode("csqp-yrk1-e8", &mut buf).unwrap(),
+        b"foobar"
+    );
+    assert_eq!(
+        Base32Crockford::decode("CSQPYRKIE8", &mut buf).unwrap(),
+        b"foobar"
+    );
+    assert_eq!(
+        Base32Crockford::decode("CSQPYRKlE8", &mut buf).unwrap(),
+        b"foobar"
+    );
+    assert_eq!(Base32Crockford::decode("O0", &mut buf).unwrap(), &[0]);
+
+    // `U` is only valid as a check symbol
+    assert_eq!(
+        Base32Crockford::decode("CSQPYRKU", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32CrockfordCheck::decode("csqpyrk1u", &mut buf).unwrap(),
+        b"fooba"
+    );
+}
+
+#[test]
+fn check_symbols() {
+    // Values 32-36 are encoded using additional check symbols
+    for (byte, encoded) in [
+        (8, "10*"),
+        (36, "4G~"),
+        (27, "3C$"),
+        (18, "28="),
+        (9, "14U"),
+    ] {
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            Base32CrockfordCheck::encode(&[byte], &mut buf).unwrap(),
+            encoded
+        );
+        assert_eq!(
+            Base32CrockfordCheck::decode(encoded, &mut buf).unwrap(),
+            &[byte]
+        );
+    }
+}
+
+#[test]
+fn reject_invalid_check_symbol() {
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        Base32CrockfordCheck::decode("CSQPYRK1E8S", &mut buf),
+        Err(Error::InvalidCheckSymbol)
+    );
+    assert_eq!(
+        Base32CrockfordCheck::decode("CSQPYRK1E8#", &mut buf),
+        Err(Error::InvalidCheckSymbol)
+    );
+    assert_eq!(
+        Base32CrockfordCheck::decode("", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32CrockfordCheck::decode("-", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
@@ -0,0 +1,163 @@
+//! RFC 4648 Base32 tests
+
+use base32ct::{Base32, Base32Hex, Base32HexUnpadded, Base32Unpadded, Encoding, Error};
+
+/// Base32 test vector
+struct TestVector {
+    raw: &'static [u8],
+    b32: &'static str,
+}
+
+/// Test vectors from RFC 4648 Section 10.
+const STANDARD_TEST_VECTORS: &[TestVector] = &[
+    TestVector { raw: b"", b32: "" },
+    TestVector {
+        raw: b"f",
+        b32: "MY======",
+    },
+    TestVector {
+        raw: b"fo",
+        b32: "MZXQ====",
+    },
+    TestVector {
+        raw: b"foo",
+        b32: "MZXW6===",
+    },
+    TestVector {
+        raw: b"foob",
+        b32: "MZXW6YQ=",
+    },
+    TestVector {
+        raw: b"fooba",
+        b32: "MZXW6YTB",
+    },
+    TestVector {
+        raw: b"foobar",
+        b32: "MZXW6YTBOI======",
+    },
+];
+
+/// Test vectors from RFC 4648 Section 10.
+const HEX_TEST_VECTORS: &[TestVector] = &[
+    TestVector { raw: b"", b32: "" },
+    TestVector {
+        raw: b"f",
+        b32: "CO======",
+    },
+    TestVector {
+        raw: b"fo",
+        b32: "CPNG====",
+    },
+    TestVector {
+        raw: b"foo",
+        b32: "CPNMU===",
+    },
+    TestVector {
+        raw: b"foob",
+        b32: "CPNMUOG=",
+    },
+    TestVector {
+        raw: b"fooba",
+        b32: "CPNMUOJ1",
+    },
+    TestVector {
+        raw: b"foobar",
+        b32: "CPNMUOJ1E8======",
+    },
+];
+
+fn check_vectors<Padded: Encoding, Unpadded: Encoding>(vectors: &[TestVector]) {
+    for vector in vectors {
+        let unpadded = vector.b32.trim_end_matches('=');
+
+        let mut buf = [0u8; 16];
+        assert_eq!(Padded::encoded_len(vector.raw.len()), vector.b32.len());
+        assert_eq!(Padded::encode(vector.raw, &mut buf).unwrap(), vector.b32);
+        assert_eq!(Unpadded::encoded_len(vector.raw.len()), unpadded.len());
+        assert_eq!(Unpadded::encode(vector.raw, &mut buf).unwrap(), unpadded);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(Padded::decode(vector.b32, &mut buf).unwrap(), vector.raw);
+        assert_eq!(Unpadded::decode(unpadded, &mut buf).unwrap(), vector.raw);
+
+        // Padding is required by the padded variants only
+        if unpadded != vector.b32 {
+            assert_eq!(
+                Padded::decode(unpadded, &mut buf),
+                Err(Error::InvalidEncoding)
+            );
+            assert_eq!(
+                Unpadded::decode(vector.b32, &mut buf),
+                Err(Error::InvalidEncoding)
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(Padded::encode_string(vector.raw), vector.b32);
+            assert_eq!(Padded::decode_vec(vector.b32).unwrap(), vector.raw);
+        }
+    }
+}
+
+#[test]
+fn standard() {
+    check_vectors::<Base32, Base32Unpadded>(STANDARD_TEST_VECTORS);
+}
+
+#[test]
+fn hex() {
+    check_vectors::<Base32Hex, Base32HexUnpadded>(HEX_TEST_VECTORS);
+}
+
+#[test]
+fn reject_invalid_chars() {
+    let mut buf = [0u8; 16];
+
+    for input in ["mzxw6ytb", "MZXW6YT1", "MZXW6YT-", "MZXW 6YT"] {
+        assert_eq!(Base32::decode(input, &mut buf), Err(Error::InvalidEncoding));
+    }
+}
+
+#[test]
+fn reject_invalid_padding() {
+    let mut buf = [0u8; 16];
+
+    for input in [
+        "MY=====",
+        "MY=======",
+        "M=======",
+        "MZX=====",
+        "MZXW6Y==",
+        "========",
+    ] {
+        assert_eq!(Base32::decode(input, &mut buf), Err(Error::InvalidEncoding));
+    }
+}
+
+#[test]
+fn reject_non_canonical() {
+    // Nonzero bits following the final byte
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        Base32Unpadded::decode("MZ", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32Unpadded::decode("MZXR", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+fn reject_short_buffer() {
+    let mut buf = [0u8; 5];
+    assert_eq!(
+        Base32::decode("MZXW6YTBOI======", &mut buf),
+        Err(Error::InvalidLength)
+    );
+    assert_eq!(
+        Base32::encode(b"foobar", &mut buf),
+        Err(Error::InvalidLength)
+    );
+}
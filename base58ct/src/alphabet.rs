@@ -0,0 +1,125 @@
+//! Base58 alphabets
+
+// Arithmetic on characters and digits, which are always small
+#![allow(clippy::integer_arithmetic)]
+
+use core::{fmt::Debug, ops::RangeInclusive};
+
+/// Core encoder/decoder functions for a particular Base58 alphabet.
+pub trait Alphabet: 'static + Copy + Debug + Eq + Send + Sized + Sync {
+    /// Character ranges making up the alphabet, in order.
+    const RANGES: &'static [RangeInclusive<u8>];
+
+    /// Decode a Base58 character into its value, returning a negative
+    /// value if the character is invalid.
+    fn decode_char(src: u8) -> i16 {
+        let src = src as i16;
+        let mut res: i16 = -1;
+        let mut index: i16 = 0;
+
+        for range in Self::RANGES {
+            let first = *range.start() as i16;
+            let last = *range.end() as i16;
+
+            // Compute exclusive range from inclusive one
+            let start = first - 1;
+            let end = last + 1;
+            res += (((start - src) & (src - end)) >> 8) & (src - first + index + 1);
+            index += last - first + 1;
+        }
+
+        res
+    }
+
+    /// Encode a Base58 digit (i.e. a value less than 58) as a character.
+    fn encode_digit(src: u8) -> u8 {
+        let src = src as i16;
+        let mut res = src;
+        let mut index: i16 = 0;
+        let mut prev_end: i16 = 0;
+
+        for range in Self::RANGES {
+            let first = *range.start() as i16;
+            let last = *range.end() as i16;
+
+            // Offset from the end of the previous range to this one, which
+            // applies to all digits from this range onwards
+            res += ((index - 1 - src) >> 8) & (first - prev_end);
+            index += last - first + 1;
+            prev_end = last + 1;
+        }
+
+        res as u8
+    }
+}
+
+/// Bitcoin Base58 alphabet, also used by e.g. IPFS and Solana.
+///
+/// ```text
+/// [1-9]      [A-H]      [J-N]      [P-Z]      [a-k]      [m-z]
+/// 0x31-0x39, 0x41-0x48, 0x4a-0x4e, 0x50-0x5a, 0x61-0x6b, 0x6d-0x7a
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base58;
+
+impl Alphabet for Base58 {
+    const RANGES: &'static [RangeInclusive<u8>] = &[
+        b'1'..=b'9',
+        b'A'..=b'H',
+        b'J'..=b'N',
+        b'P'..=b'Z',
+        b'a'..=b'k',
+        b'm'..=b'z',
+    ];
+}
+
+/// Flickr Base58 alphabet.
+///
+/// ```text
+/// [1-9]      [a-k]      [m-z]      [A-H]      [J-N]      [P-Z]
+/// 0x31-0x39, 0x61-0x6b, 0x6d-0x7a, 0x41-0x48, 0x4a-0x4e, 0x50-0x5a
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base58Flickr;
+
+impl Alphabet for Base58Flickr {
+    const RANGES: &'static [RangeInclusive<u8>] = &[
+        b'1'..=b'9',
+        b'a'..=b'k',
+        b'm'..=b'z',
+        b'A'..=b'H',
+        b'J'..=b'N',
+        b'P'..=b'Z',
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, Base58, Base58Flickr};
+
+    const BITCOIN: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    const FLICKR: &[u8; 58] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+
+    fn check_alphabet<A: Alphabet>(chars: &[u8; 58]) {
+        for (digit, &char) in chars.iter().enumerate() {
+            assert_eq!(A::encode_digit(digit as u8), char);
+            assert_eq!(A::decode_char(char), digit as i16);
+        }
+
+        for char in 0..=255u8 {
+            if !chars.contains(&char) {
+                assert!(A::decode_char(char) < 0, "{:#x}", char);
+            }
+        }
+    }
+
+    #[test]
+    fn bitcoin() {
+        check_alphabet::<Base58>(BITCOIN);
+    }
+
+    #[test]
+    fn flickr() {
+        check_alphabet::<Base58Flickr>(FLICKR);
+    }
+}
@@ -0,0 +1,116 @@
+//! Base58Check encoding.
+
+use crate::{encoding, Base58, Encoding, Error};
+use core::marker::PhantomData;
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Length of the checksum appended to the data.
+const CHECKSUM_LEN: usize = 4;
+
+/// Base58Check with the Bitcoin alphabet.
+pub type Base58Check = Check<Base58>;
+
+/// Base58Check: Base58 encoding `E` of data followed by a 4-byte checksum,
+/// the first 4 bytes of the double SHA-256 hash of the data.
+///
+/// The checksum is verified in constant time, however the data is hashed
+/// using the `sha2` crate, which may not be constant-time on all platforms.
+///
+/// ```
+/// use base58ct::Base58Check;
+///
+/// let payload = [0x00, 0x01, 0x02];
+/// let mut buf = [0u8; 16];
+/// let encoded = Base58Check::encode(&payload, &mut buf).unwrap();
+/// assert_eq!(encoded, "1W8eAT7x");
+///
+/// let mut dec_buf = [0u8; 16];
+/// assert_eq!(Base58Check::decode(encoded, &mut dec_buf).unwrap(), payload);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Check<E: Encoding> {
+    /// Phantom parameter for the Base58 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding> Check<E> {
+    /// Decode a Base58Check string into the provided destination buffer,
+    /// returning the data without its checksum.
+    ///
+    /// See [`Encoding::decode`] for the size of the buffer.
+    pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let decoded = E::decode(src, dst)?;
+        let data_len = decoded
+            .len()
+            .checked_sub(CHECKSUM_LEN)
+            .ok_or(Error::InvalidEncoding)?;
+        let (data, checksum) = decoded.split_at(data_len);
+
+        let diff = checksum
+            .iter()
+            .zip(self::checksum(data).iter())
+            .fold(0, |acc, (a, b)| acc | (a ^ b));
+
+        if diff == 0 {
+            Ok(data)
+        } else {
+            Err(Error::InvalidChecksum)
+        }
+    }
+
+    /// Decode a Base58Check string into a byte vector, returning the data
+    /// without its checksum.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; E::max_decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    /// Encode the input byte slice as Base58Check.
+    ///
+    /// See [`Check::max_encoded_len`] for the size of the buffer.
+    pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        let checksum = checksum(src);
+        let len = src
+            .len()
+            .checked_add(CHECKSUM_LEN)
+            .ok_or(Error::InvalidLength)?;
+        let input = src.iter().chain(checksum.iter()).copied();
+        encoding::encode_iter::<E, _>(input, len, dst)
+    }
+
+    /// Encode input byte slice into a [`String`] containing Base58Check.
+    ///
+    /// # Panics
+    /// If `input` is too large for its encoded length to be allocated.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_string(input: &[u8]) -> String {
+        let mut dst = vec![0u8; Self::max_encoded_len(input.len())];
+        let len = Self::encode(input, &mut dst).expect("encoding error").len();
+        dst.truncate(len);
+        String::from_utf8(dst).expect("invalid UTF-8")
+    }
+
+    /// Get the maximum length of Base58Check produced by encoding the given
+    /// number of bytes.
+    ///
+    /// This is also the size of the buffer needed to encode them.
+    pub fn max_encoded_len(len: usize) -> usize {
+        E::max_encoded_len(len.saturating_add(CHECKSUM_LEN))
+    }
+}
+
+/// Compute the checksum of the given data.
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    checksum.copy_from_slice(&hash[..CHECKSUM_LEN]);
+    checksum
+}
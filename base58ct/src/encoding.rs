@@ -0,0 +1,254 @@
+//! Base58 encodings
+
+use crate::{alphabet::Alphabet, Error};
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(doc)]
+use crate::{Base58, Base58Flickr};
+
+/// Base58 encoding trait.
+///
+/// This trait must be imported to make use of any Base58 alphabet defined
+/// in this crate.
+///
+/// The following encoding types impl this trait:
+///
+/// - [`Base58`]: Bitcoin alphabet.
+/// - [`Base58Flickr`]: Flickr alphabet.
+pub trait Encoding: Alphabet {
+    /// Decode a Base58 string into the provided destination buffer.
+    ///
+    /// The buffer must be at least [`Encoding::max_decoded_len`] bytes,
+    /// or large enough for the decoded data if that is smaller, as it's
+    /// also used as scratch space while decoding.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
+    /// Decode a Base58 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error>;
+
+    /// Encode the input byte slice as Base58.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base58 string value. The buffer must be at least
+    /// [`Encoding::max_encoded_len`] bytes, as it's also used as scratch
+    /// space while encoding.
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error>;
+
+    /// Encode input byte slice into a [`String`] containing Base58.
+    ///
+    /// # Panics
+    /// If `input` is too large for its encoded length to be allocated.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode_string(input: &[u8]) -> String;
+
+    /// Get the maximum length of Base58 produced by encoding the given
+    /// number of bytes.
+    ///
+    /// The actual length depends on the data being encoded.
+    fn max_encoded_len(len: usize) -> usize;
+
+    /// Get the maximum length of data produced by decoding the given number
+    /// of characters of Base58.
+    ///
+    /// The actual length depends on the data being decoded.
+    fn max_decoded_len(len: usize) -> usize;
+}
+
+impl<T: Alphabet> Encoding for T {
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+
+        if src.is_empty() {
+            return Ok(&dst[..0]);
+        }
+
+        let len = dst.len().min(Self::max_decoded_len(src.len()));
+
+        // The decoded value must fit in the scratch space
+        if len < bignum_decoded_len(src.len()) {
+            return Err(Error::InvalidLength);
+        }
+
+        let dst = &mut dst[..len];
+        dst.fill(0);
+
+        let mut err = 0;
+
+        for &char in src {
+            let value = Self::decode_char(char);
+            err |= value;
+            push_digit(dst, (value & 0x3f) as u8);
+        }
+
+        // Any invalid characters set the high bits of `err`
+        if err & !0x3f != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        // Each leading zero character represents a leading zero byte
+        let zeros = count_leading(src.iter().map(|&char| char ^ Self::encode_digit(0)));
+        let decoded_len = significant_len(dst)
+            .checked_add(zeros)
+            .ok_or(Error::InvalidLength)?;
+        let skip = len.checked_sub(decoded_len).ok_or(Error::InvalidLength)?;
+        shift_left(dst, skip);
+        Ok(&dst[..decoded_len])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; Self::max_decoded_len(input.len())];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+        encode_iter::<T, _>(src.iter().copied(), src.len(), dst)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String {
+        let mut dst = vec![0u8; Self::max_encoded_len(input.len())];
+        let len = Self::encode(input, &mut dst).expect("encoding error").len();
+        dst.truncate(len);
+        String::from_utf8(dst).expect("invalid UTF-8")
+    }
+
+    fn max_encoded_len(len: usize) -> usize {
+        // log(256) / log(58) ~= 1.366
+        len.saturating_mul(138)
+            .checked_div(100)
+            .and_then(|len| len.checked_add(1))
+            .unwrap_or(usize::MAX)
+    }
+
+    fn max_decoded_len(len: usize) -> usize {
+        // Each leading zero character decodes to a byte, and the rest decode
+        // to fewer bytes than characters
+        len
+    }
+}
+
+/// Encode the bytes produced by the given iterator as Base58.
+pub(crate) fn encode_iter<A, I>(src: I, src_len: usize, dst: &mut [u8]) -> Result<&str, Error>
+where
+    A: Alphabet,
+    I: Iterator<Item = u8> + Clone,
+{
+    if src_len == 0 {
+        return Ok("");
+    }
+
+    let len = A::max_encoded_len(src_len);
+    let dst = dst.get_mut(..len).ok_or(Error::InvalidLength)?;
+    dst.fill(0);
+
+    for byte in src.clone() {
+        push_byte(dst, byte);
+    }
+
+    // Each leading zero byte is represented by a leading zero digit
+    let zeros = count_leading(src);
+    let encoded_len = significant_len(dst)
+        .checked_add(zeros)
+        .ok_or(Error::InvalidLength)?;
+    let skip = len.checked_sub(encoded_len).ok_or(Error::InvalidLength)?;
+    shift_left(dst, skip);
+
+    for digit in dst.iter_mut() {
+        *digit = A::encode_digit(*digit);
+    }
+
+    Ok(str::from_utf8(&dst[..encoded_len])?)
+}
+
+/// Number of bytes needed to hold the big-endian number decoded from the
+/// given number of characters of Base58 (excluding leading zero bytes).
+fn bignum_decoded_len(len: usize) -> usize {
+    // log(58) / log(256) ~= 0.732
+    len.saturating_mul(733)
+        .checked_div(1000)
+        .and_then(|len| len.checked_add(1))
+        .unwrap_or(usize::MAX)
+}
+
+/// Multiply the big-endian base 58 number in `digits` by 256 and add `byte`.
+///
+/// The number must be large enough that this doesn't overflow. Performs the
+/// same operations regardless of the values involved (division by a constant
+/// is compiled to multiplication).
+#[allow(clippy::integer_arithmetic)]
+fn push_byte(digits: &mut [u8], byte: u8) {
+    let mut carry = byte as u32;
+
+    for digit in digits.iter_mut().rev() {
+        carry += (*digit as u32) << 8;
+        *digit = (carry % 58) as u8;
+        carry /= 58;
+    }
+
+    debug_assert_eq!(carry, 0);
+}
+
+/// Multiply the big-endian base 256 number in `bytes` by 58 and add
+/// `digit`.
+///
+/// The number must be large enough that this doesn't overflow. Performs the
+/// same operations regardless of the values involved.
+#[allow(clippy::integer_arithmetic)]
+fn push_digit(bytes: &mut [u8], digit: u8) {
+    let mut carry = digit as u32;
+
+    for byte in bytes.iter_mut().rev() {
+        carry += *byte as u32 * 58;
+        *byte = carry as u8;
+        carry >>= 8;
+    }
+}
+
+/// Count the leading zero values in the given sequence.
+#[allow(clippy::integer_arithmetic)]
+fn count_leading(values: impl Iterator<Item = u8>) -> usize {
+    let mut count = 0;
+    let mut leading = 1;
+
+    for value in values {
+        // 1 if `value` is 0, else 0
+        leading &= (value as usize).wrapping_sub(1) >> 8 & 1;
+        count += leading;
+    }
+
+    count
+}
+
+/// Get the length of the big-endian number in `num` without leading zeros.
+fn significant_len(num: &[u8]) -> usize {
+    num.len().saturating_sub(count_leading(num.iter().copied()))
+}
+
+/// Shift the contents of `buf` left by `n` positions, filling the end with
+/// zeros, without any memory accesses which depend on `n`.
+#[allow(clippy::integer_arithmetic)]
+fn shift_left(buf: &mut [u8], n: usize) {
+    let mut shift = 1;
+    let mut bit = 0;
+
+    while shift < buf.len() {
+        let mask = ((n >> bit) & 1) as u8 * 0xff;
+
+        for i in 0..buf.len() {
+            let shifted = buf.get(i + shift).copied().unwrap_or(0);
+            buf[i] = (buf[i] & !mask) | (shifted & mask);
+        }
+
+        shift <<= 1;
+        bit += 1;
+    }
+}
@@ -0,0 +1,71 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/base58ct/0.1.0"
+)]
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::integer_arithmetic,
+    clippy::panic,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unsafe_code,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! ```
+//! use base58ct::{Base58, Encoding};
+//!
+//! let bytes = b"example bytestring!";
+//!
+//! let mut enc_buf = [0u8; 32];
+//! assert!(Base58::max_encoded_len(bytes.len()) <= enc_buf.len());
+//! let encoded = Base58::encode(bytes, &mut enc_buf).unwrap();
+//! assert_eq!(encoded, "KaRXeUPHPJdfiW35ib46HfPTyz");
+//!
+//! let mut dec_buf = [0u8; 32];
+//! let decoded = Base58::decode(encoded, &mut dec_buf).unwrap();
+//! assert_eq!(decoded, bytes);
+//! ```
+//!
+//! # Implementation
+//!
+//! Conversion to and from Base58 is implemented as quadratic-time
+//! arithmetic over a buffer sized according to the length of the input,
+//! with every step performed regardless of the values involved. Characters
+//! are encoded and decoded using integer arithmetic alone, in the same way
+//! as the `base64ct` crate, and the result is moved into place using
+//! a sequence of conditional shifts rather than data-dependent indexing.
+//!
+//! Not constant-time with respect to the length of the input or the output,
+//! the latter of which depends on the data (e.g. its leading zero bytes).
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod alphabet;
+mod encoding;
+mod errors;
+
+#[cfg(feature = "check")]
+mod check;
+
+pub use crate::{
+    alphabet::{Base58, Base58Flickr},
+    encoding::Encoding,
+    errors::Error,
+};
+
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub use crate::check::{Base58Check, Check};
@@ -0,0 +1,145 @@
+//! Bitcoin Base58 tests
+
+use base58ct::{Base58, Base58Flickr, Encoding, Error};
+use hex_literal::hex;
+
+/// Base58 test vector
+struct TestVector {
+    raw: &'static [u8],
+    b58: &'static str,
+}
+
+/// Test vectors from Bitcoin Core's `base58_encode_decode.json`.
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector { raw: b"", b58: "" },
+    TestVector {
+        raw: &hex!("61"),
+        b58: "2g",
+    },
+    TestVector {
+        raw: &hex!("626262"),
+        b58: "a3gV",
+    },
+    TestVector {
+        raw: &hex!("636363"),
+        b58: "aPEr",
+    },
+    TestVector {
+        raw: &hex!("73696d706c792061206c6f6e6720737472696e67"),
+        b58: "2cFupjhnEsSn59qHXstmK2ffpLv2",
+    },
+    TestVector {
+        raw: &hex!("00eb15231dfceb60925886b67d065299925915aeb172c06647"),
+        b58: "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+    },
+    TestVector {
+        raw: &hex!("516b6fcd0f"),
+        b58: "ABnLTmg",
+    },
+    TestVector {
+        raw: &hex!("bf4f89001e670274dd"),
+        b58: "3SEo3LWLoPntC",
+    },
+    TestVector {
+        raw: &hex!("572e4794"),
+        b58: "3EFU7m",
+    },
+    TestVector {
+        raw: &hex!("ecac89cad93923c02321"),
+        b58: "EJDM8drfXA6uyA",
+    },
+    TestVector {
+        raw: &hex!("10c8511e"),
+        b58: "Rt5zm",
+    },
+    TestVector {
+        raw: &hex!("00000000000000000000"),
+        b58: "1111111111",
+    },
+    TestVector {
+        raw: &hex!(
+            "000111d38e5fc9071ffcd20b4a763cc9ae4f252bb4e48fd66a835e252ada93ff"
+            "480d6dd43dc62a641155a5"
+        ),
+        b58: "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    },
+];
+
+#[test]
+fn encode_test_vectors() {
+    let mut buf = [0u8; 1024];
+
+    for vector in TEST_VECTORS {
+        let out = Base58::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(vector.b58, out);
+        assert!(vector.b58.len() <= Base58::max_encoded_len(vector.raw.len()));
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(vector.b58, Base58::encode_string(vector.raw));
+    }
+}
+
+#[test]
+fn decode_test_vectors() {
+    let mut buf = [0u8; 1024];
+
+    for vector in TEST_VECTORS {
+        let out = Base58::decode(vector.b58, &mut buf).unwrap();
+        assert_eq!(vector.raw, out);
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(vector.raw, Base58::decode_vec(vector.b58).unwrap());
+    }
+}
+
+#[test]
+fn encode_and_decode_various_lengths() {
+    let mut data = [0u8; 64];
+    let mut encoded_buf = [0u8; 128];
+    let mut decoded_buf = [0u8; 128];
+
+    for (i, byte) in data.iter_mut().enumerate().skip(3) {
+        *byte = (i as u8).wrapping_mul(151);
+    }
+
+    for i in 0..data.len() {
+        let encoded = Base58::encode(&data[..i], &mut encoded_buf).unwrap();
+        let decoded = Base58::decode(encoded, &mut decoded_buf).unwrap();
+        assert_eq!(decoded, &data[..i]);
+
+        let encoded = Base58Flickr::encode(&data[..i], &mut encoded_buf).unwrap();
+        let decoded = Base58Flickr::decode(encoded, &mut decoded_buf).unwrap();
+        assert_eq!(decoded, &data[..i]);
+    }
+}
+
+#[test]
+fn flickr() {
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        Base58Flickr::encode(&hex!("516b6fcd0f"), &mut buf).unwrap(),
+        "abMksLF"
+    );
+}
+
+#[test]
+fn reject_invalid_characters() {
+    let mut buf = [0u8; 16];
+
+    for invalid in ["0", "O", "I", "l", "3EF 7m", "3EFU7m\n", "+"] {
+        assert_eq!(
+            Base58::decode(invalid, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
+
+#[test]
+fn reject_short_buffer() {
+    let mut buf = [0u8; 4];
+    assert_eq!(Base58::encode(b"abcd", &mut buf), Err(Error::InvalidLength));
+    assert_eq!(
+        Base58::decode("2cFupjhnEsSn59qHXstmK2ffpLv2", &mut buf),
+        Err(Error::InvalidLength)
+    );
+}
@@ -0,0 +1,47 @@
+//! Base58Check tests
+
+#![cfg(feature = "check")]
+
+use base58ct::{Base58Check, Error};
+use hex_literal::hex;
+
+/// Uncompressed WIF private key example.
+const WIF_KEY: &[u8] = &hex!("800c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d");
+const WIF_B58: &str = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+
+#[test]
+fn encode() {
+    let mut buf = [0u8; 64];
+    assert_eq!(Base58Check::encode(WIF_KEY, &mut buf).unwrap(), WIF_B58);
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(Base58Check::encode_string(WIF_KEY), WIF_B58);
+}
+
+#[test]
+fn decode() {
+    let mut buf = [0u8; 64];
+    assert_eq!(Base58Check::decode(WIF_B58, &mut buf).unwrap(), WIF_KEY);
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(Base58Check::decode_vec(WIF_B58).unwrap(), WIF_KEY);
+}
+
+#[test]
+fn reject_invalid_checksum() {
+    let mut buf = [0u8; 64];
+    let corrupted = WIF_B58.replace("TLvyTJ", "TLvyTK");
+    assert_eq!(
+        Base58Check::decode(&corrupted, &mut buf),
+        Err(Error::InvalidChecksum)
+    );
+}
+
+#[test]
+fn reject_missing_checksum() {
+    let mut buf = [0u8; 64];
+    assert_eq!(
+        Base58Check::decode("2g", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
@@ -57,8 +57,8 @@ impl<'o, E: Encoding> Encoder<'o, E> {
     /// slice.
     ///
     /// Output will be wrapped at the specified interval, using the provided
-    /// line ending. Use [`LineEnding::default()`] to use the conventional line
-    /// ending for the target OS.
+    /// line ending. Use [`LineEnding::default()`] for the conventional LF line
+    /// ending.
     ///
     /// Minimum allowed line width is 4.
     pub fn new_wrapped(
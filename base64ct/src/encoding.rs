@@ -9,11 +9,26 @@ use core::str;
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "simd")]
+use crate::simd;
+
 #[cfg(doc)]
 use crate::{Base64, Base64Bcrypt, Base64Crypt, Base64Unpadded, Base64Url, Base64UrlUnpadded};
 
+/// Number of characters of Base64 decoded by each task of
+/// [`Encoding::decode_par`].
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 16 * 1024;
+
+/// Number of bytes decoded by each task of [`Encoding::decode_par`].
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_DECODED_LEN: usize = PAR_CHUNK_LEN / 4 * 3;
+
 /// Padding character
-const PAD: u8 = b'=';
+pub(crate) const PAD: u8 = b'=';
 
 /// Base64 encoding trait.
 ///
@@ -32,6 +47,17 @@ pub trait Encoding: Variant {
     /// Decode a Base64 string into the provided destination buffer.
     fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
 
+    /// Decode a Base64 string into the provided destination buffer, using
+    /// multiple threads for large inputs.
+    ///
+    /// The input is split into chunks of complete Base64 blocks which are
+    /// decoded in parallel using [`rayon`]. The result (including any error)
+    /// is the same as that of [`Encoding::decode`], and like it, all of the
+    /// input is decoded before any invalid characters are reported.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn decode_par(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
     /// Decode a Base64 string in-place.
     ///
     /// NOTE: this method does not (yet) validate that padding is well-formed,
@@ -80,8 +106,19 @@ impl<T: Variant> Encoding for T {
 
         let dst = &mut dst[..dlen];
 
-        let mut src_chunks = src_unpadded.chunks_exact(4);
-        let mut dst_chunks = dst.chunks_exact_mut(3);
+        #[cfg(feature = "simd")]
+        let (src_pos, dst_pos) = if T::STANDARD_ALPHABET {
+            let (src_pos, dst_pos, e) = simd::decode(src_unpadded, dst);
+            err |= e;
+            (src_pos, dst_pos)
+        } else {
+            (0, 0)
+        };
+        #[cfg(not(feature = "simd"))]
+        let (src_pos, dst_pos) = (0, 0);
+
+        let mut src_chunks = src_unpadded[src_pos..].chunks_exact(4);
+        let mut dst_chunks = dst[dst_pos..].chunks_exact_mut(3);
         for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
             err |= Self::decode_3bytes(s, d);
         }
@@ -103,6 +140,49 @@ impl<T: Variant> Encoding for T {
         }
     }
 
+    #[cfg(feature = "rayon")]
+    fn decode_par(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+
+        if src.len() <= PAR_CHUNK_LEN {
+            return Self::decode(src, dst);
+        }
+
+        // Split off the last block, which may be partial or padded, and
+        // decode the complete blocks preceding it in parallel
+        let body_len = src.len().saturating_sub(1) & !3;
+        let (body, tail) = src.split_at(body_len);
+        let body_decoded_len = decoded_len(body_len);
+
+        if dst.len() < body_decoded_len {
+            return Err(Error::InvalidLength);
+        }
+
+        let (dst_body, dst_tail) = dst.split_at_mut(body_decoded_len);
+
+        // Padding is only valid in the last block, so use the unpadded
+        // variant for the rest. Errors are combined without short-circuiting
+        // so that all of the input is processed regardless.
+        let body_err = body
+            .par_chunks(PAR_CHUNK_LEN)
+            .zip(dst_body.par_chunks_mut(PAR_CHUNK_DECODED_LEN))
+            .map(|(src, dst)| T::Unpadded::decode(src, dst).is_err())
+            .reduce(|| false, |a, b| a | b);
+
+        let tail_len = Self::decode(tail, dst_tail).map(<[u8]>::len);
+
+        match (body_err, tail_len) {
+            (false, Ok(tail_len)) => {
+                let len = body_decoded_len
+                    .checked_add(tail_len)
+                    .ok_or(Error::InvalidLength)?;
+                Ok(&dst[..len])
+            }
+            (true, _) => Err(Error::InvalidEncoding),
+            (false, Err(err)) => Err(err),
+        }
+    }
+
     // TODO(tarcieri): explicitly checked/wrapped arithmetic
     #[allow(clippy::integer_arithmetic)]
     fn decode_in_place(mut buf: &mut [u8]) -> Result<&[u8], InvalidEncodingError> {
@@ -196,8 +276,17 @@ impl<T: Variant> Encoding for T {
 
         let dst = &mut dst[..elen];
 
-        let mut src_chunks = src.chunks_exact(3);
-        let mut dst_chunks = dst.chunks_exact_mut(4);
+        #[cfg(feature = "simd")]
+        let (src_pos, dst_pos) = if T::STANDARD_ALPHABET {
+            simd::encode(src, dst)
+        } else {
+            (0, 0)
+        };
+        #[cfg(not(feature = "simd"))]
+        let (src_pos, dst_pos) = (0, 0);
+
+        let mut src_chunks = src[src_pos..].chunks_exact(3);
+        let mut dst_chunks = dst[dst_pos..].chunks_exact_mut(4);
 
         for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
             Self::encode_3bytes(s, d);
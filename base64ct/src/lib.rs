@@ -81,7 +81,12 @@ mod encoder;
 mod encoding;
 mod errors;
 mod line_ending;
+mod stream;
 mod variant;
+mod wrapped;
+
+#[cfg(feature = "simd")]
+mod simd;
 
 #[cfg(test)]
 mod test_vectors;
@@ -92,13 +97,20 @@ pub use crate::{
     encoding::Encoding,
     errors::{Error, InvalidEncodingError, InvalidLengthError},
     line_ending::LineEnding,
+    stream::{StreamDecoder, StreamEncoder},
     variant::{
         bcrypt::Base64Bcrypt,
         crypt::Base64Crypt,
+        custom::{Alphabet, Base64Custom},
         standard::{Base64, Base64Unpadded},
         url::{Base64Url, Base64UrlUnpadded},
     },
+    wrapped::{Base64Mime, Base64Pem, Wrapped, MIME_LINE_WIDTH, PEM_LINE_WIDTH},
 };
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::stream::{DecoderReader, EncoderWriter};
+
 /// Minimum supported line width.
 const MIN_LINE_WIDTH: usize = 4;
@@ -8,8 +8,9 @@ pub(crate) const CHAR_LF: u8 = 0x0a;
 
 /// Line endings: variants of newline characters that can be used with Base64.
 ///
-/// Use [`LineEnding::default`] to get an appropriate line ending for the
-/// current operating system.
+/// [`LineEnding::default`] is [`LineEnding::LF`] on all platforms, as is
+/// conventional for Base64 and PEM. Use [`LineEnding::CRLF`] explicitly for
+/// consumers which require it, e.g. some Windows tools.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum LineEnding {
@@ -24,12 +25,6 @@ pub enum LineEnding {
 }
 
 impl Default for LineEnding {
-    // Default line ending matches conventions for target OS
-    #[cfg(windows)]
-    fn default() -> LineEnding {
-        LineEnding::CRLF
-    }
-    #[cfg(not(windows))]
     fn default() -> LineEnding {
         LineEnding::LF
     }
@@ -38,7 +33,7 @@ impl Default for LineEnding {
 #[allow(clippy::len_without_is_empty)]
 impl LineEnding {
     /// Get the byte serialization of this [`LineEnding`].
-    pub fn as_bytes(self) -> &'static [u8] {
+    pub const fn as_bytes(self) -> &'static [u8] {
         match self {
             LineEnding::CR => &[CHAR_CR],
             LineEnding::LF => &[CHAR_LF],
@@ -47,7 +42,7 @@ impl LineEnding {
     }
 
     /// Get the encoded length of this [`LineEnding`].
-    pub fn len(self) -> usize {
+    pub const fn len(self) -> usize {
         self.as_bytes().len()
     }
 }
@@ -0,0 +1,65 @@
+//! SIMD-accelerated backends for the standard Base64 alphabet.
+//!
+//! These are used by the [`Base64`][`crate::Base64`] and
+//! [`Base64Unpadded`][`crate::Base64Unpadded`] variants when the `simd`
+//! crate feature is enabled and the CPU supports them: AVX2 is detected at
+//! runtime on x86/x86_64, whereas NEON is always available on AArch64. They
+//! process the bulk of the input in large blocks, leaving the remainder
+//! (including any padding) to the scalar implementation, which is also used
+//! on CPUs without support for any backend.
+//!
+//! The AVX2 backend is based on the vectorized algorithm described by Muła
+//! and Lemire in "Faster Base64 Encoding and Decoding Using AVX2
+//! Instructions". Like the scalar implementation, both backends avoid
+//! data-dependent branches and table lookups in memory: all lookups are
+//! performed using byte shuffles or table instructions within registers.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+cpufeatures::new!(avx2_cpuid, "avx2");
+
+/// Encode as many complete blocks of the input as possible into `dst`.
+///
+/// Returns the number of bytes of input consumed (always a multiple of 3)
+/// and the number of bytes of output written.
+#[allow(unreachable_code, unused_variables)]
+pub(crate) fn encode(src: &[u8], dst: &mut [u8]) -> (usize, usize) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if avx2_cpuid::get() {
+        // SAFETY: support for AVX2 was checked above
+        #[allow(unsafe_code)]
+        return unsafe { avx2::encode(src, dst) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    return neon::encode(src, dst);
+
+    (0, 0)
+}
+
+/// Decode as many complete blocks of the (unpadded) input as possible into
+/// `dst`.
+///
+/// Returns the number of bytes of input consumed (always a multiple of 4),
+/// the number of bytes of output written, and a nonzero error flag if the
+/// input contained invalid characters. Like the scalar implementation, the
+/// error flag is only checked once all of the input has been processed.
+#[allow(unreachable_code, unused_variables)]
+pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> (usize, usize, i16) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if avx2_cpuid::get() {
+        // SAFETY: support for AVX2 was checked above
+        #[allow(unsafe_code)]
+        return unsafe { avx2::decode(src, dst) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    return neon::decode(src, dst);
+
+    (0, 0, 0)
+}
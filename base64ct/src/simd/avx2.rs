@@ -0,0 +1,273 @@
+//! AVX2 backend.
+//!
+//! Encodes 24 bytes of input to 32 characters of Base64 at a time, and
+//! decodes 32 characters to 24 bytes at a time.
+
+// Offsets are always checked against the lengths of the slices they index
+#![allow(clippy::integer_arithmetic, unsafe_code)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Number of bytes of input encoded at a time.
+const ENCODE_INPUT_LEN: usize = 24;
+
+/// Number of bytes loaded when encoding: each 128-bit lane is loaded from
+/// a 16-byte window, of which only the first 12 bytes are used.
+const ENCODE_LOAD_LEN: usize = 28;
+
+/// Number of characters of Base64 decoded at a time.
+const DECODE_INPUT_LEN: usize = 32;
+
+/// Number of bytes of output produced from each block of decoded input.
+const DECODE_OUTPUT_LEN: usize = 24;
+
+/// Encode as many complete blocks of the input as possible into `dst`.
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn encode(src: &[u8], dst: &mut [u8]) -> (usize, usize) {
+    let mut src_pos = 0;
+    let mut dst_pos = 0;
+
+    while src.len() - src_pos >= ENCODE_LOAD_LEN && dst.len() - dst_pos >= 32 {
+        let input = src.as_ptr().add(src_pos);
+        let lo = _mm_loadu_si128(input as *const __m128i);
+        let hi = _mm_loadu_si128(input.add(12) as *const __m128i);
+        let output = encode_translate(encode_reshuffle(_mm256_set_m128i(hi, lo)));
+        _mm256_storeu_si256(dst.as_mut_ptr().add(dst_pos) as *mut __m256i, output);
+
+        src_pos += ENCODE_INPUT_LEN;
+        dst_pos += 32;
+    }
+
+    (src_pos, dst_pos)
+}
+
+/// Decode as many complete blocks of the input as possible into `dst`.
+///
+/// # Safety
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn decode(src: &[u8], dst: &mut [u8]) -> (usize, usize, i16) {
+    let mut src_pos = 0;
+    let mut dst_pos = 0;
+    let mut err = _mm256_setzero_si256();
+
+    while src.len() - src_pos >= DECODE_INPUT_LEN && dst.len() - dst_pos >= DECODE_OUTPUT_LEN {
+        let input = _mm256_loadu_si256(src.as_ptr().add(src_pos) as *const __m256i);
+        let (values, invalid) = decode_translate(input);
+        err = _mm256_or_si256(err, invalid);
+
+        // Store via a temporary buffer as only 24 of the 32 bytes are used
+        let mut output = [0u8; 32];
+        _mm256_storeu_si256(output.as_mut_ptr() as *mut __m256i, decode_pack(values));
+        dst[dst_pos..][..DECODE_OUTPUT_LEN].copy_from_slice(&output[..DECODE_OUTPUT_LEN]);
+
+        src_pos += DECODE_INPUT_LEN;
+        dst_pos += DECODE_OUTPUT_LEN;
+    }
+
+    (src_pos, dst_pos, (_mm256_testz_si256(err, err) == 0) as i16)
+}
+
+/// Split each group of 3 bytes in the first 12 bytes of each 128-bit lane
+/// into four 6-bit values, each stored in its own byte.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn encode_reshuffle(input: __m256i) -> __m256i {
+    // Arrange the bytes of each group as [b1, b0, b2, b1]
+    let input = _mm256_shuffle_epi8(
+        input,
+        _mm256_setr_epi8(
+            1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10, //
+            1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10,
+        ),
+    );
+
+    // Shift the 1st and 3rd 6-bit values of each group into place
+    let t0 = _mm256_and_si256(input, _mm256_set1_epi32(0x0fc0_fc00));
+    let t1 = _mm256_mulhi_epu16(t0, _mm256_set1_epi32(0x0400_0040));
+
+    // Shift the 2nd and 4th 6-bit values of each group into place
+    let t2 = _mm256_and_si256(input, _mm256_set1_epi32(0x003f_03f0));
+    let t3 = _mm256_mullo_epi16(t2, _mm256_set1_epi32(0x0100_0010));
+
+    _mm256_or_si256(t1, t3)
+}
+
+/// Translate 6-bit values into characters of the standard Base64 alphabet.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn encode_translate(values: __m256i) -> __m256i {
+    // Offsets from each range of 6-bit values to its characters
+    let offsets = _mm256_setr_epi8(
+        71, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -19, -16, 65, 0, 0, //
+        71, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -19, -16, 65, 0, 0,
+    );
+
+    // Compute the index of each value's offset: 0 for `a-z`, 1-10 for `0-9`,
+    // 11 for `+`, 12 for `/`, and 13 for `A-Z`
+    let index = _mm256_subs_epu8(values, _mm256_set1_epi8(51));
+    let upper = _mm256_cmpgt_epi8(_mm256_set1_epi8(26), values);
+    let index = _mm256_or_si256(index, _mm256_and_si256(upper, _mm256_set1_epi8(13)));
+
+    _mm256_add_epi8(values, _mm256_shuffle_epi8(offsets, index))
+}
+
+/// Translate characters of the standard Base64 alphabet into 6-bit values.
+///
+/// Returns the values along with a mask which is nonzero for any invalid
+/// characters.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn decode_translate(input: __m256i) -> (__m256i, __m256i) {
+    // Bitmasks of the valid high nibbles for each low nibble, and vice versa
+    let lut_lo = _mm256_setr_epi8(
+        0x15, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x13, 0x1a, 0x1b, 0x1b, 0x1b,
+        0x1a, //
+        0x15, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x13, 0x1a, 0x1b, 0x1b, 0x1b,
+        0x1a,
+    );
+    let lut_hi = _mm256_setr_epi8(
+        0x10, 0x10, 0x01, 0x02, 0x04, 0x08, 0x04, 0x08, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+        0x10, //
+        0x10, 0x10, 0x01, 0x02, 0x04, 0x08, 0x04, 0x08, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10,
+        0x10,
+    );
+
+    // Offsets from the characters with each high nibble to their values
+    // (with `/` using index 1)
+    let lut_roll = _mm256_setr_epi8(
+        0, 16, 19, 4, -65, -65, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, //
+        0, 16, 19, 4, -65, -65, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0,
+    );
+
+    let nibble_mask = _mm256_set1_epi8(0x0f);
+    let hi_nibbles = _mm256_and_si256(_mm256_srli_epi32(input, 4), nibble_mask);
+    let lo_nibbles = _mm256_and_si256(input, nibble_mask);
+
+    let invalid = _mm256_and_si256(
+        _mm256_shuffle_epi8(lut_lo, lo_nibbles),
+        _mm256_shuffle_epi8(lut_hi, hi_nibbles),
+    );
+
+    let slash = _mm256_cmpeq_epi8(input, _mm256_set1_epi8(b'/' as i8));
+    let roll = _mm256_shuffle_epi8(lut_roll, _mm256_add_epi8(slash, hi_nibbles));
+
+    (_mm256_add_epi8(input, roll), invalid)
+}
+
+/// Pack each group of four 6-bit values into 3 bytes, returning the 24
+/// decoded bytes at the start of the output.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn decode_pack(values: __m256i) -> __m256i {
+    // Merge pairs of 6-bit values into 12-bit values, then pairs of those
+    // into 24-bit values
+    let merged = _mm256_maddubs_epi16(values, _mm256_set1_epi32(0x0140_0140));
+    let merged = _mm256_madd_epi16(merged, _mm256_set1_epi32(0x0001_1000));
+
+    // Convert each 24-bit value to big endian, packing them at the start of
+    // each 128-bit lane, and then pack the lanes together
+    let packed = _mm256_shuffle_epi8(
+        merged,
+        _mm256_setr_epi8(
+            2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1, //
+            2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1,
+        ),
+    );
+
+    _mm256_permutevar8x32_epi32(packed, _mm256_setr_epi32(0, 1, 2, 4, 5, 6, 7, 7))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{variant::Variant, Base64};
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Deterministic pseudorandom test data.
+    fn test_data(len: usize) -> [u8; 256] {
+        let mut data = [0u8; 256];
+        let mut state = 0x2545_f491_u32;
+
+        for byte in data.iter_mut().take(len) {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *byte = state as u8;
+        }
+
+        data
+    }
+
+    #[test]
+    fn encode_matches_scalar() {
+        if !super::super::avx2_cpuid::get() {
+            return;
+        }
+
+        for len in 0..=192 {
+            let data = test_data(len);
+            let mut out = [0u8; 512];
+            let (src_len, dst_len) = unsafe { super::encode(&data[..len], &mut out) };
+            assert_eq!(src_len % 24, 0);
+            assert_eq!(dst_len, src_len / 3 * 4);
+            assert!(len - src_len < 28);
+
+            let mut expected = [0u8; 4];
+            for (block, encoded) in data[..src_len].chunks(3).zip(out.chunks(4)) {
+                Base64::encode_3bytes(block, &mut expected);
+                assert_eq!(encoded, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_matches_scalar() {
+        if !super::super::avx2_cpuid::get() {
+            return;
+        }
+
+        for len in (0..=256).step_by(4) {
+            // Use every character of the alphabet
+            let mut src = [0u8; 256];
+            for (i, char) in src.iter_mut().enumerate().take(len) {
+                *char = ALPHABET[i * 7 % 64];
+            }
+
+            let mut out = [0u8; 256];
+            let (src_len, dst_len, err) = unsafe { super::decode(&src[..len], &mut out) };
+            assert_eq!(err, 0);
+            assert_eq!(src_len, len / 32 * 32);
+            assert_eq!(dst_len, src_len / 4 * 3);
+
+            let mut expected = [0u8; 3];
+            for (block, decoded) in src[..src_len].chunks(4).zip(out.chunks(3)) {
+                assert_eq!(Base64::decode_3bytes(block, &mut expected), 0);
+                assert_eq!(decoded, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_invalid() {
+        if !super::super::avx2_cpuid::get() {
+            return;
+        }
+
+        for invalid in [b'=', b'-', b'_', b'.', b'\n', b' ', 0x00, 0x80, 0xff] {
+            for pos in [0, 17, 31] {
+                let mut src = [b'A'; 32];
+                src[pos] = invalid;
+                let mut out = [0u8; 24];
+                let (_, _, err) = unsafe { super::decode(&src, &mut out) };
+                assert_ne!(err, 0, "{:#x} at {}", invalid, pos);
+            }
+        }
+    }
+}
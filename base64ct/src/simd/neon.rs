@@ -0,0 +1,235 @@
+//! NEON backend.
+//!
+//! Encodes 48 bytes of input to 64 characters of Base64 at a time, and
+//! decodes 64 characters to 48 bytes at a time.
+//!
+//! NEON is a mandatory part of AArch64, so unlike the AVX2 backend no runtime
+//! detection is needed. Lookups are performed using `TBL` instructions on
+//! tables held in registers, which take constant time.
+
+// Offsets are always checked against the lengths of the slices they index
+#![allow(clippy::integer_arithmetic, unsafe_code)]
+
+use core::arch::aarch64::*;
+
+/// Number of bytes of input encoded at a time.
+const ENCODE_INPUT_LEN: usize = 48;
+
+/// Number of characters of Base64 produced from each block of encoded input.
+const ENCODE_OUTPUT_LEN: usize = 64;
+
+/// Number of characters of Base64 decoded at a time.
+const DECODE_INPUT_LEN: usize = 64;
+
+/// Number of bytes of output produced from each block of decoded input.
+const DECODE_OUTPUT_LEN: usize = 48;
+
+/// Characters of the standard Base64 alphabet, indexed by 6-bit value.
+const ENCODE_TABLE: [u8; 64] = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// 6-bit values of the ASCII characters, split into two tables of 64
+/// characters each, with `0xff` for characters outside the alphabet.
+const DECODE_TABLE: [[u8; 64]; 2] = decode_table();
+
+/// Encode as many complete blocks of the input as possible into `dst`.
+pub(super) fn encode(src: &[u8], dst: &mut [u8]) -> (usize, usize) {
+    let mut src_pos = 0;
+    let mut dst_pos = 0;
+
+    // SAFETY: NEON is available on all AArch64 targets, and blocks are only
+    // loaded and stored within the bounds of `src` and `dst`
+    unsafe {
+        let table = load_table(&ENCODE_TABLE);
+        let mask = vdupq_n_u8(0x3f);
+
+        while src.len() - src_pos >= ENCODE_INPUT_LEN && dst.len() - dst_pos >= ENCODE_OUTPUT_LEN {
+            // Deinterleave the 1st, 2nd, and 3rd bytes of each group
+            let input = vld3q_u8(src.as_ptr().add(src_pos));
+
+            // Split each group into four 6-bit values
+            let v0 = vshrq_n_u8::<2>(input.0);
+            let v1 = vorrq_u8(vshlq_n_u8::<4>(input.0), vshrq_n_u8::<4>(input.1));
+            let v2 = vorrq_u8(vshlq_n_u8::<2>(input.1), vshrq_n_u8::<6>(input.2));
+            let v3 = input.2;
+
+            // Translate the values to characters, interleaving them on store
+            let output = uint8x16x4_t(
+                vqtbl4q_u8(table, v0),
+                vqtbl4q_u8(table, vandq_u8(v1, mask)),
+                vqtbl4q_u8(table, vandq_u8(v2, mask)),
+                vqtbl4q_u8(table, vandq_u8(v3, mask)),
+            );
+            vst4q_u8(dst.as_mut_ptr().add(dst_pos), output);
+
+            src_pos += ENCODE_INPUT_LEN;
+            dst_pos += ENCODE_OUTPUT_LEN;
+        }
+    }
+
+    (src_pos, dst_pos)
+}
+
+/// Decode as many complete blocks of the input as possible into `dst`.
+pub(super) fn decode(src: &[u8], dst: &mut [u8]) -> (usize, usize, i16) {
+    let mut src_pos = 0;
+    let mut dst_pos = 0;
+
+    // SAFETY: NEON is available on all AArch64 targets, and blocks are only
+    // loaded and stored within the bounds of `src` and `dst`
+    unsafe {
+        let lut_lo = load_table(&DECODE_TABLE[0]);
+        let lut_hi = load_table(&DECODE_TABLE[1]);
+        let mut err = vdupq_n_u8(0);
+
+        while src.len() - src_pos >= DECODE_INPUT_LEN && dst.len() - dst_pos >= DECODE_OUTPUT_LEN {
+            // Deinterleave the 1st, 2nd, 3rd, and 4th characters of each group
+            let input = vld4q_u8(src.as_ptr().add(src_pos));
+            let v0 = decode_translate(lut_lo, lut_hi, input.0, &mut err);
+            let v1 = decode_translate(lut_lo, lut_hi, input.1, &mut err);
+            let v2 = decode_translate(lut_lo, lut_hi, input.2, &mut err);
+            let v3 = decode_translate(lut_lo, lut_hi, input.3, &mut err);
+
+            // Pack each group of four 6-bit values into 3 bytes, interleaving
+            // them on store
+            let output = uint8x16x3_t(
+                vorrq_u8(vshlq_n_u8::<2>(v0), vshrq_n_u8::<4>(v1)),
+                vorrq_u8(vshlq_n_u8::<4>(v1), vshrq_n_u8::<2>(v2)),
+                vorrq_u8(vshlq_n_u8::<6>(v2), v3),
+            );
+            vst3q_u8(dst.as_mut_ptr().add(dst_pos), output);
+
+            src_pos += DECODE_INPUT_LEN;
+            dst_pos += DECODE_OUTPUT_LEN;
+        }
+
+        (src_pos, dst_pos, (vmaxvq_u8(err) != 0) as i16)
+    }
+}
+
+/// Translate characters of the standard Base64 alphabet into 6-bit values.
+///
+/// Sets bits of `err` for any invalid characters.
+#[inline(always)]
+unsafe fn decode_translate(
+    lut_lo: uint8x16x4_t,
+    lut_hi: uint8x16x4_t,
+    input: uint8x16_t,
+    err: &mut uint8x16_t,
+) -> uint8x16_t {
+    // Look up characters `0x00-0x3f` in `lut_lo` and `0x40-0x7f` in `lut_hi`:
+    // indices outside of a table yield 0
+    let values = vorrq_u8(
+        vqtbl4q_u8(lut_lo, input),
+        vqtbl4q_u8(lut_hi, vsubq_u8(input, vdupq_n_u8(0x40))),
+    );
+
+    // Characters outside the alphabet yield `0xff`, except for non-ASCII
+    // characters which are outside both tables
+    let invalid = vorrq_u8(
+        vcgtq_u8(values, vdupq_n_u8(0x3f)),
+        vcgtq_u8(input, vdupq_n_u8(0x7f)),
+    );
+    *err = vorrq_u8(*err, invalid);
+
+    values
+}
+
+/// Load a table of 64 bytes into registers for use with `vqtbl4q_u8`.
+#[inline(always)]
+unsafe fn load_table(table: &[u8; 64]) -> uint8x16x4_t {
+    uint8x16x4_t(
+        vld1q_u8(table.as_ptr()),
+        vld1q_u8(table.as_ptr().add(16)),
+        vld1q_u8(table.as_ptr().add(32)),
+        vld1q_u8(table.as_ptr().add(48)),
+    )
+}
+
+/// Build [`DECODE_TABLE`] from [`ENCODE_TABLE`].
+const fn decode_table() -> [[u8; 64]; 2] {
+    let mut table = [[0xff; 64]; 2];
+    let mut i = 0;
+
+    while i < 64 {
+        let char = ENCODE_TABLE[i] as usize;
+        table[char / 64][char % 64] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{variant::Variant, Base64};
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// Deterministic pseudorandom test data.
+    fn test_data(len: usize) -> [u8; 256] {
+        let mut data = [0u8; 256];
+        let mut state = 0x2545_f491_u32;
+
+        for byte in data.iter_mut().take(len) {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *byte = state as u8;
+        }
+
+        data
+    }
+
+    #[test]
+    fn encode_matches_scalar() {
+        for len in 0..=192 {
+            let data = test_data(len);
+            let mut out = [0u8; 512];
+            let (src_len, dst_len) = super::encode(&data[..len], &mut out);
+            assert_eq!(src_len, len / 48 * 48);
+            assert_eq!(dst_len, src_len / 3 * 4);
+
+            let mut expected = [0u8; 4];
+            for (block, encoded) in data[..src_len].chunks(3).zip(out.chunks(4)) {
+                Base64::encode_3bytes(block, &mut expected);
+                assert_eq!(encoded, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_matches_scalar() {
+        for len in (0..=256).step_by(4) {
+            // Use every character of the alphabet
+            let mut src = [0u8; 256];
+            for (i, char) in src.iter_mut().enumerate().take(len) {
+                *char = ALPHABET[i * 7 % 64];
+            }
+
+            let mut out = [0u8; 256];
+            let (src_len, dst_len, err) = super::decode(&src[..len], &mut out);
+            assert_eq!(err, 0);
+            assert_eq!(src_len, len / 64 * 64);
+            assert_eq!(dst_len, src_len / 4 * 3);
+
+            let mut expected = [0u8; 3];
+            for (block, decoded) in src[..src_len].chunks(4).zip(out.chunks(3)) {
+                assert_eq!(Base64::decode_3bytes(block, &mut expected), 0);
+                assert_eq!(decoded, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_invalid() {
+        for invalid in [b'=', b'-', b'_', b'.', b'\n', b' ', 0x00, 0x80, 0xc1, 0xff] {
+            for pos in [0, 17, 63] {
+                let mut src = [b'A'; 64];
+                src[pos] = invalid;
+                let mut out = [0u8; 48];
+                let (_, _, err) = super::decode(&src, &mut out);
+                assert_ne!(err, 0, "{:#x} at {}", invalid, pos);
+            }
+        }
+    }
+}
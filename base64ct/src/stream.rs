@@ -0,0 +1,512 @@
+//! Streaming Base64 encoder and decoder state machines.
+//!
+//! Unlike [`Encoder`][`crate::Encoder`] and [`Decoder`][`crate::Decoder`],
+//! which operate on a complete output or input buffer respectively, these
+//! types accept input in arbitrarily sized chunks and use a constant amount
+//! of memory, buffering only a partial Base64 block between calls.
+
+use crate::{
+    Encoding,
+    Error::{self, InvalidEncoding, InvalidLength},
+};
+use core::{marker::PhantomData, str};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Streaming Base64 encoder.
+///
+/// Encodes input provided in chunks via [`StreamEncoder::update`], buffering
+/// at most 2 bytes which don't yet form a complete 3-byte block. Once all of
+/// the input has been provided, [`StreamEncoder::finish`] encodes the final
+/// (potentially padded) block.
+#[derive(Clone, Debug)]
+pub struct StreamEncoder<E: Encoding> {
+    /// Input which doesn't yet form a complete block.
+    block: [u8; 3],
+
+    /// Length of the data in `block`.
+    block_len: usize,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding> StreamEncoder<E> {
+    /// Create a new streaming encoder.
+    pub fn new() -> Self {
+        Self {
+            block: [0u8; 3],
+            block_len: 0,
+            encoding: PhantomData,
+        }
+    }
+
+    /// Get the length of the Base64 which [`StreamEncoder::update`] will
+    /// write when given `input_len` bytes of input.
+    pub fn update_len(&self, input_len: usize) -> Result<usize, Error> {
+        self.block_len
+            .checked_add(input_len)
+            .map(|len| len / 3)
+            .and_then(|blocks| blocks.checked_mul(4))
+            .ok_or(InvalidLength)
+    }
+
+    /// Encode the provided chunk of input, writing the Base64 encoding of
+    /// all complete blocks into `out` and returning it.
+    ///
+    /// `out` must be at least [`StreamEncoder::update_len`] bytes long,
+    /// which is never more than `(input.len() + 2) / 3 * 4`. If it isn't,
+    /// [`Error::InvalidLength`] is returned and no input is consumed.
+    pub fn update<'o>(&mut self, mut input: &[u8], out: &'o mut [u8]) -> Result<&'o str, Error> {
+        if out.len() < self.update_len(input.len())? {
+            return Err(InvalidLength);
+        }
+
+        let mut out_len = 0;
+
+        // Complete the buffered block, if any
+        if self.block_len != 0 {
+            self.fill_block(&mut input);
+
+            if self.block_len == self.block.len() {
+                out_len = E::encode(&self.block, out)?.len();
+                self.block_len = 0;
+            }
+        }
+
+        let (blocks, rest) = split_blocks(input, 3);
+        let remaining = out.get_mut(out_len..).ok_or(InvalidLength)?;
+        out_len = out_len
+            .checked_add(E::encode(blocks, remaining)?.len())
+            .ok_or(InvalidLength)?;
+
+        let mut rest = rest;
+        self.fill_block(&mut rest);
+        debug_assert!(rest.is_empty());
+
+        Ok(str::from_utf8(&out[..out_len])?)
+    }
+
+    /// Finish encoding, writing the Base64 encoding of the final (partial)
+    /// block into `out` and returning it.
+    ///
+    /// `out` must be at least 4 bytes long.
+    pub fn finish(self, out: &mut [u8]) -> Result<&str, Error> {
+        Ok(E::encode(&self.block[..self.block_len], out)?)
+    }
+
+    /// Move as much of the input as fits into the block buffer.
+    fn fill_block(&mut self, input: &mut &[u8]) {
+        let len = self
+            .block
+            .len()
+            .saturating_sub(self.block_len)
+            .min(input.len());
+        let (head, rest) = input.split_at(len);
+        self.block[self.block_len..][..len].copy_from_slice(head);
+        self.block_len = self.block_len.saturating_add(len);
+        *input = rest;
+    }
+}
+
+impl<E: Encoding> Default for StreamEncoder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming Base64 decoder.
+///
+/// Decodes input provided in chunks via [`StreamDecoder::update`], buffering
+/// at most 3 characters which don't yet form a complete 4-character block.
+/// Once all of the input has been provided, [`StreamDecoder::finish`]
+/// decodes the final block of unpadded encodings and checks that no input
+/// was left over.
+///
+/// The input must be a contiguous Base64 string, i.e. it can't contain
+/// whitespace or line endings.
+#[derive(Clone, Debug)]
+pub struct StreamDecoder<E: Encoding> {
+    /// Input which doesn't yet form a complete block.
+    block: [u8; 4],
+
+    /// Length of the data in `block`.
+    block_len: usize,
+
+    /// Has padding been encountered (i.e. the end of the Base64 data)?
+    padded: bool,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding> StreamDecoder<E> {
+    /// Create a new streaming decoder.
+    pub fn new() -> Self {
+        Self {
+            block: [0u8; 4],
+            block_len: 0,
+            padded: false,
+            encoding: PhantomData,
+        }
+    }
+
+    /// Get the maximum length of the data which [`StreamDecoder::update`]
+    /// will write when given `input_len` characters of input.
+    pub fn update_len(&self, input_len: usize) -> Result<usize, Error> {
+        self.block_len
+            .checked_add(input_len)
+            .map(|len| len / 4)
+            .and_then(|blocks| blocks.checked_mul(3))
+            .ok_or(InvalidLength)
+    }
+
+    /// Decode the provided chunk of Base64, writing the decoded data of all
+    /// complete blocks into `out` and returning it.
+    ///
+    /// `out` must be at least [`StreamDecoder::update_len`] bytes long,
+    /// which is never more than `(input.len() + 3) / 4 * 3`. If it isn't,
+    /// [`Error::InvalidLength`] is returned and no input is consumed.
+    pub fn update<'o>(&mut self, mut input: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        if out.len() < self.update_len(input.len())? {
+            return Err(InvalidLength);
+        }
+
+        // Padding can only occur at the end of the data
+        if self.padded && !input.is_empty() {
+            return Err(InvalidEncoding);
+        }
+
+        let mut out_len = 0;
+
+        // Complete the buffered block, if any
+        if self.block_len != 0 {
+            self.fill_block(&mut input);
+
+            if self.block_len == self.block.len() {
+                let block = self.block;
+                out_len = self.decode_blocks(&block, out)?;
+                self.block_len = 0;
+            }
+        }
+
+        let (blocks, mut rest) = split_blocks(input, 4);
+
+        if !blocks.is_empty() {
+            if self.padded {
+                return Err(InvalidEncoding);
+            }
+
+            let remaining = out.get_mut(out_len..).ok_or(InvalidLength)?;
+            out_len = out_len
+                .checked_add(self.decode_blocks(blocks, remaining)?)
+                .ok_or(InvalidLength)?;
+        }
+
+        if self.padded && !rest.is_empty() {
+            return Err(InvalidEncoding);
+        }
+
+        self.fill_block(&mut rest);
+        debug_assert!(rest.is_empty());
+
+        Ok(&out[..out_len])
+    }
+
+    /// Finish decoding, writing the data decoded from the final (partial)
+    /// block of unpadded encodings into `out` and returning it.
+    ///
+    /// `out` must be at least 3 bytes long. Returns [`Error::InvalidEncoding`]
+    /// if the input ended with an incomplete block.
+    pub fn finish(self, out: &mut [u8]) -> Result<&[u8], Error> {
+        if self.block_len == 0 {
+            return Ok(&out[..0]);
+        }
+
+        // Padded encodings are always made up of complete blocks
+        if E::PADDED {
+            return Err(InvalidEncoding);
+        }
+
+        E::decode(&self.block[..self.block_len], out)
+    }
+
+    /// Decode complete blocks of Base64, returning the decoded length.
+    fn decode_blocks(&mut self, blocks: &[u8], out: &mut [u8]) -> Result<usize, Error> {
+        let len = E::decode(blocks, out)?.len();
+        self.padded = E::PADDED && blocks.last() == Some(&b'=');
+        Ok(len)
+    }
+
+    /// Move as much of the input as fits into the block buffer.
+    fn fill_block(&mut self, input: &mut &[u8]) {
+        let len = self
+            .block
+            .len()
+            .saturating_sub(self.block_len)
+            .min(input.len());
+        let (head, rest) = input.split_at(len);
+        self.block[self.block_len..][..len].copy_from_slice(head);
+        self.block_len = self.block_len.saturating_add(len);
+        *input = rest;
+    }
+}
+
+impl<E: Encoding> Default for StreamDecoder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split the input into complete blocks of the given size and the
+/// remaining partial block.
+fn split_blocks(input: &[u8], block_size: usize) -> (&[u8], &[u8]) {
+    let rest_len = input.chunks_exact(block_size).remainder().len();
+    input.split_at(input.len().saturating_sub(rest_len))
+}
+
+/// Number of bytes of input processed at a time by the [`io`] adapters.
+#[cfg(feature = "std")]
+const IO_CHUNK_SIZE: usize = 768;
+
+/// [`io::Write`] adapter which Base64 encodes all data written to it,
+/// writing the result to an underlying writer.
+///
+/// [`EncoderWriter::finish`] must be called to write the final block once
+/// all of the data has been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct EncoderWriter<E: Encoding, W: io::Write> {
+    /// Streaming encoder state.
+    encoder: StreamEncoder<E>,
+
+    /// Underlying writer.
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<E: Encoding, W: io::Write> EncoderWriter<E, W> {
+    /// Create a new encoder which writes Base64 to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            encoder: StreamEncoder::new(),
+            writer,
+        }
+    }
+
+    /// Finish encoding, writing the final block, and return the underlying
+    /// writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut out = [0u8; 4];
+        let encoded = self.encoder.finish(&mut out)?;
+        self.writer.write_all(encoded.as_bytes())?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Encoding, W: io::Write> io::Write for EncoderWriter<E, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let input = &buf[..buf.len().min(IO_CHUNK_SIZE)];
+        let mut out = [0u8; IO_CHUNK_SIZE / 3 * 4 + 4];
+        let encoded = self.encoder.update(input, &mut out)?;
+        self.writer.write_all(encoded.as_bytes())?;
+        Ok(input.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// [`io::Read`] adapter which decodes Base64 read from an underlying reader.
+///
+/// As with [`StreamDecoder`], the input must be a contiguous Base64 string.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct DecoderReader<E: Encoding, R: io::Read> {
+    /// Streaming decoder state.
+    decoder: StreamDecoder<E>,
+
+    /// Underlying reader.
+    reader: R,
+
+    /// Decoded data which hasn't been read yet.
+    decoded: [u8; IO_CHUNK_SIZE],
+
+    /// Range of the unread data in `decoded`.
+    position: usize,
+
+    /// Length of the data in `decoded`.
+    decoded_len: usize,
+
+    /// Has the underlying reader been exhausted?
+    finished: bool,
+}
+
+#[cfg(feature = "std")]
+impl<E: Encoding, R: io::Read> DecoderReader<E, R> {
+    /// Create a new decoder which reads Base64 from the given reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            decoder: StreamDecoder::new(),
+            reader,
+            decoded: [0u8; IO_CHUNK_SIZE],
+            position: 0,
+            decoded_len: 0,
+            finished: false,
+        }
+    }
+
+    /// Consume the decoder, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read and decode the next chunk of input.
+    fn fill(&mut self) -> io::Result<()> {
+        // Leave room for the partial block buffered by the decoder
+        let mut input = [0u8; IO_CHUNK_SIZE / 3 * 4 - 4];
+        let len = self.reader.read(&mut input)?;
+
+        self.position = 0;
+        self.decoded_len = if len == 0 {
+            self.finished = true;
+            let decoder = core::mem::take(&mut self.decoder);
+            decoder.finish(&mut self.decoded)?.len()
+        } else {
+            self.decoder.update(&input[..len], &mut self.decoded)?.len()
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Encoding, R: io::Read> io::Read for DecoderReader<E, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.decoded_len {
+            if self.finished || buf.is_empty() {
+                return Ok(0);
+            }
+
+            self.fill()?;
+        }
+
+        let remaining = &self.decoded[self.position..self.decoded_len];
+        let len = remaining.len().min(buf.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.position = self.position.saturating_add(len);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamDecoder, StreamEncoder};
+    use crate::{test_vectors::*, Base64, Base64Unpadded, Encoding, Error};
+
+    #[cfg(feature = "std")]
+    use {
+        super::{DecoderReader, EncoderWriter},
+        alloc::vec::Vec,
+        std::io::{Read, Write},
+    };
+
+    #[test]
+    fn encode_padded() {
+        encode_test::<Base64>(PADDED_BIN, PADDED_BASE64);
+    }
+
+    #[test]
+    fn encode_unpadded() {
+        encode_test::<Base64Unpadded>(UNPADDED_BIN, UNPADDED_BASE64);
+    }
+
+    #[test]
+    fn decode_padded() {
+        decode_test::<Base64>(PADDED_BIN, PADDED_BASE64);
+    }
+
+    #[test]
+    fn decode_unpadded() {
+        decode_test::<Base64Unpadded>(UNPADDED_BIN, UNPADDED_BASE64);
+    }
+
+    #[test]
+    fn decode_after_padding() {
+        let mut decoder = StreamDecoder::<Base64>::new();
+        let mut out = [0u8; 16];
+        assert_eq!(decoder.update(b"QQ==", &mut out), Ok(&b"A"[..]));
+        assert_eq!(
+            decoder.update(b"QQ==", &mut out),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let mut decoder = StreamDecoder::<Base64>::new();
+        let mut out = [0u8; 16];
+        assert_eq!(decoder.update(b"QUJDRA", &mut out), Ok(&b"ABC"[..]));
+        assert_eq!(decoder.finish(&mut out), Err(Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn output_too_short() {
+        let mut encoder = StreamEncoder::<Base64>::new();
+        let mut out = [0u8; 3];
+        assert_eq!(encoder.update(b"ABC", &mut out), Err(Error::InvalidLength));
+        assert_eq!(encoder.finish(&mut [0u8; 4]), Ok(""));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_adapters() {
+        let data = (0..=255u8).cycle().take(5000).collect::<Vec<_>>();
+
+        let mut writer = EncoderWriter::<Base64, _>::new(Vec::new());
+        writer.write_all(&data).unwrap();
+        let encoded = writer.finish().unwrap();
+        assert_eq!(encoded, Base64::encode_string(&data).as_bytes());
+
+        let mut reader = DecoderReader::<Base64, _>::new(&encoded[..]);
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    /// Encode the input in chunks of every possible size.
+    fn encode_test<E: Encoding>(input: &[u8], expected: &str) {
+        for chunk_size in 1..input.len() {
+            let mut encoder = StreamEncoder::<E>::new();
+            let mut out = [0u8; 1024];
+            let mut pos = 0;
+
+            for chunk in input.chunks(chunk_size) {
+                pos += encoder.update(chunk, &mut out[pos..]).unwrap().len();
+            }
+
+            pos += encoder.finish(&mut out[pos..]).unwrap().len();
+            assert_eq!(&out[..pos], expected.as_bytes());
+        }
+    }
+
+    /// Decode the input in chunks of every possible size.
+    fn decode_test<E: Encoding>(expected: &[u8], input: &str) {
+        for chunk_size in 1..input.len() {
+            let mut decoder = StreamDecoder::<E>::new();
+            let mut out = [0u8; 1024];
+            let mut pos = 0;
+
+            for chunk in input.as_bytes().chunks(chunk_size) {
+                pos += decoder.update(chunk, &mut out[pos..]).unwrap().len();
+            }
+
+            pos += decoder.finish(&mut out[pos..]).unwrap().len();
+            assert_eq!(&out[..pos], expected);
+        }
+    }
+}
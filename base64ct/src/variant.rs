@@ -7,6 +7,7 @@ use core::{fmt::Debug, ops::Range};
 
 pub mod bcrypt;
 pub mod crypt;
+pub mod custom;
 pub mod standard;
 pub mod url;
 
@@ -29,6 +30,12 @@ pub trait Variant: 'static + Copy + Debug + Eq + Send + Sized + Sync {
     /// Encoder passes
     const ENCODER: &'static [Encode];
 
+    /// Does this variant use the standard Base64 alphabet?
+    ///
+    /// Enables the SIMD-accelerated backends when the `simd` feature is
+    /// enabled.
+    const STANDARD_ALPHABET: bool = false;
+
     /// Decode 3 bytes of a Base64 message.
     #[inline(always)]
     fn decode_3bytes(src: &[u8], dst: &mut [u8]) -> i16 {
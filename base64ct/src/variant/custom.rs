@@ -0,0 +1,213 @@
+//! Base64 encodings with user-defined alphabets.
+
+use super::{Decode, Encode, Variant};
+use crate::encoding::PAD;
+use core::{fmt::Debug, marker::PhantomData, ops::RangeInclusive};
+
+/// Alphabet of a user-defined Base64 encoding.
+///
+/// The alphabet is described as a sequence of character ranges, which
+/// are concatenated to produce the 64 characters of the alphabet in order.
+/// For example, the standard Base64 alphabet is:
+///
+/// ```
+/// use base64ct::{Alphabet, Base64, Base64Custom, Encoding};
+/// use core::ops::RangeInclusive;
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// struct StandardAlphabet;
+///
+/// impl Alphabet for StandardAlphabet {
+///     const RANGES: &'static [RangeInclusive<u8>] =
+///         &[b'A'..=b'Z', b'a'..=b'z', b'0'..=b'9', b'+'..=b'+', b'/'..=b'/'];
+/// }
+///
+/// type MyBase64 = Base64Custom<StandardAlphabet, true>;
+///
+/// let bytes = b"example bytestring!";
+/// let mut buf = [0u8; 32];
+/// assert_eq!(
+///     MyBase64::encode(bytes, &mut buf).unwrap(),
+///     Base64::encode_string(bytes)
+/// );
+/// ```
+///
+/// The ranges must contain exactly 64 distinct printable ASCII characters
+/// (i.e. `0x21-0x7e`), not including the `=` padding character. This is
+/// checked at compile time when the alphabet is used.
+pub trait Alphabet: 'static + Copy + Debug + Eq + Send + Sync {
+    /// Character ranges making up the alphabet, in order.
+    const RANGES: &'static [RangeInclusive<u8>];
+}
+
+/// Base64 encoding with a user-defined [`Alphabet`], with `=` padding if
+/// `PADDED` is `true`.
+///
+/// Encoding and decoding are constant-time with respect to the data, just
+/// like the built-in variants, in a time proportional to the number of
+/// ranges in the alphabet.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64Custom<A: Alphabet, const PADDED: bool> {
+    alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet, const PADDED: bool> Base64Custom<A, PADDED> {
+    /// Fails to evaluate (and thus to compile) if the alphabet is invalid.
+    const VALID: () = [()][!is_valid(A::RANGES) as usize];
+}
+
+impl<A: Alphabet, const PADDED: bool> Variant for Base64Custom<A, PADDED> {
+    type Unpadded = Base64Custom<A, false>;
+    const PADDED: bool = PADDED;
+    const BASE: u8 = *first_char(A::RANGES);
+
+    // Steps are computed from the alphabet's ranges on the fly instead
+    const DECODER: &'static [Decode] = &[];
+    const ENCODER: &'static [Encode] = &[];
+
+    fn decode_6bits(src: u8) -> i16 {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        let src = src as i16;
+        let mut res: i16 = -1;
+        let mut index: i16 = 0;
+
+        for range in A::RANGES {
+            let first = *range.start() as i16;
+            let last = *range.end() as i16;
+
+            // Compute exclusive range from inclusive one
+            let start = first - 1;
+            let end = last + 1;
+            res += (((start - src) & (src - end)) >> 8) & (src - first + index + 1);
+            index += last - first + 1;
+        }
+
+        res
+    }
+
+    #[inline(always)]
+    fn encode_6bits(src: i16) -> u8 {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        let mut diff = src + Self::BASE as i16;
+        let mut index: i16 = 0;
+        let mut prev: Option<&RangeInclusive<u8>> = None;
+
+        for range in A::RANGES {
+            if let Some(prev) = prev {
+                let prev_len = *prev.end() as i16 - *prev.start() as i16 + 1;
+                let offset = *range.start() as i16 - *prev.start() as i16 - prev_len;
+                index += prev_len;
+                diff += ((index - 1 - src) >> 8) & offset;
+            }
+
+            prev = Some(range);
+        }
+
+        diff as u8
+    }
+}
+
+/// Get the first character of an alphabet, or `0` if it's empty (which is
+/// rejected by [`is_valid`]).
+const fn first_char(ranges: &[RangeInclusive<u8>]) -> &u8 {
+    if ranges.is_empty() {
+        &0
+    } else {
+        ranges[0].start()
+    }
+}
+
+/// Does the given sequence of ranges make up a valid alphabet?
+const fn is_valid(ranges: &[RangeInclusive<u8>]) -> bool {
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < ranges.len() {
+        let (start, end) = (*ranges[i].start(), *ranges[i].end());
+
+        if start > end || start < 0x21 || end > 0x7e || (start <= PAD && PAD <= end) {
+            return false;
+        }
+
+        // Ranges must not overlap
+        let mut j = 0;
+        while j < i {
+            if start <= *ranges[j].end() && *ranges[j].start() <= end {
+                return false;
+            }
+            j += 1;
+        }
+
+        len += (end - start) as usize + 1;
+        i += 1;
+    }
+
+    len == 64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid;
+
+    #[test]
+    fn validate_alphabet() {
+        assert!(is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+',
+            b'/'..=b'/'
+        ]));
+        assert!(is_valid(&[
+            b'.'..=b'/',
+            b'0'..=b'9',
+            b'A'..=b'Z',
+            b'a'..=b'z'
+        ]));
+
+        // Too short
+        assert!(!is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+'
+        ]));
+        assert!(!is_valid(&[]));
+
+        // Overlapping
+        assert!(!is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+',
+            b'Z'..=b'Z'
+        ]));
+
+        // Includes padding or non-printable characters
+        assert!(!is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+',
+            b'='..=b'='
+        ]));
+        assert!(!is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+',
+            b' '..=b' '
+        ]));
+        assert!(!is_valid(&[
+            b'A'..=b'Z',
+            b'a'..=b'z',
+            b'0'..=b'9',
+            b'+'..=b'+',
+            0x80..=0x80
+        ]));
+    }
+}
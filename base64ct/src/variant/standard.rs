@@ -17,6 +17,7 @@ impl Variant for Base64 {
     const BASE: u8 = b'A';
     const DECODER: &'static [Decode] = DECODER;
     const ENCODER: &'static [Encode] = ENCODER;
+    const STANDARD_ALPHABET: bool = true;
 }
 
 /// Standard Base64 encoding *without* padding.
@@ -34,6 +35,7 @@ impl Variant for Base64Unpadded {
     const BASE: u8 = b'A';
     const DECODER: &'static [Decode] = DECODER;
     const ENCODER: &'static [Encode] = ENCODER;
+    const STANDARD_ALPHABET: bool = true;
 }
 
 /// Standard Base64 decoder
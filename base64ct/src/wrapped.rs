@@ -0,0 +1,256 @@
+//! Line-wrapped Base64 encodings, e.g. as used by MIME and PEM.
+
+use crate::{
+    Base64, Decoder, Encoding,
+    Error::{self, InvalidLength},
+    LineEnding, MIN_LINE_WIDTH,
+};
+use core::{cmp, marker::PhantomData, str};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Line width used by PEM ([RFC 7468 § 2]).
+///
+/// [RFC 7468 § 2]: https://datatracker.ietf.org/doc/html/rfc7468#section-2
+pub const PEM_LINE_WIDTH: usize = 64;
+
+/// Maximum line width allowed by MIME ([RFC 2045 § 6.8]).
+///
+/// [RFC 2045 § 6.8]: https://datatracker.ietf.org/doc/html/rfc2045#section-6.8
+pub const MIME_LINE_WIDTH: usize = 76;
+
+/// Standard Base64 wrapped at the [`PEM_LINE_WIDTH`] of 64 characters.
+pub type Base64Pem = Wrapped<Base64, PEM_LINE_WIDTH>;
+
+/// Standard Base64 wrapped at the [`MIME_LINE_WIDTH`] of 76 characters.
+pub type Base64Mime = Wrapped<Base64, MIME_LINE_WIDTH>;
+
+/// Base64 encoding `E` with line breaks every `WIDTH` characters.
+///
+/// Line endings are inserted between lines when encoding (but not after the
+/// last line). When decoding, any of `CRLF`, `CR` or `LF` are accepted (see
+/// [`Decoder::new_wrapped`]), along with a line ending after the last line.
+/// All lines except the last must be exactly `WIDTH` characters.
+///
+/// The minimum supported width is 4.
+///
+/// ```
+/// use base64ct::{Base64Mime, LineEnding};
+///
+/// let bytes = [0u8; 60];
+/// let mut buf = [0u8; 128];
+/// let encoded = Base64Mime::encode(&bytes, &mut buf, LineEnding::CRLF).unwrap();
+/// assert_eq!(encoded.lines().count(), 2);
+///
+/// let mut dec_buf = [0u8; 60];
+/// let decoded = Base64Mime::decode(encoded, &mut dec_buf).unwrap();
+/// assert_eq!(decoded, bytes);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Wrapped<E: Encoding, const WIDTH: usize> {
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<E: Encoding, const WIDTH: usize> Wrapped<E, WIDTH> {
+    /// Decode line-wrapped Base64 into the provided destination buffer.
+    pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+
+        if src.is_empty() {
+            return Ok(&dst[..0]);
+        }
+
+        let mut decoder = Decoder::<E>::new_wrapped(src, WIDTH)?;
+        let dst = dst
+            .get_mut(..decoder.remaining_len())
+            .ok_or(InvalidLength)?;
+        let decoded = decoder.decode(dst)?;
+
+        if decoder.is_finished() {
+            Ok(decoded)
+        } else {
+            Err(InvalidLength)
+        }
+    }
+
+    /// Decode line-wrapped Base64 into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut output = Vec::new();
+        Decoder::<E>::new_wrapped(input.as_bytes(), WIDTH)?.decode_to_end(&mut output)?;
+        Ok(output)
+    }
+
+    /// Encode the input byte slice as line-wrapped Base64 using the given
+    /// line ending.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base64 string value.
+    pub fn encode<'o>(
+        src: &[u8],
+        dst: &'o mut [u8],
+        line_ending: LineEnding,
+    ) -> Result<&'o str, Error> {
+        if WIDTH < MIN_LINE_WIDTH {
+            return Err(InvalidLength);
+        }
+
+        let elen = Self::encoded_len(src, line_ending);
+        let dst = dst.get_mut(..elen).ok_or(InvalidLength)?;
+
+        // Encode the input at the end of the buffer, then move each line
+        // into place, leaving room for the line endings between them
+        let offset = elen.checked_sub(E::encoded_len(src)).ok_or(InvalidLength)?;
+        E::encode(src, &mut dst[offset..])?;
+
+        let mut pos = 0;
+        let mut line_start = offset;
+
+        while line_start < elen {
+            let line_len = cmp::min(WIDTH, elen.saturating_sub(line_start));
+            let line_end = line_start.checked_add(line_len).ok_or(InvalidLength)?;
+            dst.copy_within(line_start..line_end, pos);
+            pos = pos.checked_add(line_len).ok_or(InvalidLength)?;
+            line_start = line_end;
+
+            if line_start < elen {
+                let ending = line_ending.as_bytes();
+                dst[pos..][..ending.len()].copy_from_slice(ending);
+                pos = pos.checked_add(ending.len()).ok_or(InvalidLength)?;
+            }
+        }
+
+        debug_assert_eq!(pos, elen);
+        Ok(str::from_utf8(dst)?)
+    }
+
+    /// Encode input byte slice into a [`String`] containing line-wrapped
+    /// Base64.
+    ///
+    /// # Panics
+    /// If `input` length is greater than `usize::MAX/4`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encode_string(input: &[u8], line_ending: LineEnding) -> String {
+        let elen = Self::encoded_len(input, line_ending);
+        let mut dst = vec![0u8; elen];
+        let res = Self::encode(input, &mut dst, line_ending).expect("encoding error");
+
+        debug_assert_eq!(elen, res.len());
+        String::from_utf8(dst).expect("invalid UTF-8")
+    }
+
+    /// Get the length of line-wrapped Base64 produced by encoding the given
+    /// bytes using the given line ending.
+    ///
+    /// WARNING: this function will return `0` for lengths greater than `usize::MAX/4`!
+    pub fn encoded_len(bytes: &[u8], line_ending: LineEnding) -> usize {
+        let unwrapped_len = E::encoded_len(bytes);
+
+        unwrapped_len
+            .saturating_sub(1)
+            .checked_div(WIDTH)
+            .and_then(|endings| endings.checked_mul(line_ending.len()))
+            .and_then(|len| len.checked_add(unwrapped_len))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wrapped;
+    use crate::{test_vectors::*, Base64, Base64Mime, Base64Unpadded, Error, LineEnding};
+
+    #[test]
+    fn encode_multiline_padded() {
+        let mut buf = [0u8; 1024];
+        let encoded =
+            Wrapped::<Base64, 70>::encode(MULTILINE_PADDED_BIN, &mut buf, LineEnding::LF).unwrap();
+        assert_eq!(encoded, MULTILINE_PADDED_BASE64);
+        assert_eq!(
+            Wrapped::<Base64, 70>::encoded_len(MULTILINE_PADDED_BIN, LineEnding::LF),
+            MULTILINE_PADDED_BASE64.len()
+        );
+    }
+
+    #[test]
+    fn encode_multiline_unpadded() {
+        let mut buf = [0u8; 1024];
+        let encoded =
+            Wrapped::<Base64Unpadded, 70>::encode(MULTILINE_UNPADDED_BIN, &mut buf, LineEnding::LF)
+                .unwrap();
+        assert_eq!(encoded, MULTILINE_UNPADDED_BASE64);
+    }
+
+    #[test]
+    fn decode_multiline() {
+        let mut buf = [0u8; 1024];
+        let decoded = Wrapped::<Base64, 70>::decode(MULTILINE_PADDED_BASE64, &mut buf).unwrap();
+        assert_eq!(decoded, MULTILINE_PADDED_BIN);
+
+        let decoded =
+            Wrapped::<Base64Unpadded, 70>::decode(MULTILINE_UNPADDED_BASE64, &mut buf).unwrap();
+        assert_eq!(decoded, MULTILINE_UNPADDED_BIN);
+
+        // Lines must be the expected width
+        assert!(Wrapped::<Base64, 64>::decode(MULTILINE_PADDED_BASE64, &mut buf).is_err());
+    }
+
+    #[test]
+    fn round_trip_various_lengths() {
+        let data = [0xA5u8; 256];
+        let mut encoded_buf = [0u8; 512];
+        let mut decoded_buf = [0u8; 256];
+
+        for line_ending in [LineEnding::LF, LineEnding::CRLF] {
+            for len in 0..data.len() {
+                let encoded =
+                    Base64Mime::encode(&data[..len], &mut encoded_buf, line_ending).unwrap();
+                assert_eq!(
+                    encoded.len(),
+                    Base64Mime::encoded_len(&data[..len], line_ending)
+                );
+                assert!(encoded.lines().all(|line| line.len() <= 76));
+
+                let decoded = Base64Mime::decode(encoded, &mut decoded_buf).unwrap();
+                assert_eq!(decoded, &data[..len]);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_trailing_line_ending() {
+        let mut buf = [0u8; 16];
+        let decoded = Base64Mime::decode("AAECAw==\r\n", &mut buf).unwrap();
+        assert_eq!(decoded, &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn invalid_width() {
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            Wrapped::<Base64, 3>::encode(b"abc", &mut buf, LineEnding::LF),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            Wrapped::<Base64, 3>::decode("YWJj", &mut buf),
+            Err(Error::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn short_buffer() {
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            Base64Mime::encode(b"abcd", &mut buf, LineEnding::LF),
+            Err(Error::InvalidLength)
+        );
+    }
+}
@@ -76,5 +76,45 @@ macro_rules! impl_tests {
                 }
             }
         }
+
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn decode_par_matches_serial() {
+            let mut data = vec![0u8; 100_000];
+            for (i, byte) in data.iter_mut().enumerate() {
+                *byte = (i % 251) as u8;
+            }
+
+            let mut encoded = vec![0u8; <$encoding>::encoded_len(&data)];
+            let mut serial = vec![0u8; data.len()];
+            let mut parallel = vec![0u8; data.len()];
+
+            for len in [0, 1, 2, 3, 12_287, 12_288, 12_289, 50_000, 100_000] {
+                let encoded = <$encoding>::encode(&data[..len], &mut encoded).unwrap();
+                let decoded = <$encoding>::decode_par(encoded, &mut parallel).unwrap();
+                assert_eq!(decoded, &data[..len]);
+
+                // Invalid characters anywhere in the input
+                for pos in [0, encoded.len() / 2, encoded.len().saturating_sub(1)] {
+                    let mut invalid = encoded.as_bytes().to_vec();
+                    if let Some(char) = invalid.get_mut(pos) {
+                        *char = b'*';
+                    }
+
+                    assert_eq!(
+                        <$encoding>::decode_par(&invalid, &mut parallel),
+                        <$encoding>::decode(&invalid, &mut serial)
+                    );
+                }
+
+                // Insufficient output buffer
+                if len > 0 {
+                    assert_eq!(
+                        <$encoding>::decode_par(encoded, &mut parallel[..len - 1]),
+                        Err(Error::InvalidLength)
+                    );
+                }
+            }
+        }
     };
 }
@@ -0,0 +1,120 @@
+//! Tests for Base64 encodings with user-defined alphabets
+
+use base64ct::{
+    Alphabet, Base64, Base64Bcrypt, Base64Crypt, Base64Custom, Base64Unpadded, Base64Url, Encoding,
+    Error,
+};
+use core::ops::RangeInclusive;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Standard;
+
+impl Alphabet for Standard {
+    const RANGES: &'static [RangeInclusive<u8>] = &[
+        b'A'..=b'Z',
+        b'a'..=b'z',
+        b'0'..=b'9',
+        b'+'..=b'+',
+        b'/'..=b'/',
+    ];
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Url;
+
+impl Alphabet for Url {
+    const RANGES: &'static [RangeInclusive<u8>] = &[
+        b'A'..=b'Z',
+        b'a'..=b'z',
+        b'0'..=b'9',
+        b'-'..=b'-',
+        b'_'..=b'_',
+    ];
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Bcrypt;
+
+impl Alphabet for Bcrypt {
+    const RANGES: &'static [RangeInclusive<u8>] =
+        &[b'.'..=b'/', b'A'..=b'Z', b'a'..=b'z', b'0'..=b'9'];
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Crypt;
+
+impl Alphabet for Crypt {
+    const RANGES: &'static [RangeInclusive<u8>] =
+        &[b'.'..=b'/', b'0'..=b'9', b'A'..=b'Z', b'a'..=b'z'];
+}
+
+/// Alphabet which isn't used by any of the built-in variants.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Shuffled;
+
+impl Alphabet for Shuffled {
+    const RANGES: &'static [RangeInclusive<u8>] = &[
+        b'~'..=b'~',
+        b'a'..=b'z',
+        b'!'..=b'!',
+        b'0'..=b'9',
+        b'A'..=b'Z',
+    ];
+}
+
+/// Deterministic test data covering every byte value.
+fn test_data() -> [u8; 256] {
+    let mut data = [0u8; 256];
+
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i as u8).wrapping_mul(167).wrapping_add(13);
+    }
+
+    data
+}
+
+/// Check the custom encoding `C` is equivalent to the built-in encoding `E`.
+fn check_equivalent<C: Encoding, E: Encoding>() {
+    let data = test_data();
+    let mut expected = [0u8; 512];
+    let mut actual = [0u8; 512];
+    let mut decoded = [0u8; 256];
+
+    for len in 0..data.len() {
+        let expected = E::encode(&data[..len], &mut expected).unwrap();
+        let actual = C::encode(&data[..len], &mut actual).unwrap();
+        assert_eq!(expected, actual);
+        assert_eq!(C::decode(actual, &mut decoded).unwrap(), &data[..len]);
+    }
+}
+
+#[test]
+fn equivalent_to_builtin_variants() {
+    check_equivalent::<Base64Custom<Standard, true>, Base64>();
+    check_equivalent::<Base64Custom<Standard, false>, Base64Unpadded>();
+    check_equivalent::<Base64Custom<Url, true>, Base64Url>();
+    check_equivalent::<Base64Custom<Bcrypt, false>, Base64Bcrypt>();
+    check_equivalent::<Base64Custom<Crypt, false>, Base64Crypt>();
+}
+
+#[test]
+fn shuffled_alphabet() {
+    type Shuffled64 = Base64Custom<Shuffled, true>;
+
+    // Every 6-bit value in order
+    let raw = b"\x00\x10\x83\x10Q\x87 \x92\x8B0\xD3\x8FA\x14\x93QU\x97a\x96\x9Bq\
+                \xD7\x9F\x82\x18\xA3\x92Y\xA7\xA2\x9A\xAB\xB2\xDB\xAF\xC3\x1C\xB3\
+                \xD3\x5D\xB7\xE3\x9E\xBB\xF3\xDF\xBF";
+    let b64 = "~abcdefghijklmnopqrstuvwxyz!0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut buf = [0u8; 128];
+    assert_eq!(Shuffled64::encode(raw, &mut buf).unwrap(), b64);
+    assert_eq!(Shuffled64::decode(b64, &mut buf).unwrap(), raw);
+
+    for invalid in ["~a+b", "~a/b", "~a=b", "~a b", "~a-b"] {
+        assert_eq!(
+            Shuffled64::decode(invalid, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
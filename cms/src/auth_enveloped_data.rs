@@ -0,0 +1,143 @@
+//! `AuthEnvelopedData` content type as defined in [RFC 5083], along with the
+//! AES-GCM and AES-CCM parameters defined in [RFC 5084].
+//!
+//! [RFC 5083]: https://datatracker.ietf.org/doc/html/rfc5083
+//! [RFC 5084]: https://datatracker.ietf.org/doc/html/rfc5084
+
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::enveloped_data::{EncryptedContentInfo, OriginatorInfo, RecipientInfos};
+
+use const_oid::db::rfc5911::ID_CT_AUTH_ENVELOPED_DATA;
+use der::asn1::OctetString;
+use der::{Decode, Error, Sequence, Tag};
+use x509_cert::attr::Attributes;
+
+/// `AuthEnvelopedData` as defined in [RFC 5083 Section 2.1].
+///
+/// ```text
+/// AuthEnvelopedData ::= SEQUENCE {
+///     version CMSVersion,
+///     originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+///     recipientInfos RecipientInfos,
+///     authEncryptedContentInfo EncryptedContentInfo,
+///     authAttrs [1] IMPLICIT AuthAttributes OPTIONAL,
+///     mac MessageAuthenticationCode,
+///     unauthAttrs [2] IMPLICIT UnauthAttributes OPTIONAL }
+///
+/// AuthAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// UnauthAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// MessageAuthenticationCode ::= OCTET STRING
+/// ```
+///
+/// The `authAttrs` are authenticated along with the content: when present,
+/// their DER encoding is used as the AEAD associated data. For AES-GCM and
+/// AES-CCM the `mac` is the authentication tag, whose length is given by the
+/// [`GcmParameters`] or [`CcmParameters`].
+///
+/// [RFC 5083 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc5083#section-2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct AuthEnvelopedData<'a> {
+    pub version: CmsVersion,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub originator_info: Option<OriginatorInfo<'a>>,
+    pub recipient_infos: RecipientInfos<'a>,
+    pub auth_encrypted_content_info: EncryptedContentInfo<'a>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub auth_attrs: Option<Attributes<'a>>,
+    pub mac: OctetString<'a>,
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT", optional = "true")]
+    pub unauth_attrs: Option<Attributes<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for AuthEnvelopedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for AuthEnvelopedData<'a> {
+    type Error = Error;
+
+    /// Decode the `AuthEnvelopedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-ct-authEnvelopedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_CT_AUTH_ENVELOPED_DATA)
+    }
+}
+
+/// `GCMParameters` as defined in [RFC 5084 Section 3.2].
+///
+/// ```text
+/// GCMParameters ::= SEQUENCE {
+///     aes-nonce        OCTET STRING, -- recommended size is 12 octets
+///     aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+///
+/// AES-GCM-ICVlen ::= INTEGER (12 | 13 | 14 | 15 | 16)
+/// ```
+///
+/// [RFC 5084 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[asn1(validate = "GcmParameters::validate")]
+#[allow(missing_docs)]
+pub struct GcmParameters<'a> {
+    pub nonce: OctetString<'a>,
+    #[asn1(default = "default_icv_len")]
+    pub icv_len: u8,
+}
+
+impl GcmParameters<'_> {
+    /// Check the ICV length is one of the allowed values.
+    fn validate(&self) -> der::Result<()> {
+        if !(12..=16).contains(&self.icv_len) {
+            return Err(Tag::Integer.value_error());
+        }
+
+        Ok(())
+    }
+}
+
+/// `CCMParameters` as defined in [RFC 5084 Section 3.1].
+///
+/// ```text
+/// CCMParameters ::= SEQUENCE {
+///     aes-nonce         OCTET STRING (SIZE(7..13)),
+///     aes-ICVlen        AES-CCM-ICVlen DEFAULT 12 }
+///
+/// AES-CCM-ICVlen ::= INTEGER (4 | 6 | 8 | 10 | 12 | 14 | 16)
+/// ```
+///
+/// [RFC 5084 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[asn1(validate = "CcmParameters::validate")]
+#[allow(missing_docs)]
+pub struct CcmParameters<'a> {
+    pub nonce: OctetString<'a>,
+    #[asn1(default = "default_icv_len")]
+    pub icv_len: u8,
+}
+
+impl CcmParameters<'_> {
+    /// Check the nonce size and ICV length are within the allowed values.
+    fn validate(&self) -> der::Result<()> {
+        if !(7..=13).contains(&self.nonce.as_bytes().len()) {
+            return Err(Tag::OctetString.value_error());
+        }
+
+        if !(4..=16).contains(&self.icv_len) || self.icv_len % 2 != 0 {
+            return Err(Tag::Integer.value_error());
+        }
+
+        Ok(())
+    }
+}
+
+/// Default length of the integrity check value (i.e. authentication tag) for
+/// [`GcmParameters`] and [`CcmParameters`].
+fn default_icv_len() -> u8 {
+    12
+}
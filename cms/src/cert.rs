@@ -0,0 +1,83 @@
+//! Certificate-related types as defined in [RFC 5652 Section 10.2].
+//!
+//! [RFC 5652 Section 10.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2
+
+use alloc::vec::Vec;
+
+use der::asn1::{Any, ObjectIdentifier, UIntBytes};
+use der::{Choice, Sequence};
+use x509_cert::{name::Name, Certificate};
+
+/// `CertificateChoices` as defined in [RFC 5652 Section 10.2.2].
+///
+/// ```text
+/// CertificateChoices ::= CHOICE {
+///     certificate Certificate,
+///     extendedCertificate [0] IMPLICIT ExtendedCertificate,
+///          -- Obsolete
+///     ...,
+///     [[3: v1AttrCert [1] IMPLICIT AttributeCertificateV1]],
+///          -- Obsolete
+///     [[4: v2AttrCert [2] IMPLICIT AttributeCertificateV2]],
+///     [[5: other      [3] IMPLICIT OtherCertificateFormat]] }
+/// ```
+///
+/// The obsolete `extendedCertificate` and `v1AttrCert` alternatives, as well
+/// as attribute certificates, are not supported.
+///
+/// [RFC 5652 Section 10.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(clippy::large_enum_variant)]
+#[allow(missing_docs)]
+pub enum CertificateChoices<'a> {
+    Certificate(Certificate<'a>),
+
+    #[asn1(context_specific = "3", tag_mode = "IMPLICIT", constructed = "true")]
+    Other(OtherCertificateFormat<'a>),
+}
+
+/// `OtherCertificateFormat` as defined in [RFC 5652 Section 10.2.2].
+///
+/// ```text
+/// OtherCertificateFormat ::= SEQUENCE {
+///     otherCertFormat OBJECT IDENTIFIER,
+///     otherCert ANY DEFINED BY otherCertFormat }
+/// ```
+///
+/// [RFC 5652 Section 10.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OtherCertificateFormat<'a> {
+    pub other_cert_format: ObjectIdentifier,
+    pub other_cert: Any<'a>,
+}
+
+/// `CertificateSet` as defined in [RFC 5652 Section 10.2.3].
+///
+/// ```text
+/// CertificateSet ::= SET OF CertificateChoices
+/// ```
+///
+/// [RFC 5652 Section 10.2.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.3
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CertificateSet<'a>(pub Vec<CertificateChoices<'a>>);
+
+impl_set_of!(CertificateSet);
+
+/// `IssuerAndSerialNumber` as defined in [RFC 5652 Section 10.2.4].
+///
+/// ```text
+/// IssuerAndSerialNumber ::= SEQUENCE {
+///     issuer Name,
+///     serialNumber CertificateSerialNumber }
+///
+/// CertificateSerialNumber ::= INTEGER
+/// ```
+///
+/// [RFC 5652 Section 10.2.4]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct IssuerAndSerialNumber<'a> {
+    pub issuer: Name<'a>,
+    pub serial_number: UIntBytes<'a>,
+}
@@ -0,0 +1,33 @@
+//! Degenerate "certs-only" `SignedData` as described in
+//! [RFC 5652 Section 5.2], as found in `.p7b` and `.p7c` files.
+//!
+//! These are commonly used by CAs (and Windows) to distribute certificate
+//! chains, and contain no signers and no content.
+//!
+//! [RFC 5652 Section 5.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.2
+
+use crate::content_info::ContentInfo;
+use crate::signed_data::SignedData;
+
+use alloc::vec::Vec;
+
+use const_oid::db::rfc5911::ID_SIGNED_DATA;
+use der::{Decode, Result};
+use x509_cert::Certificate;
+
+/// Decode the X.509 certificates from the DER encoding of a `ContentInfo`
+/// containing a `SignedData`, in the order they're encoded in.
+///
+/// Certificates in other formats are skipped. Signers (if any) are ignored,
+/// and their signatures aren't verified.
+pub fn decode(der: &[u8]) -> Result<Vec<Certificate<'_>>> {
+    let content_info = ContentInfo::from_der(der)?;
+    let signed_data = SignedData::try_from(&content_info)?;
+    Ok(signed_data.x509_certificates().cloned().collect())
+}
+
+/// Encode the given X.509 certificates as a certs-only `SignedData`, wrapped
+/// in a `ContentInfo`.
+pub fn encode<'a>(certificates: impl IntoIterator<Item = Certificate<'a>>) -> Result<Vec<u8>> {
+    ContentInfo::encode_content(ID_SIGNED_DATA, &SignedData::new_certs_only(certificates))
+}
@@ -0,0 +1,117 @@
+//! `CompressedData` content type as defined in [RFC 3274].
+//!
+//! [RFC 3274]: https://datatracker.ietf.org/doc/html/rfc3274
+
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::signed_data::EncapsulatedContentInfo;
+
+use const_oid::db::rfc6268::ID_CT_COMPRESSED_DATA;
+use der::{Decode, Error, Sequence};
+use spki::AlgorithmIdentifier;
+
+#[cfg(feature = "zlib")]
+use {
+    alloc::vec::Vec,
+    const_oid::db::rfc6268::ID_ALG_ZLIB_COMPRESS,
+    der::asn1::{ObjectIdentifier, OctetString},
+};
+
+/// Compression level used by [`compress`], which is zlib's default.
+#[cfg(feature = "zlib")]
+const ZLIB_LEVEL: u8 = 6;
+
+/// `CompressedData` as defined in [RFC 3274 Section 1.1].
+///
+/// ```text
+/// CompressedData ::= SEQUENCE {
+///     version CMSVersion,       -- Always set to 0
+///     compressionAlgorithm CompressionAlgorithmIdentifier,
+///     encapContentInfo EncapsulatedContentInfo }
+///
+/// CompressionAlgorithmIdentifier ::= AlgorithmIdentifier
+/// ```
+///
+/// The only compression algorithm defined for CMS is `id-alg-zlibCompress`,
+/// without parameters, in which case the `eContent` is a zlib stream as
+/// defined in [RFC 1950].
+///
+/// [RFC 3274 Section 1.1]: https://datatracker.ietf.org/doc/html/rfc3274#section-1.1
+/// [RFC 1950]: https://datatracker.ietf.org/doc/html/rfc1950
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CompressedData<'a> {
+    pub version: CmsVersion,
+    pub compression_algorithm: AlgorithmIdentifier<'a>,
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+}
+
+impl<'a> CompressedData<'a> {
+    /// Decompress the encapsulated content, which must have been compressed
+    /// with `id-alg-zlibCompress`.
+    ///
+    /// Returns [`Error::Compression`][`crate::Error::Compression`] if the
+    /// content is missing or malformed, or if it decompresses to more than
+    /// `max_len` bytes: since compressed content is usually untrusted, the
+    /// limit guards against "zip bombs".
+    #[cfg(feature = "zlib")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zlib")))]
+    pub fn decompress(&self, max_len: usize) -> crate::Result<Vec<u8>> {
+        if self.compression_algorithm.oid != ID_ALG_ZLIB_COMPRESS {
+            return Err(crate::Error::Compression);
+        }
+
+        let econtent = self
+            .encap_content_info
+            .econtent
+            .ok_or(crate::Error::Compression)?;
+
+        miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(econtent.as_bytes(), max_len)
+            .map_err(|_| crate::Error::Compression)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for CompressedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for CompressedData<'a> {
+    type Error = Error;
+
+    /// Decode the `CompressedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-ct-compressedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_CT_COMPRESSED_DATA)
+    }
+}
+
+/// Compress the given content with `id-alg-zlibCompress`, returning the DER
+/// encoding of a `ContentInfo` containing a `CompressedData`.
+///
+/// `econtent_type` is the content type of the (uncompressed) content, e.g.
+/// `id-data`.
+#[cfg(feature = "zlib")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zlib")))]
+pub fn compress(econtent_type: ObjectIdentifier, content: &[u8]) -> crate::Result<Vec<u8>> {
+    let compressed = miniz_oxide::deflate::compress_to_vec_zlib(content, ZLIB_LEVEL);
+
+    let compressed_data = CompressedData {
+        version: CmsVersion::V0,
+        compression_algorithm: AlgorithmIdentifier {
+            oid: ID_ALG_ZLIB_COMPRESS,
+            parameters: None,
+        },
+        encap_content_info: EncapsulatedContentInfo {
+            econtent_type,
+            econtent: Some(OctetString::new(compressed.as_slice())?),
+        },
+    };
+
+    Ok(ContentInfo::encode_content(
+        ID_CT_COMPRESSED_DATA,
+        &compressed_data,
+    )?)
+}
@@ -0,0 +1,90 @@
+//! `ContentInfo` and related types as defined in [RFC 5652 Section 3].
+//!
+//! [RFC 5652 Section 3]: https://datatracker.ietf.org/doc/html/rfc5652#section-3
+
+use alloc::vec::Vec;
+
+use der::asn1::{Any, ObjectIdentifier};
+use der::{Decode, DecodeValue, Encode, Enumerated, Error, FixedTag, Sequence, Tag};
+
+/// `ContentInfo` as defined in [RFC 5652 Section 3].
+///
+/// ```text
+/// ContentInfo ::= SEQUENCE {
+///     contentType        CONTENT-TYPE.
+///                     &id({ContentSet}),
+///     content            [0] EXPLICIT CONTENT-TYPE.
+///                     &Type({ContentSet}{@contentType})}
+/// ```
+///
+/// The `content` is left undecoded: use [`Any::decode_into`] or the
+/// conversions provided by the content types (e.g. [`SignedData`]) to access
+/// it.
+///
+/// [RFC 5652 Section 3]: https://datatracker.ietf.org/doc/html/rfc5652#section-3
+/// [`SignedData`]: crate::signed_data::SignedData
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct ContentInfo<'a> {
+    pub content_type: ObjectIdentifier,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
+    pub content: Any<'a>,
+}
+
+impl<'a> ContentInfo<'a> {
+    /// Decode the content, checking that the content type matches the
+    /// expected one.
+    pub(crate) fn decode_content<T>(&self, content_type: ObjectIdentifier) -> der::Result<T>
+    where
+        T: DecodeValue<'a> + FixedTag,
+    {
+        if self.content_type != content_type {
+            return Err(Tag::ObjectIdentifier.value_error());
+        }
+
+        self.content.decode_into()
+    }
+
+    /// Encode the given content, wrapped in a `ContentInfo` of the given
+    /// type.
+    pub(crate) fn encode_content(
+        content_type: ObjectIdentifier,
+        content: &impl Encode,
+    ) -> der::Result<Vec<u8>> {
+        let content = content.to_vec()?;
+
+        ContentInfo {
+            content_type,
+            content: Any::from_der(&content)?,
+        }
+        .to_vec()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ContentInfo<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `CMSVersion` as defined in [RFC 5652 Section 10.2.5].
+///
+/// ```text
+/// CMSVersion ::= INTEGER  { v0(0), v1(1), v2(2), v3(3), v4(4), v5(5) }
+/// ```
+///
+/// [RFC 5652 Section 10.2.5]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.5
+#[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+#[allow(missing_docs)]
+pub enum CmsVersion {
+    V0 = 0,
+    V1 = 1,
+    V2 = 2,
+    V3 = 3,
+    V4 = 4,
+    V5 = 5,
+}
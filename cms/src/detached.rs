@@ -0,0 +1,254 @@
+//! Detached signatures: `SignedData` whose content is conveyed separately,
+//! as described in [RFC 5652 Section 5].
+//!
+//! Signatures are made over the DER encoding of the signed attributes, which
+//! include the content type and a digest of the content as described in
+//! [RFC 5652 Section 5.4].
+//!
+//! Since only the digest of the content is signed, content which is too
+//! large to hold in memory can be streamed into a [`Digest`] and signed or
+//! verified with [`sign_digest`] and [`verify_digest`].
+//!
+//! [RFC 5652 Section 5]: https://datatracker.ietf.org/doc/html/rfc5652#section-5
+//! [RFC 5652 Section 5.4]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.4
+
+use crate::cert::{CertificateChoices, CertificateSet, IssuerAndSerialNumber};
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::signed_attributes::SignedAttributesBuilder;
+use crate::signed_data::{
+    EncapsulatedContentInfo, SignedData, SignerIdentifier, SignerInfo, SignerInfos,
+};
+use crate::{Error, Result};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use const_oid::db::rfc5280::ID_CE_SUBJECT_KEY_IDENTIFIER;
+use const_oid::db::rfc5911::{ID_CONTENT_TYPE, ID_DATA, ID_MESSAGE_DIGEST, ID_SIGNED_DATA};
+use der::asn1::{Any, ObjectIdentifier, OctetString, SetOfVec};
+use der::{Decode, Encode};
+use digest::Digest;
+use signature::{Signature, Signer, Verifier};
+use spki::AlgorithmIdentifier;
+use x509_cert::attr::Attributes;
+use x509_cert::ext::pkix::SubjectKeyIdentifier;
+use x509_cert::Certificate;
+
+/// Sign the given content, returning the DER encoding of a `ContentInfo`
+/// containing a `SignedData` with a single signer and without the content.
+///
+/// The signer is identified by the issuer and serial number of `cert`, which
+/// is also included in the `SignedData`. `digest_alg` and `signature_alg`
+/// must identify `D` and the algorithm used by `signer` respectively.
+pub fn sign<D, S>(
+    content: &[u8],
+    signer: &impl Signer<S>,
+    cert: &Certificate<'_>,
+    digest_alg: AlgorithmIdentifier<'_>,
+    signature_alg: AlgorithmIdentifier<'_>,
+) -> Result<Vec<u8>>
+where
+    D: Digest,
+    S: Signature,
+{
+    sign_digest(
+        D::new_with_prefix(content),
+        signer,
+        cert,
+        digest_alg,
+        signature_alg,
+    )
+}
+
+/// Sign the content which has been input into the given digest, as for
+/// [`sign`].
+///
+/// This allows signing content which is too large to hold in memory, by
+/// streaming it into the digest (e.g. via its `io::Write` impl).
+pub fn sign_digest<D, S>(
+    digest: D,
+    signer: &impl Signer<S>,
+    cert: &Certificate<'_>,
+    digest_alg: AlgorithmIdentifier<'_>,
+    signature_alg: AlgorithmIdentifier<'_>,
+) -> Result<Vec<u8>>
+where
+    D: Digest,
+    S: Signature,
+{
+    let mut builder = SignedAttributesBuilder::new();
+    builder
+        .content_type(ID_DATA)?
+        .message_digest(&digest.finalize())?;
+    let signed_attrs = builder.build()?;
+
+    let signature = signer
+        .try_sign(&signed_attrs.to_vec()?)
+        .map_err(|_| Error::Signature)?;
+
+    let signer_info = SignerInfo {
+        version: CmsVersion::V1,
+        sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: cert.tbs_certificate.issuer.clone(),
+            serial_number: cert.tbs_certificate.serial_number,
+        }),
+        digest_alg,
+        signed_attrs: Some(signed_attrs),
+        signature_algorithm: signature_alg,
+        signature: OctetString::new(signature.as_ref())?,
+        unsigned_attrs: None,
+    };
+
+    let signed_data = SignedData {
+        version: CmsVersion::V1,
+        digest_algorithms: SetOfVec::try_from([digest_alg])?,
+        encap_content_info: EncapsulatedContentInfo {
+            econtent_type: ID_DATA,
+            econtent: None,
+        },
+        certificates: Some(CertificateSet(vec![CertificateChoices::Certificate(
+            cert.clone(),
+        )])),
+        crls: None,
+        signer_infos: SignerInfos(vec![signer_info]),
+    };
+
+    Ok(ContentInfo::encode_content(ID_SIGNED_DATA, &signed_data)?)
+}
+
+/// Verify the signature made by the holder of `cert` over the given content,
+/// where `der` is the DER encoding of a `ContentInfo` containing a
+/// `SignedData`.
+///
+/// `D` must be the digest algorithm identified by the signer's
+/// [`SignerInfo::digest_alg`], and `verifier` must use the public key of
+/// `cert`. The certificate itself isn't validated.
+pub fn verify<D, S>(
+    der: &[u8],
+    content: &[u8],
+    cert: &Certificate<'_>,
+    verifier: &impl Verifier<S>,
+) -> Result<()>
+where
+    D: Digest,
+    S: Signature,
+{
+    let signer_info = find_signer(der, cert)?;
+
+    match signer_info.signed_attrs {
+        Some(_) => verify_signer_digest(&signer_info, D::new_with_prefix(content), verifier),
+        // Without signed attributes, the content itself is signed
+        None => {
+            let signature = signer_signature(&signer_info)?;
+            verifier
+                .verify(content, &signature)
+                .map_err(|_| Error::Signature)
+        }
+    }
+}
+
+/// Verify the signature made by the holder of `cert` over the content which
+/// has been input into the given digest, as for [`verify`].
+///
+/// This allows verifying content which is too large to hold in memory, by
+/// streaming it into the digest (e.g. via its `io::Write` impl). The signer
+/// must have included signed attributes, since otherwise the signature is
+/// over the content itself: [`Error::AttributesInvalid`] is returned if not.
+pub fn verify_digest<D, S>(
+    der: &[u8],
+    digest: D,
+    cert: &Certificate<'_>,
+    verifier: &impl Verifier<S>,
+) -> Result<()>
+where
+    D: Digest,
+    S: Signature,
+{
+    verify_signer_digest(&find_signer(der, cert)?, digest, verifier)
+}
+
+/// Find the `SignerInfo` for `cert` in the given `ContentInfo`, checking that
+/// the signed content type is `id-data` when there are no signed attributes.
+fn find_signer<'a>(der: &'a [u8], cert: &Certificate<'_>) -> Result<SignerInfo<'a>> {
+    let signed_data = SignedData::try_from(&ContentInfo::from_der(der)?)?;
+    let econtent_type = signed_data.encap_content_info.econtent_type;
+
+    let signer_info = signed_data
+        .signer_infos
+        .0
+        .into_iter()
+        .find(|signer_info| is_signer(&signer_info.sid, cert))
+        .ok_or(Error::SignerNotFound)?;
+
+    let content_type_valid = match &signer_info.signed_attrs {
+        Some(signed_attrs) => attr_value(signed_attrs, ID_CONTENT_TYPE)?.oid()? == econtent_type,
+        // Without signed attributes, the content must be `id-data`
+        None => econtent_type == ID_DATA,
+    };
+
+    if !content_type_valid {
+        return Err(Error::AttributesInvalid);
+    }
+
+    Ok(signer_info)
+}
+
+/// Verify the signature over the signed attributes of the given signer,
+/// which must contain the message digest of the content.
+fn verify_signer_digest<D, S>(
+    signer_info: &SignerInfo<'_>,
+    digest: D,
+    verifier: &impl Verifier<S>,
+) -> Result<()>
+where
+    D: Digest,
+    S: Signature,
+{
+    let signed_attrs = signer_info
+        .signed_attrs
+        .as_ref()
+        .ok_or(Error::AttributesInvalid)?;
+
+    let message_digest = attr_value(signed_attrs, ID_MESSAGE_DIGEST)?.octet_string()?;
+
+    if message_digest.as_bytes() != digest.finalize().as_slice() {
+        return Err(Error::DigestMismatch);
+    }
+
+    let signature = signer_signature(signer_info)?;
+    verifier
+        .verify(&signed_attrs.to_vec()?, &signature)
+        .map_err(|_| Error::Signature)
+}
+
+/// Parse the signature of the given signer.
+fn signer_signature<S: Signature>(signer_info: &SignerInfo<'_>) -> Result<S> {
+    S::from_bytes(signer_info.signature.as_bytes()).map_err(|_| Error::Signature)
+}
+
+/// Does the given signer identifier identify the subject of `cert`?
+fn is_signer(sid: &SignerIdentifier<'_>, cert: &Certificate<'_>) -> bool {
+    match sid {
+        SignerIdentifier::IssuerAndSerialNumber(sid) => {
+            sid.issuer == cert.tbs_certificate.issuer
+                && sid.serial_number == cert.tbs_certificate.serial_number
+        }
+        SignerIdentifier::SubjectKeyIdentifier(sid) => cert
+            .tbs_certificate
+            .extensions
+            .iter()
+            .flatten()
+            .filter(|ext| ext.extn_id == ID_CE_SUBJECT_KEY_IDENTIFIER)
+            .any(|ext| SubjectKeyIdentifier::from_der(ext.extn_value).as_ref() == Ok(sid)),
+    }
+}
+
+/// Get the single value of the signed attribute with the given type.
+fn attr_value<'a>(attrs: &Attributes<'a>, oid: ObjectIdentifier) -> Result<Any<'a>> {
+    let mut attrs = attrs.iter().filter(|attr| attr.oid == oid);
+
+    match (attrs.next(), attrs.next()) {
+        (Some(attr), None) if attr.values.len() == 1 => Ok(attr.values.as_slice()[0]),
+        _ => Err(Error::AttributesInvalid),
+    }
+}
@@ -0,0 +1,55 @@
+//! `DigestedData` content type as defined in [RFC 5652 Section 7].
+//!
+//! [RFC 5652 Section 7]: https://datatracker.ietf.org/doc/html/rfc5652#section-7
+
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::signed_data::EncapsulatedContentInfo;
+
+use const_oid::db::rfc5911::ID_DIGESTED_DATA;
+use der::asn1::OctetString;
+use der::{Decode, Error, Sequence};
+use spki::AlgorithmIdentifier;
+
+/// `DigestedData` as defined in [RFC 5652 Section 7].
+///
+/// ```text
+/// DigestedData ::= SEQUENCE {
+///     version CMSVersion,
+///     digestAlgorithm DigestAlgorithmIdentifier,
+///     encapContentInfo EncapsulatedContentInfo,
+///     digest Digest }
+///
+/// Digest ::= OCTET STRING
+/// ```
+///
+/// The `version` is 0 when the encapsulated content type is `id-data`, and 2
+/// otherwise. The `digest` is computed over the content octets, as for
+/// `SignedData` without signed attributes.
+///
+/// [RFC 5652 Section 7]: https://datatracker.ietf.org/doc/html/rfc5652#section-7
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct DigestedData<'a> {
+    pub version: CmsVersion,
+    pub digest_algorithm: AlgorithmIdentifier<'a>,
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+    pub digest: OctetString<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for DigestedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for DigestedData<'a> {
+    type Error = Error;
+
+    /// Decode the `DigestedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-digestedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_DIGESTED_DATA)
+    }
+}
@@ -0,0 +1,51 @@
+//! `EncryptedData` content type as defined in [RFC 5652 Section 8].
+//!
+//! [RFC 5652 Section 8]: https://datatracker.ietf.org/doc/html/rfc5652#section-8
+
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::enveloped_data::EncryptedContentInfo;
+
+use const_oid::db::rfc5911::ID_ENCRYPTED_DATA;
+use der::{Decode, Error, Sequence};
+use x509_cert::attr::Attributes;
+
+/// `EncryptedData` as defined in [RFC 5652 Section 8].
+///
+/// ```text
+/// EncryptedData ::= SEQUENCE {
+///     version CMSVersion,
+///     encryptedContentInfo EncryptedContentInfo,
+///     unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+/// ```
+///
+/// Unlike `EnvelopedData`, there are no recipients: the content-encryption
+/// key is managed by other means, e.g. a password or pre-shared key. The
+/// `version` is 2 when `unprotectedAttrs` is present, and 0 otherwise.
+///
+/// [RFC 5652 Section 8]: https://datatracker.ietf.org/doc/html/rfc5652#section-8
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EncryptedData<'a> {
+    pub version: CmsVersion,
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub unprotected_attrs: Option<Attributes<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for EncryptedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for EncryptedData<'a> {
+    type Error = Error;
+
+    /// Decode the `EncryptedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-encryptedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_ENCRYPTED_DATA)
+    }
+}
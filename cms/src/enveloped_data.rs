@@ -0,0 +1,403 @@
+//! `EnvelopedData` content type as defined in [RFC 5652 Section 6].
+//!
+//! [RFC 5652 Section 6]: https://datatracker.ietf.org/doc/html/rfc5652#section-6
+
+use crate::cert::{CertificateSet, IssuerAndSerialNumber};
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::revocation::RevocationInfoChoices;
+
+use alloc::vec::Vec;
+
+use const_oid::db::rfc5911::ID_ENVELOPED_DATA;
+use der::asn1::{Any, BitString, GeneralizedTime, ObjectIdentifier, OctetString};
+use der::{Choice, Decode, Error, Sequence};
+use spki::AlgorithmIdentifier;
+use x509_cert::attr::Attributes;
+use x509_cert::ext::pkix::SubjectKeyIdentifier;
+
+/// `EnvelopedData` as defined in [RFC 5652 Section 6.1].
+///
+/// ```text
+/// EnvelopedData ::= SEQUENCE {
+///     version CMSVersion,
+///     originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+///     recipientInfos RecipientInfos,
+///     encryptedContentInfo EncryptedContentInfo,
+///     unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+///
+/// UnprotectedAttributes ::= SET SIZE (1..MAX) OF Attribute
+/// ```
+///
+/// [RFC 5652 Section 6.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EnvelopedData<'a> {
+    pub version: CmsVersion,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub originator_info: Option<OriginatorInfo<'a>>,
+    pub recipient_infos: RecipientInfos<'a>,
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub unprotected_attrs: Option<Attributes<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for EnvelopedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for EnvelopedData<'a> {
+    type Error = Error;
+
+    /// Decode the `EnvelopedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-envelopedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_ENVELOPED_DATA)
+    }
+}
+
+/// `OriginatorInfo` as defined in [RFC 5652 Section 6.1].
+///
+/// ```text
+/// OriginatorInfo ::= SEQUENCE {
+///     certs [0] IMPLICIT CertificateSet OPTIONAL,
+///     crls [1] IMPLICIT RevocationInfoChoices OPTIONAL }
+/// ```
+///
+/// [RFC 5652 Section 6.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.1
+#[derive(Clone, Debug, Default, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OriginatorInfo<'a> {
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub certs: Option<CertificateSet<'a>>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub crls: Option<RevocationInfoChoices<'a>>,
+}
+
+/// `EncryptedContentInfo` as defined in [RFC 5652 Section 6.1].
+///
+/// ```text
+/// EncryptedContentInfo ::= SEQUENCE {
+///     contentType ContentType,
+///     contentEncryptionAlgorithm ContentEncryptionAlgorithmIdentifier,
+///     encryptedContent [0] IMPLICIT EncryptedContent OPTIONAL }
+///
+/// EncryptedContent ::= OCTET STRING
+/// ```
+///
+/// The `encryptedContent` is absent when it is conveyed separately.
+///
+/// Encrypted content is borrowed from the encoded message when decoded from
+/// memory. Content which is too large for this can be streamed from the
+/// message with `stream::ContentReader` (with the `std` feature).
+///
+/// [RFC 5652 Section 6.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EncryptedContentInfo<'a> {
+    pub content_type: ObjectIdentifier,
+    pub content_enc_alg: AlgorithmIdentifier<'a>,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub encrypted_content: Option<OctetString<'a>>,
+}
+
+/// `RecipientInfos` as defined in [RFC 5652 Section 6.1].
+///
+/// ```text
+/// RecipientInfos ::= SET SIZE (1..MAX) OF RecipientInfo
+/// ```
+///
+/// [RFC 5652 Section 6.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.1
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RecipientInfos<'a>(pub Vec<RecipientInfo<'a>>);
+
+impl_set_of!(RecipientInfos);
+
+/// `RecipientInfo` as defined in [RFC 5652 Section 6.2].
+///
+/// ```text
+/// RecipientInfo ::= CHOICE {
+///     ktri KeyTransRecipientInfo,
+///     kari [1] KeyAgreeRecipientInfo,
+///     kekri [2] KEKRecipientInfo,
+///     pwri [3] PasswordRecipientinfo,
+///     ori [4] OtherRecipientInfo }
+/// ```
+///
+/// [RFC 5652 Section 6.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum RecipientInfo<'a> {
+    Ktri(KeyTransRecipientInfo<'a>),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Kari(KeyAgreeRecipientInfo<'a>),
+
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT", constructed = "true")]
+    Kekri(KekRecipientInfo<'a>),
+
+    #[asn1(context_specific = "3", tag_mode = "IMPLICIT", constructed = "true")]
+    Pwri(PasswordRecipientInfo<'a>),
+
+    #[asn1(context_specific = "4", tag_mode = "IMPLICIT", constructed = "true")]
+    Ori(OtherRecipientInfo<'a>),
+}
+
+/// `KeyTransRecipientInfo` as defined in [RFC 5652 Section 6.2.1].
+///
+/// ```text
+/// KeyTransRecipientInfo ::= SEQUENCE {
+///     version CMSVersion,  -- always set to 0 or 2
+///     rid RecipientIdentifier,
+///     keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///     encryptedKey EncryptedKey }
+///
+/// EncryptedKey ::= OCTET STRING
+/// ```
+///
+/// [RFC 5652 Section 6.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct KeyTransRecipientInfo<'a> {
+    pub version: CmsVersion,
+    pub rid: RecipientIdentifier<'a>,
+    pub key_enc_alg: AlgorithmIdentifier<'a>,
+    pub enc_key: OctetString<'a>,
+}
+
+/// `RecipientIdentifier` as defined in [RFC 5652 Section 6.2.1].
+///
+/// ```text
+/// RecipientIdentifier ::= CHOICE {
+///     issuerAndSerialNumber IssuerAndSerialNumber,
+///     subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+///
+/// When the `subjectKeyIdentifier` alternative is used, the
+/// [`KeyTransRecipientInfo::version`] must be [`CmsVersion::V2`] rather than
+/// [`CmsVersion::V0`].
+///
+/// [RFC 5652 Section 6.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum RecipientIdentifier<'a> {
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    SubjectKeyIdentifier(SubjectKeyIdentifier<'a>),
+}
+
+/// `KeyAgreeRecipientInfo` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// KeyAgreeRecipientInfo ::= SEQUENCE {
+///     version CMSVersion,  -- always set to 3
+///     originator [0] EXPLICIT OriginatorIdentifierOrKey,
+///     ukm [1] EXPLICIT UserKeyingMaterial OPTIONAL,
+///     keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///     recipientEncryptedKeys RecipientEncryptedKeys }
+///
+/// UserKeyingMaterial ::= OCTET STRING
+///
+/// RecipientEncryptedKeys ::= SEQUENCE OF RecipientEncryptedKey
+/// ```
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct KeyAgreeRecipientInfo<'a> {
+    pub version: CmsVersion,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
+    pub originator: OriginatorIdentifierOrKey<'a>,
+    #[asn1(context_specific = "1", tag_mode = "EXPLICIT", optional = "true")]
+    pub ukm: Option<OctetString<'a>>,
+    pub key_enc_alg: AlgorithmIdentifier<'a>,
+    pub recipient_enc_keys: Vec<RecipientEncryptedKey<'a>>,
+}
+
+/// `OriginatorIdentifierOrKey` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// OriginatorIdentifierOrKey ::= CHOICE {
+///     issuerAndSerialNumber IssuerAndSerialNumber,
+///     subjectKeyIdentifier [0] SubjectKeyIdentifier,
+///     originatorKey [1] OriginatorPublicKey }
+/// ```
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum OriginatorIdentifierOrKey<'a> {
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    SubjectKeyIdentifier(SubjectKeyIdentifier<'a>),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    OriginatorKey(OriginatorPublicKey<'a>),
+}
+
+/// `OriginatorPublicKey` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// OriginatorPublicKey ::= SEQUENCE {
+///     algorithm AlgorithmIdentifier,
+///     publicKey BIT STRING }
+/// ```
+///
+/// This is typically the sender's ephemeral public key.
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OriginatorPublicKey<'a> {
+    pub algorithm: AlgorithmIdentifier<'a>,
+    pub public_key: BitString<'a>,
+}
+
+/// `RecipientEncryptedKey` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// RecipientEncryptedKey ::= SEQUENCE {
+///     rid KeyAgreeRecipientIdentifier,
+///     encryptedKey EncryptedKey }
+/// ```
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct RecipientEncryptedKey<'a> {
+    pub rid: KeyAgreeRecipientIdentifier<'a>,
+    pub enc_key: OctetString<'a>,
+}
+
+/// `KeyAgreeRecipientIdentifier` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// KeyAgreeRecipientIdentifier ::= CHOICE {
+///     issuerAndSerialNumber IssuerAndSerialNumber,
+///     rKeyId [0] IMPLICIT RecipientKeyIdentifier }
+/// ```
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum KeyAgreeRecipientIdentifier<'a> {
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", constructed = "true")]
+    RKeyId(RecipientKeyIdentifier<'a>),
+}
+
+/// `RecipientKeyIdentifier` as defined in [RFC 5652 Section 6.2.2].
+///
+/// ```text
+/// RecipientKeyIdentifier ::= SEQUENCE {
+///     subjectKeyIdentifier SubjectKeyIdentifier,
+///     date GeneralizedTime OPTIONAL,
+///     other OtherKeyAttribute OPTIONAL }
+/// ```
+///
+/// [RFC 5652 Section 6.2.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct RecipientKeyIdentifier<'a> {
+    pub subject_key_identifier: SubjectKeyIdentifier<'a>,
+    pub date: Option<GeneralizedTime>,
+    pub other: Option<OtherKeyAttribute<'a>>,
+}
+
+/// `KEKRecipientInfo` as defined in [RFC 5652 Section 6.2.3].
+///
+/// ```text
+/// KEKRecipientInfo ::= SEQUENCE {
+///     version CMSVersion,  -- always set to 4
+///     kekid KEKIdentifier,
+///     keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///     encryptedKey EncryptedKey }
+/// ```
+///
+/// [RFC 5652 Section 6.2.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct KekRecipientInfo<'a> {
+    pub version: CmsVersion,
+    pub kek_id: KekIdentifier<'a>,
+    pub key_enc_alg: AlgorithmIdentifier<'a>,
+    pub enc_key: OctetString<'a>,
+}
+
+/// `KEKIdentifier` as defined in [RFC 5652 Section 6.2.3].
+///
+/// ```text
+/// KEKIdentifier ::= SEQUENCE {
+///     keyIdentifier OCTET STRING,
+///     date GeneralizedTime OPTIONAL,
+///     other OtherKeyAttribute OPTIONAL }
+/// ```
+///
+/// [RFC 5652 Section 6.2.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct KekIdentifier<'a> {
+    pub key_identifier: OctetString<'a>,
+    pub date: Option<GeneralizedTime>,
+    pub other: Option<OtherKeyAttribute<'a>>,
+}
+
+/// `PasswordRecipientInfo` as defined in [RFC 5652 Section 6.2.4].
+///
+/// ```text
+/// PasswordRecipientInfo ::= SEQUENCE {
+///     version CMSVersion,   -- always set to 0
+///     keyDerivationAlgorithm [0] KeyDerivationAlgorithmIdentifier
+///                                OPTIONAL,
+///     keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///     encryptedKey EncryptedKey }
+/// ```
+///
+/// [RFC 5652 Section 6.2.4]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct PasswordRecipientInfo<'a> {
+    pub version: CmsVersion,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub key_derivation_alg: Option<AlgorithmIdentifier<'a>>,
+    pub key_enc_alg: AlgorithmIdentifier<'a>,
+    pub enc_key: OctetString<'a>,
+}
+
+/// `OtherRecipientInfo` as defined in [RFC 5652 Section 6.2.5].
+///
+/// ```text
+/// OtherRecipientInfo ::= SEQUENCE {
+///     oriType OBJECT IDENTIFIER,
+///     oriValue ANY DEFINED BY oriType }
+/// ```
+///
+/// [RFC 5652 Section 6.2.5]: https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.5
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OtherRecipientInfo<'a> {
+    pub ori_type: ObjectIdentifier,
+    pub ori_value: Any<'a>,
+}
+
+/// `OtherKeyAttribute` as defined in [RFC 5652 Section 10.2.7].
+///
+/// ```text
+/// OtherKeyAttribute ::= SEQUENCE {
+///     keyAttrId OBJECT IDENTIFIER,
+///     keyAttr ANY DEFINED BY keyAttrId OPTIONAL }
+/// ```
+///
+/// [RFC 5652 Section 10.2.7]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.7
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OtherKeyAttribute<'a> {
+    pub key_attr_id: ObjectIdentifier,
+    pub key_attr: Option<Any<'a>>,
+}
@@ -0,0 +1,64 @@
+//! Error types
+
+use core::fmt;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// The signed attributes are missing the content type or message digest
+    /// attributes, or they're malformed.
+    AttributesInvalid,
+
+    /// Compressing or decompressing the content failed, or the compression
+    /// algorithm isn't supported.
+    Compression,
+
+    /// The message digest in the signed attributes doesn't match the content.
+    DigestMismatch,
+
+    /// The S/MIME entity is malformed, or its `smime-type` doesn't match its
+    /// content.
+    Mime,
+
+    /// Signing failed, or the signature is invalid.
+    Signature,
+
+    /// None of the signers match the given certificate.
+    SignerNotFound,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "CMS ASN.1 error: {}", err),
+            Error::AttributesInvalid => f.write_str("CMS signed attributes invalid"),
+            Error::Compression => f.write_str("CMS compression error"),
+            Error::DigestMismatch => f.write_str("CMS message digest mismatch"),
+            Error::Mime => f.write_str("CMS S/MIME entity invalid"),
+            Error::Signature => f.write_str("CMS signature error"),
+            Error::SignerNotFound => f.write_str("CMS signer not found"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
@@ -0,0 +1,87 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/cms/0.1.0-pre"
+)]
+#![forbid(unsafe_code, clippy::unwrap_used)]
+#![warn(
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Implement the DER traits for a newtype around a `Vec` which is encoded as
+/// a `SET OF`.
+///
+/// Unlike [`der::asn1::SetOfVec`], elements are kept in the order they were
+/// decoded (or added) in, since CMS producers don't always sort them, and
+/// their order can be significant (e.g. certificate chains).
+macro_rules! impl_set_of {
+    ($set:ident) => {
+        impl ::der::FixedTag for $set<'_> {
+            const TAG: ::der::Tag = ::der::Tag::Set;
+        }
+
+        impl<'a> ::der::DecodeValue<'a> for $set<'a> {
+            fn decode_value(
+                decoder: &mut ::der::Decoder<'a>,
+                header: ::der::Header,
+            ) -> ::der::Result<Self> {
+                ::der::DecodeValue::decode_value(decoder, header).map(Self)
+            }
+        }
+
+        impl ::der::EncodeValue for $set<'_> {
+            fn value_len(&self) -> ::der::Result<::der::Length> {
+                ::der::EncodeValue::value_len(&self.0)
+            }
+
+            fn encode_value(&self, writer: &mut dyn ::der::Writer) -> ::der::Result<()> {
+                ::der::EncodeValue::encode_value(&self.0, writer)
+            }
+        }
+    };
+}
+
+pub mod auth_enveloped_data;
+pub mod cert;
+pub mod certs_only;
+pub mod compressed_data;
+pub mod content_info;
+#[cfg(feature = "sign")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+pub mod detached;
+pub mod digested_data;
+pub mod encrypted_data;
+pub mod enveloped_data;
+pub mod revocation;
+pub mod signed_attributes;
+pub mod signed_data;
+#[cfg(feature = "smime")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smime")))]
+pub mod smime;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod stream;
+pub mod timestamp;
+
+mod error;
+
+pub use crate::{
+    content_info::{CmsVersion, ContentInfo},
+    error::{Error, Result},
+};
+pub use der;
+pub use x509_cert;
+
+#[cfg(feature = "sign")]
+pub use {digest, signature};
@@ -0,0 +1,57 @@
+//! Revocation information types as defined in [RFC 5652 Section 10.2.1].
+//!
+//! [RFC 5652 Section 10.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1
+
+use alloc::vec::Vec;
+
+use der::asn1::{Any, ObjectIdentifier};
+use der::{Choice, Sequence};
+use x509_cert::crl::CertificateList;
+
+/// `RevocationInfoChoices` as defined in [RFC 5652 Section 10.2.1].
+///
+/// ```text
+/// RevocationInfoChoices ::= SET OF RevocationInfoChoice
+/// ```
+///
+/// [RFC 5652 Section 10.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RevocationInfoChoices<'a>(pub Vec<RevocationInfoChoice<'a>>);
+
+impl_set_of!(RevocationInfoChoices);
+
+/// `RevocationInfoChoice` as defined in [RFC 5652 Section 10.2.1].
+///
+/// ```text
+/// RevocationInfoChoice ::= CHOICE {
+///     crl CertificateList,
+///     ...,
+///     [[5: other [1] IMPLICIT OtherRevocationInfoFormat ]] }
+/// ```
+///
+/// [RFC 5652 Section 10.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(clippy::large_enum_variant)]
+#[allow(missing_docs)]
+pub enum RevocationInfoChoice<'a> {
+    Crl(CertificateList<'a>),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Other(OtherRevocationInfoFormat<'a>),
+}
+
+/// `OtherRevocationInfoFormat` as defined in [RFC 5652 Section 10.2.1].
+///
+/// ```text
+/// OtherRevocationInfoFormat ::= SEQUENCE {
+///     otherRevInfoFormat OBJECT IDENTIFIER,
+///     otherRevInfo ANY DEFINED BY otherRevInfoFormat }
+/// ```
+///
+/// [RFC 5652 Section 10.2.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OtherRevocationInfoFormat<'a> {
+    pub other_rev_info_format: ObjectIdentifier,
+    pub other_rev_info: Any<'a>,
+}
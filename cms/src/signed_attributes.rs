@@ -0,0 +1,236 @@
+//! Signed attributes of a [`SignerInfo`] as described in
+//! [RFC 5652 Section 5.3], and the standard attributes defined in
+//! [RFC 5652 Section 11] and [RFC 5035 Section 5.4].
+//!
+//! When present, the signature of a [`SignerInfo`] is computed over the DER
+//! encoding of its signed attributes, which must be a correctly ordered
+//! `SET OF`. [`SignedAttributesBuilder`] takes care of this.
+//!
+//! [`SignerInfo`]: crate::signed_data::SignerInfo
+//! [RFC 5652 Section 5.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.3
+//! [RFC 5652 Section 11]: https://datatracker.ietf.org/doc/html/rfc5652#section-11
+//! [RFC 5035 Section 5.4]: https://datatracker.ietf.org/doc/html/rfc5035#section-5.4
+
+use crate::{Error, Result};
+
+use alloc::vec::Vec;
+
+use const_oid::db::rfc5911::{
+    ID_AA_SIGNING_CERTIFICATE_V_2, ID_CONTENT_TYPE, ID_MESSAGE_DIGEST, ID_SIGNING_TIME,
+};
+use const_oid::db::rfc5912::ID_SHA_256;
+use der::asn1::{
+    Any, GeneralizedTime, ObjectIdentifier, OctetString, SetOfVec, UIntBytes, UtcTime,
+};
+use der::{DateTime, Decode, Encode, Sequence};
+use spki::AlgorithmIdentifier;
+use x509_cert::attr::{Attribute, Attributes};
+use x509_cert::ext::pkix::certpolicy::PolicyInformation;
+use x509_cert::ext::pkix::name::GeneralNames;
+use x509_cert::time::Time;
+
+#[cfg(feature = "sign")]
+use {
+    alloc::vec,
+    digest::Digest,
+    x509_cert::{ext::pkix::name::GeneralName, Certificate},
+};
+
+/// `SigningCertificateV2` as defined in [RFC 5035 Section 5.4].
+///
+/// ```text
+/// SigningCertificateV2 ::=  SEQUENCE {
+///     certs        SEQUENCE OF ESSCertIDv2,
+///     policies     SEQUENCE OF PolicyInformation OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 5035 Section 5.4]: https://datatracker.ietf.org/doc/html/rfc5035#section-5.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SigningCertificateV2<'a> {
+    pub certs: Vec<EssCertIdV2<'a>>,
+    pub policies: Option<Vec<PolicyInformation<'a>>>,
+}
+
+/// `ESSCertIDv2` as defined in [RFC 5035 Section 5.4].
+///
+/// ```text
+/// ESSCertIDv2 ::=  SEQUENCE {
+///     hashAlgorithm           AlgorithmIdentifier
+///            DEFAULT {algorithm id-sha256},
+///     certHash                 Hash,
+///     issuerSerial             IssuerSerial OPTIONAL
+/// }
+///
+/// Hash ::= OCTET STRING
+/// ```
+///
+/// The `certHash` is computed over the DER encoding of the certificate.
+///
+/// [RFC 5035 Section 5.4]: https://datatracker.ietf.org/doc/html/rfc5035#section-5.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EssCertIdV2<'a> {
+    #[asn1(default = "default_hash_algorithm")]
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    pub cert_hash: OctetString<'a>,
+    pub issuer_serial: Option<IssuerSerial<'a>>,
+}
+
+/// `IssuerSerial` as defined in [RFC 5035 Section 5.4].
+///
+/// ```text
+/// IssuerSerial ::= SEQUENCE {
+///     issuer                   GeneralNames,
+///     serialNumber             CertificateSerialNumber
+/// }
+/// ```
+///
+/// [RFC 5035 Section 5.4]: https://datatracker.ietf.org/doc/html/rfc5035#section-5.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct IssuerSerial<'a> {
+    pub issuer: GeneralNames<'a>,
+    pub serial_number: UIntBytes<'a>,
+}
+
+/// Default `hashAlgorithm` of an [`EssCertIdV2`].
+fn default_hash_algorithm<'a>() -> AlgorithmIdentifier<'a> {
+    AlgorithmIdentifier {
+        oid: ID_SHA_256,
+        parameters: None,
+    }
+}
+
+/// Builder for the signed attributes of a [`SignerInfo`].
+///
+/// Attribute values are DER encoded as they are added, and [`build`]
+/// assembles them into a `SET OF` sorted as required by DER, whose encoding
+/// is the input to the signature.
+///
+/// The `contentType` and `messageDigest` attributes, which are required
+/// whenever signed attributes are present, must be added before building.
+///
+/// [`SignerInfo`]: crate::signed_data::SignerInfo
+/// [`build`]: SignedAttributesBuilder::build
+#[derive(Clone, Debug, Default)]
+pub struct SignedAttributesBuilder {
+    /// Attribute types and the DER encodings of their values.
+    attrs: Vec<(ObjectIdentifier, Vec<u8>)>,
+}
+
+impl SignedAttributesBuilder {
+    /// Create a new builder without any attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the `contentType` attribute, identifying the type of the signed
+    /// content.
+    pub fn content_type(&mut self, content_type: ObjectIdentifier) -> Result<&mut Self> {
+        self.attribute(ID_CONTENT_TYPE, &content_type)
+    }
+
+    /// Add the `messageDigest` attribute, containing the digest of the
+    /// signed content.
+    pub fn message_digest(&mut self, digest: &[u8]) -> Result<&mut Self> {
+        self.attribute(ID_MESSAGE_DIGEST, &OctetString::new(digest)?)
+    }
+
+    /// Add the `signingTime` attribute.
+    ///
+    /// As required by [RFC 5652 Section 11.3], times from 1950 through 2049
+    /// are encoded as `UTCTime`, and other times as `GeneralizedTime`.
+    ///
+    /// [RFC 5652 Section 11.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-11.3
+    pub fn signing_time(&mut self, time: DateTime) -> Result<&mut Self> {
+        let time = if (1950..=2049).contains(&time.year()) {
+            Time::UtcTime(UtcTime::from_date_time(time)?)
+        } else {
+            Time::GeneralTime(GeneralizedTime::from_date_time(time))
+        };
+
+        self.attribute(ID_SIGNING_TIME, &time)
+    }
+
+    /// Add the `signingCertificateV2` attribute.
+    pub fn signing_certificate_v2(
+        &mut self,
+        signing_certificate: &SigningCertificateV2<'_>,
+    ) -> Result<&mut Self> {
+        self.attribute(ID_AA_SIGNING_CERTIFICATE_V_2, signing_certificate)
+    }
+
+    /// Add the `signingCertificateV2` attribute, identifying `cert` by its
+    /// hash and its issuer and serial number.
+    ///
+    /// `hash_algorithm` must identify `D`.
+    #[cfg(feature = "sign")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+    pub fn signing_certificate<D: Digest>(
+        &mut self,
+        cert: &Certificate<'_>,
+        hash_algorithm: AlgorithmIdentifier<'_>,
+    ) -> Result<&mut Self> {
+        let cert_hash = D::digest(cert.to_vec()?);
+
+        self.signing_certificate_v2(&SigningCertificateV2 {
+            certs: vec![EssCertIdV2 {
+                hash_algorithm,
+                cert_hash: OctetString::new(&cert_hash)?,
+                issuer_serial: Some(IssuerSerial {
+                    issuer: vec![GeneralName::DirectoryName(
+                        cert.tbs_certificate.issuer.clone(),
+                    )],
+                    serial_number: cert.tbs_certificate.serial_number,
+                }),
+            }],
+            policies: None,
+        })
+    }
+
+    /// Add an attribute with the given type and single value, replacing any
+    /// attribute of the same type which was previously added.
+    pub fn attribute(&mut self, oid: ObjectIdentifier, value: &impl Encode) -> Result<&mut Self> {
+        let value = value.to_vec()?;
+
+        match self.attrs.iter_mut().find(|(attr_oid, _)| *attr_oid == oid) {
+            Some((_, attr_value)) => *attr_value = value,
+            None => self.attrs.push((oid, value)),
+        }
+
+        Ok(self)
+    }
+
+    /// Build the signed attributes.
+    ///
+    /// Returns [`Error::AttributesInvalid`] if the `contentType` or
+    /// `messageDigest` attribute is missing.
+    pub fn build(&self) -> Result<Attributes<'_>> {
+        for oid in [ID_CONTENT_TYPE, ID_MESSAGE_DIGEST] {
+            if !self.attrs.iter().any(|(attr_oid, _)| *attr_oid == oid) {
+                return Err(Error::AttributesInvalid);
+            }
+        }
+
+        let attrs = self
+            .attrs
+            .iter()
+            .map(|(oid, value)| {
+                Ok(Attribute {
+                    oid: *oid,
+                    values: SetOfVec::try_from([Any::from_der(value)?])?,
+                })
+            })
+            .collect::<der::Result<Vec<_>>>()?;
+
+        Ok(attrs.try_into()?)
+    }
+
+    /// Build the signed attributes, returning their DER encoding, which is
+    /// the input to the signature.
+    pub fn to_der(&self) -> Result<Vec<u8>> {
+        Ok(self.build()?.to_vec()?)
+    }
+}
@@ -0,0 +1,205 @@
+//! `SignedData` content type as defined in [RFC 5652 Section 5].
+//!
+//! [RFC 5652 Section 5]: https://datatracker.ietf.org/doc/html/rfc5652#section-5
+
+use crate::cert::{CertificateChoices, CertificateSet, IssuerAndSerialNumber};
+use crate::content_info::{CmsVersion, ContentInfo};
+use crate::revocation::RevocationInfoChoices;
+
+use alloc::vec::Vec;
+
+use const_oid::db::rfc5911::{ID_DATA, ID_SIGNED_DATA};
+use der::asn1::{ObjectIdentifier, OctetString, SetOfVec};
+use der::{Choice, Decode, Error, Sequence};
+use spki::AlgorithmIdentifier;
+use x509_cert::attr::Attributes;
+use x509_cert::ext::pkix::SubjectKeyIdentifier;
+use x509_cert::Certificate;
+
+/// `SignedData` as defined in [RFC 5652 Section 5.1].
+///
+/// ```text
+/// SignedData ::= SEQUENCE {
+///     version CMSVersion,
+///     digestAlgorithms DigestAlgorithmIdentifiers,
+///     encapContentInfo EncapsulatedContentInfo,
+///     certificates [0] IMPLICIT CertificateSet OPTIONAL,
+///     crls [1] IMPLICIT RevocationInfoChoices OPTIONAL,
+///     signerInfos SignerInfos }
+/// ```
+///
+/// [RFC 5652 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SignedData<'a> {
+    pub version: CmsVersion,
+    pub digest_algorithms: DigestAlgorithmIdentifiers<'a>,
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub certificates: Option<CertificateSet<'a>>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub crls: Option<RevocationInfoChoices<'a>>,
+    pub signer_infos: SignerInfos<'a>,
+}
+
+impl<'a> SignedData<'a> {
+    /// Create a degenerate "certs-only" `SignedData`, as used by `.p7b` and
+    /// `.p7c` files to distribute certificates, which has no signers and no
+    /// encapsulated content.
+    ///
+    /// Certificates are encoded in the order they're given in.
+    pub fn new_certs_only(certificates: impl IntoIterator<Item = Certificate<'a>>) -> Self {
+        Self {
+            version: CmsVersion::V1,
+            digest_algorithms: DigestAlgorithmIdentifiers::new(),
+            encap_content_info: EncapsulatedContentInfo {
+                econtent_type: ID_DATA,
+                econtent: None,
+            },
+            certificates: Some(CertificateSet(
+                certificates
+                    .into_iter()
+                    .map(CertificateChoices::Certificate)
+                    .collect(),
+            )),
+            crls: None,
+            signer_infos: SignerInfos::default(),
+        }
+    }
+
+    /// Is this a degenerate "certs-only" `SignedData`, i.e. one without
+    /// signers or encapsulated content?
+    pub fn is_certs_only(&self) -> bool {
+        self.signer_infos.0.is_empty() && self.encap_content_info.econtent.is_none()
+    }
+
+    /// Iterate over the X.509 certificates in this `SignedData`, skipping
+    /// certificates in other formats.
+    pub fn x509_certificates(&self) -> impl Iterator<Item = &Certificate<'a>> {
+        self.certificates
+            .iter()
+            .flat_map(|certs| certs.0.iter())
+            .filter_map(|cert| match cert {
+                CertificateChoices::Certificate(cert) => Some(cert),
+                CertificateChoices::Other(_) => None,
+            })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SignedData<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&ContentInfo<'a>> for SignedData<'a> {
+    type Error = Error;
+
+    /// Decode the `SignedData` contained in the given [`ContentInfo`],
+    /// checking that its content type is `id-signedData`.
+    fn try_from(content_info: &ContentInfo<'a>) -> Result<Self, Self::Error> {
+        content_info.decode_content(ID_SIGNED_DATA)
+    }
+}
+
+/// `DigestAlgorithmIdentifiers` as defined in [RFC 5652 Section 5.1].
+///
+/// ```text
+/// DigestAlgorithmIdentifiers ::= SET OF DigestAlgorithmIdentifier
+/// ```
+///
+/// [RFC 5652 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.1
+pub type DigestAlgorithmIdentifiers<'a> = SetOfVec<AlgorithmIdentifier<'a>>;
+
+/// `SignerInfos` as defined in [RFC 5652 Section 5.1].
+///
+/// ```text
+/// SignerInfos ::= SET OF SignerInfo
+/// ```
+///
+/// [RFC 5652 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.1
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignerInfos<'a>(pub Vec<SignerInfo<'a>>);
+
+impl_set_of!(SignerInfos);
+
+/// `EncapsulatedContentInfo` as defined in [RFC 5652 Section 5.2].
+///
+/// ```text
+/// EncapsulatedContentInfo ::= SEQUENCE {
+///     eContentType ContentType,
+///     eContent [0] EXPLICIT OCTET STRING OPTIONAL }
+/// ```
+///
+/// The `eContent` is absent when the signature is detached.
+///
+/// Encapsulated content is borrowed from the encoded message when decoded
+/// from memory. Content which is too large for this can be streamed from
+/// the message with `stream::ContentReader` (with the `std` feature).
+///
+/// [RFC 5652 Section 5.2]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct EncapsulatedContentInfo<'a> {
+    pub econtent_type: ObjectIdentifier,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub econtent: Option<OctetString<'a>>,
+}
+
+/// `SignerInfo` as defined in [RFC 5652 Section 5.3].
+///
+/// ```text
+/// SignerInfo ::= SEQUENCE {
+///     version CMSVersion,
+///     sid SignerIdentifier,
+///     digestAlgorithm DigestAlgorithmIdentifier,
+///     signedAttrs [0] IMPLICIT SignedAttributes OPTIONAL,
+///     signatureAlgorithm SignatureAlgorithmIdentifier,
+///     signature SignatureValue,
+///     unsignedAttrs [1] IMPLICIT UnsignedAttributes OPTIONAL }
+///
+/// SignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// UnsignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// SignatureValue ::= OCTET STRING
+/// ```
+///
+/// [RFC 5652 Section 5.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SignerInfo<'a> {
+    pub version: CmsVersion,
+    pub sid: SignerIdentifier<'a>,
+    pub digest_alg: AlgorithmIdentifier<'a>,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub signed_attrs: Option<Attributes<'a>>,
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+    pub signature: OctetString<'a>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub unsigned_attrs: Option<Attributes<'a>>,
+}
+
+/// `SignerIdentifier` as defined in [RFC 5652 Section 5.3].
+///
+/// ```text
+/// SignerIdentifier ::= CHOICE {
+///     issuerAndSerialNumber IssuerAndSerialNumber,
+///     subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+///
+/// When the `subjectKeyIdentifier` alternative is used, the
+/// [`SignerInfo::version`] must be [`CmsVersion::V3`] rather than
+/// [`CmsVersion::V1`].
+///
+/// [RFC 5652 Section 5.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.3
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum SignerIdentifier<'a> {
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    SubjectKeyIdentifier(SubjectKeyIdentifier<'a>),
+}
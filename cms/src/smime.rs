@@ -0,0 +1,250 @@
+//! S/MIME `application/pkcs7-mime` entities as described in
+//! [RFC 8551 Section 3.2].
+//!
+//! These are MIME entities whose body is the base64 encoding of a DER encoded
+//! `ContentInfo`, and whose `smime-type` parameter identifies its content
+//! type, e.g.:
+//!
+//! ```text
+//! Content-Type: application/pkcs7-mime; smime-type=enveloped-data; name="smime.p7m"
+//! Content-Transfer-Encoding: base64
+//! Content-Disposition: attachment; filename="smime.p7m"
+//!
+//! MIIB5QYJKoZIhvcNAQcDoIIB1jCCAdICAQAxggGNMIIBiQIBADBxMGQxFDASBgNV...
+//! ```
+//!
+//! [RFC 8551 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc8551#section-3.2
+
+use crate::content_info::ContentInfo;
+use crate::signed_data::SignedData;
+use crate::{Error, Result};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use base64ct::{Base64, Encoding};
+use const_oid::db::{
+    rfc5911::{ID_CT_AUTH_ENVELOPED_DATA, ID_ENVELOPED_DATA, ID_SIGNED_DATA},
+    rfc6268::ID_CT_COMPRESSED_DATA,
+};
+use der::Decode;
+
+/// Maximum length of a line of base64 in the body, per [RFC 2045 Section 6.8].
+///
+/// [RFC 2045 Section 6.8]: https://datatracker.ietf.org/doc/html/rfc2045#section-6.8
+const LINE_WIDTH: usize = 76;
+
+/// Media type of S/MIME entities.
+const MEDIA_TYPE: &str = "application/pkcs7-mime";
+
+/// Media type used by older S/MIME implementations.
+const LEGACY_MEDIA_TYPE: &str = "application/x-pkcs7-mime";
+
+/// `smime-type` parameter of an `application/pkcs7-mime` entity, as defined
+/// in [RFC 8551 Section 3.2.2].
+///
+/// [RFC 8551 Section 3.2.2]: https://datatracker.ietf.org/doc/html/rfc8551#section-3.2.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SmimeType {
+    /// `authEnveloped-data`: an `AuthEnvelopedData`.
+    AuthEnvelopedData,
+
+    /// `certs-only`: a degenerate `SignedData` containing only certificates
+    /// and/or CRLs.
+    CertsOnly,
+
+    /// `compressed-data`: a `CompressedData`.
+    CompressedData,
+
+    /// `enveloped-data`: an `EnvelopedData`.
+    EnvelopedData,
+
+    /// `signed-data`: a `SignedData` with encapsulated content.
+    SignedData,
+}
+
+impl SmimeType {
+    /// Determine the `smime-type` of the given `ContentInfo`.
+    ///
+    /// Returns [`Error::Mime`] if S/MIME doesn't define a type for its
+    /// content type.
+    pub fn from_content_info(content_info: &ContentInfo<'_>) -> Result<Self> {
+        match content_info.content_type {
+            ID_CT_AUTH_ENVELOPED_DATA => Ok(Self::AuthEnvelopedData),
+            ID_CT_COMPRESSED_DATA => Ok(Self::CompressedData),
+            ID_ENVELOPED_DATA => Ok(Self::EnvelopedData),
+            ID_SIGNED_DATA => {
+                if SignedData::try_from(content_info)?.is_certs_only() {
+                    Ok(Self::CertsOnly)
+                } else {
+                    Ok(Self::SignedData)
+                }
+            }
+            _ => Err(Error::Mime),
+        }
+    }
+
+    /// Get the `smime-type` parameter value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AuthEnvelopedData => "authEnveloped-data",
+            Self::CertsOnly => "certs-only",
+            Self::CompressedData => "compressed-data",
+            Self::EnvelopedData => "enveloped-data",
+            Self::SignedData => "signed-data",
+        }
+    }
+
+    /// Get the conventional file name for entities of this type, per
+    /// [RFC 8551 Section 3.2.1].
+    ///
+    /// [RFC 8551 Section 3.2.1]: https://datatracker.ietf.org/doc/html/rfc8551#section-3.2.1
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::CertsOnly => "smime.p7c",
+            Self::CompressedData => "smime.p7z",
+            _ => "smime.p7m",
+        }
+    }
+}
+
+impl FromStr for SmimeType {
+    type Err = Error;
+
+    /// Parse an `smime-type` parameter value, ignoring case.
+    fn from_str(s: &str) -> Result<Self> {
+        [
+            Self::AuthEnvelopedData,
+            Self::CertsOnly,
+            Self::CompressedData,
+            Self::EnvelopedData,
+            Self::SignedData,
+        ]
+        .into_iter()
+        .find(|smime_type| smime_type.as_str().eq_ignore_ascii_case(s))
+        .ok_or(Error::Mime)
+    }
+}
+
+impl fmt::Display for SmimeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Wrap the DER encoding of a `ContentInfo` in an `application/pkcs7-mime`
+/// entity, with its `smime-type` determined by
+/// [`SmimeType::from_content_info`].
+///
+/// The entity has `Content-Type`, `Content-Transfer-Encoding` and
+/// `Content-Disposition` headers, and uses CRLF line endings. No
+/// `MIME-Version` header is added, since the entity may be embedded in a
+/// larger message.
+pub fn wrap(der: &[u8]) -> Result<String> {
+    let smime_type = SmimeType::from_content_info(&ContentInfo::from_der(der)?)?;
+    let file_name = smime_type.file_name();
+
+    let mut entity = alloc::format!(
+        "Content-Type: {}; smime-type={}; name=\"{}\"\r\n\
+         Content-Transfer-Encoding: base64\r\n\
+         Content-Disposition: attachment; filename=\"{}\"\r\n\
+         \r\n",
+        MEDIA_TYPE,
+        smime_type,
+        file_name,
+        file_name
+    );
+
+    let body = Base64::encode_string(der);
+    for line in body.as_bytes().chunks(LINE_WIDTH) {
+        // Base64 is ASCII, so lines are always valid UTF-8
+        entity.extend(line.iter().map(|&b| char::from(b)));
+        entity.push_str("\r\n");
+    }
+
+    Ok(entity)
+}
+
+/// Unwrap an `application/pkcs7-mime` entity, returning the DER encoding of
+/// the `ContentInfo` in its body.
+///
+/// The entity must have a `Content-Type` of `application/pkcs7-mime` (or
+/// the legacy `application/x-pkcs7-mime`) and a `Content-Transfer-Encoding`
+/// of `base64`. If present, the `smime-type` must match the content, except
+/// that `signed-data` is also accepted for certs-only `SignedData`. Either
+/// CRLF or LF line endings may be used, and other headers are ignored.
+pub fn unwrap(entity: &str) -> Result<Vec<u8>> {
+    let (headers, body) = split_entity(entity)?;
+
+    let mut content_type = None;
+    let mut transfer_encoding = None;
+
+    for (name, value) in headers {
+        if name.eq_ignore_ascii_case("Content-Type") {
+            content_type = Some(value);
+        } else if name.eq_ignore_ascii_case("Content-Transfer-Encoding") {
+            transfer_encoding = Some(value);
+        }
+    }
+
+    let content_type = content_type.ok_or(Error::Mime)?;
+    let mut params = content_type.split(';');
+    let media_type = params.next().unwrap_or_default().trim();
+
+    if !media_type.eq_ignore_ascii_case(MEDIA_TYPE)
+        && !media_type.eq_ignore_ascii_case(LEGACY_MEDIA_TYPE)
+    {
+        return Err(Error::Mime);
+    }
+
+    match transfer_encoding {
+        Some(encoding) if encoding.trim().eq_ignore_ascii_case("base64") => (),
+        _ => return Err(Error::Mime),
+    }
+
+    let smime_type = params
+        .filter_map(|param| param.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("smime-type"))
+        .map(|(_, value)| value.trim().trim_matches('"').parse::<SmimeType>())
+        .transpose()?;
+
+    let base64: String = body.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    let der = Base64::decode_vec(&base64).map_err(|_| Error::Mime)?;
+    let actual = SmimeType::from_content_info(&ContentInfo::from_der(&der)?)?;
+
+    match (smime_type, actual) {
+        (None, _) | (Some(SmimeType::SignedData), SmimeType::CertsOnly) => Ok(der),
+        (Some(expected), actual) if expected == actual => Ok(der),
+        _ => Err(Error::Mime),
+    }
+}
+
+/// Split an entity into its (unfolded) header fields and its body.
+fn split_entity(entity: &str) -> Result<(Vec<(&str, String)>, &str)> {
+    let mut headers: Vec<(&str, String)> = Vec::new();
+    let mut rest = entity;
+
+    loop {
+        let (line, next) = match rest.find('\n') {
+            Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+            None => return Err(Error::Mime),
+        };
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        rest = next;
+
+        if line.is_empty() {
+            return Ok((headers, rest));
+        }
+
+        if line.starts_with(|c| c == ' ' || c == '\t') {
+            // Continuation of a folded header field
+            let (_, value) = headers.last_mut().ok_or(Error::Mime)?;
+            value.push_str(line);
+        } else {
+            let (name, value) = line.split_once(':').ok_or(Error::Mime)?;
+            headers.push((name.trim(), value.trim().into()));
+        }
+    }
+}
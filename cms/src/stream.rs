@@ -0,0 +1,342 @@
+//! Streaming decoding of the encapsulated content of a `SignedData` and the
+//! encrypted content of an `EnvelopedData`.
+//!
+//! The rest of these messages is small enough to hold in memory, but their
+//! content may not be. [`ContentReader`] reads a DER-encoded `ContentInfo`
+//! from an [`io::Read`], and provides its content as an [`io::Read`] itself,
+//! so it can be copied in chunks into a sink such as a file or a digest.
+//!
+//! Once the content has been read, the remainder of the message is returned
+//! without the content, as a `ContentInfo` which can be decoded in the same
+//! way as a detached signature. A signature over encapsulated content can
+//! therefore be verified by streaming the content into a digest and passing
+//! the result to `detached::verify_digest`.
+
+use crate::content_info::ContentInfo;
+use crate::enveloped_data::EnvelopedData;
+use crate::signed_data::SignedData;
+use crate::Result;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+
+use const_oid::db::rfc5911::{ID_ENVELOPED_DATA, ID_SIGNED_DATA};
+use der::asn1::{Any, ObjectIdentifier};
+use der::{Decode, Encode, Header, Length, Tag, TagNumber};
+
+#[cfg(feature = "sign")]
+use digest::Digest;
+
+/// Size of the chunks in which content is copied.
+const CHUNK_SIZE: usize = 8192;
+
+/// Streaming decoder for a `ContentInfo` containing a `SignedData` or an
+/// `EnvelopedData`.
+///
+/// Reading from a `ContentReader` yields the bytes of the `eContent` of the
+/// `SignedData`, or of the `encryptedContent` of the `EnvelopedData`, which
+/// are read from the underlying reader in chunks as they're requested. No
+/// bytes are yielded if the content is absent.
+///
+/// Only DER is supported: in particular, the message must not use
+/// indefinite lengths or a constructed `OCTET STRING` for the content.
+pub struct ContentReader<R> {
+    /// Underlying reader, positioned within the content
+    reader: R,
+
+    /// Type of the message
+    kind: Kind,
+
+    /// Type of the content
+    content_type: ObjectIdentifier,
+
+    /// Length of the content, if present
+    content_len: Option<usize>,
+
+    /// Length of the content which hasn't been read yet
+    remaining: usize,
+
+    /// Encoded fields of the `SignedData` or `EnvelopedData` preceding the
+    /// `EncapsulatedContentInfo` or `EncryptedContentInfo`
+    prefix: Vec<u8>,
+
+    /// Encoded fields of the `EncapsulatedContentInfo` or
+    /// `EncryptedContentInfo` preceding the content
+    info_prefix: Vec<u8>,
+
+    /// Length of the fields of the `SignedData` or `EnvelopedData` following
+    /// the `EncapsulatedContentInfo` or `EncryptedContentInfo`
+    suffix_len: usize,
+}
+
+/// Types of message supported by [`ContentReader`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    SignedData,
+    EnvelopedData,
+}
+
+impl Kind {
+    /// `ContentInfo` content type of this kind of message.
+    fn content_type(self) -> ObjectIdentifier {
+        match self {
+            Kind::SignedData => ID_SIGNED_DATA,
+            Kind::EnvelopedData => ID_ENVELOPED_DATA,
+        }
+    }
+}
+
+impl<R: Read> ContentReader<R> {
+    /// Start decoding a `ContentInfo` containing a `SignedData`, reading up
+    /// to the start of its `eContent`.
+    pub fn signed_data(reader: R) -> Result<Self> {
+        Self::new(reader, Kind::SignedData)
+    }
+
+    /// Start decoding a `ContentInfo` containing an `EnvelopedData`, reading
+    /// up to the start of its `encryptedContent`.
+    pub fn enveloped_data(reader: R) -> Result<Self> {
+        Self::new(reader, Kind::EnvelopedData)
+    }
+
+    fn new(mut reader: R, kind: Kind) -> Result<Self> {
+        // ContentInfo
+        let (header, _) = read_header(&mut reader, None)?;
+        header.tag.assert_eq(Tag::Sequence)?;
+        let mut remaining = usize::try_from(header.length)?;
+
+        let content_type = ObjectIdentifier::from_der(&read_tlv(&mut reader, &mut remaining)?)?;
+
+        if content_type != kind.content_type() {
+            return Err(Tag::ObjectIdentifier.value_error().into());
+        }
+
+        // [0] EXPLICIT, containing the `SignedData` or `EnvelopedData`
+        let (header, _) = read_header(&mut reader, Some(&mut remaining))?;
+        header.tag.assert_eq(Tag::ContextSpecific {
+            constructed: true,
+            number: TagNumber::N0,
+        })?;
+        check_len(header, remaining)?;
+
+        let (header, _) = read_header(&mut reader, Some(&mut remaining))?;
+        header.tag.assert_eq(Tag::Sequence)?;
+        check_len(header, remaining)?;
+
+        // The content info is the first `SEQUENCE` among the fields
+        let mut prefix = Vec::new();
+
+        let mut info_len = loop {
+            let (header, encoded) = read_header(&mut reader, Some(&mut remaining))?;
+            let len = usize::try_from(header.length)?;
+            consume(&mut remaining, len, header.tag)?;
+
+            if header.tag == Tag::Sequence {
+                break len;
+            }
+
+            prefix.extend_from_slice(&encoded);
+            read_value(&mut reader, len, &mut prefix)?;
+        };
+
+        // eContentType or contentType, followed by the
+        // contentEncryptionAlgorithm of an `EncryptedContentInfo`
+        let mut info_prefix = read_tlv(&mut reader, &mut info_len)?;
+        let content_type = ObjectIdentifier::from_der(&info_prefix)?;
+
+        if kind == Kind::EnvelopedData {
+            info_prefix.extend_from_slice(&read_tlv(&mut reader, &mut info_len)?);
+        }
+
+        let content_len = match info_len {
+            0 => None,
+            _ => Some(read_content_header(&mut reader, kind, info_len)?),
+        };
+
+        Ok(Self {
+            reader,
+            kind,
+            content_type,
+            content_len,
+            remaining: content_len.unwrap_or(0),
+            prefix,
+            info_prefix,
+            suffix_len: remaining,
+        })
+    }
+
+    /// Get the type of the content, i.e. the `eContentType` of the
+    /// `SignedData` or the `contentType` of the `EnvelopedData`.
+    pub fn content_type(&self) -> ObjectIdentifier {
+        self.content_type
+    }
+
+    /// Get the length of the content, or `None` if it's absent.
+    pub fn content_len(&self) -> Option<usize> {
+        self.content_len
+    }
+
+    /// Copy the rest of the content into the given writer in chunks.
+    pub fn copy_to(&mut self, writer: &mut impl Write) -> Result<()> {
+        io::copy(self, writer).map_err(der::Error::from)?;
+        Ok(())
+    }
+
+    /// Input the rest of the content into the given digest in chunks.
+    #[cfg(feature = "sign")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+    pub fn update_digest(&mut self, digest: &mut impl Digest) -> Result<()> {
+        let mut buf = [0u8; CHUNK_SIZE];
+
+        loop {
+            match self.read(&mut buf).map_err(der::Error::from)? {
+                0 => return Ok(()),
+                len => digest.update(&buf[..len]),
+            }
+        }
+    }
+
+    /// Finish decoding the message, skipping any content which hasn't been
+    /// read, and return the DER encoding of the `ContentInfo` without its
+    /// content.
+    ///
+    /// The rest of the message is checked to be well-formed. Any data
+    /// following the `ContentInfo` is left unread.
+    pub fn finish(mut self) -> Result<Vec<u8>> {
+        self.copy_to(&mut io::sink())?;
+
+        let mut body = self.prefix;
+        body.extend_from_slice(&Any::new(Tag::Sequence, &self.info_prefix)?.to_vec()?);
+        read_value(&mut self.reader, self.suffix_len, &mut body)?;
+
+        let der = ContentInfo::encode_content(
+            self.kind.content_type(),
+            &Any::new(Tag::Sequence, &body)?,
+        )?;
+        let content_info = ContentInfo::from_der(&der)?;
+
+        match self.kind {
+            Kind::SignedData => {
+                SignedData::try_from(&content_info)?;
+            }
+            Kind::EnvelopedData => {
+                EnvelopedData::try_from(&content_info)?;
+            }
+        }
+
+        Ok(der)
+    }
+}
+
+impl<R: Read> Read for ContentReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.remaining).min(CHUNK_SIZE);
+
+        if len == 0 {
+            return Ok(0);
+        }
+
+        match self.reader.read(&mut buf[..len])? {
+            0 => Err(io::ErrorKind::UnexpectedEof.into()),
+            n => {
+                self.remaining -= n;
+                Ok(n)
+            }
+        }
+    }
+}
+
+/// Read the header of the content, which must fill the rest of the content
+/// info, returning the length of the content.
+fn read_content_header(reader: &mut impl Read, kind: Kind, mut info_len: usize) -> Result<usize> {
+    let (header, _) = read_header(reader, Some(&mut info_len))?;
+
+    // eContent [0] EXPLICIT OCTET STRING, or
+    // encryptedContent [0] IMPLICIT OCTET STRING
+    header.tag.assert_eq(Tag::ContextSpecific {
+        constructed: kind == Kind::SignedData,
+        number: TagNumber::N0,
+    })?;
+    check_len(header, info_len)?;
+
+    if kind == Kind::SignedData {
+        let (header, _) = read_header(reader, Some(&mut info_len))?;
+        header.tag.assert_eq(Tag::OctetString)?;
+        check_len(header, info_len)?;
+    }
+
+    Ok(info_len)
+}
+
+/// Read a header, subtracting its encoded length from `remaining` if
+/// given, and return it along with its encoding.
+fn read_header(reader: &mut impl Read, remaining: Option<&mut usize>) -> Result<(Header, Vec<u8>)> {
+    let mut encoded = vec![0u8; 2];
+    read_exact(reader, &mut encoded)?;
+
+    // Long form lengths are followed by up to 4 octets
+    if let len @ 0x81..=0x84 = encoded[1] {
+        encoded.resize(2 + usize::from(len & 0x7f), 0);
+        read_exact(reader, &mut encoded[2..])?;
+    }
+
+    let header = Header::from_der(&encoded)?;
+
+    if let Some(remaining) = remaining {
+        consume(remaining, encoded.len(), header.tag)?;
+    }
+
+    Ok((header, encoded))
+}
+
+/// Read a complete TLV, subtracting its encoded length from `remaining`,
+/// and return its encoding.
+fn read_tlv(reader: &mut impl Read, remaining: &mut usize) -> Result<Vec<u8>> {
+    let (header, mut encoded) = read_header(reader, Some(remaining))?;
+    let len = usize::try_from(header.length)?;
+    consume(remaining, len, header.tag)?;
+    read_value(reader, len, &mut encoded)?;
+    Ok(encoded)
+}
+
+/// Read a value of the given length, appending it to `out`.
+///
+/// The buffer grows as the value is read, rather than being allocated
+/// according to the untrusted length up front.
+fn read_value(reader: &mut impl Read, len: usize, out: &mut Vec<u8>) -> Result<()> {
+    let read = reader
+        .by_ref()
+        .take(len as u64)
+        .read_to_end(out)
+        .map_err(der::Error::from)?;
+
+    if read != len {
+        return Err(der::Error::from(io::Error::from(io::ErrorKind::UnexpectedEof)).into());
+    }
+
+    Ok(())
+}
+
+/// Fill the given buffer from the reader.
+fn read_exact(reader: &mut impl Read, buf: &mut [u8]) -> Result<()> {
+    reader.read_exact(buf).map_err(der::Error::from)?;
+    Ok(())
+}
+
+/// Subtract `len` from the remaining length of the enclosing value.
+fn consume(remaining: &mut usize, len: usize, tag: Tag) -> Result<()> {
+    *remaining = remaining
+        .checked_sub(len)
+        .ok_or(der::ErrorKind::Length { tag })?;
+    Ok(())
+}
+
+/// Check that the value of `header` fills the rest of the enclosing value.
+fn check_len(header: Header, remaining: usize) -> Result<()> {
+    if header.length == Length::try_from(remaining)? {
+        Ok(())
+    } else {
+        Err(der::ErrorKind::Length { tag: header.tag }.into())
+    }
+}
@@ -0,0 +1,201 @@
+//! Time-stamp tokens as defined in [RFC 3161], and their use as the
+//! `id-aa-timeStampToken` unsigned attribute of a [`SignerInfo`] as
+//! described in [RFC 3161 Appendix A].
+//!
+//! Such "signature time-stamps" prove that a signature existed at the time
+//! given in the token, allowing it to be validated after the signer's
+//! certificate has expired (as in CAdES-T).
+//!
+//! [RFC 3161]: https://datatracker.ietf.org/doc/html/rfc3161
+//! [RFC 3161 Appendix A]: https://datatracker.ietf.org/doc/html/rfc3161#appendix-A
+
+use crate::content_info::ContentInfo;
+use crate::signed_data::{SignedData, SignerInfo};
+
+use alloc::vec::Vec;
+
+use der::asn1::{Any, GeneralizedTime, ObjectIdentifier, OctetString, SetOfVec, UIntBytes};
+use der::{Decode, Error, Sequence, Tag};
+use spki::AlgorithmIdentifier;
+use x509_cert::attr::Attribute;
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::Extensions;
+
+/// `id-aa-timeStampToken` as defined in [RFC 3161 Appendix A].
+///
+/// [RFC 3161 Appendix A]: https://datatracker.ietf.org/doc/html/rfc3161#appendix-A
+pub const ID_AA_TIME_STAMP_TOKEN: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.2.14");
+
+/// `id-ct-TSTInfo` as defined in [RFC 3161 Section 2.4.2].
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+pub const ID_CT_TST_INFO: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+
+/// `TimeStampToken` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// TimeStampToken ::= ContentInfo
+///     -- contentType is id-signedData ([CMS])
+///     -- content is SignedData ([CMS])
+/// ```
+///
+/// The `SignedData` encapsulates a DER encoded [`TstInfo`], which can be
+/// decoded with [`TstInfo::try_from`].
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+pub type TimeStampToken<'a> = ContentInfo<'a>;
+
+/// `TSTInfo` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// TSTInfo ::= SEQUENCE  {
+///     version                      INTEGER  { v1(1) },
+///     policy                       TSAPolicyId,
+///     messageImprint               MessageImprint,
+///     serialNumber                 INTEGER,
+///     genTime                      GeneralizedTime,
+///     accuracy                     Accuracy                 OPTIONAL,
+///     ordering                     BOOLEAN             DEFAULT FALSE,
+///     nonce                        INTEGER                  OPTIONAL,
+///     tsa                          [0] GeneralName          OPTIONAL,
+///     extensions                   [1] IMPLICIT Extensions  OPTIONAL }
+///
+/// TSAPolicyId ::= OBJECT IDENTIFIER
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct TstInfo<'a> {
+    pub version: u8,
+    pub policy: ObjectIdentifier,
+    pub message_imprint: MessageImprint<'a>,
+    pub serial_number: UIntBytes<'a>,
+    pub gen_time: GeneralizedTime,
+    pub accuracy: Option<Accuracy>,
+    #[asn1(default = "Default::default")]
+    pub ordering: bool,
+    pub nonce: Option<UIntBytes<'a>>,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
+    pub tsa: Option<GeneralName<'a>>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TstInfo<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<&SignedData<'a>> for TstInfo<'a> {
+    type Error = Error;
+
+    /// Decode the `TSTInfo` encapsulated in the `SignedData` of a
+    /// [`TimeStampToken`], checking that its content type is
+    /// `id-ct-TSTInfo`.
+    fn try_from(signed_data: &SignedData<'a>) -> Result<Self, Self::Error> {
+        let encap_content_info = &signed_data.encap_content_info;
+
+        if encap_content_info.econtent_type != ID_CT_TST_INFO {
+            return Err(Tag::ObjectIdentifier.value_error());
+        }
+
+        match encap_content_info.econtent {
+            Some(econtent) => Self::from_der(econtent.as_bytes()),
+            None => Err(Tag::OctetString.value_error()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&TimeStampToken<'a>> for TstInfo<'a> {
+    type Error = Error;
+
+    /// Decode the `TSTInfo` encapsulated in the given [`TimeStampToken`].
+    ///
+    /// The token's signature isn't verified.
+    fn try_from(token: &TimeStampToken<'a>) -> Result<Self, Self::Error> {
+        Self::try_from(&SignedData::try_from(token)?)
+    }
+}
+
+/// `MessageImprint` as defined in [RFC 3161 Section 2.4.1].
+///
+/// ```text
+/// MessageImprint ::= SEQUENCE  {
+///     hashAlgorithm                AlgorithmIdentifier,
+///     hashedMessage                OCTET STRING  }
+/// ```
+///
+/// For a signature time-stamp, the `hashedMessage` is the digest of the
+/// [`SignerInfo::signature`] octets.
+///
+/// [RFC 3161 Section 2.4.1]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct MessageImprint<'a> {
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    pub hashed_message: OctetString<'a>,
+}
+
+/// `Accuracy` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// Accuracy ::= SEQUENCE {
+///     seconds        INTEGER              OPTIONAL,
+///     millis     [0] INTEGER  (1..999)    OPTIONAL,
+///     micros     [1] INTEGER  (1..999)    OPTIONAL  }
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Accuracy {
+    pub seconds: Option<u32>,
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub millis: Option<u16>,
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub micros: Option<u16>,
+}
+
+impl<'a> SignerInfo<'a> {
+    /// Iterate over the time-stamp tokens in the `id-aa-timeStampToken`
+    /// unsigned attributes of this `SignerInfo`.
+    pub fn time_stamp_tokens(&self) -> impl Iterator<Item = der::Result<TimeStampToken<'a>>> + '_ {
+        self.unsigned_attrs
+            .iter()
+            .flat_map(|attrs| attrs.iter())
+            .filter(|attr| attr.oid == ID_AA_TIME_STAMP_TOKEN)
+            .flat_map(|attr| attr.values.iter())
+            .map(|value| value.decode_into())
+    }
+
+    /// Add the DER encoding of a time-stamp token to the unsigned attributes
+    /// of this `SignerInfo`, as a new `id-aa-timeStampToken` attribute.
+    ///
+    /// The token's [`MessageImprint`] should be computed over the
+    /// [`SignerInfo::signature`] octets. The token is checked to contain a
+    /// [`TstInfo`], but isn't otherwise validated.
+    pub fn add_time_stamp_token(&mut self, token: &'a [u8]) -> der::Result<()> {
+        TstInfo::try_from(&TimeStampToken::from_der(token)?)?;
+
+        let attr = Attribute {
+            oid: ID_AA_TIME_STAMP_TOKEN,
+            values: SetOfVec::try_from([Any::from_der(token)?])?,
+        };
+
+        let mut attrs: Vec<_> = self
+            .unsigned_attrs
+            .iter()
+            .flat_map(|attrs| attrs.iter())
+            .cloned()
+            .collect();
+        attrs.push(attr);
+        self.unsigned_attrs = Some(attrs.try_into()?);
+        Ok(())
+    }
+}
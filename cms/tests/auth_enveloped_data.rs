@@ -0,0 +1,141 @@
+//! `AuthEnvelopedData` tests
+
+use cms::{
+    auth_enveloped_data::{AuthEnvelopedData, CcmParameters, GcmParameters},
+    der::{
+        asn1::{Any, OctetString, SetOfVec},
+        Decode, Encode,
+    },
+    enveloped_data::RecipientInfo,
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::rfc5911::{ID_AES_256_GCM, ID_CONTENT_TYPE, ID_CT_AUTH_ENVELOPED_DATA, ID_DATA};
+use hex_literal::hex;
+use x509_cert::attr::Attribute;
+
+/// `AuthEnvelopedData` with AES-256-GCM and a key transport (RSA) recipient.
+const RSA_DER: &[u8] = include_bytes!("examples/auth-enveloped-rsa.der");
+
+/// `EnvelopedData` with a key transport (RSA) recipient.
+const ENVELOPED_DER: &[u8] = include_bytes!("examples/enveloped-rsa.der");
+
+/// AES-GCM nonce used by [`RSA_DER`].
+const NONCE: [u8; 12] = hex!("72f1756f9cedd48ea342105e");
+
+fn auth_enveloped_data(der: &[u8]) -> AuthEnvelopedData<'_> {
+    let content_info = ContentInfo::from_der(der).unwrap();
+    assert_eq!(content_info.content_type, ID_CT_AUTH_ENVELOPED_DATA);
+    AuthEnvelopedData::try_from(&content_info).unwrap()
+}
+
+#[test]
+fn decode_rsa() {
+    let auth_enveloped_data = auth_enveloped_data(RSA_DER);
+    assert_eq!(auth_enveloped_data.version, CmsVersion::V0);
+    assert!(auth_enveloped_data.originator_info.is_none());
+    assert!(auth_enveloped_data.auth_attrs.is_none());
+    assert!(auth_enveloped_data.unauth_attrs.is_none());
+    assert_eq!(
+        auth_enveloped_data.mac.as_bytes(),
+        hex!("350f8b6e17e01395d072d46175879355")
+    );
+
+    assert_eq!(auth_enveloped_data.recipient_infos.0.len(), 1);
+    assert!(matches!(
+        auth_enveloped_data.recipient_infos.0[0],
+        RecipientInfo::Ktri(_)
+    ));
+
+    let content_info = &auth_enveloped_data.auth_encrypted_content_info;
+    assert_eq!(content_info.content_type, ID_DATA);
+    assert_eq!(content_info.content_enc_alg.oid, ID_AES_256_GCM);
+    assert_eq!(
+        content_info.encrypted_content.unwrap().as_bytes().len(),
+        b"Hello, CMS!\n".len()
+    );
+
+    let params: GcmParameters<'_> = content_info
+        .content_enc_alg
+        .parameters_any()
+        .unwrap()
+        .decode_into()
+        .unwrap();
+    assert_eq!(params.nonce.as_bytes(), NONCE);
+    assert_eq!(params.icv_len, 16);
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let content_info = ContentInfo::from_der(ENVELOPED_DER).unwrap();
+    assert!(AuthEnvelopedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let content_info = ContentInfo::from_der(RSA_DER).unwrap();
+    assert_eq!(content_info.to_vec().unwrap(), RSA_DER);
+
+    let auth_enveloped_data = AuthEnvelopedData::try_from(&content_info).unwrap();
+    assert_eq!(
+        auth_enveloped_data.to_vec().unwrap(),
+        content_info.content.to_vec().unwrap()
+    );
+}
+
+#[test]
+fn encode_auth_attrs() {
+    let content_type = Attribute {
+        oid: ID_CONTENT_TYPE,
+        values: SetOfVec::try_from([Any::from(&ID_DATA)]).unwrap(),
+    };
+
+    let mut auth_enveloped_data = auth_enveloped_data(RSA_DER);
+    auth_enveloped_data.auth_attrs = Some(SetOfVec::try_from([content_type]).unwrap());
+
+    let der = auth_enveloped_data.to_vec().unwrap();
+    let decoded = AuthEnvelopedData::from_der(&der).unwrap();
+    assert_eq!(decoded, auth_enveloped_data);
+
+    let auth_attrs = decoded.auth_attrs.unwrap();
+    assert_eq!(auth_attrs.len(), 1);
+    assert_eq!(auth_attrs.as_slice()[0].oid, ID_CONTENT_TYPE);
+}
+
+#[test]
+fn gcm_parameters() {
+    let params = GcmParameters {
+        nonce: OctetString::new(&NONCE).unwrap(),
+        icv_len: 12,
+    };
+
+    // The default ICV length is omitted
+    let der = params.to_vec().unwrap();
+    assert_eq!(der, hex!("300e040c72f1756f9cedd48ea342105e"));
+    assert_eq!(GcmParameters::from_der(&der).unwrap(), params);
+
+    let der = hex!("3011040c72f1756f9cedd48ea342105e020110");
+    assert_eq!(GcmParameters::from_der(&der).unwrap().icv_len, 16);
+
+    let der = hex!("3011040c72f1756f9cedd48ea342105e02010b");
+    assert!(GcmParameters::from_der(&der).is_err());
+}
+
+#[test]
+fn ccm_parameters() {
+    let params = CcmParameters {
+        nonce: OctetString::new(&NONCE[..7]).unwrap(),
+        icv_len: 8,
+    };
+
+    let der = params.to_vec().unwrap();
+    assert_eq!(der, hex!("300c040772f1756f9cedd4020108"));
+    assert_eq!(CcmParameters::from_der(&der).unwrap(), params);
+
+    // ICV length must be even
+    let der = hex!("300c040772f1756f9cedd4020109");
+    assert!(CcmParameters::from_der(&der).is_err());
+
+    // Nonce must be between 7 and 13 bytes
+    let der = hex!("3008040672f1756f9ced");
+    assert!(CcmParameters::from_der(&der).is_err());
+}
@@ -0,0 +1,75 @@
+//! Certs-only `SignedData` tests
+
+use cms::{certs_only, der::Decode, signed_data::SignedData, ContentInfo};
+use hex_literal::hex;
+
+/// Certs-only `SignedData` containing an RSA and an ECDSA certificate.
+const CHAIN_DER: &[u8] = include_bytes!("examples/certs-chain.p7b");
+
+/// Certs-only `SignedData` containing a certificate and a CRL.
+const CERTS_CRL_DER: &[u8] = include_bytes!("examples/certs-crl.p7b");
+
+/// `SignedData` with encapsulated content, signed with RSA.
+const SIGNED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+fn signed_data(der: &[u8]) -> SignedData<'_> {
+    SignedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap()
+}
+
+#[test]
+fn decode_chain() {
+    let certs = certs_only::decode(CHAIN_DER).unwrap();
+    assert_eq!(certs.len(), 2);
+    assert_eq!(
+        certs[0].tbs_certificate.serial_number.as_bytes(),
+        hex!("a4332e8de969e3bb")
+    );
+    assert_eq!(
+        certs[1].tbs_certificate.serial_number.as_bytes(),
+        hex!("117edd0dbfe73792634489b228927fb9708132e8")
+    );
+
+    assert!(signed_data(CHAIN_DER).is_certs_only());
+}
+
+#[test]
+fn encode_chain() {
+    let certs = certs_only::decode(CHAIN_DER).unwrap();
+    assert_eq!(certs_only::encode(certs.clone()).unwrap(), CHAIN_DER);
+
+    // Certificates are kept in order
+    let reversed = certs_only::encode(certs.iter().rev().cloned()).unwrap();
+    assert_ne!(reversed, CHAIN_DER);
+    assert_eq!(
+        certs_only::decode(&reversed).unwrap(),
+        certs.into_iter().rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn decode_with_crl() {
+    let certs = certs_only::decode(CERTS_CRL_DER).unwrap();
+    assert_eq!(certs.len(), 1);
+
+    let signed_data = signed_data(CERTS_CRL_DER);
+    assert!(signed_data.is_certs_only());
+    assert_eq!(signed_data.x509_certificates().next(), certs.first());
+}
+
+#[test]
+fn decode_signed_message() {
+    // Certificates can also be extracted from signed messages
+    let certs = certs_only::decode(SIGNED_DER).unwrap();
+    assert_eq!(certs.len(), 1);
+    assert!(!signed_data(SIGNED_DER).is_certs_only());
+}
+
+#[test]
+fn encode_empty() {
+    let der = certs_only::encode([]).unwrap();
+    assert!(certs_only::decode(&der).unwrap().is_empty());
+
+    let signed_data = signed_data(&der);
+    assert!(signed_data.is_certs_only());
+    assert_eq!(signed_data, SignedData::new_certs_only([]));
+}
@@ -0,0 +1,107 @@
+//! `CompressedData` tests
+
+use cms::{
+    compressed_data::CompressedData,
+    der::{Decode, Encode},
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::ID_DATA,
+    rfc6268::{ID_ALG_ZLIB_COMPRESS, ID_CT_COMPRESSED_DATA},
+};
+use hex_literal::hex;
+
+/// `CompressedData` with zlib compressed content.
+const ZLIB_DER: &[u8] = include_bytes!("examples/compressed-zlib.der");
+
+/// `DigestedData` with encapsulated content, digested with SHA-256.
+const DIGESTED_DER: &[u8] = include_bytes!("examples/digested-sha256.der");
+
+/// Compressed message.
+#[cfg(feature = "zlib")]
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+#[test]
+fn decode_zlib() {
+    let content_info = ContentInfo::from_der(ZLIB_DER).unwrap();
+    assert_eq!(content_info.content_type, ID_CT_COMPRESSED_DATA);
+
+    let compressed_data = CompressedData::try_from(&content_info).unwrap();
+    assert_eq!(compressed_data.version, CmsVersion::V0);
+    assert_eq!(
+        compressed_data.compression_algorithm.oid,
+        ID_ALG_ZLIB_COMPRESS
+    );
+    assert!(compressed_data.compression_algorithm.parameters.is_none());
+    assert_eq!(compressed_data.encap_content_info.econtent_type, ID_DATA);
+    assert_eq!(
+        compressed_data
+            .encap_content_info
+            .econtent
+            .unwrap()
+            .as_bytes(),
+        hex!("78daf348cdc9c9d75170f60d56e4020018fb034f")
+    );
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let content_info = ContentInfo::from_der(DIGESTED_DER).unwrap();
+    assert!(CompressedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let content_info = ContentInfo::from_der(ZLIB_DER).unwrap();
+    assert_eq!(content_info.to_vec().unwrap(), ZLIB_DER);
+
+    let compressed_data = CompressedData::try_from(&content_info).unwrap();
+    assert_eq!(
+        compressed_data.to_vec().unwrap(),
+        content_info.content.to_vec().unwrap()
+    );
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn decompress_zlib() {
+    let content_info = ContentInfo::from_der(ZLIB_DER).unwrap();
+    let compressed_data = CompressedData::try_from(&content_info).unwrap();
+    assert_eq!(compressed_data.decompress(1024).unwrap(), MESSAGE);
+
+    // Content which decompresses to more than the limit is rejected
+    assert_eq!(
+        compressed_data.decompress(MESSAGE.len() - 1),
+        Err(cms::Error::Compression)
+    );
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn decompress_unsupported_algorithm() {
+    let content_info = ContentInfo::from_der(ZLIB_DER).unwrap();
+    let mut compressed_data = CompressedData::try_from(&content_info).unwrap();
+    compressed_data.compression_algorithm.oid = ID_DATA;
+    assert_eq!(
+        compressed_data.decompress(1024),
+        Err(cms::Error::Compression)
+    );
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn compress_round_trip() {
+    let content = MESSAGE.repeat(100);
+    let der = cms::compressed_data::compress(ID_DATA, &content).unwrap();
+
+    let content_info = ContentInfo::from_der(&der).unwrap();
+    let compressed_data = CompressedData::try_from(&content_info).unwrap();
+    assert_eq!(compressed_data.version, CmsVersion::V0);
+    assert_eq!(
+        compressed_data.compression_algorithm.oid,
+        ID_ALG_ZLIB_COMPRESS
+    );
+    assert_eq!(compressed_data.encap_content_info.econtent_type, ID_DATA);
+    assert!(der.len() < content.len());
+    assert_eq!(compressed_data.decompress(content.len()).unwrap(), content);
+}
@@ -0,0 +1,244 @@
+//! Detached signature tests
+
+#![cfg(feature = "sign")]
+
+use cms::{
+    der::{Decode, Encode},
+    detached,
+    signature::{Error, Signature as _, Signer, Verifier},
+    signed_data::{SignedData, SignerIdentifier},
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::ID_DATA,
+    rfc5912::{ECDSA_WITH_SHA_256, ID_SHA_256},
+};
+use hex_literal::hex;
+use p256::ecdsa::{DerSignature, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha256};
+use spki::AlgorithmIdentifier;
+use x509_cert::Certificate;
+
+/// `SignedData` with detached content, signed with ECDSA by OpenSSL.
+const EC_DETACHED_DER: &[u8] = include_bytes!("examples/signed-ec-detached.der");
+
+/// `SignedData` with encapsulated content, signed with ECDSA, identifying the
+/// signer by subject key identifier and without signed attributes.
+const EC_KEYID_NOATTR_DER: &[u8] = include_bytes!("examples/signed-ec-keyid-noattr.der");
+
+/// `SignedData` with encapsulated content, signed with RSA.
+const RSA_ATTACHED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+/// Signed message.
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+/// Private scalar of the key in `examples/ec-prv.pem`.
+const EC_PRIVATE_KEY: [u8; 32] =
+    hex!("592063ae550d655f0eacd4aa2aa507a0e0aa64a5e2deae0e2ed7840346c2e98c");
+
+/// ECDSA/P-256 signer producing the DER encoded signatures used by CMS.
+struct EcSigner(SigningKey);
+
+impl Signer<DerSignature> for EcSigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<DerSignature, Error> {
+        Ok(self.0.try_sign(msg)?.to_der())
+    }
+}
+
+/// ECDSA/P-256 verifier accepting the DER encoded signatures used by CMS.
+struct EcVerifier(VerifyingKey);
+
+impl EcVerifier {
+    fn new(cert: &Certificate<'_>) -> Self {
+        let public_key = cert
+            .tbs_certificate
+            .subject_public_key_info
+            .subject_public_key;
+        Self(VerifyingKey::from_sec1_bytes(public_key).unwrap())
+    }
+}
+
+impl Verifier<DerSignature> for EcVerifier {
+    fn verify(&self, msg: &[u8], signature: &DerSignature) -> Result<(), Error> {
+        self.0
+            .verify(msg, &p256::ecdsa::Signature::from_der(signature.as_ref())?)
+    }
+}
+
+fn signed_data(der: &[u8]) -> SignedData<'_> {
+    SignedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap()
+}
+
+fn first_cert(der: &[u8]) -> Certificate<'_> {
+    signed_data(der).x509_certificates().next().unwrap().clone()
+}
+
+fn sha256_alg() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: ID_SHA_256,
+        parameters: None,
+    }
+}
+
+fn ecdsa_with_sha256_alg() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: ECDSA_WITH_SHA_256,
+        parameters: None,
+    }
+}
+
+#[test]
+fn verify_openssl() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let verifier = EcVerifier::new(&cert);
+    detached::verify::<Sha256, _>(EC_DETACHED_DER, MESSAGE, &cert, &verifier).unwrap();
+}
+
+#[test]
+fn verify_keyid_without_signed_attrs() {
+    let cert = first_cert(EC_KEYID_NOATTR_DER);
+    let verifier = EcVerifier::new(&cert);
+    detached::verify::<Sha256, _>(EC_KEYID_NOATTR_DER, MESSAGE, &cert, &verifier).unwrap();
+
+    assert_eq!(
+        detached::verify::<Sha256, _>(EC_KEYID_NOATTR_DER, b"Goodbye", &cert, &verifier),
+        Err(cms::Error::Signature)
+    );
+}
+
+#[test]
+fn verify_wrong_content() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let verifier = EcVerifier::new(&cert);
+    assert_eq!(
+        detached::verify::<Sha256, _>(EC_DETACHED_DER, b"Goodbye", &cert, &verifier),
+        Err(cms::Error::DigestMismatch)
+    );
+}
+
+#[test]
+fn verify_wrong_signer() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let verifier = EcVerifier::new(&cert);
+    let rsa_cert = first_cert(RSA_ATTACHED_DER);
+    assert_eq!(
+        detached::verify::<Sha256, _>(EC_DETACHED_DER, MESSAGE, &rsa_cert, &verifier),
+        Err(cms::Error::SignerNotFound)
+    );
+}
+
+#[test]
+fn verify_tampered_signature() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let verifier = EcVerifier::new(&cert);
+
+    // Flip a bit in the last byte of `s`, which is the last byte of the message
+    let mut der = EC_DETACHED_DER.to_vec();
+    *der.last_mut().unwrap() ^= 1;
+
+    assert_eq!(
+        detached::verify::<Sha256, _>(&der, MESSAGE, &cert, &verifier),
+        Err(cms::Error::Signature)
+    );
+}
+
+#[test]
+fn sign_round_trip() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let signer = EcSigner(SigningKey::from_bytes(&EC_PRIVATE_KEY).unwrap());
+
+    let der = detached::sign::<Sha256, DerSignature>(
+        MESSAGE,
+        &signer,
+        &cert,
+        sha256_alg(),
+        ecdsa_with_sha256_alg(),
+    )
+    .unwrap();
+
+    let signed_data = signed_data(&der);
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert_eq!(signed_data.encap_content_info.econtent_type, ID_DATA);
+    assert!(signed_data.encap_content_info.econtent.is_none());
+    assert_eq!(signed_data.x509_certificates().next(), Some(&cert));
+
+    let signer_info = &signed_data.signer_infos.0[0];
+    assert_eq!(signer_info.digest_alg, sha256_alg());
+    assert_eq!(signer_info.signature_algorithm, ecdsa_with_sha256_alg());
+    match &signer_info.sid {
+        SignerIdentifier::IssuerAndSerialNumber(sid) => {
+            assert_eq!(sid.serial_number, cert.tbs_certificate.serial_number)
+        }
+        other => panic!("unexpected signer identifier: {:?}", other),
+    }
+
+    // The signature is over the DER encoding of the signed attributes
+    let signed_attrs = signer_info.signed_attrs.as_ref().unwrap().to_vec().unwrap();
+    let signature = DerSignature::from_bytes(signer_info.signature.as_bytes()).unwrap();
+    let verifier = EcVerifier::new(&cert);
+    verifier.verify(&signed_attrs, &signature).unwrap();
+
+    detached::verify::<Sha256, _>(&der, MESSAGE, &cert, &verifier).unwrap();
+    assert_eq!(
+        detached::verify::<Sha256, _>(&der, b"Goodbye", &cert, &verifier),
+        Err(cms::Error::DigestMismatch)
+    );
+}
+
+#[test]
+fn verify_digest_streamed() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let verifier = EcVerifier::new(&cert);
+
+    let mut digest = Sha256::new();
+    for chunk in MESSAGE.chunks(5) {
+        digest.update(chunk);
+    }
+
+    detached::verify_digest(EC_DETACHED_DER, digest, &cert, &verifier).unwrap();
+}
+
+#[test]
+fn verify_digest_without_signed_attrs() {
+    let cert = first_cert(EC_KEYID_NOATTR_DER);
+    let verifier = EcVerifier::new(&cert);
+    assert_eq!(
+        detached::verify_digest(
+            EC_KEYID_NOATTR_DER,
+            Sha256::new_with_prefix(MESSAGE),
+            &cert,
+            &verifier
+        ),
+        Err(cms::Error::AttributesInvalid)
+    );
+}
+
+#[test]
+fn sign_digest_streamed() {
+    let cert = first_cert(EC_DETACHED_DER);
+    let signer = EcSigner(SigningKey::from_bytes(&EC_PRIVATE_KEY).unwrap());
+    let verifier = EcVerifier::new(&cert);
+
+    // Content which is never held in memory all at once
+    let chunk = [0x5a; 4096];
+    let mut digest = Sha256::new();
+    for _ in 0..256 {
+        digest.update(chunk);
+    }
+
+    let der = detached::sign_digest(
+        digest.clone(),
+        &signer,
+        &cert,
+        sha256_alg(),
+        ecdsa_with_sha256_alg(),
+    )
+    .unwrap();
+
+    detached::verify_digest(&der, digest, &cert, &verifier).unwrap();
+    detached::verify::<Sha256, _>(&der, &[0x5a; 4096 * 256], &cert, &verifier).unwrap();
+    assert_eq!(
+        detached::verify::<Sha256, _>(&der, &chunk, &cert, &verifier),
+        Err(cms::Error::DigestMismatch)
+    );
+}
@@ -0,0 +1,63 @@
+//! `DigestedData` tests
+
+use cms::{
+    der::{Decode, Encode},
+    digested_data::DigestedData,
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::{ID_DATA, ID_DIGESTED_DATA},
+    rfc5912::ID_SHA_256,
+};
+use hex_literal::hex;
+use sha2::{Digest, Sha256};
+
+/// `DigestedData` with encapsulated content, digested with SHA-256.
+const SHA256_DER: &[u8] = include_bytes!("examples/digested-sha256.der");
+
+/// `EncryptedData` encrypted with AES-128-CBC.
+const ENCRYPTED_DER: &[u8] = include_bytes!("examples/encrypted-aes128.der");
+
+/// Digested message.
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+#[test]
+fn decode_sha256() {
+    let content_info = ContentInfo::from_der(SHA256_DER).unwrap();
+    assert_eq!(content_info.content_type, ID_DIGESTED_DATA);
+
+    let digested_data = DigestedData::try_from(&content_info).unwrap();
+    assert_eq!(digested_data.version, CmsVersion::V0);
+    assert_eq!(digested_data.digest_algorithm.oid, ID_SHA_256);
+    assert!(digested_data.digest_algorithm.parameters.is_none());
+    assert_eq!(digested_data.encap_content_info.econtent_type, ID_DATA);
+
+    let econtent = digested_data.encap_content_info.econtent.unwrap();
+    assert_eq!(econtent.as_bytes(), MESSAGE);
+    assert_eq!(
+        digested_data.digest.as_bytes(),
+        hex!("e731a36bbff033b024e8b760edd0b1a8931df17a2af02fb3eb7cfa9a3afd0987")
+    );
+    assert_eq!(
+        digested_data.digest.as_bytes(),
+        Sha256::digest(econtent.as_bytes()).as_slice()
+    );
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let content_info = ContentInfo::from_der(ENCRYPTED_DER).unwrap();
+    assert!(DigestedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let content_info = ContentInfo::from_der(SHA256_DER).unwrap();
+    assert_eq!(content_info.to_vec().unwrap(), SHA256_DER);
+
+    let digested_data = DigestedData::try_from(&content_info).unwrap();
+    assert_eq!(
+        digested_data.to_vec().unwrap(),
+        content_info.content.to_vec().unwrap()
+    );
+}
@@ -0,0 +1,82 @@
+//! `EncryptedData` tests
+
+use cms::{
+    der::{
+        asn1::{Any, ObjectIdentifier, OctetString, SetOfVec},
+        Decode, Encode,
+    },
+    encrypted_data::EncryptedData,
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::rfc5911::{ID_AES_128_CBC, ID_DATA, ID_ENCRYPTED_DATA};
+use hex_literal::hex;
+use x509_cert::attr::Attribute;
+
+/// `EncryptedData` encrypted with AES-128-CBC using a pre-shared key.
+const AES128_DER: &[u8] = include_bytes!("examples/encrypted-aes128.der");
+
+/// `DigestedData` with encapsulated content, digested with SHA-256.
+const DIGESTED_DER: &[u8] = include_bytes!("examples/digested-sha256.der");
+
+fn encrypted_data(der: &[u8]) -> EncryptedData<'_> {
+    let content_info = ContentInfo::from_der(der).unwrap();
+    assert_eq!(content_info.content_type, ID_ENCRYPTED_DATA);
+    EncryptedData::try_from(&content_info).unwrap()
+}
+
+#[test]
+fn decode_aes128() {
+    let encrypted_data = encrypted_data(AES128_DER);
+    assert_eq!(encrypted_data.version, CmsVersion::V0);
+    assert!(encrypted_data.unprotected_attrs.is_none());
+
+    let content_info = &encrypted_data.encrypted_content_info;
+    assert_eq!(content_info.content_type, ID_DATA);
+    assert_eq!(content_info.content_enc_alg.oid, ID_AES_128_CBC);
+
+    let iv = content_info
+        .content_enc_alg
+        .parameters_any()
+        .unwrap()
+        .decode_into::<OctetString<'_>>()
+        .unwrap();
+    assert_eq!(iv.as_bytes(), hex!("33aec0b1451c07cd46b0f61680ac8942"));
+    assert_eq!(content_info.encrypted_content.unwrap().as_bytes().len(), 16);
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let content_info = ContentInfo::from_der(DIGESTED_DER).unwrap();
+    assert!(EncryptedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let content_info = ContentInfo::from_der(AES128_DER).unwrap();
+    assert_eq!(content_info.to_vec().unwrap(), AES128_DER);
+
+    let encrypted_data = EncryptedData::try_from(&content_info).unwrap();
+    assert_eq!(
+        encrypted_data.to_vec().unwrap(),
+        content_info.content.to_vec().unwrap()
+    );
+}
+
+#[test]
+fn encode_unprotected_attrs() {
+    let attr = Attribute {
+        oid: ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.1"),
+        values: SetOfVec::try_from([Any::from_der(&hex!("0c0474657374")).unwrap()]).unwrap(),
+    };
+
+    let mut encrypted_data = encrypted_data(AES128_DER);
+    encrypted_data.version = CmsVersion::V2;
+    encrypted_data.unprotected_attrs = Some(SetOfVec::try_from([attr]).unwrap());
+
+    let der = encrypted_data.to_vec().unwrap();
+    assert_eq!(EncryptedData::from_der(&der).unwrap(), encrypted_data);
+
+    // `unprotectedAttrs` is the trailing `[1]` tagged field
+    let attrs_der = encrypted_data.unprotected_attrs.unwrap().to_vec().unwrap();
+    assert_eq!(der[der.len() - attrs_der.len()], 0xa1);
+}
@@ -0,0 +1,231 @@
+//! `EnvelopedData` tests
+
+use cms::{
+    cert::CertificateSet,
+    der::{
+        asn1::{Any, ObjectIdentifier, SetOfVec},
+        Decode, Encode,
+    },
+    enveloped_data::{
+        EnvelopedData, KeyAgreeRecipientIdentifier, OriginatorIdentifierOrKey, OriginatorInfo,
+        RecipientIdentifier, RecipientInfo,
+    },
+    signed_data::SignedData,
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::{
+        ID_AES_128_CBC, ID_AES_128_WRAP, ID_AES_256_CBC, ID_DATA, ID_ENVELOPED_DATA, ID_PBKDF_2,
+    },
+    rfc5912::{ID_EC_PUBLIC_KEY, RSA_ENCRYPTION},
+};
+use hex_literal::hex;
+use x509_cert::attr::Attribute;
+
+/// `EnvelopedData` with a key transport (RSA) recipient.
+const RSA_DER: &[u8] = include_bytes!("examples/enveloped-rsa.der");
+
+/// `EnvelopedData` with a key agreement (ECDH) recipient identified by
+/// subject key identifier.
+const EC_KEYID_DER: &[u8] = include_bytes!("examples/enveloped-ec-keyid.der");
+
+/// `EnvelopedData` with key transport, key agreement, KEK and password
+/// recipients.
+const MULTI_DER: &[u8] = include_bytes!("examples/enveloped-multi.der");
+
+/// `SignedData` containing the RSA recipient's certificate.
+const SIGNED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+/// `dhSinglePass-stdDH-sha1kdf-scheme` as defined in RFC 5753.
+const DH_SINGLE_PASS_STD_DH_SHA1KDF: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.133.16.840.63.0.2");
+
+/// `id-alg-PWRI-KEK` as defined in RFC 3211.
+const ID_ALG_PWRI_KEK: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.9");
+
+fn enveloped_data(der: &[u8]) -> EnvelopedData<'_> {
+    let content_info = ContentInfo::from_der(der).unwrap();
+    assert_eq!(content_info.content_type, ID_ENVELOPED_DATA);
+    EnvelopedData::try_from(&content_info).unwrap()
+}
+
+#[test]
+fn decode_rsa() {
+    let enveloped_data = enveloped_data(RSA_DER);
+    assert_eq!(enveloped_data.version, CmsVersion::V0);
+    assert!(enveloped_data.originator_info.is_none());
+    assert!(enveloped_data.unprotected_attrs.is_none());
+
+    let content_info = &enveloped_data.encrypted_content_info;
+    assert_eq!(content_info.content_type, ID_DATA);
+    assert_eq!(content_info.content_enc_alg.oid, ID_AES_256_CBC);
+    assert_eq!(content_info.encrypted_content.unwrap().as_bytes().len(), 16);
+
+    assert_eq!(enveloped_data.recipient_infos.0.len(), 1);
+    let ktri = match &enveloped_data.recipient_infos.0[0] {
+        RecipientInfo::Ktri(ktri) => ktri,
+        other => panic!("unexpected recipient: {:?}", other),
+    };
+
+    assert_eq!(ktri.version, CmsVersion::V0);
+    assert_eq!(ktri.key_enc_alg.oid, RSA_ENCRYPTION);
+    assert_eq!(ktri.enc_key.as_bytes().len(), 256);
+
+    match &ktri.rid {
+        RecipientIdentifier::IssuerAndSerialNumber(rid) => {
+            assert_eq!(rid.serial_number.as_bytes(), hex!("a4332e8de969e3bb"))
+        }
+        other => panic!("unexpected recipient identifier: {:?}", other),
+    }
+}
+
+#[test]
+fn decode_ec_keyid() {
+    let enveloped_data = enveloped_data(EC_KEYID_DER);
+    assert_eq!(enveloped_data.version, CmsVersion::V2);
+    assert_eq!(
+        enveloped_data.encrypted_content_info.content_enc_alg.oid,
+        ID_AES_128_CBC
+    );
+
+    assert_eq!(enveloped_data.recipient_infos.0.len(), 1);
+    let kari = match &enveloped_data.recipient_infos.0[0] {
+        RecipientInfo::Kari(kari) => kari,
+        other => panic!("unexpected recipient: {:?}", other),
+    };
+
+    assert_eq!(kari.version, CmsVersion::V3);
+    assert!(kari.ukm.is_none());
+    assert_eq!(kari.key_enc_alg.oid, DH_SINGLE_PASS_STD_DH_SHA1KDF);
+    assert_eq!(
+        kari.key_enc_alg
+            .parameters_any()
+            .unwrap()
+            .decode_into::<spki::AlgorithmIdentifier<'_>>()
+            .unwrap()
+            .oid,
+        ID_AES_128_WRAP
+    );
+
+    match &kari.originator {
+        OriginatorIdentifierOrKey::OriginatorKey(key) => {
+            assert_eq!(key.algorithm.oid, ID_EC_PUBLIC_KEY);
+            assert_eq!(key.public_key.raw_bytes().len(), 65);
+        }
+        other => panic!("unexpected originator: {:?}", other),
+    }
+
+    assert_eq!(kari.recipient_enc_keys.len(), 1);
+    let recipient_enc_key = &kari.recipient_enc_keys[0];
+    assert_eq!(recipient_enc_key.enc_key.as_bytes().len(), 24);
+
+    match &recipient_enc_key.rid {
+        KeyAgreeRecipientIdentifier::RKeyId(rkey_id) => {
+            assert_eq!(
+                rkey_id.subject_key_identifier.0.as_bytes(),
+                hex!("160ffb432275b52234115c6c5830d4e23a9fc0af")
+            );
+            assert!(rkey_id.date.is_none());
+            assert!(rkey_id.other.is_none());
+        }
+        other => panic!("unexpected recipient identifier: {:?}", other),
+    }
+}
+
+#[test]
+fn decode_multi() {
+    let enveloped_data = enveloped_data(MULTI_DER);
+    assert_eq!(enveloped_data.version, CmsVersion::V3);
+
+    let recipient_infos = &enveloped_data.recipient_infos.0;
+    assert_eq!(recipient_infos.len(), 4);
+    assert!(matches!(recipient_infos[0], RecipientInfo::Ktri(_)));
+
+    match &recipient_infos[1] {
+        RecipientInfo::Kari(kari) => {
+            assert_eq!(kari.key_enc_alg.oid, DH_SINGLE_PASS_STD_DH_SHA1KDF);
+            assert!(matches!(
+                kari.recipient_enc_keys[0].rid,
+                KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(_)
+            ));
+        }
+        other => panic!("unexpected recipient: {:?}", other),
+    }
+
+    match &recipient_infos[2] {
+        RecipientInfo::Kekri(kekri) => {
+            assert_eq!(kekri.version, CmsVersion::V4);
+            assert_eq!(kekri.kek_id.key_identifier.as_bytes(), b"kek-id");
+            assert_eq!(kekri.key_enc_alg.oid, ID_AES_128_WRAP);
+            assert_eq!(kekri.enc_key.as_bytes().len(), 40);
+        }
+        other => panic!("unexpected recipient: {:?}", other),
+    }
+
+    match &recipient_infos[3] {
+        RecipientInfo::Pwri(pwri) => {
+            assert_eq!(pwri.version, CmsVersion::V0);
+            assert_eq!(pwri.key_derivation_alg.unwrap().oid, ID_PBKDF_2);
+            assert_eq!(pwri.key_enc_alg.oid, ID_ALG_PWRI_KEK);
+            assert_eq!(pwri.enc_key.as_bytes().len(), 48);
+        }
+        other => panic!("unexpected recipient: {:?}", other),
+    }
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let content_info = ContentInfo::from_der(SIGNED_DER).unwrap();
+    assert!(EnvelopedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    for der in [RSA_DER, EC_KEYID_DER, MULTI_DER] {
+        let content_info = ContentInfo::from_der(der).unwrap();
+        assert_eq!(content_info.to_vec().unwrap(), der);
+
+        let enveloped_data = EnvelopedData::try_from(&content_info).unwrap();
+        assert_eq!(
+            enveloped_data.to_vec().unwrap(),
+            content_info.content.to_vec().unwrap()
+        );
+    }
+}
+
+#[test]
+fn encode_originator_info_and_unprotected_attrs() {
+    let signed_data = SignedData::try_from(&ContentInfo::from_der(SIGNED_DER).unwrap()).unwrap();
+    let certs: CertificateSet<'_> = signed_data.certificates.unwrap();
+
+    let attr_value = Any::from_der(&hex!("0c0474657374")).unwrap();
+    let attr = Attribute {
+        oid: ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.1"),
+        values: SetOfVec::try_from([attr_value]).unwrap(),
+    };
+
+    let mut enveloped_data = enveloped_data(RSA_DER);
+    enveloped_data.version = CmsVersion::V2;
+    enveloped_data.originator_info = Some(OriginatorInfo {
+        certs: Some(certs),
+        crls: None,
+    });
+    enveloped_data.unprotected_attrs = Some(SetOfVec::try_from([attr]).unwrap());
+
+    let der = enveloped_data.to_vec().unwrap();
+    let decoded = EnvelopedData::from_der(&der).unwrap();
+    assert_eq!(decoded, enveloped_data);
+
+    // `originatorInfo` is the `[0]` tagged field after the version, and
+    // `unprotectedAttrs` the trailing `[1]` tagged one
+    let content = Any::from_der(&der).unwrap();
+    assert_eq!(content.value()[3], 0xa0);
+
+    let content_info = ContentInfo {
+        content_type: ID_ENVELOPED_DATA,
+        content,
+    };
+    let content_info_der = content_info.to_vec().unwrap();
+    let decoded = EnvelopedData::try_from(&ContentInfo::from_der(&content_info_der).unwrap());
+    assert_eq!(decoded.unwrap(), enveloped_data);
+}
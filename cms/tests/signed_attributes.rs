@@ -0,0 +1,187 @@
+//! Signed attributes builder tests
+
+use cms::{
+    der::{
+        asn1::{Any, ObjectIdentifier, OctetString},
+        DateTime, Decode, Encode, Tag, Tagged,
+    },
+    signed_attributes::{EssCertIdV2, SignedAttributesBuilder, SigningCertificateV2},
+    signed_data::{SignedData, SignerInfo},
+    ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::{ID_AA_SIGNING_CERTIFICATE_V_2, ID_CONTENT_TYPE, ID_DATA, ID_SIGNING_TIME},
+    rfc5912::{ID_SHA_256, ID_SHA_384},
+};
+use hex_literal::hex;
+use sha2::{Digest, Sha256};
+use spki::AlgorithmIdentifier;
+use x509_cert::{attr::Attributes, ext::pkix::name::GeneralName};
+
+/// `SignedData` with detached content and CAdES-BES signed attributes,
+/// signed with ECDSA by OpenSSL.
+const CADES_DER: &[u8] = include_bytes!("examples/signed-ec-cades.der");
+
+/// Signed message.
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+fn signer_info(der: &[u8]) -> SignerInfo<'_> {
+    let signed_data = SignedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap();
+    signed_data.signer_infos.0.into_iter().next().unwrap()
+}
+
+fn attr_value<'a>(attrs: &Attributes<'a>, oid: ObjectIdentifier) -> Any<'a> {
+    let attr = attrs.iter().find(|attr| attr.oid == oid).unwrap();
+    assert_eq!(attr.values.len(), 1);
+    attr.values.as_slice()[0]
+}
+
+fn sha256_alg() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: ID_SHA_256,
+        parameters: None,
+    }
+}
+
+/// Builder with the required attributes.
+fn builder() -> SignedAttributesBuilder {
+    let mut builder = SignedAttributesBuilder::new();
+    builder
+        .content_type(ID_DATA)
+        .unwrap()
+        .message_digest(&Sha256::digest(MESSAGE))
+        .unwrap();
+    builder
+}
+
+#[cfg(feature = "sign")]
+#[test]
+fn rebuild_openssl_cades() {
+    let signer_info = signer_info(CADES_DER);
+    let expected = signer_info.signed_attrs.unwrap().to_vec().unwrap();
+
+    let signed_data = SignedData::try_from(&ContentInfo::from_der(CADES_DER).unwrap()).unwrap();
+    let cert = signed_data.x509_certificates().next().unwrap();
+
+    // Added in the reverse of DER order
+    let mut builder = SignedAttributesBuilder::new();
+    builder
+        .signing_certificate::<Sha256>(cert, sha256_alg())
+        .unwrap()
+        .message_digest(&Sha256::digest(MESSAGE))
+        .unwrap()
+        .signing_time(DateTime::new(2026, 10, 16, 11, 43, 6).unwrap())
+        .unwrap()
+        .content_type(ID_DATA)
+        .unwrap();
+
+    assert_eq!(builder.to_der().unwrap(), expected);
+}
+
+#[test]
+fn decode_openssl_signing_certificate_v2() {
+    let signed_data = SignedData::try_from(&ContentInfo::from_der(CADES_DER).unwrap()).unwrap();
+    let cert = signed_data.x509_certificates().next().unwrap();
+
+    let signed_attrs = signer_info(CADES_DER).signed_attrs.unwrap();
+    let value = attr_value(&signed_attrs, ID_AA_SIGNING_CERTIFICATE_V_2);
+    let signing_certificate: SigningCertificateV2<'_> = value.decode_into().unwrap();
+
+    assert!(signing_certificate.policies.is_none());
+    assert_eq!(signing_certificate.certs.len(), 1);
+
+    let ess_cert_id = &signing_certificate.certs[0];
+    assert_eq!(ess_cert_id.hash_algorithm, sha256_alg());
+    assert_eq!(
+        ess_cert_id.cert_hash.as_bytes(),
+        Sha256::digest(cert.to_vec().unwrap()).as_slice()
+    );
+
+    let issuer_serial = ess_cert_id.issuer_serial.as_ref().unwrap();
+    assert_eq!(
+        issuer_serial.serial_number,
+        cert.tbs_certificate.serial_number
+    );
+    assert_eq!(
+        issuer_serial.issuer,
+        [GeneralName::DirectoryName(
+            cert.tbs_certificate.issuer.clone()
+        )]
+    );
+
+    assert_eq!(
+        signing_certificate.to_vec().unwrap(),
+        value.to_vec().unwrap()
+    );
+}
+
+#[test]
+fn ess_cert_id_v2_default_hash_algorithm() {
+    let cert_hash = [0x11; 32];
+
+    // `hashAlgorithm` is omitted when it is SHA-256
+    let ess_cert_id = EssCertIdV2 {
+        hash_algorithm: sha256_alg(),
+        cert_hash: OctetString::new(&cert_hash).unwrap(),
+        issuer_serial: None,
+    };
+    let der = ess_cert_id.to_vec().unwrap();
+    assert_eq!(der[..4], hex!("30220420"));
+    assert_eq!(EssCertIdV2::from_der(&der).unwrap(), ess_cert_id);
+
+    let ess_cert_id = EssCertIdV2 {
+        hash_algorithm: AlgorithmIdentifier {
+            oid: ID_SHA_384,
+            parameters: None,
+        },
+        ..ess_cert_id
+    };
+    let der = ess_cert_id.to_vec().unwrap();
+    assert_eq!(der[..17], hex!("302f300b06096086480165030402020420"));
+    assert_eq!(EssCertIdV2::from_der(&der).unwrap(), ess_cert_id);
+}
+
+#[test]
+fn signing_time_encoding() {
+    let mut builder = builder();
+
+    builder
+        .signing_time(DateTime::new(2049, 12, 31, 23, 59, 59).unwrap())
+        .unwrap();
+    let attrs = builder.build().unwrap();
+    assert_eq!(attr_value(&attrs, ID_SIGNING_TIME).tag(), Tag::UtcTime);
+
+    builder
+        .signing_time(DateTime::new(2050, 1, 1, 0, 0, 0).unwrap())
+        .unwrap();
+    let attrs = builder.build().unwrap();
+    assert_eq!(
+        attr_value(&attrs, ID_SIGNING_TIME).tag(),
+        Tag::GeneralizedTime
+    );
+    assert_eq!(attrs.len(), 3);
+}
+
+#[test]
+fn missing_required_attributes() {
+    let mut builder = SignedAttributesBuilder::new();
+    builder.content_type(ID_DATA).unwrap();
+    assert_eq!(builder.build(), Err(cms::Error::AttributesInvalid));
+
+    let mut builder = SignedAttributesBuilder::new();
+    builder.message_digest(&Sha256::digest(MESSAGE)).unwrap();
+    assert_eq!(builder.to_der(), Err(cms::Error::AttributesInvalid));
+}
+
+#[test]
+fn attribute_replaced() {
+    let mut builder = builder();
+    builder.content_type(ID_SIGNING_TIME).unwrap();
+
+    let attrs = builder.build().unwrap();
+    assert_eq!(attrs.len(), 2);
+    assert_eq!(
+        attr_value(&attrs, ID_CONTENT_TYPE).oid().unwrap(),
+        ID_SIGNING_TIME
+    );
+}
@@ -0,0 +1,202 @@
+//! `SignedData` tests
+
+use cms::{
+    cert::CertificateChoices,
+    der::{
+        asn1::{Any, OctetString},
+        Decode, Encode,
+    },
+    revocation::RevocationInfoChoice,
+    signed_data::{SignedData, SignerIdentifier},
+    CmsVersion, ContentInfo,
+};
+use const_oid::db::{
+    rfc5911::{ID_CONTENT_TYPE, ID_DATA, ID_MESSAGE_DIGEST, ID_SIGNED_DATA},
+    rfc5912::{ECDSA_WITH_SHA_256, ID_SHA_256, RSA_ENCRYPTION},
+};
+use hex_literal::hex;
+
+/// `SignedData` with encapsulated content, signed with RSA.
+const RSA_ATTACHED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+/// `SignedData` with detached content, signed with RSA.
+const RSA_DETACHED_DER: &[u8] = include_bytes!("examples/signed-rsa-detached.der");
+
+/// `SignedData` with encapsulated content, signed with ECDSA, identifying the
+/// signer by subject key identifier and without signed attributes.
+const EC_KEYID_NOATTR_DER: &[u8] = include_bytes!("examples/signed-ec-keyid-noattr.der");
+
+/// Degenerate (certs-only) `SignedData` containing a certificate and a CRL.
+const CERTS_CRL_DER: &[u8] = include_bytes!("examples/certs-crl.p7b");
+
+/// Signed message.
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+/// SHA-256 digest of [`MESSAGE`].
+const MESSAGE_DIGEST: [u8; 32] =
+    hex!("e731a36bbff033b024e8b760edd0b1a8931df17a2af02fb3eb7cfa9a3afd0987");
+
+fn signed_data(der: &[u8]) -> SignedData<'_> {
+    let content_info = ContentInfo::from_der(der).unwrap();
+    assert_eq!(content_info.content_type, ID_SIGNED_DATA);
+    SignedData::try_from(&content_info).unwrap()
+}
+
+#[test]
+fn decode_rsa_attached() {
+    let signed_data = signed_data(RSA_ATTACHED_DER);
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert_eq!(signed_data.digest_algorithms.len(), 1);
+    assert_eq!(signed_data.digest_algorithms.as_slice()[0].oid, ID_SHA_256);
+    assert_eq!(signed_data.encap_content_info.econtent_type, ID_DATA);
+    assert_eq!(
+        signed_data.encap_content_info.econtent.unwrap().as_bytes(),
+        MESSAGE
+    );
+    assert!(signed_data.crls.is_none());
+
+    let certificates = signed_data.certificates.unwrap();
+    assert_eq!(certificates.0.len(), 1);
+    let cert = match &certificates.0[0] {
+        CertificateChoices::Certificate(cert) => cert,
+        other => panic!("unexpected certificate: {:?}", other),
+    };
+
+    assert_eq!(signed_data.signer_infos.0.len(), 1);
+    let signer_info = &signed_data.signer_infos.0[0];
+    assert_eq!(signer_info.version, CmsVersion::V1);
+    assert_eq!(signer_info.digest_alg.oid, ID_SHA_256);
+    assert_eq!(signer_info.signature_algorithm.oid, RSA_ENCRYPTION);
+    assert_eq!(signer_info.signature.as_bytes().len(), 256);
+    assert!(signer_info.unsigned_attrs.is_none());
+
+    match &signer_info.sid {
+        SignerIdentifier::IssuerAndSerialNumber(sid) => {
+            assert_eq!(sid.issuer, cert.tbs_certificate.issuer);
+            assert_eq!(sid.serial_number, cert.tbs_certificate.serial_number);
+            assert_eq!(sid.serial_number.as_bytes(), hex!("a4332e8de969e3bb"));
+        }
+        other => panic!("unexpected signer identifier: {:?}", other),
+    }
+
+    let signed_attrs = signer_info.signed_attrs.as_ref().unwrap();
+    assert_eq!(signed_attrs.len(), 4);
+
+    let content_type = signed_attrs
+        .iter()
+        .find(|attr| attr.oid == ID_CONTENT_TYPE)
+        .unwrap();
+    assert_eq!(content_type.values.as_slice()[0].oid().unwrap(), ID_DATA);
+
+    let message_digest = signed_attrs
+        .iter()
+        .find(|attr| attr.oid == ID_MESSAGE_DIGEST)
+        .unwrap();
+    assert_eq!(
+        message_digest.values.as_slice()[0]
+            .octet_string()
+            .unwrap()
+            .as_bytes(),
+        MESSAGE_DIGEST
+    );
+}
+
+#[test]
+fn decode_rsa_detached() {
+    let signed_data = signed_data(RSA_DETACHED_DER);
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert_eq!(signed_data.encap_content_info.econtent_type, ID_DATA);
+    assert!(signed_data.encap_content_info.econtent.is_none());
+    assert_eq!(signed_data.certificates.unwrap().0.len(), 1);
+    assert_eq!(signed_data.signer_infos.0.len(), 1);
+}
+
+#[test]
+fn decode_ec_keyid_noattr() {
+    let signed_data = signed_data(EC_KEYID_NOATTR_DER);
+    assert_eq!(signed_data.version, CmsVersion::V3);
+    assert_eq!(
+        signed_data.encap_content_info.econtent.unwrap().as_bytes(),
+        MESSAGE
+    );
+
+    let signer_info = &signed_data.signer_infos.0[0];
+    assert_eq!(signer_info.version, CmsVersion::V3);
+    assert_eq!(signer_info.signature_algorithm.oid, ECDSA_WITH_SHA_256);
+    assert!(signer_info.signed_attrs.is_none());
+
+    match &signer_info.sid {
+        SignerIdentifier::SubjectKeyIdentifier(ski) => assert_eq!(
+            ski.0.as_bytes(),
+            hex!("160ffb432275b52234115c6c5830d4e23a9fc0af")
+        ),
+        other => panic!("unexpected signer identifier: {:?}", other),
+    }
+}
+
+#[test]
+fn decode_certs_crl() {
+    let signed_data = signed_data(CERTS_CRL_DER);
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert!(signed_data.digest_algorithms.is_empty());
+    assert_eq!(signed_data.encap_content_info.econtent_type, ID_DATA);
+    assert!(signed_data.encap_content_info.econtent.is_none());
+    assert!(signed_data.signer_infos.0.is_empty());
+    assert_eq!(signed_data.certificates.unwrap().0.len(), 1);
+
+    let crls = signed_data.crls.unwrap();
+    assert_eq!(crls.0.len(), 1);
+    assert!(matches!(crls.0[0], RevocationInfoChoice::Crl(_)));
+}
+
+#[test]
+fn decode_wrong_content_type() {
+    let mut content_info = ContentInfo::from_der(RSA_ATTACHED_DER).unwrap();
+    content_info.content_type = ID_DATA;
+    assert!(SignedData::try_from(&content_info).is_err());
+}
+
+#[test]
+fn round_trip() {
+    for der in [
+        RSA_ATTACHED_DER,
+        RSA_DETACHED_DER,
+        EC_KEYID_NOATTR_DER,
+        CERTS_CRL_DER,
+    ] {
+        let content_info = ContentInfo::from_der(der).unwrap();
+        assert_eq!(content_info.to_vec().unwrap(), der);
+
+        let signed_data = SignedData::try_from(&content_info).unwrap();
+        let signed_data_der = signed_data.to_vec().unwrap();
+        assert_eq!(signed_data_der, content_info.content.to_vec().unwrap());
+
+        // Wrap the re-encoded `SignedData` in a new `ContentInfo`
+        let content_info = ContentInfo {
+            content_type: ID_SIGNED_DATA,
+            content: Any::from_der(&signed_data_der).unwrap(),
+        };
+        assert_eq!(content_info.to_vec().unwrap(), der);
+    }
+}
+
+#[test]
+fn encode_detached() {
+    let signed_data_der = ContentInfo::from_der(EC_KEYID_NOATTR_DER)
+        .unwrap()
+        .content
+        .to_vec()
+        .unwrap();
+
+    // Detach the content, then reattach it
+    let mut signed_data = SignedData::from_der(&signed_data_der).unwrap();
+    let econtent = signed_data.encap_content_info.econtent.take();
+    assert_eq!(econtent, Some(OctetString::new(MESSAGE).unwrap()));
+
+    let detached_der = signed_data.to_vec().unwrap();
+    assert_eq!(SignedData::from_der(&detached_der).unwrap(), signed_data);
+    assert!(detached_der.len() < signed_data_der.len());
+
+    signed_data.encap_content_info.econtent = econtent;
+    assert_eq!(signed_data.to_vec().unwrap(), signed_data_der);
+}
@@ -0,0 +1,111 @@
+//! S/MIME entity tests
+
+#![cfg(feature = "smime")]
+
+use cms::smime::{self, SmimeType};
+
+/// S/MIME entity containing [`ENVELOPED_DER`], produced by OpenSSL.
+const ENVELOPED_EML: &str = include_str!("examples/smime-enveloped-rsa.eml");
+
+/// `EnvelopedData` with a key transport (RSA) recipient.
+const ENVELOPED_DER: &[u8] = include_bytes!("examples/enveloped-rsa.der");
+
+/// `SignedData` with encapsulated content, signed with RSA.
+const SIGNED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+/// Certs-only `SignedData` containing an RSA and an ECDSA certificate.
+const CERTS_ONLY_DER: &[u8] = include_bytes!("examples/certs-chain.p7b");
+
+/// `AuthEnvelopedData` with AES-256-GCM and a key transport (RSA) recipient.
+const AUTH_ENVELOPED_DER: &[u8] = include_bytes!("examples/auth-enveloped-rsa.der");
+
+/// `CompressedData` with zlib compressed content.
+const COMPRESSED_DER: &[u8] = include_bytes!("examples/compressed-zlib.der");
+
+/// `DigestedData`, which has no `smime-type`.
+const DIGESTED_DER: &[u8] = include_bytes!("examples/digested-sha256.der");
+
+#[test]
+fn unwrap_openssl() {
+    assert_eq!(smime::unwrap(ENVELOPED_EML).unwrap(), ENVELOPED_DER);
+}
+
+#[test]
+fn wrap_round_trip() {
+    for (der, smime_type, file_name) in [
+        (ENVELOPED_DER, "enveloped-data", "smime.p7m"),
+        (SIGNED_DER, "signed-data", "smime.p7m"),
+        (CERTS_ONLY_DER, "certs-only", "smime.p7c"),
+        (AUTH_ENVELOPED_DER, "authEnveloped-data", "smime.p7m"),
+        (COMPRESSED_DER, "compressed-data", "smime.p7z"),
+    ] {
+        let entity = smime::wrap(der).unwrap();
+        let content_type = format!(
+            "Content-Type: application/pkcs7-mime; smime-type={}; name=\"{}\"\r\n",
+            smime_type, file_name
+        );
+        assert!(entity.starts_with(&content_type));
+        assert!(entity.contains("\r\nContent-Transfer-Encoding: base64\r\n"));
+
+        let (_, body) = entity.split_once("\r\n\r\n").unwrap();
+        assert!(body.ends_with("\r\n"));
+        assert!(body.split("\r\n").all(|line| line.len() <= 76));
+
+        assert_eq!(smime::unwrap(&entity).unwrap(), der);
+    }
+}
+
+#[test]
+fn wrap_unsupported_content_type() {
+    assert_eq!(smime::wrap(DIGESTED_DER), Err(cms::Error::Mime));
+}
+
+#[test]
+fn unwrap_smime_type_mismatch() {
+    let entity = ENVELOPED_EML.replace("smime-type=enveloped-data", "smime-type=signed-data");
+    assert_eq!(smime::unwrap(&entity), Err(cms::Error::Mime));
+
+    // Certs-only messages are commonly labelled as `signed-data`
+    let entity = smime::wrap(CERTS_ONLY_DER)
+        .unwrap()
+        .replace("smime-type=certs-only", "smime-type=signed-data");
+    assert_eq!(smime::unwrap(&entity).unwrap(), CERTS_ONLY_DER);
+}
+
+#[test]
+fn unwrap_folded_legacy_headers() {
+    let (_, body) = ENVELOPED_EML.split_once("\n\n").unwrap();
+    let entity = format!(
+        "content-type: application/x-pkcs7-mime;\r\n\
+         \tsmime-type=\"Enveloped-Data\"; name=smime.p7m\r\n\
+         CONTENT-TRANSFER-ENCODING: BASE64\r\n\
+         \r\n{}",
+        body
+    );
+    assert_eq!(smime::unwrap(&entity).unwrap(), ENVELOPED_DER);
+}
+
+#[test]
+fn unwrap_invalid() {
+    let (headers, body) = ENVELOPED_EML.split_once("\n\n").unwrap();
+
+    for entity in [
+        ENVELOPED_EML.replace("application/pkcs7-mime", "application/pkcs7-signature"),
+        ENVELOPED_EML.replace("Content-Transfer-Encoding: base64\n", ""),
+        ENVELOPED_EML.replace("smime-type=enveloped-data", "smime-type=signed-receipt"),
+        format!("{}\n\n{}!", headers, body),
+        headers.to_string(),
+    ] {
+        assert_eq!(smime::unwrap(&entity), Err(cms::Error::Mime));
+    }
+}
+
+#[test]
+fn parse_smime_type() {
+    assert_eq!("certs-only".parse(), Ok(SmimeType::CertsOnly));
+    assert_eq!(
+        "AUTHENVELOPED-DATA".parse(),
+        Ok(SmimeType::AuthEnvelopedData)
+    );
+    assert_eq!("signed-receipt".parse::<SmimeType>(), Err(cms::Error::Mime));
+}
@@ -0,0 +1,161 @@
+//! Streaming decoding tests
+
+#![cfg(feature = "std")]
+
+use cms::{
+    der::Decode, enveloped_data::EnvelopedData, signed_data::SignedData, stream::ContentReader,
+    ContentInfo,
+};
+use const_oid::db::rfc5911::ID_DATA;
+use std::io::{self, Read};
+
+/// `SignedData` with encapsulated content, signed with RSA.
+const RSA_ATTACHED_DER: &[u8] = include_bytes!("examples/signed-rsa-attached.der");
+
+/// `SignedData` with detached content, signed with RSA.
+const RSA_DETACHED_DER: &[u8] = include_bytes!("examples/signed-rsa-detached.der");
+
+/// `EnvelopedData` with a key transport (RSA) recipient.
+const ENVELOPED_RSA_DER: &[u8] = include_bytes!("examples/enveloped-rsa.der");
+
+/// Signed message.
+const MESSAGE: &[u8] = b"Hello, CMS!\n";
+
+/// Reader which returns a single byte at a time.
+struct ByteReader<'a>(&'a [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(out)) => {
+                *out = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+fn signed_data(der: &[u8]) -> SignedData<'_> {
+    SignedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap()
+}
+
+fn enveloped_data(der: &[u8]) -> EnvelopedData<'_> {
+    EnvelopedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap()
+}
+
+#[test]
+fn stream_signed_data() {
+    let mut reader = ContentReader::signed_data(RSA_ATTACHED_DER).unwrap();
+    assert_eq!(reader.content_type(), ID_DATA);
+    assert_eq!(reader.content_len(), Some(MESSAGE.len()));
+
+    let mut content = Vec::new();
+    reader.copy_to(&mut content).unwrap();
+    assert_eq!(content, MESSAGE);
+
+    // The rest of the message is returned without the content
+    let der = reader.finish().unwrap();
+    let mut expected = signed_data(RSA_ATTACHED_DER);
+    expected.encap_content_info.econtent = None;
+    assert_eq!(signed_data(&der), expected);
+}
+
+#[test]
+fn stream_signed_data_in_chunks() {
+    let mut reader = ContentReader::signed_data(ByteReader(RSA_ATTACHED_DER)).unwrap();
+
+    let mut content = Vec::new();
+    reader.read_to_end(&mut content).unwrap();
+    assert_eq!(content, MESSAGE);
+
+    let der = reader.finish().unwrap();
+    let expected = ContentReader::signed_data(RSA_ATTACHED_DER).unwrap();
+    assert_eq!(der, expected.finish().unwrap());
+}
+
+#[test]
+fn stream_detached_signed_data() {
+    let mut reader = ContentReader::signed_data(RSA_DETACHED_DER).unwrap();
+    assert_eq!(reader.content_type(), ID_DATA);
+    assert_eq!(reader.content_len(), None);
+
+    let mut content = Vec::new();
+    reader.copy_to(&mut content).unwrap();
+    assert!(content.is_empty());
+    assert_eq!(reader.finish().unwrap(), RSA_DETACHED_DER);
+}
+
+#[cfg(feature = "sign")]
+#[test]
+fn stream_signed_data_into_digest() {
+    use cms::digest::Digest;
+    use sha2::Sha256;
+
+    let mut reader = ContentReader::signed_data(RSA_ATTACHED_DER).unwrap();
+    let mut digest = Sha256::new();
+    reader.update_digest(&mut digest).unwrap();
+    assert_eq!(digest.finalize(), Sha256::digest(MESSAGE));
+}
+
+#[test]
+fn stream_enveloped_data() {
+    let expected = enveloped_data(ENVELOPED_RSA_DER);
+    let encrypted_content = expected
+        .encrypted_content_info
+        .encrypted_content
+        .unwrap()
+        .as_bytes();
+
+    let mut reader = ContentReader::enveloped_data(ENVELOPED_RSA_DER).unwrap();
+    assert_eq!(reader.content_type(), ID_DATA);
+    assert_eq!(reader.content_len(), Some(encrypted_content.len()));
+
+    let mut content = Vec::new();
+    reader.copy_to(&mut content).unwrap();
+    assert_eq!(content, encrypted_content);
+
+    let der = reader.finish().unwrap();
+    let mut expected = expected;
+    expected.encrypted_content_info.encrypted_content = None;
+    assert_eq!(enveloped_data(&der), expected);
+}
+
+#[test]
+fn finish_skips_unread_content() {
+    let mut reader = ContentReader::signed_data(RSA_ATTACHED_DER).unwrap();
+    let mut buf = [0u8; 3];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, MESSAGE[..3]);
+
+    let der = reader.finish().unwrap();
+    assert_eq!(signed_data(&der).encap_content_info.econtent, None);
+}
+
+#[test]
+fn reject_wrong_content_type() {
+    assert!(ContentReader::enveloped_data(RSA_ATTACHED_DER).is_err());
+    assert!(ContentReader::signed_data(ENVELOPED_RSA_DER).is_err());
+}
+
+#[test]
+fn reject_truncated() {
+    // Truncated within the content
+    let offset = RSA_ATTACHED_DER
+        .windows(MESSAGE.len())
+        .position(|window| window == MESSAGE)
+        .unwrap();
+    let der = &RSA_ATTACHED_DER[..offset + 3];
+    let mut reader = ContentReader::signed_data(der).unwrap();
+    assert!(reader.copy_to(&mut io::sink()).is_err());
+
+    // Truncated after the content
+    let der = &RSA_ATTACHED_DER[..RSA_ATTACHED_DER.len() - 1];
+    let mut reader = ContentReader::signed_data(der).unwrap();
+    reader.copy_to(&mut io::sink()).unwrap();
+    assert!(reader.finish().is_err());
+
+    // Truncated before the content
+    assert!(ContentReader::signed_data(&RSA_ATTACHED_DER[..offset - 1]).is_err());
+}
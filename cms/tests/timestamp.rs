@@ -0,0 +1,110 @@
+//! Time-stamp token tests
+
+use cms::{
+    der::{
+        asn1::{Any, ObjectIdentifier},
+        Decode, Encode,
+    },
+    signed_data::SignedData,
+    timestamp::{Accuracy, TimeStampToken, TstInfo, ID_AA_TIME_STAMP_TOKEN},
+    ContentInfo,
+};
+use const_oid::db::{rfc5911::ID_SIGNED_DATA, rfc5912::ID_SHA_256};
+use hex_literal::hex;
+use sha2::{Digest, Sha256};
+use x509_cert::ext::pkix::name::GeneralName;
+
+/// RFC 3161 time-stamp token over the signature in [`SIGNED_DER`].
+const TOKEN_DER: &[u8] = include_bytes!("examples/tst-token.der");
+
+/// `SignedData` with detached content, signed with ECDSA.
+const SIGNED_DER: &[u8] = include_bytes!("examples/signed-ec-detached.der");
+
+fn signed_data(der: &[u8]) -> SignedData<'_> {
+    SignedData::try_from(&ContentInfo::from_der(der).unwrap()).unwrap()
+}
+
+#[test]
+fn decode_token() {
+    let token = TimeStampToken::from_der(TOKEN_DER).unwrap();
+    let tst_info = TstInfo::try_from(&token).unwrap();
+
+    assert_eq!(tst_info.version, 1);
+    assert_eq!(
+        tst_info.policy,
+        ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.2.1")
+    );
+    assert_eq!(tst_info.serial_number.as_bytes(), [2]);
+    assert_eq!(tst_info.gen_time.to_unix_duration().as_secs(), 1792150258);
+    assert_eq!(
+        tst_info.accuracy,
+        Some(Accuracy {
+            seconds: Some(1),
+            millis: Some(500),
+            micros: None,
+        })
+    );
+    assert!(!tst_info.ordering);
+    assert_eq!(tst_info.nonce.unwrap().as_bytes(), hex!("919ee8a46f2403fa"));
+    assert!(matches!(tst_info.tsa, Some(GeneralName::DirectoryName(_))));
+    assert!(tst_info.extensions.is_none());
+
+    let message_imprint = &tst_info.message_imprint;
+    assert_eq!(message_imprint.hash_algorithm.oid, ID_SHA_256);
+    assert_eq!(
+        message_imprint.hashed_message.as_bytes(),
+        hex!("c12ef6870d2ab764dd1593aa46c8b9d42a3212afa156a5e75166a32a5a4d844f")
+    );
+
+    // The imprint is over the signature value of the time-stamped signer
+    let signed_data = signed_data(SIGNED_DER);
+    let signature = signed_data.signer_infos.0[0].signature.as_bytes();
+    assert_eq!(
+        message_imprint.hashed_message.as_bytes(),
+        Sha256::digest(signature).as_slice()
+    );
+}
+
+#[test]
+fn decode_tst_info_wrong_content_type() {
+    let signed_data = signed_data(SIGNED_DER);
+    assert!(TstInfo::try_from(&signed_data).is_err());
+}
+
+#[test]
+fn add_and_extract_token() {
+    let mut signed_data = signed_data(SIGNED_DER);
+    let signer_info = &mut signed_data.signer_infos.0[0];
+    assert!(signer_info.unsigned_attrs.is_none());
+    assert_eq!(signer_info.time_stamp_tokens().count(), 0);
+
+    signer_info.add_time_stamp_token(TOKEN_DER).unwrap();
+    let unsigned_attrs = signer_info.unsigned_attrs.as_ref().unwrap();
+    assert_eq!(unsigned_attrs.len(), 1);
+    assert_eq!(unsigned_attrs.as_slice()[0].oid, ID_AA_TIME_STAMP_TOKEN);
+
+    let signed_data_der = signed_data.to_vec().unwrap();
+    let content_info_der = ContentInfo {
+        content_type: ID_SIGNED_DATA,
+        content: Any::from_der(&signed_data_der).unwrap(),
+    }
+    .to_vec()
+    .unwrap();
+
+    let decoded = self::signed_data(&content_info_der);
+    assert_eq!(decoded, signed_data);
+
+    let tokens = decoded.signer_infos.0[0]
+        .time_stamp_tokens()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(tokens, [TimeStampToken::from_der(TOKEN_DER).unwrap()]);
+}
+
+#[test]
+fn add_invalid_token() {
+    let mut signed_data = signed_data(SIGNED_DER);
+    let signer_info = &mut signed_data.signer_infos.0[0];
+    assert!(signer_info.add_time_stamp_token(SIGNED_DER).is_err());
+    assert!(signer_info.unsigned_attrs.is_none());
+}
@@ -1,19 +1,18 @@
 //! Arcs are integer values which exist within an OID's hierarchy.
 
 use crate::{Error, ObjectIdentifier, Result};
-use core::mem;
 
 /// Type alias used to represent an "arc" (i.e. integer identifier value).
 ///
 /// X.660 does not define a maximum size of an arc.
 ///
-/// The current representation is `u32`, which has been selected as being
-/// sufficient to cover the current PKCS/PKIX use cases this library has been
-/// used in conjunction with.
+/// The current representation is `u128`, which is large enough to cover
+/// the current PKCS/PKIX use cases this library has been used in conjunction
+/// with, as well as OIDs containing UUIDs under the `2.25` arc as described
+/// in [ITU X.667].
 ///
-/// Future versions may potentially make it larger if a sufficiently important
-/// use case is discovered.
-pub type Arc = u32;
+/// [ITU X.667]: https://www.itu.int/rec/T-REC-X.667
+pub type Arc = u128;
 
 /// Maximum value of the first arc in an OID.
 pub(crate) const ARC_MAX_FIRST: Arc = 2;
@@ -21,12 +20,6 @@ pub(crate) const ARC_MAX_FIRST: Arc = 2;
 /// Maximum value of the second arc in an OID.
 pub(crate) const ARC_MAX_SECOND: Arc = 39;
 
-/// Maximum number of bytes supported in an arc.
-const ARC_MAX_BYTES: usize = mem::size_of::<Arc>();
-
-/// Maximum value of the last byte in an arc.
-const ARC_MAX_LAST_OCTET: u8 = 0b11110000; // Max bytes of leading 1-bits
-
 /// [`Iterator`] over [`Arc`] values (a.k.a. nodes) in an [`ObjectIdentifier`].
 ///
 /// This iterates over all arcs in an OID, including the root.
@@ -62,21 +55,21 @@ impl<'a> Arcs<'a> {
                 Ok(Some(root.second_arc()))
             }
             Some(offset) => {
-                let mut result = 0;
+                let mut result: Arc = 0;
                 let mut arc_bytes = 0;
 
                 loop {
                     let len = checked_add!(offset, arc_bytes);
 
                     match self.oid.as_bytes().get(len).cloned() {
-                        // The arithmetic below includes advance checks
-                        // against `ARC_MAX_BYTES` and `ARC_MAX_LAST_OCTET`
-                        // which ensure the operations will not overflow.
+                        // The arithmetic below includes an advance check
+                        // that no bits are shifted out of the result, which
+                        // ensures the operations will not overflow.
                         #[allow(clippy::integer_arithmetic)]
                         Some(byte) => {
                             arc_bytes = checked_add!(arc_bytes, 1);
 
-                            if (arc_bytes > ARC_MAX_BYTES) && (byte & ARC_MAX_LAST_OCTET != 0) {
+                            if result.leading_zeros() < 7 {
                                 return Err(Error::ArcTooBig);
                             }
 
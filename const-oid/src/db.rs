@@ -1,12 +1,29 @@
 //! OID Names Database
 //!
 //! The contents of this database are generated from the official IANA
-//! [Object Identifier Descriptors] Registry CSV file and from [RFC 5280].
+//! [Object Identifier Descriptors] Registry CSV file and from [RFC 5280]
+//! and the [RFC 5911]/[RFC 5912] ASN.1 modules.
 //! If we are missing values you care about, please contribute a patch to
 //! `oiddbgen` (a subcrate in the source code) to generate the values from
 //! the relevant standard.
 //!
+//! # Usage
+//!
+//! The [`DB`] constant can be used to look up OIDs by name, and names by OID:
+//!
+//! ```
+//! use const_oid::{db::{rfc5912::ID_EC_PUBLIC_KEY, DB}, ObjectIdentifier};
+//!
+//! assert_eq!(DB.by_name("id-ecPublicKey"), Some(&ID_EC_PUBLIC_KEY));
+//!
+//! let oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+//! assert_eq!(DB.by_oid(&oid), Some("rsaEncryption"));
+//! assert_eq!(DB.describe(&oid).to_string(), "rsaEncryption (1.2.840.113549.1.1.1)");
+//! ```
+//!
 //! [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280
+//! [RFC 5911]: https://datatracker.ietf.org/doc/html/rfc5911
+//! [RFC 5912]: https://datatracker.ietf.org/doc/html/rfc5912
 //! [Object Identifier Descriptors]: https://www.iana.org/assignments/ldap-parameters/ldap-parameters.xhtml#ldap-parameters-3
 
 #![allow(clippy::integer_arithmetic, missing_docs)]
@@ -16,6 +33,7 @@ mod gen;
 pub use gen::*;
 
 use crate::{Error, ObjectIdentifier};
+use core::fmt;
 
 /// A const implementation of byte equals.
 const fn eq(lhs: &[u8], rhs: &[u8]) -> bool {
@@ -84,6 +102,17 @@ impl<'a> Database<'a> {
         None
     }
 
+    /// Describes an OID using its name (if known) followed by its dotted
+    /// string form, e.g. `id-ecPublicKey (1.2.840.10045.2.1)`.
+    ///
+    /// OIDs which aren't in the database are displayed in dotted form alone.
+    pub const fn describe<'b>(&self, oid: &'b ObjectIdentifier) -> Description<'a, 'b> {
+        Description {
+            name: self.by_oid(oid),
+            oid,
+        }
+    }
+
     /// Finds a named oid by its associated name.
     pub const fn by_name(&self, name: &str) -> Option<&'a ObjectIdentifier> {
         let mut i = 0;
@@ -101,6 +130,25 @@ impl<'a> Database<'a> {
     }
 }
 
+/// Descriptive form of an OID, returned by [`Database::describe`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Description<'a, 'b> {
+    /// Name of the OID, if known
+    name: Option<&'a str>,
+
+    /// OID being described
+    oid: &'b ObjectIdentifier,
+}
+
+impl fmt::Display for Description<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{} ({})", name, self.oid),
+            None => write!(f, "{}", self.oid),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ObjectIdentifier;
@@ -123,4 +171,28 @@ mod tests {
 
         assert_eq!(None, super::DB.by_name("purplePeopleEater"));
     }
+
+    #[test]
+    fn describe() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(super::DB.describe(&CN).to_string(), "cn (2.5.4.3)");
+
+        let unknown = ObjectIdentifier::new_unwrap("0.1.2.3.4.5.6.7.8.9");
+        assert_eq!(
+            super::DB.describe(&unknown).to_string(),
+            "0.1.2.3.4.5.6.7.8.9"
+        );
+    }
+
+    #[test]
+    fn algorithm_names() {
+        use super::rfc5912::{ID_EC_PUBLIC_KEY, RSA_ENCRYPTION};
+
+        assert_eq!(Some("id-ecPublicKey"), super::DB.by_oid(&ID_EC_PUBLIC_KEY));
+        assert_eq!(Some("rsaEncryption"), super::DB.by_oid(&RSA_ENCRYPTION));
+        assert_eq!(Some(&ID_EC_PUBLIC_KEY), super::DB.by_name("id-ecPublicKey"));
+        assert_eq!(Ok("id-ce-subjectAltName"), super::DB.resolve("2.5.29.17"));
+    }
 }
@@ -80,7 +80,7 @@ impl Encoder {
                 let nbytes = base128_len(arc);
 
                 // Shouldn't overflow on any 16-bit+ architectures
-                if self.cursor + nbytes + 1 >= ObjectIdentifier::MAX_SIZE {
+                if self.cursor + nbytes + 1 > ObjectIdentifier::MAX_SIZE {
                     return Err(Error::Length);
                 }
 
@@ -111,36 +111,33 @@ impl Encoder {
     }
 
     /// Encode a single byte of a Base 128 value.
-    const fn encode_base128_byte(mut self, mut n: u32, i: usize, continued: bool) -> Result<Self> {
+    ///
+    /// Writes the low 7 bits of `n` at offset `i` from the cursor, followed
+    /// by the remaining bits at the preceding offsets.
+    const fn encode_base128_byte(mut self, n: Arc, i: usize, continued: bool) -> Result<Self> {
         let mask = if continued { 0b10000000 } else { 0 };
+        self.bytes[checked_add!(self.cursor, i)] = (n & 0b1111111) as u8 | mask;
 
-        // Underflow checked by branch
-        #[allow(clippy::integer_arithmetic)]
-        if n > 0x80 {
-            self.bytes[checked_add!(self.cursor, i)] = (n & 0b1111111) as u8 | mask;
-            n >>= 7;
-
-            if i > 0 {
-                self.encode_base128_byte(n, i.saturating_sub(1), true)
-            } else {
-                Err(Error::Base128)
-            }
+        if i > 0 {
+            self.encode_base128_byte(n >> 7, i.saturating_sub(1), true)
         } else {
-            self.bytes[self.cursor] = n as u8 | mask;
             Ok(self)
         }
     }
 }
 
 /// Compute the length - 1 of an arc when encoded in base 128.
+#[allow(clippy::integer_arithmetic)]
 const fn base128_len(arc: Arc) -> usize {
-    match arc {
-        0..=0x7f => 0,
-        0x80..=0x3fff => 1,
-        0x4000..=0x1fffff => 2,
-        0x200000..=0x1fffffff => 3,
-        _ => 4,
+    let mut len = 0;
+    let mut n = arc >> 7;
+
+    while n > 0 {
+        len += 1;
+        n >>= 7;
     }
+
+    len
 }
 
 #[cfg(test)]
@@ -162,4 +159,17 @@ mod tests {
         let encoder = encoder.arc(1).unwrap();
         assert_eq!(&encoder.bytes[..encoder.cursor], EXAMPLE_OID_BER);
     }
+
+    #[test]
+    fn encode_base128_boundaries() {
+        let encoder = Encoder::new().arc(1).unwrap().arc(2).unwrap();
+        let encoder = encoder.arc(127).unwrap();
+        let encoder = encoder.arc(128).unwrap();
+        let encoder = encoder.arc(16383).unwrap();
+        let encoder = encoder.arc(16384).unwrap();
+        assert_eq!(
+            &encoder.bytes[..encoder.cursor],
+            &hex!("2A7F8100FF7F818000")
+        );
+    }
 }
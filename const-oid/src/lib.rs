@@ -27,6 +27,7 @@ mod arcs;
 mod encoder;
 mod error;
 mod parser;
+mod urn;
 
 #[cfg(feature = "db")]
 #[cfg_attr(docsrs, doc(cfg(feature = "db")))]
@@ -35,10 +36,14 @@ pub mod db;
 pub use crate::{
     arcs::{Arc, Arcs},
     error::{Error, Result},
+    urn::Urn,
 };
 
 use crate::encoder::Encoder;
-use core::{fmt, str::FromStr};
+use core::{fmt, iter, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
 
 /// A trait which associates an OID with a type.
 pub trait AssociatedOid {
@@ -59,7 +64,7 @@ pub trait AssociatedOid {
 /// - The OID MUST have at least 3 arcs
 /// - The first arc MUST be within the range 0-2
 /// - The second arc MUST be within the range 0-39
-/// - The BER/DER encoding of the OID MUST be shorter than
+/// - The BER/DER encoding of the OID MUST be no longer than
 ///   [`ObjectIdentifier::MAX_SIZE`]
 #[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct ObjectIdentifier {
@@ -73,8 +78,16 @@ pub struct ObjectIdentifier {
 #[allow(clippy::len_without_is_empty)]
 impl ObjectIdentifier {
     /// Maximum size of a BER/DER-encoded OID in bytes.
+    ///
+    /// This can be raised to 255 bytes by enabling the `large-oids` feature,
+    /// at the cost of making [`ObjectIdentifier`] 256 bytes in size.
+    #[cfg(not(feature = "large-oids"))]
     pub const MAX_SIZE: usize = 39; // makes `ObjectIdentifier` 40-bytes total w\ 1-byte length
 
+    /// Maximum size of a BER/DER-encoded OID in bytes.
+    #[cfg(feature = "large-oids")]
+    pub const MAX_SIZE: usize = 255; // maximum representable by the 1-byte length
+
     /// Parse an [`ObjectIdentifier`] from the dot-delimited string form,
     /// panicking on parse errors.
     ///
@@ -110,6 +123,21 @@ impl ObjectIdentifier {
     }
 
     /// Parse an OID from a slice of [`Arc`] values (i.e. integers).
+    ///
+    /// This can be used to construct OIDs at runtime from arcs obtained from
+    /// other sources (e.g. LDAP, SNMP, or configuration files):
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// let arcs = [1, 2, 840, 10045, 2, 1];
+    /// let oid = ObjectIdentifier::from_arcs(arcs).unwrap();
+    /// assert_eq!(oid, ObjectIdentifier::new_unwrap("1.2.840.10045.2.1"));
+    ///
+    /// // Child OIDs can be derived using `push_arc`
+    /// let child = oid.push_arc(1).unwrap();
+    /// assert_eq!(child, ObjectIdentifier::new_unwrap("1.2.840.10045.2.1.1"));
+    /// ```
     pub fn from_arcs(arcs: impl IntoIterator<Item = Arc>) -> Result<Self> {
         let mut encoder = Encoder::new();
 
@@ -126,8 +154,9 @@ impl ObjectIdentifier {
 
         match len {
             0 => return Err(Error::Empty),
+            1..=2 => return Err(Error::NotEnoughArcs),
             3..=Self::MAX_SIZE => (),
-            _ => return Err(Error::NotEnoughArcs),
+            _ => return Err(Error::Length),
         }
         let mut bytes = [0u8; Self::MAX_SIZE];
         bytes[..len].copy_from_slice(ber_bytes);
@@ -164,15 +193,91 @@ impl ObjectIdentifier {
         Arcs::new(self)
     }
 
+    /// Display this OID as a URN, e.g. `urn:oid:1.2.840.113549.1.1.1`.
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// let oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+    /// assert_eq!(oid.urn().to_string(), "urn:oid:1.2.840.113549.1.1.1");
+    /// ```
+    pub fn urn(&self) -> Urn<'_> {
+        Urn::new(self)
+    }
+
     /// Get the length of this [`ObjectIdentifier`] in arcs.
     pub fn len(&self) -> usize {
         self.arcs().count()
     }
 
     /// Get the parent OID of this one (if applicable).
-    pub fn parent(&self) -> Option<Self> {
-        let num_arcs = self.len().checked_sub(1)?;
-        Self::from_arcs(self.arcs().take(num_arcs)).ok()
+    ///
+    /// Returns `None` if the parent would have fewer than 3 arcs.
+    pub const fn parent(&self) -> Option<Self> {
+        let mut bytes = self.bytes;
+        let mut i = (self.length as usize).saturating_sub(1);
+
+        // Clear the bytes of the last arc, stopping at the end of the arc
+        // which precedes it (the first byte is always the root arcs)
+        while i > 0 {
+            bytes[i] = 0;
+            i = i.saturating_sub(1);
+
+            if bytes[i] & 0b10000000 == 0 {
+                break;
+            }
+        }
+
+        if i == 0 {
+            return None;
+        }
+
+        Some(Self {
+            bytes,
+            length: i.saturating_add(1) as u8,
+        })
+    }
+
+    /// Iterate over the ancestors of this OID, starting with its parent.
+    ///
+    /// Only ancestors with at least 3 arcs are included.
+    pub fn parents(&self) -> impl Iterator<Item = Self> {
+        iter::successors(self.parent(), Self::parent)
+    }
+
+    /// Does this OID begin with the arcs of the given OID?
+    ///
+    /// This is the case if `prefix` is this OID or one of its ancestors,
+    /// which can be used to determine whether an OID falls under a given
+    /// arc:
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// const ID_CE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29");
+    ///
+    /// let oid = ObjectIdentifier::new_unwrap("2.5.29.17");
+    /// assert!(oid.starts_with(&ID_CE));
+    /// assert!(!ID_CE.starts_with(&oid));
+    /// ```
+    pub const fn starts_with(&self, prefix: &Self) -> bool {
+        // Each arc's encoding ends with a byte with the high bit clear, so
+        // a byte-wise prefix always ends on an arc boundary
+        if prefix.length > self.length {
+            return false;
+        }
+
+        let mut i = 0;
+
+        while i < prefix.length as usize {
+            if self.bytes[i] != prefix.bytes[i] {
+                return false;
+            }
+
+            i = i.saturating_add(1);
+        }
+
+        true
     }
 
     /// Push an additional arc onto this OID, returning the child OID.
@@ -183,6 +288,33 @@ impl ObjectIdentifier {
             Err(err) => Err(err),
         }
     }
+
+    /// Push an additional arc onto this OID, returning the child OID and
+    /// panicking on errors.
+    ///
+    /// Like [`ObjectIdentifier::new_unwrap`], this is intended to allow the
+    /// result to be bound to a constant value, e.g. to define a family of
+    /// OIDs from a common base:
+    ///
+    /// ```
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// pub const PKCS_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1");
+    /// pub const RSA_ENCRYPTION: ObjectIdentifier = PKCS_1.push_arc_unwrap(1);
+    /// pub const SHA_256_WITH_RSA_ENCRYPTION: ObjectIdentifier = PKCS_1.push_arc_unwrap(11);
+    ///
+    /// assert_eq!(RSA_ENCRYPTION, ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1"));
+    /// assert_eq!(RSA_ENCRYPTION.parent(), Some(PKCS_1));
+    /// ```
+    ///
+    /// Use [`ObjectIdentifier::push_arc`] for fallible derivation.
+    // TODO(tarcieri): remove this when `Result::unwrap` is `const fn`
+    pub const fn push_arc_unwrap(self, arc: Arc) -> Self {
+        match self.push_arc(arc) {
+            Ok(oid) => oid,
+            Err(err) => err.panic(),
+        }
+    }
 }
 
 impl AsRef<[u8]> for ObjectIdentifier {
@@ -207,6 +339,14 @@ impl TryFrom<&[u8]> for ObjectIdentifier {
     }
 }
 
+impl TryFrom<&[Arc]> for ObjectIdentifier {
+    type Error = Error;
+
+    fn try_from(arcs: &[Arc]) -> Result<Self> {
+        Self::from_arcs(arcs.iter().copied())
+    }
+}
+
 impl From<&ObjectIdentifier> for ObjectIdentifier {
     fn from(oid: &ObjectIdentifier) -> ObjectIdentifier {
         *oid
@@ -236,3 +376,40 @@ impl fmt::Display for ObjectIdentifier {
         Ok(())
     }
 }
+
+/// Serializes OIDs using their dotted string form, e.g. `1.2.840.113549.1.1.1`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for ObjectIdentifier {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes OIDs from their dotted string form, e.g. `1.2.840.113549.1.1.1`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct OidVisitor;
+
+        impl de::Visitor<'_> for OidVisitor {
+            type Value = ObjectIdentifier;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a dot-delimited object identifier")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> core::result::Result<ObjectIdentifier, E> {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(OidVisitor)
+    }
+}
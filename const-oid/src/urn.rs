@@ -0,0 +1,34 @@
+//! OID URN formatting.
+
+use crate::ObjectIdentifier;
+use core::fmt;
+
+/// Prefix of OID URNs, as defined in [RFC 3061].
+///
+/// [RFC 3061]: https://datatracker.ietf.org/doc/html/rfc3061
+const URN_PREFIX: &str = "urn:oid:";
+
+/// Displays an [`ObjectIdentifier`] as a URN, e.g.
+/// `urn:oid:1.2.840.113549.1.1.1`, as defined in [RFC 3061].
+///
+/// Returned by [`ObjectIdentifier::urn`].
+///
+/// [RFC 3061]: https://datatracker.ietf.org/doc/html/rfc3061
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Urn<'a> {
+    /// OID being displayed
+    oid: &'a ObjectIdentifier,
+}
+
+impl<'a> Urn<'a> {
+    /// Create a new URN formatter for the given OID.
+    pub(crate) fn new(oid: &'a ObjectIdentifier) -> Self {
+        Self { oid }
+    }
+}
+
+impl fmt::Display for Urn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", URN_PREFIX, self.oid)
+    }
+}
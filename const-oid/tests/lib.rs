@@ -3,7 +3,7 @@
 // TODO(tarcieri): test full set of OID encoding constraints specified here:
 // <https://misc.daniel-marschall.de/asn.1/oid_facts.html>
 
-use const_oid::{Error, ObjectIdentifier};
+use const_oid::{Arc, Error, ObjectIdentifier};
 use hex_literal::hex;
 use std::string::ToString;
 
@@ -22,6 +22,11 @@ const EXAMPLE_OID_2_STR: &str = "2.16.840.1.101.3.4.1.42";
 const EXAMPLE_OID_2_BER: &[u8] = &hex!("60864801650304012A");
 const EXAMPLE_OID_2: ObjectIdentifier = ObjectIdentifier::new_unwrap(EXAMPLE_OID_2_STR);
 
+/// Example OID value with a UUID arc (from ITU X.667).
+const EXAMPLE_OID_UUID_STR: &str = "2.25.329800735698586629295641978511506172918";
+const EXAMPLE_OID_UUID_BER: &[u8] = &hex!("6983F09DA7EBCFDEE0C7A1A7B2C0948CC8F9D776");
+const EXAMPLE_OID_UUID: ObjectIdentifier = ObjectIdentifier::new_unwrap(EXAMPLE_OID_UUID_STR);
+
 /// Example OID value with a large arc
 const EXAMPLE_OID_LARGE_ARC_STR: &str = "0.9.2342.19200300.100.1.1";
 const EXAMPLE_OID_LARGE_ARC_BER: &[u8] = &hex!("0992268993F22C640101");
@@ -129,7 +134,7 @@ fn display() {
 }
 
 #[test]
-fn try_from_u32_slice() {
+fn try_from_arc_slice() {
     let oid1 = ObjectIdentifier::from_arcs([1, 2, 840, 10045, 2, 1]).unwrap();
     assert_eq!(oid1.arc(0).unwrap(), 1);
     assert_eq!(oid1.arc(1).unwrap(), 2);
@@ -157,6 +162,13 @@ fn try_from_u32_slice() {
         ObjectIdentifier::from_arcs([1, 40, 840, 10045, 3, 1, 7]),
         Err(Error::ArcInvalid { arc: 40 })
     );
+
+    let arcs: &[Arc] = &[0, 9, 2342, 19200300, 100, 1, 1];
+    assert_eq!(ObjectIdentifier::try_from(arcs).unwrap(), EXAMPLE_OID_0);
+    assert_eq!(
+        ObjectIdentifier::from_arcs(EXAMPLE_OID_0.arcs()).unwrap(),
+        EXAMPLE_OID_0
+    );
 }
 
 #[test]
@@ -197,6 +209,62 @@ fn parent() {
     let parent = oid.parent().unwrap();
     assert_eq!(parent, ObjectIdentifier::new("1.2.3").unwrap());
     assert_eq!(parent.parent(), None);
+
+    // Multi-byte arcs
+    assert_eq!(
+        EXAMPLE_OID_0.parent().unwrap().to_string(),
+        "0.9.2342.19200300.100.1"
+    );
+    assert_eq!(
+        EXAMPLE_OID_1.parent().unwrap(),
+        ObjectIdentifier::new("1.2.840.10045.2").unwrap()
+    );
+    assert_eq!(
+        EXAMPLE_OID_UUID.push_arc(1).unwrap().parent(),
+        Some(EXAMPLE_OID_UUID)
+    );
+    assert_eq!(EXAMPLE_OID_UUID.parent(), None);
+}
+
+#[test]
+fn parents() {
+    let parents: Vec<_> = EXAMPLE_OID_0.parents().map(|oid| oid.to_string()).collect();
+    assert_eq!(
+        parents,
+        [
+            "0.9.2342.19200300.100.1",
+            "0.9.2342.19200300.100",
+            "0.9.2342.19200300",
+            "0.9.2342"
+        ]
+    );
+}
+
+#[test]
+fn starts_with() {
+    let prefix = ObjectIdentifier::new("1.2.840").unwrap();
+    assert!(EXAMPLE_OID_1.starts_with(&prefix));
+    assert!(EXAMPLE_OID_1.starts_with(&EXAMPLE_OID_1));
+    assert!(!prefix.starts_with(&EXAMPLE_OID_1));
+    assert!(!EXAMPLE_OID_2.starts_with(&prefix));
+
+    // Arcs must match entirely, not just their leading digits
+    let partial = ObjectIdentifier::new("1.2.84").unwrap();
+    assert!(!EXAMPLE_OID_1.starts_with(&partial));
+
+    for parent in EXAMPLE_OID_LARGE_ARC.parents() {
+        assert!(EXAMPLE_OID_LARGE_ARC.starts_with(&parent));
+    }
+}
+
+#[test]
+fn const_derivation() {
+    const BASE: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840");
+    const CHILD: ObjectIdentifier = BASE.push_arc_unwrap(10045).push_arc_unwrap(2);
+    const PARENT: Option<ObjectIdentifier> = CHILD.parent();
+
+    assert_eq!(CHILD, ObjectIdentifier::new("1.2.840.10045.2").unwrap());
+    assert_eq!(PARENT, ObjectIdentifier::new("1.2.840.10045").ok());
 }
 
 #[test]
@@ -207,3 +275,77 @@ fn push_arc() {
         ObjectIdentifier::new("1.2.3.4").unwrap()
     );
 }
+
+#[test]
+fn uuid_arc() {
+    assert_eq!(EXAMPLE_OID_UUID.as_bytes(), EXAMPLE_OID_UUID_BER);
+    assert_eq!(EXAMPLE_OID_UUID.to_string(), EXAMPLE_OID_UUID_STR);
+    assert_eq!(
+        EXAMPLE_OID_UUID.arc(2).unwrap(),
+        0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6
+    );
+
+    let oid = ObjectIdentifier::from_bytes(EXAMPLE_OID_UUID_BER).unwrap();
+    assert_eq!(oid, EXAMPLE_OID_UUID);
+}
+
+#[test]
+fn max_arc() {
+    let oid = ObjectIdentifier::from_arcs([1, 2, Arc::MAX]).unwrap();
+    assert_eq!(oid.arc(2).unwrap(), Arc::MAX);
+    assert_eq!(
+        ObjectIdentifier::new("1.2.340282366920938463463374607431768211455").unwrap(),
+        oid
+    );
+    assert_eq!(ObjectIdentifier::from_bytes(oid.as_bytes()).unwrap(), oid);
+}
+
+#[test]
+fn arc_too_big() {
+    assert_eq!(
+        ObjectIdentifier::new("1.2.340282366920938463463374607431768211456"),
+        Err(Error::ArcTooBig)
+    );
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&hex!("2A84808080808080808080808080808080808000")),
+        Err(Error::ArcTooBig)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let json = serde_json::to_string(&EXAMPLE_OID_1).unwrap();
+    assert_eq!(json, "\"1.2.840.10045.2.1\"");
+
+    let oid: ObjectIdentifier = serde_json::from_str(&json).unwrap();
+    assert_eq!(oid, EXAMPLE_OID_1);
+
+    assert!(serde_json::from_str::<ObjectIdentifier>("\"1.2\"").is_err());
+    assert!(serde_json::from_str::<ObjectIdentifier>("[1, 2, 840]").is_err());
+}
+
+#[test]
+fn max_size() {
+    // Each arc after the root is encoded as a single byte
+    let arcs = (0..=ObjectIdentifier::MAX_SIZE).map(|i| if i == 0 { 1 } else { 2 });
+    let oid = ObjectIdentifier::from_arcs(arcs).unwrap();
+    assert_eq!(oid.as_bytes().len(), ObjectIdentifier::MAX_SIZE);
+    assert_eq!(ObjectIdentifier::from_bytes(oid.as_bytes()).unwrap(), oid);
+    assert_eq!(oid.to_string().parse::<ObjectIdentifier>().unwrap(), oid);
+
+    assert_eq!(oid.push_arc(1), Err(Error::Length));
+    assert_eq!(
+        ObjectIdentifier::from_bytes(&[0x2A; ObjectIdentifier::MAX_SIZE + 1]),
+        Err(Error::Length)
+    );
+}
+
+#[test]
+fn urn() {
+    assert_eq!(EXAMPLE_OID_1.urn().to_string(), "urn:oid:1.2.840.10045.2.1");
+    assert_eq!(
+        EXAMPLE_OID_UUID.urn().to_string(),
+        "urn:oid:2.25.329800735698586629295641978511506172918"
+    );
+}
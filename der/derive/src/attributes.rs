@@ -5,7 +5,7 @@ use proc_macro2::TokenStream;
 use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
 use std::{fmt::Debug, str::FromStr};
-use syn::{Attribute, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path};
+use syn::{Attribute, Ident, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path};
 
 /// Attribute name.
 pub(crate) const ATTR_NAME: &str = "asn1";
@@ -19,14 +19,31 @@ pub(crate) struct TypeAttrs {
     /// Tagging mode for this type: `EXPLICIT` or `IMPLICIT`, supplied as
     /// `#[asn1(tag_mode = "...")]`.
     ///
+    /// This is the default tagging mode for all `CONTEXT-SPECIFIC` fields
+    /// or variants of the type, i.e. the equivalent of an ASN.1 module's
+    /// `DEFINITIONS IMPLICIT TAGS`. It can be overridden on a per-field basis.
+    ///
     /// The default value is `EXPLICIT`.
     pub tag_mode: TagMode,
+
+    /// Is this type extensible, i.e. does its definition end with the `...`
+    /// extensibility marker? Supplied as `#[asn1(extensible = "true")]`.
+    ///
+    /// Unknown trailing fields of extensible types are ignored when decoding
+    /// rather than being treated as an error.
+    pub extensible: bool,
+
+    /// Path to a function which checks the invariants of a decoded value,
+    /// supplied as `#[asn1(validate = "...")]`.
+    pub validate: Option<Path>,
 }
 
 impl TypeAttrs {
     /// Parse attributes from a struct field or enum variant.
     pub fn parse(attrs: &[Attribute]) -> Self {
         let mut tag_mode = None;
+        let mut extensible = None;
+        let mut validate = None;
 
         let mut parsed_attrs = Vec::new();
         AttrNameValue::from_attributes(attrs, &mut parsed_attrs);
@@ -39,16 +56,39 @@ impl TypeAttrs {
                 }
 
                 tag_mode = Some(mode);
+            // `extensible = "..."` attribute
+            } else if let Some(ext) = attr.parse_value("extensible") {
+                if extensible.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `extensible` attribute");
+                }
+
+                extensible = Some(ext);
+            // `validate = "..."` attribute
+            } else if attr.parse_value::<String>("validate").is_some() {
+                if validate.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `validate` attribute");
+                }
+
+                validate = Some(attr.value.parse().unwrap_or_else(|e| {
+                    abort!(
+                        attr.value,
+                        "error parsing ASN.1 `validate` attribute: {}",
+                        e
+                    )
+                }));
             } else {
                 abort!(
                     attr.name,
-                    "invalid `asn1` attribute (valid options are `tag_mode`)",
+                    "invalid `asn1` attribute \
+                    (valid options are `tag_mode`, `extensible`, `validate`)",
                 );
             }
         }
 
         Self {
             tag_mode: tag_mode.unwrap_or_default(),
+            extensible: extensible.unwrap_or_default(),
+            validate,
         }
     }
 }
@@ -66,9 +106,26 @@ pub(crate) struct FieldAttrs {
     /// where encoding is omitted per DER and to omit the encoding per DER
     pub default: Option<Path>,
 
+    /// Path to a function used to decode this field in place of its `Decode`
+    /// impl, supplied as `#[asn1(decode_with = "...")]`.
+    pub decode_with: Option<Path>,
+
+    /// Path to a function used to convert this field into an encodable value
+    /// in place of its `Encode` impl, supplied as `#[asn1(encode_with = "...")]`.
+    pub encode_with: Option<Path>,
+
     /// Is this field "extensible", i.e. preceded by the `...` extensibility marker?
     pub extensible: bool,
 
+    /// Does this field collect the unknown trailing fields of an extensible
+    /// type? Supplied as `#[asn1(extension_additions = "true")]`.
+    pub extension_additions: bool,
+
+    /// Are the fields of this field's `SEQUENCE` type included in place of
+    /// the field itself, i.e. ASN.1 `COMPONENTS OF`? Supplied as
+    /// `#[asn1(flatten = "true")]`.
+    pub flatten: bool,
+
     /// Is this field `OPTIONAL`?
     pub optional: bool,
 
@@ -81,6 +138,13 @@ pub(crate) struct FieldAttrs {
 
     /// Is the inner type constructed?
     pub constructed: bool,
+
+    /// Value of the `#[asn1(capture_raw = "...")]` attribute if provided.
+    ///
+    /// Fields with this attribute aren't encoded, but are instead populated
+    /// with the raw DER encoding of either the containing type or a sibling
+    /// field when decoding.
+    pub capture_raw: Option<CaptureRaw>,
 }
 
 impl FieldAttrs {
@@ -97,10 +161,15 @@ impl FieldAttrs {
         let mut context_specific = None;
 
         let mut default = None;
+        let mut decode_with = None;
+        let mut encode_with = None;
         let mut extensible = None;
+        let mut extension_additions = None;
+        let mut flatten = None;
         let mut optional = None;
         let mut tag_mode = None;
         let mut constructed = None;
+        let mut capture_raw = None;
 
         let mut parsed_attrs = Vec::new();
         AttrNameValue::from_attributes(attrs, &mut parsed_attrs);
@@ -122,6 +191,32 @@ impl FieldAttrs {
                 default = Some(attr.value.parse().unwrap_or_else(|e| {
                     abort!(attr.value, "error parsing ASN.1 `default` attribute: {}", e)
                 }));
+            // `decode_with` attribute
+            } else if attr.parse_value::<String>("decode_with").is_some() {
+                if decode_with.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `decode_with` attribute");
+                }
+
+                decode_with = Some(attr.value.parse().unwrap_or_else(|e| {
+                    abort!(
+                        attr.value,
+                        "error parsing ASN.1 `decode_with` attribute: {}",
+                        e
+                    )
+                }));
+            // `encode_with` attribute
+            } else if attr.parse_value::<String>("encode_with").is_some() {
+                if encode_with.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `encode_with` attribute");
+                }
+
+                encode_with = Some(attr.value.parse().unwrap_or_else(|e| {
+                    abort!(
+                        attr.value,
+                        "error parsing ASN.1 `encode_with` attribute: {}",
+                        e
+                    )
+                }));
             // `extensible` attribute
             } else if let Some(ext) = attr.parse_value("extensible") {
                 if extensible.is_some() {
@@ -129,6 +224,20 @@ impl FieldAttrs {
                 }
 
                 extensible = Some(ext);
+            // `extension_additions` attribute
+            } else if let Some(additions) = attr.parse_value("extension_additions") {
+                if extension_additions.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `extension_additions` attribute");
+                }
+
+                extension_additions = Some(additions);
+            // `flatten` attribute
+            } else if let Some(flat) = attr.parse_value("flatten") {
+                if flatten.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `flatten` attribute");
+                }
+
+                flatten = Some(flat);
             // `optional` attribute
             } else if let Some(opt) = attr.parse_value("optional") {
                 if optional.is_some() {
@@ -157,6 +266,13 @@ impl FieldAttrs {
                 }
 
                 constructed = Some(ty);
+            // `capture_raw = "..."` attribute
+            } else if let Some(target) = attr.parse_value("capture_raw") {
+                if capture_raw.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `capture_raw` attribute");
+                }
+
+                capture_raw = Some(target);
             } else {
                 abort!(
                     attr.name,
@@ -166,14 +282,23 @@ impl FieldAttrs {
             }
         }
 
+        if tag_mode == Some(TagMode::Implicit) && context_specific.is_none() {
+            abort_call_site!("implicit tagging requires a `context_specific` tag number");
+        }
+
         Self {
             asn1_type,
             context_specific,
             default,
+            decode_with,
+            encode_with,
             extensible: extensible.unwrap_or_default(),
+            extension_additions: extension_additions.unwrap_or_default(),
+            flatten: flatten.unwrap_or_default(),
             optional: optional.unwrap_or_default(),
             tag_mode: tag_mode.unwrap_or(type_attrs.tag_mode),
             constructed: constructed.unwrap_or_default(),
+            capture_raw,
         }
     }
 
@@ -185,10 +310,9 @@ impl FieldAttrs {
                 number: tag_number,
             }),
 
-            None => match self.tag_mode {
-                TagMode::Explicit => self.asn1_type.map(Tag::Universal),
-                TagMode::Implicit => abort_call_site!("implicit tagging requires a `tag_number`"),
-            },
+            // The tagging mode only applies to `CONTEXT-SPECIFIC` fields, so a
+            // type-level `IMPLICIT` mode is ignored for universally tagged ones.
+            None => self.asn1_type.map(Tag::Universal),
         }
     }
 
@@ -282,6 +406,29 @@ impl FieldAttrs {
     }
 }
 
+/// Target of a `#[asn1(capture_raw = "...")]` attribute.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum CaptureRaw {
+    /// Capture the complete encoding of the containing type, supplied as
+    /// `#[asn1(capture_raw = "true")]`.
+    Container,
+
+    /// Capture the complete encoding of the sibling field with the given name,
+    /// supplied as `#[asn1(capture_raw = "field_name")]`.
+    Field(Ident),
+}
+
+impl FromStr for CaptureRaw {
+    type Err = syn::Error;
+
+    fn from_str(s: &str) -> syn::Result<Self> {
+        match s {
+            "true" => Ok(Self::Container),
+            _ => syn::parse_str(s).map(Self::Field),
+        }
+    }
+}
+
 /// Name/value pair attribute.
 struct AttrNameValue {
     /// Attribute name.
@@ -42,6 +42,14 @@ impl DeriveChoice {
             .map(|lt| lt.lifetime.clone());
 
         let type_attrs = TypeAttrs::parse(&input.attrs);
+
+        if type_attrs.extensible || type_attrs.validate.is_some() {
+            abort!(
+                input.ident,
+                "`extensible` and `validate` are not allowed on CHOICE"
+            );
+        }
+
         let variants = data
             .variants
             .iter()
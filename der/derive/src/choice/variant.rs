@@ -77,8 +77,26 @@ impl ChoiceVariant {
         let ident = input.ident.clone();
         let attrs = FieldAttrs::parse(&input.attrs, type_attrs);
 
-        if attrs.extensible {
-            abort!(&ident, "`extensible` is not allowed on CHOICE");
+        if attrs.extensible || attrs.extension_additions {
+            abort!(
+                &ident,
+                "`extensible` and `extension_additions` are not allowed on CHOICE"
+            );
+        }
+
+        if attrs.capture_raw.is_some() {
+            abort!(&ident, "`capture_raw` is not allowed on CHOICE");
+        }
+
+        if attrs.flatten {
+            abort!(&ident, "`flatten` is not allowed on CHOICE");
+        }
+
+        if attrs.decode_with.is_some() || attrs.encode_with.is_some() {
+            abort!(
+                &ident,
+                "`decode_with` and `encode_with` are not allowed on CHOICE"
+            );
         }
 
         // Validate that variant is a 1-element tuple struct
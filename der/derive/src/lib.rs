@@ -9,6 +9,7 @@
 //! - [`Choice`][`derive@Choice`]: map ASN.1 `CHOICE` to a Rust enum.
 //! - [`Enumerated`][`derive@Enumerated`]: map ASN.1 `ENUMERATED` to a C-like Rust enum.
 //! - [`Sequence`][`derive@Sequence`]: map ASN.1 `SEQUENCE` to a Rust struct.
+//! - [`Set`][`derive@Set`]: map ASN.1 `SET` to a Rust struct.
 //! - [`ValueOrd`][`derive@ValueOrd`]: determine DER ordering for ASN.1 `SET OF`.
 //!
 //! Note that this crate shouldn't be used directly, but instead accessed
@@ -40,11 +41,85 @@
 //! This attribute can be used to declare the tagging mode used by a particular
 //! ASN.1 module.
 //!
-//! It's used when parsing `CONTEXT-SENSITIVE` fields.
+//! It's used when parsing `CONTEXT-SENSITIVE` fields, and sets the default
+//! tagging mode for every `CONTEXT-SPECIFIC` field or variant of the type,
+//! mirroring an ASN.1 module declared with `DEFINITIONS IMPLICIT TAGS`.
+//! Fields which aren't `CONTEXT-SPECIFIC` are unaffected.
 //!
 //! The default is `EXPLICIT`, so the attribute only needs to be added when
 //! a particular module is declared `IMPLICIT`.
 //!
+//! The mode can be overridden for an individual field or variant by placing
+//! a field-level `#[asn1(tag_mode = "...")]` attribute on it:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! #[asn1(tag_mode = "IMPLICIT")]
+//! pub struct Example {
+//!     #[asn1(context_specific = "0")]
+//!     pub implicit: bool,
+//!
+//!     #[asn1(context_specific = "1", tag_mode = "EXPLICIT")]
+//!     pub explicit: bool,
+//! }
+//! ```
+//!
+//! ### `#[asn1(extensible = "true")]` attribute: trailing `...` extensibility marker
+//!
+//! This attribute can be added to a `struct` deriving [`Sequence`] or [`Set`]
+//! whose ASN.1 definition contains the `...` extensibility marker, so that
+//! encodings produced by newer versions of a protocol can still be decoded.
+//!
+//! Unknown fields following the known fields of a `SEQUENCE` (or unknown
+//! members of a `SET`) are skipped when decoding rather than causing an
+//! error. A `SET` member matching a known field which is already present is
+//! still rejected as a duplicate. For a `SEQUENCE` they can instead be retained by adding a final
+//! field with the `#[asn1(extension_additions = "true")]` attribute, whose
+//! type is `Vec<Any<'a>>` (or any other `Vec` of decodable types):
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! #[asn1(extensible = "true")]
+//! pub struct Example<'a> {
+//!     pub version: u8,
+//!
+//!     #[asn1(extension_additions = "true")]
+//!     pub additions: Vec<Any<'a>>,
+//! }
+//! ```
+//!
+//! Retained fields are encoded again as-is following the known fields.
+//!
+//! ### `#[asn1(validate = "...")]` attribute: post-decode validation
+//!
+//! This attribute can be added to a `struct` deriving [`Sequence`] or [`Set`]
+//! to check invariants which span multiple fields when decoding, so they
+//! don't need to be re-checked by every consumer of the type.
+//!
+//! It specifies the path to a function with the signature
+//! `fn(&T) -> der::Result<()>`, where `T` is the type being derived, which
+//! is called with each decoded value. Any error it returns is returned from
+//! decoding:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! #[asn1(validate = "AlgorithmIdentifier::validate")]
+//! pub struct AlgorithmIdentifier<'a> {
+//!     pub algorithm: ObjectIdentifier,
+//!     pub parameters: Option<Any<'a>>,
+//! }
+//!
+//! impl AlgorithmIdentifier<'_> {
+//!     fn validate(&self) -> der::Result<()> {
+//!         if self.algorithm == RSA_ENCRYPTION && self.parameters != Some(Any::NULL) {
+//!             return Err(Tag::Sequence.value_error());
+//!         }
+//!
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
 //! ## Field-level attributes
 //!
 //! The following attributes can be added to either the fields of a particular
@@ -62,12 +137,55 @@
 //! This behaves like `serde_derive`'s `default` attribute, allowing you to
 //! specify the path to a function which returns a default value.
 //!
+//! ### `#[asn1(decode_with = "...")]` and `#[asn1(encode_with = "...")]` attributes
+//!
+//! These attributes make it possible to customize how an individual `struct`
+//! field is decoded and encoded without having to hand-write the
+//! [`Sequence`] impl for the whole type, similar to `serde_derive`'s `with`.
+//!
+//! `decode_with` specifies the path to a function with the signature
+//! `fn(&mut der::Decoder<'a>) -> der::Result<T>`, where `T` is the type of
+//! the field.
+//!
+//! `encode_with` specifies the path to a function with the signature
+//! `fn(&T) -> der::Result<E>`, where `E` is any type which impls
+//! `der::Encode`, and whose encoding is used in place of the field's.
+//!
+//! Either can be used alone, but they can't be combined with the `type`,
+//! `context_specific`, or `default` attributes. `decode_with` also can't be
+//! combined with `optional`: to decode an optional field, the function
+//! should return an `Option` itself.
+//!
 //! ### `#[asn1(extensible = "true")]` attribute: support for `...` extensibility operator
 //!
 //! This attribute can be applied to the fields of `struct` types, and will
 //! skip over unrecognized lower-numbered `CONTEXT-SPECIFIC` fields when
 //! looking for a particular field of a struct.
 //!
+//! ### `#[asn1(flatten = "true")]` attribute: `COMPONENTS OF` support
+//!
+//! This attribute can be applied to a field of a `struct` deriving
+//! [`Sequence`] whose type also derives [`Sequence`], and includes the
+//! fields of that type in place of the field itself, rather than encoding
+//! it as a nested `SEQUENCE`. This mirrors the ASN.1 `COMPONENTS OF`
+//! notation:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! pub struct Extended<'a> {
+//!     // COMPONENTS OF AlgorithmIdentifier
+//!     #[asn1(flatten = "true")]
+//!     pub algorithm: AlgorithmIdentifier<'a>,
+//!
+//!     pub comment: Utf8String<'a>,
+//! }
+//! ```
+//!
+//! The flattened type can't capture its own raw encoding with
+//! `capture_raw = "true"`, or be `#[asn1(extensible = "true")]`, as its
+//! unknown trailing fields couldn't be told apart from those of the
+//! enclosing `struct`.
+//!
 //! ### `#[asn1(optional = "true")]` attribute: support for `OPTIONAL` fields
 //!
 //! This attribute explicitly annotates a field as `OPTIONAL`.
@@ -98,6 +216,28 @@
 //! This attribute can be used to specify that an "inner" type is constructed. It is most
 //! commonly used when a `CHOICE` has a constructed inner type.
 //!
+//! ### `#[asn1(capture_raw = "...")]` attribute: retaining raw encodings
+//!
+//! This attribute can be applied to `&'a [u8]` fields of `struct` types
+//! deriving [`Sequence`], and populates the field with the exact DER encoding
+//! (i.e. the complete tag-length-value production) of either the struct
+//! itself (`capture_raw = "true"`) or a sibling field (`capture_raw = "field_name"`)
+//! when decoding. Such fields are omitted when encoding.
+//!
+//! This is useful for verifying signatures over signed substructures such as
+//! a `TBSCertificate` without having to re-encode them:
+//!
+//! ```ignore
+//! #[derive(Sequence)]
+//! pub struct Certificate<'a> {
+//!     #[asn1(capture_raw = "tbs_certificate")]
+//!     pub tbs_certificate_der: &'a [u8],
+//!     pub tbs_certificate: TbsCertificate<'a>,
+//!     pub signature_algorithm: AlgorithmIdentifier<'a>,
+//!     pub signature: BitString<'a>,
+//! }
+//! ```
+//!
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
@@ -122,6 +262,7 @@ mod choice;
 mod enumerated;
 mod newtype;
 mod sequence;
+mod set;
 mod tag;
 mod value_ord;
 
@@ -132,6 +273,7 @@ use crate::{
     enumerated::DeriveEnumerated,
     newtype::DeriveNewtype,
     sequence::DeriveSequence,
+    set::DeriveSet,
     tag::{Tag, TagMode, TagNumber},
     value_ord::DeriveValueOrd,
 };
@@ -245,6 +387,26 @@ pub fn derive_enumerated(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Generic types
+///
+/// Structs can be generic over the types of their fields, e.g. a container
+/// whose payload type is chosen by the user:
+///
+/// ```ignore
+/// #[derive(Sequence)]
+/// pub struct ContentInfo<T> {
+///     pub content_type: ObjectIdentifier,
+///
+///     #[asn1(context_specific = "0")]
+///     pub content: T,
+/// }
+/// ```
+///
+/// The generated impls bound each type parameter on `Decode`, `EncodeValue`,
+/// and `Tagged` in addition to any bounds and where clauses on the struct
+/// itself. Fields which require other bounds, e.g. `Option<T>` requiring
+/// `T: Choice`, need them to be added to the struct's where clause.
+///
 /// # `#[asn1(type = "...")]` attribute
 ///
 /// See [toplevel documentation for the `der_derive` crate][2] for more
@@ -259,6 +421,45 @@ pub fn derive_sequence(input: TokenStream) -> TokenStream {
     DeriveSequence::new(input).to_tokens().into()
 }
 
+/// Derive the [`Decode`][1] and [`Encode`][2] traits on a `struct`
+/// representing an ASN.1 `SET`.
+///
+/// Unlike a `SEQUENCE`, the members of a `SET` are unordered: when decoding,
+/// they're accepted in any order and matched to fields by their tags, and
+/// when encoding they're sorted by tag as DER requires. Every field must
+/// therefore have a distinct tag.
+///
+/// # Usage
+///
+/// ```ignore
+/// use der::{asn1::{ObjectIdentifier, Utf8String}, Set};
+///
+/// #[derive(Set)]
+/// pub struct Example<'a> {
+///     pub oid: ObjectIdentifier,
+///
+///     #[asn1(context_specific = "0", optional = "true")]
+///     pub name: Option<Utf8String<'a>>,
+///
+///     #[asn1(type = "OCTET STRING")]
+///     pub value: &'a [u8],
+/// }
+/// ```
+///
+/// Fields support the same `#[asn1]` attributes as [`Sequence`][3], apart
+/// from `capture_raw`, `decode_with`, `encode_with`, `extension_additions`, and
+/// `flatten`.
+///
+/// [1]: https://docs.rs/der/latest/der/trait.Decode.html
+/// [2]: https://docs.rs/der/latest/der/trait.Encode.html
+/// [3]: derive@Sequence
+#[proc_macro_derive(Set, attributes(asn1))]
+#[proc_macro_error]
+pub fn derive_set(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    DeriveSet::new(input).to_tokens().into()
+}
+
 /// Derive the [`ValueOrd`][1] trait on a `struct`.
 ///
 /// This trait is used in conjunction with ASN.1 `SET OF` types to determine
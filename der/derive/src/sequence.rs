@@ -1,14 +1,14 @@
 //! Support for deriving the `Sequence` trait on structs for the purposes of
 //! decoding/encoding ASN.1 `SEQUENCE` types as mapped to struct fields.
 
-mod field;
+pub(crate) mod field;
 
-use crate::TypeAttrs;
+use crate::{attributes::CaptureRaw, TypeAttrs};
 use field::SequenceField;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Ident, Lifetime};
+use syn::{parse_quote, DeriveInput, Generics, Ident, Lifetime, LifetimeDef, Path};
 
 /// Derive the `Sequence` trait for a struct
 pub(crate) struct DeriveSequence {
@@ -18,8 +18,18 @@ pub(crate) struct DeriveSequence {
     /// Lifetime of the struct.
     lifetime: Option<Lifetime>,
 
+    /// Generic parameters of the struct, including its where clause.
+    generics: Generics,
+
     /// Fields of the struct.
     fields: Vec<SequenceField>,
+
+    /// Is the sequence extensible, i.e. should unknown trailing fields be
+    /// tolerated when decoding?
+    extensible: bool,
+
+    /// Function used to validate decoded values.
+    validate: Option<Path>,
 }
 
 impl DeriveSequence {
@@ -42,69 +52,276 @@ impl DeriveSequence {
 
         let type_attrs = TypeAttrs::parse(&input.attrs);
 
-        let fields = data
+        let fields: Vec<SequenceField> = data
             .fields
             .iter()
             .map(|field| SequenceField::new(field, &type_attrs))
             .collect();
 
+        for field in &fields {
+            let captures = fields
+                .iter()
+                .filter(|f| f.attrs.capture_raw == field.attrs.capture_raw)
+                .count();
+
+            if field.attrs.capture_raw.is_some() && captures > 1 {
+                abort!(field.ident, "duplicate `capture_raw` target");
+            }
+
+            if let Some(CaptureRaw::Field(target)) = &field.attrs.capture_raw {
+                match fields.iter().find(|f| &f.ident == target) {
+                    Some(f) if f.attrs.capture_raw.is_none() => (),
+                    _ => abort!(
+                        target,
+                        "`capture_raw` must name another encoded field of this struct"
+                    ),
+                }
+            }
+        }
+
+        let additions = fields.iter().position(|f| f.attrs.extension_additions);
+
+        if let Some(position) = additions {
+            let field = &fields[position];
+
+            if !type_attrs.extensible {
+                abort!(
+                    field.ident,
+                    "`extension_additions` requires an `#[asn1(extensible = \"true\")]` struct"
+                );
+            }
+
+            // Captured fields don't occur in the encoding
+            if fields[position + 1..]
+                .iter()
+                .any(|f| f.attrs.capture_raw.is_none())
+            {
+                abort!(
+                    field.ident,
+                    "`extension_additions` must be the last encoded field of the struct"
+                );
+            }
+        }
+
         Self {
             ident: input.ident,
             lifetime,
+            generics: input.generics,
             fields,
+            extensible: type_attrs.extensible,
+            validate: type_attrs.validate,
         }
     }
 
     /// Lower the derived output into a [`TokenStream`].
     pub fn to_tokens(&self) -> TokenStream {
         let ident = &self.ident;
+        let mut generics = self.generics.clone();
 
-        // Explicit lifetime or `'_`
+        // Decode using the struct's lifetime if it has one, or otherwise
+        // introduce a new lifetime parameter for the impls.
+        // TODO(tarcieri): support multiple lifetimes
         let lifetime = match self.lifetime {
-            Some(ref lifetime) => quote!(#lifetime),
-            None => quote!('_),
+            Some(ref lifetime) => lifetime.clone(),
+            None => {
+                let lifetime = Lifetime::new("'__der", Span::call_site());
+                generics
+                    .params
+                    .insert(0, LifetimeDef::new(lifetime.clone()).into());
+                lifetime
+            }
         };
 
-        // Lifetime parameters
-        // TODO(tarcieri): support multiple lifetimes
-        let lt_params = self
-            .lifetime
-            .as_ref()
-            .map(|_| lifetime.clone())
-            .unwrap_or_default();
+        // Bound all type parameters on the traits needed to decode/encode them
+        let mut decode_generics = generics.clone();
+        let mut sequence_generics = generics;
+
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+
+            decode_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#param: ::der::Decode<#lifetime>));
 
+            sequence_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! {
+                    #param: ::der::Decode<#lifetime> + ::der::EncodeValue + ::der::Tagged
+                });
+        }
+
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+        let (sequence_impl_generics, _, sequence_where_clause) = sequence_generics.split_for_impl();
+
+        let mut capture_container = Vec::new();
         let mut decode_body = Vec::new();
         let mut decode_result = Vec::new();
         let mut encode_body = Vec::new();
+        let mut encode_helpers = TokenStream::new();
 
         for field in &self.fields {
-            decode_body.push(field.to_decode_tokens());
             decode_result.push(&field.ident);
-            encode_body.push(field.to_encode_tokens());
+
+            if field.attrs.extension_additions {
+                let ident = &field.ident;
+                let field_type = &field.field_type;
+
+                decode_body.push(quote! {
+                    let mut #ident: #field_type = ::core::default::Default::default();
+
+                    while !::der::Reader::is_finished(decoder) {
+                        #ident.push(decoder.decode()?);
+                    }
+                });
+
+                // Additions are encoded in place rather than as a `SEQUENCE OF`
+                encode_helpers.extend(quote! {
+                    struct ExtensionAdditions<'s, T>(&'s [T]);
+
+                    impl<T: ::der::Encode> ::der::Encode for ExtensionAdditions<'_, T> {
+                        fn encoded_len(&self) -> ::der::Result<::der::Length> {
+                            self.0
+                                .iter()
+                                .try_fold(::der::Length::ZERO, |acc, field| acc + field.encoded_len()?)
+                        }
+
+                        fn encode(&self, encoder: &mut dyn ::der::Writer) -> ::der::Result<()> {
+                            self.0.iter().try_for_each(|field| field.encode(encoder))
+                        }
+                    }
+                });
+                encode_body.push(quote!(&ExtensionAdditions(&self.#ident[..])));
+                continue;
+            }
+
+            match &field.attrs.capture_raw {
+                Some(CaptureRaw::Container) => {
+                    let ident = &field.ident;
+                    capture_container.push(quote! {
+                        let #ident = decoder.raw_tlv(header)?;
+                    });
+                }
+                // Decoded along with the field being captured
+                Some(CaptureRaw::Field(_)) => (),
+                None if field.attrs.flatten => {
+                    let ident = &field.ident;
+                    decode_body.push(self.field_decode_tokens(field));
+                    encode_body.push(quote!(&Flattened(&self.#ident)));
+                }
+                None => {
+                    decode_body.push(self.field_decode_tokens(field));
+                    encode_body.push(field.to_encode_tokens());
+                }
+            }
         }
 
-        quote! {
-            impl<#lt_params> ::der::DecodeValue<#lifetime> for #ident<#lt_params> {
-                fn decode_value(
-                    decoder: &mut ::der::Decoder<#lifetime>,
-                    header: ::der::Header,
-                ) -> ::der::Result<Self> {
-                    use ::der::DecodeValue;
-                    ::der::asn1::SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+        // Flattened fields are encoded without their `SEQUENCE` header
+        if self.fields.iter().any(|f| f.attrs.flatten) {
+            encode_helpers.extend(quote! {
+                struct Flattened<'s, T>(&'s T);
+
+                impl<T: ::der::EncodeValue> ::der::Encode for Flattened<'_, T> {
+                    fn encoded_len(&self) -> ::der::Result<::der::Length> {
+                        self.0.value_len()
+                    }
+
+                    fn encode(&self, encoder: &mut dyn ::der::Writer) -> ::der::Result<()> {
+                        self.0.encode_value(encoder)
+                    }
+                }
+            });
+        }
+
+        // Skip over any unknown trailing fields of an extensible sequence
+        // which aren't being retained
+        if self.extensible && !self.fields.iter().any(|f| f.attrs.extension_additions) {
+            decode_body.push(quote! {
+                while !::der::Reader::is_finished(decoder) {
+                    decoder.tlv_bytes()?;
+                }
+            });
+        }
+
+        // Check the invariants of the decoded value, if requested
+        let decode_finish = match &self.validate {
+            Some(validate) => quote! {
+                let value = Self {
+                    #(#decode_result),*
+                };
+                #validate(&value)?;
+                Ok(value)
+            },
+            None => quote! {
+                Ok(Self {
+                    #(#decode_result),*
+                })
+            },
+        };
+
+        // Types which capture their own encoding need its header, so the
+        // fields can only be decoded as part of decoding the whole sequence.
+        // Extensible types consume all trailing fields, including those of a
+        // sequence they'd be flattened into, so they can't be flattened either.
+        let decode_impls = if capture_container.is_empty() && !self.extensible {
+            quote! {
+                impl #decode_impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics
+                #decode_where_clause
+                {
+                    fn decode_value(
+                        decoder: &mut ::der::Decoder<#lifetime>,
+                        header: ::der::Header,
+                    ) -> ::der::Result<Self> {
+                        use ::der::DecodeValue;
+                        ::der::asn1::SequenceRef::decode_value(decoder, header)?
+                            .decode_body(<Self as ::der::DecodeFields<#lifetime>>::decode_fields)
+                    }
+                }
+
+                impl #decode_impl_generics ::der::DecodeFields<#lifetime> for #ident #ty_generics
+                #decode_where_clause
+                {
+                    fn decode_fields(decoder: &mut ::der::Decoder<#lifetime>) -> ::der::Result<Self> {
                         #(#decode_body)*
 
-                        Ok(Self {
-                            #(#decode_result),*
+                        #decode_finish
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #decode_impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics
+                #decode_where_clause
+                {
+                    fn decode_value(
+                        decoder: &mut ::der::Decoder<#lifetime>,
+                        header: ::der::Header,
+                    ) -> ::der::Result<Self> {
+                        use ::der::DecodeValue;
+                        #(#capture_container)*
+                        ::der::asn1::SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+                            #(#decode_body)*
+
+                            #decode_finish
                         })
-                    })
+                    }
                 }
             }
+        };
 
-            impl<#lt_params> ::der::Sequence<#lifetime> for #ident<#lt_params> {
-                fn fields<F, T>(&self, f: F) -> ::der::Result<T>
+        quote! {
+            #decode_impls
+
+            impl #sequence_impl_generics ::der::Sequence<#lifetime> for #ident #ty_generics
+            #sequence_where_clause
+            {
+                fn fields<__F, __T>(&self, f: __F) -> ::der::Result<__T>
                 where
-                    F: FnOnce(&[&dyn der::Encode]) -> ::der::Result<T>,
+                    __F: FnOnce(&[&dyn der::Encode]) -> ::der::Result<__T>,
                 {
+                    #encode_helpers
                     f(&[
                         #(#encode_body),*
                     ])
@@ -112,6 +329,35 @@ impl DeriveSequence {
             }
         }
     }
+
+    /// Derive code for decoding a field, additionally capturing its raw
+    /// encoding if it's the target of a `capture_raw` field.
+    fn field_decode_tokens(&self, field: &SequenceField) -> TokenStream {
+        let decode = field.to_decode_tokens();
+
+        let capture = self.fields.iter().find(|f| match &f.attrs.capture_raw {
+            Some(CaptureRaw::Field(target)) => target == &field.ident,
+            _ => false,
+        });
+
+        match capture {
+            Some(capture) => {
+                let ident = &field.ident;
+                let raw_ident = &capture.ident;
+
+                quote! {
+                    let (#ident, #raw_ident) = {
+                        let mut raw_decoder = decoder.clone();
+                        #decode
+                        let raw_len = (::der::Reader::position(decoder)
+                            - ::der::Reader::position(&raw_decoder))?;
+                        (#ident, ::der::Reader::read_slice(&mut raw_decoder, raw_len)?)
+                    };
+                }
+            }
+            None => decode,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -328,4 +574,124 @@ mod tests {
         );
         assert_eq!(utf8_string.attrs.tag_mode, TagMode::Implicit);
     }
+
+    /// `IMPLICIT` type-level tagging with per-field overrides.
+    #[test]
+    fn implicit_override_example() {
+        let input = parse_quote! {
+            #[asn1(tag_mode = "IMPLICIT")]
+            pub struct MixedSequence {
+                version: u8,
+
+                #[asn1(context_specific = "0")]
+                implicit: bool,
+
+                #[asn1(context_specific = "1", tag_mode = "EXPLICIT")]
+                explicit: bool,
+            }
+        };
+
+        let ir = DeriveSequence::new(input);
+        assert_eq!(ir.ident, "MixedSequence");
+        assert_eq!(ir.fields.len(), 3);
+
+        let version = &ir.fields[0];
+        assert_eq!(version.ident, "version");
+        assert_eq!(version.attrs.context_specific, None);
+
+        let implicit = &ir.fields[1];
+        assert_eq!(implicit.ident, "implicit");
+        assert_eq!(implicit.attrs.context_specific, Some("0".parse().unwrap()));
+        assert_eq!(implicit.attrs.tag_mode, TagMode::Implicit);
+
+        let explicit = &ir.fields[2];
+        assert_eq!(explicit.ident, "explicit");
+        assert_eq!(explicit.attrs.context_specific, Some("1".parse().unwrap()));
+        assert_eq!(explicit.attrs.tag_mode, TagMode::Explicit);
+    }
+
+    /// CMS `ContentInfo` generic over its content type.
+    #[test]
+    fn generic_example() {
+        let input = parse_quote! {
+            pub struct ContentInfo<T>
+            where
+                T: Clone,
+            {
+                pub content_type: ObjectIdentifier,
+
+                #[asn1(context_specific = "0")]
+                pub content: T,
+            }
+        };
+
+        let ir = DeriveSequence::new(input);
+        assert_eq!(ir.ident, "ContentInfo");
+        assert_eq!(ir.lifetime, None);
+        assert_eq!(ir.generics.type_params().count(), 1);
+        assert_eq!(ir.fields.len(), 2);
+
+        let tokens = ir.to_tokens().to_string();
+        assert!(tokens.contains(
+            &quote::quote!(T: ::der::Decode<'__der> + ::der::EncodeValue + ::der::Tagged)
+                .to_string()
+        ));
+        assert!(tokens.contains(&quote::quote!(T: Clone).to_string()));
+    }
+
+    /// Extensible sequence retaining its unknown trailing fields.
+    #[test]
+    fn extensible_example() {
+        let input = parse_quote! {
+            #[asn1(extensible = "true")]
+            pub struct Example<'a> {
+                pub version: u8,
+
+                #[asn1(extension_additions = "true")]
+                pub additions: Vec<Any<'a>>,
+            }
+        };
+
+        let ir = DeriveSequence::new(input);
+        assert!(ir.extensible);
+        assert_eq!(ir.fields.len(), 2);
+        assert!(!ir.fields[0].attrs.extension_additions);
+        assert!(ir.fields[1].attrs.extension_additions);
+    }
+
+    /// Sequence including the fields of another via `COMPONENTS OF`.
+    #[test]
+    fn flatten_example() {
+        let input = parse_quote! {
+            pub struct Example<'a> {
+                #[asn1(flatten = "true")]
+                pub algorithm: AlgorithmIdentifier<'a>,
+
+                pub critical: bool,
+            }
+        };
+
+        let ir = DeriveSequence::new(input);
+        assert_eq!(ir.fields.len(), 2);
+        assert!(ir.fields[0].attrs.flatten);
+        assert!(!ir.fields[1].attrs.flatten);
+
+        let tokens = ir.to_tokens().to_string();
+        assert!(tokens.contains(&quote::quote!(impl<'a> ::der::DecodeFields<'a>).to_string()));
+    }
+
+    /// Sequence with a post-decode validation function.
+    #[test]
+    fn validate_example() {
+        let input = parse_quote! {
+            #[asn1(validate = "Example::validate")]
+            pub struct Example {
+                pub version: u8,
+            }
+        };
+
+        let ir = DeriveSequence::new(input);
+        let expected: syn::Path = parse_quote!(Example::validate);
+        assert_eq!(ir.validate, Some(expected));
+    }
 }
@@ -7,20 +7,20 @@ use quote::quote;
 use syn::{Field, Ident, Path, Type};
 
 /// "IR" for a field of a derived `Sequence`.
-pub(super) struct SequenceField {
+pub(crate) struct SequenceField {
     /// Variant name.
-    pub(super) ident: Ident,
+    pub(crate) ident: Ident,
 
     /// Field-level attributes.
-    pub(super) attrs: FieldAttrs,
+    pub(crate) attrs: FieldAttrs,
 
     /// Field type
-    pub(super) field_type: Type,
+    pub(crate) field_type: Type,
 }
 
 impl SequenceField {
     /// Create a new [`SequenceField`] from the input [`Field`].
-    pub(super) fn new(field: &Field, type_attrs: &TypeAttrs) -> Self {
+    pub(crate) fn new(field: &Field, type_attrs: &TypeAttrs) -> Self {
         let ident = field.ident.as_ref().cloned().unwrap_or_else(|| {
             abort!(
                 field,
@@ -44,6 +44,74 @@ impl SequenceField {
             );
         }
 
+        if (attrs.decode_with.is_some() || attrs.encode_with.is_some())
+            && (attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some())
+        {
+            abort!(
+                ident,
+                "`decode_with` and `encode_with` can't be combined with \
+                `type`, `context_specific`, or `default`"
+            );
+        }
+
+        if attrs.decode_with.is_some() && attrs.optional {
+            abort!(
+                ident,
+                "`decode_with` can't be combined with `optional`: \
+                the function must decode the `Option` itself"
+            );
+        }
+
+        if attrs.capture_raw.is_some()
+            && (attrs.decode_with.is_some()
+                || attrs.encode_with.is_some()
+                || attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some()
+                || attrs.extensible
+                || attrs.extension_additions
+                || attrs.optional)
+        {
+            abort!(
+                ident,
+                "`capture_raw` fields can't have other ASN.1 field qualifiers"
+            );
+        }
+
+        if attrs.flatten
+            && (attrs.decode_with.is_some()
+                || attrs.encode_with.is_some()
+                || attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some()
+                || attrs.extensible
+                || attrs.extension_additions
+                || attrs.optional
+                || attrs.capture_raw.is_some())
+        {
+            abort!(
+                ident,
+                "`flatten` fields can't have other ASN.1 field qualifiers"
+            );
+        }
+
+        if attrs.extension_additions
+            && (attrs.decode_with.is_some()
+                || attrs.encode_with.is_some()
+                || attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some()
+                || attrs.extensible
+                || attrs.optional)
+        {
+            abort!(
+                ident,
+                "`extension_additions` fields can't have other ASN.1 field qualifiers"
+            );
+        }
+
         Self {
             ident,
             attrs,
@@ -52,7 +120,22 @@ impl SequenceField {
     }
 
     /// Derive code for decoding a field of a sequence.
-    pub(super) fn to_decode_tokens(&self) -> TokenStream {
+    pub(crate) fn to_decode_tokens(&self) -> TokenStream {
+        if let Some(decode_with) = &self.attrs.decode_with {
+            let ident = &self.ident;
+            return quote! {
+                let #ident = #decode_with(decoder)?;
+            };
+        }
+
+        if self.attrs.flatten {
+            let ident = &self.ident;
+            let field_type = &self.field_type;
+            return quote! {
+                let #ident = <#field_type as ::der::DecodeFields>::decode_fields(decoder)?;
+            };
+        }
+
         let mut lowerer = LowerFieldDecoder::new(&self.attrs);
 
         if self.attrs.asn1_type.is_some() {
@@ -76,7 +159,14 @@ impl SequenceField {
     }
 
     /// Derive code for encoding a field of a sequence.
-    pub(super) fn to_encode_tokens(&self) -> TokenStream {
+    pub(crate) fn to_encode_tokens(&self) -> TokenStream {
+        if let Some(encode_with) = &self.attrs.encode_with {
+            let ident = &self.ident;
+            return quote! {
+                &#encode_with(&self.#ident)?
+            };
+        }
+
         let mut lowerer = LowerFieldEncoder::new(&self.ident);
         let attrs = &self.attrs;
 
@@ -270,10 +360,15 @@ mod tests {
             asn1_type: None,
             context_specific: None,
             default: None,
+            decode_with: None,
+            encode_with: None,
             extensible: false,
+            extension_additions: false,
+            flatten: false,
             optional: false,
             tag_mode: TagMode::Explicit,
             constructed: false,
+            capture_raw: None,
         };
 
         let field_type = Ident::new("String", span);
@@ -310,10 +405,15 @@ mod tests {
             asn1_type: None,
             context_specific: Some(TagNumber(0)),
             default: None,
+            decode_with: None,
+            encode_with: None,
             extensible: false,
+            extension_additions: false,
+            flatten: false,
             optional: false,
             tag_mode: TagMode::Implicit,
             constructed: false,
+            capture_raw: None,
         };
 
         let field_type = Ident::new("String", span);
@@ -355,4 +455,40 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn decode_with_encode_with() {
+        let span = Span::call_site();
+        let ident = Ident::new("custom_field", span);
+
+        let attrs = FieldAttrs {
+            decode_with: Some(syn::parse_quote!(custom::decode)),
+            encode_with: Some(syn::parse_quote!(custom::encode)),
+            ..Default::default()
+        };
+
+        let field_type = Ident::new("String", span);
+
+        let field = SequenceField {
+            ident,
+            attrs,
+            field_type: type_path(field_type),
+        };
+
+        assert_eq!(
+            field.to_decode_tokens().to_string(),
+            quote! {
+                let custom_field = custom::decode(decoder)?;
+            }
+            .to_string()
+        );
+
+        assert_eq!(
+            field.to_encode_tokens().to_string(),
+            quote! {
+                &custom::encode(&self.custom_field)?
+            }
+            .to_string()
+        );
+    }
 }
@@ -0,0 +1,402 @@
+//! Support for deriving the `Decode` and `Encode` traits on structs for the
+//! purposes of decoding/encoding ASN.1 `SET` types as mapped to struct fields.
+
+use crate::{sequence::field::SequenceField, TypeAttrs};
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, DeriveInput, GenericArgument, Generics, Ident, Lifetime, LifetimeDef, Path,
+    PathArguments, Type,
+};
+
+/// Derive the `Set` trait for a struct
+pub(crate) struct DeriveSet {
+    /// Name of the set struct.
+    ident: Ident,
+
+    /// Lifetime of the struct.
+    lifetime: Option<Lifetime>,
+
+    /// Generic parameters of the struct, including its where clause.
+    generics: Generics,
+
+    /// Fields of the struct.
+    fields: Vec<SequenceField>,
+
+    /// Is the set extensible, i.e. should unknown members be tolerated when
+    /// decoding?
+    extensible: bool,
+
+    /// Function used to validate decoded values.
+    validate: Option<Path>,
+}
+
+impl DeriveSet {
+    /// Parse [`DeriveInput`].
+    pub fn new(input: DeriveInput) -> Self {
+        let data = match input.data {
+            syn::Data::Struct(data) => data,
+            _ => abort!(
+                input.ident,
+                "can't derive `Set` on this type: only `struct` types are allowed",
+            ),
+        };
+
+        // TODO(tarcieri): properly handle multiple lifetimes
+        let lifetime = input
+            .generics
+            .lifetimes()
+            .next()
+            .map(|lt| lt.lifetime.clone());
+
+        let type_attrs = TypeAttrs::parse(&input.attrs);
+
+        let fields = data
+            .fields
+            .iter()
+            .map(|field| {
+                let field = SequenceField::new(field, &type_attrs);
+                let attrs = &field.attrs;
+
+                if attrs.capture_raw.is_some()
+                    || attrs.decode_with.is_some()
+                    || attrs.encode_with.is_some()
+                    || attrs.extension_additions
+                    || attrs.flatten
+                {
+                    abort!(
+                        field.ident,
+                        "`capture_raw`, `decode_with`, `encode_with`, \
+                        `extension_additions`, and `flatten` are not allowed on SET"
+                    );
+                }
+
+                field
+            })
+            .collect();
+
+        Self {
+            ident: input.ident,
+            lifetime,
+            generics: input.generics,
+            fields,
+            extensible: type_attrs.extensible,
+            validate: type_attrs.validate,
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream`].
+    pub fn to_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let mut generics = self.generics.clone();
+
+        // Decode using the struct's lifetime if it has one, or otherwise
+        // introduce a new lifetime parameter for the impls.
+        let lifetime = match self.lifetime {
+            Some(ref lifetime) => lifetime.clone(),
+            None => {
+                let lifetime = Lifetime::new("'__der", Span::call_site());
+                generics
+                    .params
+                    .insert(0, LifetimeDef::new(lifetime.clone()).into());
+                lifetime
+            }
+        };
+
+        // Bound all type parameters on the traits needed to decode/encode them
+        let mut decode_generics = generics;
+        let mut encode_generics = self.generics.clone();
+
+        // Untagged members are ordered by the smallest tag they can decode,
+        // so encoding them also requires `Choice`
+        let encode_choice = match self.lifetime {
+            Some(ref lifetime) => quote!(::der::Choice<#lifetime>),
+            None => quote!(for<'__der> ::der::Choice<'__der>),
+        };
+
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+
+            decode_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#param: ::der::Choice<#lifetime>));
+
+            encode_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#param: ::der::EncodeValue + #encode_choice));
+        }
+
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+        let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+
+        let mut slots = Vec::new();
+        let mut decode_arms = Vec::new();
+        let mut known_tags = Vec::new();
+        let mut decode_result = Vec::new();
+        let mut encode_fields = Vec::new();
+        let mut encode_refs = Vec::new();
+
+        // Unknown members of extensible sets are skipped rather than rejected,
+        // but known members which occur more than once are always an error
+        let unknown = if self.extensible {
+            quote! {
+                decoder.tlv_bytes()?;
+            }
+        } else {
+            quote! {
+                return Err(decoder.error(tag.unexpected_error(None).kind()));
+            }
+        };
+
+        for (i, field) in self.fields.iter().enumerate() {
+            let field_ident = &field.ident;
+            let slot = format_ident!("slot_{}", i);
+            let binding = format_ident!("field_{}", i);
+            let decode = field.to_decode_tokens();
+            let matches = self.field_matches_tokens(field, &lifetime);
+
+            decode_arms.push(quote! {
+                if #slot.is_none() && #matches {
+                    #slot = Some({
+                        #decode
+                        #field_ident
+                    });
+                    continue;
+                }
+            });
+            known_tags.push(matches);
+
+            let absent = if field.attrs.optional {
+                quote!(None)
+            } else if let Some(default) = &field.attrs.default {
+                quote!(#default())
+            } else {
+                quote!(return Err(decoder.error(::der::ErrorKind::SetMissing)))
+            };
+
+            slots.push(quote!(let mut #slot = None;));
+            decode_result.push(quote! {
+                #field_ident: match #slot {
+                    Some(value) => value,
+                    None => #absent,
+                }
+            });
+
+            // Encoders for optional fields (including ones omitted when equal
+            // to their default value) are `Option`s which encode nothing when
+            // absent, so their position doesn't matter
+            let encode = field.to_encode_tokens();
+            let order = self.field_order_tokens(field);
+
+            encode_fields.push(quote!(let #binding = #encode;));
+            encode_refs.push(quote!((#order, #binding as &dyn ::der::Encode)));
+        }
+
+        // Check the invariants of the decoded value, if requested
+        let decode_finish = match &self.validate {
+            Some(validate) => quote! {
+                let value = Self {
+                    #(#decode_result),*
+                };
+                #validate(&value)?;
+                Ok(value)
+            },
+            None => quote! {
+                Ok(Self {
+                    #(#decode_result),*
+                })
+            },
+        };
+
+        quote! {
+            impl #decode_impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics
+            #decode_where_clause
+            {
+                fn decode_value(
+                    decoder: &mut ::der::Decoder<#lifetime>,
+                    header: ::der::Header,
+                ) -> ::der::Result<Self> {
+                    use ::der::{DecodeValue, Reader as _};
+
+                    // Members of a `SET` can occur in any order, so decode
+                    // each one into the slot for the field it matches.
+                    ::der::asn1::SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+                        #(#slots)*
+
+                        while !decoder.is_finished() {
+                            let tag = decoder.peek_tag()?;
+                            #(#decode_arms)*
+
+                            // All of the fields this member matches are
+                            // already present, i.e. it's a duplicate
+                            if false #(|| #known_tags)* {
+                                return Err(decoder.error(tag.unexpected_error(None).kind()));
+                            }
+
+                            #unknown
+                        }
+
+                        #decode_finish
+                    })
+                }
+            }
+
+            impl #encode_impl_generics ::der::EncodeValue for #ident #ty_generics
+            #encode_where_clause
+            {
+                fn value_len(&self) -> ::der::Result<::der::Length> {
+                    #(#encode_fields)*
+                    [#(#encode_refs),*]
+                        .iter()
+                        .try_fold(::der::Length::ZERO, |acc, (_, field)| acc + field.encoded_len()?)
+                }
+
+                fn encode_value(&self, encoder: &mut dyn ::der::Writer) -> ::der::Result<()> {
+                    #(#encode_fields)*
+                    let mut fields = [#(#encode_refs),*];
+
+                    // DER requires the members of a `SET` be sorted by tag,
+                    // where an untagged `CHOICE` has the smallest tag of its
+                    // alternatives regardless of which one it holds
+                    fields.sort_unstable_by_key(|(tag, _)| tag.map(|t| (t.class(), t.number())));
+
+                    for (_, field) in fields.iter() {
+                        field.encode(encoder)?;
+                    }
+
+                    Ok(())
+                }
+            }
+
+            impl #encode_impl_generics ::der::FixedTag for #ident #ty_generics
+            #encode_where_clause
+            {
+                const TAG: ::der::Tag = ::der::Tag::Set;
+            }
+        }
+    }
+
+    /// Derive an expression which is true if a `tag` belongs to the given
+    /// field.
+    fn field_matches_tokens(&self, field: &SequenceField, lifetime: &Lifetime) -> TokenStream {
+        if let Some(tag_number) = field.attrs.context_specific {
+            let tag_number = tag_number.to_tokens();
+            return quote!(tag.is_context_specific() && tag.number() == #tag_number);
+        }
+
+        if let Some(ty) = field.attrs.asn1_type {
+            let tag = ty.tag();
+            return quote!(tag == #tag);
+        }
+
+        let field_type = member_type(field);
+        quote!(<#field_type as ::der::Choice<#lifetime>>::can_decode(tag))
+    }
+
+    /// Derive an expression for the `Option<Tag>` which determines the
+    /// position of the given field when encoding.
+    fn field_order_tokens(&self, field: &SequenceField) -> TokenStream {
+        // Only the class and number of the tag are significant
+        if let Some(tag_number) = field.attrs.context_specific {
+            let tag_number = tag_number.to_tokens();
+            return quote! {
+                Some(::der::Tag::ContextSpecific {
+                    constructed: false,
+                    number: #tag_number,
+                })
+            };
+        }
+
+        if let Some(ty) = field.attrs.asn1_type {
+            let tag = ty.tag();
+            return quote!(Some(#tag));
+        }
+
+        let field_type = member_type(field);
+        quote!(<#field_type as ::der::Choice<'_>>::min_tag())
+    }
+}
+
+/// Get the type of the member of a `SET` for the given field, i.e. `T` for
+/// `optional` fields of type `Option<T>`.
+fn member_type(field: &SequenceField) -> &Type {
+    if field.attrs.optional {
+        option_inner_type(&field.field_type).unwrap_or_else(|| {
+            abort!(
+                field.ident,
+                "`optional` fields of a SET must have type `Option<T>`"
+            )
+        })
+    } else {
+        &field.field_type
+    }
+}
+
+/// Get `T` from a type of the form `Option<T>`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{option_inner_type, DeriveSet};
+    use crate::{TagMode, TagNumber};
+    use syn::{parse_quote, Type};
+
+    /// X.501 `Attribute`-like set of distinctly tagged members.
+    #[test]
+    fn set_example() {
+        let input = parse_quote! {
+            #[asn1(tag_mode = "IMPLICIT")]
+            pub struct ExampleSet<'a> {
+                pub oid: ObjectIdentifier,
+
+                #[asn1(context_specific = "0", optional = "true")]
+                pub name: Option<Utf8String<'a>>,
+
+                #[asn1(type = "OCTET STRING")]
+                pub value: &'a [u8],
+            }
+        };
+
+        let ir = DeriveSet::new(input);
+        assert_eq!(ir.ident, "ExampleSet");
+        assert_eq!(ir.lifetime.map(|lt| lt.to_string()).as_deref(), Some("'a"));
+        assert_eq!(ir.fields.len(), 3);
+
+        let name = &ir.fields[1];
+        assert_eq!(name.ident, "name");
+        assert_eq!(name.attrs.context_specific, Some(TagNumber(0)));
+        assert_eq!(name.attrs.tag_mode, TagMode::Implicit);
+        assert!(name.attrs.optional);
+    }
+
+    #[test]
+    fn option_inner() {
+        let ty: Type = parse_quote!(Option<Any<'a>>);
+        let inner: Type = parse_quote!(Any<'a>);
+        assert_eq!(option_inner_type(&ty), Some(&inner));
+
+        let ty: Type = parse_quote!(Vec<u8>);
+        assert_eq!(option_inner_type(&ty), None);
+    }
+}
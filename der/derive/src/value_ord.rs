@@ -9,15 +9,15 @@ use crate::{FieldAttrs, TypeAttrs};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{DeriveInput, Field, Ident, Lifetime, Variant};
+use syn::{parse_quote, DeriveInput, Field, Generics, Ident, Variant};
 
 /// Derive the `Enumerated` trait for an enum.
 pub(crate) struct DeriveValueOrd {
     /// Name of the enum.
     ident: Ident,
 
-    /// Lifetime of the struct.
-    lifetime: Option<Lifetime>,
+    /// Generic parameters of the struct, including its where clause.
+    generics: Generics,
 
     /// Fields of structs or enum variants.
     fields: Vec<ValueField>,
@@ -29,13 +29,6 @@ impl DeriveValueOrd {
         let ident = input.ident;
         let type_attrs = TypeAttrs::parse(&input.attrs);
 
-        // TODO(tarcieri): properly handle multiple lifetimes
-        let lifetime = input
-            .generics
-            .lifetimes()
-            .next()
-            .map(|lt| lt.lifetime.clone());
-
         let fields = match input.data {
             syn::Data::Enum(data) => data
                 .variants
@@ -56,7 +49,7 @@ impl DeriveValueOrd {
 
         Self {
             ident,
-            lifetime,
+            generics: input.generics,
             fields,
         }
     }
@@ -65,13 +58,18 @@ impl DeriveValueOrd {
     pub fn to_tokens(&self) -> TokenStream {
         let ident = &self.ident;
 
-        // Lifetime parameters
-        // TODO(tarcieri): support multiple lifetimes
-        let lt_params = self
-            .lifetime
-            .as_ref()
-            .map(|lt| vec![lt.clone()])
-            .unwrap_or_default();
+        // Bound all type parameters on `DerOrd`
+        let mut generics = self.generics.clone();
+
+        for param in self.generics.type_params() {
+            let param = &param.ident;
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#param: ::der::DerOrd));
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let mut body = Vec::new();
 
@@ -80,7 +78,7 @@ impl DeriveValueOrd {
         }
 
         quote! {
-            impl<#(#lt_params)*> ::der::ValueOrd for #ident<#(#lt_params)*> {
+            impl #impl_generics ::der::ValueOrd for #ident #ty_generics #where_clause {
                 fn value_cmp(&self, other: &Self) -> ::der::Result<::core::cmp::Ordering> {
                     #[allow(unused_imports)]
                     use ::der::DerOrd;
@@ -125,11 +123,19 @@ impl ValueField {
 
     /// Lower to [`TokenStream`].
     fn to_tokens(&self) -> TokenStream {
+        // Captured raw encodings aren't part of the value
+        if self.attrs.capture_raw.is_some() {
+            return TokenStream::new();
+        }
+
         let ident = &self.ident;
         let mut binding1 = quote!(self.#ident);
         let mut binding2 = quote!(other.#ident);
 
-        if let Some(ty) = &self.attrs.asn1_type {
+        if let Some(encode_with) = &self.attrs.encode_with {
+            binding1 = quote!(#encode_with(&#binding1)?);
+            binding2 = quote!(#encode_with(&#binding2)?);
+        } else if let Some(ty) = &self.attrs.asn1_type {
             binding1 = ty.encoder(&binding1);
             binding2 = ty.encoder(&binding2);
         }
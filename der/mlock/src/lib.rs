@@ -0,0 +1,175 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/der-mlock/0.1.0-pre"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+//! # Usage
+//!
+//! ```
+//! # fn main() -> der::Result<()> {
+//! use der::{asn1::OctetString, SecretDocument};
+//! use der_mlock::LockedDocument;
+//!
+//! // `SEQUENCE` containing a secret `OCTET STRING`
+//! let doc = SecretDocument::encode_msg(&[OctetString::new(&[0x42; 32])?])?;
+//! let locked = LockedDocument::new(doc);
+//!
+//! let [secret]: [OctetString<'_>; 1] = locked.decode_msg()?;
+//! assert_eq!(secret.as_bytes(), &[0x42; 32]);
+//!
+//! // Locking is best effort: check whether it succeeded
+//! if !locked.is_locked() {
+//!     // e.g. warn that the secret may be swapped to disk
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod sys;
+
+use core::{fmt, ptr::NonNull, slice};
+use der::{zeroize::Zeroize, Decode, Length, Result, SecretDocument};
+use std::alloc::{self, Layout};
+
+/// ASN.1 DER-encoded secret document held in locked memory.
+///
+/// The document is copied into its own page-aligned allocation, which is
+/// locked into memory using `mlock(2)` on Unix or `VirtualLock` on Windows,
+/// preventing it from being written to swap. Since no other data shares its
+/// pages, locking and unlocking a [`LockedDocument`] never affects other
+/// locked memory.
+///
+/// Locking is best effort: it fails if the process exceeds its limit on
+/// locked memory (e.g. `RLIMIT_MEMLOCK`) or on other platforms, which
+/// [`LockedDocument::is_locked`] can be used to detect. The contents are
+/// zeroized before being unlocked on drop.
+pub struct LockedDocument {
+    /// Page-aligned buffer containing the document.
+    ptr: NonNull<u8>,
+
+    /// Layout of the buffer, which is a whole number of pages.
+    layout: Layout,
+
+    /// Length of the document in bytes.
+    len: Length,
+
+    /// Is the buffer locked?
+    locked: bool,
+}
+
+impl LockedDocument {
+    /// Copy the given document into locked memory.
+    ///
+    /// The original document is zeroized and dropped.
+    pub fn new(doc: SecretDocument) -> Self {
+        let bytes = doc.as_bytes();
+        let page_size = sys::page_size();
+        let size = (bytes.len() + page_size - 1) / page_size * page_size;
+        let layout = Layout::from_size_align(size.max(page_size), page_size)
+            .expect("page size is a power of two");
+
+        #[allow(unsafe_code)]
+        // SAFETY: the layout has a non-zero size
+        let ptr = unsafe { alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        let locked = sys::lock(ptr.as_ptr(), layout.size());
+
+        let mut locked_doc = Self {
+            ptr,
+            layout,
+            len: doc.len(),
+            locked,
+        };
+
+        locked_doc.as_bytes_mut()[..bytes.len()].copy_from_slice(bytes);
+        locked_doc
+    }
+
+    /// Borrow the inner serialized bytes of this document.
+    pub fn as_bytes(&self) -> &[u8] {
+        #[allow(unsafe_code)]
+        // SAFETY: the buffer is initialized and contains at least `len` bytes
+        unsafe {
+            slice::from_raw_parts(self.ptr.as_ptr(), self.byte_len())
+        }
+    }
+
+    /// Get the length of the encoded ASN.1 DER in bytes.
+    pub fn len(&self) -> Length {
+        self.len
+    }
+
+    /// Is the memory containing this document locked, preventing it from
+    /// being swapped to disk?
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Try to decode the inner ASN.1 DER message as the given type.
+    pub fn decode_msg<'a, T: Decode<'a>>(&'a self) -> Result<T> {
+        T::from_der(self.as_bytes())
+    }
+
+    /// Mutably borrow the inner serialized bytes of this document.
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        #[allow(unsafe_code)]
+        // SAFETY: the buffer is initialized and contains at least `len` bytes
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr.as_ptr(), self.byte_len())
+        }
+    }
+
+    /// Get the length of the document as a `usize`.
+    fn byte_len(&self) -> usize {
+        usize::try_from(self.len).expect("document length fits in memory")
+    }
+}
+
+impl Clone for LockedDocument {
+    fn clone(&self) -> Self {
+        let doc = SecretDocument::try_from(self.as_bytes()).expect("document is valid DER");
+        Self::new(doc)
+    }
+}
+
+impl fmt::Debug for LockedDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LockedDocument")
+            .field("locked", &self.locked)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for LockedDocument {
+    fn drop(&mut self) {
+        self.as_bytes_mut().zeroize();
+
+        if self.locked {
+            sys::unlock(self.ptr.as_ptr(), self.layout.size());
+        }
+
+        #[allow(unsafe_code)]
+        // SAFETY: the buffer was allocated in `new` with the same layout
+        unsafe {
+            alloc::dealloc(self.ptr.as_ptr(), self.layout);
+        }
+    }
+}
+
+impl From<SecretDocument> for LockedDocument {
+    fn from(doc: SecretDocument) -> LockedDocument {
+        LockedDocument::new(doc)
+    }
+}
+
+// SAFETY: `LockedDocument` uniquely owns its buffer, like a `Vec<u8>`
+#[allow(unsafe_code)]
+unsafe impl Send for LockedDocument {}
+
+// SAFETY: `LockedDocument` only allows shared access through `&self`
+#[allow(unsafe_code)]
+unsafe impl Sync for LockedDocument {}
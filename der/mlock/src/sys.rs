@@ -0,0 +1,89 @@
+//! Platform-specific memory locking.
+
+#![allow(unsafe_code)]
+
+/// Fallback page size if it can't be queried.
+#[cfg(not(windows))]
+const DEFAULT_PAGE_SIZE: usize = 4096;
+
+/// Get the size of a memory page, which is the granularity of locking.
+#[cfg(unix)]
+pub(crate) fn page_size() -> usize {
+    // SAFETY: `sysconf` has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+    match usize::try_from(size) {
+        Ok(size) if size.is_power_of_two() => size,
+        _ => DEFAULT_PAGE_SIZE,
+    }
+}
+
+/// Lock the given memory into RAM, preventing it from being swapped to disk.
+///
+/// Returns `true` if the memory was successfully locked.
+#[cfg(unix)]
+pub(crate) fn lock(ptr: *const u8, len: usize) -> bool {
+    // SAFETY: `mlock` doesn't access the memory, and fails on invalid ranges
+    unsafe { libc::mlock(ptr.cast(), len) == 0 }
+}
+
+/// Unlock memory previously locked with [`lock`].
+#[cfg(unix)]
+pub(crate) fn unlock(ptr: *const u8, len: usize) {
+    // SAFETY: `munlock` doesn't access the memory, and fails on invalid ranges
+    unsafe {
+        libc::munlock(ptr.cast(), len);
+    }
+}
+
+/// Get the size of a memory page, which is the granularity of locking.
+#[cfg(windows)]
+pub(crate) fn page_size() -> usize {
+    use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+    // SAFETY: `SYSTEM_INFO` is plain data, which `GetSystemInfo` fills in
+    let info = unsafe {
+        let mut info = core::mem::zeroed::<SYSTEM_INFO>();
+        GetSystemInfo(&mut info);
+        info
+    };
+
+    info.dwPageSize as usize
+}
+
+/// Lock the given memory into the working set of the process, preventing it
+/// from being paged out to disk.
+///
+/// Returns `true` if the memory was successfully locked.
+#[cfg(windows)]
+pub(crate) fn lock(ptr: *const u8, len: usize) -> bool {
+    // SAFETY: `VirtualLock` doesn't access the memory, and fails on invalid
+    // ranges
+    unsafe { windows_sys::Win32::System::Memory::VirtualLock(ptr.cast(), len) != 0 }
+}
+
+/// Unlock memory previously locked with [`lock`].
+#[cfg(windows)]
+pub(crate) fn unlock(ptr: *const u8, len: usize) {
+    // SAFETY: `VirtualUnlock` doesn't access the memory, and fails on invalid
+    // ranges
+    unsafe {
+        windows_sys::Win32::System::Memory::VirtualUnlock(ptr.cast(), len);
+    }
+}
+
+/// Get the size of a memory page, which is the granularity of locking.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn page_size() -> usize {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Memory locking is unsupported on this platform.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn lock(_ptr: *const u8, _len: usize) -> bool {
+    false
+}
+
+/// Memory locking is unsupported on this platform.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn unlock(_ptr: *const u8, _len: usize) {}
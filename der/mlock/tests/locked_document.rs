@@ -0,0 +1,61 @@
+//! `LockedDocument` tests
+
+use der::{asn1::OctetString, SecretDocument};
+use der_mlock::LockedDocument;
+use hex_literal::hex;
+
+/// DER-encoded `SEQUENCE` containing a 32-byte secret `OCTET STRING`.
+const SECRET_DER: &[u8] =
+    &hex!("302204200102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20");
+
+fn locked_document() -> LockedDocument {
+    LockedDocument::new(SecretDocument::try_from(SECRET_DER).unwrap())
+}
+
+#[test]
+fn copy_and_decode() {
+    let doc = locked_document();
+    assert_eq!(doc.as_bytes(), SECRET_DER);
+    assert_eq!(doc.len(), der::Length::new(36));
+
+    let [secret]: [OctetString<'_>; 1] = doc.decode_msg().unwrap();
+    assert_eq!(secret.as_bytes(), &SECRET_DER[4..]);
+}
+
+#[test]
+#[cfg(any(unix, windows))]
+fn lock() {
+    assert!(locked_document().is_locked());
+}
+
+#[test]
+fn clones_are_independent() {
+    let doc = locked_document();
+    let clone = doc.clone();
+    assert_ne!(doc.as_bytes().as_ptr(), clone.as_bytes().as_ptr());
+    assert_eq!(doc.is_locked(), clone.is_locked());
+
+    drop(doc);
+    assert_eq!(clone.as_bytes(), SECRET_DER);
+}
+
+#[test]
+fn page_aligned() {
+    let doc = locked_document();
+    let other = locked_document();
+
+    // Pages are at least 4 KiB on all supported platforms. Each document
+    // occupies its own pages, so unlocking one never unlocks the other.
+    let page = |doc: &LockedDocument| doc.as_bytes().as_ptr() as usize / 4096;
+    assert_eq!(doc.as_bytes().as_ptr() as usize % 4096, 0);
+    assert_ne!(page(&doc), page(&other));
+}
+
+#[test]
+fn debug_redacts_contents() {
+    let doc = locked_document();
+    assert_eq!(
+        format!("{:?}", doc),
+        format!("LockedDocument {{ locked: {}, .. }}", doc.is_locked())
+    );
+}
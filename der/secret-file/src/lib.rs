@@ -0,0 +1,45 @@
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/der-secret-file/0.1.0-pre"
+)]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+//! # Usage
+//!
+//! ```
+//! # fn main() -> std::io::Result<()> {
+//! use std::io::Write;
+//!
+//! # let dir = std::env::temp_dir();
+//! # let path = dir.join(format!("der-secret-file-doctest-{}", std::process::id()));
+//! let mut file = der_secret_file::create_new(&path)?;
+//! file.write_all(b"secret")?;
+//! # drop(file);
+//! # std::fs::remove_file(&path)?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod sys;
+
+use std::{fs::File, io, path::Path};
+
+/// Create a file which is only accessible by its owner, opened for writing.
+///
+/// If the file already exists, its permissions are restricted before it's
+/// truncated, since they only apply when a file is created.
+pub fn create(path: impl AsRef<Path>) -> io::Result<File> {
+    sys::create(path.as_ref(), true)
+}
+
+/// Create a new file which is only accessible by its owner, opened for
+/// writing.
+///
+/// Returns an error if the file already exists, which also ensures data is
+/// never written through a symlink or to a file created by another user.
+pub fn create_new(path: impl AsRef<Path>) -> io::Result<File> {
+    sys::create(path.as_ref(), false)
+}
@@ -0,0 +1,49 @@
+//! Platform-specific file creation.
+
+#[cfg(windows)]
+mod windows;
+
+#[cfg(windows)]
+pub(crate) use self::windows::create;
+
+#[cfg(not(windows))]
+use std::{fs, io, path::Path};
+
+/// Options for opening a file for writing, creating it if necessary.
+#[cfg(not(windows))]
+fn open_options(overwrite: bool) -> fs::OpenOptions {
+    let mut options = fs::OpenOptions::new();
+    options.write(true);
+
+    if overwrite {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+
+    options
+}
+
+/// Create a file with mode `0600`.
+///
+/// New files are created with this mode atomically, and the mode of an
+/// existing file is also restricted before any data is written.
+#[cfg(unix)]
+pub(crate) fn create(path: &Path, overwrite: bool) -> io::Result<fs::File> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    /// File permissions for secret data
+    const SECRET_FILE_PERMS: u32 = 0o600;
+
+    let file = open_options(overwrite).mode(SECRET_FILE_PERMS).open(path)?;
+
+    file.set_permissions(fs::Permissions::from_mode(SECRET_FILE_PERMS))?;
+    Ok(file)
+}
+
+/// Create a file with the platform's default permissions, which can't be
+/// restricted.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn create(path: &Path, overwrite: bool) -> io::Result<fs::File> {
+    open_options(overwrite).open(path)
+}
@@ -0,0 +1,333 @@
+//! Creation of files with an owner-only DACL on Windows.
+
+#![allow(unsafe_code)]
+
+use std::{
+    ffi::c_void,
+    fs::File,
+    io, mem,
+    os::windows::{ffi::OsStrExt, io::FromRawHandle},
+    path::Path,
+    ptr,
+};
+use windows_sys::Win32::{
+    Foundation::{CloseHandle, BOOL, HANDLE, INVALID_HANDLE_VALUE, PSID},
+    Security::{
+        AddAccessAllowedAce,
+        Authorization::{SetSecurityInfo, SE_FILE_OBJECT},
+        GetLengthSid, GetTokenInformation, InitializeAcl, InitializeSecurityDescriptor,
+        SetSecurityDescriptorControl, SetSecurityDescriptorDacl, TokenUser, ACCESS_ALLOWED_ACE,
+        ACL, ACL_REVISION, DACL_SECURITY_INFORMATION, PROTECTED_DACL_SECURITY_INFORMATION,
+        SECURITY_ATTRIBUTES, SECURITY_DESCRIPTOR, TOKEN_QUERY, TOKEN_USER,
+    },
+    Storage::FileSystem::{
+        CreateFileW, CREATE_ALWAYS, CREATE_NEW, FILE_ALL_ACCESS, FILE_ATTRIBUTE_NORMAL,
+        FILE_GENERIC_WRITE, FILE_SHARE_NONE, READ_CONTROL,
+    },
+    System::{
+        SystemServices::{SECURITY_DESCRIPTOR_REVISION, SE_DACL_PROTECTED, WRITE_DAC},
+        Threading::{GetCurrentProcess, OpenProcessToken},
+    },
+};
+
+/// Create a file whose DACL only grants access to the current user.
+///
+/// The DACL is protected, so no entries are inherited from the parent
+/// directory. New files are created with it atomically, and it replaces the
+/// DACL of an existing file before any data is written, since the security
+/// descriptor passed when creating only applies to new files. Other
+/// processes are denied access to the file while it's open.
+pub(crate) fn create(path: &Path, overwrite: bool) -> io::Result<File> {
+    let path = wide_path(path)?;
+    let user = CurrentUser::get()?;
+    let mut acl = owner_only_acl(user.sid())?;
+    let acl_ptr = acl.as_mut_ptr().cast::<ACL>();
+
+    // SAFETY: `SECURITY_DESCRIPTOR` is plain data, which is initialized by
+    // `InitializeSecurityDescriptor`
+    let mut descriptor = unsafe { mem::zeroed::<SECURITY_DESCRIPTOR>() };
+    let descriptor_ptr = (&mut descriptor as *mut SECURITY_DESCRIPTOR).cast::<c_void>();
+
+    // SAFETY: `descriptor` and `acl` outlive every use of the descriptor
+    unsafe {
+        check(InitializeSecurityDescriptor(
+            descriptor_ptr,
+            SECURITY_DESCRIPTOR_REVISION,
+        ))?;
+        check(SetSecurityDescriptorDacl(descriptor_ptr, 1, acl_ptr, 0))?;
+        check(SetSecurityDescriptorControl(
+            descriptor_ptr,
+            SE_DACL_PROTECTED as u16,
+            SE_DACL_PROTECTED as u16,
+        ))?;
+    }
+
+    let attributes = SECURITY_ATTRIBUTES {
+        nLength: mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor_ptr,
+        bInheritHandle: 0,
+    };
+
+    let disposition = if overwrite { CREATE_ALWAYS } else { CREATE_NEW };
+
+    // SAFETY: `path` is NUL-terminated, and `attributes` points to a valid
+    // security descriptor
+    let handle = unsafe {
+        CreateFileW(
+            path.as_ptr(),
+            FILE_GENERIC_WRITE | READ_CONTROL | WRITE_DAC,
+            FILE_SHARE_NONE,
+            &attributes,
+            disposition,
+            FILE_ATTRIBUTE_NORMAL,
+            0,
+        )
+    };
+
+    if handle == INVALID_HANDLE_VALUE {
+        return Err(io::Error::last_os_error());
+    }
+
+    // SAFETY: `handle` is a valid file handle, which is now owned by `file`
+    let file = unsafe { File::from_raw_handle(handle as _) };
+
+    if overwrite {
+        // SAFETY: `handle` is open with `WRITE_DAC` access, and `acl` is valid
+        let err = unsafe {
+            SetSecurityInfo(
+                handle,
+                SE_FILE_OBJECT,
+                DACL_SECURITY_INFORMATION | PROTECTED_DACL_SECURITY_INFORMATION,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                acl_ptr,
+                ptr::null(),
+            )
+        };
+
+        if err != 0 {
+            return Err(io::Error::from_raw_os_error(err as i32));
+        }
+    }
+
+    Ok(file)
+}
+
+/// User account of the current process.
+struct CurrentUser {
+    /// `TOKEN_USER` followed by the SID it points to, aligned for the
+    /// pointer it contains.
+    buf: Vec<usize>,
+}
+
+impl CurrentUser {
+    /// Get the user from the current process's access token.
+    fn get() -> io::Result<Self> {
+        let mut token: HANDLE = 0;
+
+        // SAFETY: `GetCurrentProcess` returns a pseudo handle which is always
+        // valid, and `token` receives a handle which is closed by `Token`
+        unsafe {
+            check(OpenProcessToken(
+                GetCurrentProcess(),
+                TOKEN_QUERY,
+                &mut token,
+            ))?
+        };
+        let token = Token(token);
+
+        // Query the size of the buffer, which fails with
+        // `ERROR_INSUFFICIENT_BUFFER`
+        let mut len = 0;
+
+        // SAFETY: a null buffer of length 0 is never written to
+        unsafe { GetTokenInformation(token.0, TokenUser, ptr::null_mut(), 0, &mut len) };
+
+        let mut buf = vec![0usize; len as usize / mem::size_of::<usize>() + 1];
+
+        // SAFETY: `buf` is at least `len` bytes
+        unsafe {
+            check(GetTokenInformation(
+                token.0,
+                TokenUser,
+                buf.as_mut_ptr().cast(),
+                len,
+                &mut len,
+            ))?
+        };
+
+        Ok(Self { buf })
+    }
+
+    /// Get the user's SID, which points into `self`.
+    fn sid(&self) -> PSID {
+        // SAFETY: `buf` was filled in by `GetTokenInformation` with a
+        // `TOKEN_USER`, and is suitably aligned
+        unsafe { (*self.buf.as_ptr().cast::<TOKEN_USER>()).User.Sid }
+    }
+}
+
+/// Access token handle, which is closed on drop.
+struct Token(HANDLE);
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        // SAFETY: the handle is open, and only closed once
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+/// Build an ACL containing a single entry which grants the given SID full
+/// access to a file.
+///
+/// The ACL is returned as a buffer of `u32`s, which are its required
+/// alignment.
+fn owner_only_acl(sid: PSID) -> io::Result<Vec<u32>> {
+    // SAFETY: `sid` is a valid SID
+    let sid_len = unsafe { GetLengthSid(sid) } as usize;
+
+    // The ACE's `SidStart` field is the first `u32` of the SID
+    let len = mem::size_of::<ACL>() + mem::size_of::<ACCESS_ALLOWED_ACE>() - mem::size_of::<u32>()
+        + sid_len;
+
+    let mut acl = vec![0u32; len / mem::size_of::<u32>() + 1];
+    let acl_ptr = acl.as_mut_ptr().cast::<ACL>();
+
+    // SAFETY: `acl` is large enough for the header and a single entry
+    unsafe {
+        check(InitializeAcl(
+            acl_ptr,
+            (acl.len() * mem::size_of::<u32>()) as u32,
+            ACL_REVISION,
+        ))?;
+        check(AddAccessAllowedAce(
+            acl_ptr,
+            ACL_REVISION,
+            FILE_ALL_ACCESS,
+            sid,
+        ))?;
+    }
+
+    Ok(acl)
+}
+
+/// Convert a path to a NUL-terminated UTF-16 string.
+fn wide_path(path: &Path) -> io::Result<Vec<u16>> {
+    let mut wide = path.as_os_str().encode_wide().collect::<Vec<u16>>();
+
+    if wide.contains(&0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "path contains a NUL character",
+        ));
+    }
+
+    wide.push(0);
+    Ok(wide)
+}
+
+/// Convert the result of a Win32 function into an [`io::Result`].
+fn check(result: BOOL) -> io::Result<()> {
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, CurrentUser};
+    use std::{ffi::c_void, fs, io, os::windows::io::AsRawHandle, path::PathBuf, ptr};
+    use windows_sys::Win32::{
+        Security::{
+            Authorization::{GetSecurityInfo, SE_FILE_OBJECT},
+            EqualSid, GetAce, GetSecurityDescriptorControl, ACCESS_ALLOWED_ACE, ACL,
+            DACL_SECURITY_INFORMATION,
+        },
+        Storage::FileSystem::FILE_ALL_ACCESS,
+        System::{
+            Memory::LocalFree,
+            SystemServices::{ACCESS_ALLOWED_ACE_TYPE, SE_DACL_PROTECTED},
+        },
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("der-secret-file-{}-{}", std::process::id(), name))
+    }
+
+    /// Check that the file's DACL is protected and only grants the current
+    /// user access.
+    fn assert_owner_only(file: &fs::File) {
+        let user = CurrentUser::get().unwrap();
+        let mut dacl: *mut ACL = ptr::null_mut();
+        let mut descriptor: *mut c_void = ptr::null_mut();
+
+        unsafe {
+            let err = GetSecurityInfo(
+                file.as_raw_handle() as _,
+                SE_FILE_OBJECT,
+                DACL_SECURITY_INFORMATION,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                &mut dacl,
+                ptr::null_mut(),
+                &mut descriptor,
+            );
+            assert_eq!(err, 0);
+
+            let mut control = 0u16;
+            let mut revision = 0u32;
+            check(GetSecurityDescriptorControl(
+                descriptor,
+                &mut control,
+                &mut revision,
+            ))
+            .unwrap();
+            assert_ne!(control & SE_DACL_PROTECTED as u16, 0);
+
+            assert!(!dacl.is_null());
+            assert_eq!((*dacl).AceCount, 1);
+
+            let mut ace: *mut c_void = ptr::null_mut();
+            check(GetAce(dacl, 0, &mut ace)).unwrap();
+            let ace = ace.cast::<ACCESS_ALLOWED_ACE>();
+            assert_eq!((*ace).Header.AceType as u32, ACCESS_ALLOWED_ACE_TYPE);
+            assert_eq!((*ace).Mask, FILE_ALL_ACCESS);
+
+            let sid = ptr::addr_of!((*ace).SidStart) as *mut c_void;
+            assert_ne!(EqualSid(sid, user.sid()), 0);
+
+            LocalFree(descriptor as isize);
+        }
+    }
+
+    #[test]
+    fn create_new() {
+        let path = temp_path("create-new");
+        let file = super::create(&path, false).unwrap();
+        assert_owner_only(&file);
+        drop(file);
+
+        let err = super::create(&path, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn overwrite_existing() {
+        // Created with the DACL inherited from the temporary directory
+        let path = temp_path("overwrite");
+        fs::write(&path, b"existing").unwrap();
+
+        let file = super::create(&path, true).unwrap();
+        assert_owner_only(&file);
+        drop(file);
+
+        assert_eq!(fs::metadata(&path).unwrap().len(), 0);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
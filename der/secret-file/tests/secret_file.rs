@@ -0,0 +1,51 @@
+//! Secret file creation tests
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("der-secret-file-{}-{}", std::process::id(), name))
+}
+
+#[cfg(unix)]
+fn assert_owner_only(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let perms = fs::metadata(path).unwrap().permissions();
+    assert_eq!(perms.mode() & 0o777, 0o600);
+}
+
+#[cfg(not(unix))]
+fn assert_owner_only(_path: &Path) {}
+
+#[test]
+fn create_new() {
+    let path = temp_path("create-new");
+    let mut file = der_secret_file::create_new(&path).unwrap();
+    file.write_all(b"secret").unwrap();
+    drop(file);
+
+    assert_eq!(fs::read(&path).unwrap(), b"secret");
+    assert_owner_only(&path);
+
+    let err = der_secret_file::create_new(&path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn create_overwrites() {
+    let path = temp_path("create");
+    fs::write(&path, b"a longer existing file").unwrap();
+
+    let mut file = der_secret_file::create(&path).unwrap();
+    file.write_all(b"secret").unwrap();
+    drop(file);
+
+    assert_eq!(fs::read(&path).unwrap(), b"secret");
+    assert_owner_only(&path);
+    fs::remove_file(&path).unwrap();
+}
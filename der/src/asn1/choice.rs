@@ -12,6 +12,28 @@ use crate::{Decode, FixedTag, Tag, Tagged};
 pub trait Choice<'a>: Decode<'a> + Tagged {
     /// Is the provided [`Tag`] decodable as a variant of this `CHOICE`?
     fn can_decode(tag: Tag) -> bool;
+
+    /// Get the smallest tag of this `CHOICE`'s alternatives, ordered by class
+    /// and then by number.
+    ///
+    /// This is the tag which determines the position of an untagged `CHOICE`
+    /// among the members of a `SET` (X.680 §8.6). The default impl searches
+    /// for the first tag in that order which [`Choice::can_decode`].
+    fn min_tag() -> Option<Tag> {
+        for class in [0x00, 0x40, 0x80, 0xC0] {
+            for number in 0..0x1F {
+                for constructed in [0x00, 0x20] {
+                    if let Ok(tag) = Tag::try_from(class | constructed | number) {
+                        if Self::can_decode(tag) {
+                            return Some(tag);
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
 }
 
 /// This blanket impl allows any [`Tagged`] type to function as a [`Choice`]
@@ -23,4 +45,8 @@ where
     fn can_decode(tag: Tag) -> bool {
         T::TAG == tag
     }
+
+    fn min_tag() -> Option<Tag> {
+        Some(T::TAG)
+    }
 }
@@ -22,6 +22,18 @@ pub trait Sequence<'a>: Decode<'a> {
         F: FnOnce(&[&dyn Encode]) -> Result<T>;
 }
 
+/// Decode the fields of a `SEQUENCE` from a [`Decoder`] without decoding
+/// its header.
+///
+/// This is used to include the fields of one `SEQUENCE` type within another,
+/// i.e. the ASN.1 `COMPONENTS OF` notation. It's impl'd by the `Sequence`
+/// custom derive, except for extensible types, which would consume the
+/// trailing fields of the enclosing `SEQUENCE`.
+pub trait DecodeFields<'a>: Sized {
+    /// Decode the fields of this `SEQUENCE`, stopping after the last one.
+    fn decode_fields(decoder: &mut Decoder<'a>) -> Result<Self>;
+}
+
 impl<'a, M> EncodeValue for M
 where
     M: Sequence<'a>,
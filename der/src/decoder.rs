@@ -209,6 +209,22 @@ impl<'a> Decoder<'a> {
         self.read_slice((header_len + header.length)?)
     }
 
+    /// Obtain the complete TLV production (i.e. including the header) of a
+    /// value whose [`Header`] has just been decoded, without advancing the
+    /// cursor.
+    ///
+    /// This makes it possible to retain the exact encoding of a value while
+    /// decoding it, e.g. the signed portion of a certificate.
+    pub fn raw_tlv(&self, header: Header) -> Result<&'a [u8]> {
+        let start = (self.position - header.encoded_len()?)?;
+        let end = (self.position + header.length)?;
+
+        self.bytes
+            .as_slice()
+            .get(start.try_into()?..end.try_into()?)
+            .ok_or_else(|| Error::incomplete(self.input_len()))
+    }
+
     /// Obtain the remaining bytes in this decoder from the current cursor
     /// position.
     fn remaining(&self) -> Result<&'a [u8]> {
@@ -272,7 +288,7 @@ impl<'a> Reader<'a> for Decoder<'a> {
 #[cfg(test)]
 mod tests {
     use super::Decoder;
-    use crate::{Decode, ErrorKind, Length, Reader, Tag};
+    use crate::{Decode, ErrorKind, Header, Length, Reader, Tag};
     use hex_literal::hex;
 
     // INTEGER: 42
@@ -352,4 +368,12 @@ mod tests {
         assert_eq!(header.length, Length::ONE);
         assert_eq!(decoder.position(), Length::ZERO); // Position unchanged
     }
+
+    #[test]
+    fn raw_tlv() {
+        let mut decoder = Decoder::new(EXAMPLE_MSG).unwrap();
+        let header = Header::decode(&mut decoder).unwrap();
+        assert_eq!(decoder.raw_tlv(header).unwrap(), &EXAMPLE_MSG[..3]);
+        assert_eq!(decoder.position(), Length::from(2u8)); // Position unchanged
+    }
 }
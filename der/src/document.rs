@@ -203,6 +203,10 @@ impl TryFrom<Vec<u8>> for Document {
 /// This type provides additional hardening such as ensuring that the contents
 /// are zeroized-on-drop, and also using more restrictive file permissions when
 /// writing files to disk.
+///
+/// Files are only accessible by their owner: on Unix they're written with
+/// mode `0600`, and on Windows with a protected DACL which only grants access
+/// to the current user (see the `der-secret-file` crate).
 #[cfg(feature = "zeroize")]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "zeroize"))))]
 #[derive(Clone)]
@@ -264,7 +268,15 @@ impl SecretDocument {
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
-        write_secret_file(path, self.as_bytes())
+        write_secret_file(path, self.as_bytes(), true)
+    }
+
+    /// Write ASN.1 DER document to a new file, returning an error if the file
+    /// already exists.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_der_file_new(&self, path: impl AsRef<Path>) -> Result<()> {
+        write_secret_file(path, self.as_bytes(), false)
     }
 
     /// Read PEM-encoded ASN.1 DER document from a file.
@@ -283,7 +295,20 @@ impl SecretDocument {
         label: &'static str,
         line_ending: pem::LineEnding,
     ) -> Result<()> {
-        write_secret_file(path, self.to_pem(label, line_ending)?.as_bytes())
+        write_secret_file(path, self.to_pem(label, line_ending)?.as_bytes(), true)
+    }
+
+    /// Write PEM-encoded ASN.1 DER document to a new file, returning an error
+    /// if the file already exists.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    pub fn write_pem_file_new(
+        &self,
+        path: impl AsRef<Path>,
+        label: &'static str,
+        line_ending: pem::LineEnding,
+    ) -> Result<()> {
+        write_secret_file(path, self.to_pem(label, line_ending)?.as_bytes(), false)
     }
 }
 #[cfg(feature = "zeroize")]
@@ -339,30 +364,26 @@ fn decode_sequence<'a>(decoder: &mut Decoder<'a>) -> Result<&'a [u8]> {
 }
 
 /// Write a file containing secret data to the filesystem, restricting the
-/// file permissions so it's only readable by the owner
-#[cfg(all(unix, feature = "std", feature = "zeroize"))]
-fn write_secret_file(path: impl AsRef<Path>, data: &[u8]) -> Result<()> {
-    use std::{io::Write, os::unix::fs::OpenOptionsExt};
-
-    /// File permissions for secret data
-    #[cfg(unix)]
-    const SECRET_FILE_PERMS: u32 = 0o600;
-
-    fs::OpenOptions::new()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .mode(SECRET_FILE_PERMS)
-        .open(path)
-        .and_then(|mut file| file.write_all(data))?;
-
-    Ok(())
-}
-
-/// Write a file containing secret data to the filesystem
-// TODO(tarcieri): permissions hardening on Windows
-#[cfg(all(not(unix), feature = "std", feature = "zeroize"))]
-fn write_secret_file(path: impl AsRef<Path>, data: &[u8]) -> Result<()> {
-    fs::write(path, data)?;
+/// file permissions so it's only accessible by the owner.
+///
+/// On Unix, the file has mode `0600`. On Windows, its DACL only grants access
+/// to the current user, and isn't inherited from its parent directory. New
+/// files are created with these permissions atomically, and the permissions
+/// of an existing file are also restricted before any data is written.
+///
+/// If `overwrite` is `false`, an error is returned if the file already
+/// exists, which also ensures the data is never written through a symlink
+/// or to a file created by another user.
+#[cfg(all(feature = "std", feature = "zeroize"))]
+fn write_secret_file(path: impl AsRef<Path>, data: &[u8], overwrite: bool) -> Result<()> {
+    use std::io::Write;
+
+    let mut file = if overwrite {
+        der_secret_file::create(path)?
+    } else {
+        der_secret_file::create_new(path)?
+    };
+
+    file.write_all(data)?;
     Ok(())
 }
@@ -230,6 +230,9 @@ pub enum ErrorKind {
     /// `SET` ordering error: items not in canonical order.
     SetOrdering,
 
+    /// `SET` member error: a required member is absent.
+    SetMissing,
+
     /// Integer overflow occurred (library bug!).
     Overflow,
 
@@ -331,6 +334,7 @@ impl fmt::Display for ErrorKind {
                 write!(f, "unknown/unsupported OID: {}", oid)
             }
             ErrorKind::SetOrdering => write!(f, "SET OF ordering error"),
+            ErrorKind::SetMissing => write!(f, "SET is missing a required member"),
             ErrorKind::Overflow => write!(f, "integer overflow"),
             ErrorKind::Overlength => write!(f, "ASN.1 DER message is too long"),
             #[cfg(feature = "pem")]
@@ -232,6 +232,7 @@
 //! - [`Choice`]: derive for `CHOICE` enum (see [`der_derive::Choice`])
 //! - [`Enumerated`]: derive for `ENUMERATED` enum (see [`der_derive::Enumerated`])
 //! - [`Sequence`]: derive for `SEQUENCE` struct (see [`der_derive::Sequence`])
+//! - [`Set`]: derive for `SET` struct (see [`der_derive::Set`])
 //!
 //! ### Derive [`Sequence`] for struct
 //! The following is a code example of how to use the [`Sequence`] custom derive:
@@ -367,7 +368,7 @@ mod writer;
 mod document;
 
 pub use crate::{
-    asn1::{Any, Choice, Sequence},
+    asn1::{Any, Choice, DecodeFields, Sequence},
     datetime::DateTime,
     decode::{Decode, DecodeOwned, DecodeValue},
     decoder::Decoder,
@@ -392,7 +393,7 @@ pub use crypto_bigint as bigint;
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use der_derive::{Choice, Enumerated, Newtype, Sequence, ValueOrd};
+pub use der_derive::{Choice, Enumerated, Newtype, Sequence, Set, ValueOrd};
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
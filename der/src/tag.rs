@@ -203,7 +203,7 @@ impl Tag {
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
             Tag::VisibleString => 0x1A,
-            Tag::BmpString => 0x1D,
+            Tag::BmpString => 0x1E,
             Tag::Application {
                 constructed,
                 number,
@@ -270,7 +270,7 @@ impl TryFrom<u8> for Tag {
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
             0x1A => Ok(Tag::VisibleString),
-            0x1d => Ok(Tag::BmpString),
+            0x1E => Ok(Tag::BmpString),
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
             0x40..=0x7E => Ok(Tag::Application {
@@ -389,6 +389,12 @@ mod tests {
     use super::TagNumber;
     use super::{Class, Tag};
 
+    #[test]
+    fn bmp_string() {
+        assert_eq!(u8::from(Tag::BmpString), 0x1E);
+        assert_eq!(Tag::try_from(0x1E), Ok(Tag::BmpString));
+    }
+
     #[test]
     fn tag_class() {
         assert_eq!(Tag::Boolean.class(), Class::Universal);
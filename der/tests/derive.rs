@@ -116,6 +116,18 @@ mod choice {
             }
         }
 
+        /// `Choice` with a type-level `IMPLICIT` tagging mode which also has
+        /// universally tagged variants.
+        #[derive(Choice, Debug, Eq, PartialEq)]
+        #[asn1(tag_mode = "IMPLICIT")]
+        pub enum MixedChoice<'a> {
+            #[asn1(type = "UTF8String")]
+            Utf8String(String),
+
+            #[asn1(context_specific = "0", type = "BIT STRING")]
+            BitString(BitString<'a>),
+        }
+
         const BITSTRING_DER: &'static [u8] = &hex!("80 04 00 01 02 03");
         const TIME_DER: &'static [u8] = &hex!("81 0f 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5a");
 
@@ -148,6 +160,18 @@ mod choice {
             cs_time.encode(&mut encoder).unwrap();
             assert_eq!(TIME_DER, encoder.finish().unwrap());
         }
+
+        #[test]
+        fn mixed() {
+            let utf8_der = hex!("0c 03 66 6f 6f");
+            let utf8_string = MixedChoice::from_der(&utf8_der).unwrap();
+            assert_eq!(utf8_string, MixedChoice::Utf8String("foo".to_owned()));
+            assert_eq!(utf8_string.to_vec().unwrap(), utf8_der);
+
+            let bit_string = MixedChoice::from_der(BITSTRING_DER).unwrap();
+            assert!(matches!(bit_string, MixedChoice::BitString(_)));
+            assert_eq!(bit_string.to_vec().unwrap(), BITSTRING_DER);
+        }
     }
 }
 
@@ -201,9 +225,11 @@ mod enumerated {
 /// Custom derive test cases for the `Sequence` macro.
 #[cfg(feature = "oid")]
 mod sequence {
+    use core::marker::PhantomData;
     use der::{
         asn1::{Any, ObjectIdentifier, SetOf},
-        Decode, Encode, Sequence, ValueOrd,
+        Decode, DecodeFields, Decoder, Encode, EncodeValue, FixedTag, Length, Sequence, Tag,
+        Tagged, ValueOrd, Writer,
     };
     use hex_literal::hex;
 
@@ -348,9 +374,124 @@ mod sequence {
         false
     }
 
+    /// Signed structure retaining the raw encodings of itself and its
+    /// signed portion.
+    #[derive(Debug, Eq, PartialEq, Sequence)]
+    pub struct SignedExample<'a> {
+        #[asn1(capture_raw = "true")]
+        pub der: &'a [u8],
+        #[asn1(capture_raw = "tbs")]
+        pub tbs_der: &'a [u8],
+        pub tbs: AlgorithmIdentifier<'a>,
+        #[asn1(type = "BIT STRING")]
+        pub signature: &'a [u8],
+    }
+
+    /// Structure with a field containing a DER-encoded `AlgorithmIdentifier`
+    /// wrapped in a `BIT STRING`.
+    #[derive(Debug, Eq, PartialEq, Sequence)]
+    pub struct WrappedExample<'a> {
+        pub version: u8,
+        #[asn1(decode_with = "decode_wrapped", encode_with = "encode_wrapped")]
+        pub wrapped: AlgorithmIdentifier<'a>,
+    }
+
+    /// `BIT STRING` containing a DER-encoded value.
+    pub struct WrappedBitString(Vec<u8>);
+
+    impl EncodeValue for WrappedBitString {
+        fn value_len(&self) -> der::Result<Length> {
+            Length::ONE + Length::try_from(self.0.len())?
+        }
+
+        fn encode_value(&self, writer: &mut dyn Writer) -> der::Result<()> {
+            writer.write_byte(0)?;
+            writer.write(&self.0)
+        }
+    }
+
+    impl FixedTag for WrappedBitString {
+        const TAG: Tag = Tag::BitString;
+    }
+
+    fn decode_wrapped<'a>(decoder: &mut Decoder<'a>) -> der::Result<AlgorithmIdentifier<'a>> {
+        AlgorithmIdentifier::from_der(decoder.bit_string()?.raw_bytes())
+    }
+
+    fn encode_wrapped(algorithm: &AlgorithmIdentifier<'_>) -> der::Result<WrappedBitString> {
+        algorithm.to_vec().map(WrappedBitString)
+    }
+
+    /// CMS-style `ContentInfo` which is generic over its content type.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
+    pub struct GenericContentInfo<T> {
+        pub content_type: ObjectIdentifier,
+        #[asn1(context_specific = "0")]
+        pub content: T,
+    }
+
+    /// Generic sequence which also borrows from the input.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct GenericBorrowed<'a, T>
+    where
+        T: Clone,
+    {
+        #[asn1(type = "OCTET STRING")]
+        pub label: &'a [u8],
+        pub value: T,
+    }
+
+    /// Sequence including the fields of another, i.e. `COMPONENTS OF`.
+    #[derive(Debug, Eq, PartialEq, Sequence, ValueOrd)]
+    pub struct FlattenExample<'a> {
+        #[asn1(flatten = "true")]
+        pub algorithm: AlgorithmIdentifier<'a>,
+        pub critical: bool,
+    }
+
+    /// Sequence with an invariant spanning multiple fields.
+    #[derive(Debug, Eq, PartialEq, Sequence)]
+    #[asn1(validate = "ValidatedExample::validate")]
+    pub struct ValidatedExample<'a> {
+        pub algorithm: ObjectIdentifier,
+        pub parameters: Option<Any<'a>>,
+    }
+
+    impl ValidatedExample<'_> {
+        /// `parameters` must be `NULL` for the `rsaEncryption` algorithm.
+        fn validate(&self) -> der::Result<()> {
+            if self.algorithm == RSA_ENCRYPTION_OID && self.parameters != Some(Any::NULL) {
+                return Err(Tag::Sequence.value_error());
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Extensible sequence which ignores unknown trailing fields.
+    #[derive(Debug, Eq, PartialEq, Sequence)]
+    #[asn1(extensible = "true")]
+    pub struct ExtensibleExample {
+        pub version: u8,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub flag: Option<bool>,
+    }
+
+    /// Extensible sequence which retains unknown trailing fields.
+    #[derive(Debug, Eq, PartialEq, Sequence, ValueOrd)]
+    #[asn1(extensible = "true")]
+    pub struct ExtensionAdditionsExample<'a> {
+        pub version: u8,
+        #[asn1(extension_additions = "true")]
+        pub additions: Vec<Any<'a>>,
+    }
+
     const ID_EC_PUBLIC_KEY_OID: ObjectIdentifier =
         ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
 
+    const RSA_ENCRYPTION_OID: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
     const PRIME256V1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
 
     const ALGORITHM_IDENTIFIER_DER: &[u8] =
@@ -378,6 +519,34 @@ mod sequence {
         pub typed_context_specific_optional: Option<&'a [u8]>,
     }
 
+    /// Sequence using a type-level `IMPLICIT` tagging mode, with one field
+    /// overriding it as `EXPLICIT`.
+    #[derive(Debug, Eq, PartialEq, Sequence)]
+    #[asn1(tag_mode = "IMPLICIT")]
+    pub struct ImplicitModuleExample {
+        pub version: u8,
+        #[asn1(context_specific = "0")]
+        pub implicit: bool,
+        #[asn1(context_specific = "1", tag_mode = "EXPLICIT")]
+        pub explicit: bool,
+    }
+
+    const IMPLICIT_MODULE_EXAMPLE_DER: &[u8] = &hex!("30 0b 02 01 01 80 01 ff a1 03 01 01 00");
+
+    #[test]
+    fn implicit_module_test() {
+        let example = ImplicitModuleExample::from_der(IMPLICIT_MODULE_EXAMPLE_DER).unwrap();
+        assert_eq!(
+            example,
+            ImplicitModuleExample {
+                version: 1,
+                implicit: true,
+                explicit: false,
+            }
+        );
+        assert_eq!(example.to_vec().unwrap(), IMPLICIT_MODULE_EXAMPLE_DER);
+    }
+
     #[test]
     fn idp_test() {
         let idp = IssuingDistributionPointExample::from_der(&hex!("30038101FF")).unwrap();
@@ -442,6 +611,146 @@ mod sequence {
         );
     }
 
+    #[test]
+    fn generic() {
+        let der = hex!(
+            "30 22 06 09 2a 86 48 86 f7 0d 01 07 01 a0 15"
+            "30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+        );
+
+        let content_info = GenericContentInfo::<AlgorithmIdentifier<'_>>::from_der(&der).unwrap();
+        assert_eq!(
+            content_info.content_type,
+            ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1")
+        );
+        assert_eq!(content_info.content.algorithm, ID_EC_PUBLIC_KEY_OID);
+        assert_eq!(content_info.to_vec().unwrap(), der);
+
+        let der = hex!("30 07 04 02 68 69 01 01 ff");
+        let borrowed = GenericBorrowed::<bool>::from_der(&der).unwrap();
+        assert_eq!(borrowed.label, b"hi");
+        assert!(borrowed.value);
+        assert_eq!(borrowed.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn decode_with_encode_with() {
+        let der = hex!(
+            "30 1b 02 01 02 03 16 00"
+            "30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+        );
+
+        let example = WrappedExample::from_der(&der).unwrap();
+        assert_eq!(example.version, 2);
+        assert_eq!(example.wrapped.algorithm, ID_EC_PUBLIC_KEY_OID);
+        assert_eq!(example.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn flatten() {
+        let der = hex!(
+            "30 16 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+            "01 01 ff"
+        );
+
+        let example = FlattenExample::from_der(&der).unwrap();
+        assert_eq!(example.algorithm.algorithm, ID_EC_PUBLIC_KEY_OID);
+        assert!(example.critical);
+        assert_eq!(example.to_vec().unwrap(), der);
+
+        // The flattened fields can't be nested
+        let nested = hex!(
+            "30 18 30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+            "01 01 ff"
+        );
+        assert!(FlattenExample::from_der(&nested).is_err());
+    }
+
+    /// Does `T` impl `DecodeFields`, i.e. can it be flattened?
+    ///
+    /// The inherent const shadows the trait's default only when `T` satisfies
+    /// its bound.
+    struct Flattenable<T>(PhantomData<T>);
+
+    trait NotFlattenable {
+        const IMPLS: bool = false;
+    }
+
+    impl<T> NotFlattenable for Flattenable<T> {}
+
+    impl<'a, T: DecodeFields<'a>> Flattenable<T> {
+        const IMPLS: bool = true;
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn flatten_extensible() {
+        assert!(Flattenable::<AlgorithmIdentifier<'_>>::IMPLS);
+
+        // Extensible sequences would consume the trailing fields of the
+        // sequence they're flattened into
+        assert!(!Flattenable::<ExtensibleExample>::IMPLS);
+        assert!(!Flattenable::<ExtensionAdditionsExample<'_>>::IMPLS);
+    }
+
+    #[test]
+    fn validate() {
+        let der = hex!("30 0d 06 09 2a 86 48 86 f7 0d 01 01 01 05 00");
+        let example = ValidatedExample::from_der(&der).unwrap();
+        assert_eq!(example.algorithm, RSA_ENCRYPTION_OID);
+        assert_eq!(example.parameters, Some(Any::NULL));
+
+        let err = ValidatedExample::from_der(&hex!("30 0b 06 09 2a 86 48 86 f7 0d 01 01 01"))
+            .unwrap_err();
+        assert_eq!(err.kind(), Tag::Sequence.value_error().kind());
+
+        // Other algorithms are unaffected
+        assert!(ValidatedExample::from_der(ALGORITHM_IDENTIFIER_DER).is_ok());
+    }
+
+    #[test]
+    fn extensible() {
+        let der = hex!("30 0b 02 01 02 a0 03 01 01 ff 04 01 ab");
+        let example = ExtensibleExample::from_der(&der).unwrap();
+        assert_eq!(example.version, 2);
+        assert_eq!(example.flag, Some(true));
+
+        let example = ExtensionAdditionsExample::from_der(&der).unwrap();
+        assert_eq!(example.version, 2);
+        assert_eq!(example.additions.len(), 2);
+        assert_eq!(example.additions[1].tag(), Tag::OctetString);
+        assert_eq!(example.additions[1].value(), &[0xab]);
+        assert_eq!(example.to_vec().unwrap(), der);
+
+        let der = hex!("30 03 02 01 02");
+        let example = ExtensionAdditionsExample::from_der(&der).unwrap();
+        assert!(example.additions.is_empty());
+        assert_eq!(example.to_vec().unwrap(), der);
+
+        // Trailing fields are still an error for non-extensible sequences
+        assert!(
+            AlgorithmIdentifier::from_der(&hex!("30 0b 06 03 2a 86 48 05 00 04 01 ab")).is_err()
+        );
+    }
+
+    #[test]
+    fn capture_raw() {
+        let der = hex!(
+            "30 1a"
+            "30 13 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07"
+            "03 03 00 ab cd"
+        );
+
+        let signed = SignedExample::from_der(&der).unwrap();
+        assert_eq!(signed.der, &der);
+        assert_eq!(signed.tbs_der, ALGORITHM_IDENTIFIER_DER);
+        assert_eq!(signed.tbs.algorithm, ID_EC_PUBLIC_KEY_OID);
+        assert_eq!(signed.signature, &[0xab, 0xcd]);
+
+        // Captured fields are omitted from the encoding
+        assert_eq!(signed.to_vec().unwrap(), der);
+    }
+
     #[test]
     fn encode() {
         let parameters_oid = PRIME256V1_OID;
@@ -458,6 +767,139 @@ mod sequence {
     }
 }
 
+/// Custom derive test cases for the `Set` macro.
+#[cfg(feature = "oid")]
+mod set {
+    use der::{
+        asn1::{Ia5String, ObjectIdentifier, PrintableString, Utf8String},
+        Choice, Decode, Encode, ErrorKind, Set, Tag,
+    };
+    use hex_literal::hex;
+
+    /// `SET` with members of distinct types.
+    #[derive(Debug, Eq, PartialEq, Set)]
+    #[asn1(tag_mode = "IMPLICIT")]
+    pub struct ExampleSet<'a> {
+        #[asn1(type = "OCTET STRING")]
+        pub value: &'a [u8],
+        pub oid: ObjectIdentifier,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub name: Option<Utf8String<'a>>,
+        #[asn1(optional = "true")]
+        pub flag: Option<bool>,
+    }
+
+    /// Extensible `SET` which ignores unknown members.
+    #[derive(Debug, Eq, PartialEq, Set)]
+    #[asn1(extensible = "true")]
+    pub struct ExtensibleSet {
+        pub oid: ObjectIdentifier,
+        #[asn1(optional = "true")]
+        pub flag: Option<bool>,
+    }
+
+    /// `CHOICE` of strings whose smallest tag is `UTF8String`.
+    #[derive(Choice, Debug, Eq, PartialEq)]
+    pub enum Text<'a> {
+        #[asn1(type = "UTF8String")]
+        Utf8(Utf8String<'a>),
+
+        #[asn1(type = "IA5String")]
+        Ia5(Ia5String<'a>),
+    }
+
+    /// `SET` with an untagged `CHOICE` member.
+    #[derive(Debug, Eq, PartialEq, Set)]
+    pub struct ChoiceSet<'a> {
+        pub label: PrintableString<'a>,
+        pub text: Text<'a>,
+    }
+
+    const EXAMPLE_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840");
+    const EXAMPLE_SET_DER: &[u8] = &hex!("31 0f 01 01 ff 04 01 ab 06 03 2a 86 48 80 02 68 69");
+
+    #[test]
+    fn decode() {
+        let set = ExampleSet::from_der(EXAMPLE_SET_DER).unwrap();
+        assert_eq!(set.value, &[0xab]);
+        assert_eq!(set.oid, EXAMPLE_OID);
+        assert_eq!(set.name.unwrap().as_str(), "hi");
+        assert_eq!(set.flag, Some(true));
+
+        // Members can occur in any order
+        let unordered = hex!("31 0f 80 02 68 69 06 03 2a 86 48 04 01 ab 01 01 ff");
+        assert_eq!(ExampleSet::from_der(&unordered).unwrap(), set);
+
+        let set = ExampleSet::from_der(&hex!("31 08 04 01 ab 06 03 2a 86 48")).unwrap();
+        assert_eq!(set.name, None);
+        assert_eq!(set.flag, None);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        // Missing required member
+        let err = ExampleSet::from_der(&hex!("31 03 04 01 ab")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetMissing);
+
+        // Duplicate member
+        assert!(ExampleSet::from_der(&hex!("31 0b 04 01 ab 06 03 2a 86 48 04 01 ab")).is_err());
+    }
+
+    #[test]
+    fn extensible() {
+        let set = ExtensibleSet::from_der(&hex!("31 0a 04 01 ab 06 03 2a 86 48 05 00")).unwrap();
+        assert_eq!(set.oid, EXAMPLE_OID);
+        assert_eq!(set.flag, None);
+
+        // Unknown members are an error for non-extensible sets
+        assert!(ExampleSet::from_der(&hex!("31 0a 04 01 ab 06 03 2a 86 48 05 00")).is_err());
+
+        // Duplicate known members are an error even for extensible sets
+        let err = ExtensibleSet::from_der(&hex!("31 0b 06 03 2a 86 48 06 03 2a 86 48 05 00"))
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagUnexpected {
+                expected: None,
+                actual: Tag::ObjectIdentifier
+            }
+        );
+        assert_eq!(err.position(), Some(5u8.into()));
+    }
+
+    #[test]
+    fn encode() {
+        let set = ExampleSet {
+            value: &[0xab],
+            oid: EXAMPLE_OID,
+            name: Some(Utf8String::new("hi").unwrap()),
+            flag: Some(true),
+        };
+
+        // Members are sorted by tag
+        assert_eq!(set.to_vec().unwrap(), EXAMPLE_SET_DER);
+    }
+
+    #[test]
+    fn encode_choice() {
+        // An untagged `CHOICE` is sorted by the smallest tag of its
+        // alternatives (UTF8String) rather than the tag of its value
+        let set = ChoiceSet {
+            label: PrintableString::new("a").unwrap(),
+            text: Text::Ia5(Ia5String::new("b").unwrap()),
+        };
+        let der = set.to_vec().unwrap();
+        assert_eq!(der, hex!("31 06 16 01 62 13 01 61"));
+        assert_eq!(ChoiceSet::from_der(&der).unwrap(), set);
+
+        let set = ChoiceSet {
+            label: PrintableString::new("a").unwrap(),
+            text: Text::Utf8(Utf8String::new("b").unwrap()),
+        };
+        assert_eq!(set.to_vec().unwrap(), hex!("31 06 0c 01 62 13 01 61"));
+    }
+}
+
 mod newtype {
     use der::{asn1::BitString, Decode, Encode};
     use der_derive::Newtype;
@@ -11,9 +11,10 @@
 //! [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
 
 use crate::{
-    grammar, Base64Decoder, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
-    PRE_ENCAPSULATION_BOUNDARY,
+    grammar, Base64Decoder, Error, Headers, Result, ENCAPSULATION_BOUNDARY_DELIMITER,
+    MIN_LINE_WIDTH, POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
 };
+use base64ct::{Base64, Encoding};
 use core::str;
 
 #[cfg(feature = "alloc")]
@@ -56,6 +57,81 @@ pub fn decode_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
     Ok((type_label, buf))
 }
 
+/// Decode a PEM document according to RFC 7468's "Strict" grammar, writing
+/// the decoded document over the beginning of the input buffer.
+///
+/// The decoded data is always shorter than its encoding, so this avoids the
+/// need for a second buffer in constrained environments. On success, returns
+/// the decoded label (borrowed from the post-encapsulation boundary, which
+/// is left intact) and the portion of the buffer containing the decoded
+/// message. The rest of the buffer is left in an unspecified state, both on
+/// success and on failure.
+pub fn decode_in_place(pem: &mut [u8]) -> Result<(&str, &[u8])> {
+    let (text_start, text_end, label_len) = {
+        let encapsulation = Encapsulation::try_from(&*pem)?;
+        let text = encapsulation.encapsulated_text;
+
+        // Headers would fail to decode as Base64 anyway, but check for them
+        // up front as the decoded output may overwrite them
+        if text.contains(&grammar::CHAR_COLON) {
+            return Err(Error::HeaderDisallowed);
+        }
+
+        let text_start = (text.as_ptr() as usize)
+            .checked_sub(pem.as_ptr() as usize)
+            .ok_or(Error::Length)?;
+        let text_end = text_start.checked_add(text.len()).ok_or(Error::Length)?;
+        (text_start, text_end, encapsulation.label().len())
+    };
+
+    let (body, boundary) = pem.split_at_mut(text_end);
+    let decoded_len = decode_text_in_place(body, text_start)?;
+
+    // The label was validated when parsing the encapsulation
+    let boundary = grammar::strip_leading_eol(boundary)
+        .and_then(|rest| rest.strip_prefix(POST_ENCAPSULATION_BOUNDARY))
+        .and_then(|rest| rest.get(..label_len))
+        .ok_or(Error::PostEncapsulationBoundary)?;
+
+    let label = str::from_utf8(boundary)?;
+    Ok((label, &body[..decoded_len]))
+}
+
+/// Decode a PEM document using a "lax" grammar which tolerates data that
+/// RFC 7468's "Strict" grammar rejects.
+///
+/// Specifically, this permits:
+/// - a UTF-8 byte order mark at the beginning of the input
+/// - arbitrary text before the pre-encapsulation boundary, including on the
+///   same line, and after the post-encapsulation boundary
+/// - whitespace at the end of the encapsulation boundary lines, and anywhere
+///   in the encapsulated text (including blank lines and any line width)
+///
+/// [`decode`] should be preferred unless inputs are known to be produced by
+/// non-conforming generators.
+pub fn decode_lax<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o [u8])> {
+    let encapsulation = Encapsulation::parse_lax(pem)?;
+    let len = decode_lax_text(encapsulation.encapsulated_text, buf)?;
+    Ok((encapsulation.label(), &buf[..len]))
+}
+
+/// Decode a PEM document using a "lax" grammar, returning the result as a
+/// [`Vec`] upon success.
+///
+/// See [`decode_lax`] for the differences from the "Strict" grammar.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_lax_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
+    let encapsulation = Encapsulation::parse_lax(pem)?;
+    let text = encapsulation.encapsulated_text;
+
+    // Upper bound on the decoded length, as whitespace is ignored
+    let mut buf = vec![0u8; (text.len() >> 2).saturating_mul(3)];
+    let len = decode_lax_text(text, &mut buf)?;
+    buf.truncate(len);
+    Ok((encapsulation.label(), buf))
+}
+
 /// Decode the encapsulation boundaries of a PEM document according to RFC 7468's "Strict" grammar.
 ///
 /// On success, returning the decoded label.
@@ -67,11 +143,17 @@ pub fn decode_label(pem: &[u8]) -> Result<&str> {
 ///
 /// Stateful buffered decoder type which decodes an input PEM document according
 /// to RFC 7468's "Strict" grammar.
+///
+/// Lines can be terminated by any of CRLF, CR, or LF, which needn't be used
+/// consistently throughout the document.
 #[derive(Clone)]
 pub struct Decoder<'i> {
     /// PEM type label.
     type_label: &'i str,
 
+    /// RFC 1421 encapsulated headers (if requested).
+    headers: Headers<'i>,
+
     /// Buffered Base64 decoder.
     base64: Base64Decoder<'i>,
 }
@@ -79,17 +161,48 @@ pub struct Decoder<'i> {
 impl<'i> Decoder<'i> {
     /// Create a new PEM [`Decoder`] with the default options.
     ///
-    /// Uses the default 64-character line wrapping.
+    /// The line width of the Base64 body is taken from its first line, so
+    /// documents wrapped at widths other than the standard 64 characters
+    /// (or not wrapped at all) are accepted, so long as every line except
+    /// the last has the same width.
     pub fn new(pem: &'i [u8]) -> Result<Self> {
-        Self::new_wrapped(pem, BASE64_WRAP_WIDTH)
+        let encapsulation = Encapsulation::try_from(pem)?;
+        let line_width = encapsulation.line_width();
+        Self::from_encapsulation(encapsulation, line_width)
     }
 
-    /// Create a new PEM [`Decoder`] which wraps at the given line width.
+    /// Create a new PEM [`Decoder`] which requires the Base64 body be
+    /// wrapped at the given line width.
     pub fn new_wrapped(pem: &'i [u8], line_width: usize) -> Result<Self> {
-        let encapsulation = Encapsulation::try_from(pem)?;
+        Self::from_encapsulation(Encapsulation::try_from(pem)?, line_width)
+    }
+
+    /// Create a new PEM [`Decoder`] which accepts RFC 1421 encapsulated
+    /// headers, e.g. the `Proc-Type` and `DEK-Info` headers of legacy
+    /// OpenSSL encrypted private keys.
+    ///
+    /// Such headers are disallowed by RFC 7468. Once parsed, they can be
+    /// accessed using [`Decoder::headers`].
+    pub fn new_with_headers(pem: &'i [u8]) -> Result<Self> {
+        let mut encapsulation = Encapsulation::try_from(pem)?;
+        let (headers, text) = Headers::split(encapsulation.encapsulated_text)?;
+        encapsulation.encapsulated_text = text;
+
+        let line_width = encapsulation.line_width();
+        let mut decoder = Self::from_encapsulation(encapsulation, line_width)?;
+        decoder.headers = headers;
+        Ok(decoder)
+    }
+
+    /// Create a new PEM [`Decoder`] for an already parsed [`Encapsulation`].
+    fn from_encapsulation(encapsulation: Encapsulation<'i>, line_width: usize) -> Result<Self> {
         let type_label = encapsulation.label();
         let base64 = Base64Decoder::new_wrapped(encapsulation.encapsulated_text, line_width)?;
-        Ok(Self { type_label, base64 })
+        Ok(Self {
+            type_label,
+            headers: Headers::default(),
+            base64,
+        })
     }
 
     /// Get the PEM type label for the input document.
@@ -97,6 +210,14 @@ impl<'i> Decoder<'i> {
         self.type_label
     }
 
+    /// Get the RFC 1421 encapsulated headers for the input document.
+    ///
+    /// These are always empty unless the decoder was created using
+    /// [`Decoder::new_with_headers`].
+    pub fn headers(&self) -> Headers<'i> {
+        self.headers
+    }
+
     /// Decode data into the provided output buffer.
     ///
     /// There must be at least as much remaining Base64 input to be decoded
@@ -145,6 +266,53 @@ impl<'i> io::Read for Decoder<'i> {
     }
 }
 
+/// Iterator over the documents contained in an input with several
+/// concatenated PEM documents, e.g. a bundle of CA certificates.
+///
+/// Yields a [`Decoder`] for each document in turn. Text occurring between
+/// documents is skipped as if it were the preamble of the next document.
+/// Iteration ends when no further pre-encapsulation boundaries are found.
+#[derive(Clone, Debug)]
+pub struct Documents<'i> {
+    /// Remaining input which hasn't yet been parsed.
+    remaining: &'i [u8],
+}
+
+impl<'i> Documents<'i> {
+    /// Create a new iterator over the PEM documents in the given input.
+    pub fn new(pem: &'i [u8]) -> Self {
+        Self { remaining: pem }
+    }
+}
+
+impl<'i> Iterator for Documents<'i> {
+    type Item = Result<Decoder<'i>>;
+
+    fn next(&mut self) -> Option<Result<Decoder<'i>>> {
+        if !self
+            .remaining
+            .windows(PRE_ENCAPSULATION_BOUNDARY.len())
+            .any(|window| window == PRE_ENCAPSULATION_BOUNDARY)
+        {
+            self.remaining = &[];
+            return None;
+        }
+
+        match Encapsulation::parse_next(self.remaining) {
+            Ok((encapsulation, remaining)) => {
+                self.remaining = remaining;
+                let line_width = encapsulation.line_width();
+                Some(Decoder::from_encapsulation(encapsulation, line_width))
+            }
+            Err(err) => {
+                // Stop at the first malformed document
+                self.remaining = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
 /// PEM encapsulation parser.
 ///
 /// This parser performs an initial pass over the data, locating the
@@ -153,9 +321,9 @@ impl<'i> io::Read for Decoder<'i> {
 /// on the potentially secret Base64-encoded data encapsulated between
 /// the two boundaries.
 ///
-/// It only supports a single encapsulated message at present. Future work
-/// could potentially include extending it provide an iterator over a series
-/// of encapsulated messages.
+/// [`Encapsulation::parse`] requires the input contain a single encapsulated
+/// message, whereas [`Encapsulation::parse_next`] can be used to parse a
+/// series of them.
 #[derive(Copy, Clone, Debug)]
 struct Encapsulation<'a> {
     /// Type label extracted from the pre/post-encapsulation boundaries.
@@ -223,10 +391,114 @@ impl<'a> Encapsulation<'a> {
         })
     }
 
+    /// Parse the first encapsulated message in the given data, returning it
+    /// along with the remaining data following its post-encapsulation
+    /// boundary (and line ending, if present).
+    pub fn parse_next(data: &'a [u8]) -> Result<(Self, &'a [u8])> {
+        // Strip the "preamble": optional text occurring before the pre-encapsulation boundary
+        let data = grammar::strip_preamble(data)?;
+
+        // Parse pre-encapsulation boundary (including label)
+        let data = data
+            .strip_prefix(PRE_ENCAPSULATION_BOUNDARY)
+            .ok_or(Error::PreEncapsulationBoundary)?;
+
+        let (label, body) = grammar::split_label(data).ok_or(Error::Label)?;
+
+        // Locate the post-encapsulation boundary. The Base64 alphabet doesn't
+        // include hyphen-minus, so this search never matches the (potentially
+        // secret) encapsulated text itself.
+        let boundary_pos = body
+            .windows(POST_ENCAPSULATION_BOUNDARY.len())
+            .position(|window| window == POST_ENCAPSULATION_BOUNDARY)
+            .ok_or(Error::PostEncapsulationBoundary)?;
+
+        let (text, boundary) = body.split_at(boundary_pos);
+
+        // Ensure the post-encapsulation boundary has a matching label
+        let remaining = boundary
+            .strip_prefix(POST_ENCAPSULATION_BOUNDARY)
+            .and_then(|rest| rest.strip_prefix(label.as_bytes()))
+            .and_then(|rest| rest.strip_prefix(ENCAPSULATION_BOUNDARY_DELIMITER))
+            .ok_or(Error::PostEncapsulationBoundary)?;
+
+        let remaining = grammar::strip_leading_eol(remaining).unwrap_or(remaining);
+
+        let encapsulated_text =
+            grammar::strip_trailing_eol(text).ok_or(Error::PostEncapsulationBoundary)?;
+
+        Ok((
+            Self {
+                label,
+                encapsulated_text,
+            },
+            remaining,
+        ))
+    }
+
+    /// Parse the type label and encapsulated text using a "lax" grammar.
+    ///
+    /// See [`decode_lax`] for the differences from the "Strict" grammar.
+    pub fn parse_lax(data: &'a [u8]) -> Result<Self> {
+        let data = data.strip_prefix(grammar::UTF8_BOM).unwrap_or(data);
+
+        // Skip everything up to the pre-encapsulation boundary
+        let boundary_pos = data
+            .windows(PRE_ENCAPSULATION_BOUNDARY.len())
+            .position(|window| window == PRE_ENCAPSULATION_BOUNDARY)
+            .ok_or(Error::PreEncapsulationBoundary)?;
+
+        let data = data
+            .get(boundary_pos..)
+            .and_then(|data| data.strip_prefix(PRE_ENCAPSULATION_BOUNDARY))
+            .ok_or(Error::PreEncapsulationBoundary)?;
+
+        let (line, body) = grammar::split_line(data).ok_or(Error::PreEncapsulationBoundary)?;
+
+        let label = grammar::strip_trailing_wsp(line)
+            .strip_suffix(ENCAPSULATION_BOUNDARY_DELIMITER)
+            .ok_or(Error::PreEncapsulationBoundary)?;
+
+        grammar::validate_label(label)?;
+        let label = str::from_utf8(label)?;
+
+        // Locate the post-encapsulation boundary, ignoring anything after it
+        let boundary_pos = body
+            .windows(POST_ENCAPSULATION_BOUNDARY.len())
+            .position(|window| window == POST_ENCAPSULATION_BOUNDARY)
+            .ok_or(Error::PostEncapsulationBoundary)?;
+
+        let (encapsulated_text, boundary) = body.split_at(boundary_pos);
+
+        boundary
+            .strip_prefix(POST_ENCAPSULATION_BOUNDARY)
+            .and_then(|rest| rest.strip_prefix(label.as_bytes()))
+            .and_then(|rest| rest.strip_prefix(ENCAPSULATION_BOUNDARY_DELIMITER))
+            .ok_or(Error::PostEncapsulationBoundary)?;
+
+        Ok(Self {
+            label,
+            encapsulated_text,
+        })
+    }
+
     /// Get the label parsed from the encapsulation boundaries.
     pub fn label(self) -> &'a str {
         self.label
     }
+
+    /// Get the width the encapsulated text is wrapped at, i.e. the length of
+    /// its first line.
+    ///
+    /// Line endings aren't part of the Base64 alphabet, so locating them
+    /// doesn't branch on the (potentially secret) encoded data.
+    pub fn line_width(self) -> usize {
+        self.encapsulated_text
+            .iter()
+            .position(|&char| char == grammar::CHAR_CR || char == grammar::CHAR_LF)
+            .unwrap_or(self.encapsulated_text.len())
+            .max(MIN_LINE_WIDTH)
+    }
 }
 
 impl<'a> TryFrom<&'a [u8]> for Encapsulation<'a> {
@@ -237,6 +509,117 @@ impl<'a> TryFrom<&'a [u8]> for Encapsulation<'a> {
     }
 }
 
+/// Decode encapsulated text ignoring any whitespace, returning the length
+/// of the decoded data written to `buf`.
+///
+/// The Base64 alphabet doesn't include any whitespace characters, so
+/// skipping them doesn't branch on the (potentially secret) encoded data.
+fn decode_lax_text(text: &[u8], buf: &mut [u8]) -> Result<usize> {
+    let mut quad = [0u8; 4];
+    let mut quad_len = 0usize;
+    let mut decoded_len = 0usize;
+    let mut padded = false;
+
+    for &char in text {
+        if grammar::is_wsp(char) || char == grammar::CHAR_CR || char == grammar::CHAR_LF {
+            continue;
+        }
+
+        // Padding can only occur at the end of the data
+        if padded {
+            return Err(Error::EncapsulatedText);
+        }
+
+        *quad.get_mut(quad_len).ok_or(Error::EncapsulatedText)? = char;
+        quad_len = quad_len.checked_add(1).ok_or(Error::Length)?;
+
+        if quad_len == quad.len() {
+            let out = buf.get_mut(decoded_len..).ok_or(Error::Length)?;
+            let len = Base64::decode(quad, out)?.len();
+            decoded_len = decoded_len.checked_add(len).ok_or(Error::Length)?;
+            padded = quad.ends_with(b"=");
+            quad_len = 0;
+        }
+    }
+
+    if quad_len == 0 {
+        Ok(decoded_len)
+    } else {
+        Err(Error::Base64(base64ct::Error::InvalidEncoding))
+    }
+}
+
+/// Decode the encapsulated text in `buf` beginning at `start` and extending
+/// to the end of `buf`, writing the decoded data to the beginning of `buf`
+/// and returning its length.
+///
+/// Each 4-character group is copied out of `buf` before the (shorter)
+/// decoded data is written, which always lands before the group's position
+/// in the encapsulated text, so no unread input is ever overwritten.
+///
+/// All lines except the last must have the same width, and the last line
+/// must be no longer than the others.
+fn decode_text_in_place(buf: &mut [u8], start: usize) -> Result<usize> {
+    let mut quad = [0u8; 4];
+    let mut quad_len = 0usize;
+    let mut decoded_len = 0usize;
+    let mut padded = false;
+    let mut line_width = None;
+    let mut line_len = 0usize;
+    let mut line_short = false;
+    let mut pos = start;
+
+    while let Some(&char) = buf.get(pos) {
+        pos = pos.checked_add(1).ok_or(Error::Length)?;
+
+        // The Base64 alphabet doesn't include line endings, so this doesn't
+        // branch on the (potentially secret) encoded data
+        if char == grammar::CHAR_CR || char == grammar::CHAR_LF {
+            if char == grammar::CHAR_CR && buf.get(pos) == Some(&grammar::CHAR_LF) {
+                pos = pos.checked_add(1).ok_or(Error::Length)?;
+            }
+
+            // Only the last line may be shorter than the others
+            match line_width {
+                None => line_width = Some(line_len),
+                Some(width) if line_short || line_len > width => {
+                    return Err(Error::Base64(base64ct::Error::InvalidEncoding))
+                }
+                Some(width) => line_short = line_len < width,
+            }
+
+            line_len = 0;
+            continue;
+        }
+
+        if line_short || padded {
+            return Err(Error::Base64(base64ct::Error::InvalidEncoding));
+        }
+
+        line_len = line_len.checked_add(1).ok_or(Error::Length)?;
+        *quad.get_mut(quad_len).ok_or(Error::Length)? = char;
+        quad_len = quad_len.checked_add(1).ok_or(Error::Length)?;
+
+        if quad_len == quad.len() {
+            let out = buf.get_mut(decoded_len..).ok_or(Error::Length)?;
+            let len = Base64::decode(quad, out)?.len();
+            decoded_len = decoded_len.checked_add(len).ok_or(Error::Length)?;
+            padded = quad.ends_with(b"=");
+            quad_len = 0;
+        }
+    }
+
+    if line_width.map_or(false, |width| line_len > width) {
+        return Err(Error::Base64(base64ct::Error::InvalidEncoding));
+    }
+
+    if quad_len == 0 && decoded_len != 0 {
+        Ok(decoded_len)
+    } else {
+        Err(Error::Base64(base64ct::Error::InvalidEncoding))
+    }
+}
+
 /// Check for PEM headers in the input, as they are disallowed by RFC7468.
 ///
 /// Returns `Error::HeaderDisallowed` if headers are encountered.
@@ -254,6 +637,23 @@ fn check_for_headers(pem: &[u8], err: Error) -> Error {
 mod tests {
     use super::Encapsulation;
 
+    #[test]
+    fn parse_next() {
+        let pem = b"-----BEGIN A-----\nQUJD\n-----END A-----\n\
+            between\n\
+            -----BEGIN B-----\nREVG\n-----END B-----\n";
+
+        let (first, rest) = Encapsulation::parse_next(pem).unwrap();
+        assert_eq!(first.label, "A");
+        assert_eq!(first.encapsulated_text, b"QUJD");
+        assert!(rest.starts_with(b"between\n-----BEGIN B"));
+
+        let (second, rest) = Encapsulation::parse_next(rest).unwrap();
+        assert_eq!(second.label, "B");
+        assert_eq!(second.encapsulated_text, b"REVG");
+        assert!(rest.is_empty());
+    }
+
     #[test]
     fn pkcs8_example() {
         let pem = include_bytes!("../tests/examples/pkcs8.pem");
@@ -1,8 +1,9 @@
 //! PEM encoder.
 
 use crate::{
-    grammar, Base64Encoder, Error, LineEnding, Result, BASE64_WRAP_WIDTH,
-    ENCAPSULATION_BOUNDARY_DELIMITER, POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
+    grammar, headers, Base64Encoder, Error, LineEnding, Result, BASE64_WRAP_WIDTH,
+    ENCAPSULATION_BOUNDARY_DELIMITER, MIN_LINE_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    PRE_ENCAPSULATION_BOUNDARY,
 };
 use base64ct::{Base64, Encoding};
 use core::str;
@@ -54,9 +55,7 @@ pub fn encapsulated_len_wrapped(
     line_ending: LineEnding,
     input_len: usize,
 ) -> Result<usize> {
-    if line_width < 4 {
-        return Err(Error::Length);
-    }
+    validate_line_width(line_width)?;
 
     let base64_len = input_len
         .checked_mul(4)
@@ -69,6 +68,64 @@ pub fn encapsulated_len_wrapped(
     encapsulated_len_inner(label, line_ending, base64_len_wrapped)
 }
 
+/// Compute the exact length of a PEM encoded document with the given label
+/// which encapsulates `input_len` bytes, with the Base64 body wrapped at
+/// the standard 64 characters.
+///
+/// Unlike [`encoded_len`], this is a `const fn` which only depends on the
+/// length of the input, so it can be used to size fixed-length output
+/// buffers at compile time:
+///
+/// ```
+/// use pem_rfc7468::{encoded_size, LineEnding};
+///
+/// const LABEL: &str = "PRIVATE KEY";
+/// const DER_LEN: usize = 48;
+///
+/// let der = [0u8; DER_LEN];
+/// let mut buf = [0u8; encoded_size(LABEL, LineEnding::LF, DER_LEN)];
+/// let pem = pem_rfc7468::encode(LABEL, LineEnding::LF, &der, &mut buf)?;
+/// assert_eq!(pem.len(), buf.len());
+/// # Ok::<(), pem_rfc7468::Error>(())
+/// ```
+///
+/// The label isn't validated, and on overflow `usize::MAX` is returned
+/// (which will fail to allocate as an array length at compile time).
+pub const fn encoded_size(label: &str, line_ending: LineEnding, input_len: usize) -> usize {
+    encoded_size_wrapped(label, BASE64_WRAP_WIDTH, line_ending, input_len)
+}
+
+/// Compute the exact length of a PEM encoded document with the Base64 body
+/// line wrapped at the specified `width`.
+///
+/// See [`encoded_size`]. Widths shorter than 4 characters are invalid and
+/// result in `usize::MAX`.
+// Divisions are by nonzero values and all other arithmetic saturates
+#[allow(clippy::integer_arithmetic)]
+pub const fn encoded_size_wrapped(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input_len: usize,
+) -> usize {
+    if line_width < MIN_LINE_WIDTH {
+        return usize::MAX;
+    }
+
+    let base64_len = (input_len / 3 + (input_len % 3 != 0) as usize).saturating_mul(4);
+    let line_endings =
+        (base64_len.saturating_sub(1) / line_width).saturating_mul(line_ending.len());
+
+    PRE_ENCAPSULATION_BOUNDARY
+        .len()
+        .saturating_add(POST_ENCAPSULATION_BOUNDARY.len())
+        .saturating_add(ENCAPSULATION_BOUNDARY_DELIMITER.len() * 2)
+        .saturating_add(label.len().saturating_mul(2))
+        .saturating_add(line_ending.len() * 3)
+        .saturating_add(base64_len)
+        .saturating_add(line_endings)
+}
+
 /// Get the length of a PEM encoded document with the given bytes and label.
 ///
 /// This function computes a precise length of the PEM encoding of the given
@@ -78,23 +135,110 @@ pub fn encapsulated_len_wrapped(
 /// - `Ok(len)` on success
 /// - `Err(Error::Length)` on length overflow
 pub fn encoded_len(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<usize> {
-    let base64_len = Base64::encoded_len(input);
-    let base64_len_wrapped = base64_len_wrapped(base64_len, BASE64_WRAP_WIDTH, line_ending)?;
-    encapsulated_len_inner(label, line_ending, base64_len_wrapped)
+    encoded_len_with_headers(label, &[], line_ending, input)
+}
+
+/// Get the length of a PEM encoded document with the given bytes, label, and
+/// RFC 1421 encapsulated headers.
+///
+/// See [`encode_with_headers`].
+pub fn encoded_len_with_headers(
+    label: &str,
+    headers: &[(&str, &str)],
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<usize> {
+    encoded_len_inner(label, headers, BASE64_WRAP_WIDTH, line_ending, input)
+}
+
+/// Get the length of a PEM encoded document with the Base64 body line
+/// wrapped at the specified `width`.
+///
+/// See [`encode_wrapped`].
+pub fn encoded_len_wrapped(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<usize> {
+    encoded_len_inner(label, &[], line_width, line_ending, input)
 }
 
 /// Encode a PEM document according to RFC 7468's "Strict" grammar.
+///
+/// All lines, including the encapsulation boundaries, are terminated by
+/// the given `line_ending`.
 pub fn encode<'o>(
     type_label: &str,
     line_ending: LineEnding,
     input: &[u8],
     buf: &'o mut [u8],
 ) -> Result<&'o str> {
-    let mut encoder = Encoder::new(type_label, line_ending, buf)?;
+    encode_with_headers(type_label, &[], line_ending, input, buf)
+}
+
+/// Encode a PEM document including the given RFC 1421 encapsulated headers,
+/// which are provided as `(name, value)` pairs.
+///
+/// Headers are disallowed by RFC 7468, but are used by legacy formats such
+/// as OpenSSL's "traditional" encrypted private keys. When `headers` is
+/// empty, this is identical to [`encode`].
+pub fn encode_with_headers<'o>(
+    type_label: &str,
+    headers: &[(&str, &str)],
+    line_ending: LineEnding,
+    input: &[u8],
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    encode_inner(
+        type_label,
+        headers,
+        BASE64_WRAP_WIDTH,
+        line_ending,
+        input,
+        buf,
+    )
+}
+
+/// Encode a PEM document with the Base64 body line wrapped at the specified
+/// `width`, e.g. 76 characters for MIME contexts.
+///
+/// Use [`BASE64_UNWRAPPED`][`crate::BASE64_UNWRAPPED`] to emit the body as
+/// a single line. Widths shorter than 4 characters are rejected with
+/// [`Error::Length`].
+///
+/// Note that per [RFC7468 § 2] encoding PEM with any other wrap width besides
+/// 64 is technically non-compliant, so [`encode`] should be preferred unless
+/// the consumer of the output requires otherwise.
+///
+/// [RFC7468 § 2]: https://datatracker.ietf.org/doc/html/rfc7468#section-2
+pub fn encode_wrapped<'o>(
+    type_label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    encode_inner(type_label, &[], line_width, line_ending, input, buf)
+}
+
+/// Encode a PEM document with the given headers and line width.
+fn encode_inner<'o>(
+    type_label: &str,
+    headers: &[(&str, &str)],
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    let mut encoder = Encoder::new_inner(type_label, headers, line_width, line_ending, buf)?;
     encoder.encode(input)?;
     let encoded_len = encoder.finish()?;
-    let output = &buf[..encoded_len];
+    output_str(&buf[..encoded_len])
+}
 
+/// Convert the output of an [`Encoder`] to a string.
+pub(crate) fn output_str(output: &[u8]) -> Result<&str> {
     // Sanity check
     debug_assert!(str::from_utf8(output).is_ok());
 
@@ -121,13 +265,64 @@ pub fn encode<'o>(
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn encode_string(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<String> {
-    let expected_len = encoded_len(label, line_ending, input)?;
+    encode_string_with_headers(label, &[], line_ending, input)
+}
+
+/// Encode a PEM document including the given RFC 1421 encapsulated headers,
+/// returning the result as a [`String`].
+///
+/// See [`encode_with_headers`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_string_with_headers(
+    label: &str,
+    headers: &[(&str, &str)],
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<String> {
+    let expected_len = encoded_len_with_headers(label, headers, line_ending, input)?;
+    let mut buf = vec![0u8; expected_len];
+    let actual_len = encode_with_headers(label, headers, line_ending, input, &mut buf)?.len();
+    debug_assert_eq!(expected_len, actual_len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
+
+/// Encode a PEM document with the Base64 body line wrapped at the specified
+/// `width`, returning the result as a [`String`].
+///
+/// See [`encode_wrapped`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_string_wrapped(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<String> {
+    let expected_len = encoded_len_wrapped(label, line_width, line_ending, input)?;
     let mut buf = vec![0u8; expected_len];
-    let actual_len = encode(label, line_ending, input, &mut buf)?.len();
+    let actual_len = encode_wrapped(label, line_width, line_ending, input, &mut buf)?.len();
     debug_assert_eq!(expected_len, actual_len);
     String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
 }
 
+/// Get the length of a PEM encoded document with the given headers and
+/// line width.
+fn encoded_len_inner(
+    label: &str,
+    headers: &[(&str, &str)],
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<usize> {
+    validate_line_width(line_width)?;
+    let base64_len = Base64::encoded_len(input);
+    let base64_len_wrapped = base64_len_wrapped(base64_len, line_width, line_ending)?;
+    encapsulated_len_inner(label, line_ending, base64_len_wrapped)?
+        .checked_add(headers::encoded_len(headers, line_ending)?)
+        .ok_or(Error::Length)
+}
+
 /// Compute the encapsulated length of Base64 data of the given length.
 fn encapsulated_len_inner(
     label: &str,
@@ -166,6 +361,15 @@ fn base64_len_wrapped(
         .ok_or(Error::Length)
 }
 
+/// Ensure the given width is long enough to wrap Base64 at.
+pub(crate) fn validate_line_width(line_width: usize) -> Result<()> {
+    if line_width < MIN_LINE_WIDTH {
+        Err(Error::Length)
+    } else {
+        Ok(())
+    }
+}
+
 /// Buffered PEM encoder.
 ///
 /// Stateful buffered encoder type which encodes an input PEM document according
@@ -177,6 +381,9 @@ pub struct Encoder<'l, 'o> {
     /// Line ending used to wrap Base64.
     line_ending: LineEnding,
 
+    /// Length of the encoded RFC 1421 encapsulated headers.
+    headers_len: usize,
+
     /// Buffered Base64 encoder.
     base64: Base64Encoder<'o>,
 }
@@ -201,16 +408,46 @@ impl<'l, 'o> Encoder<'l, 'o> {
     /// > boundary)
     ///
     /// This method is provided with the intended purpose of implementing the
-    /// OpenSSH private key format, which uses a non-standard wrap width of 70.
+    /// OpenSSH private key format, which uses a non-standard wrap width of 70,
+    /// as well as MIME contexts which use a width of 76. Widths shorter than
+    /// 4 characters are rejected, and [`BASE64_UNWRAPPED`][`crate::BASE64_UNWRAPPED`]
+    /// disables wrapping entirely.
     ///
     /// [RFC7468 § 2]: https://datatracker.ietf.org/doc/html/rfc7468#section-2
     pub fn new_wrapped(
         type_label: &'l str,
         line_width: usize,
         line_ending: LineEnding,
+        out: &'o mut [u8],
+    ) -> Result<Self> {
+        Self::new_inner(type_label, &[], line_width, line_ending, out)
+    }
+
+    /// Create a new PEM [`Encoder`] which writes the given RFC 1421
+    /// encapsulated headers, provided as `(name, value)` pairs, before the
+    /// Base64-encoded body.
+    ///
+    /// See [`encode_with_headers`].
+    pub fn new_with_headers(
+        type_label: &'l str,
+        headers: &[(&str, &str)],
+        line_ending: LineEnding,
+        out: &'o mut [u8],
+    ) -> Result<Self> {
+        Self::new_inner(type_label, headers, BASE64_WRAP_WIDTH, line_ending, out)
+    }
+
+    /// Write the pre-encapsulation boundary and headers, and create the
+    /// Base64 encoder for the remaining output.
+    fn new_inner(
+        type_label: &'l str,
+        headers: &[(&str, &str)],
+        line_width: usize,
+        line_ending: LineEnding,
         mut out: &'o mut [u8],
     ) -> Result<Self> {
         grammar::validate_label(type_label.as_bytes())?;
+        validate_line_width(line_width)?;
 
         for boundary_part in [
             PRE_ENCAPSULATION_BOUNDARY,
@@ -228,11 +465,14 @@ impl<'l, 'o> Encoder<'l, 'o> {
             part.copy_from_slice(boundary_part);
         }
 
+        let headers_len = headers::encoded_len(headers, line_ending)?;
+        let out = headers::encode(headers, line_ending, out)?;
         let base64 = Base64Encoder::new_wrapped(out, line_width, line_ending)?;
 
         Ok(Self {
             type_label,
             line_ending,
+            headers_len,
             base64,
         })
     }
@@ -282,7 +522,9 @@ impl<'l, 'o> Encoder<'l, 'o> {
             part.copy_from_slice(boundary_part);
         }
 
-        encapsulated_len_inner(self.type_label, self.line_ending, base64.len())
+        encapsulated_len_inner(self.type_label, self.line_ending, base64.len())?
+            .checked_add(self.headers_len)
+            .ok_or(Error::Length)
     }
 }
 
@@ -25,6 +25,10 @@ pub(crate) const CHAR_LF: u8 = 0x0a;
 /// Colon ':'
 pub(crate) const CHAR_COLON: u8 = 0x3A;
 
+/// UTF-8 byte order mark, which some tools (mostly on Windows) emit at the
+/// beginning of text files.
+pub(crate) const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
 /// Any printable character except hyphen-minus, as defined in the
 /// 'labelchar' production in the RFC 7468 ABNF grammar
 pub(crate) fn is_labelchar(char: u8) -> bool {
@@ -112,6 +116,32 @@ pub(crate) fn strip_trailing_eol(bytes: &[u8]) -> Option<&[u8]> {
     }
 }
 
+/// Strip any trailing whitespace (i.e. WSP characters) from the given bytes.
+pub(crate) fn strip_trailing_wsp(mut bytes: &[u8]) -> &[u8] {
+    while let [head @ .., last] = bytes {
+        if !is_wsp(*last) {
+            break;
+        }
+
+        bytes = head;
+    }
+
+    bytes
+}
+
+/// Split the first line from the given bytes, returning it (without its
+/// line ending) along with the bytes following the line ending.
+///
+/// Returns `None` if there is no line ending.
+pub(crate) fn split_line(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    let pos = bytes
+        .iter()
+        .position(|&char| char == CHAR_CR || char == CHAR_LF)?;
+
+    let (line, rest) = bytes.split_at(pos);
+    Some((line, strip_leading_eol(rest)?))
+}
+
 /// Split a slice beginning with a type label as located in an encapsulation
 /// boundary. Returns the label as a `&str`, and slice beginning with the
 /// encapsulated text with leading `-----` and newline removed.
@@ -0,0 +1,195 @@
+//! Well-known PEM type labels.
+
+use crate::{Error, PemLabel, Result};
+use core::{fmt, str::FromStr};
+
+/// Well-known PEM type labels.
+///
+/// Includes the labels defined in [RFC 7468 § 3] along with the "traditional"
+/// labels used by OpenSSL and OpenSSH for algorithm-specific keys.
+///
+/// This allows loaders which accept several kinds of documents to dispatch
+/// on their type, e.g. using [`Label::classify`] on the result of
+/// [`decode_label`][`crate::decode_label`].
+///
+/// [RFC 7468 § 3]: https://datatracker.ietf.org/doc/html/rfc7468#section-3
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Label {
+    /// `ATTRIBUTE CERTIFICATE`: RFC 5755 attribute certificate.
+    AttributeCertificate,
+
+    /// `CERTIFICATE`: RFC 5280 public-key certificate.
+    Certificate,
+
+    /// `CERTIFICATE REQUEST`: PKCS#10 certification request.
+    CertificateRequest,
+
+    /// `CMS`: RFC 5652 Cryptographic Message Syntax.
+    Cms,
+
+    /// `EC PARAMETERS`: RFC 5915 elliptic curve domain parameters.
+    EcParameters,
+
+    /// `EC PRIVATE KEY`: SEC1 elliptic curve private key.
+    EcPrivateKey,
+
+    /// `ENCRYPTED PRIVATE KEY`: PKCS#8 encrypted private key.
+    EncryptedPrivateKey,
+
+    /// `OPENSSH PRIVATE KEY`: OpenSSH private key.
+    OpensshPrivateKey,
+
+    /// `PKCS7`: PKCS#7 cryptographic message.
+    Pkcs7,
+
+    /// `PRIVATE KEY`: PKCS#8 private key.
+    PrivateKey,
+
+    /// `PUBLIC KEY`: X.509 `SubjectPublicKeyInfo`.
+    PublicKey,
+
+    /// `RSA PRIVATE KEY`: PKCS#1 RSA private key.
+    RsaPrivateKey,
+
+    /// `RSA PUBLIC KEY`: PKCS#1 RSA public key.
+    RsaPublicKey,
+
+    /// `X509 CRL`: RFC 5280 certificate revocation list.
+    X509Crl,
+}
+
+impl Label {
+    /// Identify a well-known PEM type label.
+    ///
+    /// Labels are matched case-sensitively as per RFC 7468. Returns `None`
+    /// for labels which aren't known.
+    pub fn classify(label: &str) -> Option<Self> {
+        match label {
+            "ATTRIBUTE CERTIFICATE" => Some(Self::AttributeCertificate),
+            "CERTIFICATE" => Some(Self::Certificate),
+            "CERTIFICATE REQUEST" => Some(Self::CertificateRequest),
+            "CMS" => Some(Self::Cms),
+            "EC PARAMETERS" => Some(Self::EcParameters),
+            "EC PRIVATE KEY" => Some(Self::EcPrivateKey),
+            "ENCRYPTED PRIVATE KEY" => Some(Self::EncryptedPrivateKey),
+            "OPENSSH PRIVATE KEY" => Some(Self::OpensshPrivateKey),
+            "PKCS7" => Some(Self::Pkcs7),
+            "PRIVATE KEY" => Some(Self::PrivateKey),
+            "PUBLIC KEY" => Some(Self::PublicKey),
+            "RSA PRIVATE KEY" => Some(Self::RsaPrivateKey),
+            "RSA PUBLIC KEY" => Some(Self::RsaPublicKey),
+            "X509 CRL" => Some(Self::X509Crl),
+            _ => None,
+        }
+    }
+
+    /// Identify the well-known label of a type implementing [`PemLabel`].
+    pub fn of<T: PemLabel>() -> Option<Self> {
+        Self::classify(T::PEM_LABEL)
+    }
+
+    /// Get the string representation of this label, as it appears in the
+    /// encapsulation boundaries.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AttributeCertificate => "ATTRIBUTE CERTIFICATE",
+            Self::Certificate => "CERTIFICATE",
+            Self::CertificateRequest => "CERTIFICATE REQUEST",
+            Self::Cms => "CMS",
+            Self::EcParameters => "EC PARAMETERS",
+            Self::EcPrivateKey => "EC PRIVATE KEY",
+            Self::EncryptedPrivateKey => "ENCRYPTED PRIVATE KEY",
+            Self::OpensshPrivateKey => "OPENSSH PRIVATE KEY",
+            Self::Pkcs7 => "PKCS7",
+            Self::PrivateKey => "PRIVATE KEY",
+            Self::PublicKey => "PUBLIC KEY",
+            Self::RsaPrivateKey => "RSA PRIVATE KEY",
+            Self::RsaPublicKey => "RSA PUBLIC KEY",
+            Self::X509Crl => "X509 CRL",
+        }
+    }
+
+    /// Is this the label of the given [`PemLabel`] type?
+    pub fn is<T: PemLabel>(self) -> bool {
+        self.as_str() == T::PEM_LABEL
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Label {
+    type Err = Error;
+
+    fn from_str(label: &str) -> Result<Self> {
+        Self::classify(label).ok_or(Error::Label)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Label {
+    type Error = Error;
+
+    fn try_from(label: &'a str) -> Result<Self> {
+        label.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Label;
+    use crate::{Error, PemLabel};
+
+    struct ExampleKey;
+
+    impl PemLabel for ExampleKey {
+        const PEM_LABEL: &'static str = "EC PRIVATE KEY";
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(Label::classify("X509 CRL"), Some(Label::X509Crl));
+        assert_eq!(Label::classify("x509 crl"), None);
+        assert_eq!(Label::classify("UNKNOWN"), None);
+        assert_eq!("CERTIFICATE".parse(), Ok(Label::Certificate));
+        assert_eq!("UNKNOWN".parse::<Label>(), Err(Error::Label));
+    }
+
+    #[test]
+    fn round_trip() {
+        for label in [
+            Label::AttributeCertificate,
+            Label::Certificate,
+            Label::CertificateRequest,
+            Label::Cms,
+            Label::EcParameters,
+            Label::EcPrivateKey,
+            Label::EncryptedPrivateKey,
+            Label::OpensshPrivateKey,
+            Label::Pkcs7,
+            Label::PrivateKey,
+            Label::PublicKey,
+            Label::RsaPrivateKey,
+            Label::RsaPublicKey,
+            Label::X509Crl,
+        ] {
+            assert_eq!(Label::classify(label.as_str()), Some(label));
+        }
+    }
+
+    #[test]
+    fn pem_label() {
+        assert_eq!(Label::of::<ExampleKey>(), Some(Label::EcPrivateKey));
+        assert!(Label::EcPrivateKey.is::<ExampleKey>());
+        assert!(!Label::PrivateKey.is::<ExampleKey>());
+    }
+}
@@ -43,8 +43,13 @@
 //!
 //! // Encode PEM
 //! use pem_rfc7468::LineEnding;
-//! let encoded_pem = pem_rfc7468::encode_string(type_label, LineEnding::default(), &data)?;
+//! let encoded_pem = pem_rfc7468::encode_string(type_label, LineEnding::LF, &data)?;
 //! assert_eq!(&encoded_pem, example_pem);
+//!
+//! // Documents with CRLF line endings decode identically
+//! let crlf_pem = pem_rfc7468::encode_string(type_label, LineEnding::CRLF, &data)?;
+//! assert_eq!(crlf_pem, example_pem.replace('\n', "\r\n"));
+//! assert_eq!(pem_rfc7468::decode_vec(crlf_pem.as_bytes())?.1, data);
 //! # Ok(())
 //! # }
 //! ```
@@ -59,16 +64,45 @@ mod decoder;
 mod encoder;
 mod error;
 mod grammar;
+mod headers;
+mod label;
+mod rewrap;
+
+#[cfg(feature = "std")]
+mod stream;
 
 pub use crate::{
-    decoder::{decode, decode_label, Decoder},
-    encoder::{encapsulated_len, encapsulated_len_wrapped, encode, encoded_len, Encoder},
+    decoder::{decode, decode_in_place, decode_label, decode_lax, Decoder, Documents},
+    encoder::{
+        encapsulated_len, encapsulated_len_wrapped, encode, encode_with_headers, encode_wrapped,
+        encoded_len, encoded_len_with_headers, encoded_len_wrapped, encoded_size,
+        encoded_size_wrapped, Encoder,
+    },
     error::{Error, Result},
+    headers::{Headers, HeadersIter},
+    label::Label,
+    rewrap::rewrap,
 };
+/// Line endings used when encoding PEM.
+///
+/// [`LineEnding::LF`] is conventional for PEM and is the default on all
+/// platforms, whereas [`LineEnding::CRLF`] is required by some Windows tools
+/// and S/MIME consumers.
+///
+/// Decoding always accepts CRLF, CR, and LF line endings as permitted by
+/// RFC 7468 § 3, regardless of which is used.
 pub use base64ct::LineEnding;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::stream::{StreamDecoder, StreamEncoder};
+
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{
+    decoder::{decode_lax_vec, decode_vec},
+    encoder::{encode_string, encode_string_with_headers, encode_string_wrapped},
+    rewrap::rewrap_string,
+};
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
@@ -96,6 +130,17 @@ const ENCAPSULATION_BOUNDARY_DELIMITER: &[u8] = b"-----";
 /// [RFC7468 § 2]: https://datatracker.ietf.org/doc/html/rfc7468#section-2
 pub const BASE64_WRAP_WIDTH: usize = 64;
 
+/// Line width which disables wrapping of the Base64 body when passed to
+/// [`Encoder::new_wrapped`] and [`encode_wrapped`], emitting it as a single
+/// line of arbitrary length.
+///
+/// Like any other width besides [`BASE64_WRAP_WIDTH`], output produced
+/// this way is non-compliant with RFC 7468.
+pub const BASE64_UNWRAPPED: usize = usize::MAX;
+
+/// Minimum width at which the Base64 body can be wrapped.
+const MIN_LINE_WIDTH: usize = 4;
+
 /// Buffered Base64 decoder type.
 pub type Base64Decoder<'i> = base64ct::Decoder<'i, base64ct::Base64>;
 
@@ -103,6 +148,9 @@ pub type Base64Decoder<'i> = base64ct::Decoder<'i, base64ct::Base64>;
 pub type Base64Encoder<'o> = base64ct::Encoder<'o, base64ct::Base64>;
 
 /// Marker trait for types with an associated PEM type label.
+///
+/// Use [`Label::of`] to identify the well-known [`Label`] of a type
+/// implementing this trait (if it has one).
 pub trait PemLabel {
     /// Expected PEM type label for a given document, e.g. `"PRIVATE KEY"`
     const PEM_LABEL: &'static str;
@@ -0,0 +1,70 @@
+//! Re-encoding of PEM documents with different formatting.
+
+use crate::{encoder, Decoder, Encoder, Error, LineEnding, Result};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Size of the chunks the document is decoded and re-encoded in.
+const CHUNK_SIZE: usize = 48;
+
+/// Parse a PEM document according to RFC 7468's "Strict" grammar (at any
+/// line width) and re-encode its contents into the provided buffer with the
+/// given line width and line ending, e.g. to normalize the documents in a
+/// certificate store.
+///
+/// The document's type label is preserved unless `new_label` is provided,
+/// in which case it must be a valid label. Relabeling doesn't change the
+/// contents of the document, so it should only be used to correct labels
+/// known to be wrong (e.g. the legacy `X509 CERTIFICATE` label for
+/// `CERTIFICATE`).
+///
+/// The document is decoded and re-encoded a chunk at a time, so no buffer
+/// other than the output is needed. See [`encode_wrapped`][`crate::encode_wrapped`]
+/// for the supported line widths.
+pub fn rewrap<'o>(
+    pem: &[u8],
+    new_label: Option<&str>,
+    line_width: usize,
+    line_ending: LineEnding,
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    let mut decoder = Decoder::new(pem)?;
+    let label = new_label.unwrap_or_else(|| decoder.type_label());
+    let mut encoder = Encoder::new_wrapped(label, line_width, line_ending, buf)?;
+    let mut chunk = [0u8; CHUNK_SIZE];
+
+    while !decoder.is_finished() {
+        let len = decoder.remaining_len().min(CHUNK_SIZE);
+        let decoded = decoder.decode(&mut chunk[..len])?;
+        encoder.encode(decoded)?;
+    }
+
+    let encoded_len = encoder.finish()?;
+    encoder::output_str(buf.get(..encoded_len).ok_or(Error::Length)?)
+}
+
+/// Parse a PEM document and re-encode it with the given line width and line
+/// ending, returning the result as a [`String`].
+///
+/// See [`rewrap`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn rewrap_string(
+    pem: &[u8],
+    new_label: Option<&str>,
+    line_width: usize,
+    line_ending: LineEnding,
+) -> Result<String> {
+    encoder::validate_line_width(line_width)?;
+
+    let decoder = Decoder::new(pem)?;
+    let label = new_label.unwrap_or_else(|| decoder.type_label());
+    let len =
+        encoder::encoded_size_wrapped(label, line_width, line_ending, decoder.remaining_len());
+
+    let mut buf = vec![0u8; len];
+    let actual_len = rewrap(pem, new_label, line_width, line_ending, &mut buf)?.len();
+    debug_assert_eq!(len, actual_len);
+    String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
+}
@@ -0,0 +1,351 @@
+//! Streaming PEM encoder and decoder built on [`std::io`].
+//!
+//! Unlike [`Decoder`][`crate::Decoder`] and [`Encoder`][`crate::Encoder`],
+//! which operate on complete in-memory buffers, these types process a PEM
+//! document a line at a time using a constant amount of memory, which makes
+//! them suitable for large documents like CRLs.
+
+use crate::{
+    encoder, grammar, Error, LineEnding, BASE64_WRAP_WIDTH, ENCAPSULATION_BOUNDARY_DELIMITER,
+    POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
+};
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+use core::str;
+use std::io::{self, BufRead, Read, Write};
+
+/// Maximum number of bytes read from the underlying reader at a time.
+///
+/// Lines longer than this are processed in several chunks.
+const CHUNK_SIZE: usize = 1024;
+
+/// Number of input bytes the encoder buffers before Base64 encoding them.
+///
+/// This is a multiple of 3 so buffered input always encodes to complete
+/// 4-character groups.
+const ENCODER_INPUT_LEN: usize = 48;
+
+/// Streaming PEM decoder.
+///
+/// Reads a PEM document from an [`io::BufRead`] a line at a time, decoding
+/// its Base64-encoded body as it's read via the [`io::Read`] impl.
+///
+/// Lines can be terminated by either LF or CRLF, and the Base64 body may be
+/// wrapped at any width.
+pub struct StreamDecoder<R: BufRead> {
+    /// Underlying reader.
+    reader: R,
+
+    /// PEM type label.
+    type_label: String,
+
+    /// Buffer for the chunk of input currently being processed.
+    chunk: Vec<u8>,
+
+    /// Base64 characters left over from the previous chunk which don't yet
+    /// form a complete 4-character group.
+    carry: Vec<u8>,
+
+    /// Decoded data which hasn't been read yet.
+    decoded: Vec<u8>,
+
+    /// Position of the unread data in `decoded`.
+    position: usize,
+
+    /// Is the next chunk at the start of a line?
+    at_line_start: bool,
+
+    /// Has padding been encountered (i.e. the end of the Base64 data)?
+    padded: bool,
+
+    /// Has the post-encapsulation boundary been read?
+    finished: bool,
+}
+
+impl<R: BufRead> StreamDecoder<R> {
+    /// Create a new streaming decoder, reading from the provided reader until
+    /// the pre-encapsulation boundary is found.
+    ///
+    /// Any text preceding the pre-encapsulation boundary is skipped.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut line = Vec::new();
+
+        let type_label = loop {
+            line.clear();
+
+            if (&mut reader)
+                .take(CHUNK_SIZE as u64)
+                .read_until(grammar::CHAR_LF, &mut line)?
+                == 0
+            {
+                return Err(Error::PreEncapsulationBoundary.into());
+            }
+
+            if line.contains(&grammar::CHAR_NUL) {
+                return Err(Error::Preamble.into());
+            }
+
+            if let Some(rest) = line.strip_prefix(PRE_ENCAPSULATION_BOUNDARY) {
+                let (label, body) = grammar::split_label(rest).ok_or(Error::Label)?;
+
+                if !body.is_empty() {
+                    return Err(Error::PreEncapsulationBoundary.into());
+                }
+
+                break String::from(label);
+            }
+        };
+
+        Ok(Self {
+            reader,
+            type_label,
+            chunk: Vec::with_capacity(CHUNK_SIZE),
+            carry: Vec::with_capacity(4),
+            decoded: Vec::new(),
+            position: 0,
+            at_line_start: true,
+            padded: false,
+            finished: false,
+        })
+    }
+
+    /// Get the PEM type label for the input document.
+    pub fn type_label(&self) -> &str {
+        &self.type_label
+    }
+
+    /// Are we finished decoding the PEM input?
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.position == self.decoded.len()
+    }
+
+    /// Consume the decoder, returning the underlying reader.
+    ///
+    /// If the decoder is finished, the reader is positioned immediately
+    /// after the post-encapsulation boundary.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read and decode the next chunk of input.
+    fn fill(&mut self) -> io::Result<()> {
+        self.decoded.clear();
+        self.position = 0;
+        self.chunk.clear();
+
+        let len = (&mut self.reader)
+            .take(CHUNK_SIZE as u64)
+            .read_until(grammar::CHAR_LF, &mut self.chunk)?;
+
+        if len == 0 {
+            return Err(Error::PostEncapsulationBoundary.into());
+        }
+
+        let line_start = self.at_line_start;
+        self.at_line_start = self.chunk.ends_with(&[grammar::CHAR_LF]);
+
+        let mut text = self.chunk.as_slice();
+
+        if self.at_line_start {
+            text = grammar::strip_trailing_eol(text).ok_or(Error::EncapsulatedText)?;
+        }
+
+        // The Base64 alphabet doesn't include hyphen-minus, so this never
+        // matches the (potentially secret) encapsulated text
+        if line_start && text.starts_with(ENCAPSULATION_BOUNDARY_DELIMITER) {
+            let label = text
+                .strip_prefix(POST_ENCAPSULATION_BOUNDARY)
+                .and_then(|rest| rest.strip_suffix(ENCAPSULATION_BOUNDARY_DELIMITER))
+                .ok_or(Error::PostEncapsulationBoundary)?;
+
+            if label != self.type_label.as_bytes() || !self.carry.is_empty() {
+                return Err(Error::PostEncapsulationBoundary.into());
+            }
+
+            self.finished = true;
+            return Ok(());
+        }
+
+        if self.padded && !text.is_empty() {
+            return Err(Error::EncapsulatedText.into());
+        }
+
+        self.carry.extend_from_slice(text);
+
+        // Decode all of the complete 4-character groups
+        let complete_len = self.carry.len() & !3;
+        let (complete, _) = self.carry.split_at(complete_len);
+        self.decoded
+            .resize((complete_len >> 2).saturating_mul(3), 0);
+        let decoded_len = Base64::decode(complete, &mut self.decoded)
+            .map_err(Error::from)?
+            .len();
+        self.decoded.truncate(decoded_len);
+        self.padded = complete.ends_with(b"=");
+        self.carry.drain(..complete_len);
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for StreamDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.decoded.len() {
+            if self.finished || buf.is_empty() {
+                return Ok(0);
+            }
+
+            self.fill()?;
+        }
+
+        let remaining = &self.decoded[self.position..];
+        let len = remaining.len().min(buf.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.position = self.position.saturating_add(len);
+        Ok(len)
+    }
+}
+
+/// Streaming PEM encoder.
+///
+/// Writes a PEM document to an [`io::Write`] incrementally as data is
+/// written to it via its own [`io::Write`] impl, wrapping the Base64-encoded
+/// body at 64 characters by default.
+///
+/// [`StreamEncoder::finish`] must be called to write the post-encapsulation
+/// boundary once all of the data has been written.
+pub struct StreamEncoder<W: Write> {
+    /// Underlying writer.
+    writer: W,
+
+    /// PEM type label.
+    type_label: String,
+
+    /// Line ending used to wrap Base64.
+    line_ending: LineEnding,
+
+    /// Width at which Base64 is wrapped.
+    line_width: usize,
+
+    /// Number of Base64 characters written to the current line.
+    column: usize,
+
+    /// Input which hasn't been encoded yet.
+    buffer: [u8; ENCODER_INPUT_LEN],
+
+    /// Length of the data in `buffer`.
+    buffer_len: usize,
+}
+
+impl<W: Write> StreamEncoder<W> {
+    /// Create a new streaming encoder, writing the pre-encapsulation boundary
+    /// to the provided writer.
+    ///
+    /// Uses the default 64-character line wrapping.
+    pub fn new(type_label: &str, line_ending: LineEnding, writer: W) -> io::Result<Self> {
+        Self::new_wrapped(type_label, BASE64_WRAP_WIDTH, line_ending, writer)
+    }
+
+    /// Create a new streaming encoder which wraps at the given line width.
+    ///
+    /// See [`Encoder::new_wrapped`][`crate::Encoder::new_wrapped`].
+    pub fn new_wrapped(
+        type_label: &str,
+        line_width: usize,
+        line_ending: LineEnding,
+        mut writer: W,
+    ) -> io::Result<Self> {
+        grammar::validate_label(type_label.as_bytes())?;
+        encoder::validate_line_width(line_width)?;
+
+        for part in [
+            PRE_ENCAPSULATION_BOUNDARY,
+            type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            line_ending.as_bytes(),
+        ] {
+            writer.write_all(part)?;
+        }
+
+        Ok(Self {
+            writer,
+            type_label: String::from(type_label),
+            line_ending,
+            line_width,
+            column: 0,
+            buffer: [0u8; ENCODER_INPUT_LEN],
+            buffer_len: 0,
+        })
+    }
+
+    /// Get the PEM type label used for this document.
+    pub fn type_label(&self) -> &str {
+        &self.type_label
+    }
+
+    /// Finish encoding PEM, writing the final line of Base64 along with the
+    /// post-encapsulation boundary, and returning the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_buffer()?;
+
+        if self.column != 0 {
+            self.writer.write_all(self.line_ending.as_bytes())?;
+        }
+
+        for part in [
+            POST_ENCAPSULATION_BOUNDARY,
+            self.type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            self.line_ending.as_bytes(),
+        ] {
+            self.writer.write_all(part)?;
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Encode the buffered input as Base64, wrapping lines as needed.
+    fn write_buffer(&mut self) -> io::Result<()> {
+        let mut encoded = [0u8; ENCODER_INPUT_LEN / 3 * 4];
+        let mut encoded =
+            Base64::encode(&self.buffer[..self.buffer_len], &mut encoded).map_err(Error::from)?;
+        self.buffer_len = 0;
+
+        while !encoded.is_empty() {
+            if self.column == self.line_width {
+                self.writer.write_all(self.line_ending.as_bytes())?;
+                self.column = 0;
+            }
+
+            let len = self
+                .line_width
+                .saturating_sub(self.column)
+                .min(encoded.len());
+            let (part, rest) = encoded.split_at(len);
+            self.writer.write_all(part.as_bytes())?;
+            self.column = self.column.saturating_add(len);
+            encoded = rest;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for StreamEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let buffer = &mut self.buffer[self.buffer_len..];
+        let len = buf.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&buf[..len]);
+        self.buffer_len = self.buffer_len.saturating_add(len);
+
+        if self.buffer_len == ENCODER_INPUT_LEN {
+            self.write_buffer()?;
+        }
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -23,6 +23,16 @@ pub enum Error {
     /// a number expected to be a prime was not a prime.
     Crypto,
 
+    /// Legacy PEM encryption errors, e.g. a missing or unsupported
+    /// `DEK-Info` header, or an incorrect password.
+    #[cfg(feature = "encryption")]
+    Encryption,
+
+    /// JSON Web Key errors, e.g. a `kty` other than `RSA`, or missing or
+    /// malformed key members.
+    #[cfg(feature = "jwk")]
+    Jwk,
+
     /// PKCS#8 errors.
     #[cfg(feature = "pkcs8")]
     Pkcs8(pkcs8::Error),
@@ -36,6 +46,10 @@ impl fmt::Display for Error {
         match self {
             Error::Asn1(err) => write!(f, "PKCS#1 ASN.1 error: {}", err),
             Error::Crypto => f.write_str("PKCS#1 cryptographic error"),
+            #[cfg(feature = "encryption")]
+            Error::Encryption => f.write_str("PKCS#1 PEM encryption error"),
+            #[cfg(feature = "jwk")]
+            Error::Jwk => f.write_str("PKCS#1 JWK error"),
             #[cfg(feature = "pkcs8")]
             Error::Pkcs8(err) => write!(f, "{}", err),
             Error::Version => f.write_str("PKCS#1 version error"),
@@ -56,12 +70,26 @@ impl From<pem::Error> for Error {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl From<pkcs5::Error> for Error {
+    fn from(err: pkcs5::Error) -> Error {
+        match err {
+            pkcs5::Error::Asn1(err) => Error::Asn1(err),
+            _ => Error::Encryption,
+        }
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 impl From<Error> for pkcs8::Error {
     fn from(err: Error) -> pkcs8::Error {
         match err {
             Error::Asn1(e) => pkcs8::Error::Asn1(e),
             Error::Crypto | Error::Version => pkcs8::Error::KeyMalformed,
+            #[cfg(feature = "encryption")]
+            Error::Encryption => pkcs8::Error::KeyMalformed,
+            #[cfg(feature = "jwk")]
+            Error::Jwk => pkcs8::Error::Jwk,
             Error::Pkcs8(e) => e,
         }
     }
@@ -70,23 +98,27 @@ impl From<Error> for pkcs8::Error {
 #[cfg(feature = "pkcs8")]
 impl From<pkcs8::Error> for Error {
     fn from(err: pkcs8::Error) -> Error {
-        Error::Pkcs8(err)
+        match err {
+            #[cfg(feature = "jwk")]
+            pkcs8::Error::Jwk => Error::Jwk,
+            _ => Error::Pkcs8(err),
+        }
     }
 }
 
 #[cfg(feature = "pkcs8")]
-impl From<Error> for pkcs8::spki::Error {
-    fn from(err: Error) -> pkcs8::spki::Error {
+impl From<Error> for spki::Error {
+    fn from(err: Error) -> spki::Error {
         match err {
-            Error::Asn1(e) => pkcs8::spki::Error::Asn1(e),
-            _ => pkcs8::spki::Error::KeyMalformed,
+            Error::Asn1(e) => spki::Error::Asn1(e),
+            _ => spki::Error::KeyMalformed,
         }
     }
 }
 
 #[cfg(feature = "pkcs8")]
-impl From<pkcs8::spki::Error> for Error {
-    fn from(err: pkcs8::spki::Error) -> Error {
+impl From<spki::Error> for Error {
+    fn from(err: spki::Error) -> Error {
         Error::Pkcs8(pkcs8::Error::PublicKey(err))
     }
 }
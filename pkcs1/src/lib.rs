@@ -15,18 +15,24 @@ extern crate alloc;
 extern crate std;
 
 mod error;
+mod params;
 mod private_key;
 mod public_key;
 mod traits;
 mod version;
 
+#[cfg(feature = "bigint")]
+mod validation;
+
 pub use der::{
     self,
     asn1::{ObjectIdentifier, UIntBytes},
 };
+pub use spki::{self, AlgorithmIdentifier};
 
 pub use self::{
     error::{Error, Result},
+    params::{MaskGenAlgorithm, PSourceAlgorithm, RsaOaepParams, RsaPssParams, TrailerField},
     private_key::RsaPrivateKey,
     public_key::RsaPublicKey,
     traits::{DecodeRsaPrivateKey, DecodeRsaPublicKey},
@@ -35,14 +41,35 @@ pub use self::{
 
 #[cfg(feature = "alloc")]
 pub use crate::{
-    private_key::{other_prime_info::OtherPrimeInfo, OtherPrimeInfos},
+    private_key::{
+        other_prime_info::OtherPrimeInfo,
+        owned::{OtherPrimeInfoOwned, RsaPrivateKeyOwned},
+        OtherPrimeInfos,
+    },
+    public_key::owned::RsaPublicKeyOwned,
     traits::{EncodeRsaPrivateKey, EncodeRsaPublicKey},
 };
 
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+pub use pkcs8::jwk::Jwk;
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 pub use der::pem::{self, LineEnding};
 
+#[cfg(feature = "bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
+pub use crate::validation::ValidationError;
+
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub use pkcs5::{pem::DekAlgorithm, rand_core};
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+pub use pkcs8;
+
 /// `rsaEncryption` Object Identifier (OID)
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
@@ -51,7 +78,7 @@ pub const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.84
 /// `AlgorithmIdentifier` for RSA.
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
-pub const ALGORITHM_ID: pkcs8::AlgorithmIdentifier<'static> = pkcs8::AlgorithmIdentifier {
+pub const ALGORITHM_ID: AlgorithmIdentifier<'static> = AlgorithmIdentifier {
     oid: ALGORITHM_OID,
     parameters: Some(der::asn1::Any::NULL),
 };
@@ -0,0 +1,491 @@
+//! PKCS#1 RSA algorithm parameters.
+
+use crate::{Error, Result};
+use der::{
+    asn1::{Any, ContextSpecificRef, ObjectIdentifier, OctetString, SequenceRef},
+    Decode, DecodeValue, Decoder, Encode, EncodeValue, FixedTag, Header, Length, Sequence, Tag,
+    TagMode, TagNumber, Writer,
+};
+use spki::AlgorithmIdentifier;
+
+/// `id-sha1` Object Identifier (OID)
+const OID_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+/// `id-sha256` Object Identifier (OID)
+const OID_SHA_256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+
+/// `id-sha384` Object Identifier (OID)
+const OID_SHA_384: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2");
+
+/// `id-sha512` Object Identifier (OID)
+const OID_SHA_512: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3");
+
+/// `id-mgf1` Object Identifier (OID)
+const OID_MGF_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8");
+
+/// `id-pSpecified` Object Identifier (OID)
+const OID_P_SPECIFIED: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.9");
+
+/// `sha1Identifier` as defined in [RFC 4055 Section 2.1], i.e. the default
+/// hash algorithm.
+///
+/// [RFC 4055 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+const SHA_1_AI: AlgorithmIdentifier<'static> = hash_algorithm(OID_SHA_1);
+
+/// Default salt length as defined in [RFC 8017 Appendix 2.3].
+///
+/// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+const DEFAULT_SALT_LEN: u16 = 20;
+
+/// Create a hash `AlgorithmIdentifier` with `NULL` parameters, as specified
+/// in [RFC 4055 Section 2.1].
+///
+/// [RFC 4055 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+const fn hash_algorithm(oid: ObjectIdentifier) -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid,
+        parameters: Some(Any::NULL),
+    }
+}
+
+/// Create an `EXPLICIT` context-specific field, or `None` if the value is
+/// equal to the field's `DEFAULT`.
+fn context_specific<'a, T>(
+    tag_number: TagNumber,
+    value: &'a T,
+    default: &T,
+) -> Option<ContextSpecificRef<'a, T>>
+where
+    T: PartialEq,
+{
+    if value == default {
+        None
+    } else {
+        Some(ContextSpecificRef {
+            tag_number,
+            tag_mode: TagMode::Explicit,
+            value,
+        })
+    }
+}
+
+/// Mask generation function as defined in [RFC 8017 Appendix 2.1].
+///
+/// PKCS#1 only defines MGF1, whose parameters are the hash algorithm it's
+/// based on:
+///
+/// ```text
+/// PKCS1MGFAlgorithms    ALGORITHM-IDENTIFIER ::= {
+///     { OID id-mgf1 PARAMETERS HashAlgorithm },
+///     ...  -- Allows for future expansion --
+/// }
+/// ```
+///
+/// [RFC 8017 Appendix 2.1]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MaskGenAlgorithm<'a> {
+    /// Mask generation function OID, i.e. `id-mgf1`.
+    pub oid: ObjectIdentifier,
+
+    /// Hash algorithm used by the mask generation function.
+    pub hash: AlgorithmIdentifier<'a>,
+}
+
+impl<'a> MaskGenAlgorithm<'a> {
+    /// Create MGF1 using the given hash algorithm.
+    pub const fn mgf1(hash: AlgorithmIdentifier<'a>) -> Self {
+        Self {
+            oid: OID_MGF_1,
+            hash,
+        }
+    }
+
+    /// Is this MGF1?
+    pub fn is_mgf1(&self) -> bool {
+        self.oid == OID_MGF_1
+    }
+}
+
+impl<'a> DecodeValue<'a> for MaskGenAlgorithm<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+            Ok(Self {
+                oid: decoder.decode()?,
+                hash: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for MaskGenAlgorithm<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.oid, &self.hash])
+    }
+}
+
+/// Source of the encoding parameters (i.e. label) used by RSAES-OAEP, as
+/// defined in [RFC 8017 Appendix 2.1].
+///
+/// PKCS#1 only defines `id-pSpecified`, whose parameters are the label:
+///
+/// ```text
+/// PKCS1PSourceAlgorithms    ALGORITHM-IDENTIFIER ::= {
+///     { OID id-pSpecified PARAMETERS EncodingParameters },
+///     ...  -- Allows for future expansion --
+/// }
+///
+/// EncodingParameters ::= OCTET STRING(SIZE(0..MAX))
+/// ```
+///
+/// [RFC 8017 Appendix 2.1]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PSourceAlgorithm<'a> {
+    /// Parameter source OID, i.e. `id-pSpecified`.
+    pub oid: ObjectIdentifier,
+
+    /// Label associated with the message.
+    pub label: &'a [u8],
+}
+
+impl<'a> PSourceAlgorithm<'a> {
+    /// Create `id-pSpecified` using the given label.
+    pub const fn p_specified(label: &'a [u8]) -> Self {
+        Self {
+            oid: OID_P_SPECIFIED,
+            label,
+        }
+    }
+
+    /// Is this `id-pSpecified`?
+    pub fn is_p_specified(&self) -> bool {
+        self.oid == OID_P_SPECIFIED
+    }
+}
+
+impl<'a> DecodeValue<'a> for PSourceAlgorithm<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+            Ok(Self {
+                oid: decoder.decode()?,
+                label: decoder.octet_string()?.as_bytes(),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PSourceAlgorithm<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.oid, &OctetString::new(self.label)?])
+    }
+}
+
+/// Trailer field as defined in [RFC 8017 Appendix 2.3].
+///
+/// ```text
+/// TrailerField ::= INTEGER { trailerFieldBC(1) }
+/// ```
+///
+/// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum TrailerField {
+    /// Trailer field `0xBC`, which is the only one supported by PKCS#1.
+    BC = 1,
+}
+
+impl Default for TrailerField {
+    fn default() -> Self {
+        Self::BC
+    }
+}
+
+impl DecodeValue<'_> for TrailerField {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> der::Result<Self> {
+        match u8::decode_value(decoder, header)? {
+            1 => Ok(Self::BC),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for TrailerField {
+    fn value_len(&self) -> der::Result<Length> {
+        (*self as u8).value_len()
+    }
+
+    fn encode_value(&self, writer: &mut dyn Writer) -> der::Result<()> {
+        (*self as u8).encode_value(writer)
+    }
+}
+
+impl FixedTag for TrailerField {
+    const TAG: Tag = Tag::Integer;
+}
+
+/// PKCS#1 RSASSA-PSS parameters as defined in [RFC 8017 Appendix 2.3].
+///
+/// ASN.1 structure containing the parameters of the RSASSA-PSS signature
+/// scheme, i.e. the `parameters` of an `AlgorithmIdentifier` whose OID is
+/// `id-RSASSA-PSS`:
+///
+/// ```text
+/// RSASSA-PSS-params ::= SEQUENCE {
+///     hashAlgorithm      [0] HashAlgorithm      DEFAULT sha1,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm   DEFAULT mgf1SHA1,
+///     saltLength         [2] INTEGER            DEFAULT 20,
+///     trailerField       [3] TrailerField       DEFAULT trailerFieldBC
+/// }
+/// ```
+///
+/// Fields which are equal to their `DEFAULT` are omitted when encoding, as
+/// required by DER. The [`Default`] impl returns the defaults listed above.
+///
+/// Parameters for common hash functions are available as constants, e.g.
+/// [`RsaPssParams::SHA_256`]:
+///
+/// ```
+/// use pkcs1::{der::Encode, RsaPssParams};
+///
+/// let params = RsaPssParams::SHA_256.with_salt_len(20);
+///
+/// let mut buf = [0u8; 64];
+/// let der = params.encode_to_slice(&mut buf).unwrap();
+/// assert_eq!(RsaPssParams::try_from(der).unwrap(), params);
+/// ```
+///
+/// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RsaPssParams<'a> {
+    /// Hash algorithm applied to the message.
+    pub hash: AlgorithmIdentifier<'a>,
+
+    /// Mask generation function.
+    pub mask_gen: MaskGenAlgorithm<'a>,
+
+    /// Length of the salt in bytes.
+    pub salt_len: u16,
+
+    /// Trailer field.
+    pub trailer_field: TrailerField,
+}
+
+impl<'a> RsaPssParams<'a> {
+    /// SHA-256 with MGF1-SHA-256 and a 32-byte salt.
+    pub const SHA_256: RsaPssParams<'static> = RsaPssParams::new(hash_algorithm(OID_SHA_256), 32);
+
+    /// SHA-384 with MGF1-SHA-384 and a 48-byte salt.
+    pub const SHA_384: RsaPssParams<'static> = RsaPssParams::new(hash_algorithm(OID_SHA_384), 48);
+
+    /// SHA-512 with MGF1-SHA-512 and a 64-byte salt.
+    pub const SHA_512: RsaPssParams<'static> = RsaPssParams::new(hash_algorithm(OID_SHA_512), 64);
+
+    /// Create parameters using the given hash algorithm for both the message
+    /// and MGF1, with the given salt length.
+    pub const fn new(hash: AlgorithmIdentifier<'a>, salt_len: u16) -> Self {
+        Self {
+            hash,
+            mask_gen: MaskGenAlgorithm::mgf1(hash),
+            salt_len,
+            trailer_field: TrailerField::BC,
+        }
+    }
+
+    /// Set the mask generation function.
+    pub const fn with_mask_gen(mut self, mask_gen: MaskGenAlgorithm<'a>) -> Self {
+        self.mask_gen = mask_gen;
+        self
+    }
+
+    /// Set the salt length in bytes.
+    pub const fn with_salt_len(mut self, salt_len: u16) -> Self {
+        self.salt_len = salt_len;
+        self
+    }
+}
+
+impl Default for RsaPssParams<'_> {
+    fn default() -> Self {
+        Self::new(SHA_1_AI, DEFAULT_SALT_LEN)
+    }
+}
+
+impl<'a> DecodeValue<'a> for RsaPssParams<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+            let default = Self::default();
+
+            Ok(Self {
+                hash: decoder
+                    .context_specific(TagNumber::N0, TagMode::Explicit)?
+                    .unwrap_or(default.hash),
+                mask_gen: decoder
+                    .context_specific(TagNumber::N1, TagMode::Explicit)?
+                    .unwrap_or(default.mask_gen),
+                salt_len: decoder
+                    .context_specific(TagNumber::N2, TagMode::Explicit)?
+                    .unwrap_or(default.salt_len),
+                trailer_field: decoder
+                    .context_specific(TagNumber::N3, TagMode::Explicit)?
+                    .unwrap_or(default.trailer_field),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaPssParams<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        let default = Self::default();
+
+        f(&[
+            &context_specific(TagNumber::N0, &self.hash, &default.hash),
+            &context_specific(TagNumber::N1, &self.mask_gen, &default.mask_gen),
+            &context_specific(TagNumber::N2, &self.salt_len, &default.salt_len),
+            &context_specific(TagNumber::N3, &self.trailer_field, &default.trailer_field),
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RsaPssParams<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for RsaPssParams<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        Ok(any.decode_into()?)
+    }
+}
+
+/// PKCS#1 RSAES-OAEP parameters as defined in [RFC 8017 Appendix 2.1].
+///
+/// ASN.1 structure containing the parameters of the RSAES-OAEP encryption
+/// scheme, i.e. the `parameters` of an `AlgorithmIdentifier` whose OID is
+/// `id-RSAES-OAEP`:
+///
+/// ```text
+/// RSAES-OAEP-params ::= SEQUENCE {
+///     hashAlgorithm      [0] HashAlgorithm     DEFAULT sha1,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm  DEFAULT mgf1SHA1,
+///     pSourceAlgorithm   [2] PSourceAlgorithm  DEFAULT pSpecifiedEmpty
+/// }
+/// ```
+///
+/// Fields which are equal to their `DEFAULT` are omitted when encoding, as
+/// required by DER. The [`Default`] impl returns the defaults listed above.
+///
+/// [RFC 8017 Appendix 2.1]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RsaOaepParams<'a> {
+    /// Hash algorithm applied to the label.
+    pub hash: AlgorithmIdentifier<'a>,
+
+    /// Mask generation function.
+    pub mask_gen: MaskGenAlgorithm<'a>,
+
+    /// Source of the label.
+    pub p_source: PSourceAlgorithm<'a>,
+}
+
+impl<'a> RsaOaepParams<'a> {
+    /// SHA-256 with MGF1-SHA-256 and an empty label.
+    pub const SHA_256: RsaOaepParams<'static> = RsaOaepParams::new(hash_algorithm(OID_SHA_256));
+
+    /// SHA-384 with MGF1-SHA-384 and an empty label.
+    pub const SHA_384: RsaOaepParams<'static> = RsaOaepParams::new(hash_algorithm(OID_SHA_384));
+
+    /// SHA-512 with MGF1-SHA-512 and an empty label.
+    pub const SHA_512: RsaOaepParams<'static> = RsaOaepParams::new(hash_algorithm(OID_SHA_512));
+
+    /// Create parameters using the given hash algorithm for both the label
+    /// and MGF1, with an empty label.
+    pub const fn new(hash: AlgorithmIdentifier<'a>) -> Self {
+        Self {
+            hash,
+            mask_gen: MaskGenAlgorithm::mgf1(hash),
+            p_source: PSourceAlgorithm::p_specified(&[]),
+        }
+    }
+
+    /// Set the mask generation function.
+    pub const fn with_mask_gen(mut self, mask_gen: MaskGenAlgorithm<'a>) -> Self {
+        self.mask_gen = mask_gen;
+        self
+    }
+
+    /// Set the label, using `id-pSpecified`.
+    pub const fn with_label(mut self, label: &'a [u8]) -> Self {
+        self.p_source = PSourceAlgorithm::p_specified(label);
+        self
+    }
+}
+
+impl Default for RsaOaepParams<'_> {
+    fn default() -> Self {
+        Self::new(SHA_1_AI)
+    }
+}
+
+impl<'a> DecodeValue<'a> for RsaOaepParams<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+            let default = Self::default();
+
+            Ok(Self {
+                hash: decoder
+                    .context_specific(TagNumber::N0, TagMode::Explicit)?
+                    .unwrap_or(default.hash),
+                mask_gen: decoder
+                    .context_specific(TagNumber::N1, TagMode::Explicit)?
+                    .unwrap_or(default.mask_gen),
+                p_source: decoder
+                    .context_specific(TagNumber::N2, TagMode::Explicit)?
+                    .unwrap_or(default.p_source),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaOaepParams<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        let default = Self::default();
+
+        f(&[
+            &context_specific(TagNumber::N0, &self.hash, &default.hash),
+            &context_specific(TagNumber::N1, &self.mask_gen, &default.mask_gen),
+            &context_specific(TagNumber::N2, &self.p_source, &default.p_source),
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RsaOaepParams<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for RsaOaepParams<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        Ok(any.decode_into()?)
+    }
+}
@@ -2,6 +2,8 @@
 
 #[cfg(feature = "alloc")]
 pub(crate) mod other_prime_info;
+#[cfg(feature = "alloc")]
+pub(crate) mod owned;
 
 use crate::{Error, Result, RsaPublicKey, Version};
 use core::fmt;
@@ -13,6 +15,15 @@ use {self::other_prime_info::OtherPrimeInfo, alloc::vec::Vec, der::SecretDocumen
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
 
+#[cfg(feature = "bigint")]
+use crate::{validation, ValidationError};
+
+#[cfg(feature = "pkcs8")]
+use crate::ALGORITHM_OID;
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use crate::ALGORITHM_ID;
+
 /// PKCS#1 RSA Private Keys as defined in [RFC 8017 Appendix 1.2].
 ///
 /// ASN.1 structure containing a serialized RSA private key:
@@ -87,6 +98,22 @@ impl<'a> RsaPrivateKey<'a> {
             Version::TwoPrime
         }
     }
+
+    /// Check the consistency of this key's components, returning every
+    /// [`ValidationError`] found.
+    ///
+    /// This checks that:
+    ///
+    /// - the modulus `n` is the product of the primes,
+    /// - `e·d ≡ 1 mod λ(n)`,
+    /// - the CRT exponents and coefficients are correct.
+    ///
+    /// The primes themselves are not tested for primality.
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
+    pub fn validate(&self) -> core::result::Result<(), Vec<ValidationError>> {
+        validation::validate(self)
+    }
 }
 
 impl<'a> Decode<'a> for RsaPrivateKey<'a> {
@@ -111,6 +138,16 @@ impl<'a> Decode<'a> for RsaPrivateKey<'a> {
                 return Err(decoder.error(der::ErrorKind::Value { tag: Tag::Integer }));
             }
 
+            // `OtherPrimeInfos` must contain at least one prime.
+            #[cfg(feature = "alloc")]
+            if result
+                .other_prime_infos
+                .as_ref()
+                .map_or(false, Vec::is_empty)
+            {
+                return Err(decoder.error(der::ErrorKind::Value { tag: Tag::Sequence }));
+            }
+
             Ok(result)
         })
     }
@@ -121,6 +158,12 @@ impl<'a> Sequence<'a> for RsaPrivateKey<'a> {
     where
         F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
     {
+        // `OtherPrimeInfos` must contain at least one prime.
+        #[cfg(feature = "alloc")]
+        if self.other_prime_infos.as_ref().map_or(false, Vec::is_empty) {
+            return Err(Tag::Sequence.value_error());
+        }
+
         f(&[
             &self.version(),
             &self.modulus,
@@ -167,6 +210,40 @@ impl fmt::Debug for RsaPrivateKey<'_> {
     }
 }
 
+/// Extract the PKCS#1 key from a PKCS#8 `PrivateKeyInfo`, ensuring its
+/// algorithm is `rsaEncryption`.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<'a> TryFrom<pkcs8::PrivateKeyInfo<'a>> for RsaPrivateKey<'a> {
+    type Error = Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'a>) -> Result<Self> {
+        private_key_info
+            .algorithm
+            .assert_algorithm_oid(ALGORITHM_OID)?;
+        Self::try_from(private_key_info.private_key)
+    }
+}
+
+/// Wrap the PKCS#1 key in a PKCS#8 `PrivateKeyInfo` with the
+/// `rsaEncryption` algorithm.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl pkcs8::EncodePrivateKey for RsaPrivateKey<'_> {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        let private_key = SecretDocument::try_from(self)?;
+
+        let private_key_info = pkcs8::PrivateKeyInfo {
+            algorithm: ALGORITHM_ID,
+            private_key: private_key.as_bytes(),
+            attributes: None,
+            public_key: None,
+        };
+
+        SecretDocument::try_from(private_key_info)
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl TryFrom<RsaPrivateKey<'_>> for SecretDocument {
@@ -0,0 +1,330 @@
+//! Owned PKCS#1 RSA private keys.
+
+use super::other_prime_info::OtherPrimeInfo;
+use crate::{Error, Result, RsaPrivateKey, RsaPublicKeyOwned, Version};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use der::{asn1::UIntBytes, zeroize::Zeroize, Decode, Decoder, Encode, Length, Writer};
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+#[cfg(feature = "pkcs8")]
+use der::SecretDocument;
+
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+/// Owned form of [`RsaPrivateKey`], which doesn't borrow from the document
+/// it was decoded from.
+///
+/// Integers are stored as big endian bytes with leading zeroes stripped, as
+/// returned by [`UIntBytes::as_bytes`]. All components are zeroized on drop.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct RsaPrivateKeyOwned {
+    /// `n`: RSA modulus.
+    pub modulus: Box<[u8]>,
+
+    /// `e`: RSA public exponent.
+    pub public_exponent: Box<[u8]>,
+
+    /// `d`: RSA private exponent.
+    pub private_exponent: Box<[u8]>,
+
+    /// `p`: first prime factor of `n`.
+    pub prime1: Box<[u8]>,
+
+    /// `q`: Second prime factor of `n`.
+    pub prime2: Box<[u8]>,
+
+    /// First exponent: `d mod (p-1)`.
+    pub exponent1: Box<[u8]>,
+
+    /// Second exponent: `d mod (q-1)`.
+    pub exponent2: Box<[u8]>,
+
+    /// CRT coefficient: `(inverse of q) mod p`.
+    pub coefficient: Box<[u8]>,
+
+    /// Additional primes `r_3`, ..., `r_u`, in order, if this is a multi-prime
+    /// RSA key (i.e. `version` is `multi`).
+    pub other_prime_infos: Option<Vec<OtherPrimeInfoOwned>>,
+}
+
+impl RsaPrivateKeyOwned {
+    /// Get the public key that corresponds to this [`RsaPrivateKeyOwned`].
+    pub fn public_key(&self) -> RsaPublicKeyOwned {
+        RsaPublicKeyOwned {
+            modulus: self.modulus.clone(),
+            public_exponent: self.public_exponent.clone(),
+        }
+    }
+
+    /// Get the [`Version`] for this key.
+    ///
+    /// Determined by the presence or absence of the
+    /// [`RsaPrivateKeyOwned::other_prime_infos`] field.
+    pub fn version(&self) -> Version {
+        if self.other_prime_infos.is_some() {
+            Version::Multi
+        } else {
+            Version::TwoPrime
+        }
+    }
+
+    /// Borrow this key as an [`RsaPrivateKey`].
+    fn borrow(&self) -> der::Result<RsaPrivateKey<'_>> {
+        let other_prime_infos = match &self.other_prime_infos {
+            Some(infos) => Some(
+                infos
+                    .iter()
+                    .map(OtherPrimeInfoOwned::borrow)
+                    .collect::<der::Result<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(RsaPrivateKey {
+            modulus: UIntBytes::new(&self.modulus)?,
+            public_exponent: UIntBytes::new(&self.public_exponent)?,
+            private_exponent: UIntBytes::new(&self.private_exponent)?,
+            prime1: UIntBytes::new(&self.prime1)?,
+            prime2: UIntBytes::new(&self.prime2)?,
+            exponent1: UIntBytes::new(&self.exponent1)?,
+            exponent2: UIntBytes::new(&self.exponent2)?,
+            coefficient: UIntBytes::new(&self.coefficient)?,
+            other_prime_infos,
+        })
+    }
+}
+
+impl From<RsaPrivateKey<'_>> for RsaPrivateKeyOwned {
+    fn from(private_key: RsaPrivateKey<'_>) -> RsaPrivateKeyOwned {
+        RsaPrivateKeyOwned::from(&private_key)
+    }
+}
+
+impl From<&RsaPrivateKey<'_>> for RsaPrivateKeyOwned {
+    fn from(private_key: &RsaPrivateKey<'_>) -> RsaPrivateKeyOwned {
+        RsaPrivateKeyOwned {
+            modulus: private_key.modulus.as_bytes().into(),
+            public_exponent: private_key.public_exponent.as_bytes().into(),
+            private_exponent: private_key.private_exponent.as_bytes().into(),
+            prime1: private_key.prime1.as_bytes().into(),
+            prime2: private_key.prime2.as_bytes().into(),
+            exponent1: private_key.exponent1.as_bytes().into(),
+            exponent2: private_key.exponent2.as_bytes().into(),
+            coefficient: private_key.coefficient.as_bytes().into(),
+            other_prime_infos: private_key
+                .other_prime_infos
+                .as_ref()
+                .map(|infos| infos.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a RsaPrivateKeyOwned> for RsaPrivateKey<'a> {
+    type Error = Error;
+
+    fn try_from(private_key: &'a RsaPrivateKeyOwned) -> Result<RsaPrivateKey<'a>> {
+        Ok(private_key.borrow()?)
+    }
+}
+
+impl<'a> Decode<'a> for RsaPrivateKeyOwned {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        RsaPrivateKey::decode(decoder).map(Into::into)
+    }
+}
+
+impl Encode for RsaPrivateKeyOwned {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.borrow()?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut dyn Writer) -> der::Result<()> {
+        self.borrow()?.encode(encoder)
+    }
+}
+
+impl TryFrom<&[u8]> for RsaPrivateKeyOwned {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl From<RsaPrivateKeyOwned> for RsaPublicKeyOwned {
+    fn from(private_key: RsaPrivateKeyOwned) -> RsaPublicKeyOwned {
+        private_key.public_key()
+    }
+}
+
+impl From<&RsaPrivateKeyOwned> for RsaPublicKeyOwned {
+    fn from(private_key: &RsaPrivateKeyOwned) -> RsaPublicKeyOwned {
+        private_key.public_key()
+    }
+}
+
+impl Drop for RsaPrivateKeyOwned {
+    fn drop(&mut self) {
+        self.private_exponent.zeroize();
+        self.prime1.zeroize();
+        self.prime2.zeroize();
+        self.exponent1.zeroize();
+        self.exponent2.zeroize();
+        self.coefficient.zeroize();
+    }
+}
+
+impl fmt::Debug for RsaPrivateKeyOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaPrivateKeyOwned")
+            .field("version", &self.version())
+            .field("modulus", &self.modulus)
+            .field("public_exponent", &self.public_exponent)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl ConstantTimeEq for RsaPrivateKeyOwned {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // NOTE: public fields are not compared in constant time
+        let public_fields_eq =
+            self.modulus == other.modulus && self.public_exponent == other.public_exponent;
+
+        let other_prime_infos_eq = match (&self.other_prime_infos, &other.other_prime_infos) {
+            (Some(a), Some(b)) if a.len() == b.len() => a
+                .iter()
+                .zip(b.iter())
+                .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b)),
+            (None, None) => Choice::from(1),
+            _ => Choice::from(0),
+        };
+
+        self.private_exponent.ct_eq(&other.private_exponent)
+            & self.prime1.ct_eq(&other.prime1)
+            & self.prime2.ct_eq(&other.prime2)
+            & self.exponent1.ct_eq(&other.exponent1)
+            & self.exponent2.ct_eq(&other.exponent2)
+            & self.coefficient.ct_eq(&other.coefficient)
+            & other_prime_infos_eq
+            & Choice::from(public_fields_eq as u8)
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl Eq for RsaPrivateKeyOwned {}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl PartialEq for RsaPrivateKeyOwned {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for RsaPrivateKeyOwned {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        Ok(RsaPrivateKey::try_from(private_key_info)?.into())
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::DecodePrivateKey for RsaPrivateKeyOwned {}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::EncodePrivateKey for RsaPrivateKeyOwned {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        self.borrow()?.to_pkcs8_der()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for RsaPrivateKeyOwned {
+    const PEM_LABEL: &'static str = "RSA PRIVATE KEY";
+}
+
+/// Owned form of [`OtherPrimeInfo`].
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct OtherPrimeInfoOwned {
+    /// Prime factor `r_i` of `n`, where `i` >= 3.
+    pub prime: Box<[u8]>,
+
+    /// Exponent: `d_i = d mod (r_i - 1)`.
+    pub exponent: Box<[u8]>,
+
+    /// CRT coefficient: `t_i = (r_1 * r_2 * ... * r_(i-1))^(-1) mod r_i`.
+    pub coefficient: Box<[u8]>,
+}
+
+impl OtherPrimeInfoOwned {
+    /// Borrow this prime info as an [`OtherPrimeInfo`].
+    fn borrow(&self) -> der::Result<OtherPrimeInfo<'_>> {
+        Ok(OtherPrimeInfo {
+            prime: UIntBytes::new(&self.prime)?,
+            exponent: UIntBytes::new(&self.exponent)?,
+            coefficient: UIntBytes::new(&self.coefficient)?,
+        })
+    }
+}
+
+impl From<&OtherPrimeInfo<'_>> for OtherPrimeInfoOwned {
+    fn from(info: &OtherPrimeInfo<'_>) -> OtherPrimeInfoOwned {
+        OtherPrimeInfoOwned {
+            prime: info.prime.as_bytes().into(),
+            exponent: info.exponent.as_bytes().into(),
+            coefficient: info.coefficient.as_bytes().into(),
+        }
+    }
+}
+
+impl Drop for OtherPrimeInfoOwned {
+    fn drop(&mut self) {
+        self.prime.zeroize();
+        self.exponent.zeroize();
+        self.coefficient.zeroize();
+    }
+}
+
+impl fmt::Debug for OtherPrimeInfoOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OtherPrimeInfoOwned")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl ConstantTimeEq for OtherPrimeInfoOwned {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.prime.ct_eq(&other.prime)
+            & self.exponent.ct_eq(&other.exponent)
+            & self.coefficient.ct_eq(&other.coefficient)
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl Eq for OtherPrimeInfoOwned {}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl PartialEq for OtherPrimeInfoOwned {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
@@ -1,5 +1,8 @@
 //! PKCS#1 RSA Public Keys.
 
+#[cfg(feature = "alloc")]
+pub(crate) mod owned;
+
 use crate::{Error, Result};
 use der::{asn1::UIntBytes, Decode, Decoder, Encode, Sequence};
 
@@ -9,6 +12,12 @@ use der::Document;
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
 
+#[cfg(feature = "pkcs8")]
+use crate::ALGORITHM_OID;
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use crate::ALGORITHM_ID;
+
 /// PKCS#1 RSA Public Keys as defined in [RFC 8017 Appendix 1.1].
 ///
 /// ASN.1 structure containing a serialized RSA public key:
@@ -58,6 +67,35 @@ impl<'a> TryFrom<&'a [u8]> for RsaPublicKey<'a> {
     }
 }
 
+/// Extract the PKCS#1 key from a `SubjectPublicKeyInfo`, ensuring its
+/// algorithm is `rsaEncryption`.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<'a> TryFrom<pkcs8::SubjectPublicKeyInfo<'a>> for RsaPublicKey<'a> {
+    type Error = Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfo<'a>) -> Result<Self> {
+        spki.algorithm.assert_algorithm_oid(ALGORITHM_OID)?;
+        Self::try_from(spki.subject_public_key)
+    }
+}
+
+/// Wrap the PKCS#1 key in a `SubjectPublicKeyInfo` with the `rsaEncryption`
+/// algorithm.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl pkcs8::EncodePublicKey for RsaPublicKey<'_> {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let public_key = Document::try_from(self)?;
+
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm: ALGORITHM_ID,
+            subject_public_key: public_key.as_bytes(),
+        }
+        .try_into()
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl TryFrom<RsaPublicKey<'_>> for Document {
@@ -0,0 +1,111 @@
+//! Owned PKCS#1 RSA public keys.
+
+use crate::{Error, Result, RsaPublicKey};
+use alloc::boxed::Box;
+use der::{asn1::UIntBytes, Decode, Decoder, Encode, Length, Writer};
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+#[cfg(feature = "pkcs8")]
+use der::Document;
+
+/// Owned form of [`RsaPublicKey`], which doesn't borrow from the document
+/// it was decoded from.
+///
+/// Integers are stored as big endian bytes with leading zeroes stripped, as
+/// returned by [`UIntBytes::as_bytes`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct RsaPublicKeyOwned {
+    /// `n`: RSA modulus
+    pub modulus: Box<[u8]>,
+
+    /// `e`: RSA public exponent
+    pub public_exponent: Box<[u8]>,
+}
+
+impl RsaPublicKeyOwned {
+    /// Borrow this key as an [`RsaPublicKey`].
+    fn borrow(&self) -> der::Result<RsaPublicKey<'_>> {
+        Ok(RsaPublicKey {
+            modulus: UIntBytes::new(&self.modulus)?,
+            public_exponent: UIntBytes::new(&self.public_exponent)?,
+        })
+    }
+}
+
+impl From<RsaPublicKey<'_>> for RsaPublicKeyOwned {
+    fn from(public_key: RsaPublicKey<'_>) -> RsaPublicKeyOwned {
+        RsaPublicKeyOwned {
+            modulus: public_key.modulus.as_bytes().into(),
+            public_exponent: public_key.public_exponent.as_bytes().into(),
+        }
+    }
+}
+
+impl From<&RsaPublicKey<'_>> for RsaPublicKeyOwned {
+    fn from(public_key: &RsaPublicKey<'_>) -> RsaPublicKeyOwned {
+        RsaPublicKeyOwned::from(*public_key)
+    }
+}
+
+impl<'a> TryFrom<&'a RsaPublicKeyOwned> for RsaPublicKey<'a> {
+    type Error = Error;
+
+    fn try_from(public_key: &'a RsaPublicKeyOwned) -> Result<RsaPublicKey<'a>> {
+        Ok(public_key.borrow()?)
+    }
+}
+
+impl<'a> Decode<'a> for RsaPublicKeyOwned {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        RsaPublicKey::decode(decoder).map(Into::into)
+    }
+}
+
+impl Encode for RsaPublicKeyOwned {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.borrow()?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut dyn Writer) -> der::Result<()> {
+        self.borrow()?.encode(encoder)
+    }
+}
+
+impl TryFrom<&[u8]> for RsaPublicKeyOwned {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<pkcs8::SubjectPublicKeyInfo<'_>> for RsaPublicKeyOwned {
+    type Error = spki::Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> spki::Result<Self> {
+        Ok(RsaPublicKey::try_from(spki)?.into())
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::DecodePublicKey for RsaPublicKeyOwned {}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::EncodePublicKey for RsaPublicKeyOwned {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        self.borrow()?.to_public_key_der()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for RsaPublicKeyOwned {
+    const PEM_LABEL: &'static str = "RSA PUBLIC KEY";
+}
@@ -0,0 +1,165 @@
+//! RSA private key consistency validation.
+
+use crate::RsaPrivateKey;
+use alloc::vec::Vec;
+use core::fmt;
+use der::{asn1::UIntBytes, zeroize::Zeroizing};
+use num_bigint::BigUint;
+
+/// Integer which is zeroized on drop.
+///
+/// All private components, and any values derived from them, are held in
+/// this type.
+type Secret = Zeroizing<BigUint>;
+
+/// Inconsistency found when validating an [`RsaPrivateKey`].
+///
+/// Primes are numbered from `1` as in [RFC 8017 Section 3.2], i.e. `1` is
+/// `prime1` (`p`), `2` is `prime2` (`q`), and `3` onwards are the primes in
+/// `other_prime_infos`.
+///
+/// [RFC 8017 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc8017#section-3.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// Prime `r_i` is less than 2.
+    Prime {
+        /// Index of the prime.
+        index: usize,
+    },
+
+    /// Modulus `n` is not the product of the primes.
+    Modulus,
+
+    /// Private exponent `d` is not an inverse of `e` modulo `λ(n)`.
+    PrivateExponent,
+
+    /// CRT exponent `d_i` is not `d mod (r_i - 1)`.
+    Exponent {
+        /// Index of the prime the exponent belongs to.
+        index: usize,
+    },
+
+    /// CRT coefficient is not the expected modular inverse, i.e. `q^(-1) mod p`
+    /// for index `2`, or `(r_1 * ... * r_(i-1))^(-1) mod r_i` otherwise.
+    Coefficient {
+        /// Index of the prime the coefficient belongs to.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Prime { index } => write!(f, "prime {} is less than 2", index),
+            ValidationError::Modulus => f.write_str("modulus is not the product of the primes"),
+            ValidationError::PrivateExponent => {
+                f.write_str("private exponent is not an inverse of the public exponent")
+            }
+            ValidationError::Exponent { index } => {
+                write!(f, "CRT exponent for prime {} is incorrect", index)
+            }
+            ValidationError::Coefficient { index } => {
+                write!(f, "CRT coefficient for prime {} is incorrect", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// Check the consistency of the given private key's components.
+pub(crate) fn validate(key: &RsaPrivateKey<'_>) -> Result<(), Vec<ValidationError>> {
+    let one = BigUint::from(1u8);
+    let mut errors = Vec::new();
+
+    // `(r_i, d_i)` for each prime, in order. The capacity is reserved up
+    // front so that the values are never moved by a reallocation.
+    let other_prime_infos = key.other_prime_infos.as_deref().unwrap_or_default();
+    let mut primes = Vec::with_capacity(2 + other_prime_infos.len());
+    primes.push((to_uint(key.prime1), to_uint(key.exponent1)));
+    primes.push((to_uint(key.prime2), to_uint(key.exponent2)));
+    primes.extend(
+        other_prime_infos
+            .iter()
+            .map(|info| (to_uint(info.prime), to_uint(info.exponent))),
+    );
+
+    for (i, (prime, _)) in primes.iter().enumerate() {
+        if **prime <= one {
+            errors.push(ValidationError::Prime { index: i + 1 });
+        }
+    }
+
+    // The remaining checks are meaningless (and would underflow) otherwise
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let modulus = primes.iter().fold(secret(one.clone()), |acc, (prime, _)| {
+        secret(&*acc * &**prime)
+    });
+
+    if *modulus != BigUint::from_bytes_be(key.modulus.as_bytes()) {
+        errors.push(ValidationError::Modulus);
+    }
+
+    // `(r_i - 1)` for each prime
+    let totients = primes
+        .iter()
+        .map(|(prime, _)| secret(&**prime - &one))
+        .collect::<Vec<_>>();
+
+    // `e·d ≡ 1 mod λ(n)` iff `e·d ≡ 1 mod (r_i - 1)` for each prime, as
+    // `λ(n)` is the least common multiple of the `r_i - 1`
+    let d = to_uint(key.private_exponent);
+    let ed = secret(BigUint::from_bytes_be(key.public_exponent.as_bytes()) * &*d);
+
+    if !totients.iter().all(|m| *secret(&*ed % &**m) == &one % &**m) {
+        errors.push(ValidationError::PrivateExponent);
+    }
+
+    for (i, ((_, exponent), m)) in primes.iter().zip(&totients).enumerate() {
+        if **exponent != *secret(&*d % &**m) {
+            errors.push(ValidationError::Exponent { index: i + 1 });
+        }
+    }
+
+    let (p, q) = (&primes[0].0, &primes[1].0);
+
+    if !is_inverse(&to_uint(key.coefficient), q, p) {
+        errors.push(ValidationError::Coefficient { index: 2 });
+    }
+
+    let mut product = secret(&**p * &**q);
+
+    for (i, (info, (prime, _))) in other_prime_infos.iter().zip(&primes[2..]).enumerate() {
+        if !is_inverse(&to_uint(info.coefficient), &product, prime) {
+            errors.push(ValidationError::Coefficient { index: i + 3 });
+        }
+
+        product = secret(&*product * &**prime);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Is `x` the inverse of `a` modulo `m`?
+fn is_inverse(x: &BigUint, a: &BigUint, m: &BigUint) -> bool {
+    x < m && *secret(&*secret(x * a) % m) == BigUint::from(1u8)
+}
+
+/// Convert a big endian private component to a [`Secret`].
+fn to_uint(bytes: UIntBytes<'_>) -> Secret {
+    secret(BigUint::from_bytes_be(bytes.as_bytes()))
+}
+
+/// Wrap an intermediate value so that it's zeroized on drop.
+fn secret(n: BigUint) -> Secret {
+    Zeroizing::new(n)
+}
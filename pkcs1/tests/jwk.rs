@@ -0,0 +1,148 @@
+//! JSON Web Key (JWK) tests.
+
+#![cfg(feature = "jwk")]
+
+use der::{Document, SecretDocument};
+use pkcs1::{
+    DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey, Error, Jwk,
+    Result,
+};
+
+/// PKCS#1 `RsaPrivateKey` encoded as ASN.1 DER
+const RSA_2048_PRIV_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// PKCS#1 `RsaPublicKey` encoded as ASN.1 DER
+const RSA_2048_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// RSA-2048 PKCS#1 private key with 3 primes encoded as ASN.1 DER
+const RSA_2048_MULTI_PRIME_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv-3prime.der");
+
+/// RSA private key encoded as a JWK.
+///
+/// Generated from `rsa2048-priv.der` using the Python `cryptography` package.
+const RSA_2048_PRIV_JWK_EXAMPLE: &str = include_str!("examples/rsa2048-priv.jwk");
+
+/// RSA public key encoded as a JWK.
+const RSA_2048_PUB_JWK_EXAMPLE: &str = include_str!("examples/rsa2048-pub.jwk");
+
+/// Mock RSA private key type for testing trait impls against.
+pub struct MockPrivateKey(Vec<u8>);
+
+impl DecodeRsaPrivateKey for MockPrivateKey {
+    fn from_pkcs1_der(bytes: &[u8]) -> Result<MockPrivateKey> {
+        Ok(MockPrivateKey(bytes.to_vec()))
+    }
+}
+
+impl EncodeRsaPrivateKey for MockPrivateKey {
+    fn to_pkcs1_der(&self) -> Result<SecretDocument> {
+        Ok(SecretDocument::try_from(self.0.as_slice())?)
+    }
+}
+
+/// Mock RSA public key type for testing trait impls against.
+pub struct MockPublicKey(Vec<u8>);
+
+impl DecodeRsaPublicKey for MockPublicKey {
+    fn from_pkcs1_der(bytes: &[u8]) -> Result<MockPublicKey> {
+        Ok(MockPublicKey(bytes.to_vec()))
+    }
+}
+
+impl EncodeRsaPublicKey for MockPublicKey {
+    fn to_pkcs1_der(&self) -> Result<Document> {
+        Ok(Document::try_from(self.0.as_slice())?)
+    }
+}
+
+fn parse(json: &str) -> Jwk {
+    serde_json::from_str(json).unwrap()
+}
+
+fn to_json(jwk: &Jwk) -> String {
+    serde_json::to_string(jwk).unwrap()
+}
+
+#[test]
+fn decode_private_key_jwk() {
+    let key = MockPrivateKey::from_jwk(&parse(RSA_2048_PRIV_JWK_EXAMPLE)).unwrap();
+    assert_eq!(key.0, RSA_2048_PRIV_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_private_key_jwk() {
+    let jwk = MockPrivateKey(RSA_2048_PRIV_DER_EXAMPLE.to_vec())
+        .to_jwk()
+        .unwrap();
+    assert_eq!(to_json(&jwk), RSA_2048_PRIV_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_public_key_jwk() {
+    let key = MockPublicKey::from_jwk(&parse(RSA_2048_PUB_JWK_EXAMPLE)).unwrap();
+    assert_eq!(key.0, RSA_2048_PUB_DER_EXAMPLE);
+
+    // Public keys can also be extracted from private key JWKs
+    let key = MockPublicKey::from_jwk(&parse(RSA_2048_PRIV_JWK_EXAMPLE)).unwrap();
+    assert_eq!(key.0, RSA_2048_PUB_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_public_key_jwk() {
+    let jwk = MockPublicKey(RSA_2048_PUB_DER_EXAMPLE.to_vec())
+        .to_jwk()
+        .unwrap();
+    assert_eq!(to_json(&jwk), RSA_2048_PUB_JWK_EXAMPLE);
+}
+
+#[test]
+fn multi_prime_jwk_round_trip() {
+    let jwk = MockPrivateKey(RSA_2048_MULTI_PRIME_DER_EXAMPLE.to_vec())
+        .to_jwk()
+        .unwrap();
+    assert!(to_json(&jwk).contains(r#""oth":[{"r":"#));
+
+    let key = MockPrivateKey::from_jwk(&jwk).unwrap();
+    assert_eq!(key.0, RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+}
+
+#[test]
+fn ignore_unknown_members() {
+    let jwk = RSA_2048_PUB_JWK_EXAMPLE.replace('{', r#"{"kid":"example","use":"sig","#);
+    let key = MockPublicKey::from_jwk(&parse(&jwk)).unwrap();
+    assert_eq!(key.0, RSA_2048_PUB_DER_EXAMPLE);
+}
+
+#[test]
+fn reject_invalid_jwks() {
+    // Public key JWKs lack the private key members
+    assert_eq!(
+        MockPrivateKey::from_jwk(&parse(RSA_2048_PUB_JWK_EXAMPLE)).err(),
+        Some(Error::Jwk)
+    );
+
+    let jwk = RSA_2048_PUB_JWK_EXAMPLE.replace(r#""kty":"RSA""#, r#""kty":"EC""#);
+    assert_eq!(
+        MockPublicKey::from_jwk(&parse(&jwk)).err(),
+        Some(Error::Jwk)
+    );
+
+    let jwk = RSA_2048_PUB_JWK_EXAMPLE.replace(r#""e":"AQAB""#, r#""e":"AQAB=""#);
+    assert_eq!(
+        MockPublicKey::from_jwk(&parse(&jwk)).err(),
+        Some(Error::Jwk)
+    );
+
+    // Empty `oth`
+    let jwk = RSA_2048_PRIV_JWK_EXAMPLE.replace('{', r#"{"oth":[],"#);
+    assert_eq!(
+        MockPrivateKey::from_jwk(&parse(&jwk)).err(),
+        Some(Error::Jwk)
+    );
+}
+
+#[test]
+fn reject_duplicate_members() {
+    let jwk = RSA_2048_PUB_JWK_EXAMPLE.replace('{', r#"{"e":"AQAB","#);
+    assert!(serde_json::from_str::<Jwk>(&jwk).is_err());
+}
@@ -0,0 +1,149 @@
+//! PKCS#1 algorithm params tests
+
+use der::{asn1::Any, Encode};
+use hex_literal::hex;
+use pkcs1::{AlgorithmIdentifier, ObjectIdentifier, RsaOaepParams, RsaPssParams, TrailerField};
+
+/// RSASSA-PSS parameters with SHA-256, MGF1-SHA-256 and a 32-byte salt, as
+/// generated by OpenSSL.
+const RSA_PSS_PARAMS_SHA256: &[u8] = &hex!("3034a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a203020120");
+
+/// RSASSA-PSS parameters with all fields set to their defaults.
+const RSA_PSS_PARAMS_DEFAULT: &[u8] = &hex!("3000");
+
+/// RSASSA-PSS parameters with SHA-1 (explicitly encoded) and a 64-byte salt.
+const RSA_PSS_PARAMS_SHA1_SALT64: &[u8] = &hex!("3012a00b300906052b0e03021a0500a203020140");
+
+/// RSAES-OAEP parameters with SHA-256, MGF1-SHA-256 and an empty label, as
+/// generated by OpenSSL.
+const RSA_OAEP_PARAMS_SHA256: &[u8] = &hex!("302fa00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500");
+
+/// RSAES-OAEP parameters with SHA-256, MGF1-SHA-256 and the label `abc`.
+const RSA_OAEP_PARAMS_SHA256_LABEL: &[u8] = &hex!("3043a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a212301006092a864886f70d0101090403616263");
+
+#[test]
+fn decode_pss_params_sha256() {
+    let params = RsaPssParams::try_from(RSA_PSS_PARAMS_SHA256).unwrap();
+    assert_eq!(
+        params.hash.oid,
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+    );
+    assert_eq!(params.hash.parameters, Some(Any::NULL));
+    assert!(params.mask_gen.is_mgf1());
+    assert_eq!(params.mask_gen.hash, params.hash);
+    assert_eq!(params.salt_len, 32);
+    assert_eq!(params.trailer_field, TrailerField::BC);
+    assert_eq!(params, RsaPssParams::SHA_256);
+}
+
+#[test]
+fn decode_pss_params_default() {
+    let params = RsaPssParams::try_from(RSA_PSS_PARAMS_DEFAULT).unwrap();
+    assert_eq!(
+        params.hash.oid,
+        ObjectIdentifier::new_unwrap("1.3.14.3.2.26")
+    );
+    assert!(params.mask_gen.is_mgf1());
+    assert_eq!(params.mask_gen.hash, params.hash);
+    assert_eq!(params.salt_len, 20);
+    assert_eq!(params, RsaPssParams::default());
+}
+
+#[test]
+fn decode_pss_params_partial() {
+    let params = RsaPssParams::try_from(RSA_PSS_PARAMS_SHA1_SALT64).unwrap();
+    assert_eq!(params, RsaPssParams::default().with_salt_len(64));
+}
+
+#[test]
+fn encode_pss_params() {
+    let mut buf = [0u8; 64];
+    assert_eq!(
+        RsaPssParams::SHA_256.encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMS_SHA256
+    );
+    assert_eq!(
+        RsaPssParams::default().encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMS_DEFAULT
+    );
+
+    // Fields equal to their `DEFAULT` are omitted
+    assert_eq!(
+        RsaPssParams::default()
+            .with_salt_len(64)
+            .encode_to_slice(&mut buf)
+            .unwrap(),
+        &hex!("3005a203020140")
+    );
+}
+
+#[test]
+fn decode_pss_params_from_algorithm_identifier() {
+    // `AlgorithmIdentifier` for `id-RSASSA-PSS` with SHA-256 parameters
+    let mut buf = [0u8; 128];
+    let algorithm = AlgorithmIdentifier {
+        oid: ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10"),
+        parameters: Some(Any::try_from(RSA_PSS_PARAMS_SHA256).unwrap()),
+    };
+    let der = algorithm.encode_to_slice(&mut buf).unwrap();
+
+    let algorithm = AlgorithmIdentifier::try_from(der).unwrap();
+    let params = RsaPssParams::try_from(algorithm.parameters_any().unwrap()).unwrap();
+    assert_eq!(params, RsaPssParams::SHA_256);
+}
+
+#[test]
+fn reject_invalid_trailer_field() {
+    assert!(RsaPssParams::try_from(&hex!("3005a303020102")[..]).is_err());
+}
+
+#[test]
+fn decode_oaep_params_sha256() {
+    let params = RsaOaepParams::try_from(RSA_OAEP_PARAMS_SHA256).unwrap();
+    assert_eq!(
+        params.hash.oid,
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1")
+    );
+    assert!(params.mask_gen.is_mgf1());
+    assert_eq!(params.mask_gen.hash, params.hash);
+    assert!(params.p_source.is_p_specified());
+    assert_eq!(params.p_source.label, &[]);
+    assert_eq!(params, RsaOaepParams::SHA_256);
+
+    let params = RsaOaepParams::try_from(RSA_OAEP_PARAMS_SHA256_LABEL).unwrap();
+    assert_eq!(params.p_source.label, b"abc");
+    assert_eq!(params, RsaOaepParams::SHA_256.with_label(b"abc"));
+}
+
+#[test]
+fn decode_oaep_params_default() {
+    let params = RsaOaepParams::try_from(&hex!("3000")[..]).unwrap();
+    assert_eq!(
+        params.hash.oid,
+        ObjectIdentifier::new_unwrap("1.3.14.3.2.26")
+    );
+    assert_eq!(params.mask_gen.hash, params.hash);
+    assert!(params.p_source.is_p_specified());
+    assert_eq!(params.p_source.label, &[]);
+    assert_eq!(params, RsaOaepParams::default());
+}
+
+#[test]
+fn encode_oaep_params() {
+    let mut buf = [0u8; 128];
+    assert_eq!(
+        RsaOaepParams::SHA_256.encode_to_slice(&mut buf).unwrap(),
+        RSA_OAEP_PARAMS_SHA256
+    );
+    assert_eq!(
+        RsaOaepParams::SHA_256
+            .with_label(b"abc")
+            .encode_to_slice(&mut buf)
+            .unwrap(),
+        RSA_OAEP_PARAMS_SHA256_LABEL
+    );
+    assert_eq!(
+        RsaOaepParams::default().encode_to_slice(&mut buf).unwrap(),
+        &hex!("3000")
+    );
+}
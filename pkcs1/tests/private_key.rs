@@ -82,6 +82,43 @@ fn decode_rsa2048_multi_prime_der() {
     assert_eq!(other_prime_infos[0].coefficient.as_bytes(), hex!("39EA226CABFB317E41A5593B9168D1A0124993B45D9CD14A22BD1557CDCB43D28024AC26ED2C8530B53E9B93A878F428807C5282EBB811399F913017CDF2149013D80CDF73F609D6C692475EB7A123D0E93E6A60FC"));
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_rsa2048_der() {
+    use der::Encode;
+
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_DER_EXAMPLE);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_rsa2048_multi_prime_der() {
+    use der::Encode;
+
+    let key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+
+    // Multi-prime keys must have at least one additional prime
+    let mut key = key;
+    key.other_prime_infos = Some(Vec::new());
+    assert!(key.to_vec().is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reject_empty_other_prime_infos() {
+    // Two-prime key modified to have `version` of `multi` and an empty
+    // `otherPrimeInfos` field
+    let mut der = RSA_2048_DER_EXAMPLE.to_vec();
+    let len = u16::from_be_bytes([der[2], der[3]]) + 2;
+    der[2..4].copy_from_slice(&len.to_be_bytes());
+    der[6] = 1;
+    der.extend_from_slice(&[0x30, 0x00]);
+
+    assert!(RsaPrivateKey::try_from(der.as_slice()).is_err());
+}
+
 #[test]
 fn private_key_to_public_key() {
     let private_key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -92,3 +129,150 @@ fn private_key_to_public_key() {
     assert_eq!(public_key.modulus.as_bytes(), hex!("B6C42C515F10A6AAF282C63EDBE24243A170F3FA2633BD4833637F47CA4F6F36E03A5D29EFC3191AC80F390D874B39E30F414FCEC1FCA0ED81E547EDC2CD382C76F61C9018973DB9FA537972A7C701F6B77E0982DFC15FC01927EE5E7CD94B4F599FF07013A7C8281BDF22DCBC9AD7CABB7C4311C982F58EDB7213AD4558B332266D743AED8192D1884CADB8B14739A8DADA66DC970806D9C7AC450CB13D0D7C575FB198534FC61BC41BC0F0574E0E0130C7BBBFBDFDC9F6A6E2E3E2AFF1CBEAC89BA57884528D55CFB08327A1E8C89F4E003CF2888E933241D9D695BCBBACDC90B44E3E095FA37058EA25B13F5E295CBEAC6DE838AB8C50AF61E298975B872F"));
     assert_eq!(public_key.public_exponent.as_bytes(), hex!("010001"));
 }
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[test]
+fn pkcs8_round_trip() {
+    use der::Encode;
+    use pkcs1::pkcs8::{EncodePrivateKey, PrivateKeyInfo};
+
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let pkcs8_doc = key.to_pkcs8_der().unwrap();
+    let private_key_info = PrivateKeyInfo::try_from(pkcs8_doc.as_bytes()).unwrap();
+    assert_eq!(private_key_info.algorithm, pkcs1::ALGORITHM_ID);
+    assert_eq!(private_key_info.private_key, RSA_2048_DER_EXAMPLE);
+
+    let key = RsaPrivateKey::try_from(private_key_info).unwrap();
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_DER_EXAMPLE);
+}
+
+#[cfg(feature = "pkcs8")]
+#[test]
+fn pkcs8_wrong_algorithm() {
+    let mut private_key_info = pkcs1::pkcs8::PrivateKeyInfo {
+        algorithm: pkcs1::ALGORITHM_ID,
+        private_key: RSA_2048_DER_EXAMPLE,
+        attributes: None,
+        public_key: None,
+    };
+    assert!(RsaPrivateKey::try_from(private_key_info.clone()).is_ok());
+
+    // id-ecPublicKey
+    private_key_info.algorithm.oid = "1.2.840.10045.2.1".parse().unwrap();
+    assert!(RsaPrivateKey::try_from(private_key_info).is_err());
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn validate_rsa_keys() {
+    for der in [
+        RSA_2048_DER_EXAMPLE,
+        RSA_4096_DER_EXAMPLE,
+        RSA_2048_MULTI_PRIME_DER_EXAMPLE,
+    ] {
+        let key = RsaPrivateKey::try_from(der).unwrap();
+        assert_eq!(key.validate(), Ok(()));
+    }
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn validate_inconsistent_keys() {
+    use pkcs1::{UIntBytes, ValidationError};
+
+    let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let other_key = RsaPrivateKey::try_from(RSA_4096_DER_EXAMPLE).unwrap();
+
+    let mut bad_key = key.clone();
+    bad_key.modulus = other_key.modulus;
+    assert_eq!(bad_key.validate(), Err(vec![ValidationError::Modulus]));
+
+    let mut bad_key = key.clone();
+    bad_key.public_exponent = UIntBytes::new(&[3]).unwrap();
+    assert_eq!(
+        bad_key.validate(),
+        Err(vec![ValidationError::PrivateExponent])
+    );
+
+    let mut bad_key = key.clone();
+    bad_key.exponent2 = key.exponent1;
+    bad_key.coefficient = key.exponent1;
+    assert_eq!(
+        bad_key.validate(),
+        Err(vec![
+            ValidationError::Exponent { index: 2 },
+            ValidationError::Coefficient { index: 2 }
+        ])
+    );
+
+    let mut bad_key = key.clone();
+    bad_key.prime1 = UIntBytes::new(&[1]).unwrap();
+    assert_eq!(
+        bad_key.validate(),
+        Err(vec![ValidationError::Prime { index: 1 }])
+    );
+
+    let mut bad_key = RsaPrivateKey::try_from(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let other_prime_infos = bad_key.other_prime_infos.as_mut().unwrap();
+    other_prime_infos[0].coefficient = key.coefficient;
+    assert_eq!(
+        bad_key.validate(),
+        Err(vec![ValidationError::Coefficient { index: 3 }])
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_rsa2048_multi_prime_der() {
+    use der::{Decode, Encode};
+    use pkcs1::{RsaPrivateKeyOwned, RsaPublicKeyOwned};
+
+    // Decode from a temporary buffer which is dropped before the key is used
+    let key = {
+        let der = RSA_2048_MULTI_PRIME_DER_EXAMPLE.to_vec();
+        RsaPrivateKeyOwned::from_der(&der).unwrap()
+    };
+    assert_eq!(key.version(), Version::Multi);
+    assert_eq!(key.other_prime_infos.as_ref().unwrap().len(), 1);
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+
+    let borrowed = RsaPrivateKey::try_from(&key).unwrap();
+    assert_eq!(borrowed.to_vec().unwrap(), RSA_2048_MULTI_PRIME_DER_EXAMPLE);
+    assert_eq!(
+        RsaPrivateKeyOwned::from(borrowed).to_vec().unwrap(),
+        RSA_2048_MULTI_PRIME_DER_EXAMPLE
+    );
+
+    assert_eq!(
+        key.public_key(),
+        RsaPublicKeyOwned::from(RsaPrivateKey::try_from(&key).unwrap().public_key())
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_via_traits() {
+    use pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey, RsaPrivateKeyOwned};
+
+    let key = RsaPrivateKeyOwned::from_pkcs1_der(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(key.version(), Version::TwoPrime);
+    assert_eq!(key.to_pkcs1_der().unwrap().as_bytes(), RSA_2048_DER_EXAMPLE);
+}
+
+#[cfg(all(feature = "alloc", feature = "subtle"))]
+#[test]
+fn owned_constant_time_eq() {
+    use der::Decode;
+    use pkcs1::RsaPrivateKeyOwned;
+    use subtle::ConstantTimeEq;
+
+    let key = RsaPrivateKeyOwned::from_der(RSA_2048_MULTI_PRIME_DER_EXAMPLE).unwrap();
+    let other = RsaPrivateKeyOwned::from_der(RSA_2048_DER_EXAMPLE).unwrap();
+    assert!(bool::from(key.ct_eq(&key.clone())));
+    assert!(!bool::from(key.ct_eq(&other)));
+
+    let mut modified = key.clone();
+    modified.other_prime_infos.as_mut().unwrap()[0].exponent[0] ^= 1;
+    assert_ne!(key, modified);
+    assert_eq!(key, key.clone());
+}
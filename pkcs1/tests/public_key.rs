@@ -62,3 +62,52 @@ fn decode_rsa4096_der() {
 //     let pk = RsaPublicKey::try_from(RSA_4096_DER_EXAMPLE).unwrap();
 //     assert_eq!(pkcs1_doc.decode().modulus.as_bytes(), pk.modulus.as_bytes());
 // }
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[test]
+fn spki_round_trip() {
+    use pkcs1::pkcs8::{EncodePublicKey, SubjectPublicKeyInfo};
+
+    let key = RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let spki_doc = key.to_public_key_der().unwrap();
+    let spki = SubjectPublicKeyInfo::try_from(spki_doc.as_bytes()).unwrap();
+    assert_eq!(spki.algorithm, pkcs1::ALGORITHM_ID);
+    assert_eq!(spki.subject_public_key, RSA_2048_DER_EXAMPLE);
+    assert_eq!(RsaPublicKey::try_from(spki).unwrap(), key);
+
+    // id-ecPublicKey
+    let mut spki = spki;
+    spki.algorithm.oid = "1.2.840.10045.2.1".parse().unwrap();
+    assert!(RsaPublicKey::try_from(spki).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_rsa2048_der() {
+    use der::{Decode, Encode};
+    use pkcs1::RsaPublicKeyOwned;
+
+    // Decode from a temporary buffer which is dropped before the key is used
+    let key = {
+        let der = RSA_2048_DER_EXAMPLE.to_vec();
+        RsaPublicKeyOwned::from_der(&der).unwrap()
+    };
+    assert_eq!(&*key.public_exponent, &hex!("010001"));
+    assert_eq!(key.to_vec().unwrap(), RSA_2048_DER_EXAMPLE);
+
+    let borrowed = RsaPublicKey::try_from(&key).unwrap();
+    assert_eq!(
+        borrowed,
+        RsaPublicKey::try_from(RSA_2048_DER_EXAMPLE).unwrap()
+    );
+    assert_eq!(RsaPublicKeyOwned::from(borrowed), key);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned_via_traits() {
+    use pkcs1::{DecodeRsaPublicKey, EncodeRsaPublicKey, RsaPublicKeyOwned};
+
+    let key = RsaPublicKeyOwned::from_pkcs1_der(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_pkcs1_der().unwrap().as_bytes(), RSA_2048_DER_EXAMPLE);
+}
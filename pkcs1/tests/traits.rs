@@ -98,3 +98,46 @@ fn write_pkcs1_pem_file() {
     let pem = fs::read_to_string(path).unwrap();
     assert_eq!(&pem, RSA_2048_PRIV_PEM_EXAMPLE);
 }
+
+#[cfg(all(feature = "pem", feature = "std"))]
+#[test]
+fn write_pkcs1_files_overwrite_existing() {
+    let dir = tempdir().unwrap();
+    let key = MockPrivateKey(RSA_2048_PRIV_DER_EXAMPLE.to_vec());
+
+    // Longer than the key, so it must be truncated
+    let der_path = dir.path().join("example.der");
+    fs::write(&der_path, vec![0xff; RSA_2048_PRIV_DER_EXAMPLE.len() * 2]).unwrap();
+    key.write_pkcs1_der_file(&der_path).unwrap();
+    assert_eq!(fs::read(&der_path).unwrap(), RSA_2048_PRIV_DER_EXAMPLE);
+
+    let pem_path = dir.path().join("example.pem");
+    fs::write(&pem_path, RSA_2048_PRIV_PEM_EXAMPLE.repeat(2)).unwrap();
+    key.write_pkcs1_pem_file(&pem_path, LineEnding::LF).unwrap();
+    assert_eq!(
+        fs::read_to_string(&pem_path).unwrap(),
+        RSA_2048_PRIV_PEM_EXAMPLE
+    );
+}
+
+#[cfg(all(unix, feature = "pem", feature = "std"))]
+#[test]
+fn write_pkcs1_files_restrict_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempdir().unwrap();
+    let key = MockPrivateKey(RSA_2048_PRIV_DER_EXAMPLE.to_vec());
+
+    let der_path = dir.path().join("example.der");
+    key.write_pkcs1_der_file(&der_path).unwrap();
+    let mode = fs::metadata(&der_path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    // Overwriting an existing world-readable file also restricts it
+    let pem_path = dir.path().join("example.pem");
+    fs::write(&pem_path, b"").unwrap();
+    fs::set_permissions(&pem_path, fs::Permissions::from_mode(0o644)).unwrap();
+    key.write_pkcs1_pem_file(&pem_path, LineEnding::LF).unwrap();
+    let mode = fs::metadata(&pem_path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
@@ -0,0 +1,67 @@
+//! `AuthenticatedSafe` as defined in [RFC 7292 Section 4.1].
+//!
+//! [RFC 7292 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.1
+
+use crate::{Error, Result, SafeContents};
+use alloc::vec::Vec;
+use cms::ContentInfo;
+use const_oid::db::rfc5911::ID_DATA;
+use der::{asn1::OctetString, Decode};
+
+#[cfg(feature = "encryption")]
+use {
+    cms::encrypted_data::EncryptedData, const_oid::db::rfc5911::ID_ENCRYPTED_DATA,
+    pkcs5::EncryptionScheme,
+};
+
+/// `AuthenticatedSafe` as defined in [RFC 7292 Section 4.1].
+///
+/// ```text
+/// AuthenticatedSafe ::= SEQUENCE OF ContentInfo
+///     -- Data if unencrypted
+///     -- EncryptedData if password-encrypted
+///     -- EnvelopedData if public key-encrypted
+/// ```
+///
+/// [RFC 7292 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.1
+pub type AuthenticatedSafe<'a> = Vec<ContentInfo<'a>>;
+
+/// Decode the [`SafeContents`] of an unencrypted (i.e. `id-data`) element of
+/// an [`AuthenticatedSafe`].
+///
+/// Encrypted elements return [`Error::ContentType`]: see
+/// [`decrypt_safe_contents`].
+pub fn safe_contents<'a>(content_info: &ContentInfo<'a>) -> Result<SafeContents<'a>> {
+    Ok(SafeContents::from_der(data(content_info)?)?)
+}
+
+/// Decrypt the ASN.1 DER encoded [`SafeContents`] of a password-encrypted
+/// (i.e. `id-encryptedData`) element of an [`AuthenticatedSafe`].
+///
+/// Other content types return [`Error::ContentType`].
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub fn decrypt_safe_contents(content_info: &ContentInfo<'_>, password: &str) -> Result<Vec<u8>> {
+    if content_info.content_type != ID_ENCRYPTED_DATA {
+        return Err(Error::ContentType);
+    }
+
+    let encrypted_content_info = EncryptedData::try_from(content_info)?.encrypted_content_info;
+    let ciphertext = encrypted_content_info
+        .encrypted_content
+        .ok_or(Error::ContentType)?;
+    let scheme = EncryptionScheme::try_from(encrypted_content_info.content_enc_alg)?;
+    Ok(scheme.decrypt(password, ciphertext.as_bytes())?)
+}
+
+/// Get the contents of the `OCTET STRING` of an `id-data` [`ContentInfo`].
+pub(crate) fn data<'a>(content_info: &ContentInfo<'a>) -> Result<&'a [u8]> {
+    if content_info.content_type != ID_DATA {
+        return Err(Error::ContentType);
+    }
+
+    Ok(content_info
+        .content
+        .decode_into::<OctetString<'a>>()?
+        .as_bytes())
+}
@@ -0,0 +1,283 @@
+//! Builder for password-protected PFX files.
+
+use crate::{
+    mac_data,
+    safe_bag::{CertBag, CERT_BAG_OID, PKCS8_SHROUDED_KEY_BAG_OID, X509_CERTIFICATE_OID},
+    DigestInfo, MacData, Pfx, Result, SafeBag, SafeContents, Version,
+};
+use alloc::{vec, vec::Vec};
+use cms::ContentInfo;
+use const_oid::db::rfc5911::ID_DATA;
+use const_oid::db::rfc5912::ID_SHA_256;
+use der::{
+    asn1::{Any, Null, ObjectIdentifier, OctetString, SetOf, SetOfVec},
+    Decode, Encode, Sequence, Tag, ValueOrd,
+};
+use hmac::Mac;
+use pkcs8::{
+    pkcs5::pbes2::{ParametersBuilder, Pbkdf2Prf},
+    rand_core::{CryptoRng, RngCore},
+    Attributes, PrivateKeyInfo, FRIENDLY_NAME_OID, LOCAL_KEY_ID_OID,
+};
+use sha2::{Digest, Sha256};
+use spki::AlgorithmIdentifier;
+
+/// Length of the `macSalt` generated by [`PfxBuilder`].
+const MAC_SALT_LEN: usize = 16;
+
+/// Builder for a [`Pfx`] containing a private key and its certificate
+/// chain, protected by a password.
+///
+/// The resulting PFX can be imported by OpenSSL 3 and Windows:
+///
+/// - The certificates are stored as `certBag`s in an unencrypted
+///   `SafeContents`, unlike OpenSSL 3 which encrypts them by default.
+/// - The private key is stored in a `pkcs8ShroudedKeyBag`, encrypted using
+///   PBES2 with PBKDF2-SHA256 (or optionally scrypt) and AES-256-CBC.
+/// - The `authSafe` is protected by an HMAC-SHA256 integrity MAC, keyed
+///   using the PKCS#12 KDF.
+///
+/// The private key and the first (i.e. end-entity) certificate are linked
+/// by a `localKeyId` attribute containing the SHA-256 digest of the
+/// certificate, along with the `friendlyName` attribute if one is set.
+///
+/// ```
+/// # fn main() -> pkcs12::Result<()> {
+/// use pkcs12::{pkcs8::PrivateKeyInfo, Pfx, PfxBuilder};
+/// # let private_key = include_bytes!("../tests/examples/ec-prv.der");
+/// # let certificate = include_bytes!("../tests/examples/ec-crt.der");
+/// # let rng = rand_core::OsRng;
+///
+/// let private_key = PrivateKeyInfo::try_from(private_key.as_slice())?;
+/// let der = PfxBuilder::new(private_key)
+///     .certificate(certificate)
+///     .friendly_name("Test EC")
+///     .pbkdf2_iterations(2048)
+///     .build(rng, "hunter42")?;
+///
+/// let pfx = Pfx::try_from(der.as_slice())?;
+/// pfx.verify_mac("hunter42")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PfxBuilder<'a> {
+    private_key: PrivateKeyInfo<'a>,
+    certificates: Vec<&'a [u8]>,
+    friendly_name: Option<&'a str>,
+    kdf: KeyKdf,
+    mac_iterations: u32,
+}
+
+impl<'a> PfxBuilder<'a> {
+    /// Default PBKDF2-SHA256 iteration count used to shroud the private key.
+    pub const PBKDF2_ITERATIONS: u32 = ParametersBuilder::PBKDF2_SHA256_ITERATIONS;
+
+    /// Default PKCS#12 KDF iteration count used to derive the MAC key, which
+    /// is the same as OpenSSL.
+    pub const MAC_ITERATIONS: u32 = 2048;
+
+    /// Create a builder for the given private key.
+    pub fn new(private_key: PrivateKeyInfo<'a>) -> Self {
+        Self {
+            private_key,
+            certificates: Vec::new(),
+            friendly_name: None,
+            kdf: KeyKdf::Pbkdf2 {
+                iterations: Self::PBKDF2_ITERATIONS,
+            },
+            mac_iterations: Self::MAC_ITERATIONS,
+        }
+    }
+
+    /// Add a DER-encoded X.509 certificate.
+    ///
+    /// The end-entity certificate corresponding to the private key must be
+    /// added first, followed by the rest of the chain.
+    pub fn certificate(mut self, certificate: &'a [u8]) -> Self {
+        self.certificates.push(certificate);
+        self
+    }
+
+    /// Set the `friendlyName` of the private key and end-entity certificate.
+    pub fn friendly_name(mut self, friendly_name: &'a str) -> Self {
+        self.friendly_name = Some(friendly_name);
+        self
+    }
+
+    /// Set the PBKDF2-SHA256 iteration count used to shroud the private key.
+    pub fn pbkdf2_iterations(mut self, iterations: u32) -> Self {
+        self.kdf = KeyKdf::Pbkdf2 { iterations };
+        self
+    }
+
+    /// Shroud the private key using scrypt with the given parameters instead
+    /// of PBKDF2-SHA256.
+    ///
+    /// scrypt keys are supported by OpenSSL, but not by Windows or Java.
+    pub fn scrypt(mut self, log_n: u8, r: u16, p: u16) -> Self {
+        self.kdf = KeyKdf::Scrypt { log_n, r, p };
+        self
+    }
+
+    /// Set the PKCS#12 KDF iteration count used to derive the MAC key, which
+    /// must not exceed [`MacData::MAX_ITERATIONS`].
+    pub fn mac_iterations(mut self, iterations: u32) -> Self {
+        self.mac_iterations = iterations;
+        self
+    }
+
+    /// Build the ASN.1 DER encoded [`Pfx`], using the given RNG to generate
+    /// salts and IVs, and the given password for both the private key
+    /// encryption and the integrity MAC.
+    pub fn build(&self, mut rng: impl CryptoRng + RngCore, password: &str) -> Result<Vec<u8>> {
+        if self.mac_iterations > MacData::MAX_ITERATIONS {
+            return Err(Tag::Integer.value_error().into());
+        }
+
+        // Attributes linking the private key with its certificate
+        let local_key_id = self.certificates.first().map(Sha256::digest);
+        let friendly_name = self.friendly_name.map(bmp_string);
+        let attributes_der = encode_attributes(friendly_name.as_deref(), local_key_id.as_deref())?;
+        let attributes = attributes_der
+            .as_deref()
+            .map(Attributes::try_from)
+            .transpose()?;
+
+        // Certificates
+        let cert_bags = self
+            .certificates
+            .iter()
+            .map(|certificate| {
+                CertBag {
+                    cert_id: X509_CERTIFICATE_OID,
+                    cert_value: OctetString::new(certificate)?.into(),
+                }
+                .to_vec()
+            })
+            .collect::<der::Result<Vec<_>>>()?;
+
+        let cert_safe_contents = cert_bags
+            .iter()
+            .enumerate()
+            .map(|(i, cert_bag)| {
+                Ok(SafeBag {
+                    bag_id: CERT_BAG_OID,
+                    bag_value: Any::from_der(cert_bag)?,
+                    bag_attributes: if i == 0 { attributes } else { None },
+                })
+            })
+            .collect::<der::Result<SafeContents<'_>>>()?
+            .to_vec()?;
+
+        // Private key
+        let pbes2_params = ParametersBuilder::random(&mut rng);
+        let pbes2_params = match self.kdf {
+            KeyKdf::Pbkdf2 { iterations } => {
+                pbes2_params.pbkdf2(Pbkdf2Prf::HmacWithSha256, iterations)
+            }
+            KeyKdf::Scrypt { log_n, r, p } => pbes2_params.scrypt(log_n, r, p),
+        }
+        .aes256cbc();
+        let shrouded_key = self
+            .private_key
+            .encrypt_with_params(pbes2_params.build()?, password)?;
+
+        let key_safe_contents: SafeContents<'_> = vec![SafeBag {
+            bag_id: PKCS8_SHROUDED_KEY_BAG_OID,
+            bag_value: Any::from_der(shrouded_key.as_bytes())?,
+            bag_attributes: attributes,
+        }];
+        let key_safe_contents = key_safe_contents.to_vec()?;
+
+        let mut auth_safe = Vec::with_capacity(2);
+        if !self.certificates.is_empty() {
+            auth_safe.push(data_content_info(&cert_safe_contents)?);
+        }
+        auth_safe.push(data_content_info(&key_safe_contents)?);
+        let auth_safe = auth_safe.to_vec()?;
+
+        // Integrity MAC
+        let mut mac_salt = [0u8; MAC_SALT_LEN];
+        rng.fill_bytes(&mut mac_salt);
+
+        let mac = mac_data::hmac::<Sha256>(password, &mac_salt, self.mac_iterations, &auth_safe)
+            .finalize()
+            .into_bytes();
+
+        let mac_data = MacData {
+            mac: DigestInfo {
+                digest_algorithm: AlgorithmIdentifier {
+                    oid: ID_SHA_256,
+                    parameters: Some(Null.into()),
+                },
+                digest: OctetString::new(mac.as_slice())?,
+            },
+            mac_salt: OctetString::new(mac_salt.as_slice())?,
+            iterations: self.mac_iterations,
+        };
+
+        Ok(Pfx {
+            version: Version::V3,
+            auth_safe: data_content_info(&auth_safe)?,
+            mac_data: Some(mac_data),
+        }
+        .to_vec()?)
+    }
+}
+
+/// Password-based KDF used to shroud the private key.
+#[derive(Copy, Clone, Debug)]
+enum KeyKdf {
+    Pbkdf2 { iterations: u32 },
+    Scrypt { log_n: u8, r: u16, p: u16 },
+}
+
+/// X.501 `Attribute` with a single value.
+#[derive(Sequence, ValueOrd)]
+struct BagAttribute<'a> {
+    oid: ObjectIdentifier,
+    values: SetOf<Any<'a>, 1>,
+}
+
+/// Encode the `friendlyName` and `localKeyId` attributes as a `SET OF
+/// Attribute`, if either is present.
+fn encode_attributes(
+    friendly_name: Option<&[u8]>,
+    local_key_id: Option<&[u8]>,
+) -> Result<Option<Vec<u8>>> {
+    let mut attributes = Vec::new();
+
+    for (oid, tag, value) in [
+        (FRIENDLY_NAME_OID, Tag::BmpString, friendly_name),
+        (LOCAL_KEY_ID_OID, Tag::OctetString, local_key_id),
+    ] {
+        if let Some(value) = value {
+            attributes.push(BagAttribute {
+                oid,
+                values: [Any::new(tag, value)?].try_into()?,
+            });
+        }
+    }
+
+    if attributes.is_empty() {
+        return Ok(None);
+    }
+
+    // Sorts the attributes by their DER encoding
+    let attributes = SetOfVec::try_from(attributes)?;
+    Ok(Some(attributes.to_vec()?))
+}
+
+/// Encode a string as a big endian UTF-16 `BMPString`.
+fn bmp_string(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(u16::to_be_bytes).collect()
+}
+
+/// Wrap the given DER in an `id-data` [`ContentInfo`].
+fn data_content_info(data: &[u8]) -> Result<ContentInfo<'_>> {
+    Ok(ContentInfo {
+        content_type: ID_DATA,
+        content: OctetString::new(data)?.into(),
+    })
+}
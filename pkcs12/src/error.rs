@@ -0,0 +1,82 @@
+//! Error types
+
+use core::fmt;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// A `SafeBag`, certificate or CRL type isn't supported.
+    BagType,
+
+    /// A `ContentInfo` has an unexpected or unsupported content type, e.g. a
+    /// PFX using public-key integrity mode, or encrypted safe contents which
+    /// were accessed without a password.
+    ContentType,
+
+    /// The `MacData` digest algorithm isn't supported.
+    MacAlgorithm,
+
+    /// The `MacData` is missing, or the MAC is invalid, e.g. because the
+    /// password is incorrect.
+    MacVerification,
+
+    /// `safeContentsBag`s are nested too deeply.
+    NestingDepth,
+
+    /// Password-based encryption errors, e.g. an incorrect password or an
+    /// unsupported encryption scheme.
+    Pkcs5(pkcs5::Error),
+
+    /// PKCS#8 private key errors, including errors encrypting or decrypting
+    /// shrouded keys.
+    Pkcs8(pkcs8::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "PKCS#12 ASN.1 error: {}", err),
+            Error::BagType => f.write_str("PKCS#12 bag type unsupported"),
+            Error::ContentType => f.write_str("PKCS#12 content type unsupported"),
+            Error::MacAlgorithm => f.write_str("PKCS#12 MAC algorithm unsupported"),
+            Error::MacVerification => f.write_str("PKCS#12 MAC verification failed"),
+            Error::NestingDepth => f.write_str("PKCS#12 safe contents nested too deeply"),
+            Error::Pkcs5(err) => write!(f, "PKCS#12 encryption error: {}", err),
+            Error::Pkcs8(err) => write!(f, "PKCS#12 private key error: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
+
+impl From<pkcs5::Error> for Error {
+    fn from(err: pkcs5::Error) -> Error {
+        Error::Pkcs5(err)
+    }
+}
+
+impl From<pkcs8::Error> for Error {
+    fn from(err: pkcs8::Error) -> Error {
+        Error::Pkcs8(err)
+    }
+}
@@ -0,0 +1,157 @@
+//! Extraction of private keys, certificates and CRLs from a [`Pfx`].
+
+use crate::{
+    authenticated_safe,
+    safe_bag::{Bag, SafeBag},
+    Error, Pfx, Result, SafeContents,
+};
+use alloc::{string::String, vec::Vec};
+use const_oid::db::rfc5911::{ID_DATA, ID_ENCRYPTED_DATA};
+use der::{zeroize::Zeroizing, Decode, Document, SecretDocument};
+
+/// Maximum depth of nested `safeContentsBag`s.
+///
+/// Nested `SafeContents` are rare in practice, but decoding them recurses,
+/// so their depth is bounded to avoid exhausting the stack.
+const MAX_SAFE_CONTENTS_DEPTH: usize = 8;
+
+/// Private key extracted from a [`Pfx`] by [`Pfx::extract`].
+#[derive(Clone, Debug)]
+pub struct KeyEntry {
+    /// ASN.1 DER encoded PKCS#8 `PrivateKeyInfo`, decrypted if it was
+    /// stored in a `pkcs8ShroudedKeyBag`.
+    pub private_key: SecretDocument,
+
+    /// PKCS#9 `friendlyName` attribute, if present.
+    pub friendly_name: Option<String>,
+
+    /// PKCS#9 `localKeyID` attribute, if present.
+    pub local_key_id: Option<Vec<u8>>,
+
+    /// Index of the certificate for this private key among the extracted
+    /// [`CertificateEntry`]s, if any.
+    pub certificate: Option<usize>,
+}
+
+/// X.509 certificate extracted from a [`Pfx`] by [`Pfx::extract`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateEntry {
+    /// ASN.1 DER encoded X.509 certificate.
+    pub certificate: Document,
+
+    /// PKCS#9 `friendlyName` attribute, if present.
+    pub friendly_name: Option<String>,
+
+    /// PKCS#9 `localKeyID` attribute, if present.
+    pub local_key_id: Option<Vec<u8>>,
+}
+
+impl CertificateEntry {
+    /// Does this certificate belong to the given private key?
+    ///
+    /// The `localKeyID` attributes are compared if both are present, and the
+    /// `friendlyName` attributes otherwise.
+    fn matches(&self, key: &KeyEntry) -> bool {
+        match (&self.local_key_id, &key.local_key_id) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.friendly_name.is_some() && self.friendly_name == key.friendly_name,
+        }
+    }
+}
+
+/// Contents of a [`Pfx`].
+#[derive(Default)]
+struct Keystore {
+    keys: Vec<KeyEntry>,
+    certificates: Vec<CertificateEntry>,
+    crls: Vec<Document>,
+}
+
+impl Keystore {
+    /// Add the contents of the given [`SafeBag`]s.
+    ///
+    /// `secretBag`s, as well as certificates and CRLs which aren't X.509, are
+    /// skipped. `depth` is the number of `safeContentsBag`s enclosing them.
+    fn add_safe_contents(
+        &mut self,
+        safe_contents: &[SafeBag<'_>],
+        password: &str,
+        depth: usize,
+    ) -> Result<()> {
+        for bag in safe_contents {
+            let friendly_name = bag.friendly_name()?;
+            let local_key_id = bag.local_key_id()?.map(<[u8]>::to_vec);
+
+            match bag.value() {
+                Ok(Bag::Key(private_key)) => self.keys.push(KeyEntry {
+                    private_key: private_key.try_into()?,
+                    friendly_name,
+                    local_key_id,
+                    certificate: None,
+                }),
+                Ok(Bag::ShroudedKey(encrypted_private_key)) => self.keys.push(KeyEntry {
+                    private_key: encrypted_private_key.decrypt(password)?,
+                    friendly_name,
+                    local_key_id,
+                    certificate: None,
+                }),
+                Ok(Bag::Certificate(certificate)) => self.certificates.push(CertificateEntry {
+                    certificate: Document::encode_msg(&certificate)?,
+                    friendly_name,
+                    local_key_id,
+                }),
+                Ok(Bag::Crl(crl)) => self.crls.push(Document::encode_msg(&crl)?),
+                Ok(Bag::SafeContents(nested)) => {
+                    if depth >= MAX_SAFE_CONTENTS_DEPTH {
+                        return Err(Error::NestingDepth);
+                    }
+
+                    self.add_safe_contents(&nested, password, depth + 1)?
+                }
+                Err(Error::BagType) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Extract the private keys, certificates and CRLs from a [`Pfx`].
+pub(crate) fn extract(
+    pfx: &Pfx<'_>,
+    password: &str,
+) -> Result<(Vec<KeyEntry>, Vec<CertificateEntry>, Vec<Document>)> {
+    if pfx.mac_data.is_some() {
+        pfx.verify_mac(password)?;
+    }
+
+    let mut keystore = Keystore::default();
+
+    for content_info in pfx.authenticated_safe()? {
+        match content_info.content_type {
+            ID_DATA => {
+                let safe_contents = authenticated_safe::safe_contents(&content_info)?;
+                keystore.add_safe_contents(&safe_contents, password, 0)?;
+            }
+            ID_ENCRYPTED_DATA => {
+                let plaintext = Zeroizing::new(authenticated_safe::decrypt_safe_contents(
+                    &content_info,
+                    password,
+                )?);
+                let safe_contents = SafeContents::from_der(&plaintext)?;
+                keystore.add_safe_contents(&safe_contents, password, 0)?;
+            }
+            _ => return Err(Error::ContentType),
+        }
+    }
+
+    for key in &mut keystore.keys {
+        key.certificate = keystore
+            .certificates
+            .iter()
+            .position(|certificate| certificate.matches(key));
+    }
+
+    Ok((keystore.keys, keystore.certificates, keystore.crls))
+}
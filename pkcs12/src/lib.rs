@@ -0,0 +1,85 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/pkcs12/0.0.0"
+)]
+#![forbid(unsafe_code, clippy::unwrap_used)]
+#![warn(
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! Decode a [`Pfx`], then walk its [`AuthenticatedSafe`] to access the
+//! [`SafeBag`]s containing keys and certificates:
+//!
+//! ```
+//! use pkcs12::{authenticated_safe, Bag, Pfx};
+//!
+//! let der = include_bytes!("../tests/examples/ec-plain.p12");
+//! let pfx = Pfx::try_from(der.as_slice())?;
+//!
+//! for content_info in pfx.authenticated_safe()? {
+//!     for bag in authenticated_safe::safe_contents(&content_info)? {
+//!         match bag.value()? {
+//!             Bag::Key(private_key) => { /* ... */ }
+//!             Bag::Certificate(certificate) => { /* ... */ }
+//!             _ => (),
+//!         }
+//!     }
+//! }
+//! # Ok::<(), pkcs12::Error>(())
+//! ```
+//!
+//! With the `encryption` feature enabled, [`Pfx::extract`] decrypts and
+//! returns all of the private keys, certificates and CRLs at once, matching
+//! keys with their certificates. [`Pfx::verify_mac`] verifies the password
+//! integrity MAC, and [`PfxBuilder`] builds password-protected PFX files from
+//! a private key and its certificate chain.
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod authenticated_safe;
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub mod builder;
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub mod keystore;
+pub mod mac_data;
+pub mod pfx;
+pub mod safe_bag;
+
+mod error;
+
+pub use crate::{
+    authenticated_safe::AuthenticatedSafe,
+    error::{Error, Result},
+    mac_data::{DigestInfo, MacData},
+    pfx::{Pfx, Version},
+    safe_bag::{Bag, SafeBag, SafeContents},
+};
+pub use cms;
+pub use der;
+pub use pkcs8;
+pub use spki;
+
+#[cfg(feature = "encryption")]
+pub use crate::{
+    builder::PfxBuilder,
+    keystore::{CertificateEntry, KeyEntry},
+};
+
+/// PKCS#12 key derivation function, re-exported from [`pkcs5`].
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub use pkcs5::pbes1::kdf;
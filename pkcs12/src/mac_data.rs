@@ -0,0 +1,237 @@
+//! `MacData` as defined in [RFC 7292 Section 4].
+//!
+//! [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+
+use der::{
+    asn1::{ObjectIdentifier, OctetString},
+    Sequence, Tag,
+};
+use pkcs5::pbes2::Pbkdf2Params;
+use spki::AlgorithmIdentifier;
+
+#[cfg(feature = "encryption")]
+use {
+    crate::{Error, Result},
+    alloc::vec,
+    const_oid::db::rfc5912::{ID_SHA_224, ID_SHA_256, ID_SHA_384, ID_SHA_512},
+    der::zeroize::Zeroizing,
+    digest::{core_api::BlockSizeUser, Digest},
+    hmac::{Mac, SimpleHmac},
+    pkcs5::{
+        pbes1::kdf::{self, KeyType},
+        pbes2::{
+            Kdf, Pbkdf2Prf, HMAC_WITH_SHA224_OID, HMAC_WITH_SHA256_OID, HMAC_WITH_SHA384_OID,
+            HMAC_WITH_SHA512_OID, PBKDF2_OID,
+        },
+    },
+    sha2::{Sha224, Sha256, Sha384, Sha512},
+};
+
+#[cfg(feature = "legacy")]
+use {const_oid::db::rfc5912::ID_SHA_1, sha1::Sha1};
+
+/// `id-PBMAC1` Object Identifier (OID), as used for the `digestAlgorithm` of
+/// a [`MacData`] protected using [RFC 9579].
+///
+/// [RFC 9579]: https://datatracker.ietf.org/doc/html/rfc9579
+pub const PBMAC1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.14");
+
+/// `MacData` as defined in [RFC 7292 Section 4].
+///
+/// ```text
+/// MacData ::= SEQUENCE {
+///     mac        DigestInfo,
+///     macSalt    OCTET STRING,
+///     iterations INTEGER DEFAULT 1
+///     -- Note: The default is for historical reasons and its use is
+///     -- deprecated.
+/// }
+/// ```
+///
+/// [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[asn1(validate = "MacData::validate")]
+#[allow(missing_docs)]
+pub struct MacData<'a> {
+    pub mac: DigestInfo<'a>,
+    pub mac_salt: OctetString<'a>,
+    #[asn1(default = "default_iterations")]
+    pub iterations: u32,
+}
+
+impl MacData<'_> {
+    /// Maximum iteration count of the PKCS#12 KDF, which is the same as the
+    /// maximum PBKDF2 iteration count, i.e. 100,000,000.
+    ///
+    /// Larger iteration counts are rejected when decoding, so verifying a MAC
+    /// from an untrusted PFX takes a bounded amount of time.
+    pub const MAX_ITERATIONS: u32 = Pbkdf2Params::MAX_ITERATION_COUNT;
+
+    /// Check the iteration count is within [`MacData::MAX_ITERATIONS`].
+    fn validate(&self) -> der::Result<()> {
+        if self.iterations > Self::MAX_ITERATIONS {
+            return Err(Tag::Integer.value_error());
+        }
+
+        Ok(())
+    }
+}
+
+/// `DigestInfo` as defined in [RFC 8017 Section 9.2].
+///
+/// ```text
+/// DigestInfo ::= SEQUENCE {
+///     digestAlgorithm DigestAlgorithmIdentifier,
+///     digest OCTET STRING
+/// }
+/// ```
+///
+/// [RFC 8017 Section 9.2]: https://datatracker.ietf.org/doc/html/rfc8017#section-9.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct DigestInfo<'a> {
+    pub digest_algorithm: AlgorithmIdentifier<'a>,
+    pub digest: OctetString<'a>,
+}
+
+/// `PBMAC1-params` as defined in [RFC 8018 Appendix A.5].
+///
+/// ```text
+/// PBMAC1-params ::=  SEQUENCE {
+///     keyDerivationFunc AlgorithmIdentifier {{PBMAC1-KDFs}},
+///     messageAuthScheme AlgorithmIdentifier {{PBMAC1-MACs}}
+/// }
+/// ```
+///
+/// [RFC 8018 Appendix A.5]: https://datatracker.ietf.org/doc/html/rfc8018#appendix-A.5
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Pbmac1Params<'a> {
+    pub key_derivation_func: AlgorithmIdentifier<'a>,
+    pub message_auth_scheme: AlgorithmIdentifier<'a>,
+}
+
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+impl MacData<'_> {
+    /// Verify the MAC over `data`, i.e. the contents of the `authSafe`,
+    /// using a key derived from the given password.
+    ///
+    /// The following MAC algorithms are supported:
+    ///
+    /// - HMAC keyed using the PKCS#12 KDF with the same digest, as described
+    ///   in [RFC 7292 Appendix B], with SHA-224, SHA-256, SHA-384 or SHA-512.
+    ///   SHA-1, which is used by most PFX files created before OpenSSL 3,
+    ///   requires the `legacy` feature.
+    /// - PBMAC1 as described in [RFC 9579], with an HMAC-SHA2 family PRF and
+    ///   MAC, keyed using PBKDF2. `macSalt` and `iterations` are ignored in
+    ///   favor of the PBKDF2 parameters.
+    ///
+    /// [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+    /// [RFC 9579]: https://datatracker.ietf.org/doc/html/rfc9579
+    pub fn verify(&self, password: &str, data: &[u8]) -> Result<()> {
+        match self.mac.digest_algorithm.oid {
+            #[cfg(feature = "legacy")]
+            ID_SHA_1 => self.verify_with::<Sha1>(password, data),
+            ID_SHA_224 => self.verify_with::<Sha224>(password, data),
+            ID_SHA_256 => self.verify_with::<Sha256>(password, data),
+            ID_SHA_384 => self.verify_with::<Sha384>(password, data),
+            ID_SHA_512 => self.verify_with::<Sha512>(password, data),
+            PBMAC1_OID => self.verify_pbmac1(password, data),
+            _ => Err(Error::MacAlgorithm),
+        }
+    }
+
+    /// Verify the MAC using HMAC with the digest `D`.
+    fn verify_with<D>(&self, password: &str, data: &[u8]) -> Result<()>
+    where
+        D: Digest + BlockSizeUser,
+    {
+        hmac::<D>(password, self.mac_salt.as_bytes(), self.iterations, data)
+            .verify_slice(self.mac.digest.as_bytes())
+            .map_err(|_| Error::MacVerification)
+    }
+
+    /// Verify a PBMAC1 MAC.
+    fn verify_pbmac1(&self, password: &str, data: &[u8]) -> Result<()> {
+        let params: Pbmac1Params<'_> = self
+            .mac
+            .digest_algorithm
+            .parameters
+            .ok_or(Error::MacAlgorithm)?
+            .decode_into()?;
+
+        // RFC 9579 requires the key length to be specified
+        let pbkdf2_params = match Kdf::try_from(params.key_derivation_func)? {
+            Kdf::Pbkdf2(pbkdf2_params) => pbkdf2_params,
+            _ => return Err(Error::MacAlgorithm),
+        };
+        let key_length = pbkdf2_params.key_length.ok_or(Error::MacAlgorithm)?;
+
+        // PBKDF2 parameters aren't checked against the maximum iteration
+        // count when they're decoded
+        if pbkdf2_params.iteration_count > Pbkdf2Params::MAX_ITERATION_COUNT {
+            return Err(pkcs5::Error::AlgorithmParametersInvalid { oid: PBKDF2_OID }.into());
+        }
+
+        let mut key = Zeroizing::new(vec![0u8; key_length.into()]);
+        let password = password.as_bytes();
+
+        match pbkdf2_params.prf {
+            Pbkdf2Prf::HmacWithSha224 => pbmac1_key::<Sha224>(password, &pbkdf2_params, &mut key),
+            Pbkdf2Prf::HmacWithSha256 => pbmac1_key::<Sha256>(password, &pbkdf2_params, &mut key),
+            Pbkdf2Prf::HmacWithSha384 => pbmac1_key::<Sha384>(password, &pbkdf2_params, &mut key),
+            Pbkdf2Prf::HmacWithSha512 => pbmac1_key::<Sha512>(password, &pbkdf2_params, &mut key),
+            _ => return Err(Error::MacAlgorithm),
+        }
+
+        let tag = self.mac.digest.as_bytes();
+
+        match params.message_auth_scheme.oid {
+            HMAC_WITH_SHA224_OID => verify_hmac::<Sha224>(&key, data, tag),
+            HMAC_WITH_SHA256_OID => verify_hmac::<Sha256>(&key, data, tag),
+            HMAC_WITH_SHA384_OID => verify_hmac::<Sha384>(&key, data, tag),
+            HMAC_WITH_SHA512_OID => verify_hmac::<Sha512>(&key, data, tag),
+            _ => Err(Error::MacAlgorithm),
+        }
+    }
+}
+
+fn default_iterations() -> u32 {
+    1
+}
+
+/// Compute the HMAC with the digest `D` over `data`, keyed using the PKCS#12
+/// KDF.
+#[cfg(feature = "encryption")]
+pub(crate) fn hmac<D>(password: &str, salt: &[u8], iterations: u32, data: &[u8]) -> SimpleHmac<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    let mut key = Zeroizing::new(vec![0u8; <D as Digest>::output_size()]);
+    kdf::derive_key::<D>(password, salt, KeyType::MacKey, iterations, &mut key);
+
+    let mut mac = SimpleHmac::<D>::new_from_slice(&key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac
+}
+
+/// Derive a PBMAC1 key using PBKDF2 with HMAC with the digest `D`.
+#[cfg(feature = "encryption")]
+fn pbmac1_key<D>(password: &[u8], params: &Pbkdf2Params<'_>, out: &mut [u8])
+where
+    D: Digest + BlockSizeUser + Clone + Sync,
+{
+    pbkdf2::pbkdf2::<SimpleHmac<D>>(password, params.salt, params.iteration_count, out);
+}
+
+/// Verify an HMAC with the digest `D` over `data`.
+#[cfg(feature = "encryption")]
+fn verify_hmac<D>(key: &[u8], data: &[u8], tag: &[u8]) -> Result<()>
+where
+    D: Digest + BlockSizeUser,
+{
+    let mut mac = SimpleHmac::<D>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.verify_slice(tag).map_err(|_| Error::MacVerification)
+}
@@ -0,0 +1,105 @@
+//! `PFX` as defined in [RFC 7292 Section 4].
+//!
+//! [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+
+use crate::{authenticated_safe, AuthenticatedSafe, Error, MacData, Result};
+use cms::ContentInfo;
+use der::{Decode, Enumerated, Sequence};
+
+#[cfg(feature = "encryption")]
+use {
+    crate::keystore::{self, CertificateEntry, KeyEntry},
+    alloc::vec::Vec,
+    der::Document,
+};
+
+/// `PFX` as defined in [RFC 7292 Section 4].
+///
+/// ```text
+/// PFX ::= SEQUENCE {
+///     version    INTEGER {v3(3)}(v3,...),
+///     authSafe   ContentInfo,
+///     macData    MacData OPTIONAL
+/// }
+/// ```
+///
+/// In password integrity mode, `authSafe` is of type `id-data` and the
+/// [`MacData`] is computed over the contents of its `OCTET STRING`. In
+/// public-key integrity mode, it's of type `id-signedData` and `macData` is
+/// absent.
+///
+/// [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Pfx<'a> {
+    pub version: Version,
+    pub auth_safe: ContentInfo<'a>,
+    pub mac_data: Option<MacData<'a>>,
+}
+
+impl<'a> Pfx<'a> {
+    /// Decode the [`AuthenticatedSafe`] contained in `authSafe`.
+    ///
+    /// Only password integrity mode (i.e. `authSafe` of type `id-data`) is
+    /// supported; other content types return [`Error::ContentType`]. The
+    /// MAC is not verified: see [`Pfx::verify_mac`].
+    pub fn authenticated_safe(&self) -> Result<AuthenticatedSafe<'a>> {
+        Ok(AuthenticatedSafe::from_der(authenticated_safe::data(
+            &self.auth_safe,
+        )?)?)
+    }
+
+    /// Verify the password integrity MAC over the `authSafe`.
+    ///
+    /// Returns [`Error::MacVerification`] if the password is incorrect, or if
+    /// the `macData` is absent.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn verify_mac(&self, password: &str) -> Result<()> {
+        let mac_data = self.mac_data.as_ref().ok_or(Error::MacVerification)?;
+        mac_data.verify(password, authenticated_safe::data(&self.auth_safe)?)
+    }
+
+    /// Extract the private keys, certificates and CRLs, decrypting them with
+    /// the given password as needed.
+    ///
+    /// The MAC is verified first if present. Each private key is matched
+    /// with its certificate using their `localKeyID` attributes, or their
+    /// `friendlyName` attributes if either key lacks a `localKeyID`.
+    /// `secretBag`s, as well as certificates and CRLs which aren't X.509, are
+    /// skipped.
+    ///
+    /// Returns [`Error::NestingDepth`] if `safeContentsBag`s are nested more
+    /// than 8 deep.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn extract(
+        &self,
+        password: &str,
+    ) -> Result<(Vec<KeyEntry>, Vec<CertificateEntry>, Vec<Document>)> {
+        keystore::extract(self, password)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Pfx<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+/// Version of a [`Pfx`].
+///
+/// ```text
+/// version INTEGER {v3(3)}(v3,...)
+/// ```
+#[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// Version 3: the only version defined by [RFC 7292].
+    ///
+    /// [RFC 7292]: https://datatracker.ietf.org/doc/html/rfc7292
+    V3 = 3,
+}
@@ -0,0 +1,210 @@
+//! `SafeBag` and `SafeContents` as defined in [RFC 7292 Section 4.2].
+//!
+//! [RFC 7292 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2
+
+use crate::{Error, Result};
+use alloc::{string::String, vec::Vec};
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Decode, Sequence,
+};
+use pkcs8::{Attributes, EncryptedPrivateKeyInfo, PrivateKeyInfo};
+use x509_cert::{crl::CertificateList, Certificate};
+
+/// `keyBag` Object Identifier (OID): a PKCS#8 `PrivateKeyInfo`.
+pub const KEY_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.1");
+
+/// `pkcs8ShroudedKeyBag` Object Identifier (OID): a PKCS#8
+/// `EncryptedPrivateKeyInfo`.
+pub const PKCS8_SHROUDED_KEY_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.2");
+
+/// `certBag` Object Identifier (OID).
+pub const CERT_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.3");
+
+/// `crlBag` Object Identifier (OID).
+pub const CRL_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.4");
+
+/// `secretBag` Object Identifier (OID).
+pub const SECRET_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.5");
+
+/// `safeContentsBag` Object Identifier (OID): a nested [`SafeContents`].
+pub const SAFE_CONTENTS_BAG_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.6");
+
+/// `x509Certificate` certificate type Object Identifier (OID).
+pub const X509_CERTIFICATE_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.22.1");
+
+/// `sdsiCertificate` certificate type Object Identifier (OID).
+pub const SDSI_CERTIFICATE_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.22.2");
+
+/// `x509CRL` CRL type Object Identifier (OID).
+pub const X509_CRL_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.23.1");
+
+/// `SafeContents` as defined in [RFC 7292 Section 4.2].
+///
+/// ```text
+/// SafeContents ::= SEQUENCE OF SafeBag
+/// ```
+///
+/// [RFC 7292 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2
+pub type SafeContents<'a> = Vec<SafeBag<'a>>;
+
+/// `SafeBag` as defined in [RFC 7292 Section 4.2].
+///
+/// ```text
+/// SafeBag ::= SEQUENCE {
+///     bagId         BAG-TYPE.&id ({PKCS12BagSet}),
+///     bagValue      [0] EXPLICIT BAG-TYPE.&Type({PKCS12BagSet}{@bagId}),
+///     bagAttributes SET OF PKCS12Attribute OPTIONAL
+/// }
+/// ```
+///
+/// The `bagValue` is left undecoded: its type is determined by the `bagId`.
+/// The `bagAttributes` typically contain a `friendlyName` and `localKeyId`
+/// used to match keys with their certificates.
+///
+/// [RFC 7292 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SafeBag<'a> {
+    pub bag_id: ObjectIdentifier,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
+    pub bag_value: Any<'a>,
+    pub bag_attributes: Option<Attributes<'a>>,
+}
+
+impl<'a> SafeBag<'a> {
+    /// Decode the `bagValue` according to the `bagId`.
+    ///
+    /// Returns [`Error::BagType`] for `secretBag`s and unknown bag types, as
+    /// well as for certificates and CRLs which aren't X.509.
+    pub fn value(&self) -> Result<Bag<'a>> {
+        Ok(match self.bag_id {
+            KEY_BAG_OID => Bag::Key(self.bag_value.decode_into()?),
+            PKCS8_SHROUDED_KEY_BAG_OID => Bag::ShroudedKey(self.bag_value.decode_into()?),
+            CERT_BAG_OID => {
+                Bag::Certificate(self.bag_value.decode_into::<CertBag<'a>>()?.certificate()?)
+            }
+            CRL_BAG_OID => Bag::Crl(self.bag_value.decode_into::<CrlBag<'a>>()?.crl()?),
+            SAFE_CONTENTS_BAG_OID => Bag::SafeContents(self.bag_value.decode_into()?),
+            _ => return Err(Error::BagType),
+        })
+    }
+
+    /// Get the PKCS#9 `friendlyName` attribute, if present.
+    pub fn friendly_name(&self) -> Result<Option<String>> {
+        match self.bag_attributes {
+            Some(attributes) => Ok(attributes.friendly_name()?),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the PKCS#9 `localKeyID` attribute, if present.
+    pub fn local_key_id(&self) -> Result<Option<&'a [u8]>> {
+        match self.bag_attributes {
+            Some(attributes) => Ok(attributes.local_key_id()?),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Typed `bagValue` of a [`SafeBag`], as returned by [`SafeBag::value`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+pub enum Bag<'a> {
+    /// `keyBag`: unencrypted PKCS#8 private key.
+    Key(PrivateKeyInfo<'a>),
+
+    /// `pkcs8ShroudedKeyBag`: password-encrypted PKCS#8 private key.
+    ShroudedKey(EncryptedPrivateKeyInfo<'a>),
+
+    /// `certBag` containing an X.509 certificate.
+    Certificate(Certificate<'a>),
+
+    /// `crlBag` containing an X.509 CRL.
+    Crl(CertificateList<'a>),
+
+    /// `safeContentsBag`: nested [`SafeContents`].
+    SafeContents(SafeContents<'a>),
+}
+
+/// `CertBag` as defined in [RFC 7292 Section 4.2.3].
+///
+/// ```text
+/// CertBag ::= SEQUENCE {
+///     certId    BAG-TYPE.&id   ({CertTypes}),
+///     certValue [0] EXPLICIT BAG-TYPE.&Type ({CertTypes}{@certId})
+/// }
+/// ```
+///
+/// For [`X509_CERTIFICATE_OID`], the `certValue` is an `OCTET STRING`
+/// containing the DER encoding of the certificate.
+///
+/// [RFC 7292 Section 4.2.3]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2.3
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CertBag<'a> {
+    pub cert_id: ObjectIdentifier,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
+    pub cert_value: Any<'a>,
+}
+
+impl<'a> CertBag<'a> {
+    /// Decode the X.509 certificate.
+    ///
+    /// Returns [`Error::BagType`] if the `certId` isn't
+    /// [`X509_CERTIFICATE_OID`].
+    pub fn certificate(&self) -> Result<Certificate<'a>> {
+        if self.cert_id != X509_CERTIFICATE_OID {
+            return Err(Error::BagType);
+        }
+
+        Ok(Certificate::from_der(
+            self.cert_value.octet_string()?.as_bytes(),
+        )?)
+    }
+}
+
+/// `CRLBag` as defined in [RFC 7292 Section 4.2.4].
+///
+/// ```text
+/// CRLBag ::= SEQUENCE {
+///     crlId     BAG-TYPE.&id ({CRLTypes}),
+///     crlValue  [0] EXPLICIT BAG-TYPE.&Type ({CRLTypes}{@crlId})
+/// }
+/// ```
+///
+/// For [`X509_CRL_OID`], the `crlValue` is an `OCTET STRING` containing the
+/// DER encoding of the CRL.
+///
+/// [RFC 7292 Section 4.2.4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2.4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CrlBag<'a> {
+    pub crl_id: ObjectIdentifier,
+    #[asn1(context_specific = "0", tag_mode = "EXPLICIT")]
+    pub crl_value: Any<'a>,
+}
+
+impl<'a> CrlBag<'a> {
+    /// Decode the X.509 CRL.
+    ///
+    /// Returns [`Error::BagType`] if the `crlId` isn't [`X509_CRL_OID`].
+    pub fn crl(&self) -> Result<CertificateList<'a>> {
+        if self.crl_id != X509_CRL_OID {
+            return Err(Error::BagType);
+        }
+
+        Ok(CertificateList::from_der(
+            self.crl_value.octet_string()?.as_bytes(),
+        )?)
+    }
+}
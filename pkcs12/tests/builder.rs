@@ -0,0 +1,144 @@
+//! `PfxBuilder` tests
+
+#![cfg(feature = "encryption")]
+
+use der::{Decode, Encode};
+use pkcs12::{
+    authenticated_safe,
+    pkcs8::{EncryptedPrivateKeyInfo, PrivateKeyInfo, LOCAL_KEY_ID_OID},
+    safe_bag::{CertBag, CERT_BAG_OID, PKCS8_SHROUDED_KEY_BAG_OID, X509_CERTIFICATE_OID},
+    Error, MacData, Pfx, PfxBuilder,
+};
+use rand_core::OsRng;
+use sha2::{Digest, Sha256};
+
+/// EC private key encoded as PKCS#8 DER
+const EC_KEY_EXAMPLE: &[u8] = include_bytes!("examples/ec-prv.der");
+
+/// EC end-entity certificate encoded as DER
+const EC_CERT_EXAMPLE: &[u8] = include_bytes!("examples/ec-crt.der");
+
+/// RSA chain certificate encoded as DER
+const RSA_CERT_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-crt.der");
+
+const PASSWORD: &str = "hunter42";
+
+#[test]
+fn build_with_chain() {
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let der = PfxBuilder::new(private_key)
+        .certificate(EC_CERT_EXAMPLE)
+        .certificate(RSA_CERT_EXAMPLE)
+        .friendly_name("Test EC")
+        .pbkdf2_iterations(1000)
+        .mac_iterations(1000)
+        .build(OsRng, PASSWORD)
+        .unwrap();
+
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    pfx.verify_mac(PASSWORD).unwrap();
+    assert_eq!(pfx.verify_mac("hunter2"), Err(Error::MacVerification));
+
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(mac_data.iterations, 1000);
+    assert_eq!(mac_data.mac.digest.as_bytes().len(), 32);
+
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    assert_eq!(auth_safe.len(), 2);
+
+    // Certificates, in order, with the end-entity certificate linked to the key
+    let certs = authenticated_safe::safe_contents(&auth_safe[0]).unwrap();
+    assert_eq!(certs.len(), 2);
+
+    for (bag, expected) in certs.iter().zip([EC_CERT_EXAMPLE, RSA_CERT_EXAMPLE]) {
+        assert_eq!(bag.bag_id, CERT_BAG_OID);
+        let cert_bag = bag.bag_value.decode_into::<CertBag<'_>>().unwrap();
+        assert_eq!(cert_bag.cert_id, X509_CERTIFICATE_OID);
+        assert_eq!(
+            cert_bag.cert_value.octet_string().unwrap().as_bytes(),
+            expected
+        );
+    }
+
+    let local_key_id = Sha256::digest(EC_CERT_EXAMPLE);
+    let attrs = certs[0].bag_attributes.unwrap();
+    assert_eq!(attrs.friendly_name().unwrap().unwrap(), "Test EC");
+    assert_eq!(
+        attrs.local_key_id().unwrap().unwrap(),
+        local_key_id.as_slice()
+    );
+    assert_eq!(certs[1].bag_attributes, None);
+
+    // Shrouded private key
+    let keys = authenticated_safe::safe_contents(&auth_safe[1]).unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].bag_id, PKCS8_SHROUDED_KEY_BAG_OID);
+    assert_eq!(keys[0].bag_attributes, certs[0].bag_attributes);
+
+    let encrypted_key_der = keys[0].bag_value.to_vec().unwrap();
+    let encrypted_key = EncryptedPrivateKeyInfo::try_from(encrypted_key_der.as_slice()).unwrap();
+    let pbes2_params = encrypted_key.encryption_algorithm.pbes2().unwrap();
+    assert_eq!(pbes2_params.kdf.pbkdf2().unwrap().iteration_count, 1000);
+    assert_eq!(
+        encrypted_key.decrypt(PASSWORD).unwrap().as_bytes(),
+        EC_KEY_EXAMPLE
+    );
+}
+
+#[test]
+fn build_key_only() {
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let der = PfxBuilder::new(private_key)
+        .pbkdf2_iterations(1000)
+        .build(OsRng, PASSWORD)
+        .unwrap();
+
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    pfx.verify_mac(PASSWORD).unwrap();
+    assert_eq!(
+        pfx.mac_data.as_ref().unwrap().iterations,
+        PfxBuilder::MAC_ITERATIONS
+    );
+
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    assert_eq!(auth_safe.len(), 1);
+
+    let keys = authenticated_safe::safe_contents(&auth_safe[0]).unwrap();
+    assert_eq!(keys[0].bag_id, PKCS8_SHROUDED_KEY_BAG_OID);
+    assert_eq!(keys[0].bag_attributes, None);
+}
+
+#[test]
+fn build_sorts_attributes() {
+    // A `friendlyName` longer than the `localKeyId` sorts after it
+    let friendly_name = "A friendly name longer than the key ID";
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let der = PfxBuilder::new(private_key)
+        .certificate(EC_CERT_EXAMPLE)
+        .friendly_name(friendly_name)
+        .pbkdf2_iterations(1000)
+        .build(OsRng, PASSWORD)
+        .unwrap();
+
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    let certs = authenticated_safe::safe_contents(&auth_safe[0]).unwrap();
+    let attrs = certs[0].bag_attributes.unwrap();
+    assert_eq!(attrs.friendly_name().unwrap().unwrap(), friendly_name);
+
+    // Decoding as a `SetOfVec` rejects attributes which aren't in DER order
+    let attrs_der = attrs.to_vec().unwrap();
+    let attrs = x509_cert::attr::Attributes::from_der(&attrs_der).unwrap();
+    assert_eq!(attrs.len(), 2);
+    assert_eq!(attrs.get(0).unwrap().oid, LOCAL_KEY_ID_OID);
+}
+
+#[test]
+fn reject_mac_iterations_over_max() {
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let result = PfxBuilder::new(private_key)
+        .pbkdf2_iterations(1000)
+        .mac_iterations(MacData::MAX_ITERATIONS + 1)
+        .build(OsRng, PASSWORD);
+    assert!(matches!(result, Err(Error::Asn1(_))));
+}
@@ -0,0 +1,210 @@
+//! `Pfx::extract` tests
+
+#![cfg(feature = "encryption")]
+
+use hex_literal::hex;
+use pkcs12::{
+    cms::ContentInfo,
+    der::{
+        asn1::{Any, ObjectIdentifier, OctetString},
+        Decode, Encode,
+    },
+    pkcs8::PrivateKeyInfo,
+    safe_bag::SAFE_CONTENTS_BAG_OID,
+    Error, Pfx, PfxBuilder, SafeBag, Version,
+};
+use rand_core::OsRng;
+
+/// Unencrypted certificates and key, with an HMAC-SHA256 MAC
+const PLAIN_EXAMPLE: &[u8] = include_bytes!("examples/ec-plain.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with an
+/// HMAC-SHA256 MAC
+const AES256_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-sha256.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with an
+/// HMAC-SHA512 MAC
+const AES256_SHA512_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-sha512.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with a PBMAC1 MAC
+const AES256_PBMAC1_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-pbmac1.p12");
+
+/// Certificates encrypted with pbeWithSHAAnd40BitRC2-CBC and key encrypted
+/// with pbeWithSHAAnd3-KeyTripleDES-CBC, with an HMAC-SHA1 MAC
+#[cfg(feature = "legacy")]
+const LEGACY_EXAMPLE: &[u8] = include_bytes!("examples/ec-legacy.p12");
+
+/// Certificates and key encrypted with pbeWithSHAAnd3-KeyTripleDES-CBC, with
+/// an HMAC-SHA1 MAC
+#[cfg(feature = "legacy")]
+const DES3_EXAMPLE: &[u8] = include_bytes!("examples/ec-3des-sha1.p12");
+
+/// EC private key encoded as PKCS#8 DER
+const EC_KEY_EXAMPLE: &[u8] = include_bytes!("examples/ec-prv.der");
+
+/// EC end-entity certificate encoded as DER
+const EC_CERT_EXAMPLE: &[u8] = include_bytes!("examples/ec-crt.der");
+
+/// RSA chain certificate encoded as DER
+const RSA_CERT_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-crt.der");
+
+/// `localKeyID` of the EC key and certificate
+const LOCAL_KEY_ID: [u8; 20] = hex!("C415ACBD7EF2379447AEDA925E0D1D097B250B11");
+
+const PASSWORD: &str = "hunter42";
+
+#[test]
+fn extract_plain() {
+    extract_test(PLAIN_EXAMPLE);
+}
+
+#[test]
+fn extract_aes256() {
+    extract_test(AES256_EXAMPLE);
+}
+
+#[test]
+fn extract_aes256_sha512() {
+    extract_test(AES256_SHA512_EXAMPLE);
+}
+
+#[test]
+fn extract_aes256_pbmac1() {
+    extract_test(AES256_PBMAC1_EXAMPLE);
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn extract_legacy() {
+    extract_test(LEGACY_EXAMPLE);
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn extract_3des() {
+    extract_test(DES3_EXAMPLE);
+}
+
+#[test]
+fn extract_wrong_password() {
+    let pfx = Pfx::try_from(AES256_EXAMPLE).unwrap();
+    assert_eq!(pfx.extract("hunter2").err(), Some(Error::MacVerification));
+}
+
+#[test]
+fn extract_built() {
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let der = PfxBuilder::new(private_key)
+        .certificate(RSA_CERT_EXAMPLE)
+        .certificate(EC_CERT_EXAMPLE)
+        .pbkdf2_iterations(1000)
+        .build(OsRng, PASSWORD)
+        .unwrap();
+
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    let (keys, certs, crls) = pfx.extract(PASSWORD).unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(certs.len(), 2);
+    assert!(crls.is_empty());
+
+    // The key is linked to the first certificate, without a `friendlyName`
+    assert_eq!(keys[0].private_key.as_bytes(), EC_KEY_EXAMPLE);
+    assert_eq!(keys[0].friendly_name, None);
+    assert_eq!(keys[0].certificate, Some(0));
+    assert_eq!(certs[0].certificate.as_bytes(), RSA_CERT_EXAMPLE);
+    assert_eq!(certs[0].local_key_id, keys[0].local_key_id);
+    assert_eq!(certs[1].local_key_id, None);
+}
+
+#[test]
+fn extract_built_scrypt() {
+    let private_key = PrivateKeyInfo::try_from(EC_KEY_EXAMPLE).unwrap();
+    let der = PfxBuilder::new(private_key)
+        .certificate(EC_CERT_EXAMPLE)
+        .scrypt(10, 8, 1)
+        .build(OsRng, PASSWORD)
+        .unwrap();
+
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    let (keys, certs, _) = pfx.extract(PASSWORD).unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].private_key.as_bytes(), EC_KEY_EXAMPLE);
+    assert_eq!(certs[0].certificate.as_bytes(), EC_CERT_EXAMPLE);
+}
+
+#[test]
+fn extract_nested() {
+    // Nesting up to the maximum depth is allowed
+    let der = nested_pfx(8);
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    let (keys, certs, crls) = pfx.extract(PASSWORD).unwrap();
+    assert!(keys.is_empty() && certs.is_empty() && crls.is_empty());
+
+    let der = nested_pfx(9);
+    let pfx = Pfx::try_from(der.as_slice()).unwrap();
+    assert_eq!(pfx.extract(PASSWORD).err(), Some(Error::NestingDepth));
+}
+
+/// Encode a PFX without a MAC, containing an empty `SafeContents` nested in
+/// `depth` `safeContentsBag`s.
+fn nested_pfx(depth: usize) -> Vec<u8> {
+    let mut safe_contents = Vec::<SafeBag<'_>>::new().to_vec().unwrap();
+
+    for _ in 0..depth {
+        let bag = SafeBag {
+            bag_id: SAFE_CONTENTS_BAG_OID,
+            bag_value: Any::from_der(&safe_contents).unwrap(),
+            bag_attributes: None,
+        };
+        safe_contents = vec![bag].to_vec().unwrap();
+    }
+
+    let id_data = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+    let authenticated_safe = vec![ContentInfo {
+        content_type: id_data,
+        content: OctetString::new(safe_contents.as_slice()).unwrap().into(),
+    }]
+    .to_vec()
+    .unwrap();
+
+    Pfx {
+        version: Version::V3,
+        auth_safe: ContentInfo {
+            content_type: id_data,
+            content: OctetString::new(authenticated_safe.as_slice())
+                .unwrap()
+                .into(),
+        },
+        mac_data: None,
+    }
+    .to_vec()
+    .unwrap()
+}
+
+/// Extract the contents of one of the OpenSSL-generated examples.
+fn extract_test(der: &[u8]) {
+    let pfx = Pfx::try_from(der).unwrap();
+    let (keys, certs, crls) = pfx.extract(PASSWORD).unwrap();
+
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].private_key.as_bytes(), EC_KEY_EXAMPLE);
+    assert_eq!(keys[0].friendly_name.as_deref(), Some("Test EC"));
+    assert_eq!(
+        keys[0].local_key_id.as_deref(),
+        Some(LOCAL_KEY_ID.as_slice())
+    );
+    assert_eq!(keys[0].certificate, Some(0));
+
+    assert_eq!(certs.len(), 2);
+    assert_eq!(certs[0].certificate.as_bytes(), EC_CERT_EXAMPLE);
+    assert_eq!(certs[0].friendly_name.as_deref(), Some("Test EC"));
+    assert_eq!(
+        certs[0].local_key_id.as_deref(),
+        Some(LOCAL_KEY_ID.as_slice())
+    );
+    assert_eq!(certs[1].certificate.as_bytes(), RSA_CERT_EXAMPLE);
+    assert_eq!(certs[1].friendly_name, None);
+    assert_eq!(certs[1].local_key_id, None);
+
+    assert!(crls.is_empty());
+}
@@ -0,0 +1,229 @@
+//! `PFX` decoding tests
+
+use const_oid::db::rfc5911::{ID_DATA, ID_ENCRYPTED_DATA};
+use der::{asn1::ObjectIdentifier, Decode, Encode, ErrorKind, Tag};
+use hex_literal::hex;
+use pkcs12::{
+    authenticated_safe,
+    safe_bag::{CERT_BAG_OID, KEY_BAG_OID, PKCS8_SHROUDED_KEY_BAG_OID},
+    Bag, Error, MacData, Pfx, Version,
+};
+
+/// Unencrypted certificates and key, with an HMAC-SHA256 MAC
+const PLAIN_EXAMPLE: &[u8] = include_bytes!("examples/ec-plain.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with an
+/// HMAC-SHA256 MAC
+const AES256_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-sha256.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with an
+/// HMAC-SHA512 MAC
+#[cfg(feature = "encryption")]
+const AES256_SHA512_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-sha512.p12");
+
+/// Certificates and key encrypted with PBES2/AES-256-CBC, with a PBMAC1 MAC
+const AES256_PBMAC1_EXAMPLE: &[u8] = include_bytes!("examples/ec-aes256-pbmac1.p12");
+
+/// Certificates encrypted with pbeWithSHAAnd40BitRC2-CBC and key encrypted
+/// with pbeWithSHAAnd3-KeyTripleDES-CBC, with an HMAC-SHA1 MAC
+#[cfg(feature = "encryption")]
+const LEGACY_EXAMPLE: &[u8] = include_bytes!("examples/ec-legacy.p12");
+
+/// EC private key encoded as PKCS#8 DER
+const EC_KEY_EXAMPLE: &[u8] = include_bytes!("examples/ec-prv.der");
+
+/// EC end-entity certificate encoded as DER
+const EC_CERT_EXAMPLE: &[u8] = include_bytes!("examples/ec-crt.der");
+
+/// RSA chain certificate encoded as DER
+const RSA_CERT_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-crt.der");
+
+/// `id-sha256` OID
+const SHA256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+
+/// `id-PBMAC1` OID
+const PBMAC1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.14");
+
+/// `localKeyID` of the EC key and certificate
+const LOCAL_KEY_ID: [u8; 20] = hex!("C415ACBD7EF2379447AEDA925E0D1D097B250B11");
+
+#[test]
+fn decode_plain() {
+    let pfx = Pfx::try_from(PLAIN_EXAMPLE).unwrap();
+    assert_eq!(pfx.version, Version::V3);
+    assert_eq!(pfx.auth_safe.content_type, ID_DATA);
+
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(mac_data.mac.digest_algorithm.oid, SHA256_OID);
+    assert_eq!(
+        mac_data.mac.digest.as_bytes(),
+        hex!("D99B49D268742EA5E76A52BEAF93B4A5C3181182C4959B60ED69E7F32A2F3C4A")
+    );
+    assert_eq!(mac_data.mac_salt.as_bytes(), hex!("78BDC17E79DD0733"));
+    assert_eq!(mac_data.iterations, 2048);
+
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    assert_eq!(auth_safe.len(), 2);
+
+    let certs = authenticated_safe::safe_contents(&auth_safe[0]).unwrap();
+    assert_eq!(certs.len(), 2);
+    assert!(certs.iter().all(|bag| bag.bag_id == CERT_BAG_OID));
+
+    let attrs = certs[0].bag_attributes.unwrap();
+    assert_eq!(attrs.friendly_name().unwrap().unwrap(), "Test EC");
+    assert_eq!(attrs.local_key_id().unwrap().unwrap(), LOCAL_KEY_ID);
+    assert_eq!(certs[1].bag_attributes, None);
+
+    let keys = authenticated_safe::safe_contents(&auth_safe[1]).unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].bag_id, KEY_BAG_OID);
+
+    let attrs = keys[0].bag_attributes.unwrap();
+    assert_eq!(attrs.friendly_name().unwrap().unwrap(), "Test EC");
+    assert_eq!(attrs.local_key_id().unwrap().unwrap(), LOCAL_KEY_ID);
+}
+
+#[test]
+fn decode_aes256() {
+    let pfx = Pfx::try_from(AES256_EXAMPLE).unwrap();
+    assert_eq!(pfx.version, Version::V3);
+
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(mac_data.mac.digest_algorithm.oid, SHA256_OID);
+    assert_eq!(mac_data.iterations, 2048);
+
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    assert_eq!(auth_safe.len(), 2);
+
+    // Certificates are encrypted
+    assert_eq!(auth_safe[0].content_type, ID_ENCRYPTED_DATA);
+    assert_eq!(
+        authenticated_safe::safe_contents(&auth_safe[0]).err(),
+        Some(Error::ContentType)
+    );
+
+    // The key is shrouded, but the `SafeContents` itself is not encrypted
+    let keys = authenticated_safe::safe_contents(&auth_safe[1]).unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].bag_id, PKCS8_SHROUDED_KEY_BAG_OID);
+    assert_eq!(
+        keys[0].bag_attributes.unwrap().local_key_id().unwrap(),
+        Some(LOCAL_KEY_ID.as_slice())
+    );
+}
+
+#[test]
+fn decode_bag_values() {
+    let pfx = Pfx::try_from(PLAIN_EXAMPLE).unwrap();
+    let auth_safe = pfx.authenticated_safe().unwrap();
+
+    let certs = authenticated_safe::safe_contents(&auth_safe[0]).unwrap();
+    for (bag, expected) in certs.iter().zip([EC_CERT_EXAMPLE, RSA_CERT_EXAMPLE]) {
+        match bag.value().unwrap() {
+            Bag::Certificate(certificate) => assert_eq!(certificate.to_vec().unwrap(), expected),
+            other => panic!("unexpected bag: {:?}", other),
+        }
+    }
+
+    assert_eq!(certs[0].friendly_name().unwrap().unwrap(), "Test EC");
+    assert_eq!(certs[0].local_key_id().unwrap().unwrap(), LOCAL_KEY_ID);
+    assert_eq!(certs[1].friendly_name().unwrap(), None);
+    assert_eq!(certs[1].local_key_id().unwrap(), None);
+
+    let keys = authenticated_safe::safe_contents(&auth_safe[1]).unwrap();
+    match keys[0].value().unwrap() {
+        Bag::Key(private_key) => assert_eq!(private_key.to_vec().unwrap(), EC_KEY_EXAMPLE),
+        other => panic!("unexpected bag: {:?}", other),
+    }
+}
+
+#[test]
+fn decode_shrouded_key_bag_value() {
+    let pfx = Pfx::try_from(AES256_EXAMPLE).unwrap();
+    let auth_safe = pfx.authenticated_safe().unwrap();
+    let keys = authenticated_safe::safe_contents(&auth_safe[1]).unwrap();
+
+    match keys[0].value().unwrap() {
+        Bag::ShroudedKey(encrypted_private_key) => {
+            assert!(encrypted_private_key.encryption_algorithm.pbes2().is_some())
+        }
+        other => panic!("unexpected bag: {:?}", other),
+    }
+}
+
+#[test]
+fn encode_plain() {
+    let pfx = Pfx::try_from(PLAIN_EXAMPLE).unwrap();
+    assert_eq!(pfx.to_vec().unwrap(), PLAIN_EXAMPLE);
+}
+
+#[test]
+fn encode_aes256() {
+    let pfx = Pfx::try_from(AES256_EXAMPLE).unwrap();
+    assert_eq!(pfx.to_vec().unwrap(), AES256_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn verify_mac() {
+    for der in [PLAIN_EXAMPLE, AES256_EXAMPLE] {
+        let pfx = Pfx::try_from(der).unwrap();
+        pfx.verify_mac("hunter42").unwrap();
+        assert_eq!(pfx.verify_mac("hunter2"), Err(Error::MacVerification));
+    }
+}
+
+#[test]
+fn decode_pbmac1() {
+    let pfx = Pfx::try_from(AES256_PBMAC1_EXAMPLE).unwrap();
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(mac_data.mac.digest_algorithm.oid, PBMAC1_OID);
+    assert_eq!(mac_data.mac.digest.as_bytes().len(), 32);
+    assert_eq!(pfx.to_vec().unwrap(), AES256_PBMAC1_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn verify_mac_sha512() {
+    let pfx = Pfx::try_from(AES256_SHA512_EXAMPLE).unwrap();
+    pfx.verify_mac("hunter42").unwrap();
+    assert_eq!(pfx.verify_mac("hunter2"), Err(Error::MacVerification));
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn verify_mac_pbmac1() {
+    let pfx = Pfx::try_from(AES256_PBMAC1_EXAMPLE).unwrap();
+    pfx.verify_mac("hunter42").unwrap();
+    assert_eq!(pfx.verify_mac("hunter2"), Err(Error::MacVerification));
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn verify_mac_sha1() {
+    let pfx = Pfx::try_from(LEGACY_EXAMPLE).unwrap();
+    pfx.verify_mac("hunter42").unwrap();
+    assert_eq!(pfx.verify_mac("hunter2"), Err(Error::MacVerification));
+}
+
+#[cfg(all(feature = "encryption", not(feature = "legacy")))]
+#[test]
+fn verify_mac_sha1_unsupported() {
+    let pfx = Pfx::try_from(LEGACY_EXAMPLE).unwrap();
+    assert_eq!(pfx.verify_mac("hunter42"), Err(Error::MacAlgorithm));
+}
+
+#[test]
+fn reject_mac_iterations_over_max() {
+    let mut mac_data = Pfx::try_from(PLAIN_EXAMPLE).unwrap().mac_data.unwrap();
+    mac_data.iterations = MacData::MAX_ITERATIONS;
+    let der = mac_data.to_vec().unwrap();
+    assert_eq!(MacData::from_der(&der).unwrap(), mac_data);
+
+    mac_data.iterations += 1;
+    let der = mac_data.to_vec().unwrap();
+    assert_eq!(
+        MacData::from_der(&der).unwrap_err().kind(),
+        ErrorKind::Value { tag: Tag::Integer }
+    );
+}
@@ -16,13 +16,23 @@ pub enum Error {
         oid: ObjectIdentifier,
     },
 
+    /// ASN.1 DER-related errors, including PEM encoding errors.
+    #[cfg(feature = "pem")]
+    Asn1(der::Error),
+
     /// Decryption Failed
     DecryptFailed,
 
+    /// Legacy encrypted PEM document is not encrypted, or its `DEK-Info`
+    /// header is invalid or names an unsupported cipher.
+    #[cfg(feature = "pem")]
+    DekInfoInvalid,
+
     /// Encryption Failed
     EncryptFailed,
 
-    /// Pbes1 support is limited to parsing; encryption/decryption is not supported (won't fix)
+    /// PBES1 encryption is not supported (won't fix), and decryption requires
+    /// the `legacy` feature
     #[cfg(feature = "pbes2")]
     NoPbes1CryptSupport,
 
@@ -42,15 +52,29 @@ impl fmt::Display for Error {
             Error::AlgorithmParametersInvalid { oid } => {
                 write!(f, "PKCS#5 parameters for algorithm {} are invalid", oid)
             }
+            #[cfg(feature = "pem")]
+            Error::Asn1(err) => write!(f, "PKCS#5 ASN.1 error: {}", err),
             Error::DecryptFailed => f.write_str("PKCS#5 decryption failed"),
+            #[cfg(feature = "pem")]
+            Error::DekInfoInvalid => f.write_str("PKCS#5 invalid DEK-Info header"),
             Error::EncryptFailed => f.write_str("PKCS#5 encryption failed"),
             #[cfg(feature = "pbes2")]
-            Error::NoPbes1CryptSupport => {
-                f.write_str("PKCS#5 encryption/decryption unsupported for PBES1 (won't fix)")
-            }
+            Error::NoPbes1CryptSupport => f.write_str(
+                "PKCS#5 encryption unsupported for PBES1 (decryption requires `legacy`)",
+            ),
             Error::UnsupportedAlgorithm { oid } => {
                 write!(f, "PKCS#5 algorithm {} is unsupported", oid)
             }
         }
     }
 }
+
+#[cfg(feature = "pem")]
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
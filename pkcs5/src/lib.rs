@@ -17,18 +17,31 @@
 //!
 //! [RFC 8018]: https://tools.ietf.org/html/rfc8018
 
-#[cfg(all(feature = "alloc", feature = "pbes2"))]
+#[cfg(any(
+    all(feature = "alloc", feature = "pbes2"),
+    feature = "pem",
+    feature = "pkcs12-kdf"
+))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod error;
 
 pub mod pbes1;
 pub mod pbes2;
 
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub mod pem;
+
 pub use crate::error::{Error, Result};
 pub use der::{self, asn1::ObjectIdentifier};
 pub use spki::AlgorithmIdentifier;
 
+#[cfg(feature = "rand_core")]
+pub use rand_core;
+
 use der::{Decode, Decoder, Encode, Sequence, Tag};
 
 #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -42,7 +55,7 @@ pub enum EncryptionScheme<'a> {
     /// Password-Based Encryption Scheme 1 as defined in [RFC 8018 Section 6.1].
     ///
     /// [RFC 8018 Section 6.1]: https://tools.ietf.org/html/rfc8018#section-6.1
-    Pbes1(pbes1::Algorithm),
+    Pbes1(pbes1::Algorithm<'a>),
 
     /// Password-Based Encryption Scheme 2 as defined in [RFC 8018 Section 6.2].
     ///
@@ -59,6 +72,9 @@ impl<'a> EncryptionScheme<'a> {
     pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
         match self {
             Self::Pbes2(params) => params.decrypt(password, ciphertext),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.decrypt(password, ciphertext),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
         }
     }
@@ -69,6 +85,8 @@ impl<'a> EncryptionScheme<'a> {
     /// Returns an error if the algorithm specified in this scheme's parameters
     /// is unsupported, or if the ciphertext is malformed (e.g. not a multiple
     /// of a block mode's padding)
+    ///
+    /// PBES1 decryption requires the `legacy` feature.
     #[cfg(feature = "pbes2")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn decrypt_in_place<'b>(
@@ -78,6 +96,9 @@ impl<'a> EncryptionScheme<'a> {
     ) -> Result<&'b [u8]> {
         match self {
             Self::Pbes2(params) => params.decrypt_in_place(password, buffer),
+            #[cfg(feature = "legacy")]
+            Self::Pbes1(alg) => alg.decrypt_in_place(password, buffer),
+            #[cfg(not(feature = "legacy"))]
             Self::Pbes1(_) => Err(Error::NoPbes1CryptSupport),
         }
     }
@@ -119,7 +140,7 @@ impl<'a> EncryptionScheme<'a> {
     }
 
     /// Get [`pbes1::Parameters`] if it is the selected algorithm.
-    pub fn pbes1(&self) -> Option<&pbes1::Algorithm> {
+    pub fn pbes1(&self) -> Option<&pbes1::Algorithm<'a>> {
         match self {
             Self::Pbes1(alg) => Some(alg),
             _ => None,
@@ -153,8 +174,8 @@ impl<'a> Sequence<'a> for EncryptionScheme<'a> {
     }
 }
 
-impl<'a> From<pbes1::Algorithm> for EncryptionScheme<'a> {
-    fn from(alg: pbes1::Algorithm) -> EncryptionScheme<'a> {
+impl<'a> From<pbes1::Algorithm<'a>> for EncryptionScheme<'a> {
+    fn from(alg: pbes1::Algorithm<'a>) -> EncryptionScheme<'a> {
         Self::Pbes1(alg)
     }
 }
@@ -1,6 +1,16 @@
 //! Password-Based Encryption Scheme 1 as defined in [RFC 8018 Section 6.1].
 //!
+//! Also includes the PKCS#12 password-based encryption schemes defined in
+//! [RFC 7292 Appendix C], which share the same parameters.
+//!
 //! [RFC 8018 Section 6.1]: https://tools.ietf.org/html/rfc8018#section-6.1
+//! [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+
+#[cfg(feature = "legacy")]
+mod encryption;
+#[cfg(feature = "pkcs12-kdf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs12-kdf")))]
+pub mod kdf;
 
 use crate::AlgorithmIdentifier;
 use der::{
@@ -8,6 +18,12 @@ use der::{
     Decode, Decoder, Encode, ErrorKind, Length, Sequence, Tag, Writer,
 };
 
+#[cfg(feature = "legacy")]
+use crate::Result;
+
+#[cfg(feature = "legacy")]
+use alloc::vec::Vec;
+
 /// `pbeWithMD2AndDES-CBC` Object Identifier (OID).
 pub const PBE_WITH_MD2_AND_DES_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.1");
@@ -32,6 +48,22 @@ pub const PBE_WITH_SHA1_AND_DES_CBC_OID: ObjectIdentifier =
 pub const PBE_WITH_SHA1_AND_RC2_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.113549.1.5.11");
 
+/// `pbeWithSHAAnd128BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128_BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.5");
+
+/// `pbeWithSHAAnd40BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40_BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.6");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.3");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.4");
+
 /// Length of a PBES1 salt (as defined in the `PBEParameter` ASN.1 message).
 pub const SALT_LENGTH: usize = 8;
 
@@ -51,28 +83,57 @@ pub const SALT_LENGTH: usize = 8;
 ///
 /// [RFC 8018 Appendix A.C]: https://datatracker.ietf.org/doc/html/rfc8018#appendix-C
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Algorithm {
+pub struct Algorithm<'a> {
     /// Encryption scheme.
     pub encryption: EncryptionScheme,
 
     /// Scheme parameters.
-    pub parameters: Parameters,
+    pub parameters: Parameters<'a>,
 }
 
-impl Algorithm {
+impl<'a> Algorithm<'a> {
     /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
     pub fn oid(&self) -> ObjectIdentifier {
         self.encryption.oid()
     }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    #[cfg(feature = "legacy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "legacy")))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and this algorithm's parameters.
+    ///
+    /// The PKCS#12 schemes require the password to be valid UTF-8, as it is
+    /// converted to a `BMPString` when deriving the key.
+    ///
+    /// Returns an error if the algorithm is unsupported (i.e. uses MD2), or
+    /// if the ciphertext is malformed.
+    #[cfg(feature = "legacy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "legacy")))]
+    pub fn decrypt_in_place<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, password.as_ref(), buffer)
+    }
 }
 
-impl Decode<'_> for Algorithm {
-    fn decode(decoder: &mut Decoder<'_>) -> der::Result<Self> {
+impl<'a> Decode<'a> for Algorithm<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
         AlgorithmIdentifier::decode(decoder)?.try_into()
     }
 }
 
-impl Sequence<'_> for Algorithm {
+impl<'a> Sequence<'a> for Algorithm<'a> {
     fn fields<F, T>(&self, f: F) -> der::Result<T>
     where
         F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
@@ -81,7 +142,7 @@ impl Sequence<'_> for Algorithm {
     }
 }
 
-impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Algorithm {
+impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Algorithm<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
@@ -89,13 +150,20 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Algorithm {
         let encryption = EncryptionScheme::try_from(alg.oid)
             .map_err(|_| der::Tag::ObjectIdentifier.value_error())?;
 
-        match alg.parameters {
-            Some(any) => Ok(Self {
-                encryption,
-                parameters: any.try_into()?,
-            }),
-            None => Err(Tag::OctetString.value_error()),
+        let parameters: Parameters<'a> = match alg.parameters {
+            Some(any) => any.try_into()?,
+            None => return Err(Tag::OctetString.value_error()),
+        };
+
+        // PBES1 salts are always 8 bytes, whereas PKCS#12 allows any length
+        if !encryption.is_pkcs12() && parameters.salt.len() != SALT_LENGTH {
+            return Err(Tag::OctetString.value_error());
         }
+
+        Ok(Self {
+            encryption,
+            parameters,
+        })
     }
 }
 
@@ -107,42 +175,40 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Algorithm {
 ///    iterationCount INTEGER }
 /// ```
 ///
+/// The PKCS#12 `pkcs-12PbeParams` structure is identical, except the salt
+/// may be of any length.
+///
 /// [RFC 8018 Appendix A.3]: https://tools.ietf.org/html/rfc8018#appendix-A.3
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Parameters {
+pub struct Parameters<'a> {
     /// Salt value
-    pub salt: [u8; SALT_LENGTH],
+    pub salt: &'a [u8],
 
     /// Iteration count
-    pub iteration_count: u16,
+    pub iteration_count: u32,
 }
 
-impl Decode<'_> for Parameters {
-    fn decode(decoder: &mut Decoder<'_>) -> der::Result<Self> {
+impl<'a> Decode<'a> for Parameters<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
         Any::decode(decoder)?.try_into()
     }
 }
 
-impl Sequence<'_> for Parameters {
+impl<'a> Sequence<'a> for Parameters<'a> {
     fn fields<F, T>(&self, f: F) -> der::Result<T>
     where
         F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
     {
-        f(&[&OctetString::new(&self.salt)?, &self.iteration_count])
+        f(&[&OctetString::new(self.salt)?, &self.iteration_count])
     }
 }
 
-impl TryFrom<Any<'_>> for Parameters {
+impl<'a> TryFrom<Any<'a>> for Parameters<'a> {
     type Error = der::Error;
 
-    fn try_from(any: Any<'_>) -> der::Result<Parameters> {
+    fn try_from(any: Any<'a>) -> der::Result<Parameters<'a>> {
         any.sequence(|params| {
-            let salt = params
-                .octet_string()?
-                .as_bytes()
-                .try_into()
-                .map_err(|_| der::Tag::OctetString.value_error())?;
-
+            let salt = params.octet_string()?.as_bytes();
             let iteration_count = params.decode()?;
 
             Ok(Parameters {
@@ -153,9 +219,11 @@ impl TryFrom<Any<'_>> for Parameters {
     }
 }
 
-/// Password-Based Encryption Scheme 1 ciphersuites as defined in [RFC 8018 Appendix A.3].
+/// Password-Based Encryption Scheme 1 ciphersuites as defined in [RFC 8018 Appendix A.3],
+/// along with the PKCS#12 ciphersuites defined in [RFC 7292 Appendix C].
 ///
 /// [RFC 8018 Appendix A.3]: https://tools.ietf.org/html/rfc8018#appendix-A.3
+/// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum EncryptionScheme {
     /// `pbeWithMD2AndDES-CBC`
@@ -175,6 +243,18 @@ pub enum EncryptionScheme {
 
     /// `pbeWithSHA1AndRC2-CBC`
     PbeWithSha1AndRc2Cbc,
+
+    /// `pbeWithSHAAnd128BitRC2-CBC` (PKCS#12)
+    PbeWithShaAnd128BitRc2Cbc,
+
+    /// `pbeWithSHAAnd40BitRC2-CBC` (PKCS#12)
+    PbeWithShaAnd40BitRc2Cbc,
+
+    /// `pbeWithSHAAnd3-KeyTripleDES-CBC` (PKCS#12)
+    PbeWithShaAnd3KeyTripleDesCbc,
+
+    /// `pbeWithSHAAnd2-KeyTripleDES-CBC` (PKCS#12)
+    PbeWithShaAnd2KeyTripleDesCbc,
 }
 
 impl TryFrom<ObjectIdentifier> for EncryptionScheme {
@@ -188,6 +268,10 @@ impl TryFrom<ObjectIdentifier> for EncryptionScheme {
             PBE_WITH_MD5_AND_RC2_CBC_OID => Ok(Self::PbeWithMd5AndRc2Cbc),
             PBE_WITH_SHA1_AND_DES_CBC_OID => Ok(Self::PbeWithSha1AndDesCbc),
             PBE_WITH_SHA1_AND_RC2_CBC_OID => Ok(Self::PbeWithSha1AndRc2Cbc),
+            PBE_WITH_SHA_AND_128_BIT_RC2_CBC_OID => Ok(Self::PbeWithShaAnd128BitRc2Cbc),
+            PBE_WITH_SHA_AND_40_BIT_RC2_CBC_OID => Ok(Self::PbeWithShaAnd40BitRc2Cbc),
+            PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID => Ok(Self::PbeWithShaAnd3KeyTripleDesCbc),
+            PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC_OID => Ok(Self::PbeWithShaAnd2KeyTripleDesCbc),
             _ => Err(ErrorKind::OidUnknown { oid }.into()),
         }
     }
@@ -203,6 +287,10 @@ impl EncryptionScheme {
             Self::PbeWithMd5AndRc2Cbc => SymmetricCipher::Rc2Cbc,
             Self::PbeWithSha1AndDesCbc => SymmetricCipher::DesCbc,
             Self::PbeWithSha1AndRc2Cbc => SymmetricCipher::Rc2Cbc,
+            Self::PbeWithShaAnd128BitRc2Cbc => SymmetricCipher::Rc2Cbc128,
+            Self::PbeWithShaAnd40BitRc2Cbc => SymmetricCipher::Rc2Cbc40,
+            Self::PbeWithShaAnd3KeyTripleDesCbc => SymmetricCipher::DesEde3Cbc,
+            Self::PbeWithShaAnd2KeyTripleDesCbc => SymmetricCipher::DesEde2Cbc,
         }
     }
 
@@ -215,9 +303,29 @@ impl EncryptionScheme {
             Self::PbeWithMd5AndRc2Cbc => DigestAlgorithm::Md5,
             Self::PbeWithSha1AndDesCbc => DigestAlgorithm::Sha1,
             Self::PbeWithSha1AndRc2Cbc => DigestAlgorithm::Sha1,
+            Self::PbeWithShaAnd128BitRc2Cbc => DigestAlgorithm::Sha1,
+            Self::PbeWithShaAnd40BitRc2Cbc => DigestAlgorithm::Sha1,
+            Self::PbeWithShaAnd3KeyTripleDesCbc => DigestAlgorithm::Sha1,
+            Self::PbeWithShaAnd2KeyTripleDesCbc => DigestAlgorithm::Sha1,
         }
     }
 
+    /// Is this one of the PKCS#12 ciphersuites?
+    ///
+    /// These derive keys using the PKCS#12 key derivation function defined
+    /// in [RFC 7292 Appendix B] rather than PBKDF1.
+    ///
+    /// [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+    pub fn is_pkcs12(self) -> bool {
+        matches!(
+            self,
+            Self::PbeWithShaAnd128BitRc2Cbc
+                | Self::PbeWithShaAnd40BitRc2Cbc
+                | Self::PbeWithShaAnd3KeyTripleDesCbc
+                | Self::PbeWithShaAnd2KeyTripleDesCbc
+        )
+    }
+
     /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
     pub fn oid(self) -> ObjectIdentifier {
         match self {
@@ -227,6 +335,10 @@ impl EncryptionScheme {
             Self::PbeWithMd5AndRc2Cbc => PBE_WITH_MD5_AND_RC2_CBC_OID,
             Self::PbeWithSha1AndDesCbc => PBE_WITH_SHA1_AND_DES_CBC_OID,
             Self::PbeWithSha1AndRc2Cbc => PBE_WITH_SHA1_AND_RC2_CBC_OID,
+            Self::PbeWithShaAnd128BitRc2Cbc => PBE_WITH_SHA_AND_128_BIT_RC2_CBC_OID,
+            Self::PbeWithShaAnd40BitRc2Cbc => PBE_WITH_SHA_AND_40_BIT_RC2_CBC_OID,
+            Self::PbeWithShaAnd3KeyTripleDesCbc => PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC_OID,
+            Self::PbeWithShaAnd2KeyTripleDesCbc => PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC_OID,
         }
     }
 }
@@ -260,6 +372,32 @@ pub enum SymmetricCipher {
     /// DES in CBC mode
     DesCbc,
 
-    /// RC2 in CBC mode
+    /// RC2 in CBC mode with a 64-bit key
     Rc2Cbc,
+
+    /// RC2 in CBC mode with a 128-bit key
+    Rc2Cbc128,
+
+    /// RC2 in CBC mode with a 40-bit key
+    Rc2Cbc40,
+
+    /// 3-Key Triple DES in CBC mode
+    DesEde3Cbc,
+
+    /// 2-Key Triple DES in CBC mode
+    DesEde2Cbc,
+}
+
+impl SymmetricCipher {
+    /// Get the size of a key used by this cipher in bytes.
+    pub fn key_size(self) -> usize {
+        match self {
+            Self::DesCbc => 8,
+            Self::Rc2Cbc => 8,
+            Self::Rc2Cbc128 => 16,
+            Self::Rc2Cbc40 => 5,
+            Self::DesEde3Cbc => 24,
+            Self::DesEde2Cbc => 16,
+        }
+    }
 }
@@ -0,0 +1,110 @@
+//! PBES1 and PKCS#12 PBE decryption.
+
+use super::{
+    kdf::{self, KeyType},
+    Algorithm, DigestAlgorithm, Parameters, SymmetricCipher,
+};
+use crate::{Error, Result};
+use cbc::cipher::{
+    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, InnerIvInit, InvalidLength, KeyInit,
+};
+use des::{Des, TdesEde2, TdesEde3};
+use rc2::Rc2;
+use sha1::{Digest, Sha1};
+
+/// Size of the IV used by all supported ciphers (i.e. the DES/RC2 block size).
+const IV_LEN: usize = 8;
+
+/// Maximum size of a derived encryption key (i.e. 3-key Triple DES).
+const MAX_KEY_LEN: usize = 24;
+
+pub(super) fn decrypt_in_place<'a>(
+    alg: &Algorithm<'_>,
+    password: &[u8],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let cipher = alg.encryption.cipher();
+    let key_len = cipher.key_size();
+    let mut key = [0u8; MAX_KEY_LEN];
+    let mut iv = [0u8; IV_LEN];
+
+    if alg.encryption.is_pkcs12() {
+        let password = core::str::from_utf8(password).map_err(|_| Error::DecryptFailed)?;
+        let params = &alg.parameters;
+        kdf::derive_key::<Sha1>(
+            password,
+            params.salt,
+            KeyType::EncryptionKey,
+            params.iteration_count,
+            &mut key[..key_len],
+        );
+        kdf::derive_key::<Sha1>(
+            password,
+            params.salt,
+            KeyType::Iv,
+            params.iteration_count,
+            &mut iv,
+        );
+    } else {
+        let dk = match alg.encryption.digest() {
+            DigestAlgorithm::Md5 => pbkdf1::<md5::Md5>(password, &alg.parameters),
+            DigestAlgorithm::Sha1 => pbkdf1::<Sha1>(password, &alg.parameters),
+            DigestAlgorithm::Md2 => {
+                return Err(Error::UnsupportedAlgorithm {
+                    oid: alg.encryption.oid(),
+                })
+            }
+        };
+
+        key[..key_len].copy_from_slice(&dk[..key_len]);
+        iv.copy_from_slice(&dk[key_len..]);
+    }
+
+    let key = &key[..key_len];
+
+    match cipher {
+        SymmetricCipher::DesCbc => cbc_decrypt(alg, Des::new_from_slice(key), &iv, buffer),
+        SymmetricCipher::Rc2Cbc => cbc_decrypt(alg, rc2(key, 64), &iv, buffer),
+        SymmetricCipher::Rc2Cbc128 => cbc_decrypt(alg, rc2(key, 128), &iv, buffer),
+        SymmetricCipher::Rc2Cbc40 => cbc_decrypt(alg, rc2(key, 40), &iv, buffer),
+        SymmetricCipher::DesEde3Cbc => cbc_decrypt(alg, TdesEde3::new_from_slice(key), &iv, buffer),
+        SymmetricCipher::DesEde2Cbc => cbc_decrypt(alg, TdesEde2::new_from_slice(key), &iv, buffer),
+    }
+}
+
+fn cbc_decrypt<'a, C: BlockDecryptMut + BlockCipher>(
+    alg: &Algorithm<'_>,
+    cipher: core::result::Result<C, InvalidLength>,
+    iv: &[u8],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    cipher
+        .and_then(|cipher| cbc::Decryptor::<C>::inner_iv_slice_init(cipher, iv))
+        .map_err(|_| Error::AlgorithmParametersInvalid { oid: alg.oid() })?
+        .decrypt_padded_mut::<Pkcs7>(buffer)
+        .map_err(|_| Error::DecryptFailed)
+}
+
+/// Initialize RC2 with the given effective key length in bits.
+fn rc2(key: &[u8], effective_key_bits: usize) -> core::result::Result<Rc2, InvalidLength> {
+    Ok(Rc2::new_with_eff_key_len(key, effective_key_bits))
+}
+
+/// PBKDF1 as defined in [RFC 8018 Section 5.1], returning 16 bytes of key
+/// material: an 8-byte DES/RC2 key followed by an 8-byte IV.
+///
+/// [RFC 8018 Section 5.1]: https://tools.ietf.org/html/rfc8018#section-5.1
+fn pbkdf1<D: Digest>(password: &[u8], params: &Parameters<'_>) -> [u8; 16] {
+    let mut t = D::new()
+        .chain_update(password)
+        .chain_update(params.salt)
+        .finalize();
+
+    for _ in 1..params.iteration_count {
+        t = D::digest(&t);
+    }
+
+    let mut dk = [0u8; 16];
+    dk.copy_from_slice(&t[..16]);
+    dk
+}
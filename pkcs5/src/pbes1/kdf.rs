@@ -0,0 +1,104 @@
+//! PKCS#12 key derivation function as defined in [RFC 7292 Appendix B].
+//!
+//! This KDF derives the keys and IVs of the PKCS#12 password-based
+//! encryption schemes, as well as PKCS#12 `MacData` integrity keys. Unlike
+//! PBKDF2, the password is encoded as a NUL-terminated `BMPString` (i.e. big
+//! endian UTF-16).
+//!
+//! [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+
+use alloc::{vec, vec::Vec};
+use der::zeroize::{Zeroize, Zeroizing};
+use digest::{core_api::BlockSizeUser, Digest, Output};
+
+/// Purpose of the key material derived by [`derive_key`], i.e. the "ID"
+/// byte described in [RFC 7292 Appendix B.3].
+///
+/// [RFC 7292 Appendix B.3]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum KeyType {
+    /// Encryption or decryption key.
+    EncryptionKey = 1,
+
+    /// Initialization vector.
+    Iv = 2,
+
+    /// MAC integrity key.
+    MacKey = 3,
+}
+
+/// Derive key material from a password using the PKCS#12 KDF with the
+/// digest `D`, filling `out`.
+///
+/// All intermediate values derived from the password are zeroized.
+pub fn derive_key<D>(
+    password: &str,
+    salt: &[u8],
+    key_type: KeyType,
+    iterations: u32,
+    out: &mut [u8],
+) where
+    D: Digest + BlockSizeUser,
+{
+    let v = D::block_size();
+    let password = bmp_password(password);
+    let diversifier = vec![key_type as u8; v];
+
+    // Concatenate the salt and password, each repeated to fill a multiple of
+    // `v` bytes
+    let mut i = Zeroizing::new(Vec::with_capacity(
+        round_up(salt.len(), v) + round_up(password.len(), v),
+    ));
+
+    for input in [salt, password.as_slice()] {
+        i.extend(input.iter().cycle().take(round_up(input.len(), v)));
+    }
+
+    let mut a = Output::<D>::default();
+
+    for chunk in out.chunks_mut(<D as Digest>::output_size()) {
+        D::new()
+            .chain_update(&diversifier)
+            .chain_update(i.as_slice())
+            .finalize_into(&mut a);
+
+        for _ in 1..iterations {
+            D::new().chain_update(&a).finalize_into(&mut a);
+        }
+
+        chunk.copy_from_slice(&a[..chunk.len()]);
+
+        // Set each `v`-byte block `I_j` to `I_j + B + 1`, where `B` is `A`
+        // repeated to fill `v` bytes
+        let b = Zeroizing::new(a.iter().copied().cycle().take(v).collect::<Vec<u8>>());
+
+        for block in i.chunks_mut(v) {
+            let mut carry = 1u16;
+
+            for (x, y) in block.iter_mut().zip(b.iter()).rev() {
+                let sum = u16::from(*x) + u16::from(*y) + carry;
+                *x = sum as u8;
+                carry = sum >> 8;
+            }
+        }
+    }
+
+    a.as_mut_slice().zeroize();
+}
+
+/// Encode a password as a NUL-terminated big endian UTF-16 `BMPString`.
+fn bmp_password(password: &str) -> Zeroizing<Vec<u8>> {
+    let mut bmp = Zeroizing::new(Vec::with_capacity((password.len() + 1) * 2));
+
+    for c in password.encode_utf16().chain(Some(0)) {
+        bmp.extend_from_slice(&c.to_be_bytes());
+    }
+
+    bmp
+}
+
+/// Round `n` up to the nearest multiple of `m`.
+fn round_up(n: usize, m: usize) -> usize {
+    (n + m - 1) / m * m
+}
@@ -2,20 +2,40 @@
 //!
 //! [RFC 8018 Section 6.2]: https://tools.ietf.org/html/rfc8018#section-6.2
 
+mod builder;
 mod kdf;
 
+#[cfg(feature = "pbes2")]
+mod custom;
 #[cfg(feature = "pbes2")]
 mod encryption;
+#[cfg(feature = "pbes2")]
+mod stream;
 
+pub use self::builder::{ParametersBuilder, Strength};
 pub use self::kdf::{
-    Kdf, Pbkdf2Params, Pbkdf2Prf, ScryptParams, HMAC_WITH_SHA1_OID, HMAC_WITH_SHA256_OID,
+    Argon2Params, Kdf, Pbkdf2Params, Pbkdf2Prf, ScryptParams, ARGON2ID_OID, HMAC_WITH_SHA1_OID,
+    HMAC_WITH_SHA224_OID, HMAC_WITH_SHA256_OID, HMAC_WITH_SHA384_OID, HMAC_WITH_SHA3_224_OID,
+    HMAC_WITH_SHA3_256_OID, HMAC_WITH_SHA3_384_OID, HMAC_WITH_SHA3_512_OID, HMAC_WITH_SHA512_OID,
     PBKDF2_OID, SCRYPT_OID,
 };
 
+#[cfg(feature = "pbes2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+pub use self::custom::{CustomCipher, CustomKdf, Registry};
+
+#[cfg(feature = "pbes2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+pub use self::stream::{Decryptor, Encryptor};
+
+#[cfg(all(feature = "pbes2", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use self::stream::{DecryptReader, EncryptWriter};
+
 use crate::{AlgorithmIdentifier, Error, Result};
 use der::{
     asn1::{Any, ObjectIdentifier, OctetString},
-    Decode, Decoder, Encode, ErrorKind, Length, Sequence, Tag, Writer,
+    Decode, Decoder, Encode, Sequence, Tag,
 };
 
 #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -36,6 +56,16 @@ pub const AES_192_CBC_OID: ObjectIdentifier =
 pub const AES_256_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.42");
 
+/// 128-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation.
+pub const AES_128_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.6");
+
+/// 256-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation.
+pub const AES_256_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.46");
+
 /// DES operating in CBC mode
 #[cfg(feature = "des-insecure")]
 #[cfg_attr(docsrs, doc(cfg(feature = "des-insecure")))]
@@ -54,6 +84,12 @@ pub const PBES2_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.11
 /// AES cipher block size
 const AES_BLOCK_SIZE: usize = 16;
 
+/// AES-GCM nonce size
+const GCM_NONCE_SIZE: usize = 12;
+
+/// Default AES-GCM integrity check value (i.e. tag) length
+const GCM_DEFAULT_ICV_LEN: u8 = 12;
+
 /// DES / Triple DES block size
 #[cfg(any(feature = "3des", feature = "des-insecure"))]
 const DES_BLOCK_SIZE: usize = 8;
@@ -138,14 +174,89 @@ impl<'a> Parameters<'a> {
         Ok(Self { kdf, encryption })
     }
 
+    /// Initialize PBES2 parameters using Argon2id as the password-based
+    /// key derivation function and AES-128-CBC as the symmetric cipher.
+    ///
+    /// For more information on Argon2 parameters, see documentation for the
+    /// [`argon2::Params`] struct. The memory cost must be a power of two.
+    #[cfg(feature = "argon2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "argon2")))]
+    pub fn argon2id_aes128cbc(
+        params: argon2::Params,
+        salt: &'a [u8],
+        aes_iv: &'a [u8; AES_BLOCK_SIZE],
+    ) -> Result<Self> {
+        let kdf = Argon2Params::from_params_and_salt(params, salt)?.into();
+        let encryption = EncryptionScheme::Aes128Cbc { iv: aes_iv };
+        Ok(Self { kdf, encryption })
+    }
+
+    /// Initialize PBES2 parameters using Argon2id as the password-based
+    /// key derivation function and AES-256-CBC as the symmetric cipher.
+    ///
+    /// For more information on Argon2 parameters, see documentation for the
+    /// [`argon2::Params`] struct. The memory cost must be a power of two.
+    #[cfg(feature = "argon2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "argon2")))]
+    pub fn argon2id_aes256cbc(
+        params: argon2::Params,
+        salt: &'a [u8],
+        aes_iv: &'a [u8; AES_BLOCK_SIZE],
+    ) -> Result<Self> {
+        let kdf = Argon2Params::from_params_and_salt(params, salt)?.into();
+        let encryption = EncryptionScheme::Aes256Cbc { iv: aes_iv };
+        Ok(Self { kdf, encryption })
+    }
+
+    /// Initialize PBES2 parameters using PBKDF2-SHA256 as the password-based
+    /// key derivation function and AES-256-GCM as the authenticated cipher,
+    /// with a 16-byte integrity check value.
+    pub fn pbkdf2_sha256_aes256gcm(
+        pbkdf2_iterations: u32,
+        pbkdf2_salt: &'a [u8],
+        gcm_nonce: &'a [u8; GCM_NONCE_SIZE],
+    ) -> Result<Self> {
+        let kdf = Pbkdf2Params::hmac_with_sha256(pbkdf2_iterations, pbkdf2_salt)?.into();
+        let encryption = EncryptionScheme::Aes256Gcm {
+            nonce: gcm_nonce,
+            icv_len: 16,
+        };
+        Ok(Self { kdf, encryption })
+    }
+
+    /// Estimate how well these parameters resist offline password guessing.
+    ///
+    /// Returns `None` if they use application-provided algorithms.
+    pub fn strength(&self) -> Option<Strength> {
+        Strength::estimate(self)
+    }
+
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn decrypt(&self, password: impl AsRef<[u8]>, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        self.decrypt_with(&Registry::new(), password, ciphertext)
+    }
+
+    /// Attempt to decrypt the given ciphertext using the application-provided
+    /// algorithms in the given [`Registry`] for any algorithms not natively
+    /// supported by this crate, allocating and returning a byte vector
+    /// containing the plaintext.
+    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_with(
+        &self,
+        registry: &Registry<'_>,
+        password: impl AsRef<[u8]>,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
         let mut buffer = ciphertext.to_vec();
-        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+        let pt_len = self
+            .decrypt_in_place_with(registry, password, &mut buffer)?
+            .len();
         buffer.truncate(pt_len);
         Ok(buffer)
     }
@@ -163,22 +274,62 @@ impl<'a> Parameters<'a> {
         password: impl AsRef<[u8]>,
         buffer: &'b mut [u8],
     ) -> Result<&'b [u8]> {
-        encryption::decrypt_in_place(self, password, buffer)
+        self.decrypt_in_place_with(&Registry::new(), password, buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using the
+    /// application-provided algorithms in the given [`Registry`] for any
+    /// algorithms not natively supported by this crate.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with<'b>(
+        &self,
+        registry: &Registry<'_>,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        encryption::decrypt_in_place(self, registry, password.as_ref(), buffer)
     }
 
     /// Encrypt the given plaintext, allocating and returning a vector
     /// containing the ciphertext.
+    ///
+    /// For AES-GCM the integrity check value is appended to the ciphertext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn encrypt(&self, password: impl AsRef<[u8]>, plaintext: &[u8]) -> Result<Vec<u8>> {
-        // TODO(tarcieri): support non-AES ciphers?
-        let mut buffer = Vec::with_capacity(plaintext.len() + AES_BLOCK_SIZE);
+        self.encrypt_with(&Registry::new(), password, plaintext)
+    }
+
+    /// Encrypt the given plaintext using the application-provided algorithms
+    /// in the given [`Registry`] for any algorithms not natively supported by
+    /// this crate, allocating and returning a vector containing the
+    /// ciphertext.
+    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn encrypt_with(
+        &self,
+        registry: &Registry<'_>,
+        password: impl AsRef<[u8]>,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>> {
+        // Note: AES-GCM integrity check values are at most `AES_BLOCK_SIZE`
+        let overhead = match self.encryption {
+            EncryptionScheme::Other(alg) => registry
+                .cipher(alg.oid)
+                .ok_or(Error::UnsupportedAlgorithm { oid: alg.oid })?
+                .max_overhead(),
+            _ => AES_BLOCK_SIZE,
+        };
+
+        let mut buffer = Vec::with_capacity(plaintext.len() + overhead);
         buffer.extend_from_slice(plaintext);
-        buffer.extend_from_slice(&[0u8; AES_BLOCK_SIZE]);
+        buffer.resize(plaintext.len() + overhead, 0);
 
         let ct_len = self
-            .encrypt_in_place(password, &mut buffer, plaintext.len())?
+            .encrypt_in_place_with(registry, password, &mut buffer, plaintext.len())?
             .len();
 
         buffer.truncate(ct_len);
@@ -188,6 +339,9 @@ impl<'a> Parameters<'a> {
     /// Encrypt the given plaintext in-place using a key derived from the
     /// provided password and this scheme's parameters, writing the ciphertext
     /// into the same buffer.
+    ///
+    /// The buffer must have room for the padding or integrity check value
+    /// following the plaintext at `buffer[..pos]`.
     #[cfg(feature = "pbes2")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn encrypt_in_place<'b>(
@@ -196,7 +350,44 @@ impl<'a> Parameters<'a> {
         buffer: &'b mut [u8],
         pos: usize,
     ) -> Result<&'b [u8]> {
-        encryption::encrypt_in_place(self, password, buffer, pos)
+        self.encrypt_in_place_with(&Registry::new(), password, buffer, pos)
+    }
+
+    /// Encrypt the given plaintext in-place using the application-provided
+    /// algorithms in the given [`Registry`] for any algorithms not natively
+    /// supported by this crate.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn encrypt_in_place_with<'b>(
+        &self,
+        registry: &Registry<'_>,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        pos: usize,
+    ) -> Result<&'b [u8]> {
+        encryption::encrypt_in_place(self, registry, password.as_ref(), buffer, pos)
+    }
+
+    /// Create an [`Encryptor`] for encrypting a plaintext incrementally,
+    /// using a key derived from the provided password and this scheme's
+    /// parameters.
+    ///
+    /// Only CBC mode encryption schemes are supported.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn encryptor(&self, password: impl AsRef<[u8]>) -> Result<Encryptor> {
+        Encryptor::new(self, password.as_ref())
+    }
+
+    /// Create a [`Decryptor`] for decrypting a ciphertext incrementally,
+    /// using a key derived from the provided password and this scheme's
+    /// parameters.
+    ///
+    /// Only CBC mode encryption schemes are supported.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decryptor(&self, password: impl AsRef<[u8]>) -> Result<Decryptor> {
+        Decryptor::new(self, password.as_ref())
     }
 }
 
@@ -253,6 +444,24 @@ pub enum EncryptionScheme<'a> {
         iv: &'a [u8; AES_BLOCK_SIZE],
     },
 
+    /// AES-128 in GCM mode
+    Aes128Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Integrity check value (i.e. tag) length in bytes: 12 to 16.
+        icv_len: u8,
+    },
+
+    /// AES-256 in GCM mode
+    Aes256Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Integrity check value (i.e. tag) length in bytes: 12 to 16.
+        icv_len: u8,
+    },
+
     /// 3-Key Triple DES in CBC mode
     #[cfg(feature = "3des")]
     DesEde3Cbc {
@@ -266,19 +475,29 @@ pub enum EncryptionScheme<'a> {
         /// Initialisation vector
         iv: &'a [u8; DES_BLOCK_SIZE],
     },
+
+    /// Cipher not natively supported by this crate, which can be provided by
+    /// the application as a [`CustomCipher`].
+    Other(AlgorithmIdentifier<'a>),
 }
 
 impl<'a> EncryptionScheme<'a> {
     /// Get the size of a key used by this algorithm in bytes.
+    ///
+    /// Returns `0` for [`EncryptionScheme::Other`], as the key size is
+    /// determined by the [`CustomCipher`] implementing it.
     pub fn key_size(&self) -> usize {
         match self {
             Self::Aes128Cbc { .. } => 16,
             Self::Aes192Cbc { .. } => 24,
             Self::Aes256Cbc { .. } => 32,
+            Self::Aes128Gcm { .. } => 16,
+            Self::Aes256Gcm { .. } => 32,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => 8,
             #[cfg(feature = "3des")]
             Self::DesEde3Cbc { .. } => 24,
+            Self::Other(_) => 0,
         }
     }
 
@@ -288,10 +507,13 @@ impl<'a> EncryptionScheme<'a> {
             Self::Aes128Cbc { .. } => AES_128_CBC_OID,
             Self::Aes192Cbc { .. } => AES_192_CBC_OID,
             Self::Aes256Cbc { .. } => AES_256_CBC_OID,
+            Self::Aes128Gcm { .. } => AES_128_GCM_OID,
+            Self::Aes256Gcm { .. } => AES_256_GCM_OID,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => DES_CBC_OID,
             #[cfg(feature = "3des")]
             Self::DesEde3Cbc { .. } => DES_EDE3_CBC_OID,
+            Self::Other(alg) => alg.oid,
         }
     }
 
@@ -313,58 +535,91 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
-        // TODO(tarcieri): support for non-AES algorithms?
-        let iv = match alg.parameters {
-            Some(params) => params.octet_string()?.as_bytes(),
-            None => return Err(Tag::OctetString.value_error()),
+        if alg.oid == AES_128_GCM_OID || alg.oid == AES_256_GCM_OID {
+            let GcmParameters { nonce, icv_len } = match alg.parameters {
+                Some(params) => params.try_into()?,
+                None => return Err(Tag::Sequence.value_error()),
+            };
+
+            return Ok(match alg.oid {
+                AES_128_GCM_OID => Self::Aes128Gcm { nonce, icv_len },
+                _ => Self::Aes256Gcm { nonce, icv_len },
+            });
+        }
+
+        let iv = || match alg.parameters {
+            Some(params) => Ok(params.octet_string()?.as_bytes()),
+            None => Err(Tag::OctetString.value_error()),
         };
 
         match alg.oid {
             AES_128_CBC_OID => Ok(Self::Aes128Cbc {
-                iv: iv
+                iv: iv()?
                     .try_into()
                     .map_err(|_| der::Tag::OctetString.value_error())?,
             }),
             AES_192_CBC_OID => Ok(Self::Aes192Cbc {
-                iv: iv
+                iv: iv()?
                     .try_into()
                     .map_err(|_| der::Tag::OctetString.value_error())?,
             }),
             AES_256_CBC_OID => Ok(Self::Aes256Cbc {
-                iv: iv
+                iv: iv()?
                     .try_into()
                     .map_err(|_| der::Tag::OctetString.value_error())?,
             }),
             #[cfg(feature = "des-insecure")]
             DES_CBC_OID => Ok(Self::DesCbc {
-                iv: iv[0..DES_BLOCK_SIZE]
+                iv: iv()?[0..DES_BLOCK_SIZE]
                     .try_into()
                     .map_err(|_| der::Tag::OctetString.value_error())?,
             }),
             #[cfg(feature = "3des")]
             DES_EDE3_CBC_OID => Ok(Self::DesEde3Cbc {
-                iv: iv[0..DES_BLOCK_SIZE]
+                iv: iv()?[0..DES_BLOCK_SIZE]
                     .try_into()
                     .map_err(|_| der::Tag::OctetString.value_error())?,
             }),
-            oid => Err(ErrorKind::OidUnknown { oid }.into()),
+            _ => Ok(Self::Other(alg)),
+        }
+    }
+}
+
+impl<'a> EncryptionScheme<'a> {
+    /// Get the initialization vector for block cipher modes, or `None` for
+    /// AES-GCM, whose parameters are a [`GcmParameters`] sequence, and
+    /// unsupported algorithms.
+    fn iv(&self) -> Option<&'a [u8]> {
+        match *self {
+            EncryptionScheme::Aes128Cbc { iv } => Some(iv),
+            EncryptionScheme::Aes192Cbc { iv } => Some(iv),
+            EncryptionScheme::Aes256Cbc { iv } => Some(iv),
+            EncryptionScheme::Aes128Gcm { .. } | EncryptionScheme::Aes256Gcm { .. } => None,
+            EncryptionScheme::Other(_) => None,
+            #[cfg(feature = "des-insecure")]
+            EncryptionScheme::DesCbc { iv } => Some(iv),
+            #[cfg(feature = "3des")]
+            EncryptionScheme::DesEde3Cbc { iv } => Some(iv),
         }
     }
 }
 
+/// Convert block cipher mode schemes and [`EncryptionScheme::Other`] to an
+/// [`AlgorithmIdentifier`].
+///
+/// AES-GCM parameters are a constructed `SEQUENCE` which can't be borrowed
+/// as [`Any`], so converting AES-GCM schemes returns an error. Use the
+/// [`Encode`] impl on [`EncryptionScheme`] instead.
 impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
     type Error = der::Error;
 
     fn try_from(scheme: EncryptionScheme<'a>) -> der::Result<Self> {
-        let parameters = OctetString::new(match scheme {
-            EncryptionScheme::Aes128Cbc { iv } => iv,
-            EncryptionScheme::Aes192Cbc { iv } => iv,
-            EncryptionScheme::Aes256Cbc { iv } => iv,
-            #[cfg(feature = "des-insecure")]
-            EncryptionScheme::DesCbc { iv } => iv,
-            #[cfg(feature = "3des")]
-            EncryptionScheme::DesEde3Cbc { iv } => iv,
-        })?;
+        if let EncryptionScheme::Other(alg) = scheme {
+            return Ok(alg);
+        }
+
+        let iv = scheme.iv().ok_or_else(|| Tag::Sequence.value_error())?;
+        let parameters = OctetString::new(iv)?;
 
         Ok(AlgorithmIdentifier {
             oid: scheme.oid(),
@@ -373,12 +628,87 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
     }
 }
 
-impl<'a> Encode for EncryptionScheme<'a> {
-    fn encoded_len(&self) -> der::Result<Length> {
-        AlgorithmIdentifier::try_from(*self)?.encoded_len()
+impl<'a> Sequence<'a> for EncryptionScheme<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        match *self {
+            Self::Aes128Gcm { nonce, icv_len } | Self::Aes256Gcm { nonce, icv_len } => {
+                f(&[&self.oid(), &GcmParameters { nonce, icv_len }])
+            }
+            Self::Other(alg) => f(&[&alg.oid, &alg.parameters]),
+            _ => match self.iv() {
+                Some(iv) => f(&[&self.oid(), &OctetString::new(iv)?]),
+                None => Err(Tag::OctetString.value_error()),
+            },
+        }
+    }
+}
+
+/// AES-GCM parameters as defined in [RFC 5084 Section 3.2].
+///
+/// ```text
+/// GCMParameters ::= SEQUENCE {
+///     aes-nonce        OCTET STRING, -- recommended size is 12 octets
+///     aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+///
+/// AES-GCM-ICVlen ::= INTEGER (12 | 13 | 14 | 15 | 16)
+/// ```
+///
+/// Only the recommended 12-byte nonce size is supported.
+///
+/// [RFC 5084 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct GcmParameters<'a> {
+    /// Nonce
+    nonce: &'a [u8; GCM_NONCE_SIZE],
+
+    /// Integrity check value length in bytes
+    icv_len: u8,
+}
+
+impl<'a> Decode<'a> for GcmParameters<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.any()?.try_into()
     }
+}
 
-    fn encode(&self, writer: &mut dyn Writer) -> der::Result<()> {
-        AlgorithmIdentifier::try_from(*self)?.encode(writer)
+impl<'a> Sequence<'a> for GcmParameters<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        if !(12..=16).contains(&self.icv_len) {
+            return Err(Tag::Integer.value_error());
+        }
+
+        if self.icv_len == GCM_DEFAULT_ICV_LEN {
+            f(&[&OctetString::new(self.nonce)?])
+        } else {
+            f(&[&OctetString::new(self.nonce)?, &self.icv_len])
+        }
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GcmParameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Self> {
+        any.sequence(|params| {
+            let nonce = params
+                .octet_string()?
+                .as_bytes()
+                .try_into()
+                .map_err(|_| Tag::OctetString.value_error())?;
+
+            let icv_len = params.optional()?.unwrap_or(GCM_DEFAULT_ICV_LEN);
+
+            if !(12..=16).contains(&icv_len) {
+                return Err(Tag::Integer.value_error());
+            }
+
+            Ok(Self { nonce, icv_len })
+        })
     }
 }
@@ -0,0 +1,300 @@
+//! Builder for PBES2 parameters with modern defaults.
+
+use super::{
+    EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams, AES_256_GCM_OID,
+    AES_BLOCK_SIZE, GCM_NONCE_SIZE, SCRYPT_OID,
+};
+use crate::{Error, Result};
+
+#[cfg(feature = "rand_core")]
+use rand_core::{CryptoRng, RngCore};
+
+/// Length of the salts used by [`ParametersBuilder`].
+const SALT_LEN: usize = 16;
+
+/// Minimum scrypt parameters recommended by the [OWASP Password Storage Cheat
+/// Sheet] as `(log2(N), p)` pairs with `r = 8`, which trade memory for
+/// parallelization at a similar cost.
+///
+/// [OWASP Password Storage Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+const OWASP_SCRYPT_PARAMS: [(u8, u16); 5] = [(17, 1), (16, 2), (15, 3), (14, 5), (13, 10)];
+
+/// Builder for [`Parameters`] which defaults to scrypt and AES-256-CBC.
+///
+/// The builder owns the salt and IV, which the resulting [`Parameters`]
+/// borrow from.
+///
+/// ```
+/// # use pkcs5::pbes2::{ParametersBuilder, Strength};
+/// let builder = ParametersBuilder::new([0x42; 16], [0x24; 16]).pbkdf2_sha256();
+/// let params = builder.build().unwrap();
+/// assert_eq!(params.strength(), Some(Strength::Strong));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParametersBuilder {
+    kdf: KdfPreset,
+    cipher: CipherPreset,
+    salt: [u8; SALT_LEN],
+    iv: [u8; AES_BLOCK_SIZE],
+}
+
+impl ParametersBuilder {
+    /// Default PBKDF2 iteration count when using SHA-256 (or SHA-224) as the
+    /// PRF, per the [OWASP Password Storage Cheat Sheet].
+    ///
+    /// [OWASP Password Storage Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+    pub const PBKDF2_SHA256_ITERATIONS: u32 = 600_000;
+
+    /// Default PBKDF2 iteration count when using SHA-512 (or SHA-384) as the
+    /// PRF.
+    pub const PBKDF2_SHA512_ITERATIONS: u32 = 210_000;
+
+    /// Default scrypt CPU/memory cost `log2(N)`.
+    ///
+    /// This is the largest cost which can be encoded by [`ScryptParams`].
+    pub const SCRYPT_LOG_N: u8 = 15;
+
+    /// Default scrypt block size `r`.
+    pub const SCRYPT_R: u16 = 8;
+
+    /// Default scrypt parallelization `p`.
+    ///
+    /// As `N` is limited to 2^15, this is the parallelization recommended by
+    /// the [OWASP Password Storage Cheat Sheet] for that memory cost.
+    ///
+    /// [OWASP Password Storage Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+    pub const SCRYPT_P: u16 = 3;
+
+    /// Create a builder using the given salt and IV.
+    ///
+    /// Salts and IVs must never be reused: prefer [`ParametersBuilder::random`]
+    /// unless they are generated elsewhere. When using AES-GCM, the first
+    /// 12 bytes of the IV are used as the nonce.
+    pub fn new(salt: [u8; SALT_LEN], iv: [u8; AES_BLOCK_SIZE]) -> Self {
+        Self {
+            kdf: KdfPreset::Scrypt {
+                log_n: Self::SCRYPT_LOG_N,
+                r: Self::SCRYPT_R,
+                p: Self::SCRYPT_P,
+            },
+            cipher: CipherPreset::Aes256Cbc,
+            salt,
+            iv,
+        }
+    }
+
+    /// Create a builder using a salt and IV generated by the given RNG.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    pub fn random(mut rng: impl CryptoRng + RngCore) -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+
+        let mut iv = [0u8; AES_BLOCK_SIZE];
+        rng.fill_bytes(&mut iv);
+
+        Self::new(salt, iv)
+    }
+
+    /// Use PBKDF2 with the given PRF and iteration count.
+    pub fn pbkdf2(mut self, prf: Pbkdf2Prf, iterations: u32) -> Self {
+        self.kdf = KdfPreset::Pbkdf2 { prf, iterations };
+        self
+    }
+
+    /// Use PBKDF2-SHA256 with [`Self::PBKDF2_SHA256_ITERATIONS`].
+    pub fn pbkdf2_sha256(self) -> Self {
+        self.pbkdf2(Pbkdf2Prf::HmacWithSha256, Self::PBKDF2_SHA256_ITERATIONS)
+    }
+
+    /// Use PBKDF2-SHA512 with [`Self::PBKDF2_SHA512_ITERATIONS`].
+    pub fn pbkdf2_sha512(self) -> Self {
+        self.pbkdf2(Pbkdf2Prf::HmacWithSha512, Self::PBKDF2_SHA512_ITERATIONS)
+    }
+
+    /// Use scrypt with the given parameters.
+    ///
+    /// `log_n` must be between 1 and 15.
+    pub fn scrypt(mut self, log_n: u8, r: u16, p: u16) -> Self {
+        self.kdf = KdfPreset::Scrypt { log_n, r, p };
+        self
+    }
+
+    /// Use AES-256-CBC as the symmetric cipher (the default).
+    pub fn aes256cbc(mut self) -> Self {
+        self.cipher = CipherPreset::Aes256Cbc;
+        self
+    }
+
+    /// Use AES-256-GCM with a 16-byte integrity check value as the
+    /// authenticated cipher.
+    pub fn aes256gcm(mut self) -> Self {
+        self.cipher = CipherPreset::Aes256Gcm;
+        self
+    }
+
+    /// Get the salt.
+    pub fn salt(&self) -> &[u8; SALT_LEN] {
+        &self.salt
+    }
+
+    /// Get the IV.
+    pub fn iv(&self) -> &[u8; AES_BLOCK_SIZE] {
+        &self.iv
+    }
+
+    /// Build the [`Parameters`], borrowing the salt and IV from this builder.
+    pub fn build(&self) -> Result<Parameters<'_>> {
+        let kdf: Kdf<'_> = match self.kdf {
+            KdfPreset::Pbkdf2 { prf, iterations } => {
+                Pbkdf2Params::new(prf, iterations, &self.salt)?.into()
+            }
+            KdfPreset::Scrypt { log_n, r, p } => {
+                if !(1..=15).contains(&log_n) || r == 0 || p == 0 {
+                    return Err(Error::AlgorithmParametersInvalid { oid: SCRYPT_OID });
+                }
+
+                ScryptParams {
+                    salt: &self.salt,
+                    cost_parameter: 1 << log_n,
+                    block_size: r,
+                    parallelization: p,
+                    key_length: None,
+                }
+                .into()
+            }
+        };
+
+        let encryption = match self.cipher {
+            CipherPreset::Aes256Cbc => EncryptionScheme::Aes256Cbc { iv: &self.iv },
+            CipherPreset::Aes256Gcm => EncryptionScheme::Aes256Gcm {
+                nonce: self.iv[..GCM_NONCE_SIZE].try_into().map_err(|_| {
+                    Error::AlgorithmParametersInvalid {
+                        oid: AES_256_GCM_OID,
+                    }
+                })?,
+                icv_len: 16,
+            },
+        };
+
+        Ok(Parameters { kdf, encryption })
+    }
+}
+
+/// Key derivation function selected by a [`ParametersBuilder`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum KdfPreset {
+    Pbkdf2 { prf: Pbkdf2Prf, iterations: u32 },
+    Scrypt { log_n: u8, r: u16, p: u16 },
+}
+
+/// Cipher selected by a [`ParametersBuilder`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum CipherPreset {
+    Aes256Cbc,
+    Aes256Gcm,
+}
+
+/// Rough estimate of how well PBES2 parameters resist offline password
+/// guessing, as returned by [`Parameters::strength`].
+///
+/// Key derivation costs are compared against the minimums recommended by
+/// the [OWASP Password Storage Cheat Sheet]: parameters meeting them are
+/// [`Strength::Strong`], and those within a factor of 10 are
+/// [`Strength::Moderate`]. For scrypt, the memory cost `N * r` and the
+/// parallelization `p` must both meet one of the recommended configurations,
+/// e.g. `N = 2^17, r = 8, p = 1` or `N = 2^15, r = 8, p = 3`.
+///
+/// [OWASP Password Storage Cheat Sheet]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Strength {
+    /// Cheap to brute force, or uses a broken cipher.
+    Weak,
+
+    /// Below current recommendations, but not trivially weak.
+    Moderate,
+
+    /// Meets current recommendations.
+    Strong,
+}
+
+impl Strength {
+    /// Estimate the strength of the given parameters, returning `None` if
+    /// they use application-provided algorithms.
+    pub(super) fn estimate(params: &Parameters<'_>) -> Option<Self> {
+        let kdf = match &params.kdf {
+            Kdf::Pbkdf2(pbkdf2) => {
+                let recommended = match pbkdf2.prf {
+                    Pbkdf2Prf::HmacWithSha1 => 1_300_000,
+                    Pbkdf2Prf::HmacWithSha224
+                    | Pbkdf2Prf::HmacWithSha256
+                    | Pbkdf2Prf::HmacWithSha3_224
+                    | Pbkdf2Prf::HmacWithSha3_256 => ParametersBuilder::PBKDF2_SHA256_ITERATIONS,
+                    Pbkdf2Prf::HmacWithSha384
+                    | Pbkdf2Prf::HmacWithSha512
+                    | Pbkdf2Prf::HmacWithSha3_384
+                    | Pbkdf2Prf::HmacWithSha3_512 => ParametersBuilder::PBKDF2_SHA512_ITERATIONS,
+                };
+
+                Self::grade(pbkdf2.iteration_count.into(), recommended.into())
+            }
+            Kdf::Scrypt(scrypt) => {
+                let memory = u64::from(scrypt.cost_parameter) * u64::from(scrypt.block_size);
+                let p = scrypt.parallelization;
+
+                if OWASP_SCRYPT_PARAMS
+                    .iter()
+                    .any(|&(log_n, min_p)| memory >= 8 << log_n && p >= min_p)
+                {
+                    Self::Strong
+                } else {
+                    // Compare the total cost against the cheapest recommendation
+                    let recommended = OWASP_SCRYPT_PARAMS
+                        .iter()
+                        .map(|&(log_n, p)| (8u64 << log_n) * u64::from(p))
+                        .min()
+                        .unwrap_or(u64::MAX);
+
+                    Self::grade(memory * u64::from(p), recommended).min(Self::Moderate)
+                }
+            }
+            Kdf::Argon2id(argon2) => {
+                // Memory (in KiB) times passes, with 64 MiB and a single pass
+                // (or 32 MiB and two passes) considered sufficient.
+                let cost = 1u64
+                    .checked_shl(argon2.memory_exponent.into())
+                    .unwrap_or(u64::MAX)
+                    .saturating_mul(argon2.passes.into());
+
+                Self::grade(cost, 1 << 16)
+            }
+            Kdf::Other(_) => return None,
+        };
+
+        let cipher = match params.encryption {
+            EncryptionScheme::Aes128Cbc { .. }
+            | EncryptionScheme::Aes192Cbc { .. }
+            | EncryptionScheme::Aes256Cbc { .. }
+            | EncryptionScheme::Aes128Gcm { .. }
+            | EncryptionScheme::Aes256Gcm { .. } => Self::Strong,
+            #[cfg(feature = "3des")]
+            EncryptionScheme::DesEde3Cbc { .. } => Self::Moderate,
+            #[cfg(feature = "des-insecure")]
+            EncryptionScheme::DesCbc { .. } => Self::Weak,
+            EncryptionScheme::Other(_) => return None,
+        };
+
+        Some(kdf.min(cipher))
+    }
+
+    /// Grade a key derivation cost against the recommended cost.
+    fn grade(cost: u64, recommended: u64) -> Self {
+        if cost >= recommended {
+            Self::Strong
+        } else if cost.saturating_mul(10) >= recommended {
+            Self::Moderate
+        } else {
+            Self::Weak
+        }
+    }
+}
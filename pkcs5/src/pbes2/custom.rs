@@ -0,0 +1,132 @@
+//! Application-provided key derivation functions and ciphers.
+
+use crate::Result;
+use core::fmt;
+use der::asn1::{Any, ObjectIdentifier};
+
+/// Key derivation function provided by an application, for use with
+/// [`Kdf::Other`][`super::Kdf::Other`].
+pub trait CustomKdf {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) identifying this KDF.
+    fn oid(&self) -> ObjectIdentifier;
+
+    /// Derive a key from the given password, filling `key`.
+    ///
+    /// `parameters` are the (optional) parameters of the KDF's
+    /// `AlgorithmIdentifier`.
+    fn derive_key(
+        &self,
+        parameters: Option<Any<'_>>,
+        password: &[u8],
+        key: &mut [u8],
+    ) -> Result<()>;
+}
+
+/// Symmetric cipher provided by an application, for use with
+/// [`EncryptionScheme::Other`][`super::EncryptionScheme::Other`].
+pub trait CustomCipher {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) identifying this cipher.
+    fn oid(&self) -> ObjectIdentifier;
+
+    /// Get the size of a key used by this cipher with the given parameters
+    /// in bytes. Keys can be at most 32 bytes.
+    fn key_size(&self, parameters: Option<Any<'_>>) -> Result<usize>;
+
+    /// Get the maximum number of bytes encryption may add to a plaintext,
+    /// e.g. for padding or an authentication tag.
+    fn max_overhead(&self) -> usize;
+
+    /// Decrypt the given ciphertext in-place, returning the plaintext.
+    fn decrypt_in_place<'b>(
+        &self,
+        parameters: Option<Any<'_>>,
+        key: &[u8],
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]>;
+
+    /// Encrypt the plaintext at `buffer[..pos]` in-place, returning the
+    /// ciphertext.
+    fn encrypt_in_place<'b>(
+        &self,
+        parameters: Option<Any<'_>>,
+        key: &[u8],
+        buffer: &'b mut [u8],
+        pos: usize,
+    ) -> Result<&'b [u8]>;
+}
+
+/// Set of application-provided KDFs and ciphers to use when encrypting or
+/// decrypting with PBES2.
+///
+/// Algorithms which aren't natively supported by this crate are decoded as
+/// [`Kdf::Other`][`super::Kdf::Other`] and
+/// [`EncryptionScheme::Other`][`super::EncryptionScheme::Other`], and are
+/// looked up in the registry by OID.
+///
+/// ```
+/// # use pkcs5::pbes2::{CustomCipher, CustomKdf, Registry};
+/// fn registry<'r>(
+///     kdfs: &'r [&'r dyn CustomKdf],
+///     ciphers: &'r [&'r dyn CustomCipher],
+/// ) -> Registry<'r> {
+///     Registry::new().kdfs(kdfs).ciphers(ciphers)
+/// }
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct Registry<'r> {
+    kdfs: &'r [&'r dyn CustomKdf],
+    ciphers: &'r [&'r dyn CustomCipher],
+}
+
+impl<'r> Registry<'r> {
+    /// Create an empty registry.
+    pub const fn new() -> Self {
+        Self {
+            kdfs: &[],
+            ciphers: &[],
+        }
+    }
+
+    /// Use the given KDFs.
+    pub const fn kdfs(mut self, kdfs: &'r [&'r dyn CustomKdf]) -> Self {
+        self.kdfs = kdfs;
+        self
+    }
+
+    /// Use the given ciphers.
+    pub const fn ciphers(mut self, ciphers: &'r [&'r dyn CustomCipher]) -> Self {
+        self.ciphers = ciphers;
+        self
+    }
+
+    /// Find the KDF with the given OID.
+    pub fn kdf(&self, oid: ObjectIdentifier) -> Option<&'r dyn CustomKdf> {
+        self.kdfs.iter().copied().find(|kdf| kdf.oid() == oid)
+    }
+
+    /// Find the cipher with the given OID.
+    pub fn cipher(&self, oid: ObjectIdentifier) -> Option<&'r dyn CustomCipher> {
+        self.ciphers
+            .iter()
+            .copied()
+            .find(|cipher| cipher.oid() == oid)
+    }
+}
+
+impl fmt::Debug for Registry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("kdfs", &Oids(self.kdfs.iter().map(|kdf| kdf.oid())))
+            .field("ciphers", &Oids(self.ciphers.iter().map(|c| c.oid())))
+            .finish()
+    }
+}
+
+/// Helper for debug printing the OIDs of registered algorithms.
+struct Oids<I>(I);
+
+impl<I: Clone + Iterator<Item = ObjectIdentifier>> fmt::Debug for Oids<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
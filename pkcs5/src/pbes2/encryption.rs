@@ -1,9 +1,24 @@
 //! PBES2 encryption.
 
-use super::{EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams};
-use crate::{Error, Result};
+use super::{
+    CustomCipher, EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, Registry,
+    ScryptParams, GCM_NONCE_SIZE,
+};
+
+#[cfg(feature = "argon2")]
+use super::Argon2Params;
+use crate::{AlgorithmIdentifier, Error, Result};
+use aes_gcm::{
+    aead::{
+        consts::{U12, U13, U14, U15, U16},
+        generic_array::GenericArray,
+        AeadInPlace,
+    },
+    AesGcm, TagSize,
+};
 use cbc::cipher::{
-    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyInit, KeyIvInit,
+    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit,
+    KeyIvInit,
 };
 use hmac::{
     digest::{
@@ -45,62 +60,218 @@ fn cbc_decrypt<'a, C: BlockDecryptMut + BlockCipher + KeyInit>(
         .map_err(|_| Error::EncryptFailed)
 }
 
+fn gcm_encrypt<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+    pos: usize,
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let ct_len = pos.checked_add(T::USIZE).ok_or(Error::EncryptFailed)?;
+    let (msg, tag) = buffer
+        .get_mut(..ct_len)
+        .ok_or(Error::EncryptFailed)?
+        .split_at_mut(pos);
+
+    tag.copy_from_slice(
+        &AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+            .map_err(|_| es.to_alg_params_invalid())?
+            .encrypt_in_place_detached(GenericArray::from_slice(nonce), &[], msg)
+            .map_err(|_| Error::EncryptFailed)?,
+    );
+
+    Ok(&buffer[..ct_len])
+}
+
+fn gcm_decrypt<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let pt_len = buffer
+        .len()
+        .checked_sub(T::USIZE)
+        .ok_or(Error::DecryptFailed)?;
+    let (msg, tag) = buffer.split_at_mut(pt_len);
+
+    AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+        .map_err(|_| es.to_alg_params_invalid())?
+        .decrypt_in_place_detached(
+            GenericArray::from_slice(nonce),
+            &[],
+            msg,
+            GenericArray::from_slice(tag),
+        )
+        .map_err(|_| Error::DecryptFailed)?;
+
+    Ok(&buffer[..pt_len])
+}
+
+/// Encrypt using AES-GCM with the given integrity check value length.
+fn gcm_encrypt_with_icv_len<'a, C>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    icv_len: u8,
+    buffer: &'a mut [u8],
+    pos: usize,
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    match icv_len {
+        12 => gcm_encrypt::<C, U12>(es, key, nonce, buffer, pos),
+        13 => gcm_encrypt::<C, U13>(es, key, nonce, buffer, pos),
+        14 => gcm_encrypt::<C, U14>(es, key, nonce, buffer, pos),
+        15 => gcm_encrypt::<C, U15>(es, key, nonce, buffer, pos),
+        16 => gcm_encrypt::<C, U16>(es, key, nonce, buffer, pos),
+        _ => Err(es.to_alg_params_invalid()),
+    }
+}
+
+/// Decrypt using AES-GCM with the given integrity check value length.
+fn gcm_decrypt_with_icv_len<'a, C>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    icv_len: u8,
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    match icv_len {
+        12 => gcm_decrypt::<C, U12>(es, key, nonce, buffer),
+        13 => gcm_decrypt::<C, U13>(es, key, nonce, buffer),
+        14 => gcm_decrypt::<C, U14>(es, key, nonce, buffer),
+        15 => gcm_decrypt::<C, U15>(es, key, nonce, buffer),
+        16 => gcm_decrypt::<C, U16>(es, key, nonce, buffer),
+        _ => Err(es.to_alg_params_invalid()),
+    }
+}
+
 pub fn encrypt_in_place<'b>(
     params: &Parameters<'_>,
-    password: impl AsRef<[u8]>,
+    registry: &Registry<'_>,
+    password: &[u8],
     buf: &'b mut [u8],
     pos: usize,
 ) -> Result<&'b [u8]> {
     let es = params.encryption;
-    let key_size = es.key_size();
-    if key_size > MAX_KEY_LEN {
-        return Err(es.to_alg_params_invalid());
-    }
-    let key = EncryptionKey::derive_from_password(password.as_ref(), &params.kdf, key_size)?;
+
+    let key_size = match es {
+        EncryptionScheme::Other(alg) => custom_cipher(registry, alg)?.key_size(alg.parameters)?,
+        _ => es.key_size(),
+    };
+    let key = EncryptionKey::derive(es, registry, password, &params.kdf, key_size)?;
 
     match es {
         EncryptionScheme::Aes128Cbc { iv } => cbc_encrypt::<aes::Aes128Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes192Cbc { iv } => cbc_encrypt::<aes::Aes192Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes256Cbc { iv } => cbc_encrypt::<aes::Aes256Enc>(es, key, iv, buf, pos),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm_encrypt_with_icv_len::<aes::Aes128>(es, key, nonce, icv_len, buf, pos)
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm_encrypt_with_icv_len::<aes::Aes256>(es, key, nonce, icv_len, buf, pos)
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_encrypt::<des::TdesEde3>(es, key, iv, buf, pos),
         #[cfg(feature = "des-insecure")]
         EncryptionScheme::DesCbc { .. } => Err(Error::UnsupportedAlgorithm {
             oid: super::DES_CBC_OID,
         }),
+        EncryptionScheme::Other(alg) => {
+            custom_cipher(registry, alg)?.encrypt_in_place(alg.parameters, key.as_slice(), buf, pos)
+        }
     }
 }
 
 /// Decrypt a message encrypted with PBES2-based key derivation
 pub fn decrypt_in_place<'a>(
     params: &Parameters<'_>,
-    password: impl AsRef<[u8]>,
+    registry: &Registry<'_>,
+    password: &[u8],
     buf: &'a mut [u8],
 ) -> Result<&'a [u8]> {
     let es = params.encryption;
-    let key = EncryptionKey::derive_from_password(password.as_ref(), &params.kdf, es.key_size())?;
+
+    let key_size = match es {
+        EncryptionScheme::Other(alg) => custom_cipher(registry, alg)?.key_size(alg.parameters)?,
+        _ => es.key_size(),
+    };
+    let key = EncryptionKey::derive(es, registry, password, &params.kdf, key_size)?;
 
     match es {
         EncryptionScheme::Aes128Cbc { iv } => cbc_decrypt::<aes::Aes128Dec>(es, key, iv, buf),
         EncryptionScheme::Aes192Cbc { iv } => cbc_decrypt::<aes::Aes192Dec>(es, key, iv, buf),
         EncryptionScheme::Aes256Cbc { iv } => cbc_decrypt::<aes::Aes256Dec>(es, key, iv, buf),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm_decrypt_with_icv_len::<aes::Aes128>(es, key, nonce, icv_len, buf)
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm_decrypt_with_icv_len::<aes::Aes256>(es, key, nonce, icv_len, buf)
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_decrypt::<des::TdesEde3>(es, key, iv, buf),
         #[cfg(feature = "des-insecure")]
         EncryptionScheme::DesCbc { iv } => cbc_decrypt::<des::Des>(es, key, iv, buf),
+        EncryptionScheme::Other(alg) => {
+            custom_cipher(registry, alg)?.decrypt_in_place(alg.parameters, key.as_slice(), buf)
+        }
     }
 }
 
+/// Find the application-provided cipher for the given algorithm.
+fn custom_cipher<'r>(
+    registry: &Registry<'r>,
+    alg: AlgorithmIdentifier<'_>,
+) -> Result<&'r dyn CustomCipher> {
+    registry
+        .cipher(alg.oid)
+        .ok_or(Error::UnsupportedAlgorithm { oid: alg.oid })
+}
+
 /// Encryption key as derived by PBKDF2
 // TODO(tarcieri): zeroize?
-struct EncryptionKey {
+pub(super) struct EncryptionKey {
     buffer: [u8; MAX_KEY_LEN],
     length: usize,
 }
 
 impl EncryptionKey {
+    /// Derive a key for the given encryption scheme, checking it fits within
+    /// [`MAX_KEY_LEN`].
+    pub(super) fn derive(
+        es: EncryptionScheme<'_>,
+        registry: &Registry<'_>,
+        password: &[u8],
+        kdf: &Kdf<'_>,
+        key_size: usize,
+    ) -> Result<Self> {
+        if key_size > MAX_KEY_LEN {
+            return Err(es.to_alg_params_invalid());
+        }
+
+        Self::derive_from_password(password, kdf, key_size, registry)
+    }
+
     /// Derive an encryption key using the supplied PBKDF parameters.
-    pub fn derive_from_password(password: &[u8], kdf: &Kdf<'_>, key_size: usize) -> Result<Self> {
+    pub fn derive_from_password(
+        password: &[u8],
+        kdf: &Kdf<'_>,
+        key_size: usize,
+        registry: &Registry<'_>,
+    ) -> Result<Self> {
         // if the kdf params defined a key length, ensure it matches the required key size
         if let Some(len) = kdf.key_length() {
             if key_size != len.into() {
@@ -143,6 +314,37 @@ impl EncryptionKey {
                         pbkdf2_params,
                         key_size,
                     ),
+                    #[cfg(feature = "sha3")]
+                    Pbkdf2Prf::HmacWithSha3_224 => EncryptionKey::derive_with_pbkdf2::<
+                        sha3::Sha3_224,
+                    >(
+                        password, pbkdf2_params, key_size
+                    ),
+                    #[cfg(feature = "sha3")]
+                    Pbkdf2Prf::HmacWithSha3_256 => EncryptionKey::derive_with_pbkdf2::<
+                        sha3::Sha3_256,
+                    >(
+                        password, pbkdf2_params, key_size
+                    ),
+                    #[cfg(feature = "sha3")]
+                    Pbkdf2Prf::HmacWithSha3_384 => EncryptionKey::derive_with_pbkdf2::<
+                        sha3::Sha3_384,
+                    >(
+                        password, pbkdf2_params, key_size
+                    ),
+                    #[cfg(feature = "sha3")]
+                    Pbkdf2Prf::HmacWithSha3_512 => EncryptionKey::derive_with_pbkdf2::<
+                        sha3::Sha3_512,
+                    >(
+                        password, pbkdf2_params, key_size
+                    ),
+                    #[cfg(not(feature = "sha3"))]
+                    prf @ (Pbkdf2Prf::HmacWithSha3_224
+                    | Pbkdf2Prf::HmacWithSha3_256
+                    | Pbkdf2Prf::HmacWithSha3_384
+                    | Pbkdf2Prf::HmacWithSha3_512) => {
+                        return Err(Error::UnsupportedAlgorithm { oid: prf.oid() })
+                    }
                 };
 
                 Ok(key)
@@ -150,6 +352,26 @@ impl EncryptionKey {
             Kdf::Scrypt(scrypt_params) => {
                 EncryptionKey::derive_with_scrypt(password, scrypt_params, key_size)
             }
+            #[cfg(feature = "argon2")]
+            Kdf::Argon2id(argon2_params) => {
+                EncryptionKey::derive_with_argon2id(password, argon2_params, key_size)
+            }
+            #[cfg(not(feature = "argon2"))]
+            Kdf::Argon2id(_) => Err(Error::UnsupportedAlgorithm {
+                oid: super::ARGON2ID_OID,
+            }),
+            Kdf::Other(alg) => {
+                let custom = registry
+                    .kdf(alg.oid)
+                    .ok_or(Error::UnsupportedAlgorithm { oid: alg.oid })?;
+
+                let mut buffer = [0u8; MAX_KEY_LEN];
+                custom.derive_key(alg.parameters, password, &mut buffer[..key_size])?;
+                Ok(Self {
+                    buffer,
+                    length: key_size,
+                })
+            }
         }
     }
 
@@ -198,8 +420,37 @@ impl EncryptionKey {
         Ok(Self { buffer, length })
     }
 
+    /// Derive key using Argon2id.
+    #[cfg(feature = "argon2")]
+    fn derive_with_argon2id(
+        password: &[u8],
+        params: &Argon2Params<'_>,
+        length: usize,
+    ) -> Result<Self> {
+        let invalid = |_| Error::AlgorithmParametersInvalid {
+            oid: super::ARGON2ID_OID,
+        };
+
+        let algorithm = argon2::Algorithm::Argon2id;
+        let version = argon2::Version::V0x13;
+        let argon2 = match params.secret {
+            Some(secret) => {
+                argon2::Argon2::new_with_secret(secret, algorithm, version, params.try_into()?)
+                    .map_err(invalid)?
+            }
+            None => argon2::Argon2::new(algorithm, version, params.try_into()?),
+        };
+
+        let mut buffer = [0u8; MAX_KEY_LEN];
+        argon2
+            .hash_password_into(password, params.salt, &mut buffer[..length])
+            .map_err(invalid)?;
+
+        Ok(Self { buffer, length })
+    }
+
     /// Get the key material as a slice
-    fn as_slice(&self) -> &[u8] {
+    pub(super) fn as_slice(&self) -> &[u8] {
         &self.buffer[..self.length]
     }
 }
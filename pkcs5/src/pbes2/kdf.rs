@@ -28,11 +28,32 @@ pub const HMAC_WITH_SHA384_OID: ObjectIdentifier =
 pub const HMAC_WITH_SHA512_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.113549.2.11");
 
+/// HMAC-SHA3-224 (for use with PBKDF2)
+pub const HMAC_WITH_SHA3_224_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.13");
+
+/// HMAC-SHA3-256 (for use with PBKDF2)
+pub const HMAC_WITH_SHA3_256_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.14");
+
+/// HMAC-SHA3-384 (for use with PBKDF2)
+pub const HMAC_WITH_SHA3_384_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.15");
+
+/// HMAC-SHA3-512 (for use with PBKDF2)
+pub const HMAC_WITH_SHA3_512_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.16");
+
 /// `id-scrypt` ([RFC 7914])
 ///
 /// [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914#section-7
 pub const SCRYPT_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11591.4.11");
 
+/// `id-argon2id` as allocated in [draft-ietf-lamps-cms-argon2].
+///
+/// [draft-ietf-lamps-cms-argon2]: https://datatracker.ietf.org/doc/draft-ietf-lamps-cms-argon2/
+pub const ARGON2ID_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.19562.1.2.3");
+
 /// Type used for expressing scrypt cost
 type ScryptCost = u16;
 
@@ -45,6 +66,13 @@ pub enum Kdf<'a> {
 
     /// scrypt sequential memory-hard password hashing function.
     Scrypt(ScryptParams<'a>),
+
+    /// Argon2id memory-hard password hashing function.
+    Argon2id(Argon2Params<'a>),
+
+    /// Key derivation function not natively supported by this crate, which
+    /// can be provided by the application as a [`CustomKdf`][`super::CustomKdf`].
+    Other(AlgorithmIdentifier<'a>),
 }
 
 impl<'a> Kdf<'a> {
@@ -54,6 +82,8 @@ impl<'a> Kdf<'a> {
         match self {
             Self::Pbkdf2(params) => params.key_length,
             Self::Scrypt(params) => params.key_length,
+            Self::Argon2id(_) => None,
+            Self::Other(_) => None,
         }
     }
 
@@ -62,6 +92,8 @@ impl<'a> Kdf<'a> {
         match self {
             Self::Pbkdf2(_) => PBKDF2_OID,
             Self::Scrypt(_) => SCRYPT_OID,
+            Self::Argon2id(_) => ARGON2ID_OID,
+            Self::Other(alg) => alg.oid,
         }
     }
 
@@ -81,6 +113,14 @@ impl<'a> Kdf<'a> {
         }
     }
 
+    /// Get [`Argon2Params`] if it is the selected algorithm.
+    pub fn argon2id(&self) -> Option<&Argon2Params<'a>> {
+        match self {
+            Self::Argon2id(params) => Some(params),
+            _ => None,
+        }
+    }
+
     /// Is the selected KDF PBKDF2?
     pub fn is_pbkdf2(&self) -> bool {
         self.pbkdf2().is_some()
@@ -91,6 +131,11 @@ impl<'a> Kdf<'a> {
         self.scrypt().is_some()
     }
 
+    /// Is the selected KDF Argon2id?
+    pub fn is_argon2id(&self) -> bool {
+        self.argon2id().is_some()
+    }
+
     /// Convenience function to turn the OID (see [`oid`](Self::oid))
     /// of this [`Kdf`] into error case [`Error::AlgorithmParametersInvalid`]
     pub fn to_alg_params_invalid(&self) -> Error {
@@ -112,6 +157,8 @@ impl<'a> Sequence<'a> for Kdf<'a> {
         match self {
             Self::Pbkdf2(params) => f(&[&self.oid(), params]),
             Self::Scrypt(params) => f(&[&self.oid(), params]),
+            Self::Argon2id(params) => f(&[&self.oid(), params]),
+            Self::Other(alg) => f(&[&alg.oid, &alg.parameters]),
         }
     }
 }
@@ -128,18 +175,23 @@ impl<'a> From<ScryptParams<'a>> for Kdf<'a> {
     }
 }
 
+impl<'a> From<Argon2Params<'a>> for Kdf<'a> {
+    fn from(params: Argon2Params<'a>) -> Self {
+        Kdf::Argon2id(params)
+    }
+}
+
 impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Kdf<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
-        if let Some(params) = alg.parameters {
-            match alg.oid {
-                PBKDF2_OID => params.try_into().map(Self::Pbkdf2),
-                SCRYPT_OID => params.try_into().map(Self::Scrypt),
-                oid => Err(ErrorKind::OidUnknown { oid }.into()),
-            }
-        } else {
-            Err(Tag::OctetString.value_error())
+        let params = || alg.parameters.ok_or_else(|| Tag::OctetString.value_error());
+
+        match alg.oid {
+            PBKDF2_OID => params()?.try_into().map(Self::Pbkdf2),
+            SCRYPT_OID => params()?.try_into().map(Self::Scrypt),
+            ARGON2ID_OID => params()?.try_into().map(Self::Argon2id),
+            _ => Ok(Self::Other(alg)),
         }
     }
 }
@@ -190,8 +242,8 @@ impl<'a> Pbkdf2Params<'a> {
 
     const INVALID_ERR: Error = Error::AlgorithmParametersInvalid { oid: PBKDF2_OID };
 
-    /// Initialize PBKDF2-SHA256 with the given iteration count and salt
-    pub fn hmac_with_sha256(iteration_count: u32, salt: &'a [u8]) -> Result<Self> {
+    /// Initialize PBKDF2 with the given PRF, iteration count and salt
+    pub fn new(prf: Pbkdf2Prf, iteration_count: u32, salt: &'a [u8]) -> Result<Self> {
         if iteration_count > Self::MAX_ITERATION_COUNT {
             return Err(Self::INVALID_ERR);
         }
@@ -199,9 +251,24 @@ impl<'a> Pbkdf2Params<'a> {
             salt,
             iteration_count,
             key_length: None,
-            prf: Pbkdf2Prf::HmacWithSha256,
+            prf,
         })
     }
+
+    /// Initialize PBKDF2-SHA256 with the given iteration count and salt
+    pub fn hmac_with_sha256(iteration_count: u32, salt: &'a [u8]) -> Result<Self> {
+        Self::new(Pbkdf2Prf::HmacWithSha256, iteration_count, salt)
+    }
+
+    /// Initialize PBKDF2-SHA384 with the given iteration count and salt
+    pub fn hmac_with_sha384(iteration_count: u32, salt: &'a [u8]) -> Result<Self> {
+        Self::new(Pbkdf2Prf::HmacWithSha384, iteration_count, salt)
+    }
+
+    /// Initialize PBKDF2-SHA512 with the given iteration count and salt
+    pub fn hmac_with_sha512(iteration_count: u32, salt: &'a [u8]) -> Result<Self> {
+        Self::new(Pbkdf2Prf::HmacWithSha512, iteration_count, salt)
+    }
 }
 
 impl<'a> Decode<'a> for Pbkdf2Params<'a> {
@@ -271,6 +338,18 @@ pub enum Pbkdf2Prf {
 
     /// HMAC with SHA-512
     HmacWithSha512,
+
+    /// HMAC with SHA3-224
+    HmacWithSha3_224,
+
+    /// HMAC with SHA3-256
+    HmacWithSha3_256,
+
+    /// HMAC with SHA3-384
+    HmacWithSha3_384,
+
+    /// HMAC with SHA3-512
+    HmacWithSha3_512,
 }
 
 impl Pbkdf2Prf {
@@ -282,6 +361,10 @@ impl Pbkdf2Prf {
             Self::HmacWithSha256 => HMAC_WITH_SHA256_OID,
             Self::HmacWithSha384 => HMAC_WITH_SHA384_OID,
             Self::HmacWithSha512 => HMAC_WITH_SHA512_OID,
+            Self::HmacWithSha3_224 => HMAC_WITH_SHA3_224_OID,
+            Self::HmacWithSha3_256 => HMAC_WITH_SHA3_256_OID,
+            Self::HmacWithSha3_384 => HMAC_WITH_SHA3_384_OID,
+            Self::HmacWithSha3_512 => HMAC_WITH_SHA3_512_OID,
         }
     }
 }
@@ -320,6 +403,10 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Pbkdf2Prf {
             HMAC_WITH_SHA256_OID => Ok(Self::HmacWithSha256),
             HMAC_WITH_SHA384_OID => Ok(Self::HmacWithSha384),
             HMAC_WITH_SHA512_OID => Ok(Self::HmacWithSha512),
+            HMAC_WITH_SHA3_224_OID => Ok(Self::HmacWithSha3_224),
+            HMAC_WITH_SHA3_256_OID => Ok(Self::HmacWithSha3_256),
+            HMAC_WITH_SHA3_384_OID => Ok(Self::HmacWithSha3_384),
+            HMAC_WITH_SHA3_512_OID => Ok(Self::HmacWithSha3_512),
             oid => Err(ErrorKind::OidUnknown { oid }.into()),
         }
     }
@@ -473,3 +560,150 @@ impl<'a> TryFrom<&ScryptParams<'a>> for scrypt::Params {
         .map_err(|_| ScryptParams::INVALID_ERR)
     }
 }
+
+/// Argon2 parameters as defined in [draft-ietf-lamps-cms-argon2].
+///
+/// ```text
+/// Argon2-Parameters ::= SEQUENCE {
+///     salt OCTET STRING,
+///     passes INTEGER,
+///     parallelism INTEGER,
+///     memoryExponent INTEGER,
+///     secret OCTET STRING OPTIONAL,
+///     associatedData OCTET STRING OPTIONAL
+/// }
+/// ```
+///
+/// The derived key length is determined by the encryption scheme, and the
+/// Argon2 version is always `0x13`.
+///
+/// [draft-ietf-lamps-cms-argon2]: https://datatracker.ietf.org/doc/draft-ietf-lamps-cms-argon2/
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Argon2Params<'a> {
+    /// Argon2 salt
+    pub salt: &'a [u8],
+
+    /// Number of passes over memory `t`.
+    pub passes: u32,
+
+    /// Degree of parallelism `p`.
+    pub parallelism: u32,
+
+    /// Memory size `m` as a power of two, i.e. `2^memory_exponent` KiB.
+    pub memory_exponent: u8,
+
+    /// Optional secret value `K` (a.k.a. pepper).
+    pub secret: Option<&'a [u8]>,
+
+    /// Optional associated data `X`.
+    pub associated_data: Option<&'a [u8]>,
+}
+
+impl<'a> Argon2Params<'a> {
+    #[cfg(feature = "argon2")]
+    const INVALID_ERR: Error = Error::AlgorithmParametersInvalid { oid: ARGON2ID_OID };
+
+    /// Get the [`Argon2Params`] for the provided upstream [`argon2::Params`]
+    /// and a provided salt string.
+    ///
+    /// The memory cost of `params` must be a power of two. Key identifiers
+    /// and associated data set on `params` are not carried over.
+    #[cfg(feature = "argon2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "argon2")))]
+    pub fn from_params_and_salt(params: argon2::Params, salt: &'a [u8]) -> Result<Self> {
+        let m_cost = params.m_cost();
+
+        if !m_cost.is_power_of_two() {
+            return Err(Self::INVALID_ERR);
+        }
+
+        Ok(Self {
+            salt,
+            passes: params.t_cost(),
+            parallelism: params.p_cost(),
+            memory_exponent: m_cost.trailing_zeros() as u8,
+            secret: None,
+            associated_data: None,
+        })
+    }
+}
+
+impl<'a> Decode<'a> for Argon2Params<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.any()?.try_into()
+    }
+}
+
+impl<'a> Sequence<'a> for Argon2Params<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &OctetString::new(self.salt)?,
+            &self.passes,
+            &self.parallelism,
+            &self.memory_exponent,
+            &self.secret.map(OctetString::new).transpose()?,
+            &self.associated_data.map(OctetString::new).transpose()?,
+        ])
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Argon2Params<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Self> {
+        any.sequence(|params| {
+            let salt = params.octet_string()?;
+            let passes = params.decode()?;
+            let parallelism = params.decode()?;
+            let memory_exponent = params.decode()?;
+            let secret: Option<OctetString<'_>> = params.optional()?;
+            let associated_data: Option<OctetString<'_>> = params.optional()?;
+
+            Ok(Self {
+                salt: salt.as_bytes(),
+                passes,
+                parallelism,
+                memory_exponent,
+                secret: secret.map(|s| s.as_bytes()),
+                associated_data: associated_data.map(|ad| ad.as_bytes()),
+            })
+        })
+    }
+}
+
+#[cfg(feature = "argon2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "argon2")))]
+impl<'a> TryFrom<Argon2Params<'a>> for argon2::Params {
+    type Error = Error;
+
+    fn try_from(params: Argon2Params<'a>) -> Result<argon2::Params> {
+        argon2::Params::try_from(&params)
+    }
+}
+
+#[cfg(feature = "argon2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "argon2")))]
+impl<'a> TryFrom<&Argon2Params<'a>> for argon2::Params {
+    type Error = Error;
+
+    fn try_from(params: &Argon2Params<'a>) -> Result<argon2::Params> {
+        let m_cost = 1u32
+            .checked_shl(params.memory_exponent.into())
+            .ok_or(Argon2Params::INVALID_ERR)?;
+
+        let mut builder = argon2::ParamsBuilder::new();
+        let invalid = |_| Argon2Params::INVALID_ERR;
+        builder.m_cost(m_cost).map_err(invalid)?;
+        builder.t_cost(params.passes).map_err(invalid)?;
+        builder.p_cost(params.parallelism).map_err(invalid)?;
+
+        if let Some(associated_data) = params.associated_data {
+            builder.data(associated_data).map_err(invalid)?;
+        }
+
+        builder.params().map_err(invalid)
+    }
+}
@@ -0,0 +1,413 @@
+//! Streaming PBES2 encryption and decryption.
+//!
+//! Only supports the CBC mode schemes: streaming decryption of AES-GCM would
+//! release plaintext before it has been authenticated.
+
+use super::{encryption::EncryptionKey, EncryptionScheme, Parameters, Registry, AES_BLOCK_SIZE};
+use crate::{Error, Result};
+use cbc::cipher::{generic_array::GenericArray, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Maximum block size of the supported ciphers.
+const MAX_BLOCK_SIZE: usize = AES_BLOCK_SIZE;
+
+/// Size of the chunks the [`io`] adapters process data in.
+#[cfg(feature = "std")]
+const IO_CHUNK_SIZE: usize = 4096;
+
+/// Incremental PBES2 encryptor, created with [`Parameters::encryptor`].
+///
+/// Plaintext is passed to [`Encryptor::update`] in chunks of any size, and
+/// the final padded block is produced by [`Encryptor::finalize`]. The
+/// resulting ciphertext is identical to that of [`Parameters::encrypt`].
+pub struct Encryptor {
+    mode: EncryptMode,
+    buffer: [u8; MAX_BLOCK_SIZE],
+    pos: usize,
+}
+
+impl Encryptor {
+    pub(super) fn new(params: &Parameters<'_>, password: &[u8]) -> Result<Self> {
+        let es = params.encryption;
+        let key =
+            EncryptionKey::derive(es, &Registry::new(), password, &params.kdf, es.key_size())?;
+        let invalid = |_| es.to_alg_params_invalid();
+
+        let mode = match es {
+            EncryptionScheme::Aes128Cbc { iv } => EncryptMode::Aes128(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            EncryptionScheme::Aes192Cbc { iv } => EncryptMode::Aes192(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            EncryptionScheme::Aes256Cbc { iv } => EncryptMode::Aes256(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            #[cfg(feature = "3des")]
+            EncryptionScheme::DesEde3Cbc { iv } => EncryptMode::DesEde3(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            _ => return Err(Error::UnsupportedAlgorithm { oid: es.oid() }),
+        };
+
+        Ok(Self {
+            mode,
+            buffer: [0u8; MAX_BLOCK_SIZE],
+            pos: 0,
+        })
+    }
+
+    /// Get the block size of the cipher in bytes.
+    pub fn block_size(&self) -> usize {
+        self.mode.block_size()
+    }
+
+    /// Encrypt the given chunk of plaintext, writing all complete blocks of
+    /// ciphertext to `output` and returning the number of bytes written.
+    ///
+    /// `output` must have room for `input.len() + block_size()` bytes.
+    pub fn update(&mut self, mut input: &[u8], output: &mut [u8]) -> Result<usize> {
+        let block_size = self.block_size();
+
+        if output.len() < (self.pos + input.len()) / block_size * block_size {
+            return Err(Error::EncryptFailed);
+        }
+
+        let mut written = 0;
+
+        while !input.is_empty() {
+            let n = (block_size - self.pos).min(input.len());
+            self.buffer[self.pos..][..n].copy_from_slice(&input[..n]);
+            self.pos += n;
+            input = &input[n..];
+
+            if self.pos == block_size {
+                let block = &mut output[written..][..block_size];
+                block.copy_from_slice(&self.buffer[..block_size]);
+                self.mode.encrypt_block(block);
+                written += block_size;
+                self.pos = 0;
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// Pad and encrypt the final block of plaintext, writing it to `output`
+    /// and returning the number of bytes written (i.e. `block_size()`).
+    pub fn finalize(mut self, output: &mut [u8]) -> Result<usize> {
+        let block_size = self.block_size();
+        let block = output.get_mut(..block_size).ok_or(Error::EncryptFailed)?;
+
+        // PKCS#7 padding
+        let pad = block_size - self.pos;
+        self.buffer[self.pos..block_size].fill(pad as u8);
+
+        block.copy_from_slice(&self.buffer[..block_size]);
+        self.mode.encrypt_block(block);
+        Ok(block_size)
+    }
+}
+
+/// Incremental PBES2 decryptor, created with [`Parameters::decryptor`].
+///
+/// Ciphertext is passed to [`Decryptor::update`] in chunks of any size. The
+/// final block is withheld until [`Decryptor::finalize`] so its padding can be
+/// removed.
+pub struct Decryptor {
+    mode: DecryptMode,
+    buffer: [u8; MAX_BLOCK_SIZE],
+    pos: usize,
+}
+
+impl Decryptor {
+    pub(super) fn new(params: &Parameters<'_>, password: &[u8]) -> Result<Self> {
+        let es = params.encryption;
+        let key =
+            EncryptionKey::derive(es, &Registry::new(), password, &params.kdf, es.key_size())?;
+        let invalid = |_| es.to_alg_params_invalid();
+
+        let mode = match es {
+            EncryptionScheme::Aes128Cbc { iv } => DecryptMode::Aes128(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            EncryptionScheme::Aes192Cbc { iv } => DecryptMode::Aes192(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            EncryptionScheme::Aes256Cbc { iv } => DecryptMode::Aes256(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            #[cfg(feature = "3des")]
+            EncryptionScheme::DesEde3Cbc { iv } => DecryptMode::DesEde3(
+                KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?,
+            ),
+            #[cfg(feature = "des-insecure")]
+            EncryptionScheme::DesCbc { iv } => {
+                DecryptMode::Des(KeyIvInit::new_from_slices(key.as_slice(), iv).map_err(invalid)?)
+            }
+            _ => return Err(Error::UnsupportedAlgorithm { oid: es.oid() }),
+        };
+
+        Ok(Self {
+            mode,
+            buffer: [0u8; MAX_BLOCK_SIZE],
+            pos: 0,
+        })
+    }
+
+    /// Get the block size of the cipher in bytes.
+    pub fn block_size(&self) -> usize {
+        self.mode.block_size()
+    }
+
+    /// Decrypt the given chunk of ciphertext, writing all complete blocks of
+    /// plaintext except the last to `output` and returning the number of
+    /// bytes written.
+    ///
+    /// `output` must have room for `input.len() + block_size()` bytes.
+    pub fn update(&mut self, mut input: &[u8], output: &mut [u8]) -> Result<usize> {
+        let block_size = self.block_size();
+        let total = self.pos + input.len();
+
+        if output.len() < total.saturating_sub(1) / block_size * block_size {
+            return Err(Error::DecryptFailed);
+        }
+
+        let mut written = 0;
+
+        while !input.is_empty() {
+            if self.pos == block_size {
+                let block = &mut output[written..][..block_size];
+                block.copy_from_slice(&self.buffer[..block_size]);
+                self.mode.decrypt_block(block);
+                written += block_size;
+                self.pos = 0;
+            }
+
+            let n = (block_size - self.pos).min(input.len());
+            self.buffer[self.pos..][..n].copy_from_slice(&input[..n]);
+            self.pos += n;
+            input = &input[n..];
+        }
+
+        Ok(written)
+    }
+
+    /// Decrypt the final block of ciphertext and remove its padding, writing
+    /// the remaining plaintext to `output` and returning the number of bytes
+    /// written.
+    ///
+    /// Returns [`Error::DecryptFailed`] if the ciphertext was not a multiple of
+    /// the block size or the padding is invalid (e.g. due to a wrong password).
+    pub fn finalize(mut self, output: &mut [u8]) -> Result<usize> {
+        let block_size = self.block_size();
+
+        if self.pos != block_size {
+            return Err(Error::DecryptFailed);
+        }
+
+        let block = &mut self.buffer[..block_size];
+        self.mode.decrypt_block(block);
+
+        // PKCS#7 padding
+        let pad = usize::from(block[block_size - 1]);
+        if pad == 0 || pad > block_size || block[block_size - pad..].iter().any(|&b| b != pad as u8)
+        {
+            return Err(Error::DecryptFailed);
+        }
+
+        let len = block_size - pad;
+        output
+            .get_mut(..len)
+            .ok_or(Error::DecryptFailed)?
+            .copy_from_slice(&block[..len]);
+        Ok(len)
+    }
+}
+
+/// CBC mode encryptor state for each supported cipher.
+enum EncryptMode {
+    Aes128(cbc::Encryptor<aes::Aes128Enc>),
+    Aes192(cbc::Encryptor<aes::Aes192Enc>),
+    Aes256(cbc::Encryptor<aes::Aes256Enc>),
+    #[cfg(feature = "3des")]
+    DesEde3(cbc::Encryptor<des::TdesEde3>),
+}
+
+impl EncryptMode {
+    fn block_size(&self) -> usize {
+        match self {
+            #[cfg(feature = "3des")]
+            Self::DesEde3(_) => super::DES_BLOCK_SIZE,
+            _ => AES_BLOCK_SIZE,
+        }
+    }
+
+    fn encrypt_block(&mut self, block: &mut [u8]) {
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_block_mut(GenericArray::from_mut_slice(block)),
+            Self::Aes192(cipher) => cipher.encrypt_block_mut(GenericArray::from_mut_slice(block)),
+            Self::Aes256(cipher) => cipher.encrypt_block_mut(GenericArray::from_mut_slice(block)),
+            #[cfg(feature = "3des")]
+            Self::DesEde3(cipher) => cipher.encrypt_block_mut(GenericArray::from_mut_slice(block)),
+        }
+    }
+}
+
+/// CBC mode decryptor state for each supported cipher.
+enum DecryptMode {
+    Aes128(cbc::Decryptor<aes::Aes128Dec>),
+    Aes192(cbc::Decryptor<aes::Aes192Dec>),
+    Aes256(cbc::Decryptor<aes::Aes256Dec>),
+    #[cfg(feature = "3des")]
+    DesEde3(cbc::Decryptor<des::TdesEde3>),
+    #[cfg(feature = "des-insecure")]
+    Des(cbc::Decryptor<des::Des>),
+}
+
+impl DecryptMode {
+    fn block_size(&self) -> usize {
+        match self {
+            #[cfg(feature = "3des")]
+            Self::DesEde3(_) => super::DES_BLOCK_SIZE,
+            #[cfg(feature = "des-insecure")]
+            Self::Des(_) => super::DES_BLOCK_SIZE,
+            _ => AES_BLOCK_SIZE,
+        }
+    }
+
+    fn decrypt_block(&mut self, block: &mut [u8]) {
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_block_mut(GenericArray::from_mut_slice(block)),
+            Self::Aes192(cipher) => cipher.decrypt_block_mut(GenericArray::from_mut_slice(block)),
+            Self::Aes256(cipher) => cipher.decrypt_block_mut(GenericArray::from_mut_slice(block)),
+            #[cfg(feature = "3des")]
+            Self::DesEde3(cipher) => cipher.decrypt_block_mut(GenericArray::from_mut_slice(block)),
+            #[cfg(feature = "des-insecure")]
+            Self::Des(cipher) => cipher.decrypt_block_mut(GenericArray::from_mut_slice(block)),
+        }
+    }
+}
+
+/// [`io::Write`] adapter which encrypts everything written to it before
+/// passing it on to the inner writer.
+///
+/// [`EncryptWriter::finish`] must be called once all plaintext has been
+/// written, to write the final block.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct EncryptWriter<W: io::Write> {
+    encryptor: Encryptor,
+    inner: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> EncryptWriter<W> {
+    /// Wrap the given writer with the given [`Encryptor`].
+    pub fn new(encryptor: Encryptor, inner: W) -> Self {
+        Self { encryptor, inner }
+    }
+
+    /// Write the final block of ciphertext, returning the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut block = [0u8; MAX_BLOCK_SIZE];
+        let n = self.encryptor.finalize(&mut block).map_err(io_error)?;
+        self.inner.write_all(&block[..n])?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for EncryptWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut output = [0u8; IO_CHUNK_SIZE + MAX_BLOCK_SIZE];
+
+        for chunk in buf.chunks(IO_CHUNK_SIZE) {
+            let n = self
+                .encryptor
+                .update(chunk, &mut output)
+                .map_err(io_error)?;
+            self.inner.write_all(&output[..n])?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// [`io::Read`] adapter which decrypts everything read from the inner
+/// reader.
+///
+/// Reads fail with [`io::ErrorKind::InvalidData`] if decryption fails once
+/// the end of the ciphertext is reached.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct DecryptReader<R: io::Read> {
+    decryptor: Option<Decryptor>,
+    inner: R,
+    output: [u8; IO_CHUNK_SIZE + MAX_BLOCK_SIZE],
+    start: usize,
+    end: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> DecryptReader<R> {
+    /// Wrap the given reader with the given [`Decryptor`].
+    pub fn new(decryptor: Decryptor, inner: R) -> Self {
+        Self {
+            decryptor: Some(decryptor),
+            inner,
+            output: [0u8; IO_CHUNK_SIZE + MAX_BLOCK_SIZE],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Get the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> io::Read for DecryptReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.start == self.end {
+            let decryptor = match self.decryptor.as_mut() {
+                Some(decryptor) => decryptor,
+                None => return Ok(0),
+            };
+
+            let mut input = [0u8; IO_CHUNK_SIZE];
+            let n = self.inner.read(&mut input)?;
+
+            self.start = 0;
+            self.end = if n == 0 {
+                match self.decryptor.take() {
+                    Some(decryptor) => decryptor.finalize(&mut self.output),
+                    None => Ok(0),
+                }
+            } else {
+                decryptor.update(&input[..n], &mut self.output)
+            }
+            .map_err(io_error)?;
+        }
+
+        let n = buf.len().min(self.end - self.start);
+        buf[..n].copy_from_slice(&self.output[self.start..][..n]);
+        self.start += n;
+        Ok(n)
+    }
+}
+
+/// Convert an [`Error`] into an [`io::Error`].
+#[cfg(feature = "std")]
+fn io_error(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
@@ -0,0 +1,187 @@
+//! PBES2 parameters builder tests
+
+use der::{Decode, Encode};
+use hex_literal::hex;
+use pkcs5::pbes2::{self, ParametersBuilder, Strength};
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL and extracted from the `pkcs8` crate's
+/// `tests/examples/ed25519-encpriv-aes256-pbkdf2-sha256.der` test vector.
+const PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305706092a864886f70d01050d304a302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301d06096086
+     4801650304012a0410b2d02d78b2efd9dff694cf8e0af40925"
+);
+
+/// Test salt.
+const SALT: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+
+/// Test IV.
+const IV: [u8; 16] = hex!("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+
+#[test]
+fn build_defaults() {
+    let builder = ParametersBuilder::new(SALT, IV);
+    let params = builder.build().unwrap();
+
+    let scrypt_params = params.kdf.scrypt().unwrap();
+    assert_eq!(scrypt_params.salt, &SALT);
+    assert_eq!(scrypt_params.cost_parameter, 1 << 15);
+    assert_eq!(scrypt_params.block_size, 8);
+    assert_eq!(scrypt_params.parallelization, 3);
+    assert_eq!(
+        params.encryption,
+        pbes2::EncryptionScheme::Aes256Cbc { iv: &IV }
+    );
+    assert_eq!(params.strength(), Some(Strength::Strong));
+}
+
+#[test]
+fn build_pbkdf2_presets() {
+    for (builder, prf, iterations) in [
+        (
+            ParametersBuilder::new(SALT, IV).pbkdf2_sha256(),
+            pbes2::Pbkdf2Prf::HmacWithSha256,
+            600_000,
+        ),
+        (
+            ParametersBuilder::new(SALT, IV).pbkdf2_sha512(),
+            pbes2::Pbkdf2Prf::HmacWithSha512,
+            210_000,
+        ),
+    ] {
+        let params = builder.build().unwrap();
+        let pbkdf2_params = params.kdf.pbkdf2().unwrap();
+        assert_eq!(pbkdf2_params.prf, prf);
+        assert_eq!(pbkdf2_params.iteration_count, iterations);
+        assert_eq!(pbkdf2_params.salt, &SALT);
+        assert_eq!(params.strength(), Some(Strength::Strong));
+
+        let mut buffer = [0u8; 128];
+        let der = params.encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(pbes2::Parameters::from_der(der).unwrap(), params);
+    }
+}
+
+#[test]
+fn build_aes256gcm() {
+    let builder = ParametersBuilder::new(SALT, IV).aes256gcm();
+    let params = builder.build().unwrap();
+
+    assert_eq!(
+        params.encryption,
+        pbes2::EncryptionScheme::Aes256Gcm {
+            nonce: &hex!("f0f1f2f3f4f5f6f7f8f9fafb"),
+            icv_len: 16
+        }
+    );
+}
+
+#[test]
+fn build_invalid_scrypt_params() {
+    for (log_n, r, p) in [(0, 8, 1), (16, 8, 1), (15, 0, 1), (15, 8, 0)] {
+        assert_eq!(
+            ParametersBuilder::new(SALT, IV)
+                .scrypt(log_n, r, p)
+                .build()
+                .err(),
+            Some(pkcs5::Error::AlgorithmParametersInvalid {
+                oid: pbes2::SCRYPT_OID
+            })
+        );
+    }
+}
+
+#[test]
+fn strength_estimates() {
+    // OpenSSL's default of 2048 PBKDF2 iterations
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID).unwrap();
+    assert_eq!(scheme.pbes2().unwrap().strength(), Some(Strength::Weak));
+
+    for (iterations, strength) in [
+        (59_999, Strength::Weak),
+        (60_000, Strength::Moderate),
+        (600_000, Strength::Strong),
+    ] {
+        let builder =
+            ParametersBuilder::new(SALT, IV).pbkdf2(pbes2::Pbkdf2Prf::HmacWithSha256, iterations);
+        assert_eq!(builder.build().unwrap().strength(), Some(strength));
+    }
+
+    for (log_n, r, p, strength) in [
+        (13, 8, 10, Strength::Strong),
+        (14, 16, 3, Strength::Strong),
+        (15, 8, 1, Strength::Moderate),
+        (14, 8, 1, Strength::Moderate),
+        // Parallelization can't fully make up for a lack of memory
+        (10, 8, 1000, Strength::Moderate),
+        (10, 8, 1, Strength::Weak),
+    ] {
+        let builder = ParametersBuilder::new(SALT, IV).scrypt(log_n, r, p);
+        assert_eq!(builder.build().unwrap().strength(), Some(strength));
+    }
+
+    let params = pbes2::Parameters {
+        kdf: pbes2::Kdf::Other(pkcs5::AlgorithmIdentifier {
+            oid: pkcs5::ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.2"),
+            parameters: None,
+        }),
+        encryption: pbes2::EncryptionScheme::Aes256Cbc { iv: &IV },
+    };
+    assert_eq!(params.strength(), None);
+}
+
+#[cfg(all(feature = "alloc", feature = "pbes2"))]
+#[test]
+fn build_round_trip() {
+    let plaintext = b"attack at dawn";
+
+    for builder in [
+        ParametersBuilder::new(SALT, IV).scrypt(10, 8, 1),
+        ParametersBuilder::new(SALT, IV)
+            .pbkdf2(pbes2::Pbkdf2Prf::HmacWithSha512, 1000)
+            .aes256gcm(),
+    ] {
+        let params = builder.build().unwrap();
+        let ciphertext = params.encrypt("hunter42", plaintext).unwrap();
+        assert_eq!(params.decrypt("hunter42", &ciphertext).unwrap(), plaintext);
+    }
+}
+
+#[cfg(feature = "rand_core")]
+#[test]
+fn build_random() {
+    use pkcs5::rand_core::{CryptoRng, Error, RngCore};
+
+    /// Deterministic RNG which counts up from zero.
+    struct CountingRng(u8);
+
+    impl RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            unimplemented!()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unimplemented!()
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for CountingRng {}
+
+    let builder = ParametersBuilder::random(CountingRng(0));
+    assert_eq!(builder.salt(), &SALT);
+    assert_eq!(builder.iv(), &hex!("101112131415161718191a1b1c1d1e1f"));
+}
@@ -0,0 +1,218 @@
+//! Application-provided KDF and cipher tests
+
+#![cfg(all(feature = "alloc", feature = "pbes2"))]
+
+use der::{
+    asn1::{Any, ObjectIdentifier, OctetString},
+    Decode, Encode,
+};
+use hex_literal::hex;
+use pkcs5::{
+    pbes2::{self, CustomCipher, CustomKdf, Registry},
+    AlgorithmIdentifier, Error, Result,
+};
+
+/// OID for the test cipher (under the RFC 5612 example enterprise number).
+const XOR_CIPHER_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.1");
+
+/// OID for the test KDF (under the RFC 5612 example enterprise number).
+const XOR_KDF_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.32473.2");
+
+/// Test salt.
+const SALT: &[u8] = &hex!("79d982e70df91a88");
+
+/// Test AES-CBC IV.
+const IV: &[u8; 16] = &hex!("b2d02d78b2efd9dff694cf8e0af40925");
+
+/// Test password.
+const PASSWORD: &[u8] = b"hunter42";
+
+/// Test plaintext.
+const PLAINTEXT: &[u8] = b"attack at dawn";
+
+/// Toy cipher which XORs the plaintext with the key, and appends a checksum.
+struct XorCipher;
+
+impl CustomCipher for XorCipher {
+    fn oid(&self) -> ObjectIdentifier {
+        XOR_CIPHER_OID
+    }
+
+    fn key_size(&self, parameters: Option<Any<'_>>) -> Result<usize> {
+        match parameters {
+            None => Ok(16),
+            Some(_) => Err(Error::AlgorithmParametersInvalid {
+                oid: XOR_CIPHER_OID,
+            }),
+        }
+    }
+
+    fn max_overhead(&self) -> usize {
+        1
+    }
+
+    fn decrypt_in_place<'b>(
+        &self,
+        _parameters: Option<Any<'_>>,
+        key: &[u8],
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        let (checksum, msg) = buffer.split_last_mut().ok_or(Error::DecryptFailed)?;
+        xor(key, msg);
+
+        if *checksum != msg.iter().fold(0, |acc, b| acc ^ b) {
+            return Err(Error::DecryptFailed);
+        }
+
+        Ok(msg)
+    }
+
+    fn encrypt_in_place<'b>(
+        &self,
+        _parameters: Option<Any<'_>>,
+        key: &[u8],
+        buffer: &'b mut [u8],
+        pos: usize,
+    ) -> Result<&'b [u8]> {
+        let buffer = buffer.get_mut(..=pos).ok_or(Error::EncryptFailed)?;
+        buffer[pos] = buffer[..pos].iter().fold(0, |acc, b| acc ^ b);
+        xor(key, &mut buffer[..pos]);
+        Ok(buffer)
+    }
+}
+
+/// Toy KDF which XORs the password with a salt given as an `OCTET STRING`.
+struct XorKdf;
+
+impl CustomKdf for XorKdf {
+    fn oid(&self) -> ObjectIdentifier {
+        XOR_KDF_OID
+    }
+
+    fn derive_key(
+        &self,
+        parameters: Option<Any<'_>>,
+        password: &[u8],
+        key: &mut [u8],
+    ) -> Result<()> {
+        let salt = parameters
+            .and_then(|params| params.octet_string().ok())
+            .ok_or(Error::AlgorithmParametersInvalid { oid: XOR_KDF_OID })?;
+
+        for (i, k) in key.iter_mut().enumerate() {
+            *k = password[i % password.len()] ^ salt.as_bytes()[i % salt.as_bytes().len()];
+        }
+
+        Ok(())
+    }
+}
+
+fn xor(key: &[u8], msg: &mut [u8]) {
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b ^= key[i % key.len()];
+    }
+}
+
+/// PBES2 parameters using PBKDF2-SHA256 and the test cipher.
+fn pbkdf2_xor_params() -> pbes2::Parameters<'static> {
+    pbes2::Parameters {
+        kdf: pbes2::Pbkdf2Params::hmac_with_sha256(2048, SALT)
+            .unwrap()
+            .into(),
+        encryption: pbes2::EncryptionScheme::Other(AlgorithmIdentifier {
+            oid: XOR_CIPHER_OID,
+            parameters: None,
+        }),
+    }
+}
+
+#[test]
+fn decode_unknown_algorithms() {
+    let salt = OctetString::new(SALT).unwrap();
+    let params = pbes2::Parameters {
+        kdf: pbes2::Kdf::Other(AlgorithmIdentifier {
+            oid: XOR_KDF_OID,
+            parameters: Some(salt.into()),
+        }),
+        encryption: pbes2::EncryptionScheme::Aes128Cbc { iv: IV },
+    };
+
+    let mut buffer = [0u8; 128];
+    let der = params.encode_to_slice(&mut buffer).unwrap();
+    let decoded = pbes2::Parameters::from_der(der).unwrap();
+    assert_eq!(decoded, params);
+    assert_eq!(decoded.kdf.oid(), XOR_KDF_OID);
+
+    let params = pbkdf2_xor_params();
+    let mut buffer = [0u8; 128];
+    let der = params.encode_to_slice(&mut buffer).unwrap();
+    let decoded = pbes2::Parameters::from_der(der).unwrap();
+    assert_eq!(decoded, params);
+    assert_eq!(decoded.encryption.oid(), XOR_CIPHER_OID);
+}
+
+#[test]
+fn unregistered_algorithms_unsupported() {
+    let params = pbkdf2_xor_params();
+
+    assert_eq!(
+        params.encrypt(PASSWORD, PLAINTEXT),
+        Err(Error::UnsupportedAlgorithm {
+            oid: XOR_CIPHER_OID
+        })
+    );
+
+    let params = pbes2::Parameters {
+        kdf: pbes2::Kdf::Other(AlgorithmIdentifier {
+            oid: XOR_KDF_OID,
+            parameters: None,
+        }),
+        encryption: pbes2::EncryptionScheme::Aes128Cbc { iv: IV },
+    };
+
+    assert_eq!(
+        params.decrypt(PASSWORD, &[0u8; 16]),
+        Err(Error::UnsupportedAlgorithm { oid: XOR_KDF_OID })
+    );
+}
+
+#[test]
+fn custom_cipher_round_trip() {
+    let params = pbkdf2_xor_params();
+    let registry = Registry::new().ciphers(&[&XorCipher]);
+
+    let ciphertext = params.encrypt_with(&registry, PASSWORD, PLAINTEXT).unwrap();
+    assert_eq!(ciphertext.len(), PLAINTEXT.len() + 1);
+    assert_ne!(&ciphertext[..PLAINTEXT.len()], PLAINTEXT);
+
+    let plaintext = params
+        .decrypt_with(&registry, PASSWORD, &ciphertext)
+        .unwrap();
+    assert_eq!(plaintext, PLAINTEXT);
+
+    assert_eq!(
+        params.decrypt_with(&registry, b"hunter43", &ciphertext),
+        Err(Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn custom_kdf_round_trip() {
+    let salt = OctetString::new(SALT).unwrap();
+    let params = pbes2::Parameters {
+        kdf: pbes2::Kdf::Other(AlgorithmIdentifier {
+            oid: XOR_KDF_OID,
+            parameters: Some(salt.into()),
+        }),
+        encryption: pbes2::EncryptionScheme::Aes128Cbc { iv: IV },
+    };
+    let registry = Registry::new().kdfs(&[&XorKdf]);
+
+    let ciphertext = params.encrypt_with(&registry, PASSWORD, PLAINTEXT).unwrap();
+    assert_eq!(ciphertext.len(), 16);
+
+    let plaintext = params
+        .decrypt_with(&registry, PASSWORD, &ciphertext)
+        .unwrap();
+    assert_eq!(plaintext, PLAINTEXT);
+}
@@ -24,6 +24,33 @@ const PBES2_SCRYPT_AES256CBC_ALG_ID: &[u8] = &hex!(
     09bd0a6251f2254f9fd5963887c27cf01"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier` example.
+///
+/// Uses the default 12-byte integrity check value length.
+const PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     48016503040106300e040cb2d02d78b2efd9dff694cf8e"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example.
+///
+/// Uses a 16-byte integrity check value.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + Argon2id + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Uses a 16-byte salt, 3 passes, 1 lane and 2^10 KiB of memory.
+const PBES2_ARGON2ID_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302a060b2b0601040181986a010203301b
+     0410a0a1a2a3a4a5a6a7a8a9aaabacadaeaf02010302010102010a301d060960
+     864801650304012a0410b0b1b2b3b4b5b6b7b8b9babbbcbdbebf"
+);
+
 /// Plaintext of Ed25519 PKCS#8 private key.
 ///
 /// This is the hex-encoded contents of `ed25519-priv.der` from
@@ -53,6 +80,36 @@ const ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT: &[u8] = &hex!(
      065EEB366D6E6C98CC3B0E7E69BDC861C88AFEB8F03DBA1E2C6D99D06D17360C"
 );
 
+/// Ciphertext and 12-byte ICV of Ed25519 PKCS#8 private key when encrypted
+/// using PBKDF2-SHA256 and AES-128-GCM.
+///
+/// Generated with the Python `cryptography` package, truncating the tag.
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM: &[u8] = &hex!(
+    "3E0DC329AC5B06976DE13282429BD11BB4B7E525648D21042711084FAD57F779
+     27DDD619C2AA7174054107A2D8B0E2EBA0249A2B66E4DAF351D5DEA1"
+);
+
+/// Ciphertext and 16-byte ICV of Ed25519 PKCS#8 private key when encrypted
+/// using PBKDF2-SHA256 and AES-256-GCM.
+///
+/// Generated with the Python `cryptography` package.
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM: &[u8] = &hex!(
+    "DC95A61BC03E10E1865D750036DCF2D287EB0390E5BD234979FCBB01271830C0
+     585BC98D993C20957687AE7E8A267F94AFF98338AF30F4908B0F714A94E09A11"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// Argon2id as the KDF.
+///
+/// Generated with:
+/// $ openssl kdf -keylen 32 -kdfopt pass:hunter42 -kdfopt hexsalt:a0a1a2a3a4a5a6a7a8a9aaabacadaeaf
+///   -kdfopt iter:3 -kdfopt lanes:1 -kdfopt memcost:1024 ARGON2ID
+/// $ openssl enc -aes-256-cbc -K <key> -iv b0b1b2b3b4b5b6b7b8b9babbbcbdbebf
+const ED25519_PKCS8_KEY_CIPHERTEXT_ARGON2ID: &[u8] = &hex!(
+    "FDF72F614D6C4FBBCD652EBD3614ACDF044FDE05EEA43F8C66543E7A46EA7DD7
+     B898740B0DC26160E6DD12959DBFC1070A2E51EAE80AFE97E6974FA1305AB002"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -91,6 +148,44 @@ const ED25519_PKCS8_KEY_CIPHERTEXT_DESCBC: &[u8] = &hex!(
     93E4E3893840181FBC63D75297B416A0B96CB7F9AB45CEABA"
 );
 
+/// PBES2 + PBKDF2-SHA512 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+///
+/// ```text
+/// $ openssl pkcs8 -topk8 -v2 aes-256-cbc -v2prf hmacWithSHA512 -outform DER
+/// ```
+const PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305f06092a864886f70d01050d3052303106092a864886f70d01050c30240410
+     db06e288170a63e856d4227e4cd7c2d102020800300c06082a864886f70d020b
+     0500301d060960864801650304012a0410fabf29212add548eda28ba6c30bd13
+     c3"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// PBKDF2-SHA512 and AES-256-CBC.
+const ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA512: &[u8] = &hex!(
+    "AFBC15F842873D0D350B013197F559D732DF97E197FA9CCC1C78CFCF91B0978D
+     55030F724C1C3926450244A0BE4B4D2A71AF6F795F05A90186937263B3553D6C"
+);
+
+/// PBES2 + PBKDF2-SHA3-256 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL as above with `-v2prf id-hmacWithSHA3-256`.
+const PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "306006092a864886f70d01050d3053303206092a864886f70d01050c30250410
+     5ff081a8f9c99f987c00701d120cf4a002020800300d06096086480165030402
+     0e0500301d060960864801650304012a04102e7cd90c103c65d4cfcaaf03dc38
+     7691"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// PBKDF2-SHA3-256 and AES-256-CBC.
+const ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA3_256: &[u8] = &hex!(
+    "0FF5F687D7C52B5D67655DA0D5302583647854C6E241028B2D221F0188073213
+     A47E8F8C7276C9D6D63288E7C142DB416962F07D6788EFE521AB3CB47DBD17C2"
+);
+
 /// Password used to encrypt the keys.
 const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
 
@@ -110,6 +205,105 @@ fn decrypt_pbes2_scrypt_aes256cbc() {
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
 
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes2_aes256gcm_tampered() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    buffer[0] ^= 1;
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer).err(),
+        Some(pkcs5::Error::DecryptFailed)
+    );
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    assert_eq!(
+        scheme.decrypt_in_place(b"hunter2", &mut buffer).err(),
+        Some(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn encrypt_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES128GCM);
+}
+
+#[test]
+fn encrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+
+    // Buffer must have room for the ICV
+    let mut buffer = [0u8; 60];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    assert_eq!(
+        scheme
+            .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+            .err(),
+        Some(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[test]
+#[cfg(feature = "argon2")]
+fn decrypt_pbes2_argon2id_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_ARGON2ID_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_ARGON2ID);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+#[cfg(feature = "argon2")]
+fn encrypt_pbes2_argon2id_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_ARGON2ID_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_ARGON2ID);
+}
+
+#[test]
+#[cfg(not(feature = "argon2"))]
+fn decrypt_pbes2_argon2id_unsupported() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_ARGON2ID_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_ARGON2ID);
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer).err(),
+        Some(pkcs5::Error::UnsupportedAlgorithm {
+            oid: pkcs5::pbes2::ARGON2ID_OID
+        })
+    );
+}
+
 #[test]
 #[cfg(feature = "3des")]
 fn decrypt_pbes2_pbkdf2_sha256_desede3cbc() {
@@ -127,3 +321,56 @@ fn decrypt_pbes2_pbkdf2_sha256_descbc() {
     let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha512_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA512);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn encrypt_pbes2_pbkdf2_sha512_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA512);
+}
+
+#[test]
+#[cfg(feature = "sha3")]
+fn decrypt_pbes2_pbkdf2_sha3_256_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA3_256);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+#[cfg(feature = "sha3")]
+fn encrypt_pbes2_pbkdf2_sha3_256_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA3_256);
+}
+
+#[test]
+#[cfg(not(feature = "sha3"))]
+fn decrypt_pbes2_pbkdf2_sha3_256_unsupported() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA3_256);
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer).err(),
+        Some(pkcs5::Error::UnsupportedAlgorithm {
+            oid: pkcs5::pbes2::HMAC_WITH_SHA3_256_OID
+        })
+    );
+}
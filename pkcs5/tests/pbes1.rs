@@ -0,0 +1,273 @@
+//! Password-Based Encryption Scheme 1 (and PKCS#12 PBE) tests
+
+use der::{Decode, Encode};
+use hex_literal::hex;
+use pkcs5::pbes1;
+
+/// `pbeWithMD5AndDES-CBC` `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+///
+/// ```text
+/// $ openssl pkcs8 -topk8 -provider legacy -provider default -v1 PBE-MD5-DES \
+///     -passout pass:hunter42 -outform DER
+/// ```
+///
+/// The PKCS#12 examples below were generated the same way using the
+/// `PBE-SHA1-3DES`, `PBE-SHA1-2DES`, `PBE-SHA1-RC2-128` and
+/// `PBE-SHA1-RC2-40` algorithms.
+const PBE_MD5_DES_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d010503300e040821880a5c7ffd283602020800");
+
+/// `pbeWithSHA1AndRC2-CBC` `AlgorithmIdentifier` example.
+const PBE_SHA1_RC2_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d01050b300e0408a306951209d5fdf102020800");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` `AlgorithmIdentifier` example.
+const PBE_SHA_3DES_ALG_ID: &[u8] =
+    &hex!("301c060a2a864886f70d010c0103300e0408b48bd8bd7769c7a902020800");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` `AlgorithmIdentifier` example.
+const PBE_SHA_2DES_ALG_ID: &[u8] =
+    &hex!("301c060a2a864886f70d010c0104300e0408daa77ff33d5dd83702020800");
+
+/// `pbeWithSHAAnd128BitRC2-CBC` `AlgorithmIdentifier` example.
+const PBE_SHA_RC2_128_ALG_ID: &[u8] =
+    &hex!("301c060a2a864886f70d010c0105300e0408e8d21805fa64222602020800");
+
+/// `pbeWithSHAAnd40BitRC2-CBC` `AlgorithmIdentifier` example.
+const PBE_SHA_RC2_40_ALG_ID: &[u8] =
+    &hex!("301c060a2a864886f70d010c0106300e0408b142c0ca31d3246902020800");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` `AlgorithmIdentifier` example with a
+/// 20-byte salt and 100,000 iterations, as produced by Java keystores.
+///
+/// Generated by OpenSSL as above with `-saltlen 20 -iter 100000`.
+const PBE_SHA_3DES_JAVA_ALG_ID: &[u8] = &hex!(
+    "3029060a2a864886f70d010c0103301b0414a9d504c9d98f51a670067c47c17a
+     a072d7f5c85802030186a0"
+);
+
+#[test]
+fn decode_pbe_md5_des() {
+    let alg = pbes1::Algorithm::from_der(PBE_MD5_DES_ALG_ID).unwrap();
+    assert_eq!(alg.encryption, pbes1::EncryptionScheme::PbeWithMd5AndDesCbc);
+    assert_eq!(alg.encryption.cipher(), pbes1::SymmetricCipher::DesCbc);
+    assert_eq!(alg.encryption.digest(), pbes1::DigestAlgorithm::Md5);
+    assert!(!alg.encryption.is_pkcs12());
+    assert_eq!(alg.parameters.salt, &hex!("21880a5c7ffd2836"));
+    assert_eq!(alg.parameters.iteration_count, 2048);
+}
+
+#[test]
+fn decode_pkcs12_schemes() {
+    for (alg_id, encryption, cipher) in [
+        (
+            PBE_SHA_3DES_ALG_ID,
+            pbes1::EncryptionScheme::PbeWithShaAnd3KeyTripleDesCbc,
+            pbes1::SymmetricCipher::DesEde3Cbc,
+        ),
+        (
+            PBE_SHA_2DES_ALG_ID,
+            pbes1::EncryptionScheme::PbeWithShaAnd2KeyTripleDesCbc,
+            pbes1::SymmetricCipher::DesEde2Cbc,
+        ),
+        (
+            PBE_SHA_RC2_128_ALG_ID,
+            pbes1::EncryptionScheme::PbeWithShaAnd128BitRc2Cbc,
+            pbes1::SymmetricCipher::Rc2Cbc128,
+        ),
+        (
+            PBE_SHA_RC2_40_ALG_ID,
+            pbes1::EncryptionScheme::PbeWithShaAnd40BitRc2Cbc,
+            pbes1::SymmetricCipher::Rc2Cbc40,
+        ),
+    ] {
+        let alg = pbes1::Algorithm::from_der(alg_id).unwrap();
+        assert_eq!(alg.encryption, encryption);
+        assert_eq!(alg.encryption.cipher(), cipher);
+        assert_eq!(alg.encryption.digest(), pbes1::DigestAlgorithm::Sha1);
+        assert!(alg.encryption.is_pkcs12());
+        assert_eq!(alg.parameters.iteration_count, 2048);
+    }
+}
+
+#[test]
+fn decode_pkcs12_long_salt() {
+    let alg = pbes1::Algorithm::from_der(PBE_SHA_3DES_JAVA_ALG_ID).unwrap();
+    assert_eq!(
+        alg.encryption,
+        pbes1::EncryptionScheme::PbeWithShaAnd3KeyTripleDesCbc
+    );
+    assert_eq!(alg.parameters.salt.len(), 20);
+    assert_eq!(alg.parameters.iteration_count, 100_000);
+}
+
+#[test]
+fn reject_pbes1_long_salt() {
+    // `pbeWithMD5AndDES-CBC` with the 20-byte salt from the Java example
+    let alg_id = hex!(
+        "302706092a864886f70d010503301a0414a9d504c9d98f51a670067c47c17aa0
+         72d7f5c85802020800"
+    );
+    assert!(pbes1::Algorithm::from_der(&alg_id).is_err());
+}
+
+#[test]
+fn encode_pbes1() {
+    for alg_id in [
+        PBE_MD5_DES_ALG_ID,
+        PBE_SHA1_RC2_ALG_ID,
+        PBE_SHA_3DES_ALG_ID,
+        PBE_SHA_RC2_40_ALG_ID,
+        PBE_SHA_3DES_JAVA_ALG_ID,
+    ] {
+        let alg = pbes1::Algorithm::from_der(alg_id).unwrap();
+        let mut buffer = [0u8; 64];
+        assert_eq!(alg.encode_to_slice(&mut buffer).unwrap(), alg_id);
+    }
+}
+
+#[cfg(feature = "legacy")]
+mod decrypt {
+    use super::*;
+
+    /// Password used to encrypt the examples.
+    const PASSWORD: &[u8] = b"hunter42";
+
+    /// Ed25519 PKCS#8 `PrivateKeyInfo` which all the examples decrypt to.
+    const PLAINTEXT: &[u8] = &hex!(
+        "302e020100300506032b65700422042017ed9c73e9db649ec189a612831c5fc5
+         70238207c1aa9dfbd2c53e3ff5e5ea85"
+    );
+
+    /// Decrypt the given ciphertext with the given `AlgorithmIdentifier`,
+    /// checking it matches [`PLAINTEXT`].
+    fn assert_decrypts(alg_id: &[u8], ciphertext: &[u8]) {
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        assert_eq!(scheme.decrypt(PASSWORD, ciphertext).unwrap(), PLAINTEXT);
+
+        let mut buffer = ciphertext.to_vec();
+        let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+        assert_eq!(plaintext, PLAINTEXT);
+    }
+
+    #[test]
+    fn decrypt_pbe_md5_des() {
+        assert_decrypts(
+            PBE_MD5_DES_ALG_ID,
+            &hex!(
+                "3cd863be517dd1c2dca799bc25c6a59f428ca0f4b746015e0ff7666056decd07
+                 5810c1dafec1f3ff3ff3b0e64f2509233fc2b83a343bb497"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha1_rc2() {
+        assert_decrypts(
+            PBE_SHA1_RC2_ALG_ID,
+            &hex!(
+                "f04779c6ddf08a0af736f25604b5c8949c1ab949da744d511100bdfeaf37a05f
+                 f7d3f906c074e10754da5e932615ae5526f993e80b542914"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha_3des() {
+        assert_decrypts(
+            PBE_SHA_3DES_ALG_ID,
+            &hex!(
+                "87c9388bebfa77a12216860a9a12b169ac843c0c030a4bd3c66b2af18cb3488b
+                 4dbbef257bf068038e4b769196ee82cacac4da566d065d89"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha_2des() {
+        assert_decrypts(
+            PBE_SHA_2DES_ALG_ID,
+            &hex!(
+                "e0d019d39469e09e9ce24ea272217babd2ca0d4fd5579f9b9551e5c2b2d54946
+                 cbf6b770f991881c57b4a986429049212925fffc50bb918f"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha_rc2_128() {
+        assert_decrypts(
+            PBE_SHA_RC2_128_ALG_ID,
+            &hex!(
+                "89b96a2a05416b86b6fa80c443e88f42353d51cecf22320ed2562c818a10a1c7
+                 0f2c10b9839e82b5e07215e4923b37f32c7dbaa0b9b3b06f"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha_rc2_40() {
+        assert_decrypts(
+            PBE_SHA_RC2_40_ALG_ID,
+            &hex!(
+                "a4256068f4148c32a4c29b5590300e2ca859c943911244504f140f86ec55ad97
+                 c06bcab12e90a952a250f2d7fe6de47c194f295b40464e6c"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_pbe_sha_3des_java() {
+        assert_decrypts(
+            PBE_SHA_3DES_JAVA_ALG_ID,
+            &hex!(
+                "079a90724b933e75b9faf9a5bb93101608ace8b2ebdf7b10b1385676fddaea54
+                 76b6143b444711bc944662b03257695e513920de83cf3664"
+            ),
+        );
+    }
+
+    #[test]
+    fn decrypt_wrong_password() {
+        let scheme = pkcs5::EncryptionScheme::try_from(PBE_SHA_3DES_ALG_ID).unwrap();
+        let ciphertext = hex!(
+            "87c9388bebfa77a12216860a9a12b169ac843c0c030a4bd3c66b2af18cb3488b
+             4dbbef257bf068038e4b769196ee82cacac4da566d065d89"
+        );
+
+        // Wrong passwords are overwhelmingly likely to produce invalid padding
+        assert_eq!(
+            scheme.decrypt(b"hunter43", &ciphertext),
+            Err(pkcs5::Error::DecryptFailed)
+        );
+    }
+}
+
+/// PKCS#12 key derivation function known-answer tests with SHA-1.
+#[cfg(feature = "legacy")]
+mod kdf {
+    use hex_literal::hex;
+    use pkcs5::pbes1::kdf::{derive_key, KeyType};
+    use sha1::Sha1;
+
+    const SALT: [u8; 8] = hex!("0A58CF64530D823F");
+
+    #[test]
+    fn derive_encryption_key() {
+        let mut key = [0u8; 24];
+        derive_key::<Sha1>("smeg", &SALT, KeyType::EncryptionKey, 1, &mut key);
+        assert_eq!(
+            key,
+            hex!("8AAAE6297B6CB04642AB5B077851284EB7128F1A2A7FBCA3")
+        );
+    }
+
+    #[test]
+    fn derive_iv() {
+        let mut iv = [0u8; 8];
+        derive_key::<Sha1>("smeg", &SALT, KeyType::Iv, 1, &mut iv);
+        assert_eq!(iv, hex!("79993DFE048D3B76"));
+    }
+}
@@ -24,6 +24,30 @@ const PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID: &[u8] = &hex!(
      4801650304012a0410b2d02d78b2efd9dff694cf8e0af40925"
 );
 
+/// PBES2 + PBKDF2-SHA512 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+///
+/// ```text
+/// $ openssl pkcs8 -topk8 -v2 aes-256-cbc -v2prf hmacWithSHA512 -outform DER
+/// ```
+const PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305f06092a864886f70d01050d3052303106092a864886f70d01050c30240410
+     db06e288170a63e856d4227e4cd7c2d102020800300c06082a864886f70d020b
+     0500301d060960864801650304012a0410fabf29212add548eda28ba6c30bd13
+     c3"
+);
+
+/// PBES2 + PBKDF2-SHA3-256 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL as above with `-v2prf id-hmacWithSHA3-256`.
+const PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "306006092a864886f70d01050d3053303206092a864886f70d01050c30250410
+     5ff081a8f9c99f987c00701d120cf4a002020800300d06096086480165030402
+     0e0500301d060960864801650304012a04102e7cd90c103c65d4cfcaaf03dc38
+     7691"
+);
+
 /// PBES2 + scrypt + AES-256-CBC `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -34,6 +58,33 @@ const PBES2_SCRYPT_AES256CBC_ALG_ID: &[u8] = &hex!(
     09bd0a6251f2254f9fd5963887c27cf01"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier` example.
+///
+/// Uses the default 12-byte integrity check value length.
+const PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     48016503040106300e040cb2d02d78b2efd9dff694cf8e"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example.
+///
+/// Uses a 16-byte integrity check value.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + Argon2id + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Uses a 16-byte salt, 3 passes, 1 lane and 2^10 KiB of memory.
+const PBES2_ARGON2ID_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302a060b2b0601040181986a010203301b
+     0410a0a1a2a3a4a5a6a7a8a9aaabacadaeaf02010302010102010a301d060960
+     864801650304012a0410b0b1b2b3b4b5b6b7b8b9babbbcbdbebf"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -96,6 +147,37 @@ fn decode_pbes2_pbkdf2_sha256_aes256cbc() {
     }
 }
 
+/// Decoding test for PBES2 + PBKDF2-SHA512/SHA3-256 + AES-256-CBC
+/// `AlgorithmIdentifier`s
+#[test]
+fn decode_pbes2_pbkdf2_sha512_sha3_256_aes256cbc() {
+    for (alg_id, prf, salt) in [
+        (
+            PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID,
+            pbes2::Pbkdf2Prf::HmacWithSha512,
+            hex!("db06e288170a63e856d4227e4cd7c2d1"),
+        ),
+        (
+            PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID,
+            pbes2::Pbkdf2Prf::HmacWithSha3_256,
+            hex!("5ff081a8f9c99f987c00701d120cf4a0"),
+        ),
+    ] {
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        let params = scheme.pbes2().unwrap();
+
+        let pbkdf2_params = params.kdf.pbkdf2().unwrap();
+        assert_eq!(pbkdf2_params.salt, &salt);
+        assert_eq!(pbkdf2_params.iteration_count, 2048);
+        assert_eq!(pbkdf2_params.key_length, None);
+        assert_eq!(pbkdf2_params.prf, prf);
+        assert!(matches!(
+            params.encryption,
+            pbes2::EncryptionScheme::Aes256Cbc { .. }
+        ));
+    }
+}
+
 /// Decoding test for PBES2 + scrypt + AES-256-CBC `AlgorithmIdentifier`
 #[test]
 fn decode_pbes2_scrypt_aes256cbc() {
@@ -117,6 +199,78 @@ fn decode_pbes2_scrypt_aes256cbc() {
     }
 }
 
+/// Decoding test for PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_pbkdf2_sha256_aes128gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    assert_eq!(params.kdf.pbkdf2().unwrap().iteration_count, 2048);
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            assert_eq!(nonce, &hex!("B2D02D78B2EFD9DFF694CF8E"));
+            assert_eq!(icv_len, 12);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+}
+
+/// Decoding test for PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    assert_eq!(params.encryption.oid(), pbes2::AES_256_GCM_OID);
+    assert_eq!(params.encryption.key_size(), 32);
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            assert_eq!(nonce, &hex!("B2D02D78B2EFD9DFF694CF8E"));
+            assert_eq!(icv_len, 16);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+}
+
+/// AES-GCM parameters with an out of range ICV length are rejected
+#[test]
+fn reject_invalid_gcm_icv_len() {
+    let mut der = PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID.to_vec();
+    let len = der.len();
+
+    for icv_len in [11, 17] {
+        der[len - 1] = icv_len;
+        assert!(pkcs5::EncryptionScheme::try_from(der.as_slice()).is_err());
+    }
+}
+
+/// Decoding test for PBES2 + Argon2id + AES-256-CBC `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_argon2id_aes256cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_ARGON2ID_AES256CBC_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+    assert_eq!(params.kdf.oid(), pbes2::ARGON2ID_OID);
+
+    let argon2_params = params.kdf.argon2id().unwrap();
+    assert_eq!(
+        argon2_params.salt,
+        &hex!("A0A1A2A3A4A5A6A7A8A9AAABACADAEAF")
+    );
+    assert_eq!(argon2_params.passes, 3);
+    assert_eq!(argon2_params.parallelism, 1);
+    assert_eq!(argon2_params.memory_exponent, 10);
+    assert_eq!(argon2_params.secret, None);
+    assert_eq!(argon2_params.associated_data, None);
+    assert_eq!(params.kdf.key_length(), None);
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Aes256Cbc { iv } => {
+            assert_eq!(iv, &hex!("B0B1B2B3B4B5B6B7B8B9BABBBCBDBEBF"));
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+}
+
 /// Decoding test for PBES2 + PBKDF2-SHA256 + DES-EDE3-CBC `AlgorithmIdentifier`
 #[cfg(feature = "3des")]
 #[test]
@@ -185,6 +339,41 @@ fn encode_pbes2_pbkdf2_sha256_aes256cbc() {
     assert_eq!(encoded_der, PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID);
 }
 
+/// Encoding test for PBES2 + PBKDF2-SHA512/SHA3-256 + AES-256-CBC
+/// `AlgorithmIdentifier`s
+#[test]
+fn encode_pbes2_pbkdf2_sha512_sha3_256_aes256cbc() {
+    for alg_id in [
+        PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID,
+        PBES2_PBKDF2_SHA3_256_AES256CBC_ALG_ID,
+    ] {
+        let mut buffer = [0u8; 1024];
+
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        let mut encoder = der::Encoder::new(&mut buffer);
+        scheme.encode(&mut encoder).unwrap();
+
+        let encoded_der = encoder.finish().unwrap();
+        assert_eq!(encoded_der, alg_id);
+    }
+
+    let salt = hex!("db06e288170a63e856d4227e4cd7c2d1");
+    let pbkdf2_params = pbes2::Pbkdf2Params::hmac_with_sha512(2048, &salt).unwrap();
+    let params = pbes2::Parameters {
+        kdf: pbkdf2_params.into(),
+        encryption: pbes2::EncryptionScheme::Aes256Cbc {
+            iv: &hex!("fabf29212add548eda28ba6c30bd13c3"),
+        },
+    };
+
+    let mut buffer = [0u8; 1024];
+    let scheme = pkcs5::EncryptionScheme::from(params);
+    assert_eq!(
+        scheme.encode_to_slice(&mut buffer).unwrap(),
+        PBES2_PBKDF2_SHA512_AES256CBC_ALG_ID
+    );
+}
+
 /// Encoding test for PBES2 + scrypt + AES-256-CBC `AlgorithmIdentifier`
 #[test]
 fn encode_pbes2_scrypt_aes256cbc() {
@@ -197,3 +386,67 @@ fn encode_pbes2_scrypt_aes256cbc() {
     let encoded_der = encoder.finish().unwrap();
     assert_eq!(encoded_der, PBES2_SCRYPT_AES256CBC_ALG_ID);
 }
+
+/// Encoding test for PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier`
+#[test]
+fn encode_pbes2_pbkdf2_sha256_aes128gcm() {
+    let mut buffer = [0u8; 1024];
+
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let mut encoder = der::Encoder::new(&mut buffer);
+    scheme.encode(&mut encoder).unwrap();
+
+    let encoded_der = encoder.finish().unwrap();
+    assert_eq!(encoded_der, PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID);
+}
+
+/// Encoding test for PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier`
+#[test]
+fn encode_pbes2_pbkdf2_sha256_aes256gcm() {
+    let salt = hex!("79D982E70DF91A88");
+    let nonce = hex!("B2D02D78B2EFD9DFF694CF8E");
+    let mut buffer = [0u8; 1024];
+
+    let params = pbes2::Parameters::pbkdf2_sha256_aes256gcm(2048, &salt, &nonce).unwrap();
+    let scheme = pkcs5::EncryptionScheme::from(params);
+    let mut encoder = der::Encoder::new(&mut buffer);
+    scheme.encode(&mut encoder).unwrap();
+
+    let encoded_der = encoder.finish().unwrap();
+    assert_eq!(encoded_der, PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID);
+}
+
+/// Encoding test for PBES2 + Argon2id + AES-256-CBC `AlgorithmIdentifier`
+#[test]
+fn encode_pbes2_argon2id_aes256cbc() {
+    let mut buffer = [0u8; 1024];
+
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_ARGON2ID_AES256CBC_ALG_ID).unwrap();
+    let mut encoder = der::Encoder::new(&mut buffer);
+    scheme.encode(&mut encoder).unwrap();
+
+    let encoded_der = encoder.finish().unwrap();
+    assert_eq!(encoded_der, PBES2_ARGON2ID_AES256CBC_ALG_ID);
+}
+
+/// Round trip test for Argon2id parameters with the optional secret and
+/// associated data present
+#[test]
+fn argon2id_params_optional_fields() {
+    let params = pbes2::Argon2Params {
+        salt: &[0xA0; 16],
+        passes: 3,
+        parallelism: 4,
+        memory_exponent: 16,
+        secret: Some(b"pepper"),
+        associated_data: Some(b"context"),
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = der::Encoder::new(&mut buffer);
+    params.encode(&mut encoder).unwrap();
+    let encoded_der = encoder.finish().unwrap();
+
+    let decoded = <pbes2::Argon2Params<'_> as der::Decode<'_>>::from_der(encoded_der).unwrap();
+    assert_eq!(decoded, params);
+}
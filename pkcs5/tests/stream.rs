@@ -0,0 +1,183 @@
+//! Streaming PBES2 encryption tests
+
+#![cfg(feature = "pbes2")]
+
+use hex_literal::hex;
+use pkcs5::pbes2;
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL and extracted from the `pkcs8` crate's
+/// `tests/examples/ed25519-encpriv-aes256-pbkdf2-sha256.der` test vector.
+const PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID: &[u8] = &hex!(
+    "305706092a864886f70d01050d304a302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301d06096086
+     4801650304012a0410b2d02d78b2efd9dff694cf8e0af40925"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-128-GCM `AlgorithmIdentifier` example.
+const PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     48016503040106300e040cb2d02d78b2efd9dff694cf8e"
+);
+
+/// Plaintext of Ed25519 PKCS#8 private key.
+const ED25519_PKCS8_KEY_PLAINTEXT: &[u8] = &hex!(
+    "302e020100300506032b65700422042017ed9c73e9db649ec189a612831c5fc5
+     70238207c1aa9dfbd2c53e3ff5e5ea85"
+);
+
+/// Ciphertext of Ed25519 PKCS#8 private key when encrypted using
+/// PBKDF2-SHA256 and AES-256-CBC.
+const ED25519_PKCS8_KEY_CIPHERTEXT: &[u8] = &hex!(
+    "D0CD6C770F4BB87176422305C17401809E226674CE74185D221BFDAA95069890
+     C8882FCE02B05D41BCBF54B035595BCD4154B32593708469B86AACF8815A7B2B"
+);
+
+/// Password used to encrypt the keys.
+const PASSWORD: &[u8] = b"hunter42";
+
+/// Chunk sizes to feed the encryptor/decryptor with, including sizes which
+/// aren't aligned to the block size.
+const CHUNK_SIZES: &[usize] = &[1, 7, 16, 17, 48, 1000];
+
+fn params() -> pbes2::Parameters<'static> {
+    pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID)
+        .unwrap()
+        .pbes2()
+        .unwrap()
+        .clone()
+}
+
+fn encrypt_chunked(plaintext: &[u8], chunk_size: usize) -> Vec<u8> {
+    let mut encryptor = params().encryptor(PASSWORD).unwrap();
+    let mut ciphertext = Vec::new();
+    let mut output = vec![0u8; chunk_size + encryptor.block_size()];
+
+    for chunk in plaintext.chunks(chunk_size) {
+        let n = encryptor.update(chunk, &mut output).unwrap();
+        ciphertext.extend_from_slice(&output[..n]);
+    }
+
+    let n = encryptor.finalize(&mut output).unwrap();
+    ciphertext.extend_from_slice(&output[..n]);
+    ciphertext
+}
+
+fn decrypt_chunked(ciphertext: &[u8], chunk_size: usize) -> pkcs5::Result<Vec<u8>> {
+    let mut decryptor = params().decryptor(PASSWORD)?;
+    let mut plaintext = Vec::new();
+    let mut output = vec![0u8; chunk_size + decryptor.block_size()];
+
+    for chunk in ciphertext.chunks(chunk_size) {
+        let n = decryptor.update(chunk, &mut output)?;
+        plaintext.extend_from_slice(&output[..n]);
+    }
+
+    let n = decryptor.finalize(&mut output)?;
+    plaintext.extend_from_slice(&output[..n]);
+    Ok(plaintext)
+}
+
+#[test]
+fn encrypt_stream() {
+    for &chunk_size in CHUNK_SIZES {
+        assert_eq!(
+            encrypt_chunked(ED25519_PKCS8_KEY_PLAINTEXT, chunk_size),
+            ED25519_PKCS8_KEY_CIPHERTEXT
+        );
+    }
+}
+
+#[test]
+fn decrypt_stream() {
+    for &chunk_size in CHUNK_SIZES {
+        assert_eq!(
+            decrypt_chunked(ED25519_PKCS8_KEY_CIPHERTEXT, chunk_size).unwrap(),
+            ED25519_PKCS8_KEY_PLAINTEXT
+        );
+    }
+}
+
+#[test]
+fn stream_round_trip() {
+    let plaintext = (0..=255u8).cycle().take(5000).collect::<Vec<_>>();
+
+    for &chunk_size in CHUNK_SIZES {
+        let ciphertext = encrypt_chunked(&plaintext, chunk_size);
+        assert_eq!(ciphertext.len(), 5008);
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(params().encrypt(PASSWORD, &plaintext).unwrap(), ciphertext);
+
+        assert_eq!(decrypt_chunked(&ciphertext, chunk_size).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn decrypt_stream_truncated() {
+    let ciphertext = &ED25519_PKCS8_KEY_CIPHERTEXT[..40];
+    assert_eq!(
+        decrypt_chunked(ciphertext, 16),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn update_output_too_small() {
+    let mut encryptor = params().encryptor(PASSWORD).unwrap();
+    let mut output = [0u8; 15];
+    assert_eq!(
+        encryptor.update(&[0u8; 16], &mut output),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[test]
+fn stream_gcm_unsupported() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES128GCM_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    assert_eq!(
+        params.decryptor(PASSWORD).err(),
+        Some(pkcs5::Error::UnsupportedAlgorithm {
+            oid: pbes2::AES_128_GCM_OID
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+mod io {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn encrypt_writer() {
+        let encryptor = params().encryptor(PASSWORD).unwrap();
+        let mut writer = pbes2::EncryptWriter::new(encryptor, Vec::new());
+
+        for chunk in ED25519_PKCS8_KEY_PLAINTEXT.chunks(5) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert_eq!(writer.finish().unwrap(), ED25519_PKCS8_KEY_CIPHERTEXT);
+    }
+
+    #[test]
+    fn decrypt_reader() {
+        let decryptor = params().decryptor(PASSWORD).unwrap();
+        let mut reader = pbes2::DecryptReader::new(decryptor, ED25519_PKCS8_KEY_CIPHERTEXT);
+        let mut plaintext = Vec::new();
+        reader.read_to_end(&mut plaintext).unwrap();
+        assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+    }
+
+    #[test]
+    fn decrypt_reader_wrong_password() {
+        let decryptor = params().decryptor(b"hunter2").unwrap();
+        let mut reader = pbes2::DecryptReader::new(decryptor, ED25519_PKCS8_KEY_CIPHERTEXT);
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,233 @@
+//! Legacy content-encryption algorithms and their parameters, as used by the
+//! `encrypted-data` content type [RFC 5652 § 8](https://datatracker.ietf.org/doc/html/rfc5652#section-8)
+//!
+//! These are commonly found in PKCS#12 files exported from Java keystores
+//! (JKS/JCEKS) and by older Windows versions, which encrypt their contents
+//! with RC2 or Triple-DES, often using the PKCS#12 password-based encryption
+//! schemes.
+//!
+//! Only parsing is supported: these algorithms are insecure and shouldn't be
+//! used to encrypt new data.
+
+use der::{
+    asn1::{Any, ObjectIdentifier, OctetString},
+    Decode, Decoder, Encode, Sequence, Tag,
+};
+use spki::AlgorithmIdentifier;
+
+/// `rc2-cbc` Object Identifier (OID).
+pub const RC2_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.3.2");
+
+/// `des-ede3-cbc` Object Identifier (OID).
+pub const DES_EDE3_CBC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.3.7");
+
+/// `pbeWithSHAAnd128BitRC4` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128BIT_RC4_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.1");
+
+/// `pbeWithSHAAnd40BitRC4` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40BIT_RC4_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.2");
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.3");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_2KEY_TRIPLE_DES_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.4");
+
+/// `pbeWithSHAAnd128BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.5");
+
+/// `pbeWithSHAAnd40BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.6");
+
+/// Size of the RC2 and DES block, and therefore of their CBC mode IVs.
+const BLOCK_SIZE: usize = 8;
+
+/// Content-encryption scheme of an
+/// [`EncryptedContentInfo`](crate::enveloped_data_content::EncryptedContentInfo)
+///
+/// Decoded from its `contentEncryptionAlgorithm` by
+/// [`EncryptedContentInfo::encryption_scheme`](crate::enveloped_data_content::EncryptedContentInfo::encryption_scheme).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EncryptionScheme<'a> {
+    /// RC2 in CBC mode ([`RC2_CBC_OID`]).
+    Rc2Cbc(Rc2CbcParameter<'a>),
+
+    /// Three-key Triple-DES in CBC mode ([`DES_EDE3_CBC_OID`]).
+    DesEde3Cbc {
+        /// the initialization vector.
+        iv: &'a [u8; BLOCK_SIZE],
+    },
+
+    /// One of the PKCS#12 password-based encryption schemes, identified by
+    /// one of the `PBE_WITH_SHA_AND_*_OID` constants.
+    Pkcs12Pbe {
+        /// identifies the scheme.
+        oid: ObjectIdentifier,
+
+        /// the key derivation parameters.
+        params: Pkcs12PbeParams<'a>,
+    },
+}
+
+impl<'a> EncryptionScheme<'a> {
+    /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            EncryptionScheme::Rc2Cbc(_) => RC2_CBC_OID,
+            EncryptionScheme::DesEde3Cbc { .. } => DES_EDE3_CBC_OID,
+            EncryptionScheme::Pkcs12Pbe { oid, .. } => *oid,
+        }
+    }
+}
+
+impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
+    type Error = spki::Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'a>) -> spki::Result<EncryptionScheme<'a>> {
+        match alg.oid {
+            RC2_CBC_OID => Ok(EncryptionScheme::Rc2Cbc(alg.parameters_any()?.try_into()?)),
+            DES_EDE3_CBC_OID => Ok(EncryptionScheme::DesEde3Cbc {
+                iv: decode_iv(alg.parameters_any()?.octet_string()?)?,
+            }),
+            PBE_WITH_SHA_AND_128BIT_RC4_OID
+            | PBE_WITH_SHA_AND_40BIT_RC4_OID
+            | PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID
+            | PBE_WITH_SHA_AND_2KEY_TRIPLE_DES_CBC_OID
+            | PBE_WITH_SHA_AND_128BIT_RC2_CBC_OID
+            | PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID => Ok(EncryptionScheme::Pkcs12Pbe {
+                oid: alg.oid,
+                params: alg.parameters_any()?.try_into()?,
+            }),
+            oid => Err(spki::Error::OidUnknown { oid }),
+        }
+    }
+}
+
+/// RC2-CBC parameters [RFC 8018 Appendix B.2.3](https://datatracker.ietf.org/doc/html/rfc8018#appendix-B.2.3)
+///
+/// ```text
+/// RC2-CBC-Parameter ::= SEQUENCE {
+///   rc2ParameterVersion INTEGER OPTIONAL,
+///   iv OCTET STRING (SIZE(8)) }
+/// ```
+///
+/// The fields of type `RC2-CBC-Parameter` have the following meanings:
+///   - [`rc2_parameter_version`](Rc2CbcParameter::rc2_parameter_version) encodes
+///     the RC2 "effective key bits", see [`Rc2CbcParameter::effective_key_bits`].
+///   - [`iv`](Rc2CbcParameter::iv) is the initialization vector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rc2CbcParameter<'a> {
+    /// encodes the RC2 "effective key bits".
+    pub rc2_parameter_version: Option<u32>,
+
+    /// the initialization vector.
+    pub iv: &'a [u8; BLOCK_SIZE],
+}
+
+impl<'a> Rc2CbcParameter<'a> {
+    /// Get the RC2 "effective key bits" encoded by the
+    /// [`rc2_parameter_version`](Rc2CbcParameter::rc2_parameter_version).
+    ///
+    /// Returns `None` for versions below 256 which don't encode 40, 64 or
+    /// 128 effective key bits, as only those are defined by RFC 8018.
+    pub fn effective_key_bits(&self) -> Option<u32> {
+        match self.rc2_parameter_version {
+            None => Some(32),
+            Some(160) => Some(40),
+            Some(120) => Some(64),
+            Some(58) => Some(128),
+            Some(version) if version >= 256 => Some(version),
+            Some(_) => None,
+        }
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for Rc2CbcParameter<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Rc2CbcParameter<'a>> {
+        any.sequence(|decoder| {
+            Ok(Rc2CbcParameter {
+                rc2_parameter_version: decoder.decode()?,
+                iv: decode_iv(decoder.octet_string()?)?,
+            })
+        })
+    }
+}
+
+impl<'a> Decode<'a> for Rc2CbcParameter<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Rc2CbcParameter<'a>> {
+        Any::decode(decoder)?.try_into()
+    }
+}
+
+impl<'a> Sequence<'a> for Rc2CbcParameter<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.rc2_parameter_version, &OctetString::new(self.iv)?])
+    }
+}
+
+/// PKCS#12 password-based encryption parameters [RFC 7292 Appendix C](https://datatracker.ietf.org/doc/html/rfc7292#appendix-C)
+///
+/// ```text
+/// pkcs-12PbeParams ::= SEQUENCE {
+///   salt        OCTET STRING,
+///   iterations  INTEGER }
+/// ```
+///
+/// The fields of type `pkcs-12PbeParams` have the following meanings:
+///   - [`salt`](Pkcs12PbeParams::salt) is the salt input to the key derivation.
+///   - [`iterations`](Pkcs12PbeParams::iterations) is the number of iterations
+///     of the key derivation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pkcs12PbeParams<'a> {
+    /// the salt input to the key derivation.
+    pub salt: &'a [u8],
+
+    /// the number of iterations of the key derivation.
+    pub iterations: u32,
+}
+
+impl<'a> TryFrom<Any<'a>> for Pkcs12PbeParams<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Pkcs12PbeParams<'a>> {
+        any.sequence(|decoder| {
+            Ok(Pkcs12PbeParams {
+                salt: decoder.octet_string()?.as_bytes(),
+                iterations: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Decode<'a> for Pkcs12PbeParams<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Pkcs12PbeParams<'a>> {
+        Any::decode(decoder)?.try_into()
+    }
+}
+
+impl<'a> Sequence<'a> for Pkcs12PbeParams<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&OctetString::new(self.salt)?, &self.iterations])
+    }
+}
+
+/// Decode a CBC mode IV for RC2 or DES.
+fn decode_iv(iv: OctetString<'_>) -> der::Result<&[u8; BLOCK_SIZE]> {
+    iv.as_bytes()
+        .try_into()
+        .map_err(|_| Tag::OctetString.value_error())
+}
@@ -1,6 +1,6 @@
 //! `enveloped-data` content type [RFC 5652 § 6](https://datatracker.ietf.org/doc/html/rfc5652#section-6)
 
-use crate::ContentType;
+use crate::{encryption_scheme::EncryptionScheme, ContentType};
 
 use der::{
     asn1::{ContextSpecific, OctetString},
@@ -49,6 +49,16 @@ pub struct EncryptedContentInfo<'a> {
     pub encrypted_content: Option<&'a [u8]>,
 }
 
+impl<'a> EncryptedContentInfo<'a> {
+    /// Decode the [`content_encryption_algorithm`](EncryptedContentInfo::content_encryption_algorithm)
+    /// as one of the legacy [`EncryptionScheme`]s.
+    ///
+    /// Returns [`spki::Error::OidUnknown`] for any other algorithm.
+    pub fn encryption_scheme(&self) -> spki::Result<EncryptionScheme<'a>> {
+        self.content_encryption_algorithm.try_into()
+    }
+}
+
 impl<'a> Decode<'a> for EncryptedContentInfo<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<EncryptedContentInfo<'a>> {
         decoder.sequence(|decoder| {
@@ -17,6 +17,7 @@ pub use crate::{content_info::ContentInfo, content_type::ContentType};
 
 pub mod data_content;
 pub mod encrypted_data_content;
+pub mod encryption_scheme;
 pub mod enveloped_data_content;
 
 use der::asn1::ObjectIdentifier;
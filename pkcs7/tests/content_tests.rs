@@ -1,9 +1,14 @@
 //! PKCS#7 example tests
 
-use der::{asn1::ObjectIdentifier, Decode, Encoder};
+use der::{asn1::ObjectIdentifier, Decode, Encode, Encoder};
 use hex_literal::hex;
 use pkcs7::{
-    encrypted_data_content::EncryptedDataContent, enveloped_data_content::EncryptedContentInfo,
+    encrypted_data_content::EncryptedDataContent,
+    encryption_scheme::{
+        EncryptionScheme, Pkcs12PbeParams, Rc2CbcParameter,
+        PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID, PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID,
+    },
+    enveloped_data_content::EncryptedContentInfo,
     ContentInfo, ContentType,
 };
 use spki::AlgorithmIdentifier;
@@ -15,6 +20,37 @@ fn encode_content_info<'a>(content_info: &ContentInfo<'a>, buf: &'a mut [u8]) ->
     encoder.finish().expect("encoding success")
 }
 
+/// Decode an `encryptedData` example, check that it re-encodes identically and
+/// return its encryption scheme and encrypted content length.
+fn decode_encrypted_data_example(path: &str) -> (EncryptionScheme<'static>, usize) {
+    let bytes = fs::read(path).unwrap_or_else(|_| panic!("Failed to read from {}", path));
+    let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+
+    let content = ContentInfo::from_der(bytes).expect("expected valid data");
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+    assert_eq!(encoded_content, bytes);
+
+    match content {
+        ContentInfo::EncryptedData(Some(EncryptedDataContent {
+            version: _,
+            encrypted_content_info:
+                encrypted_content_info @ EncryptedContentInfo {
+                    content_type: ContentType::Data,
+                    encrypted_content: Some(encrypted_content),
+                    ..
+                },
+        })) => (
+            encrypted_content_info
+                .encryption_scheme()
+                .expect("known encryption scheme"),
+            encrypted_content.len(),
+        ),
+        _ => panic!("expected ContentInfo::EncryptedData(Some(_))"),
+    }
+}
+
 #[test]
 fn decode_cert_example() {
     let path = "./tests/examples/certData.bin";
@@ -78,3 +114,111 @@ fn decode_encrypted_key_example() {
 
     assert_eq!(encoded_content, bytes)
 }
+
+#[test]
+fn decode_encrypted_key_scheme() {
+    let (scheme, _) = decode_encrypted_data_example("./tests/examples/keyEncryptedData.bin");
+
+    assert_eq!(
+        scheme,
+        EncryptionScheme::Pkcs12Pbe {
+            oid: PBE_WITH_SHA_AND_40BIT_RC2_CBC_OID,
+            params: Pkcs12PbeParams {
+                salt: &hex!("ad2d4b4e87b34d67"),
+                iterations: 2048,
+            },
+        }
+    );
+}
+
+#[test]
+fn decode_encrypted_pbe_sha_3des_example() {
+    let (scheme, len) =
+        decode_encrypted_data_example("./tests/examples/encryptedDataPbeSha3des.bin");
+
+    assert_eq!(
+        scheme,
+        EncryptionScheme::Pkcs12Pbe {
+            oid: PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID,
+            params: Pkcs12PbeParams {
+                salt: &hex!("d321e0f08eeb01ad"),
+                iterations: 2048,
+            },
+        }
+    );
+    assert_eq!(scheme.oid(), PBE_WITH_SHA_AND_3KEY_TRIPLE_DES_CBC_OID);
+    assert_eq!(len, 552);
+}
+
+#[test]
+fn decode_encrypted_des_ede3_cbc_example() {
+    let (scheme, len) = decode_encrypted_data_example("./tests/examples/encryptedDataDesEde3.bin");
+
+    assert_eq!(
+        scheme,
+        EncryptionScheme::DesEde3Cbc {
+            iv: &hex!("50cd5bb183da6177"),
+        }
+    );
+    assert_eq!(len, 16);
+}
+
+#[test]
+fn decode_encrypted_rc2_cbc_examples() {
+    let (scheme, len) = decode_encrypted_data_example("./tests/examples/encryptedDataRc2_40.bin");
+    let params = Rc2CbcParameter {
+        rc2_parameter_version: Some(160),
+        iv: &hex!("242bc186f7cb245e"),
+    };
+    assert_eq!(scheme, EncryptionScheme::Rc2Cbc(params));
+    assert_eq!(params.effective_key_bits(), Some(40));
+    assert_eq!(len, 16);
+
+    let (scheme, len) = decode_encrypted_data_example("./tests/examples/encryptedDataRc2_128.bin");
+    let params = Rc2CbcParameter {
+        rc2_parameter_version: Some(58),
+        iv: &hex!("83cd6d6269fc6560"),
+    };
+    assert_eq!(scheme, EncryptionScheme::Rc2Cbc(params));
+    assert_eq!(params.effective_key_bits(), Some(128));
+    assert_eq!(len, 16);
+}
+
+#[test]
+fn encryption_scheme_errors() {
+    // AES-128-CBC isn't a legacy scheme
+    let aes_128_cbc = AlgorithmIdentifier {
+        oid: ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.2"),
+        parameters: None,
+    };
+    assert_eq!(
+        EncryptionScheme::try_from(aes_128_cbc),
+        Err(spki::Error::OidUnknown {
+            oid: aes_128_cbc.oid
+        })
+    );
+
+    // The RC2 IV must be a single block
+    let params = der::asn1::Any::from_der(&hex!("300a02013a04050001020304")).unwrap();
+    let rc2_cbc = AlgorithmIdentifier {
+        oid: pkcs7::encryption_scheme::RC2_CBC_OID,
+        parameters: Some(params),
+    };
+    assert!(EncryptionScheme::try_from(rc2_cbc).is_err());
+}
+
+#[test]
+fn rc2_cbc_parameter_round_trip() {
+    let mut buf = [0u8; 16];
+
+    let der = hex!("300d02013a040883cd6d6269fc6560");
+    let params = Rc2CbcParameter::from_der(&der).unwrap();
+    assert_eq!(params.rc2_parameter_version, Some(58));
+    assert_eq!(params.encode_to_slice(&mut buf).unwrap(), der);
+
+    // Without a version, 32 effective key bits are used
+    let der = hex!("300a040883cd6d6269fc6560");
+    let params = Rc2CbcParameter::from_der(&der).unwrap();
+    assert_eq!(params.effective_key_bits(), Some(32));
+    assert_eq!(params.encode_to_slice(&mut buf).unwrap(), der);
+}
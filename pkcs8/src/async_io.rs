@@ -0,0 +1,117 @@
+//! Asynchronous file helpers for use with the [`tokio`] runtime.
+//!
+//! These are async equivalents of the `read_*_file` and `write_*_file`
+//! methods of [`DecodePrivateKey`] and [`EncodePrivateKey`]. Filesystem I/O
+//! is performed on tokio's blocking thread pool, so loading keys from an
+//! async context doesn't stall the runtime. Files are written with the same
+//! restrictive permissions as the synchronous methods.
+//!
+//! These functions must be called from within a tokio runtime.
+//!
+//! [`tokio`]: https://docs.rs/tokio
+
+use crate::{DecodePrivateKey, EncodePrivateKey, Result, SecretDocument};
+use std::{io, panic, path::Path};
+use tokio_rt::task;
+
+#[cfg(feature = "pem")]
+use crate::{LineEnding, PrivateKeyInfo};
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+/// Load PKCS#8 private key from an ASN.1 DER-encoded file on the local
+/// filesystem (binary format).
+pub async fn read_pkcs8_der_file<K: DecodePrivateKey>(path: impl AsRef<Path>) -> Result<K> {
+    let path = path.as_ref().to_path_buf();
+    let doc = blocking(move || SecretDocument::read_der_file(path)).await?;
+    K::from_pkcs8_der(doc.as_bytes())
+}
+
+/// Load PKCS#8 private key from a PEM-encoded file on the local filesystem.
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub async fn read_pkcs8_pem_file<K: DecodePrivateKey>(path: impl AsRef<Path>) -> Result<K> {
+    let path = path.as_ref().to_path_buf();
+    let (label, doc) = blocking(move || SecretDocument::read_pem_file(path)).await?;
+    PrivateKeyInfo::validate_pem_label(&label)?;
+    K::from_pkcs8_der(doc.as_bytes())
+}
+
+/// Write ASN.1 DER-encoded PKCS#8 private key to the given path
+///
+/// On Unix, the file is only readable by its owner. An existing file is
+/// overwritten.
+pub async fn write_pkcs8_der_file(
+    key: &impl EncodePrivateKey,
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    let doc = key.to_pkcs8_der()?;
+    let path = path.as_ref().to_path_buf();
+    blocking(move || doc.write_der_file(path)).await
+}
+
+/// Write ASN.1 DER-encoded PKCS#8 private key to a new file at the given
+/// path, returning an error if it already exists.
+///
+/// On Unix, the file is atomically created so it's only readable by its
+/// owner.
+pub async fn write_pkcs8_der_file_new(
+    key: &impl EncodePrivateKey,
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    let doc = key.to_pkcs8_der()?;
+    let path = path.as_ref().to_path_buf();
+    blocking(move || doc.write_der_file_new(path)).await
+}
+
+/// Write PEM-encoded PKCS#8 private key to the given path
+///
+/// On Unix, the file is only readable by its owner. An existing file is
+/// overwritten.
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub async fn write_pkcs8_pem_file(
+    key: &impl EncodePrivateKey,
+    path: impl AsRef<Path>,
+    line_ending: LineEnding,
+) -> Result<()> {
+    let doc = key.to_pkcs8_der()?;
+    let path = path.as_ref().to_path_buf();
+    blocking(move || doc.write_pem_file(path, PrivateKeyInfo::PEM_LABEL, line_ending)).await
+}
+
+/// Write PEM-encoded PKCS#8 private key to a new file at the given path,
+/// returning an error if it already exists.
+///
+/// On Unix, the file is atomically created so it's only readable by its
+/// owner.
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub async fn write_pkcs8_pem_file_new(
+    key: &impl EncodePrivateKey,
+    path: impl AsRef<Path>,
+    line_ending: LineEnding,
+) -> Result<()> {
+    let doc = key.to_pkcs8_der()?;
+    let path = path.as_ref().to_path_buf();
+    blocking(move || doc.write_pem_file_new(path, PrivateKeyInfo::PEM_LABEL, line_ending)).await
+}
+
+/// Run a blocking filesystem operation on tokio's blocking thread pool.
+///
+/// Panics in the operation are propagated to the caller. If the task is
+/// cancelled because the runtime is shutting down, an I/O error is returned.
+async fn blocking<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> der::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    match task::spawn_blocking(f).await {
+        Ok(result) => Ok(result?),
+        Err(err) => match err.try_into_panic() {
+            Ok(payload) => panic::resume_unwind(payload),
+            Err(_) => Err(der::Error::from(der::ErrorKind::Io(io::ErrorKind::Interrupted)).into()),
+        },
+    }
+}
@@ -0,0 +1,227 @@
+//! PKCS#8 private key attributes.
+
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Decode, DecodeValue, Decoder, Encode, EncodeValue, FixedTag, Header, Length, Reader, Sequence,
+    Tag, Tagged, Writer,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// PKCS#9 `friendlyName` attribute OID, as used by PKCS#12.
+pub const FRIENDLY_NAME_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.20");
+
+/// PKCS#9 `localKeyID` attribute OID, as used by PKCS#12.
+pub const LOCAL_KEY_ID_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.21");
+
+/// PKCS#8 `Attributes`.
+///
+/// Described in [RFC 5208 Section 5] as an X.501 `SET OF Attribute`.
+///
+/// The attributes are validated when decoded, and borrowed as the DER
+/// encoding of the `SET OF` contents, which is preserved as-is when
+/// re-encoding.
+///
+/// [RFC 5208 Section 5]: https://tools.ietf.org/html/rfc5208#section-5
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attributes<'a> {
+    /// DER encoded `Attribute`s.
+    der: &'a [u8],
+}
+
+impl<'a> Attributes<'a> {
+    /// Iterate over the attributes.
+    pub fn iter(&self) -> AttributesIter<'a> {
+        AttributesIter {
+            decoder: Decoder::new(self.der).ok(),
+        }
+    }
+
+    /// Get the first attribute with the given type, if present.
+    pub fn get(&self, oid: ObjectIdentifier) -> Option<Attribute<'a>> {
+        self.iter().find(|attr| attr.oid == oid)
+    }
+
+    /// Get the PKCS#9 `friendlyName` attribute, if present.
+    ///
+    /// Its value is a `BMPString`, which is decoded from UTF-16.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn friendly_name(&self) -> der::Result<Option<String>> {
+        self.get(FRIENDLY_NAME_OID)
+            .map(|attr| {
+                let value = attr.value()?;
+                value.tag().assert_eq(Tag::BmpString)?;
+
+                let bytes = value.value();
+                if bytes.len() % 2 != 0 {
+                    return Err(Tag::BmpString.value_error());
+                }
+
+                let units = bytes
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+
+                char::decode_utf16(units)
+                    .collect::<Result<String, _>>()
+                    .map_err(|_| Tag::BmpString.value_error())
+            })
+            .transpose()
+    }
+
+    /// Get the PKCS#9 `localKeyID` attribute, if present.
+    pub fn local_key_id(&self) -> der::Result<Option<&'a [u8]>> {
+        self.get(LOCAL_KEY_ID_OID)
+            .map(|attr| Ok(attr.value()?.octet_string()?.as_bytes()))
+            .transpose()
+    }
+}
+
+impl<'a> DecodeValue<'a> for Attributes<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        let der = decoder.read_slice(header.length)?;
+
+        let mut attrs = Decoder::new(der)?;
+        while !attrs.is_finished() {
+            attrs.decode::<Attribute<'_>>()?;
+        }
+
+        Ok(Self { der })
+    }
+}
+
+impl EncodeValue for Attributes<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.der.len().try_into()
+    }
+
+    fn encode_value(&self, writer: &mut dyn Writer) -> der::Result<()> {
+        writer.write(self.der)
+    }
+}
+
+impl FixedTag for Attributes<'_> {
+    const TAG: Tag = Tag::Set;
+}
+
+impl<'a> TryFrom<&'a [u8]> for Attributes<'a> {
+    type Error = der::Error;
+
+    /// Decode the DER encoding of a `SET OF Attribute`.
+    fn try_from(bytes: &'a [u8]) -> der::Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> IntoIterator for Attributes<'a> {
+    type Item = Attribute<'a>;
+    type IntoIter = AttributesIter<'a>;
+
+    fn into_iter(self) -> AttributesIter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over [`Attributes`].
+#[derive(Clone, Debug)]
+pub struct AttributesIter<'a> {
+    /// Decoder for the remaining attributes.
+    decoder: Option<Decoder<'a>>,
+}
+
+impl<'a> Iterator for AttributesIter<'a> {
+    type Item = Attribute<'a>;
+
+    fn next(&mut self) -> Option<Attribute<'a>> {
+        let decoder = self.decoder.as_mut()?;
+
+        if decoder.is_finished() {
+            return None;
+        }
+
+        // Attributes are validated when decoded, so this can't fail
+        decoder.decode().ok()
+    }
+}
+
+/// X.501 `Attribute`.
+///
+/// ```text
+/// Attribute ::= SEQUENCE {
+///     type             AttributeType,
+///     values    SET OF AttributeValue }
+///
+/// AttributeType ::= OBJECT IDENTIFIER
+///
+/// AttributeValue ::= ANY
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attribute<'a> {
+    /// Attribute type.
+    pub oid: ObjectIdentifier,
+
+    /// DER encoded attribute values.
+    values: &'a [u8],
+}
+
+impl<'a> Attribute<'a> {
+    /// Iterate over the values of this attribute.
+    pub fn values(&self) -> impl Iterator<Item = Any<'a>> {
+        let mut decoder = Decoder::new(self.values).ok();
+
+        core::iter::from_fn(move || {
+            let decoder = decoder.as_mut()?;
+
+            if decoder.is_finished() {
+                return None;
+            }
+
+            // Values are validated when decoded, so this can't fail
+            decoder.any().ok()
+        })
+    }
+
+    /// Get the value of a single-valued attribute.
+    ///
+    /// Returns an error if the attribute doesn't have exactly one value.
+    pub fn value(&self) -> der::Result<Any<'a>> {
+        let mut values = self.values();
+
+        match (values.next(), values.next()) {
+            (Some(value), None) => Ok(value),
+            _ => Err(Tag::Set.value_error()),
+        }
+    }
+}
+
+impl<'a> Decode<'a> for Attribute<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let oid = decoder.oid()?;
+            let values = decoder.any()?;
+            values.tag().assert_eq(Tag::Set)?;
+
+            let mut value_decoder = Decoder::new(values.value())?;
+            while !value_decoder.is_finished() {
+                value_decoder.any()?;
+            }
+
+            Ok(Self {
+                oid,
+                values: values.value(),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Attribute<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.oid, &Any::new(Tag::Set, self.values)?])
+    }
+}
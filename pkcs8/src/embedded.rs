@@ -0,0 +1,184 @@
+//! Algorithm-specific private keys embedded in [`PrivateKeyInfo`].
+//!
+//! The `privateKey` field of a [`PrivateKeyInfo`] contains the private key in
+//! a format specific to its algorithm. This module provides minimal decoders
+//! and encoders for the formats of the most common key types, which only
+//! borrow their contents.
+//!
+//! For complete support of these formats, including validation of the key,
+//! use the [`pkcs1`] or [`sec1`] crates.
+//!
+//! [`PrivateKeyInfo`]: crate::PrivateKeyInfo
+//! [`pkcs1`]: https://docs.rs/pkcs1
+//! [`sec1`]: https://docs.rs/sec1
+
+use core::fmt;
+use der::{
+    asn1::{BitString, ContextSpecific, OctetString, UIntBytes},
+    Decode, Decoder, Encode, Reader, Sequence, TagMode, TagNumber,
+};
+
+/// `ECPrivateKey` version.
+const EC_PRIVATE_KEY_VERSION: u8 = 1;
+
+/// Context-specific tag number for the public key of an `ECPrivateKey`.
+const EC_PUBLIC_KEY_TAG: TagNumber = TagNumber::N1;
+
+/// Version of a two-prime `RSAPrivateKey`.
+const RSA_PRIVATE_KEY_VERSION: u8 = 0;
+
+/// SEC1 `ECPrivateKey`, as embedded in PKCS#8.
+///
+/// The `parameters` field is skipped when decoding and omitted when
+/// encoding, as the curve is identified by the [`AlgorithmIdentifier`] of the
+/// [`PrivateKeyInfo`].
+///
+/// [`AlgorithmIdentifier`]: crate::AlgorithmIdentifier
+/// [`PrivateKeyInfo`]: crate::PrivateKeyInfo
+#[derive(Clone)]
+pub struct EcPrivateKey<'a> {
+    /// Private scalar as a big endian byte string.
+    pub private_key: &'a [u8],
+
+    /// SEC1-encoded public key point.
+    pub public_key: Option<&'a [u8]>,
+}
+
+impl<'a> Decode<'a> for EcPrivateKey<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            if decoder.uint8()? != EC_PRIVATE_KEY_VERSION {
+                return Err(der::Tag::Integer.value_error());
+            }
+
+            let private_key = decoder.octet_string()?.as_bytes();
+            let public_key = decoder
+                .context_specific::<BitString<'_>>(EC_PUBLIC_KEY_TAG, TagMode::Explicit)?
+                .map(|bs| {
+                    bs.as_bytes()
+                        .ok_or_else(|| der::Tag::BitString.value_error())
+                })
+                .transpose()?;
+
+            // Skip the `parameters`, which are redundant with the algorithm
+            while !decoder.is_finished() {
+                decoder.any()?;
+            }
+
+            Ok(Self {
+                private_key,
+                public_key,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for EcPrivateKey<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &EC_PRIVATE_KEY_VERSION,
+            &OctetString::new(self.private_key)?,
+            &self
+                .public_key
+                .map(|pk| {
+                    BitString::from_bytes(pk).map(|value| ContextSpecific {
+                        tag_number: EC_PUBLIC_KEY_TAG,
+                        tag_mode: TagMode::Explicit,
+                        value,
+                    })
+                })
+                .transpose()?,
+        ])
+    }
+}
+
+impl fmt::Debug for EcPrivateKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcPrivateKey")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// PKCS#1 `RSAPrivateKey` with two primes, as embedded in PKCS#8.
+///
+/// Multi-prime keys are rejected when decoding.
+#[derive(Clone)]
+pub struct RsaPrivateKey<'a> {
+    /// RSA modulus `n`.
+    pub n: UIntBytes<'a>,
+
+    /// Public exponent `e`.
+    pub e: UIntBytes<'a>,
+
+    /// Private exponent `d`.
+    pub d: UIntBytes<'a>,
+
+    /// First prime factor `p` of `n`.
+    pub p: UIntBytes<'a>,
+
+    /// Second prime factor `q` of `n`.
+    pub q: UIntBytes<'a>,
+
+    /// First exponent `d mod (p-1)`.
+    pub dp: UIntBytes<'a>,
+
+    /// Second exponent `d mod (q-1)`.
+    pub dq: UIntBytes<'a>,
+
+    /// CRT coefficient `(inverse of q) mod p`.
+    pub qi: UIntBytes<'a>,
+}
+
+impl<'a> Decode<'a> for RsaPrivateKey<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            // Multi-prime keys have version 1
+            if decoder.uint8()? != RSA_PRIVATE_KEY_VERSION {
+                return Err(der::Tag::Integer.value_error());
+            }
+
+            Ok(Self {
+                n: decoder.decode()?,
+                e: decoder.decode()?,
+                d: decoder.decode()?,
+                p: decoder.decode()?,
+                q: decoder.decode()?,
+                dp: decoder.decode()?,
+                dq: decoder.decode()?,
+                qi: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaPrivateKey<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &RSA_PRIVATE_KEY_VERSION,
+            &self.n,
+            &self.e,
+            &self.d,
+            &self.p,
+            &self.q,
+            &self.dp,
+            &self.dq,
+            &self.qi,
+        ])
+    }
+}
+
+impl fmt::Debug for RsaPrivateKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaPrivateKey")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish_non_exhaustive()
+    }
+}
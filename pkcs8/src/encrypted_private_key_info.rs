@@ -2,7 +2,10 @@
 
 use crate::{Error, Result};
 use core::fmt;
-use der::{asn1::OctetString, Decode, Decoder, Encode, Sequence};
+use der::{
+    asn1::{OctetString, SequenceRef},
+    Decode, DecodeValue, Decoder, Encode, Header, Sequence,
+};
 use pkcs5::EncryptionScheme;
 
 #[cfg(feature = "alloc")]
@@ -64,18 +67,12 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
     #[cfg(feature = "encryption")]
     #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
     pub(crate) fn encrypt(
-        mut rng: impl CryptoRng + RngCore,
+        rng: impl CryptoRng + RngCore,
         password: impl AsRef<[u8]>,
         doc: &[u8],
     ) -> Result<SecretDocument> {
-        let mut salt = [0u8; 16];
-        rng.fill_bytes(&mut salt);
-
-        let mut iv = [0u8; 16];
-        rng.fill_bytes(&mut iv);
-
-        let pbes2_params = pbes2::Parameters::scrypt_aes256cbc(Default::default(), &salt, &iv)?;
-        EncryptedPrivateKeyInfo::encrypt_with(pbes2_params, password, doc)
+        let builder = pbes2::ParametersBuilder::random(rng);
+        EncryptedPrivateKeyInfo::encrypt_with(builder.build()?, password, doc)
     }
 
     /// Encrypt this private key using a symmetric encryption key derived
@@ -97,9 +94,12 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
     }
 }
 
-impl<'a> Decode<'a> for EncryptedPrivateKeyInfo<'a> {
-    fn decode(decoder: &mut Decoder<'a>) -> der::Result<EncryptedPrivateKeyInfo<'a>> {
-        decoder.sequence(|decoder| {
+impl<'a> DecodeValue<'a> for EncryptedPrivateKeyInfo<'a> {
+    fn decode_value(
+        decoder: &mut Decoder<'a>,
+        header: Header,
+    ) -> der::Result<EncryptedPrivateKeyInfo<'a>> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
             Ok(Self {
                 encryption_algorithm: decoder.decode()?,
                 encrypted_data: decoder.octet_string()?.as_bytes(),
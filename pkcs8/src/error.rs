@@ -19,6 +19,11 @@ pub enum Error {
     #[cfg(feature = "pkcs5")]
     EncryptedPrivateKey(pkcs5::Error),
 
+    /// JSON Web Key errors, e.g. malformed JSON, an unsupported `kty` or
+    /// `crv`, or missing key members.
+    #[cfg(feature = "jwk")]
+    Jwk,
+
     /// Malformed cryptographic key contained in a PKCS#8 document.
     ///
     /// This is intended for relaying errors related to the raw data contained
@@ -40,6 +45,8 @@ impl fmt::Display for Error {
             Error::Asn1(err) => write!(f, "PKCS#8 ASN.1 error: {}", err),
             #[cfg(feature = "pkcs5")]
             Error::EncryptedPrivateKey(err) => write!(f, "{}", err),
+            #[cfg(feature = "jwk")]
+            Error::Jwk => f.write_str("PKCS#8 JWK error"),
             Error::KeyMalformed => f.write_str("PKCS#8 cryptographic key data malformed"),
             Error::ParametersMalformed => f.write_str("PKCS#8 algorithm parameters malformed"),
             Error::PublicKey(err) => write!(f, "public key error: {}", err),
@@ -0,0 +1,739 @@
+//! JSON Web Key (JWK) support.
+//!
+//! Converts PKCS#8 private keys and X.509 `SubjectPublicKeyInfo` public keys
+//! to and from JWKs ([RFC 7517]) for the following key types:
+//!
+//! - `RSA` ([RFC 7518 Section 6.3]): RSA keys, including multi-prime keys.
+//! - `EC` ([RFC 7518 Section 6.2]): the `P-256`, `P-384` and `P-521` curves.
+//! - `OKP` ([RFC 8037]): the `Ed25519`, `Ed448`, `X25519` and `X448` curves.
+//!
+//! JWKs of `EC` and `OKP` keys always contain the public key, which can't
+//! be computed without elliptic curve arithmetic. Private keys of these types
+//! can only be encoded when they include their public key, i.e. embedded in
+//! the SEC1 `ECPrivateKey` or in a PKCS#8 v2 `OneAsymmetricKey`.
+//!
+//! [`Jwk`] implements serde's `Serialize` and `Deserialize`, so JWKs can be
+//! read and written as JSON using any serde format, such as `serde_json`:
+//!
+//! ```
+//! # fn main() -> pkcs8::Result<()> {
+//! use pkcs8::{jwk::{self, Jwk}, SubjectPublicKeyInfo};
+//!
+//! let json = r#"{"kty":"OKP","crv":"Ed25519","x":"TSkWfz8ZEqb3rfopOgUaFcBexnuPFyZ7HFVQ3OhTvQ0"}"#;
+//! let jwk: Jwk = serde_json::from_str(json).expect("malformed JSON");
+//!
+//! // DER-encoded `SubjectPublicKeyInfo`
+//! let doc = jwk::decode_public_key(&jwk)?;
+//! let spki: SubjectPublicKeyInfo<'_> = doc.decode_msg()?;
+//!
+//! let jwk = jwk::encode_public_key(&spki)?;
+//! assert_eq!(serde_json::to_string(&jwk).expect("serialization failed"), json);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The members of RSA keys are converted to and from PKCS#1 `RSAPrivateKey`
+//! and `RSAPublicKey` structures by [`decode_rsa_private_key`],
+//! [`decode_rsa_public_key`], [`encode_rsa_private_key`] and
+//! [`encode_rsa_public_key`], which are shared with the `pkcs1` crate.
+//! These support multi-prime keys, whose additional primes are carried in
+//! the `oth` member.
+//!
+//! [RFC 7517]: https://datatracker.ietf.org/doc/html/rfc7517
+//! [RFC 7518 Section 6.2]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.2
+//! [RFC 7518 Section 6.3]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3
+//! [RFC 8037]: https://datatracker.ietf.org/doc/html/rfc8037
+
+use crate::{
+    embedded::EcPrivateKey, AlgorithmIdentifier, Error, PrivateKeyInfo, Result,
+    SubjectPublicKeyInfo,
+};
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use core::fmt;
+use der::{
+    asn1::{ObjectIdentifier, OctetString, UIntBytes},
+    zeroize::{Zeroize, Zeroizing},
+    Decode, Decoder, Document, Encode, SecretDocument, Sequence,
+};
+use serde::{Deserialize, Serialize};
+
+/// `rsaEncryption` Object Identifier (OID).
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `id-ecPublicKey` Object Identifier (OID).
+const EC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// Value of the `kty` member for RSA keys.
+const KTY_RSA: &str = "RSA";
+
+/// Value of the `kty` member for elliptic curve keys.
+const KTY_EC: &str = "EC";
+
+/// Value of the `kty` member for octet key pairs.
+const KTY_OKP: &str = "OKP";
+
+/// Version of a two-prime `RSAPrivateKey`.
+const RSA_TWO_PRIME_VERSION: u8 = 0;
+
+/// Version of a multi-prime `RSAPrivateKey`.
+const RSA_MULTI_PRIME_VERSION: u8 = 1;
+
+/// Curve supported by `EC` or `OKP` keys.
+struct Curve {
+    /// Value of the `crv` member.
+    crv: &'static str,
+
+    /// Object Identifier of the curve for `EC` keys, or of the algorithm for
+    /// `OKP` keys.
+    oid: ObjectIdentifier,
+
+    /// Length of the private key and of each public key coordinate.
+    len: usize,
+}
+
+/// Curves of `EC` keys.
+const EC_CURVES: &[Curve] = &[
+    Curve {
+        crv: "P-256",
+        oid: ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"),
+        len: 32,
+    },
+    Curve {
+        crv: "P-384",
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.34"),
+        len: 48,
+    },
+    Curve {
+        crv: "P-521",
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.35"),
+        len: 66,
+    },
+];
+
+/// Curves of `OKP` keys.
+const OKP_CURVES: &[Curve] = &[
+    Curve {
+        crv: "Ed25519",
+        oid: ObjectIdentifier::new_unwrap("1.3.101.112"),
+        len: 32,
+    },
+    Curve {
+        crv: "Ed448",
+        oid: ObjectIdentifier::new_unwrap("1.3.101.113"),
+        len: 57,
+    },
+    Curve {
+        crv: "X25519",
+        oid: ObjectIdentifier::new_unwrap("1.3.101.110"),
+        len: 32,
+    },
+    Curve {
+        crv: "X448",
+        oid: ObjectIdentifier::new_unwrap("1.3.101.111"),
+        len: 56,
+    },
+];
+
+/// JSON Web Key.
+///
+/// Holds the members describing the key material, of which the private key
+/// members are zeroized on drop. Other members (e.g. `kid`, `alg`, `use`)
+/// are ignored when deserializing.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Jwk {
+    kty: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crv: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    n: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    e: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    x: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    y: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    d: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    p: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dq: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    qi: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    oth: Option<Vec<OtherPrimeJwk>>,
+}
+
+impl Jwk {
+    /// Create a JWK of the given key type without any other members.
+    fn new(kty: &str) -> Self {
+        Self {
+            kty: kty.into(),
+            crv: None,
+            n: None,
+            e: None,
+            x: None,
+            y: None,
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+            oth: None,
+        }
+    }
+
+    /// Get the key type (`kty` member).
+    pub fn kty(&self) -> &str {
+        &self.kty
+    }
+
+    /// Get the curve of an `EC` or `OKP` key (`crv` member).
+    pub fn crv(&self) -> Option<&str> {
+        self.crv.as_deref()
+    }
+
+    /// Does this JWK contain a private key, i.e. a `d` member?
+    pub fn is_private(&self) -> bool {
+        self.d.is_some()
+    }
+}
+
+impl fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        self.d.zeroize();
+        self.p.zeroize();
+        self.q.zeroize();
+        self.dp.zeroize();
+        self.dq.zeroize();
+        self.qi.zeroize();
+    }
+}
+
+/// Additional prime of a multi-prime RSA JWK (element of the `oth` member).
+#[derive(Clone, Deserialize, Serialize)]
+struct OtherPrimeJwk {
+    r: String,
+    d: String,
+    t: String,
+}
+
+impl Drop for OtherPrimeJwk {
+    fn drop(&mut self) {
+        self.r.zeroize();
+        self.d.zeroize();
+        self.t.zeroize();
+    }
+}
+
+/// PKCS#1 `RSAPrivateKey`, including the additional primes of multi-prime
+/// keys.
+struct RsaPrivateKey<'a> {
+    n: UIntBytes<'a>,
+    e: UIntBytes<'a>,
+    d: UIntBytes<'a>,
+    p: UIntBytes<'a>,
+    q: UIntBytes<'a>,
+    dp: UIntBytes<'a>,
+    dq: UIntBytes<'a>,
+    qi: UIntBytes<'a>,
+    other_primes: Option<Vec<OtherPrimeInfo<'a>>>,
+}
+
+impl<'a> Decode<'a> for RsaPrivateKey<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let version = decoder.uint8()?;
+
+            let key = Self {
+                n: decoder.decode()?,
+                e: decoder.decode()?,
+                d: decoder.decode()?,
+                p: decoder.decode()?,
+                q: decoder.decode()?,
+                dp: decoder.decode()?,
+                dq: decoder.decode()?,
+                qi: decoder.decode()?,
+                other_primes: decoder.decode()?,
+            };
+
+            // Only multi-prime keys have other primes, and they must have
+            // at least one
+            let multi_prime = key.other_primes.as_ref().map(|primes| !primes.is_empty());
+
+            match (version, multi_prime) {
+                (RSA_TWO_PRIME_VERSION, None) | (RSA_MULTI_PRIME_VERSION, Some(true)) => Ok(key),
+                _ => Err(der::Tag::Integer.value_error()),
+            }
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaPrivateKey<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        let version = if self.other_primes.is_some() {
+            RSA_MULTI_PRIME_VERSION
+        } else {
+            RSA_TWO_PRIME_VERSION
+        };
+
+        f(&[
+            &version,
+            &self.n,
+            &self.e,
+            &self.d,
+            &self.p,
+            &self.q,
+            &self.dp,
+            &self.dq,
+            &self.qi,
+            &self.other_primes,
+        ])
+    }
+}
+
+/// PKCS#1 `OtherPrimeInfo`.
+struct OtherPrimeInfo<'a> {
+    r: UIntBytes<'a>,
+    d: UIntBytes<'a>,
+    t: UIntBytes<'a>,
+}
+
+impl<'a> Decode<'a> for OtherPrimeInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                r: decoder.decode()?,
+                d: decoder.decode()?,
+                t: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for OtherPrimeInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.r, &self.d, &self.t])
+    }
+}
+
+/// PKCS#1 `RSAPublicKey`.
+struct RsaPublicKey<'a> {
+    n: UIntBytes<'a>,
+    e: UIntBytes<'a>,
+}
+
+impl<'a> Decode<'a> for RsaPublicKey<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                n: decoder.decode()?,
+                e: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaPublicKey<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.n, &self.e])
+    }
+}
+
+/// Convert a private key JWK to the DER encoding of the equivalent PKCS#8
+/// [`PrivateKeyInfo`].
+///
+/// The JWK must contain all of the private key members of its key type,
+/// e.g. the CRT parameters of RSA keys.
+pub fn decode_private_key(jwk: &Jwk) -> Result<SecretDocument> {
+    match jwk.kty.as_str() {
+        KTY_RSA => {
+            let private_key = decode_rsa_private_key(jwk)?;
+            encode_private_key_info(rsa_algorithm(), private_key.as_bytes(), None)
+        }
+        KTY_EC => {
+            let curve = find_curve(EC_CURVES, &jwk.crv)?;
+            let public_key = decode_ec_point(jwk, curve)?;
+            let d = decode_key(&jwk.d, curve)?;
+
+            let private_key = Zeroizing::new(
+                EcPrivateKey {
+                    private_key: &d,
+                    public_key: Some(&public_key),
+                }
+                .to_vec()?,
+            );
+
+            encode_private_key_info(ec_algorithm(curve), &private_key, None)
+        }
+        KTY_OKP => {
+            let curve = find_curve(OKP_CURVES, &jwk.crv)?;
+            let public_key = decode_key(&jwk.x, curve)?;
+            let d = decode_key(&jwk.d, curve)?;
+            let private_key = Zeroizing::new(OctetString::new(d.as_slice())?.to_vec()?);
+
+            encode_private_key_info(okp_algorithm(curve), &private_key, Some(&public_key))
+        }
+        _ => Err(Error::Jwk),
+    }
+}
+
+/// Convert a public key JWK to the DER encoding of the equivalent
+/// [`SubjectPublicKeyInfo`].
+///
+/// Private key JWKs are also accepted, in which case only the public key
+/// members are used.
+pub fn decode_public_key(jwk: &Jwk) -> Result<Document> {
+    let (algorithm, subject_public_key);
+    match jwk.kty.as_str() {
+        KTY_RSA => {
+            algorithm = rsa_algorithm();
+            subject_public_key = decode_rsa_public_key(jwk)?.into_vec();
+        }
+        KTY_EC => {
+            let curve = find_curve(EC_CURVES, &jwk.crv)?;
+            algorithm = ec_algorithm(curve);
+            subject_public_key = decode_ec_point(jwk, curve)?;
+        }
+        KTY_OKP => {
+            let curve = find_curve(OKP_CURVES, &jwk.crv)?;
+            algorithm = okp_algorithm(curve);
+            subject_public_key = decode_key(&jwk.x, curve)?.as_slice().to_vec();
+        }
+        _ => return Err(Error::Jwk),
+    }
+
+    Ok(Document::encode_msg(&SubjectPublicKeyInfo {
+        algorithm,
+        subject_public_key: &subject_public_key,
+    })?)
+}
+
+/// Convert a PKCS#8 [`PrivateKeyInfo`] to a JWK.
+pub fn encode_private_key(private_key: &PrivateKeyInfo<'_>) -> Result<Jwk> {
+    let algorithm = private_key.algorithm;
+
+    match algorithm.oid {
+        RSA_OID => encode_rsa_private_key(private_key.private_key),
+        EC_OID => {
+            let curve = ec_curve(&algorithm)?;
+            let key = EcPrivateKey::from_der(private_key.private_key)?;
+            let public_key = key.public_key.or(private_key.public_key);
+
+            if key.private_key.len() != curve.len {
+                return Err(Error::KeyMalformed);
+            }
+
+            let mut jwk = encode_ec_point(curve, public_key.ok_or(Error::Jwk)?)?;
+            jwk.d = Some(Base64UrlUnpadded::encode_string(key.private_key));
+            Ok(jwk)
+        }
+        oid => {
+            let curve = okp_curve(oid)?;
+            let d = OctetString::from_der(private_key.private_key)?;
+
+            let mut jwk = encode_okp_key(curve, private_key.public_key.ok_or(Error::Jwk)?)?;
+            jwk.d = Some(Base64UrlUnpadded::encode_string(d.as_bytes()));
+            Ok(jwk)
+        }
+    }
+}
+
+/// Convert an X.509 [`SubjectPublicKeyInfo`] to a JWK.
+pub fn encode_public_key(public_key: &SubjectPublicKeyInfo<'_>) -> Result<Jwk> {
+    let algorithm = public_key.algorithm;
+
+    match algorithm.oid {
+        RSA_OID => encode_rsa_public_key(public_key.subject_public_key),
+        EC_OID => encode_ec_point(ec_curve(&algorithm)?, public_key.subject_public_key),
+        oid => encode_okp_key(okp_curve(oid)?, public_key.subject_public_key),
+    }
+}
+
+/// Convert an RSA private key JWK to the DER encoding of the equivalent
+/// PKCS#1 `RSAPrivateKey`.
+///
+/// The JWK must contain all of the CRT parameters (`p`, `q`, `dp`, `dq` and
+/// `qi`) as well as `n`, `e` and `d`, and may contain additional primes in
+/// the `oth` member.
+pub fn decode_rsa_private_key(jwk: &Jwk) -> Result<SecretDocument> {
+    check_kty(jwk, KTY_RSA)?;
+
+    let n = decode_member(&jwk.n)?;
+    let e = decode_member(&jwk.e)?;
+    let d = decode_member(&jwk.d)?;
+    let p = decode_member(&jwk.p)?;
+    let q = decode_member(&jwk.q)?;
+    let dp = decode_member(&jwk.dp)?;
+    let dq = decode_member(&jwk.dq)?;
+    let qi = decode_member(&jwk.qi)?;
+
+    let other_primes = match &jwk.oth {
+        Some(oth) if oth.is_empty() => return Err(Error::Jwk),
+        Some(oth) => Some(
+            oth.iter()
+                .map(|prime| {
+                    Ok([
+                        decode_uint(&prime.r)?,
+                        decode_uint(&prime.d)?,
+                        decode_uint(&prime.t)?,
+                    ])
+                })
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+
+    let other_prime_infos = match &other_primes {
+        Some(other_primes) => Some(
+            other_primes
+                .iter()
+                .map(|[r, d, t]| {
+                    Ok(OtherPrimeInfo {
+                        r: UIntBytes::new(r)?,
+                        d: UIntBytes::new(d)?,
+                        t: UIntBytes::new(t)?,
+                    })
+                })
+                .collect::<der::Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+
+    Ok(SecretDocument::encode_msg(&RsaPrivateKey {
+        n: UIntBytes::new(&n)?,
+        e: UIntBytes::new(&e)?,
+        d: UIntBytes::new(&d)?,
+        p: UIntBytes::new(&p)?,
+        q: UIntBytes::new(&q)?,
+        dp: UIntBytes::new(&dp)?,
+        dq: UIntBytes::new(&dq)?,
+        qi: UIntBytes::new(&qi)?,
+        other_primes: other_prime_infos,
+    })?)
+}
+
+/// Convert an RSA public key JWK to the DER encoding of the equivalent
+/// PKCS#1 `RSAPublicKey`.
+///
+/// Private key JWKs are also accepted, in which case only the public key
+/// members are used.
+pub fn decode_rsa_public_key(jwk: &Jwk) -> Result<Document> {
+    check_kty(jwk, KTY_RSA)?;
+
+    let n = decode_member(&jwk.n)?;
+    let e = decode_member(&jwk.e)?;
+
+    Ok(Document::encode_msg(&RsaPublicKey {
+        n: UIntBytes::new(&n)?,
+        e: UIntBytes::new(&e)?,
+    })?)
+}
+
+/// Convert a DER-encoded PKCS#1 `RSAPrivateKey` to a JWK.
+pub fn encode_rsa_private_key(der: &[u8]) -> Result<Jwk> {
+    let key = RsaPrivateKey::from_der(der)?;
+
+    let mut jwk = Jwk::new(KTY_RSA);
+    jwk.n = Some(encode_uint(key.n));
+    jwk.e = Some(encode_uint(key.e));
+    jwk.d = Some(encode_uint(key.d));
+    jwk.p = Some(encode_uint(key.p));
+    jwk.q = Some(encode_uint(key.q));
+    jwk.dp = Some(encode_uint(key.dp));
+    jwk.dq = Some(encode_uint(key.dq));
+    jwk.qi = Some(encode_uint(key.qi));
+    jwk.oth = key.other_primes.map(|primes| {
+        primes
+            .iter()
+            .map(|prime| OtherPrimeJwk {
+                r: encode_uint(prime.r),
+                d: encode_uint(prime.d),
+                t: encode_uint(prime.t),
+            })
+            .collect()
+    });
+
+    Ok(jwk)
+}
+
+/// Convert a DER-encoded PKCS#1 `RSAPublicKey` to a JWK.
+pub fn encode_rsa_public_key(der: &[u8]) -> Result<Jwk> {
+    let key = RsaPublicKey::from_der(der)?;
+
+    let mut jwk = Jwk::new(KTY_RSA);
+    jwk.n = Some(encode_uint(key.n));
+    jwk.e = Some(encode_uint(key.e));
+    Ok(jwk)
+}
+
+/// Ensure a JWK has the given key type.
+fn check_kty(jwk: &Jwk, kty: &str) -> Result<()> {
+    if jwk.kty == kty {
+        Ok(())
+    } else {
+        Err(Error::Jwk)
+    }
+}
+
+/// Encode a [`PrivateKeyInfo`] with the given algorithm and private key,
+/// and optionally public key.
+fn encode_private_key_info(
+    algorithm: AlgorithmIdentifier<'_>,
+    private_key: &[u8],
+    public_key: Option<&[u8]>,
+) -> Result<SecretDocument> {
+    let mut private_key_info = PrivateKeyInfo::new(algorithm, private_key);
+    private_key_info.public_key = public_key;
+    Ok(SecretDocument::encode_msg(&private_key_info)?)
+}
+
+/// [`AlgorithmIdentifier`] of RSA keys.
+fn rsa_algorithm() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: RSA_OID,
+        parameters: Some(der::asn1::Any::NULL),
+    }
+}
+
+/// [`AlgorithmIdentifier`] of elliptic curve keys on the given curve.
+fn ec_algorithm(curve: &'static Curve) -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: EC_OID,
+        parameters: Some((&curve.oid).into()),
+    }
+}
+
+/// [`AlgorithmIdentifier`] of octet key pairs on the given curve.
+fn okp_algorithm(curve: &Curve) -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: curve.oid,
+        parameters: None,
+    }
+}
+
+/// Find the curve of an `EC` or `OKP` JWK by its `crv` member.
+fn find_curve(curves: &'static [Curve], crv: &Option<String>) -> Result<&'static Curve> {
+    let crv = crv.as_deref().ok_or(Error::Jwk)?;
+    curves
+        .iter()
+        .find(|curve| curve.crv == crv)
+        .ok_or(Error::Jwk)
+}
+
+/// Find the curve of an elliptic curve key by its algorithm parameters.
+fn ec_curve(algorithm: &AlgorithmIdentifier<'_>) -> Result<&'static Curve> {
+    let oid = algorithm.parameters_oid()?;
+    EC_CURVES
+        .iter()
+        .find(|curve| curve.oid == oid)
+        .ok_or(Error::Jwk)
+}
+
+/// Find the curve of an octet key pair by its algorithm.
+fn okp_curve(oid: ObjectIdentifier) -> Result<&'static Curve> {
+    OKP_CURVES
+        .iter()
+        .find(|curve| curve.oid == oid)
+        .ok_or(Error::Jwk)
+}
+
+/// Encode the public members of an `EC` JWK from an uncompressed point.
+fn encode_ec_point(curve: &Curve, point: &[u8]) -> Result<Jwk> {
+    match point.split_first() {
+        Some((0x04, coordinates)) if coordinates.len() == curve.len * 2 => {
+            let (x, y) = coordinates.split_at(curve.len);
+
+            let mut jwk = Jwk::new(KTY_EC);
+            jwk.crv = Some(curve.crv.into());
+            jwk.x = Some(Base64UrlUnpadded::encode_string(x));
+            jwk.y = Some(Base64UrlUnpadded::encode_string(y));
+            Ok(jwk)
+        }
+        // Compressed points can't be decompressed without curve arithmetic
+        _ => Err(Error::Jwk),
+    }
+}
+
+/// Decode the public members of an `EC` JWK into an uncompressed point.
+fn decode_ec_point(jwk: &Jwk, curve: &Curve) -> Result<Vec<u8>> {
+    let x = decode_key(&jwk.x, curve)?;
+    let y = decode_key(&jwk.y, curve)?;
+
+    let mut point = Vec::with_capacity(1 + curve.len * 2);
+    point.push(0x04);
+    point.extend_from_slice(&x);
+    point.extend_from_slice(&y);
+    Ok(point)
+}
+
+/// Encode the public members of an `OKP` JWK.
+fn encode_okp_key(curve: &Curve, public_key: &[u8]) -> Result<Jwk> {
+    if public_key.len() != curve.len {
+        return Err(Error::KeyMalformed);
+    }
+
+    let mut jwk = Jwk::new(KTY_OKP);
+    jwk.crv = Some(curve.crv.into());
+    jwk.x = Some(Base64UrlUnpadded::encode_string(public_key));
+    Ok(jwk)
+}
+
+/// Decode a member containing a key or coordinate of the curve's length.
+fn decode_key(member: &Option<String>, curve: &Curve) -> Result<Zeroizing<Vec<u8>>> {
+    let bytes = decode_member(member)?;
+
+    if bytes.len() != curve.len {
+        return Err(Error::Jwk);
+    }
+
+    Ok(bytes)
+}
+
+/// Decode a required Base64url-encoded member.
+fn decode_member(member: &Option<String>) -> Result<Zeroizing<Vec<u8>>> {
+    decode_uint(member.as_deref().ok_or(Error::Jwk)?)
+}
+
+/// Decode a non-empty Base64url-encoded value, e.g. a "Base64urlUInt".
+fn decode_uint(s: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let bytes = Zeroizing::new(Base64UrlUnpadded::decode_vec(s).map_err(|_| Error::Jwk)?);
+
+    if bytes.is_empty() {
+        return Err(Error::Jwk);
+    }
+
+    Ok(bytes)
+}
+
+/// Encode a "Base64urlUInt" value.
+///
+/// [`UIntBytes`] strips leading zeroes except from zero itself, which is
+/// encoded as a single zero octet as required by RFC 7518.
+fn encode_uint(uint: UIntBytes<'_>) -> String {
+    Base64UrlUnpadded::encode_string(uint.as_bytes())
+}
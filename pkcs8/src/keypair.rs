@@ -0,0 +1,262 @@
+//! Raw keypair bytes for algorithms with fixed-size keys.
+//!
+//! [`KeypairBytes`] extracts the private key, along with the public key if
+//! present, from PKCS#8 v1 and v2 documents for any [`KeypairAlgorithm`].
+//! Signature and key exchange crates can use it to construct keypairs
+//! without parsing the algorithm-specific key encodings themselves.
+//!
+//! The following algorithms are supported out of the box:
+//!
+//! - [`Ed25519`], [`Ed448`], [`X25519`] and [`X448`] ([RFC 8410])
+//! - [`NistP256`], [`NistP384`] and [`NistP521`] ([RFC 5915])
+//!
+//! [RFC 5915]: https://datatracker.ietf.org/doc/html/rfc5915
+//! [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410
+
+use crate::{
+    embedded::EcPrivateKey, AlgorithmIdentifier, DecodePrivateKey, EncodePrivateKey, Error,
+    PrivateKeyInfo, Result,
+};
+use core::fmt;
+use der::{
+    asn1::{ObjectIdentifier, OctetString},
+    zeroize::{Zeroize, Zeroizing},
+    Decode, Encode, SecretDocument,
+};
+
+/// `id-ecPublicKey` Object Identifier (OID).
+const EC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// Encoding of the `privateKey` field of a PKCS#8 document.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PrivateKeyFormat {
+    /// `CurvePrivateKey` as described in [RFC 8410 Section 7]: an
+    /// `OCTET STRING` containing the private key. The public key is stored
+    /// in the PKCS#8 v2 `publicKey` field.
+    ///
+    /// [RFC 8410 Section 7]: https://datatracker.ietf.org/doc/html/rfc8410#section-7
+    Curve,
+
+    /// SEC1 `ECPrivateKey` as described in [RFC 5915], with the public key
+    /// embedded as a SEC1-encoded point.
+    ///
+    /// [RFC 5915]: https://datatracker.ietf.org/doc/html/rfc5915
+    Sec1,
+}
+
+/// Algorithm whose keys can be represented as [`KeypairBytes`].
+pub trait KeypairAlgorithm {
+    /// `algorithm` OID of the [`AlgorithmIdentifier`].
+    const ALGORITHM_OID: ObjectIdentifier;
+
+    /// `parameters` OID of the [`AlgorithmIdentifier`], if any.
+    const PARAMETERS_OID: Option<ObjectIdentifier>;
+
+    /// Encoding of the private key.
+    const PRIVATE_KEY_FORMAT: PrivateKeyFormat;
+
+    /// Private key bytes, e.g. `[u8; 32]`.
+    type SecretKey: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]> + Clone + Zeroize;
+
+    /// Public key bytes, e.g. `[u8; 32]`.
+    type PublicKey: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]> + Clone;
+}
+
+/// Private key bytes, along with the public key bytes if they're available,
+/// for a [`KeypairAlgorithm`].
+///
+/// PKCS#8 v1 documents don't contain a public key, in which case
+/// [`KeypairBytes::public_key`] is `None` when decoding, unless it's
+/// embedded in a SEC1 `ECPrivateKey`.
+///
+/// When encoding, keys in the [`PrivateKeyFormat::Curve`] format are encoded
+/// as PKCS#8 v2 if the public key is present. Otherwise the public key is
+/// embedded in the SEC1 `ECPrivateKey`.
+///
+/// The private key is zeroized on drop.
+pub struct KeypairBytes<A: KeypairAlgorithm> {
+    /// Private key bytes.
+    pub secret_key: A::SecretKey,
+
+    /// Public key bytes, if available.
+    pub public_key: Option<A::PublicKey>,
+}
+
+impl<A: KeypairAlgorithm> Clone for KeypairBytes<A> {
+    fn clone(&self) -> Self {
+        Self {
+            secret_key: self.secret_key.clone(),
+            public_key: self.public_key.clone(),
+        }
+    }
+}
+
+impl<A: KeypairAlgorithm> fmt::Debug for KeypairBytes<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeypairBytes")
+            .field("public_key", &self.public_key.as_ref().map(AsRef::as_ref))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: KeypairAlgorithm> Drop for KeypairBytes<A> {
+    fn drop(&mut self) {
+        self.secret_key.zeroize();
+    }
+}
+
+impl<A: KeypairAlgorithm> TryFrom<PrivateKeyInfo<'_>> for KeypairBytes<A> {
+    type Error = Error;
+
+    fn try_from(private_key: PrivateKeyInfo<'_>) -> Result<Self> {
+        private_key
+            .algorithm
+            .assert_algorithm_oid(A::ALGORITHM_OID)?;
+
+        if private_key.algorithm.oids()?.1 != A::PARAMETERS_OID {
+            return Err(Error::ParametersMalformed);
+        }
+
+        let (secret_key, public_key) = match A::PRIVATE_KEY_FORMAT {
+            PrivateKeyFormat::Curve => (
+                OctetString::from_der(private_key.private_key)?.as_bytes(),
+                private_key.public_key,
+            ),
+            PrivateKeyFormat::Sec1 => {
+                let ec_private_key = EcPrivateKey::from_der(private_key.private_key)?;
+                (
+                    ec_private_key.private_key,
+                    ec_private_key.public_key.or(private_key.public_key),
+                )
+            }
+        };
+
+        Ok(Self {
+            secret_key: A::SecretKey::try_from(secret_key).map_err(|_| Error::KeyMalformed)?,
+            public_key: public_key
+                .map(|bytes| A::PublicKey::try_from(bytes).map_err(|_| Error::KeyMalformed))
+                .transpose()?,
+        })
+    }
+}
+
+impl<A: KeypairAlgorithm> DecodePrivateKey for KeypairBytes<A> {}
+
+impl<A: KeypairAlgorithm> EncodePrivateKey for KeypairBytes<A> {
+    fn to_pkcs8_der(&self) -> Result<SecretDocument> {
+        let secret_key = self.secret_key.as_ref();
+        let public_key = self.public_key.as_ref().map(AsRef::as_ref);
+
+        let private_key = Zeroizing::new(match A::PRIVATE_KEY_FORMAT {
+            PrivateKeyFormat::Curve => OctetString::new(secret_key)?.to_vec()?,
+            PrivateKeyFormat::Sec1 => EcPrivateKey {
+                private_key: secret_key,
+                public_key,
+            }
+            .to_vec()?,
+        });
+
+        let parameters_oid = A::PARAMETERS_OID;
+        let algorithm = AlgorithmIdentifier {
+            oid: A::ALGORITHM_OID,
+            parameters: parameters_oid.as_ref().map(Into::into),
+        };
+        let mut private_key_info = PrivateKeyInfo::new(algorithm, &private_key);
+
+        if A::PRIVATE_KEY_FORMAT == PrivateKeyFormat::Curve {
+            private_key_info.public_key = public_key;
+        }
+
+        Ok(SecretDocument::encode_msg(&private_key_info)?)
+    }
+}
+
+macro_rules! impl_keypair_algorithm {
+    (
+        $name:ident, $doc:expr, $oid:expr, $params:expr, $format:ident,
+        $secret_size:expr, $public_size:expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+        pub struct $name;
+
+        impl KeypairAlgorithm for $name {
+            const ALGORITHM_OID: ObjectIdentifier = $oid;
+            const PARAMETERS_OID: Option<ObjectIdentifier> = $params;
+            const PRIVATE_KEY_FORMAT: PrivateKeyFormat = PrivateKeyFormat::$format;
+            type SecretKey = [u8; $secret_size];
+            type PublicKey = [u8; $public_size];
+        }
+    };
+}
+
+impl_keypair_algorithm!(
+    Ed25519,
+    "Ed25519 signature algorithm (`id-Ed25519`).",
+    ObjectIdentifier::new_unwrap("1.3.101.112"),
+    None,
+    Curve,
+    32,
+    32
+);
+
+impl_keypair_algorithm!(
+    Ed448,
+    "Ed448 signature algorithm (`id-Ed448`).",
+    ObjectIdentifier::new_unwrap("1.3.101.113"),
+    None,
+    Curve,
+    57,
+    57
+);
+
+impl_keypair_algorithm!(
+    X25519,
+    "X25519 key agreement algorithm (`id-X25519`).",
+    ObjectIdentifier::new_unwrap("1.3.101.110"),
+    None,
+    Curve,
+    32,
+    32
+);
+
+impl_keypair_algorithm!(
+    X448,
+    "X448 key agreement algorithm (`id-X448`).",
+    ObjectIdentifier::new_unwrap("1.3.101.111"),
+    None,
+    Curve,
+    56,
+    56
+);
+
+impl_keypair_algorithm!(
+    NistP256,
+    "NIST P-256 elliptic curve (`secp256r1`).\n\nPublic keys are uncompressed SEC1 points.",
+    EC_OID,
+    Some(ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7")),
+    Sec1,
+    32,
+    65
+);
+
+impl_keypair_algorithm!(
+    NistP384,
+    "NIST P-384 elliptic curve (`secp384r1`).\n\nPublic keys are uncompressed SEC1 points.",
+    EC_OID,
+    Some(ObjectIdentifier::new_unwrap("1.3.132.0.34")),
+    Sec1,
+    48,
+    97
+);
+
+impl_keypair_algorithm!(
+    NistP521,
+    "NIST P-521 elliptic curve (`secp521r1`).\n\nPublic keys are uncompressed SEC1 points.",
+    EC_OID,
+    Some(ObjectIdentifier::new_unwrap("1.3.132.0.35")),
+    Sec1,
+    66,
+    133
+);
@@ -18,12 +18,37 @@
 //!
 //! - [`EncryptedPrivateKeyInfo`]: (with `pkcs5` feature) encrypted key.
 //! - [`PrivateKeyInfo`]: algorithm identifier and data representing a private key.
-//!   Optionally also includes public key data for asymmetric keys.
+//!   Optionally also includes public key data for asymmetric keys, and
+//!   [`Attributes`] such as the PKCS#12 `friendlyName` and `localKeyID`.
 //! - [`SubjectPublicKeyInfo`]: algorithm identifier and data representing a public key
 //!   (re-exported from the [`spki`] crate)
 //!
 //! When the `pem` feature is enabled, it also supports decoding/encoding
-//! documents from "PEM encoding" format as defined in RFC 7468.
+//! documents from "PEM encoding" format as defined in RFC 7468, and reading
+//! bundles of concatenated PEM-encoded keys with [`PemBundle`].
+//!
+//! ## Keypair Bytes
+//! When the `alloc` feature is enabled, [`KeypairBytes`] extracts the raw
+//! private and public key bytes of Ed25519, Ed448, X25519, X448 and NIST
+//! P-256/P-384/P-521 keys from PKCS#8 v1 and v2 documents, and encodes them
+//! back to PKCS#8. See the [`keypair`] module for details.
+//!
+//! ## Embedded Private Keys
+//! The [`embedded`] module decodes and encodes the SEC1 `ECPrivateKey` and
+//! PKCS#1 `RSAPrivateKey` structures contained in the private key of a
+//! [`PrivateKeyInfo`] for elliptic curve and RSA keys.
+//!
+//! ## JSON Web Key Support
+//! When the `jwk` feature is enabled, the [`jwk`] module converts private and
+//! public keys to and from JSON Web Keys for RSA, elliptic curve (P-256,
+//! P-384 and P-521) and octet key pair (Ed25519, Ed448, X25519 and X448)
+//! key types.
+//!
+//! ## Async File I/O
+//! When the `tokio` feature is enabled, the [`async_io`] module provides
+//! async equivalents of the file reading and writing methods of
+//! [`DecodePrivateKey`] and `EncodePrivateKey` for the tokio runtime, which
+//! don't block it on disk I/O.
 //!
 //! ## Encrypted Private Key Support
 //! [`EncryptedPrivateKeyInfo`] supports decoding/encoding encrypted PKCS#8
@@ -41,7 +66,12 @@
 //!       - SHA-2 based PRF with HMAC-SHA224, HMAC-SHA256, HMAC-SHA384, or HMAC-SHA512
 //!       - SHA-1 based PRF with HMAC-SHA1, when the `sha1` feature of this crate is enabled.
 //!   - Symmetric encryption: AES-128-CBC, AES-192-CBC, or AES-256-CBC
-//!     (best available options for PKCS#5v2)
+//!     (most widely supported options for PKCS#5v2), or AES-128-GCM or
+//!     AES-256-GCM ([RFC 5084](https://datatracker.ietf.org/doc/html/rfc5084))
+//!
+//! [`PrivateKeyInfo::encrypt`] uses scrypt and AES-256-CBC with a random salt
+//! and IV. Other algorithms can be selected with [`pkcs5::pbes2::ParametersBuilder`]
+//! and [`PrivateKeyInfo::encrypt_with_params`].
 //!  
 //! ## Legacy DES-CBC and DES-EDE3-CBC (3DES) support (optional)
 //! When the `des-insecure` and/or `3des` features are enabled this crate provides support for
@@ -64,11 +94,12 @@
 //! [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]: https://tools.ietf.org/html/rfc8018#section-6.2
 //! [scrypt]: https://en.wikipedia.org/wiki/Scrypt
 
-#[cfg(feature = "pem")]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod attributes;
 mod error;
 mod private_key_info;
 mod traits;
@@ -77,7 +108,25 @@ mod version;
 #[cfg(feature = "pkcs5")]
 pub(crate) mod encrypted_private_key_info;
 
+#[cfg(feature = "pem")]
+mod pem_bundle;
+
+pub mod embedded;
+
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+pub mod jwk;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod keypair;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod async_io;
+
 pub use crate::{
+    attributes::{Attribute, Attributes, AttributesIter, FRIENDLY_NAME_OID, LOCAL_KEY_ID_OID},
     error::{Error, Result},
     private_key_info::PrivateKeyInfo,
     traits::DecodePrivateKey,
@@ -88,14 +137,17 @@ pub use spki::{self, AlgorithmIdentifier, DecodePublicKey, SubjectPublicKeyInfo}
 
 #[cfg(feature = "alloc")]
 pub use {
-    crate::traits::EncodePrivateKey,
+    crate::{
+        keypair::{KeypairAlgorithm, KeypairBytes},
+        traits::EncodePrivateKey,
+    },
     der::{Document, SecretDocument},
     spki::EncodePublicKey,
 };
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use der::pem::LineEnding;
+pub use {crate::pem_bundle::PemBundle, der::pem::LineEnding};
 
 #[cfg(feature = "pkcs5")]
 pub use {encrypted_private_key_info::EncryptedPrivateKeyInfo, pkcs5};
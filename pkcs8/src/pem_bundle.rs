@@ -0,0 +1,104 @@
+//! Bundles of concatenated PEM-encoded private keys.
+
+use crate::{Error, PrivateKeyInfo, Result};
+use alloc::vec::Vec;
+use der::{
+    pem::{self, PemLabel},
+    SecretDocument,
+};
+
+#[cfg(feature = "std")]
+use {
+    alloc::{borrow::ToOwned, string::String},
+    der::zeroize::Zeroizing,
+    std::{fs, path::Path},
+};
+
+/// Type label of PEM-encoded `EncryptedPrivateKeyInfo` documents.
+///
+/// Bundles may contain encrypted keys even when the `pkcs5` feature, and
+/// with it `EncryptedPrivateKeyInfo`, is disabled.
+const ENCRYPTED_PEM_LABEL: &str = "ENCRYPTED PRIVATE KEY";
+
+/// Iterator over the private keys in a bundle of concatenated PEM documents,
+/// e.g. keys exported from an HSM.
+///
+/// Yields the type label and DER encoding of each `PRIVATE KEY` or
+/// `ENCRYPTED PRIVATE KEY` document, in order. Documents with other labels
+/// (e.g. `CERTIFICATE`), and text between documents, are skipped.
+///
+/// Iteration ends after the first malformed document, yielding its error.
+///
+/// ```
+/// # fn main() -> pkcs8::Result<()> {
+/// # let pem = include_str!("../tests/examples/ed25519-priv-pkcs8v1.pem");
+/// use pkcs8::{PemBundle, PrivateKeyInfo};
+///
+/// for key in PemBundle::new(pem) {
+///     let (label, doc) = key?;
+///
+///     if label == "PRIVATE KEY" {
+///         let private_key: PrivateKeyInfo<'_> = doc.decode_msg()?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PemBundle<'a> {
+    /// PEM documents which haven't been read yet.
+    documents: pem::Documents<'a>,
+}
+
+impl<'a> PemBundle<'a> {
+    /// Create a new iterator over the private keys in the given PEM bundle.
+    pub fn new(pem: &'a str) -> Self {
+        Self {
+            documents: pem::Documents::new(pem.as_bytes()),
+        }
+    }
+
+    /// Read all of the private keys in a PEM bundle file on the local
+    /// filesystem.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Vec<(String, SecretDocument)>> {
+        let pem = Zeroizing::new(fs::read_to_string(path).map_err(der::Error::from)?);
+
+        PemBundle::new(&pem)
+            .map(|key| key.map(|(label, doc)| (label.to_owned(), doc)))
+            .collect()
+    }
+}
+
+impl<'a> Iterator for PemBundle<'a> {
+    type Item = Result<(&'a str, SecretDocument)>;
+
+    fn next(&mut self) -> Option<Result<(&'a str, SecretDocument)>> {
+        loop {
+            let mut decoder = match self.documents.next()? {
+                Ok(decoder) => decoder,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            let label = decoder.type_label();
+
+            if label != PrivateKeyInfo::PEM_LABEL && label != ENCRYPTED_PEM_LABEL {
+                continue;
+            }
+
+            let mut buf = Vec::new();
+            let doc = match decoder.decode_to_end(&mut buf) {
+                Ok(_) => SecretDocument::try_from(buf).map_err(Error::from),
+                Err(err) => Err(err.into()),
+            };
+
+            if doc.is_err() {
+                // Stop at the first malformed document
+                self.documents = pem::Documents::new(&[]);
+            }
+
+            return Some(doc.map(|doc| (label, doc)));
+        }
+    }
+}
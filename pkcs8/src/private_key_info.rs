@@ -1,10 +1,10 @@
 //! PKCS#8 `PrivateKeyInfo`.
 
-use crate::{AlgorithmIdentifier, Error, Result, Version};
+use crate::{AlgorithmIdentifier, Attributes, Error, Result, SubjectPublicKeyInfo, Version};
 use core::fmt;
 use der::{
-    asn1::{Any, BitString, ContextSpecific, OctetString},
-    Decode, Decoder, Encode, Reader, Sequence, TagMode, TagNumber,
+    asn1::{Any, BitString, ContextSpecific, OctetString, SequenceRef},
+    Decode, DecodeValue, Decoder, Encode, Header, Reader, Sequence, TagMode, TagNumber,
 };
 
 #[cfg(feature = "alloc")]
@@ -24,14 +24,16 @@ use der::pem::PemLabel;
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+/// Context-specific tag number for the attributes.
+const ATTRIBUTES_TAG: TagNumber = TagNumber::N0;
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::N1;
 
 /// PKCS#8 `PrivateKeyInfo`.
 ///
 /// ASN.1 structure containing an [`AlgorithmIdentifier`], private key
-/// data in an algorithm specific format, and optional attributes
-/// (ignored by this implementation).
+/// data in an algorithm specific format, and optional [`Attributes`].
 ///
 /// Supports PKCS#8 v1 as described in [RFC 5208] and PKCS#8 v2 as described
 /// in [RFC 5958]. PKCS#8 v2 keys include an additional public key field.
@@ -96,6 +98,9 @@ pub struct PrivateKeyInfo<'a> {
     /// Private key data.
     pub private_key: &'a [u8],
 
+    /// Attributes of the private key, such as the PKCS#12 `friendlyName`.
+    pub attributes: Option<Attributes<'a>>,
+
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
 }
@@ -109,10 +114,36 @@ impl<'a> PrivateKeyInfo<'a> {
         Self {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         }
     }
 
+    /// Set the public key, making this a PKCS#8 v2 `OneAsymmetricKey`.
+    ///
+    /// `public_key` must be encoded as for the `subjectPublicKey` of a
+    /// [`SubjectPublicKeyInfo`] with the same [`AlgorithmIdentifier`].
+    pub fn with_public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Set the attributes of the private key.
+    pub fn with_attributes(mut self, attributes: Attributes<'a>) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Get the [`SubjectPublicKeyInfo`] for the public key included in a
+    /// PKCS#8 v2 `OneAsymmetricKey`, if present.
+    pub fn public_key_info(&self) -> Option<SubjectPublicKeyInfo<'a>> {
+        self.public_key
+            .map(|subject_public_key| SubjectPublicKeyInfo {
+                algorithm: self.algorithm,
+                subject_public_key,
+            })
+    }
+
     /// Get the PKCS#8 [`Version`] for this structure.
     ///
     /// [`Version::V1`] if `public_key` is `None`, [`Version::V2`] if `Some`.
@@ -131,8 +162,13 @@ impl<'a> PrivateKeyInfo<'a> {
     /// - PBKDF: scrypt with default parameters:
     ///   - log₂(N): 15
     ///   - r: 8
-    ///   - p: 1
-    /// - Cipher: AES-256-CBC (best available option for PKCS#5 encryption)
+    ///   - p: 3
+    /// - Cipher: AES-256-CBC (most widely supported option for PKCS#5 encryption)
+    ///
+    /// These are the defaults of [`pbes2::ParametersBuilder`], with a random
+    /// salt and IV. To use other algorithms such as AES-256-GCM, configure a
+    /// builder and pass the parameters it builds to
+    /// [`PrivateKeyInfo::encrypt_with_params`].
     #[cfg(feature = "encryption")]
     #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
     pub fn encrypt(
@@ -146,6 +182,25 @@ impl<'a> PrivateKeyInfo<'a> {
 
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password and [`pbes2::Parameters`].
+    ///
+    /// ```
+    /// # fn main() -> pkcs8::Result<()> {
+    /// # let der = include_bytes!("../tests/examples/ed25519-priv-pkcs8v1.der");
+    /// use pkcs8::{pkcs5::pbes2, EncryptedPrivateKeyInfo, PrivateKeyInfo};
+    ///
+    /// let private_key = PrivateKeyInfo::try_from(der.as_ref())?;
+    ///
+    /// // The salt and IV should be random, e.g. from `ParametersBuilder::random`
+    /// let builder = pbes2::ParametersBuilder::new([0x42; 16], [0x24; 16])
+    ///     .pbkdf2_sha256()
+    ///     .aes256gcm();
+    /// let encrypted = private_key.encrypt_with_params(builder.build()?, "hunter42")?;
+    ///
+    /// let encrypted = EncryptedPrivateKeyInfo::try_from(encrypted.as_bytes())?;
+    /// assert_eq!(encrypted.decrypt("hunter42")?.as_bytes(), der);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[cfg(feature = "encryption")]
     #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
     pub fn encrypt_with_params(
@@ -158,13 +213,15 @@ impl<'a> PrivateKeyInfo<'a> {
     }
 }
 
-impl<'a> Decode<'a> for PrivateKeyInfo<'a> {
-    fn decode(decoder: &mut Decoder<'a>) -> der::Result<PrivateKeyInfo<'a>> {
-        decoder.sequence(|decoder| {
+impl<'a> DecodeValue<'a> for PrivateKeyInfo<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<PrivateKeyInfo<'a>> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
             // Parse and validate `version` INTEGER.
             let version = Version::decode(decoder)?;
             let algorithm = decoder.decode()?;
             let private_key = decoder.octet_string()?.into();
+            let attributes =
+                decoder.context_specific::<Attributes<'_>>(ATTRIBUTES_TAG, TagMode::Implicit)?;
             let public_key = decoder
                 .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, TagMode::Implicit)?
                 .map(|bs| {
@@ -188,6 +245,7 @@ impl<'a> Decode<'a> for PrivateKeyInfo<'a> {
             Ok(Self {
                 algorithm,
                 private_key,
+                attributes,
                 public_key,
             })
         })
@@ -203,6 +261,11 @@ impl<'a> Sequence<'a> for PrivateKeyInfo<'a> {
             &u8::from(self.version()),
             &self.algorithm,
             &OctetString::new(self.private_key)?,
+            &self.attributes.map(|value| ContextSpecific {
+                tag_number: ATTRIBUTES_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
             &self
                 .public_key
                 .map(|pk| {
@@ -230,6 +293,7 @@ impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
         f.debug_struct("PrivateKeyInfo")
             .field("version", &self.version())
             .field("algorithm", &self.algorithm)
+            .field("attributes", &self.attributes)
             .field("public_key", &self.public_key)
             .finish_non_exhaustive()
     }
@@ -266,8 +330,9 @@ impl PemLabel for PrivateKeyInfo<'_> {
 impl<'a> ConstantTimeEq for PrivateKeyInfo<'a> {
     fn ct_eq(&self, other: &Self) -> Choice {
         // NOTE: public fields are not compared in constant time
-        let public_fields_eq =
-            self.algorithm == other.algorithm && self.public_key == other.public_key;
+        let public_fields_eq = self.algorithm == other.algorithm
+            && self.attributes == other.attributes
+            && self.public_key == other.public_key;
 
         self.private_key.ct_eq(other.private_key) & Choice::from(public_fields_eq as u8)
     }
@@ -129,17 +129,54 @@ pub trait EncodePrivateKey {
     }
 
     /// Write ASN.1 DER-encoded PKCS#8 private key to the given path
+    ///
+    /// The file is only accessible by its owner (mode `0600` on Unix, or a DACL
+    /// which only grants access to the current user on Windows). An existing
+    /// file is overwritten, after restricting its permissions.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     fn write_pkcs8_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
         Ok(self.to_pkcs8_der()?.write_der_file(path)?)
     }
 
-    /// Write ASN.1 DER-encoded PKCS#8 private key to the given path
+    /// Write ASN.1 DER-encoded PKCS#8 private key to a new file at the given
+    /// path, returning an error if it already exists.
+    ///
+    /// The file is atomically created so it's only accessible by its owner
+    /// (mode `0600` on Unix, or a DACL which only grants access to the current
+    /// user on Windows).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pkcs8_der_file_new(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(self.to_pkcs8_der()?.write_der_file_new(path)?)
+    }
+
+    /// Write PEM-encoded PKCS#8 private key to the given path
+    ///
+    /// The file is only accessible by its owner (mode `0600` on Unix, or a DACL
+    /// which only grants access to the current user on Windows). An existing
+    /// file is overwritten, after restricting its permissions.
     #[cfg(all(feature = "pem", feature = "std"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
     fn write_pkcs8_pem_file(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
         let doc = self.to_pkcs8_der()?;
         Ok(doc.write_pem_file(path, PrivateKeyInfo::PEM_LABEL, line_ending)?)
     }
+
+    /// Write PEM-encoded PKCS#8 private key to a new file at the given path,
+    /// returning an error if it already exists.
+    ///
+    /// The file is atomically created so it's only accessible by its owner
+    /// (mode `0600` on Unix, or a DACL which only grants access to the current
+    /// user on Windows).
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    fn write_pkcs8_pem_file_new(
+        &self,
+        path: impl AsRef<Path>,
+        line_ending: LineEnding,
+    ) -> Result<()> {
+        let doc = self.to_pkcs8_der()?;
+        Ok(doc.write_pem_file_new(path, PrivateKeyInfo::PEM_LABEL, line_ending)?)
+    }
 }
@@ -232,3 +232,82 @@ fn decrypt_ed25519_der_encpriv_des_pbkdf2_sha256() {
     let pk = enc_pk.decrypt(PASSWORD).unwrap();
     assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_ed25519_der_encpriv_aes256gcm_pbkdf2_sha256() {
+    let builder = pbes2::ParametersBuilder::new([0x42; 16], [0x24; 16])
+        .pbkdf2(pbes2::Pbkdf2Prf::HmacWithSha256, 2048)
+        .aes256gcm();
+
+    let pk_plaintext = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_plaintext
+        .encrypt_with_params(builder.build().unwrap(), PASSWORD)
+        .unwrap();
+
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(pk_encrypted.as_bytes()).unwrap();
+    let pbes2_params = enc_pk.encryption_algorithm.pbes2().unwrap();
+    assert_eq!(pbes2_params.encryption.oid(), pbes2::AES_256_GCM_OID);
+
+    let pk = enc_pk.decrypt(PASSWORD).unwrap();
+    assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+}
+
+/// Deterministic RNG for testing, which is *NOT* suitable for generating
+/// real salts and IVs.
+#[cfg(feature = "encryption")]
+struct CounterRng(u8);
+
+#[cfg(feature = "encryption")]
+impl pkcs8::rand_core::RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        pkcs8::rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        pkcs8::rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), pkcs8::rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl pkcs8::rand_core::CryptoRng for CounterRng {}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_ed25519_der_encpriv_default() {
+    let pk_plaintext = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_plaintext.encrypt(CounterRng(0), PASSWORD).unwrap();
+
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(pk_encrypted.as_bytes()).unwrap();
+    let pbes2_params = enc_pk.encryption_algorithm.pbes2().unwrap();
+    let scrypt_params = pbes2_params.kdf.scrypt().unwrap();
+    assert_eq!(
+        scrypt_params.salt,
+        &hex!("000102030405060708090a0b0c0d0e0f")
+    );
+    assert_eq!(scrypt_params.cost_parameter, 1 << 15);
+    assert_eq!(scrypt_params.block_size, 8);
+    assert_eq!(scrypt_params.parallelization, 3);
+    assert_eq!(
+        pbes2_params.encryption,
+        pbes2::EncryptionScheme::Aes256Cbc {
+            iv: &hex!("101112131415161718191a1b1c1d1e1f")
+        }
+    );
+    assert_eq!(pbes2_params.strength(), Some(pbes2::Strength::Strong));
+
+    let pk = enc_pk.decrypt(PASSWORD).unwrap();
+    assert_eq!(pk.as_bytes(), ED25519_DER_PLAINTEXT_EXAMPLE);
+}
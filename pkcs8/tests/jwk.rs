@@ -0,0 +1,202 @@
+//! JSON Web Key (JWK) tests
+
+#![cfg(feature = "jwk")]
+
+use der::Decode;
+use pkcs8::{
+    jwk::{self, Jwk},
+    Error, PrivateKeyInfo, SubjectPublicKeyInfo,
+};
+
+/// RSA-2048 PKCS#8 private key encoded as ASN.1 DER
+const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const RSA_2048_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// RSA-2048 private key encoded as a JWK
+const RSA_2048_JWK_EXAMPLE: &str = include_str!("examples/rsa2048-priv.jwk");
+
+/// RSA-2048 public key encoded as a JWK
+const RSA_2048_PUB_JWK_EXAMPLE: &str = include_str!("examples/rsa2048-pub.jwk");
+
+/// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Elliptic Curve (P-256) private key encoded as a JWK
+const EC_P256_JWK_EXAMPLE: &str = include_str!("examples/p256-priv.jwk");
+
+/// Elliptic Curve (P-256) public key encoded as a JWK
+const EC_P256_PUB_JWK_EXAMPLE: &str = include_str!("examples/p256-pub.jwk");
+
+/// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER
+const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_PUB_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// Ed25519 private key encoded as a JWK
+///
+/// Note: this is the key in `ed25519-priv-pkcs8v1.der`.
+const ED25519_JWK_EXAMPLE: &str = include_str!("examples/ed25519-priv.jwk");
+
+/// Ed25519 public key encoded as a JWK
+const ED25519_PUB_JWK_EXAMPLE: &str = include_str!("examples/ed25519-pub.jwk");
+
+/// Parse a JWK from JSON.
+fn parse(json: &str) -> Jwk {
+    serde_json::from_str(json).unwrap()
+}
+
+/// Serialize a JWK as JSON.
+fn to_json(jwk: &Jwk) -> String {
+    serde_json::to_string(jwk).unwrap()
+}
+
+#[test]
+fn decode_rsa_2048_jwk() {
+    let doc = jwk::decode_private_key(&parse(RSA_2048_JWK_EXAMPLE)).unwrap();
+    assert_eq!(doc.as_bytes(), RSA_2048_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_rsa_2048_jwk() {
+    let pk = PrivateKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    let jwk = jwk::encode_private_key(&pk).unwrap();
+    assert_eq!(to_json(&jwk), RSA_2048_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_rsa_2048_pub_jwk() {
+    let doc = jwk::decode_public_key(&parse(RSA_2048_PUB_JWK_EXAMPLE)).unwrap();
+    assert_eq!(doc.as_bytes(), RSA_2048_PUB_DER_EXAMPLE);
+
+    // Public keys can also be extracted from private key JWKs
+    let doc = jwk::decode_public_key(&parse(RSA_2048_JWK_EXAMPLE)).unwrap();
+    assert_eq!(doc.as_bytes(), RSA_2048_PUB_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_rsa_2048_pub_jwk() {
+    let spki = SubjectPublicKeyInfo::from_der(RSA_2048_PUB_DER_EXAMPLE).unwrap();
+    let jwk = jwk::encode_public_key(&spki).unwrap();
+    assert_eq!(to_json(&jwk), RSA_2048_PUB_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_ec_p256_jwk() {
+    let doc = jwk::decode_private_key(&parse(EC_P256_JWK_EXAMPLE)).unwrap();
+    let pk = PrivateKeyInfo::try_from(doc.as_bytes()).unwrap();
+    let expected = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(pk.algorithm, expected.algorithm);
+
+    // The embedded `ECPrivateKey` omits the redundant curve parameters
+    let jwk = jwk::encode_private_key(&pk).unwrap();
+    assert_eq!(to_json(&jwk), EC_P256_JWK_EXAMPLE);
+}
+
+#[test]
+fn encode_ec_p256_jwk() {
+    let pk = PrivateKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let jwk = jwk::encode_private_key(&pk).unwrap();
+    assert_eq!(to_json(&jwk), EC_P256_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_ec_p256_pub_jwk() {
+    let doc = jwk::decode_public_key(&parse(EC_P256_PUB_JWK_EXAMPLE)).unwrap();
+    assert_eq!(doc.as_bytes(), EC_P256_PUB_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_ec_p256_pub_jwk() {
+    let spki = SubjectPublicKeyInfo::from_der(EC_P256_PUB_DER_EXAMPLE).unwrap();
+    let jwk = jwk::encode_public_key(&spki).unwrap();
+    assert_eq!(to_json(&jwk), EC_P256_PUB_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_ed25519_jwk() {
+    let doc = jwk::decode_private_key(&parse(ED25519_JWK_EXAMPLE)).unwrap();
+    let pk = PrivateKeyInfo::try_from(doc.as_bytes()).unwrap();
+    let v1 = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    let spki = SubjectPublicKeyInfo::from_der(ED25519_PUB_DER_EXAMPLE).unwrap();
+
+    assert_eq!(pk.algorithm, v1.algorithm);
+    assert_eq!(pk.private_key, v1.private_key);
+    assert_eq!(pk.public_key, Some(spki.subject_public_key));
+}
+
+#[test]
+fn encode_ed25519_jwk() {
+    let mut pk = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+
+    // The public key is required
+    assert_eq!(jwk::encode_private_key(&pk).err(), Some(Error::Jwk));
+
+    let spki = SubjectPublicKeyInfo::from_der(ED25519_PUB_DER_EXAMPLE).unwrap();
+    pk.public_key = Some(spki.subject_public_key);
+    let jwk = jwk::encode_private_key(&pk).unwrap();
+    assert_eq!(to_json(&jwk), ED25519_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_ed25519_pub_jwk() {
+    let doc = jwk::decode_public_key(&parse(ED25519_PUB_JWK_EXAMPLE)).unwrap();
+    assert_eq!(doc.as_bytes(), ED25519_PUB_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_ed25519_pub_jwk() {
+    let spki = SubjectPublicKeyInfo::from_der(ED25519_PUB_DER_EXAMPLE).unwrap();
+    let jwk = jwk::encode_public_key(&spki).unwrap();
+    assert_eq!(to_json(&jwk), ED25519_PUB_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_malformed_jwk() {
+    // Missing private key member
+    assert_eq!(
+        jwk::decode_private_key(&parse(EC_P256_PUB_JWK_EXAMPLE)).err(),
+        Some(Error::Jwk)
+    );
+
+    // Unsupported key type and curve
+    assert_eq!(
+        jwk::decode_public_key(&parse(r#"{"kty":"oct","k":"AAAA"}"#)).err(),
+        Some(Error::Jwk)
+    );
+    assert_eq!(
+        jwk::decode_public_key(&parse(r#"{"kty":"OKP","crv":"Ed25519ph","x":"AAAA"}"#)).err(),
+        Some(Error::Jwk)
+    );
+
+    // Coordinate of the wrong length
+    assert_eq!(
+        jwk::decode_public_key(&parse(r#"{"kty":"OKP","crv":"Ed25519","x":"AAAA"}"#)).err(),
+        Some(Error::Jwk)
+    );
+}
+
+#[test]
+fn jwk_members() {
+    let jwk = parse(EC_P256_JWK_EXAMPLE);
+    assert_eq!(jwk.kty(), "EC");
+    assert_eq!(jwk.crv(), Some("P-256"));
+    assert!(jwk.is_private());
+    assert!(!parse(EC_P256_PUB_JWK_EXAMPLE).is_private());
+
+    // Members unrelated to the key material are ignored
+    let jwk = parse(&ED25519_PUB_JWK_EXAMPLE.replace('{', r#"{"kid":"example","use":"sig","#));
+    assert_eq!(to_json(&jwk), ED25519_PUB_JWK_EXAMPLE);
+}
+
+#[test]
+fn decode_rsa_key_type() {
+    let jwk = parse(EC_P256_JWK_EXAMPLE);
+    assert_eq!(jwk::decode_rsa_private_key(&jwk).err(), Some(Error::Jwk));
+    assert_eq!(jwk::decode_rsa_public_key(&jwk).err(), Some(Error::Jwk));
+}
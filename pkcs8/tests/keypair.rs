@@ -0,0 +1,125 @@
+//! `KeypairBytes` tests
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use pkcs8::{
+    keypair::{Ed25519, Ed448, NistP256, NistP384, X25519},
+    DecodePrivateKey, EncodePrivateKey, Error, KeypairBytes,
+};
+
+/// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER
+const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// Ed25519 PKCS#8 v2 private key + public key encoded as ASN.1 DER
+const ED25519_DER_V2_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v2.der");
+
+/// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
+
+/// RSA-2048 PKCS#8 private key encoded as ASN.1 DER
+const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-priv.der");
+
+/// X25519 PKCS#8 private key encoded as ASN.1 DER
+const X25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/x25519-priv.der");
+
+#[test]
+fn decode_ed25519_v1() {
+    let keypair = KeypairBytes::<Ed25519>::from_pkcs8_der(ED25519_DER_V1_EXAMPLE).unwrap();
+    assert_eq!(
+        keypair.secret_key,
+        hex!("17ED9C73E9DB649EC189A612831C5FC570238207C1AA9DFBD2C53E3FF5E5EA85")
+    );
+    assert_eq!(keypair.public_key, None);
+}
+
+#[test]
+fn decode_ed25519_v2() {
+    let keypair = KeypairBytes::<Ed25519>::from_pkcs8_der(ED25519_DER_V2_EXAMPLE).unwrap();
+    assert_eq!(
+        keypair.secret_key,
+        hex!("D4EE72DBF913584AD5B6D8F1F769F8AD3AFE7C28CBF1D4FBE097A88F44755842")
+    );
+    assert_eq!(
+        keypair.public_key,
+        Some(hex!(
+            "19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1"
+        ))
+    );
+}
+
+#[test]
+fn decode_x25519() {
+    let keypair = KeypairBytes::<X25519>::from_pkcs8_der(X25519_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        keypair.secret_key,
+        hex!("7060252933AC6E7A4A9B0EB2632C5A040A87257ADB869A3ECCC3D16B724F2647")
+    );
+    assert_eq!(keypair.public_key, None);
+}
+
+#[test]
+fn decode_ec_p256() {
+    let keypair = KeypairBytes::<NistP256>::from_pkcs8_der(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        keypair.secret_key,
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D")
+    );
+
+    // The public key is taken from the SEC1 `ECPrivateKey`
+    assert_eq!(
+        keypair.public_key.unwrap(),
+        hex!(
+            "041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F
+             C481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F"
+        )
+    );
+}
+
+#[test]
+fn decode_wrong_algorithm() {
+    // Mismatched `algorithm` OIDs
+    assert!(KeypairBytes::<X25519>::from_pkcs8_der(ED25519_DER_V1_EXAMPLE).is_err());
+    assert!(KeypairBytes::<Ed448>::from_pkcs8_der(ED25519_DER_V1_EXAMPLE).is_err());
+    assert!(KeypairBytes::<Ed25519>::from_pkcs8_der(RSA_2048_DER_EXAMPLE).is_err());
+
+    // Mismatched curve
+    assert_eq!(
+        KeypairBytes::<NistP384>::from_pkcs8_der(EC_P256_DER_EXAMPLE).err(),
+        Some(Error::ParametersMalformed)
+    );
+}
+
+#[test]
+fn encode_ed25519_v1() {
+    let keypair = KeypairBytes::<Ed25519>::from_pkcs8_der(ED25519_DER_V1_EXAMPLE).unwrap();
+    let der = keypair.to_pkcs8_der().unwrap();
+    assert_eq!(der.as_bytes(), ED25519_DER_V1_EXAMPLE);
+}
+
+#[test]
+fn encode_ed25519_v2() {
+    let mut keypair = KeypairBytes::<Ed25519>::from_pkcs8_der(ED25519_DER_V1_EXAMPLE).unwrap();
+    keypair.public_key = Some(hex!(
+        "4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D"
+    ));
+
+    let der = keypair.to_pkcs8_der().unwrap();
+    let decoded = KeypairBytes::<Ed25519>::from_pkcs8_der(der.as_bytes()).unwrap();
+    assert_eq!(decoded.secret_key, keypair.secret_key);
+    assert_eq!(decoded.public_key, keypair.public_key);
+}
+
+#[test]
+fn encode_x25519() {
+    let keypair = KeypairBytes::<X25519>::from_pkcs8_der(X25519_DER_EXAMPLE).unwrap();
+    let der = keypair.to_pkcs8_der().unwrap();
+    assert_eq!(der.as_bytes(), X25519_DER_EXAMPLE);
+}
+
+#[test]
+fn encode_ec_p256() {
+    let keypair = KeypairBytes::<NistP256>::from_pkcs8_der(EC_P256_DER_EXAMPLE).unwrap();
+    let der = keypair.to_pkcs8_der().unwrap();
+    assert_eq!(der.as_bytes(), EC_P256_DER_EXAMPLE);
+}
@@ -0,0 +1,96 @@
+//! PEM bundle tests
+
+#![cfg(feature = "pem")]
+
+use pkcs8::{der::pem, Error, PemBundle, PrivateKeyInfo};
+
+/// Ed25519 PKCS#8 v1 private key encoded as PEM
+const ED25519_PEM_V1_EXAMPLE: &str = include_str!("examples/ed25519-priv-pkcs8v1.pem");
+
+/// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER
+const ED25519_DER_V1_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
+
+/// Ed25519 PKCS#8 v2 private key encoded as PEM
+const ED25519_PEM_V2_EXAMPLE: &str = include_str!("examples/ed25519-priv-pkcs8v2.pem");
+
+/// Ed25519 public key encoded as PEM
+const ED25519_PUB_PEM_EXAMPLE: &str = include_str!("examples/ed25519-pub.pem");
+
+/// Ed25519 encrypted private key encoded as PEM
+const ED25519_ENC_PEM_EXAMPLE: &str =
+    include_str!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.pem");
+
+/// Ed25519 encrypted private key encoded as ASN.1 DER
+const ED25519_ENC_DER_EXAMPLE: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-aes256-pbkdf2-sha256.der");
+
+fn bundle() -> String {
+    [
+        "Exported keys\n",
+        ED25519_PEM_V1_EXAMPLE,
+        ED25519_PUB_PEM_EXAMPLE,
+        ED25519_ENC_PEM_EXAMPLE,
+        "\n",
+        ED25519_PEM_V2_EXAMPLE,
+    ]
+    .concat()
+}
+
+#[test]
+fn read_bundle() {
+    let bundle = bundle();
+    let mut keys = PemBundle::new(&bundle);
+
+    let (label, doc) = keys.next().unwrap().unwrap();
+    assert_eq!(label, "PRIVATE KEY");
+    assert_eq!(doc.as_bytes(), ED25519_DER_V1_EXAMPLE);
+
+    // The public key is skipped
+    let (label, doc) = keys.next().unwrap().unwrap();
+    assert_eq!(label, "ENCRYPTED PRIVATE KEY");
+    assert_eq!(doc.as_bytes(), ED25519_ENC_DER_EXAMPLE);
+
+    let (label, doc) = keys.next().unwrap().unwrap();
+    assert_eq!(label, "PRIVATE KEY");
+    let private_key: PrivateKeyInfo<'_> = doc.decode_msg().unwrap();
+    assert!(private_key.public_key.is_some());
+
+    assert!(keys.next().is_none());
+}
+
+#[test]
+fn read_bundle_malformed() {
+    // Truncated within the encrypted private key
+    let truncated = [
+        ED25519_PEM_V1_EXAMPLE,
+        ED25519_PUB_PEM_EXAMPLE,
+        &ED25519_ENC_PEM_EXAMPLE[..80],
+    ]
+    .concat();
+    let mut keys = PemBundle::new(&truncated);
+
+    assert!(keys.next().unwrap().is_ok());
+    assert_eq!(
+        keys.next().unwrap().err(),
+        Some(Error::from(pem::Error::PostEncapsulationBoundary))
+    );
+    assert!(keys.next().is_none());
+
+    assert!(PemBundle::new(ED25519_PUB_PEM_EXAMPLE).next().is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_bundle_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bundle.pem");
+    std::fs::write(&path, bundle()).unwrap();
+
+    let keys = PemBundle::read_file(&path).unwrap();
+    let labels: Vec<_> = keys.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(
+        labels,
+        ["PRIVATE KEY", "ENCRYPTED PRIVATE KEY", "PRIVATE KEY"]
+    );
+    assert_eq!(keys[0].1.as_bytes(), ED25519_DER_V1_EXAMPLE);
+}
@@ -1,7 +1,7 @@
 //! PKCS#8 private key tests
 
 use hex_literal::hex;
-use pkcs8::{PrivateKeyInfo, Version};
+use pkcs8::{Attributes, PrivateKeyInfo, Version, FRIENDLY_NAME_OID};
 
 #[cfg(feature = "alloc")]
 use der::Encode;
@@ -9,6 +9,13 @@ use der::Encode;
 #[cfg(feature = "pem")]
 use der::{pem::LineEnding, EncodePem};
 
+/// PKCS#9 `localKeyID` and `friendlyName` (`BMPString` "key") attributes
+/// encoded as ASN.1 DER
+const ATTRIBUTES_DER: [u8; 46] = hex!(
+    "312c301306092a864886f70d0109153106040401020304"
+    "3015" "06092a864886f70d0109143108" "1e06006b00650079"
+);
+
 /// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
 
@@ -91,6 +98,53 @@ fn decode_ed25519_der_v2() {
     assert_eq!(pk.algorithm.parameters, None);
     assert_eq!(pk.private_key, PRIV_KEY);
     assert_eq!(pk.public_key, Some(&PUB_KEY[..]));
+
+    let mut attributes = pk.attributes.unwrap().iter();
+    let attribute = attributes.next().unwrap();
+    assert_eq!(attribute.oid, "1.2.840.113549.1.9.9.20".parse().unwrap());
+    assert_eq!(
+        attribute.value().unwrap().utf8_string().unwrap().as_str(),
+        "Curdle Chairs"
+    );
+    assert!(attributes.next().is_none());
+}
+
+#[test]
+fn decode_attributes() {
+    let attributes = Attributes::try_from(&ATTRIBUTES_DER[..]).unwrap();
+    assert_eq!(attributes.iter().count(), 2);
+    assert_eq!(attributes.local_key_id().unwrap(), Some(&[1, 2, 3, 4][..]));
+    assert!(attributes
+        .get("1.2.840.113549.1.9.9.20".parse().unwrap())
+        .is_none());
+
+    let friendly_name = attributes.get(FRIENDLY_NAME_OID).unwrap();
+    assert_eq!(friendly_name.values().count(), 1);
+    assert_eq!(friendly_name.value().unwrap().value(), hex!("006b00650079"));
+
+    // Attributes with trailing data, a non-`SET` of values, or a truncated value
+    for der in [
+        &hex!("310230003000")[..],
+        &hex!("310d300b06092a864886f70d0109150000")[..],
+        &hex!("310f300d06092a864886f70d01091531020401")[..],
+    ] {
+        assert!(Attributes::try_from(der).is_err());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_friendly_name() {
+    let attributes = Attributes::try_from(&ATTRIBUTES_DER[..]).unwrap();
+    assert_eq!(attributes.friendly_name().unwrap().unwrap(), "key");
+
+    // Unpaired UTF-16 surrogate
+    let attributes =
+        Attributes::try_from(&hex!("31123010" "06092a864886f70d0109143103" "1e01d8")[..]);
+    assert!(attributes.unwrap().friendly_name().is_err());
+
+    let private_key = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    assert_eq!(private_key.attributes, None);
 }
 
 #[test]
@@ -146,6 +200,77 @@ fn encode_ed25519_der_v2() {
     );
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_der_v2_with_public_key() {
+    let private_key = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let public_key = private_key.public_key.unwrap();
+
+    let private_key = PrivateKeyInfo::new(private_key.algorithm, private_key.private_key)
+        .with_public_key(public_key);
+    assert_eq!(private_key.version(), Version::V2);
+
+    // The example also contains attributes, which aren't copied
+    let private_der = private_key.to_vec().unwrap();
+    assert_eq!(
+        private_der,
+        hex!(
+            "3051020101300506032b657004220420d4ee72dbf913584ad5b6d8f1f769f8ad3afe7c28cbf1d4fbe097a8"
+            "8f4475584281210019bf44096984cdfe8541bac167dc3b96c85086aa30b6b6cb0c5c38ad703166e1"
+        )
+    );
+
+    let decoded = PrivateKeyInfo::try_from(private_der.as_ref()).unwrap();
+    assert_eq!(decoded.public_key, Some(public_key));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_ed25519_der_v2_with_attributes() {
+    // Attributes are preserved
+    let private_der = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE)
+        .unwrap()
+        .to_vec()
+        .unwrap();
+    assert_eq!(private_der, ED25519_DER_V2_EXAMPLE);
+
+    let attributes = Attributes::try_from(&ATTRIBUTES_DER[..]).unwrap();
+    let private_key = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE)
+        .unwrap()
+        .with_attributes(attributes);
+    assert_eq!(private_key.version(), Version::V1);
+
+    let private_der = private_key.to_vec().unwrap();
+    assert_eq!(private_der[48..50], hex!("a02c"));
+    assert_eq!(private_der[50..], ATTRIBUTES_DER[2..]);
+
+    let decoded = PrivateKeyInfo::try_from(private_der.as_ref()).unwrap();
+    assert_eq!(decoded.attributes, Some(attributes));
+    assert_eq!(
+        decoded
+            .attributes
+            .unwrap()
+            .friendly_name()
+            .unwrap()
+            .unwrap(),
+        "key"
+    );
+}
+
+#[test]
+fn ed25519_v2_public_key_info() {
+    let private_key = PrivateKeyInfo::try_from(ED25519_DER_V1_EXAMPLE).unwrap();
+    assert_eq!(private_key.public_key_info(), None);
+
+    let private_key = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    let public_key_info = private_key.public_key_info().unwrap();
+    assert_eq!(public_key_info.algorithm, private_key.algorithm);
+    assert_eq!(
+        public_key_info.subject_public_key,
+        hex!("19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1")
+    );
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_rsa_2048_der() {
@@ -14,6 +14,9 @@ use tempfile::tempdir;
 #[cfg(all(feature = "pem", feature = "std"))]
 use std::fs;
 
+#[cfg(feature = "tokio")]
+use std::future::Future;
+
 /// Ed25519 `PrivateKeyInfo` encoded as ASN.1 DER
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-priv-pkcs8v1.der");
 
@@ -106,3 +109,146 @@ fn write_pkcs8_pem_file() {
     let pem = fs::read_to_string(path).unwrap();
     assert_eq!(&pem, ED25519_PEM_EXAMPLE);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn write_pkcs8_der_file_new() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("example.der");
+    let key = MockKey(ED25519_DER_EXAMPLE.to_vec());
+    key.write_pkcs8_der_file_new(&path).unwrap();
+
+    let err = key.write_pkcs8_der_file_new(&path).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Asn1(der::ErrorKind::Io(std::io::ErrorKind::AlreadyExists).into())
+    );
+
+    let key = MockKey::read_pkcs8_der_file(&path).unwrap();
+    assert_eq!(key.as_ref(), ED25519_DER_EXAMPLE);
+}
+
+#[cfg(all(feature = "pem", feature = "std"))]
+#[test]
+fn write_pkcs8_pem_file_new() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("example.pem");
+    fs::write(&path, "existing").unwrap();
+
+    let key = MockKey(ED25519_DER_EXAMPLE.to_vec());
+    assert!(key.write_pkcs8_pem_file_new(&path, LineEnding::LF).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "existing");
+
+    fs::remove_file(&path).unwrap();
+    key.write_pkcs8_pem_file_new(&path, LineEnding::LF).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), ED25519_PEM_EXAMPLE);
+}
+
+#[cfg(all(unix, feature = "std"))]
+#[test]
+fn write_pkcs8_der_file_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempdir().unwrap();
+    let key = MockKey(ED25519_DER_EXAMPLE.to_vec());
+
+    let path = dir.path().join("new.der");
+    key.write_pkcs8_der_file_new(&path).unwrap();
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    // Permissions of an existing file are restricted when it's overwritten
+    let path = dir.path().join("existing.der");
+    std::fs::write(&path, b"").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+    key.write_pkcs8_der_file(&path).unwrap();
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+/// Run a future to completion on a new single-threaded tokio runtime.
+#[cfg(feature = "tokio")]
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio_rt::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn read_pkcs8_der_file_async() {
+    block_on(async {
+        let key: MockKey =
+            pkcs8::async_io::read_pkcs8_der_file("tests/examples/ed25519-priv-pkcs8v1.der")
+                .await
+                .unwrap();
+        assert_eq!(key.as_ref(), ED25519_DER_EXAMPLE);
+    });
+}
+
+#[cfg(all(feature = "pem", feature = "tokio"))]
+#[test]
+fn read_pkcs8_pem_file_async() {
+    block_on(async {
+        let key: MockKey =
+            pkcs8::async_io::read_pkcs8_pem_file("tests/examples/ed25519-priv-pkcs8v1.pem")
+                .await
+                .unwrap();
+        assert_eq!(key.as_ref(), ED25519_DER_EXAMPLE);
+
+        // Files with the wrong PEM label are rejected
+        assert!(
+            pkcs8::async_io::read_pkcs8_pem_file::<MockKey>("tests/examples/ed25519-pub.pem")
+                .await
+                .is_err()
+        );
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn write_pkcs8_der_file_async() {
+    block_on(async {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("example.der");
+        let key = MockKey(ED25519_DER_EXAMPLE.to_vec());
+        pkcs8::async_io::write_pkcs8_der_file_new(&key, &path)
+            .await
+            .unwrap();
+
+        let err = pkcs8::async_io::write_pkcs8_der_file_new(&key, &path)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::Asn1(der::ErrorKind::Io(std::io::ErrorKind::AlreadyExists).into())
+        );
+
+        pkcs8::async_io::write_pkcs8_der_file(&key, &path)
+            .await
+            .unwrap();
+        let key: MockKey = pkcs8::async_io::read_pkcs8_der_file(&path).await.unwrap();
+        assert_eq!(key.as_ref(), ED25519_DER_EXAMPLE);
+    });
+}
+
+#[cfg(all(feature = "pem", feature = "tokio"))]
+#[test]
+fn write_pkcs8_pem_file_async() {
+    block_on(async {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("example.pem");
+        let key = MockKey(ED25519_DER_EXAMPLE.to_vec());
+        pkcs8::async_io::write_pkcs8_pem_file(&key, &path, LineEnding::LF)
+            .await
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), ED25519_PEM_EXAMPLE);
+
+        fs::remove_file(&path).unwrap();
+        pkcs8::async_io::write_pkcs8_pem_file_new(&key, &path, LineEnding::LF)
+            .await
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), ED25519_PEM_EXAMPLE);
+    });
+}
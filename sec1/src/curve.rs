@@ -0,0 +1,244 @@
+//! Registry of named elliptic curves.
+
+use crate::util::hex;
+use der::asn1::ObjectIdentifier;
+
+#[cfg(feature = "subtle")]
+use {
+    crate::{Error, Result},
+    subtle::{Choice, ConstantTimeEq, ConstantTimeGreater},
+};
+
+/// Metadata for a named elliptic curve, identified by its OID.
+///
+/// Allows generic key-loading code to validate the lengths of private
+/// scalars and public points, and to display curve names, without needing
+/// its own per-curve tables.
+///
+/// ```
+/// use sec1::{der::asn1::ObjectIdentifier, NamedCurve};
+///
+/// let curve = NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7")).unwrap();
+/// assert_eq!(curve.name, "secp256r1");
+/// assert_eq!(curve.uncompressed_point_len(), 65);
+/// assert_eq!(NamedCurve::from_name("P-256"), Some(curve));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub struct NamedCurve {
+    /// `namedCurve` OID.
+    pub oid: ObjectIdentifier,
+
+    /// Name of the curve as used in SEC2 or RFC5639, e.g. `secp256r1`.
+    pub name: &'static str,
+
+    /// Other names of the curve, e.g. `P-256` and `prime256v1`.
+    pub aliases: &'static [&'static str],
+
+    /// Size of a serialized field element in bytes.
+    pub field_size: usize,
+
+    /// Order `n` of the base point as a big endian integer.
+    pub order: &'static [u8],
+}
+
+impl NamedCurve {
+    /// NIST P-192 elliptic curve.
+    pub const SECP192R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.1"),
+        name: "secp192r1",
+        aliases: &["P-192", "prime192v1"],
+        field_size: 24,
+        order: &hex::<24>("FFFFFFFFFFFFFFFFFFFFFFFF99DEF836146BC9B1B4D22831"),
+    };
+
+    /// NIST P-224 elliptic curve.
+    pub const SECP224R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.33"),
+        name: "secp224r1",
+        aliases: &["P-224"],
+        field_size: 28,
+        order: &hex::<28>("FFFFFFFFFFFFFFFFFFFFFFFFFFFF16A2E0B8F03E13DD29455C5C2A3D"),
+    };
+
+    /// NIST P-256 elliptic curve.
+    pub const SECP256R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"),
+        name: "secp256r1",
+        aliases: &["P-256", "prime256v1"],
+        field_size: 32,
+        order: &hex::<32>("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551"),
+    };
+
+    /// NIST P-384 elliptic curve.
+    pub const SECP384R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.34"),
+        name: "secp384r1",
+        aliases: &["P-384"],
+        field_size: 48,
+        order: &hex::<48>(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF\
+             581A0DB248B0A77AECEC196ACCC52973",
+        ),
+    };
+
+    /// NIST P-521 elliptic curve.
+    pub const SECP521R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.35"),
+        name: "secp521r1",
+        aliases: &["P-521"],
+        field_size: 66,
+        order: &hex::<66>(
+            "01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\
+             FFFA51868783BF2F966B7FCC0148F709A5D03BB5C9B8899C47AEBB6FB71E9138\
+             6409",
+        ),
+    };
+
+    /// secp256k1 elliptic curve.
+    pub const SECP256K1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.132.0.10"),
+        name: "secp256k1",
+        aliases: &[],
+        field_size: 32,
+        order: &hex::<32>("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+    };
+
+    /// brainpoolP256r1 elliptic curve.
+    pub const BRAINPOOL_P256R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.7"),
+        name: "brainpoolP256r1",
+        aliases: &[],
+        field_size: 32,
+        order: &hex::<32>("A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7"),
+    };
+
+    /// brainpoolP384r1 elliptic curve.
+    pub const BRAINPOOL_P384R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.11"),
+        name: "brainpoolP384r1",
+        aliases: &[],
+        field_size: 48,
+        order: &hex::<48>(
+            "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7\
+             CF3AB6AF6B7FC3103B883202E9046565",
+        ),
+    };
+
+    /// brainpoolP512r1 elliptic curve.
+    pub const BRAINPOOL_P512R1: Self = Self {
+        oid: ObjectIdentifier::new_unwrap("1.3.36.3.3.2.8.1.1.13"),
+        name: "brainpoolP512r1",
+        aliases: &[],
+        field_size: 64,
+        order: &hex::<64>(
+            "AADD9DB8DBE9C48B3FD4E6AE33C9FC07CB308DB3B3C9D20ED6639CCA70330870\
+             553E5C414CA92619418661197FAC10471DB1D381085DDADDB58796829CA90069",
+        ),
+    };
+
+    /// All curves in the registry.
+    pub const ALL: &'static [Self] = &[
+        Self::SECP192R1,
+        Self::SECP224R1,
+        Self::SECP256R1,
+        Self::SECP384R1,
+        Self::SECP521R1,
+        Self::SECP256K1,
+        Self::BRAINPOOL_P256R1,
+        Self::BRAINPOOL_P384R1,
+        Self::BRAINPOOL_P512R1,
+    ];
+
+    /// Look up a curve by its `namedCurve` OID.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        Self::ALL.iter().find(|curve| curve.oid == oid).copied()
+    }
+
+    /// Look up a curve by its name or one of its aliases, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|curve| {
+                curve.name.eq_ignore_ascii_case(name)
+                    || curve
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .copied()
+    }
+
+    /// Check that `private_key` is a valid private scalar for this curve:
+    /// serialized as [`NamedCurve::private_key_len`] bytes, nonzero, and less
+    /// than the order of the curve.
+    ///
+    /// Returns [`Error::Crypto`] otherwise.
+    #[cfg(feature = "subtle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+    pub fn validate_private_key(&self, private_key: &[u8]) -> Result<()> {
+        if private_key.len() != self.private_key_len() {
+            return Err(Error::Crypto);
+        }
+
+        validate_scalar(private_key, Some(self.order))
+    }
+
+    /// Length of a serialized private scalar in bytes.
+    pub fn private_key_len(&self) -> usize {
+        self.field_size
+    }
+
+    /// Length of a compressed SEC1 point in bytes.
+    pub fn compressed_point_len(&self) -> usize {
+        self.field_size + 1
+    }
+
+    /// Length of an uncompressed SEC1 point in bytes.
+    pub fn uncompressed_point_len(&self) -> usize {
+        2 * self.field_size + 1
+    }
+}
+
+/// Check that the big endian `scalar` is nonzero, and less than `order` if
+/// it's known.
+///
+/// The comparison runs in constant time with respect to the contents of
+/// `scalar`, though not its length.
+#[cfg(feature = "subtle")]
+pub(crate) fn validate_scalar(scalar: &[u8], order: Option<&[u8]>) -> Result<()> {
+    let len = scalar.len().max(order.map_or(0, <[u8]>::len));
+    let mut nonzero = Choice::from(0);
+    let mut less = Choice::from(order.is_none() as u8);
+    let mut equal = Choice::from(1);
+
+    for i in 0..len {
+        let byte = padded_byte(scalar, len, i);
+        nonzero |= !byte.ct_eq(&0);
+
+        if let Some(order) = order {
+            let order_byte = padded_byte(order, len, i);
+            less |= equal & order_byte.ct_gt(&byte);
+            equal &= byte.ct_eq(&order_byte);
+        }
+    }
+
+    if (nonzero & less).into() {
+        Ok(())
+    } else {
+        Err(Error::Crypto)
+    }
+}
+
+/// Get byte `i` of the big endian integer `bytes` left-padded with zeroes to
+/// `len` bytes.
+#[cfg(feature = "subtle")]
+fn padded_byte(bytes: &[u8], len: usize, i: usize) -> u8 {
+    let padding = len - bytes.len();
+
+    if i < padding {
+        0
+    } else {
+        bytes[i - padding]
+    }
+}
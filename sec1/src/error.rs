@@ -25,6 +25,11 @@ pub enum Error {
     /// a number expected to be a prime was not a prime.
     Crypto,
 
+    /// Legacy PEM encryption errors, e.g. a missing or unsupported
+    /// `DEK-Info` header, or an incorrect password.
+    #[cfg(feature = "encryption")]
+    Encryption,
+
     /// PKCS#8 errors.
     #[cfg(feature = "pkcs8")]
     Pkcs8(pkcs8::Error),
@@ -43,6 +48,8 @@ impl fmt::Display for Error {
             #[cfg(feature = "der")]
             Error::Asn1(err) => write!(f, "SEC1 ASN.1 error: {}", err),
             Error::Crypto => f.write_str("SEC1 cryptographic error"),
+            #[cfg(feature = "encryption")]
+            Error::Encryption => f.write_str("SEC1 PEM encryption error"),
             #[cfg(feature = "pkcs8")]
             Error::Pkcs8(err) => write!(f, "{}", err),
             Error::PointEncoding => f.write_str("elliptic curve point encoding error"),
@@ -66,6 +73,16 @@ impl From<pem::Error> for Error {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl From<pkcs5::Error> for Error {
+    fn from(err: pkcs5::Error) -> Error {
+        match err {
+            pkcs5::Error::Asn1(err) => Error::Asn1(err),
+            _ => Error::Encryption,
+        }
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 impl From<pkcs8::Error> for Error {
     fn from(err: pkcs8::Error) -> Error {
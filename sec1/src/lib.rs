@@ -17,6 +17,14 @@
 //! Additionally, when both the `alloc` and `serde` features are enabled, the
 //! serializers/deserializers will autodetect if a "human friendly" textual
 //! encoding is being used, and if so encode the points as hexadecimal.
+//!
+//! ## Point decompression
+//!
+//! When the `decompress` feature of this crate is enabled,
+//! [`EncodedPoint::decompress`] recovers the y-coordinate of compressed
+//! points from the equation of a short Weierstrass curve, using a minimal
+//! variable-time implementation of prime field arithmetic. Crates which
+//! implement curve arithmetic should decompress points themselves instead.
 
 #[cfg(feature = "alloc")]
 #[allow(unused_extern_crates)]
@@ -27,6 +35,8 @@ extern crate std;
 #[cfg(feature = "point")]
 pub mod point;
 
+#[cfg(feature = "der")]
+mod curve;
 mod error;
 #[cfg(feature = "der")]
 mod parameters;
@@ -34,6 +44,8 @@ mod parameters;
 mod private_key;
 #[cfg(feature = "der")]
 mod traits;
+#[cfg(any(feature = "der", feature = "decompress"))]
+mod util;
 
 #[cfg(feature = "der")]
 pub use der;
@@ -47,15 +59,30 @@ pub use crate::point::EncodedPoint;
 pub use generic_array::typenum::consts;
 
 #[cfg(feature = "der")]
-pub use crate::{parameters::EcParameters, private_key::EcPrivateKey, traits::DecodeEcPrivateKey};
+pub use crate::{
+    curve::NamedCurve,
+    parameters::{EcParameters, SpecifiedEcDomain},
+    private_key::EcPrivateKey,
+    traits::DecodeEcPrivateKey,
+};
 
 #[cfg(feature = "alloc")]
-pub use crate::traits::EncodeEcPrivateKey;
+pub use crate::{
+    private_key::{
+        builder::EcPrivateKeyBuilder,
+        owned::{EcParametersOwned, EcPrivateKeyOwned, SpecifiedEcDomainOwned},
+    },
+    traits::EncodeEcPrivateKey,
+};
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 pub use der::pem::{self, LineEnding};
 
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub use pkcs5::{pem::DekAlgorithm, rand_core};
+
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
 pub use pkcs8;
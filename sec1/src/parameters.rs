@@ -1,8 +1,13 @@
 use der::{
-    asn1::{Any, ObjectIdentifier},
-    DecodeValue, Decoder, EncodeValue, FixedTag, Header, Length, Tag, Writer,
+    asn1::{Any, BitString, ObjectIdentifier, OctetString, UIntBytes},
+    Choice, Decode, Decoder, Encode, EncodeValue, Length, Reader, Sequence, Tag, Tagged, Writer,
 };
 
+/// `prime-field` Object Identifier (OID) from [X9.62].
+///
+/// [X9.62]: https://webstore.ansi.org/standards/ascx9/ansix9621998
+const PRIME_FIELD_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.1.1");
+
 /// Elliptic curve parameters as described in
 /// [RFC5480 Section 2.1.1](https://datatracker.ietf.org/doc/html/rfc5480#section-2.1.1):
 ///
@@ -10,7 +15,7 @@ use der::{
 /// ECParameters ::= CHOICE {
 ///   namedCurve         OBJECT IDENTIFIER
 ///   -- implicitCurve   NULL
-///   -- specifiedCurve  SpecifiedECDomain
+///   specifiedCurve     SpecifiedECDomain
 /// }
 ///   -- implicitCurve and specifiedCurve MUST NOT be used in PKIX.
 ///   -- Details for SpecifiedECDomain can be found in [X9.62].
@@ -19,58 +24,267 @@ use der::{
 /// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(docsrs, doc(cfg(feature = "der")))]
-pub enum EcParameters {
+pub enum EcParameters<'a> {
     /// Elliptic curve named by a particular OID.
     ///
     /// > namedCurve identifies all the required values for a particular
     /// > set of elliptic curve domain parameters to be represented by an
     /// > object identifier.
     NamedCurve(ObjectIdentifier),
+
+    /// Elliptic curve with explicitly specified domain parameters.
+    ///
+    /// These are emitted by some HSMs and older tooling in place of a
+    /// named curve.
+    SpecifiedCurve(SpecifiedEcDomain<'a>),
 }
 
-impl DecodeValue<'_> for EcParameters {
-    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> der::Result<Self> {
-        ObjectIdentifier::decode_value(decoder, header).map(Self::NamedCurve)
+impl<'a> Decode<'a> for EcParameters<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        match decoder.peek_tag()? {
+            Tag::ObjectIdentifier => decoder.oid().map(Self::NamedCurve),
+            Tag::Sequence => decoder.decode().map(Self::SpecifiedCurve),
+            tag => Err(tag.unexpected_error(None)),
+        }
     }
 }
 
-impl EncodeValue for EcParameters {
+impl EncodeValue for EcParameters<'_> {
     fn value_len(&self) -> der::Result<Length> {
         match self {
             Self::NamedCurve(oid) => oid.value_len(),
+            Self::SpecifiedCurve(domain) => domain.value_len(),
         }
     }
 
     fn encode_value(&self, writer: &mut dyn Writer) -> der::Result<()> {
         match self {
             Self::NamedCurve(oid) => oid.encode_value(writer),
+            Self::SpecifiedCurve(domain) => domain.encode_value(writer),
         }
     }
 }
 
-impl EcParameters {
+impl Tagged for EcParameters<'_> {
+    fn tag(&self) -> Tag {
+        match self {
+            Self::NamedCurve(_) => Tag::ObjectIdentifier,
+            Self::SpecifiedCurve(_) => Tag::Sequence,
+        }
+    }
+}
+
+impl<'a> Choice<'a> for EcParameters<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(tag, Tag::ObjectIdentifier | Tag::Sequence)
+    }
+}
+
+impl<'a> EcParameters<'a> {
     /// Obtain the `namedCurve` OID.
     pub fn named_curve(self) -> Option<ObjectIdentifier> {
         match self {
             Self::NamedCurve(oid) => Some(oid),
+            Self::SpecifiedCurve(_) => None,
+        }
+    }
+
+    /// Obtain the `specifiedCurve` domain parameters.
+    pub fn specified_curve(self) -> Option<SpecifiedEcDomain<'a>> {
+        match self {
+            Self::NamedCurve(_) => None,
+            Self::SpecifiedCurve(domain) => Some(domain),
         }
     }
 }
 
-impl<'a> From<&'a EcParameters> for Any<'a> {
-    fn from(params: &'a EcParameters) -> Any<'a> {
+/// Only `namedCurve` parameters can be converted, since `specifiedCurve`
+/// parameters aren't stored in encoded form.
+impl<'a> TryFrom<&'a EcParameters<'a>> for Any<'a> {
+    type Error = der::Error;
+
+    fn try_from(params: &'a EcParameters<'a>) -> der::Result<Any<'a>> {
         match params {
-            EcParameters::NamedCurve(oid) => oid.into(),
+            EcParameters::NamedCurve(oid) => Ok(oid.into()),
+            EcParameters::SpecifiedCurve(_) => Err(Tag::Sequence.value_error()),
         }
     }
 }
 
-impl From<ObjectIdentifier> for EcParameters {
-    fn from(oid: ObjectIdentifier) -> EcParameters {
+impl From<ObjectIdentifier> for EcParameters<'_> {
+    fn from(oid: ObjectIdentifier) -> Self {
         EcParameters::NamedCurve(oid)
     }
 }
 
-impl FixedTag for EcParameters {
-    const TAG: Tag = Tag::ObjectIdentifier;
+impl<'a> From<SpecifiedEcDomain<'a>> for EcParameters<'a> {
+    fn from(domain: SpecifiedEcDomain<'a>) -> EcParameters<'a> {
+        EcParameters::SpecifiedCurve(domain)
+    }
+}
+
+/// Explicitly specified elliptic curve domain parameters over a prime field,
+/// as described in [SEC1: Elliptic Curve Cryptography (Version 2.0)]
+/// Appendix C.2 (p.104):
+///
+/// ```text
+/// SpecifiedECDomain ::= SEQUENCE {
+///   version SpecifiedECDomainVersion(ecdpVer1 | ecdpVer2 | ecdpVer3, ...),
+///   fieldID FieldID {{FieldTypes}},
+///   curve Curve,
+///   base ECPoint,
+///   order INTEGER,
+///   cofactor INTEGER OPTIONAL,
+///   hash HashAlgorithm OPTIONAL,
+///   ...
+/// }
+///
+/// FieldID { FIELD-ID:IOSet } ::= SEQUENCE {
+///   fieldType FIELD-ID.&id({IOSet}),
+///   parameters FIELD-ID.&Type({IOSet}{@fieldType})
+/// }
+///
+/// Curve ::= SEQUENCE {
+///   a FieldElement,
+///   b FieldElement,
+///   seed BIT STRING OPTIONAL
+/// }
+/// ```
+///
+/// Only the `prime-field` field type is supported, and the `hash` field,
+/// which isn't used by any known implementation, is rejected.
+///
+/// [SEC1: Elliptic Curve Cryptography (Version 2.0)]: https://www.secg.org/sec1-v2.pdf
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "der")))]
+pub struct SpecifiedEcDomain<'a> {
+    /// Version of the domain parameters, from 1 to 3.
+    pub version: u8,
+
+    /// Prime `p` of the underlying field.
+    pub prime: UIntBytes<'a>,
+
+    /// Curve coefficient `a` as a big endian field element.
+    pub a: &'a [u8],
+
+    /// Curve coefficient `b` as a big endian field element.
+    pub b: &'a [u8],
+
+    /// Seed used to generate the curve coefficients, if available.
+    pub seed: Option<&'a [u8]>,
+
+    /// SEC1-encoded base point `G`.
+    pub base: &'a [u8],
+
+    /// Order `n` of the base point.
+    pub order: UIntBytes<'a>,
+
+    /// Cofactor `h`, if available.
+    pub cofactor: Option<UIntBytes<'a>>,
+}
+
+impl<'a> Decode<'a> for SpecifiedEcDomain<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let version = decoder.uint8()?;
+
+            if !(1..=3).contains(&version) {
+                return Err(Tag::Integer.value_error());
+            }
+
+            let field_id = FieldId::decode(decoder)?;
+            let curve = Curve::decode(decoder)?;
+
+            Ok(Self {
+                version,
+                prime: field_id.prime,
+                a: curve.a.as_bytes(),
+                b: curve.b.as_bytes(),
+                seed: curve
+                    .seed
+                    .map(|bs| bs.as_bytes().ok_or_else(|| Tag::BitString.value_error()))
+                    .transpose()?,
+                base: decoder.octet_string()?.as_bytes(),
+                order: decoder.decode()?,
+                cofactor: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for SpecifiedEcDomain<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        let curve = Curve {
+            a: OctetString::new(self.a)?,
+            b: OctetString::new(self.b)?,
+            seed: self.seed.map(BitString::from_bytes).transpose()?,
+        };
+
+        f(&[
+            &self.version,
+            &FieldId { prime: self.prime },
+            &curve,
+            &OctetString::new(self.base)?,
+            &self.order,
+            &self.cofactor,
+        ])
+    }
+}
+
+/// `FieldID` of a prime field.
+struct FieldId<'a> {
+    prime: UIntBytes<'a>,
+}
+
+impl<'a> Decode<'a> for FieldId<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            if decoder.oid()? != PRIME_FIELD_OID {
+                return Err(Tag::ObjectIdentifier.value_error());
+            }
+
+            let prime = decoder.decode()?;
+            Ok(Self { prime })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for FieldId<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&PRIME_FIELD_OID, &self.prime])
+    }
+}
+
+/// `Curve` coefficients.
+struct Curve<'a> {
+    a: OctetString<'a>,
+    b: OctetString<'a>,
+    seed: Option<BitString<'a>>,
+}
+
+impl<'a> Decode<'a> for Curve<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                a: decoder.octet_string()?,
+                b: decoder.octet_string()?,
+                seed: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Curve<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.a, &self.b, &self.seed])
+    }
 }
@@ -5,6 +5,9 @@
 //!
 //! [SEC1: Elliptic Curve Cryptography]: https://www.secg.org/sec1-v2.pdf
 
+#[cfg(feature = "decompress")]
+mod field;
+
 use crate::{Error, Result};
 use core::{
     cmp::Ordering,
@@ -20,6 +23,9 @@ use generic_array::{
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
+#[cfg(feature = "decompress")]
+use {self::field::PrimeField, crate::util::hex};
+
 #[cfg(feature = "serde")]
 use serde::{de, ser, Deserialize, Serialize};
 
@@ -60,6 +66,83 @@ macro_rules! impl_modulus_size {
 
 impl_modulus_size!(U28, U32, U48, U66);
 
+/// Short Weierstrass elliptic curve `y² = x³ + ax + b` over a prime field,
+/// used to decompress points with [`EncodedPoint::decompress`].
+///
+/// All parameters are big endian integers, and must be less than `p`.
+#[cfg(feature = "decompress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decompress")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct WeierstrassCurve<'a> {
+    /// Field modulus `p`, which determines the field element size.
+    pub p: &'a [u8],
+
+    /// Curve coefficient `a`.
+    pub a: &'a [u8],
+
+    /// Curve coefficient `b`.
+    pub b: &'a [u8],
+}
+
+#[cfg(feature = "decompress")]
+impl WeierstrassCurve<'static> {
+    /// NIST P-224 elliptic curve (`secp224r1`).
+    pub const NIST_P224: Self = Self {
+        p: &hex::<28>("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000001"),
+        a: &hex::<28>("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFE"),
+        b: &hex::<28>("B4050A850C04B3ABF54132565044B0B7D7BFD8BA270B39432355FFB4"),
+    };
+
+    /// NIST P-256 elliptic curve (`secp256r1`).
+    pub const NIST_P256: Self = Self {
+        p: &hex::<32>("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF"),
+        a: &hex::<32>("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC"),
+        b: &hex::<32>("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B"),
+    };
+
+    /// NIST P-384 elliptic curve (`secp384r1`).
+    pub const NIST_P384: Self = Self {
+        p: &hex::<48>(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+             FFFFFFFF0000000000000000FFFFFFFF",
+        ),
+        a: &hex::<48>(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE\
+             FFFFFFFF0000000000000000FFFFFFFC",
+        ),
+        b: &hex::<48>(
+            "B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875A\
+             C656398D8A2ED19D2A85C8EDD3EC2AEF",
+        ),
+    };
+
+    /// NIST P-521 elliptic curve (`secp521r1`).
+    pub const NIST_P521: Self = Self {
+        p: &hex::<66>(
+            "01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\
+             FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\
+             FFFF",
+        ),
+        a: &hex::<66>(
+            "01FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\
+             FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\
+             FFFC",
+        ),
+        b: &hex::<66>(
+            "0051953EB9618E1C9A1F929A21A0B68540EEA2DA725B99B315F3B8B489918EF1\
+             09E156193951EC7E937B1652C0BD3BB1BF073573DF883D2C34F1EF451FD46B50\
+             3F00",
+        ),
+    };
+
+    /// secp256k1 elliptic curve.
+    pub const SECP256K1: Self = Self {
+        p: &hex::<32>("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+        a: &hex::<32>("0000000000000000000000000000000000000000000000000000000000000000"),
+        b: &hex::<32>("0000000000000000000000000000000000000000000000000000000000000007"),
+    };
+}
+
 /// SEC1 encoded curve point.
 ///
 /// This type is an enum over the compressed and uncompressed encodings,
@@ -138,6 +221,21 @@ where
         Self { bytes }
     }
 
+    /// Get the raw uncompressed coordinates, i.e. the concatenated `x || y`
+    /// coordinates with no leading SEC1 tag byte.
+    ///
+    /// Returns `None` if this point is not uncompressed: see
+    /// `EncodedPoint::decompress`.
+    pub fn to_untagged_bytes(&self) -> Option<GenericArray<u8, Size::UntaggedPointSize>> {
+        self.affine_coordinates().map(|(x, y)| {
+            let mut bytes = GenericArray::default();
+            let (x_bytes, y_bytes) = bytes.split_at_mut(Size::to_usize());
+            x_bytes.copy_from_slice(x);
+            y_bytes.copy_from_slice(y);
+            bytes
+        })
+    }
+
     /// Return [`EncodedPoint`] representing the additive identity
     /// (a.k.a. point at infinity)
     pub fn identity() -> Self {
@@ -186,6 +284,55 @@ where
         }
     }
 
+    /// Decompress this [`EncodedPoint`] using the equation of the given
+    /// curve, returning a new uncompressed [`EncodedPoint`].
+    ///
+    /// The y-coordinate of compact points is the smaller of the two
+    /// candidates, as described in [draft-jivsov-ecc-compact]. Uncompressed
+    /// points and the identity are returned unchanged.
+    ///
+    /// Returns an error if the field size of `curve` doesn't match `Size`, or
+    /// if there is no point on the curve with the given x-coordinate.
+    ///
+    /// This is implemented using variable-time arithmetic, and must only be
+    /// used with public points.
+    ///
+    /// [draft-jivsov-ecc-compact]: https://datatracker.ietf.org/doc/html/draft-jivsov-ecc-compact-05
+    #[cfg(feature = "decompress")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "decompress")))]
+    pub fn decompress(&self, curve: &WeierstrassCurve<'_>) -> Result<Self> {
+        let (x, y_is_odd) = match self.coordinates() {
+            Coordinates::Identity | Coordinates::Uncompressed { .. } => return Ok(self.clone()),
+            Coordinates::Compressed { x, y_is_odd } => (x, Some(y_is_odd)),
+            Coordinates::Compact { x } => (x, None),
+        };
+
+        if curve.p.len() != Size::to_usize() {
+            return Err(Error::PointEncoding);
+        }
+
+        let field = PrimeField::new(curve.p).ok_or(Error::PointEncoding)?;
+        let element = |bytes| field.element(bytes).ok_or(Error::PointEncoding);
+        let (x_element, a, b) = (element(x)?, element(curve.a)?, element(curve.b)?);
+
+        // y² = x³ + ax + b
+        let x_cubed = field.mul(&field.mul(&x_element, &x_element), &x_element);
+        let y_squared = field.add(&field.add(&x_cubed, &field.mul(&a, &x_element)), &b);
+        let y = field.sqrt(&y_squared).ok_or(Error::PointEncoding)?;
+        let neg_y = field.neg(&y);
+
+        let y = match y_is_odd {
+            Some(y_is_odd) if y.is_odd() == y_is_odd => y,
+            Some(_) if neg_y != y => neg_y,
+            Some(_) => return Err(Error::PointEncoding),
+            None => core::cmp::min(y, neg_y),
+        };
+
+        let mut y_bytes = GenericArray::default();
+        y.write_be_bytes(&mut y_bytes);
+        Ok(Self::from_affine_coordinates(x, &y_bytes, false))
+    }
+
     /// Get the SEC1 tag for this [`EncodedPoint`]
     pub fn tag(&self) -> Tag {
         // Tag is ensured valid by the constructor
@@ -238,6 +385,18 @@ where
             Coordinates::Compact { .. } => None,
         }
     }
+
+    /// Get the affine `(x, y)` coordinates for this [`EncodedPoint`].
+    ///
+    /// Returns `None` if this point is not uncompressed: see
+    /// `EncodedPoint::decompress`.
+    #[allow(clippy::type_complexity)]
+    pub fn affine_coordinates(&self) -> Option<(&GenericArray<u8, Size>, &GenericArray<u8, Size>)> {
+        match self.coordinates() {
+            Coordinates::Uncompressed { x, y } => Some((x, y)),
+            _ => None,
+        }
+    }
 }
 
 impl<Size> AsRef<[u8]> for EncodedPoint<Size>
@@ -323,6 +482,26 @@ where
     }
 }
 
+/// Extract the point from an X.509 `SubjectPublicKeyInfo`, ensuring its
+/// algorithm is `id-ecPublicKey` with a named curve.
+///
+/// Whether the named curve has the expected `Size` is only checked by the
+/// length of the encoded point.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<Size> TryFrom<pkcs8::SubjectPublicKeyInfo<'_>> for EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    type Error = Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> Result<Self> {
+        spki.algorithm.assert_algorithm_oid(crate::ALGORITHM_OID)?;
+        spki.algorithm.parameters_oid()?;
+        Self::from_bytes(spki.subject_public_key)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl<Size> Zeroize for EncodedPoint<Size>
 where
@@ -0,0 +1,325 @@
+//! Minimal prime field arithmetic used to decompress points.
+//!
+//! This implementation is variable-time and slow, and is only intended for
+//! operating on public values such as point coordinates. It supports primes
+//! of up to 576 bits, which covers all of the curves supported by
+//! [`ModulusSize`][`super::ModulusSize`].
+
+use core::cmp::Ordering;
+
+/// Number of 64-bit limbs in a [`Uint`].
+const LIMBS: usize = 9;
+
+/// Unsigned integer stored as little endian 64-bit limbs.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(super) struct Uint([u64; LIMBS]);
+
+impl Uint {
+    /// Zero.
+    pub const ZERO: Self = Self([0; LIMBS]);
+
+    /// One.
+    pub const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = 1;
+        Self(limbs)
+    };
+
+    /// Decode a big endian integer, returning `None` if it's too large.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut limbs = [0u64; LIMBS];
+
+        for (i, &byte) in bytes.iter().rev().enumerate() {
+            match limbs.get_mut(i / 8) {
+                Some(limb) => *limb |= u64::from(byte) << (8 * (i % 8)),
+                None if byte == 0 => (),
+                None => return None,
+            }
+        }
+
+        Some(Self(limbs))
+    }
+
+    /// Encode as a big endian integer filling `out`, truncating any high
+    /// bytes which don't fit.
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        for (i, byte) in out.iter_mut().rev().enumerate() {
+            *byte = self
+                .0
+                .get(i / 8)
+                .map_or(0, |limb| (limb >> (8 * (i % 8))) as u8);
+        }
+    }
+
+    /// Number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(i) => 64 * (i + 1) - self.0[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Get the `i`-th bit.
+    pub fn bit(&self, i: usize) -> bool {
+        self.0[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// Is this integer odd?
+    pub fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Number of trailing zero bits, or zero if this integer is zero.
+    fn trailing_zeros(&self) -> usize {
+        (0..self.bits()).take_while(|&i| !self.bit(i)).count()
+    }
+
+    /// Add, returning the sum and whether it overflowed.
+    fn adc(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0u64; LIMBS];
+        let mut carry = false;
+
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (sum, c1) = a.overflowing_add(*b);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = c1 | c2;
+        }
+
+        (Self(limbs), carry)
+    }
+
+    /// Subtract, returning the difference and whether it underflowed.
+    fn sbb(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0u64; LIMBS];
+        let mut borrow = false;
+
+        for (limb, (a, b)) in limbs.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let (diff, b1) = a.overflowing_sub(*b);
+            let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+            *limb = diff;
+            borrow = b1 | b2;
+        }
+
+        (Self(limbs), borrow)
+    }
+
+    /// Shift right by `n` bits, where `n` is less than 64.
+    fn shr(&self, n: usize) -> Self {
+        let mut limbs = self.0;
+
+        if n > 0 {
+            for (i, limb) in limbs.iter_mut().enumerate() {
+                let high = self.0.get(i + 1).map_or(0, |next| next << (64 - n));
+                *limb = (*limb >> n) | high;
+            }
+        }
+
+        Self(limbs)
+    }
+}
+
+impl Ord for Uint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for Uint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Prime field with an odd modulus `p`.
+///
+/// All field elements are expected to be fully reduced, i.e. less than `p`.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct PrimeField {
+    p: Uint,
+}
+
+impl PrimeField {
+    /// Create a field with the given big endian modulus, returning `None` if
+    /// it's too large or even.
+    ///
+    /// The modulus is not checked to be prime.
+    pub fn new(p: &[u8]) -> Option<Self> {
+        let p = Uint::from_be_bytes(p)?;
+
+        // Leave room for doubling without overflow
+        if !p.is_odd() || p.bits() >= 64 * LIMBS {
+            return None;
+        }
+
+        Some(Self { p })
+    }
+
+    /// Decode a big endian field element, returning `None` if it's not less
+    /// than the modulus.
+    pub fn element(&self, bytes: &[u8]) -> Option<Uint> {
+        Uint::from_be_bytes(bytes).filter(|n| n < &self.p)
+    }
+
+    /// Compute `a + b`.
+    pub fn add(&self, a: &Uint, b: &Uint) -> Uint {
+        let sum = a.adc(b).0;
+
+        if sum >= self.p {
+            sum.sbb(&self.p).0
+        } else {
+            sum
+        }
+    }
+
+    /// Compute `-a`.
+    pub fn neg(&self, a: &Uint) -> Uint {
+        if a == &Uint::ZERO {
+            Uint::ZERO
+        } else {
+            self.p.sbb(a).0
+        }
+    }
+
+    /// Compute `a * b`.
+    pub fn mul(&self, a: &Uint, b: &Uint) -> Uint {
+        let mut product = Uint::ZERO;
+
+        for i in (0..b.bits()).rev() {
+            product = self.add(&product, &product);
+
+            if b.bit(i) {
+                product = self.add(&product, a);
+            }
+        }
+
+        product
+    }
+
+    /// Compute `a^e`.
+    fn pow(&self, a: &Uint, e: &Uint) -> Uint {
+        let mut result = Uint::ONE;
+
+        for i in (0..e.bits()).rev() {
+            result = self.mul(&result, &result);
+
+            if e.bit(i) {
+                result = self.mul(&result, a);
+            }
+        }
+
+        result
+    }
+
+    /// Compute a square root of `a` using the Tonelli-Shanks algorithm, if
+    /// it's a quadratic residue.
+    pub fn sqrt(&self, a: &Uint) -> Option<Uint> {
+        if a == &Uint::ZERO {
+            return Some(Uint::ZERO);
+        }
+
+        let p_minus_one = self.p.sbb(&Uint::ONE).0;
+
+        // Euler's criterion
+        if self.pow(a, &p_minus_one.shr(1)) != Uint::ONE {
+            return None;
+        }
+
+        // `p - 1 = q * 2^s` with `q` odd
+        let s = p_minus_one.trailing_zeros();
+        let q = (0..s).fold(p_minus_one, |q, _| q.shr(1));
+
+        let mut t = self.pow(a, &q);
+        let mut r = self.pow(a, &q.adc(&Uint::ONE).0.shr(1));
+
+        // When `p = 3 (mod 4)`, `r = a^((p + 1) / 4)` is the square root
+        if s == 1 {
+            return Some(r);
+        }
+
+        // Find a quadratic non-residue `z`
+        let mut z = Uint::ONE;
+        loop {
+            z = self.add(&z, &Uint::ONE);
+
+            if self.pow(&z, &p_minus_one.shr(1)) == p_minus_one {
+                break;
+            }
+        }
+
+        let mut m = s;
+        let mut c = self.pow(&z, &q);
+
+        while t != Uint::ONE {
+            // Find the least `i` such that `t^(2^i) = 1`
+            let mut i = 0;
+            let mut t_pow = t;
+
+            while t_pow != Uint::ONE {
+                t_pow = self.mul(&t_pow, &t_pow);
+                i += 1;
+
+                if i == m {
+                    return None;
+                }
+            }
+
+            let b = (0..(m - i - 1)).fold(c, |b, _| self.mul(&b, &b));
+            m = i;
+            c = self.mul(&b, &b);
+            t = self.mul(&t, &c);
+            r = self.mul(&r, &b);
+        }
+
+        Some(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrimeField, Uint};
+    use hex_literal::hex;
+
+    /// NIST P-224 field modulus, where `p = 1 (mod 4)`
+    const P224: [u8; 28] = hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF000000000000000000000001");
+
+    /// NIST P-256 field modulus, where `p = 3 (mod 4)`
+    const P256: [u8; 32] = hex!("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF");
+
+    #[test]
+    fn uint_round_trip() {
+        let n = Uint::from_be_bytes(&P256).expect("P-256 modulus");
+        assert_eq!(n.bits(), 256);
+
+        let mut bytes = [0u8; 32];
+        n.write_be_bytes(&mut bytes);
+        assert_eq!(bytes, P256);
+        assert_eq!(Uint::from_be_bytes(&[0xFF; 73]), None);
+    }
+
+    #[test]
+    fn sqrt() {
+        for p in [&P224[..], &P256[..]] {
+            let field = PrimeField::new(p).expect("invalid modulus");
+
+            for x in 2u8..20 {
+                let x = field.element(&[x]).expect("invalid element");
+                let square = field.mul(&x, &x);
+                let root = field.sqrt(&square).expect("no square root");
+                assert!(root == x || root == field.neg(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_non_residue() {
+        // `-1` is a non-residue when `p = 3 (mod 4)`, so `-x^2` is too
+        let field = PrimeField::new(&P256).expect("invalid modulus");
+
+        for x in 2u8..20 {
+            let x = field.element(&[x]).expect("invalid element");
+            let square = field.mul(&x, &x);
+            assert_eq!(field.sqrt(&field.neg(&square)), None);
+        }
+    }
+}
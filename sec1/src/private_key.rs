@@ -5,6 +5,14 @@
 //!
 //! <https://www.secg.org/sec1-v2.pdf>
 
+#[cfg(feature = "subtle")]
+use crate::{curve::validate_scalar, NamedCurve};
+
+#[cfg(feature = "alloc")]
+pub(crate) mod builder;
+#[cfg(feature = "alloc")]
+pub(crate) mod owned;
+
 use crate::{EcParameters, Error, Result};
 use core::fmt;
 use der::{
@@ -18,6 +26,9 @@ use der::SecretDocument;
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
 
+#[cfg(feature = "pkcs8")]
+use crate::ALGORITHM_OID;
+
 /// `ECPrivateKey` version.
 ///
 /// From [RFC5913 Section 3]:
@@ -64,7 +75,7 @@ pub struct EcPrivateKey<'a> {
     pub private_key: &'a [u8],
 
     /// Elliptic curve parameters.
-    pub parameters: Option<EcParameters>,
+    pub parameters: Option<EcParameters<'a>>,
 
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
@@ -78,7 +89,8 @@ impl<'a> Decode<'a> for EcPrivateKey<'a> {
             }
 
             let private_key = decoder.octet_string()?.as_bytes();
-            let parameters = decoder.context_specific(EC_PARAMETERS_TAG, TagMode::Explicit)?;
+            let parameters = ContextSpecific::decode_explicit(decoder, EC_PARAMETERS_TAG)?
+                .map(|field| field.value);
             let public_key = decoder
                 .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, TagMode::Explicit)?
                 .map(|bs| bs.as_bytes().ok_or_else(|| Tag::BitString.value_error()))
@@ -128,6 +140,50 @@ impl<'a> TryFrom<&'a [u8]> for EcPrivateKey<'a> {
     }
 }
 
+impl<'a> EcPrivateKey<'a> {
+    /// Validate the private key scalar against the key's curve parameters,
+    /// catching corrupt or truncated keys.
+    ///
+    /// The scalar must be nonzero, and if the order of the curve is known,
+    /// either from [`NamedCurve`] or from specified curve parameters, less
+    /// than the order. For named curves, it must also be serialized using
+    /// [`NamedCurve::private_key_len`] bytes.
+    ///
+    /// Returns [`Error::Crypto`] if the scalar is invalid. The scalar is
+    /// compared against the order in constant time.
+    #[cfg(feature = "subtle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+    pub fn validate(&self) -> Result<()> {
+        match self.parameters {
+            Some(EcParameters::NamedCurve(oid)) => match NamedCurve::from_oid(oid) {
+                Some(curve) => curve.validate_private_key(self.private_key),
+                None => validate_scalar(self.private_key, None),
+            },
+            Some(EcParameters::SpecifiedCurve(domain)) => {
+                validate_scalar(self.private_key, Some(domain.order.as_bytes()))
+            }
+            None => validate_scalar(self.private_key, None),
+        }
+    }
+
+    /// Get the PKCS#8 [`AlgorithmIdentifier`][`pkcs8::AlgorithmIdentifier`]
+    /// for this key: `id-ecPublicKey` with the key's named curve as its
+    /// parameters.
+    ///
+    /// Returns `None` if the key doesn't have a named curve.
+    #[cfg(feature = "pkcs8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+    pub fn algorithm_identifier(&self) -> Option<pkcs8::AlgorithmIdentifier<'_>> {
+        match &self.parameters {
+            Some(EcParameters::NamedCurve(oid)) => Some(pkcs8::AlgorithmIdentifier {
+                oid: ALGORITHM_OID,
+                parameters: Some(oid.into()),
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> fmt::Debug for EcPrivateKey<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("EcPrivateKey")
@@ -137,6 +193,70 @@ impl<'a> fmt::Debug for EcPrivateKey<'a> {
     }
 }
 
+/// Extract the SEC1 key from a PKCS#8 `PrivateKeyInfo`, ensuring its
+/// algorithm is `id-ecPublicKey`.
+///
+/// The named curve from the algorithm parameters is copied into the key's
+/// `parameters`, and the public key of a PKCS#8 v2 key into its `public_key`,
+/// since they're usually omitted from keys embedded in PKCS#8. If they're
+/// present in the embedded key, they must be consistent with the PKCS#8 ones.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl<'a> TryFrom<pkcs8::PrivateKeyInfo<'a>> for EcPrivateKey<'a> {
+    type Error = Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'a>) -> Result<Self> {
+        private_key_info
+            .algorithm
+            .assert_algorithm_oid(ALGORITHM_OID)?;
+
+        let named_curve = private_key_info.algorithm.parameters_oid()?;
+        let mut private_key = Self::try_from(private_key_info.private_key)?;
+
+        match private_key.parameters {
+            Some(params) if params.named_curve() != Some(named_curve) => {
+                return Err(pkcs8::Error::ParametersMalformed.into())
+            }
+            _ => private_key.parameters = Some(EcParameters::NamedCurve(named_curve)),
+        }
+
+        match (private_key.public_key, private_key_info.public_key) {
+            (Some(pk), Some(other_pk)) if pk != other_pk => {
+                return Err(pkcs8::Error::KeyMalformed.into())
+            }
+            (None, public_key) => private_key.public_key = public_key,
+            _ => (),
+        }
+
+        Ok(private_key)
+    }
+}
+
+/// Wrap the SEC1 key in a PKCS#8 `PrivateKeyInfo` with the `id-ecPublicKey`
+/// algorithm, whose parameters are the key's named curve.
+///
+/// The `parameters` are omitted from the embedded SEC1 key, since they're
+/// redundant with the algorithm parameters.
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "pkcs8"))))]
+impl pkcs8::EncodePrivateKey for EcPrivateKey<'_> {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        let algorithm = self
+            .algorithm_identifier()
+            .ok_or(pkcs8::Error::ParametersMalformed)?;
+
+        let private_key = SecretDocument::encode_msg(&EcPrivateKey {
+            parameters: None,
+            ..self.clone()
+        })?;
+
+        SecretDocument::try_from(pkcs8::PrivateKeyInfo::new(
+            algorithm,
+            private_key.as_bytes(),
+        ))
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl TryFrom<EcPrivateKey<'_>> for SecretDocument {
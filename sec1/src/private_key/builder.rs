@@ -0,0 +1,93 @@
+//! Builder for SEC1 elliptic curve private keys.
+
+use super::owned::EcPrivateKeyOwned;
+use crate::{EcParameters, Error, Result, SpecifiedEcDomain};
+use der::asn1::ObjectIdentifier;
+
+/// Builder for an [`EcPrivateKeyOwned`] from its components.
+///
+/// ```
+/// # fn main() -> sec1::Result<()> {
+/// use sec1::{der::{asn1::ObjectIdentifier, Encode}, EcPrivateKeyBuilder};
+/// # let scalar = [1u8; 32];
+/// # let public_key = [4u8; 65];
+///
+/// let private_key = EcPrivateKeyBuilder::new(&scalar)
+///     .named_curve(ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"))
+///     .public_key(&public_key)
+///     .build()?;
+///
+/// let der = private_key.to_vec()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct EcPrivateKeyBuilder<'a> {
+    private_key: &'a [u8],
+    parameters: Option<EcParameters<'a>>,
+    public_key: Option<&'a [u8]>,
+}
+
+impl<'a> EcPrivateKeyBuilder<'a> {
+    /// Create a builder for the given big endian private scalar, which must
+    /// be serialized using the field size of the curve.
+    pub fn new(private_key: &'a [u8]) -> Self {
+        Self {
+            private_key,
+            parameters: None,
+            public_key: None,
+        }
+    }
+
+    /// Set the OID of the named curve.
+    pub fn named_curve(mut self, oid: ObjectIdentifier) -> Self {
+        self.parameters = Some(EcParameters::NamedCurve(oid));
+        self
+    }
+
+    /// Set explicitly specified curve domain parameters, for compatibility
+    /// with software which doesn't support named curves.
+    pub fn specified_curve(mut self, domain: SpecifiedEcDomain<'a>) -> Self {
+        self.parameters = Some(EcParameters::SpecifiedCurve(domain));
+        self
+    }
+
+    /// Set the SEC1-encoded public key point, which may be either compressed
+    /// or uncompressed.
+    pub fn public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Build the [`EcPrivateKeyOwned`].
+    ///
+    /// Returns [`Error::Crypto`] if the private key is empty, or
+    /// [`Error::PointEncoding`] if the public key isn't a compressed or
+    /// uncompressed point of the same size as the private key.
+    pub fn build(&self) -> Result<EcPrivateKeyOwned> {
+        let field_size = self.private_key.len();
+
+        if field_size == 0 {
+            return Err(Error::Crypto);
+        }
+
+        if let Some(public_key) = self.public_key {
+            let valid = match public_key.first() {
+                Some(2) | Some(3) => public_key.len() == field_size + 1,
+                Some(4) => public_key.len() == 2 * field_size + 1,
+                _ => false,
+            };
+
+            if !valid {
+                return Err(Error::PointEncoding);
+            }
+        }
+
+        Ok(EcPrivateKeyOwned {
+            private_key: self.private_key.into(),
+            parameters: self.parameters.as_ref().map(Into::into),
+            public_key: self.public_key.map(Into::into),
+        })
+    }
+}
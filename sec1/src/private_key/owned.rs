@@ -0,0 +1,304 @@
+//! Owned SEC1 elliptic curve private keys.
+
+use crate::{EcParameters, EcPrivateKey, Error, Result, SpecifiedEcDomain};
+use alloc::boxed::Box;
+use core::fmt;
+use der::{
+    asn1::{ObjectIdentifier, UIntBytes},
+    Decode, Decoder, Encode, Length, SecretDocument, Writer,
+};
+use zeroize::Zeroize;
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+/// Owned form of [`EcPrivateKey`], which doesn't borrow from the document it
+/// was decoded from.
+///
+/// The private key is zeroized on drop. Use [`EcPrivateKeyBuilder`] to
+/// construct keys from their components.
+///
+/// [`EcPrivateKeyBuilder`]: crate::EcPrivateKeyBuilder
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct EcPrivateKeyOwned {
+    /// Private key data.
+    pub private_key: Box<[u8]>,
+
+    /// Elliptic curve parameters.
+    pub parameters: Option<EcParametersOwned>,
+
+    /// Public key data, optionally available if version is V2.
+    pub public_key: Option<Box<[u8]>>,
+}
+
+impl EcPrivateKeyOwned {
+    /// Validate the private key scalar against the key's curve parameters.
+    ///
+    /// See [`EcPrivateKey::validate`] for details.
+    #[cfg(feature = "subtle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+    pub fn validate(&self) -> Result<()> {
+        self.borrow()?.validate()
+    }
+
+    /// Borrow this key as an [`EcPrivateKey`].
+    fn borrow(&self) -> der::Result<EcPrivateKey<'_>> {
+        Ok(EcPrivateKey {
+            private_key: &self.private_key,
+            parameters: self
+                .parameters
+                .as_ref()
+                .map(EcParametersOwned::borrow)
+                .transpose()?,
+            public_key: self.public_key.as_deref(),
+        })
+    }
+}
+
+impl From<EcPrivateKey<'_>> for EcPrivateKeyOwned {
+    fn from(private_key: EcPrivateKey<'_>) -> EcPrivateKeyOwned {
+        EcPrivateKeyOwned::from(&private_key)
+    }
+}
+
+impl From<&EcPrivateKey<'_>> for EcPrivateKeyOwned {
+    fn from(private_key: &EcPrivateKey<'_>) -> EcPrivateKeyOwned {
+        EcPrivateKeyOwned {
+            private_key: private_key.private_key.into(),
+            parameters: private_key.parameters.as_ref().map(Into::into),
+            public_key: private_key.public_key.map(Into::into),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a EcPrivateKeyOwned> for EcPrivateKey<'a> {
+    type Error = Error;
+
+    fn try_from(private_key: &'a EcPrivateKeyOwned) -> Result<EcPrivateKey<'a>> {
+        Ok(private_key.borrow()?)
+    }
+}
+
+impl<'a> Decode<'a> for EcPrivateKeyOwned {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        EcPrivateKey::decode(decoder).map(Into::into)
+    }
+}
+
+impl Encode for EcPrivateKeyOwned {
+    fn encoded_len(&self) -> der::Result<Length> {
+        self.borrow()?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut dyn Writer) -> der::Result<()> {
+        self.borrow()?.encode(encoder)
+    }
+}
+
+impl TryFrom<&[u8]> for EcPrivateKeyOwned {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl TryFrom<EcPrivateKeyOwned> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(private_key: EcPrivateKeyOwned) -> Result<Self> {
+        SecretDocument::try_from(&private_key)
+    }
+}
+
+impl TryFrom<&EcPrivateKeyOwned> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(private_key: &EcPrivateKeyOwned) -> Result<Self> {
+        Ok(Self::encode_msg(private_key)?)
+    }
+}
+
+impl Drop for EcPrivateKeyOwned {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl ConstantTimeEq for EcPrivateKeyOwned {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // NOTE: public fields are not compared in constant time
+        let public_fields_eq =
+            self.parameters == other.parameters && self.public_key == other.public_key;
+
+        self.private_key.ct_eq(&other.private_key) & Choice::from(public_fields_eq as u8)
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl Eq for EcPrivateKeyOwned {}
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl PartialEq for EcPrivateKeyOwned {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl fmt::Debug for EcPrivateKeyOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcPrivateKeyOwned")
+            .field("parameters", &self.parameters)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl TryFrom<pkcs8::PrivateKeyInfo<'_>> for EcPrivateKeyOwned {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        EcPrivateKey::try_from(private_key_info)
+            .map(Into::into)
+            .map_err(|err| match err {
+                Error::Asn1(err) => err.into(),
+                Error::Pkcs8(err) => err,
+                _ => pkcs8::Error::KeyMalformed,
+            })
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::DecodePrivateKey for EcPrivateKeyOwned {}
+
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::EncodePrivateKey for EcPrivateKeyOwned {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        self.borrow()?.to_pkcs8_der()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for EcPrivateKeyOwned {
+    const PEM_LABEL: &'static str = "EC PRIVATE KEY";
+}
+
+/// Owned form of [`EcParameters`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum EcParametersOwned {
+    /// Elliptic curve named by a particular OID.
+    NamedCurve(ObjectIdentifier),
+
+    /// Elliptic curve with explicitly specified domain parameters.
+    SpecifiedCurve(SpecifiedEcDomainOwned),
+}
+
+impl EcParametersOwned {
+    /// Obtain the `namedCurve` OID.
+    pub fn named_curve(&self) -> Option<ObjectIdentifier> {
+        match self {
+            Self::NamedCurve(oid) => Some(*oid),
+            Self::SpecifiedCurve(_) => None,
+        }
+    }
+
+    /// Borrow these parameters as [`EcParameters`].
+    fn borrow(&self) -> der::Result<EcParameters<'_>> {
+        match self {
+            Self::NamedCurve(oid) => Ok(EcParameters::NamedCurve(*oid)),
+            Self::SpecifiedCurve(domain) => domain.borrow().map(EcParameters::SpecifiedCurve),
+        }
+    }
+}
+
+impl From<&EcParameters<'_>> for EcParametersOwned {
+    fn from(params: &EcParameters<'_>) -> EcParametersOwned {
+        match params {
+            EcParameters::NamedCurve(oid) => Self::NamedCurve(*oid),
+            EcParameters::SpecifiedCurve(domain) => Self::SpecifiedCurve(domain.into()),
+        }
+    }
+}
+
+impl From<ObjectIdentifier> for EcParametersOwned {
+    fn from(oid: ObjectIdentifier) -> EcParametersOwned {
+        Self::NamedCurve(oid)
+    }
+}
+
+/// Owned form of [`SpecifiedEcDomain`].
+///
+/// Integers are stored as big endian bytes with leading zeroes stripped, as
+/// returned by [`UIntBytes::as_bytes`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct SpecifiedEcDomainOwned {
+    /// Version of the domain parameters, from 1 to 3.
+    pub version: u8,
+
+    /// Prime `p` of the underlying field.
+    pub prime: Box<[u8]>,
+
+    /// Curve coefficient `a` as a big endian field element.
+    pub a: Box<[u8]>,
+
+    /// Curve coefficient `b` as a big endian field element.
+    pub b: Box<[u8]>,
+
+    /// Seed used to generate the curve coefficients, if available.
+    pub seed: Option<Box<[u8]>>,
+
+    /// SEC1-encoded base point `G`.
+    pub base: Box<[u8]>,
+
+    /// Order `n` of the base point.
+    pub order: Box<[u8]>,
+
+    /// Cofactor `h`, if available.
+    pub cofactor: Option<Box<[u8]>>,
+}
+
+impl SpecifiedEcDomainOwned {
+    /// Borrow these domain parameters as a [`SpecifiedEcDomain`].
+    fn borrow(&self) -> der::Result<SpecifiedEcDomain<'_>> {
+        Ok(SpecifiedEcDomain {
+            version: self.version,
+            prime: UIntBytes::new(&self.prime)?,
+            a: &self.a,
+            b: &self.b,
+            seed: self.seed.as_deref(),
+            base: &self.base,
+            order: UIntBytes::new(&self.order)?,
+            cofactor: self.cofactor.as_deref().map(UIntBytes::new).transpose()?,
+        })
+    }
+}
+
+impl From<&SpecifiedEcDomain<'_>> for SpecifiedEcDomainOwned {
+    fn from(domain: &SpecifiedEcDomain<'_>) -> SpecifiedEcDomainOwned {
+        SpecifiedEcDomainOwned {
+            version: domain.version,
+            prime: domain.prime.as_bytes().into(),
+            a: domain.a.into(),
+            b: domain.b.into(),
+            seed: domain.seed.map(Into::into),
+            base: domain.base.into(),
+            order: domain.order.as_bytes().into(),
+            cofactor: domain.cofactor.map(|cofactor| cofactor.as_bytes().into()),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Crate-internal utilities.
+
+/// Decode a hexadecimal string at compile time.
+pub(crate) const fn hex<const N: usize>(hex: &str) -> [u8; N] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+
+    let hex = hex.as_bytes();
+    assert!(hex.len() == 2 * N, "invalid hex string length");
+
+    let mut bytes = [0u8; N];
+    let mut i = 0;
+
+    while i < N {
+        bytes[i] = (nibble(hex[2 * i]) << 4) | nibble(hex[2 * i + 1]);
+        i += 1;
+    }
+
+    bytes
+}
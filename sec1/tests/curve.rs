@@ -0,0 +1,45 @@
+//! Named curve registry tests
+
+#![cfg(feature = "der")]
+
+use der::asn1::ObjectIdentifier;
+use sec1::NamedCurve;
+
+#[test]
+fn from_oid() {
+    let curve = NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.3.132.0.35")).unwrap();
+    assert_eq!(curve, NamedCurve::SECP521R1);
+    assert_eq!(curve.private_key_len(), 66);
+    assert_eq!(curve.compressed_point_len(), 67);
+    assert_eq!(curve.uncompressed_point_len(), 133);
+
+    // `id-ecPublicKey` isn't a curve
+    assert_eq!(
+        NamedCurve::from_oid(ObjectIdentifier::new_unwrap("1.2.840.10045.2.1")),
+        None
+    );
+}
+
+#[test]
+fn from_name() {
+    for name in ["secp384r1", "P-384", "p-384"] {
+        assert_eq!(NamedCurve::from_name(name), Some(NamedCurve::SECP384R1));
+    }
+
+    assert_eq!(
+        NamedCurve::from_name("brainpoolP256r1"),
+        Some(NamedCurve::BRAINPOOL_P256R1)
+    );
+    assert_eq!(NamedCurve::from_name("curve25519"), None);
+}
+
+#[test]
+fn unique() {
+    for (i, curve) in NamedCurve::ALL.iter().enumerate() {
+        assert_eq!(NamedCurve::from_oid(curve.oid), Some(*curve));
+        assert_eq!(NamedCurve::from_name(curve.name), Some(*curve));
+        assert!(NamedCurve::ALL[..i]
+            .iter()
+            .all(|other| other.oid != curve.oid));
+    }
+}
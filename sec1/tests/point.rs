@@ -0,0 +1,135 @@
+//! Elliptic curve point tests
+
+#![cfg(feature = "point")]
+
+use hex_literal::hex;
+use sec1::consts::U32;
+
+#[cfg(feature = "decompress")]
+use sec1::{
+    consts::{U28, U48, U66},
+    point::{Tag, WeierstrassCurve},
+};
+
+type EncodedPoint = sec1::EncodedPoint<U32>;
+
+/// Example uncompressed point
+const UNCOMPRESSED_BYTES: [u8; 65] = hex!("0411111111111111111111111111111111111111111111111111111111111111112222222222222222222222222222222222222222222222222222222222222222");
+
+/// Example compressed point: `UNCOMPRESSED_BYTES` after point compression
+const COMPRESSED_BYTES: [u8; 33] =
+    hex!("021111111111111111111111111111111111111111111111111111111111111111");
+
+/// NIST P-256 generator point
+#[cfg(feature = "decompress")]
+const P256_G: [u8; 65] = hex!(
+    "046B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296
+     4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"
+);
+
+/// NIST P-256 generator point after point compression
+#[cfg(feature = "decompress")]
+const P256_G_COMPRESSED: [u8; 33] =
+    hex!("036B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296");
+
+#[test]
+fn affine_coordinates() {
+    let point = EncodedPoint::from_bytes(&UNCOMPRESSED_BYTES[..]).unwrap();
+    let (x, y) = point.affine_coordinates().unwrap();
+    assert_eq!(x.as_slice(), &UNCOMPRESSED_BYTES[1..33]);
+    assert_eq!(y.as_slice(), &UNCOMPRESSED_BYTES[33..]);
+    assert_eq!(
+        point.to_untagged_bytes().unwrap().as_slice(),
+        &UNCOMPRESSED_BYTES[1..]
+    );
+
+    let point = EncodedPoint::from_bytes(&COMPRESSED_BYTES[..]).unwrap();
+    assert_eq!(point.affine_coordinates(), None);
+    assert_eq!(point.to_untagged_bytes(), None);
+}
+
+#[cfg(feature = "decompress")]
+#[test]
+fn decompress() {
+    let compressed_point = EncodedPoint::from_bytes(&P256_G_COMPRESSED[..]).unwrap();
+    let point = compressed_point
+        .decompress(&WeierstrassCurve::NIST_P256)
+        .unwrap();
+    assert_eq!(point.as_bytes(), &P256_G[..]);
+    assert_eq!(point.compress(), compressed_point);
+
+    // Uncompressed points are returned unchanged
+    assert_eq!(
+        point.decompress(&WeierstrassCurve::NIST_P256).unwrap(),
+        point
+    );
+}
+
+#[cfg(feature = "decompress")]
+#[test]
+fn decompress_other_curves() {
+    let g = hex!(
+        "04B70E0CBD6BB4BF7F321390B94A03C1D356C21122343280D6115C1D21
+         BD376388B5F723FB4C22DFE6CD4375A05A07476444D5819985007E34"
+    );
+    let point = sec1::EncodedPoint::<U28>::from_bytes(&g[..]).unwrap();
+    let decompressed = point.compress().decompress(&WeierstrassCurve::NIST_P224);
+    assert_eq!(decompressed.unwrap(), point);
+
+    let g = hex!(
+        "0479BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798
+         483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"
+    );
+    let point = EncodedPoint::from_bytes(&g[..]).unwrap();
+    let decompressed = point.compress().decompress(&WeierstrassCurve::SECP256K1);
+    assert_eq!(decompressed.unwrap(), point);
+
+    let g = hex!(
+        "04AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A38
+         5502F25DBF55296C3A545E3872760AB73617DE4A96262C6F5D9E98BF9292DC29F8
+         F41DBD289A147CE9DA3113B5F0B8C00A60B1CE1D7E819D7A431D7C90EA0E5F"
+    );
+    let point = sec1::EncodedPoint::<U48>::from_bytes(&g[..]).unwrap();
+    let decompressed = point.compress().decompress(&WeierstrassCurve::NIST_P384);
+    assert_eq!(decompressed.unwrap(), point);
+
+    let g = hex!(
+        "0400C6858E06B70404E9CD9E3ECB662395B4429C648139053FB521F828AF606B4D
+         3DBAA14B5E77EFE75928FE1DC127A2FFA8DE3348B3C1856A429BF97E7E31C2E5BD
+         66011839296A789A3BC0045C8A5FB42C7D1BD998F54449579B446817AFBD17273E
+         662C97EE72995EF42640C550B9013FAD0761353C7086A272C24088BE94769FD166
+         50"
+    );
+    let point = sec1::EncodedPoint::<U66>::from_bytes(&g[..]).unwrap();
+    let decompressed = point.compress().decompress(&WeierstrassCurve::NIST_P521);
+    assert_eq!(decompressed.unwrap(), point);
+}
+
+#[cfg(feature = "decompress")]
+#[test]
+fn decompress_compact() {
+    let mut compact_bytes = P256_G_COMPRESSED;
+    compact_bytes[0] = Tag::Compact.into();
+
+    // The generator's y-coordinate is the smaller of the two candidates
+    let compact_point = EncodedPoint::from_bytes(&compact_bytes[..]).unwrap();
+    let point = compact_point
+        .decompress(&WeierstrassCurve::NIST_P256)
+        .unwrap();
+    assert_eq!(point.as_bytes(), &P256_G[..]);
+}
+
+#[cfg(feature = "decompress")]
+#[test]
+fn decompress_invalid() {
+    // Wrong field size
+    let point = EncodedPoint::from_bytes(&P256_G_COMPRESSED[..]).unwrap();
+    assert!(point.decompress(&WeierstrassCurve::NIST_P224).is_err());
+
+    // `x = 1` is not on P-256
+    let mut bytes = [0u8; 33];
+    bytes[0] = Tag::CompressedEvenY.into();
+    bytes[32] = 1;
+    let point = EncodedPoint::from_bytes(&bytes[..]).unwrap();
+    assert!(point.decompress(&WeierstrassCurve::NIST_P256).is_err());
+}
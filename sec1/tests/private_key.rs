@@ -12,6 +12,13 @@ use sec1::{EcParameters, EcPrivateKey};
 /// example key in the `pkcs8` crate.
 const P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
 
+/// NIST P-256 SEC1 private key with explicitly specified curve parameters
+/// encoded as ASN.1 DER.
+///
+/// Generated using:
+/// $ openssl ec -in p256-priv.pem -param_enc explicit -outform DER -out p256-priv-explicit.der
+const P256_EXPLICIT_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv-explicit.der");
+
 #[test]
 fn decode_p256_der() {
     let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
@@ -30,3 +37,226 @@ fn decode_p256_der() {
     );
     assert_eq!(key.public_key, Some(hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F").as_ref()));
 }
+
+#[test]
+fn decode_p256_explicit_der() {
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        key.private_key,
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D")
+    );
+
+    let domain = key.parameters.unwrap().specified_curve().unwrap();
+    assert_eq!(domain.version, 1);
+    assert_eq!(
+        domain.prime.as_bytes(),
+        hex!("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF")
+    );
+    assert_eq!(
+        domain.a,
+        hex!("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC")
+    );
+    assert_eq!(
+        domain.b,
+        hex!("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B")
+    );
+    assert_eq!(
+        domain.seed,
+        Some(hex!("C49D360886E704936A6678E1139D26B7819F7E90").as_ref())
+    );
+    assert_eq!(domain.base, hex!("046B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C2964FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"));
+    assert_eq!(
+        domain.order.as_bytes(),
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551")
+    );
+    assert_eq!(domain.cofactor.unwrap().as_bytes(), [1]);
+    assert_eq!(key.parameters.unwrap().named_curve(), None);
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn validate() {
+    EcPrivateKey::try_from(P256_DER_EXAMPLE)
+        .unwrap()
+        .validate()
+        .unwrap();
+    EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE)
+        .unwrap()
+        .validate()
+        .unwrap();
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn validate_out_of_range() {
+    let order = hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+    let order_minus_one = hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550");
+
+    for key in [P256_DER_EXAMPLE, P256_EXPLICIT_DER_EXAMPLE] {
+        let key = EcPrivateKey::try_from(key).unwrap();
+
+        for (private_key, valid) in [
+            (&order_minus_one[..], true),
+            (&order[..], false),
+            (
+                &hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632552")[..],
+                false,
+            ),
+            (
+                &hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632451")[..],
+                true,
+            ),
+            (
+                &hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632651")[..],
+                false,
+            ),
+            (&[0xFF; 32][..], false),
+            (&[0; 32][..], false),
+        ] {
+            let key = EcPrivateKey {
+                private_key,
+                ..key.clone()
+            };
+            assert_eq!(key.validate().is_ok(), valid);
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn validate_truncated() {
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    let key = EcPrivateKey {
+        private_key: &key.private_key[1..],
+        ..key
+    };
+    assert_eq!(key.validate(), Err(sec1::Error::Crypto));
+
+    // Without parameters only the scalar being nonzero can be checked
+    let key = EcPrivateKey {
+        parameters: None,
+        ..key
+    };
+    assert_eq!(key.validate(), Ok(()));
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn validate_explicit_order_with_leading_zeroes() {
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    let order_minus_one =
+        hex!("00FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550");
+
+    for (private_key, valid) in [
+        (&order_minus_one[..], true),
+        (&order_minus_one[2..], true),
+        (&[0, 1][..], true),
+        (&[0; 33][..], false),
+    ] {
+        let key = EcPrivateKey {
+            private_key,
+            ..key.clone()
+        };
+        assert_eq!(key.validate().is_ok(), valid);
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "subtle"))]
+#[test]
+fn owned_constant_time_eq() {
+    use subtle::ConstantTimeEq;
+
+    let key = sec1::EcPrivateKeyOwned::try_from(P256_DER_EXAMPLE).unwrap();
+    let mut other = key.clone();
+    assert!(bool::from(key.ct_eq(&other)));
+    assert_eq!(key, other);
+
+    other.private_key[31] ^= 1;
+    assert!(!bool::from(key.ct_eq(&other)));
+    assert_ne!(key, other);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_p256_explicit_der() {
+    use der::Encode;
+
+    let key = EcPrivateKey::try_from(P256_EXPLICIT_DER_EXAMPLE).unwrap();
+    assert_eq!(key.to_vec().unwrap(), P256_EXPLICIT_DER_EXAMPLE);
+
+    let owned = sec1::EcPrivateKeyOwned::from(&key);
+    assert_eq!(owned.to_vec().unwrap(), P256_EXPLICIT_DER_EXAMPLE);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_p256_der_owned() {
+    let key = sec1::EcPrivateKeyOwned::try_from(P256_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        &*key.private_key,
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D")
+    );
+    assert_eq!(
+        EcPrivateKey::try_from(&key).unwrap().public_key,
+        key.public_key.as_deref()
+    );
+
+    let der = der::SecretDocument::try_from(&key).unwrap();
+    assert_eq!(der.as_bytes(), P256_DER_EXAMPLE);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn build_p256_der() {
+    use der::Encode;
+
+    let key = sec1::EcPrivateKeyBuilder::new(&hex!(
+        "69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D"
+    ))
+    .named_curve(ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"))
+    .public_key(&hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F"))
+    .build()
+    .unwrap();
+
+    assert_eq!(key.to_vec().unwrap(), P256_DER_EXAMPLE);
+}
+
+#[cfg(feature = "pem")]
+#[test]
+fn build_p256_pem() {
+    use der::pem::{LineEnding, PemLabel};
+
+    let scalar = hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+    let public_key = hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+    let key = sec1::EcPrivateKeyBuilder::new(&scalar)
+        .named_curve(ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"))
+        .public_key(&public_key)
+        .build()
+        .unwrap();
+
+    let pem = der::SecretDocument::try_from(&key)
+        .unwrap()
+        .to_pem(sec1::EcPrivateKeyOwned::PEM_LABEL, LineEnding::LF)
+        .unwrap();
+    assert_eq!(
+        pem.trim_end(),
+        include_str!("examples/p256-priv.pem").trim_end()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn build_invalid_public_key() {
+    let scalar = hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+
+    // Compressed point tag with the length of an uncompressed point
+    let mut public_key = [0u8; 65];
+    public_key[0] = 0x02;
+
+    let builder = sec1::EcPrivateKeyBuilder::new(&scalar).public_key(&public_key);
+    assert_eq!(builder.build().err(), Some(sec1::Error::PointEncoding));
+    assert_eq!(
+        sec1::EcPrivateKeyBuilder::new(&[]).build().err(),
+        Some(sec1::Error::Crypto)
+    );
+}
@@ -1,9 +1,9 @@
 //! X.509 `AlgorithmIdentifier`
 
-use crate::{Error, Result};
+use crate::{AlgorithmKind, Error, Result};
 use core::cmp::Ordering;
 use der::asn1::{Any, ObjectIdentifier, SequenceRef};
-use der::{Decode, DecodeValue, Decoder, DerOrd, Encode, Header, Sequence, ValueOrd};
+use der::{Decode, DecodeValue, Decoder, DerOrd, Encode, Header, Sequence, Tag, Tagged, ValueOrd};
 
 /// X.509 `AlgorithmIdentifier` as defined in [RFC 5280 Section 4.1.1.2].
 ///
@@ -59,6 +59,54 @@ impl<'a> AlgorithmIdentifier<'a> {
         Ok(())
     }
 
+    /// Classify the `algorithm` OID, returning `None` if it's unknown.
+    pub fn kind(&self) -> Option<AlgorithmKind> {
+        AlgorithmKind::from_oid(self.oid)
+    }
+
+    /// Get the `parameters` field as [`AlgorithmParameters`], interpreting
+    /// them according to the `algorithm` OID.
+    ///
+    /// Returns an error if the parameters of a known algorithm have an
+    /// unexpected type.
+    pub fn typed_parameters(&self) -> Result<AlgorithmParameters<'a>> {
+        let params = match self.parameters {
+            None => return Ok(AlgorithmParameters::None),
+            Some(Any::NULL) => return Ok(AlgorithmParameters::Null),
+            Some(params) => params,
+        };
+
+        match (self.kind(), params.tag()) {
+            (Some(AlgorithmKind::Ec), Tag::ObjectIdentifier) => {
+                Ok(AlgorithmParameters::NamedCurve(params.oid()?))
+            }
+            (Some(AlgorithmKind::Ec), Tag::Sequence) => Ok(AlgorithmParameters::Any(params)),
+            (Some(AlgorithmKind::RsaPss), Tag::Sequence) => Ok(AlgorithmParameters::RsaPss(params)),
+            (Some(AlgorithmKind::Ec), tag) | (Some(AlgorithmKind::RsaPss), tag) => {
+                Err(tag.unexpected_error(None).into())
+            }
+            _ => Ok(AlgorithmParameters::Any(params)),
+        }
+    }
+
+    /// Compare this [`AlgorithmIdentifier`] to `other` using the given
+    /// [`MatchMode`].
+    pub fn matches(&self, other: &AlgorithmIdentifier<'_>, mode: MatchMode) -> bool {
+        if self.oid != other.oid {
+            return false;
+        }
+
+        match mode {
+            MatchMode::RsaNullEqualsAbsent if self.kind() == Some(AlgorithmKind::Rsa) => {
+                let is_null_or_absent = |params: Option<Any<'_>>| params.map_or(true, Any::is_null);
+
+                self.parameters == other.parameters
+                    || (is_null_or_absent(self.parameters) && is_null_or_absent(other.parameters))
+            }
+            _ => self.parameters == other.parameters,
+        }
+    }
+
     /// Get the `parameters` field as an [`Any`].
     ///
     /// Returns an error if `parameters` are `None`.
@@ -94,6 +142,62 @@ impl<'a> AlgorithmIdentifier<'a> {
     }
 }
 
+/// `parameters` of an [`AlgorithmIdentifier`], as interpreted by
+/// [`AlgorithmIdentifier::typed_parameters`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AlgorithmParameters<'a> {
+    /// `parameters` are absent.
+    None,
+
+    /// `parameters` are `NULL`.
+    Null,
+
+    /// OID of the named curve of an `id-ecPublicKey` key.
+    NamedCurve(ObjectIdentifier),
+
+    /// `RSASSA-PSS-params` of an `id-RSASSA-PSS` key, as defined in
+    /// [RFC 4055 § 3.1].
+    ///
+    /// These are guaranteed to be a `SEQUENCE`, and can be decoded using
+    /// e.g. `pkcs1::RsaPssParams`.
+    ///
+    /// [RFC 4055 § 3.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-3.1
+    RsaPss(Any<'a>),
+
+    /// Any other `parameters`.
+    Any(Any<'a>),
+}
+
+impl<'a> TryFrom<&AlgorithmIdentifier<'a>> for AlgorithmParameters<'a> {
+    type Error = Error;
+
+    fn try_from(algorithm: &AlgorithmIdentifier<'a>) -> Result<Self> {
+        algorithm.typed_parameters()
+    }
+}
+
+/// How [`AlgorithmIdentifier::matches`] compares `parameters`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MatchMode {
+    /// `parameters` must be identical.
+    Exact,
+
+    /// Treat `NULL` and absent `parameters` as equal for `rsaEncryption`.
+    ///
+    /// [RFC 3279 § 2.3.1] requires `NULL`, but encoders which omit them are
+    /// common in the wild.
+    ///
+    /// [RFC 3279 § 2.3.1]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.1
+    RsaNullEqualsAbsent,
+}
+
+impl Default for MatchMode {
+    fn default() -> Self {
+        Self::Exact
+    }
+}
+
 impl<'a> DecodeValue<'a> for AlgorithmIdentifier<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
         SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
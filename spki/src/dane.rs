@@ -0,0 +1,150 @@
+//! DANE TLSA record support.
+
+use crate::{Result, SubjectPublicKeyInfo};
+use alloc::vec::Vec;
+use core::fmt;
+use der::Encode;
+use sha2::{Digest, Sha256, Sha512};
+
+/// TLSA certificate usage field, as defined in [RFC 6698 § 2.1.1].
+///
+/// [RFC 6698 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.1
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TlsaUsage {
+    /// CA constraint (`PKIX-TA`).
+    PkixTa = 0,
+
+    /// Service certificate constraint (`PKIX-EE`).
+    PkixEe = 1,
+
+    /// Trust anchor assertion (`DANE-TA`).
+    DaneTa = 2,
+
+    /// Domain-issued certificate (`DANE-EE`).
+    DaneEe = 3,
+}
+
+/// TLSA selector field, as defined in [RFC 6698 § 2.1.2].
+///
+/// [RFC 6698 § 2.1.2]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.2
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TlsaSelector {
+    /// Full certificate (`Cert`).
+    Cert = 0,
+
+    /// `SubjectPublicKeyInfo` (`SPKI`).
+    Spki = 1,
+}
+
+/// TLSA matching type field, as defined in [RFC 6698 § 2.1.3].
+///
+/// [RFC 6698 § 2.1.3]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1.3
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum TlsaMatchingType {
+    /// Exact match on the selected content (`Full`).
+    Full = 0,
+
+    /// SHA-256 hash of the selected content (`SHA2-256`).
+    Sha256 = 1,
+
+    /// SHA-512 hash of the selected content (`SHA2-512`).
+    Sha512 = 2,
+}
+
+/// DANE TLSA record data, as defined in [RFC 6698 § 2.1].
+///
+/// The [`Display`][`fmt::Display`] impl produces the zone file presentation
+/// format, e.g. `3 1 1 B9968D56...`, and [`TlsaRecord::to_rdata`] produces
+/// the wire format.
+///
+/// [RFC 6698 § 2.1]: https://datatracker.ietf.org/doc/html/rfc6698#section-2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "fingerprint"))))]
+pub struct TlsaRecord {
+    /// Certificate usage.
+    pub usage: TlsaUsage,
+
+    /// Selector for the part of the certificate which is matched.
+    pub selector: TlsaSelector,
+
+    /// How the selected content is matched.
+    pub matching_type: TlsaMatchingType,
+
+    /// Certificate association data, i.e. the selected content or its hash.
+    pub data: Vec<u8>,
+}
+
+impl TlsaRecord {
+    /// Create a record from the DER encoding of the content chosen by
+    /// `selector`, i.e. either a certificate or a `SubjectPublicKeyInfo`,
+    /// hashing it as specified by `matching_type`.
+    pub fn new(
+        usage: TlsaUsage,
+        selector: TlsaSelector,
+        matching_type: TlsaMatchingType,
+        der_bytes: &[u8],
+    ) -> Self {
+        let data = match matching_type {
+            TlsaMatchingType::Full => der_bytes.to_vec(),
+            TlsaMatchingType::Sha256 => Sha256::digest(der_bytes).to_vec(),
+            TlsaMatchingType::Sha512 => Sha512::digest(der_bytes).to_vec(),
+        };
+
+        Self {
+            usage,
+            selector,
+            matching_type,
+            data,
+        }
+    }
+
+    /// Create a record for a [`SubjectPublicKeyInfo`], using the `SPKI`
+    /// selector.
+    pub fn from_spki(
+        usage: TlsaUsage,
+        matching_type: TlsaMatchingType,
+        spki: &SubjectPublicKeyInfo<'_>,
+    ) -> Result<Self> {
+        let data = match matching_type {
+            TlsaMatchingType::Full => spki.to_vec()?,
+            TlsaMatchingType::Sha256 => spki.fingerprint::<Sha256>()?.as_bytes().to_vec(),
+            TlsaMatchingType::Sha512 => spki.fingerprint::<Sha512>()?.as_bytes().to_vec(),
+        };
+
+        Ok(Self {
+            usage,
+            selector: TlsaSelector::Spki,
+            matching_type,
+            data,
+        })
+    }
+
+    /// Serialize the record data in DNS wire format.
+    pub fn to_rdata(&self) -> Vec<u8> {
+        let mut rdata = Vec::with_capacity(3 + self.data.len());
+        rdata.push(self.usage as u8);
+        rdata.push(self.selector as u8);
+        rdata.push(self.matching_type as u8);
+        rdata.extend_from_slice(&self.data);
+        rdata
+    }
+}
+
+impl fmt::Display for TlsaRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} ",
+            self.usage as u8, self.selector as u8, self.matching_type as u8
+        )?;
+
+        for byte in &self.data {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
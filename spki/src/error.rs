@@ -25,6 +25,11 @@ pub enum Error {
     /// in [`SubjectPublicKeyInfo::subject_public_key`][`crate::SubjectPublicKeyInfo::subject_public_key`].
     KeyMalformed,
 
+    /// Malformed or unsupported OpenSSH public key.
+    #[cfg(feature = "openssh")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "openssh")))]
+    OpenSsh,
+
     /// Unknown algorithm OID.
     OidUnknown {
         /// Unrecognized OID value found in e.g. a SPKI `AlgorithmIdentifier`.
@@ -40,6 +45,8 @@ impl fmt::Display for Error {
             }
             Error::Asn1(err) => write!(f, "ASN.1 error: {}", err),
             Error::KeyMalformed => f.write_str("SPKI cryptographic key data malformed"),
+            #[cfg(feature = "openssh")]
+            Error::OpenSsh => f.write_str("malformed or unsupported OpenSSH public key"),
             Error::OidUnknown { oid } => {
                 write!(f, "unknown/unsupported algorithm OID: {}", oid)
             }
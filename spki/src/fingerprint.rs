@@ -1,9 +1,17 @@
 //! SPKI fingerprint support.
 
+use core::fmt;
 use der::Writer;
-use sha2::{Digest, Sha256};
+use digest::{Digest, Output};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::string::String,
+    base64ct::{Base64, Encoding},
+};
 
 /// Size of a SHA-256 SPKI fingerprint in bytes.
+#[cfg(feature = "fingerprint")]
 pub(crate) const SIZE: usize = 32;
 
 /// Raw bytes of a SPKI fingerprint i.e. SHA-256 digest of
@@ -12,30 +20,109 @@ pub(crate) const SIZE: usize = 32;
 /// See [RFC7469 § 2.1.1] for more information.
 ///
 /// [RFC7469 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1.1
+#[cfg(feature = "fingerprint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
 pub type FingerprintBytes = [u8; SIZE];
 
+/// Fingerprint of a `SubjectPublicKeyInfo`, i.e. the digest of its DER
+/// encoding computed using the hash function `D`.
+///
+/// Formatting with `{:x}` or `{:X}` produces lowercase or uppercase hex, as
+/// used by e.g. DANE TLSA records.
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub struct Fingerprint<D: Digest> {
+    /// Raw digest output.
+    bytes: Output<D>,
+}
+
+impl<D: Digest> Fingerprint<D> {
+    /// Borrow the raw digest bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get the raw digest output.
+    pub fn into_bytes(self) -> Output<D> {
+        self.bytes
+    }
+
+    /// Encode this fingerprint as a Base64 string, as used by e.g. HPKP pins.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_base64(&self) -> String {
+        Base64::encode_string(&self.bytes)
+    }
+}
+
+impl<D: Digest> AsRef<[u8]> for Fingerprint<D> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<D: Digest> Clone for Fingerprint<D> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<D: Digest> Eq for Fingerprint<D> {}
+
+impl<D: Digest> PartialEq for Fingerprint<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<D: Digest> fmt::Debug for Fingerprint<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint({:X})", self)
+    }
+}
+
+impl<D: Digest> fmt::LowerHex for Fingerprint<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: Digest> fmt::UpperHex for Fingerprint<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 /// Writer newtype which accepts DER being serialized on-the-fly and computes a
 /// hash of the contents.
-#[derive(Clone, Default)]
-pub(crate) struct Builder {
+#[derive(Clone)]
+pub(crate) struct Builder<D> {
     /// In-progress digest being computed from streaming DER.
-    digest: Sha256,
+    digest: D,
 }
 
-impl Builder {
+impl<D: Digest> Builder<D> {
     /// Create a new fingerprint builder.
     pub fn new() -> Self {
-        Self::default()
+        Self { digest: D::new() }
     }
 
     /// Finish computing a fingerprint, returning the computed digest.
-    pub fn finish(self) -> FingerprintBytes {
-        self.digest.finalize().into()
+    pub fn finish(self) -> Fingerprint<D> {
+        Fingerprint {
+            bytes: self.digest.finalize(),
+        }
     }
 }
 
-impl Writer for Builder {
+impl<D: Digest> Writer for Builder<D> {
     fn write(&mut self, der_bytes: &[u8]) -> der::Result<()> {
         self.digest.update(der_bytes);
         Ok(())
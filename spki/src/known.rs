@@ -0,0 +1,338 @@
+//! Registry of well-known public key algorithms.
+
+use crate::{AlgorithmIdentifier, Result};
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Tag, Tagged,
+};
+
+/// `rsaEncryption` Object Identifier (OID).
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `id-RSASSA-PSS` Object Identifier (OID).
+const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+
+/// `id-ecPublicKey` Object Identifier (OID).
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `id-X25519` Object Identifier (OID).
+const X25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.110");
+
+/// `id-X448` Object Identifier (OID).
+const X448_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.111");
+
+/// `id-Ed25519` Object Identifier (OID).
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// `id-Ed448` Object Identifier (OID).
+const ED448_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.113");
+
+/// Kind of public key, as identified by the `algorithm` OID of an
+/// [`AlgorithmIdentifier`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AlgorithmKind {
+    /// RSA key usable with any RSA scheme (`rsaEncryption`).
+    Rsa,
+
+    /// RSA key restricted to RSASSA-PSS signatures (`id-RSASSA-PSS`).
+    RsaPss,
+
+    /// Elliptic curve key (`id-ecPublicKey`), with the curve given by the
+    /// `parameters`.
+    Ec,
+
+    /// X25519 key agreement key.
+    X25519,
+
+    /// X448 key agreement key.
+    X448,
+
+    /// Ed25519 signing key.
+    Ed25519,
+
+    /// Ed448 signing key.
+    Ed448,
+}
+
+impl AlgorithmKind {
+    /// Classify an `algorithm` OID, returning `None` if it's unknown.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        match oid {
+            RSA_ENCRYPTION_OID => Some(Self::Rsa),
+            RSASSA_PSS_OID => Some(Self::RsaPss),
+            EC_PUBLIC_KEY_OID => Some(Self::Ec),
+            X25519_OID => Some(Self::X25519),
+            X448_OID => Some(Self::X448),
+            ED25519_OID => Some(Self::Ed25519),
+            ED448_OID => Some(Self::Ed448),
+            _ => None,
+        }
+    }
+
+    /// Get the `algorithm` OID for this kind of key.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::Rsa => RSA_ENCRYPTION_OID,
+            Self::RsaPss => RSASSA_PSS_OID,
+            Self::Ec => EC_PUBLIC_KEY_OID,
+            Self::X25519 => X25519_OID,
+            Self::X448 => X448_OID,
+            Self::Ed25519 => ED25519_OID,
+            Self::Ed448 => ED448_OID,
+        }
+    }
+
+    /// Get the length in bytes of public keys of this kind, for the
+    /// algorithms defined in [RFC 8410], whose keys have a fixed size.
+    ///
+    /// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410
+    pub fn rfc8410_key_len(self) -> Option<usize> {
+        match self {
+            Self::X25519 | Self::Ed25519 => Some(32),
+            Self::X448 => Some(56),
+            Self::Ed448 => Some(57),
+            Self::Rsa | Self::RsaPss | Self::Ec => None,
+        }
+    }
+}
+
+/// Parameters of a [`KnownAlgorithm`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KnownParameters {
+    /// `parameters` are absent.
+    Absent,
+
+    /// `parameters` are `NULL`.
+    ///
+    /// Absent parameters are also accepted when matching, since some
+    /// encoders omit them.
+    Null,
+
+    /// `parameters` are the OID of a named elliptic curve.
+    NamedCurve(&'static ObjectIdentifier),
+
+    /// `parameters` are a `SEQUENCE` with the given DER-encoded body, which
+    /// must match exactly.
+    Sequence(&'static [u8]),
+}
+
+/// Well-known [`AlgorithmIdentifier`] for a public key, consisting of an
+/// algorithm and a conventional choice of parameters.
+///
+/// ```
+/// use spki::{AlgorithmKind, KnownAlgorithm};
+///
+/// let algorithm = KnownAlgorithm::EC_P256.algorithm_identifier()?;
+/// assert_eq!(algorithm.kind(), Some(AlgorithmKind::Ec));
+/// assert_eq!(KnownAlgorithm::from_algorithm_identifier(&algorithm), Some(KnownAlgorithm::EC_P256));
+/// assert_eq!(KnownAlgorithm::from_name("p-256"), Some(KnownAlgorithm::EC_P256));
+/// # Ok::<(), spki::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KnownAlgorithm {
+    /// Name of the algorithm, e.g. `P-256`.
+    pub name: &'static str,
+
+    /// Kind of key, which determines the `algorithm` OID.
+    pub kind: AlgorithmKind,
+
+    /// Algorithm `parameters`.
+    pub parameters: KnownParameters,
+}
+
+impl KnownAlgorithm {
+    /// RSA key with `NULL` parameters, as specified in [RFC 3279 § 2.3.1].
+    ///
+    /// [RFC 3279 § 2.3.1]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.1
+    pub const RSA: Self = Self {
+        name: "RSA",
+        kind: AlgorithmKind::Rsa,
+        parameters: KnownParameters::Null,
+    };
+
+    /// RSASSA-PSS key without parameter restrictions, as specified in
+    /// [RFC 4055 § 1.2].
+    ///
+    /// [RFC 4055 § 1.2]: https://datatracker.ietf.org/doc/html/rfc4055#section-1.2
+    pub const RSA_PSS: Self = Self {
+        name: "RSA-PSS",
+        kind: AlgorithmKind::RsaPss,
+        parameters: KnownParameters::Absent,
+    };
+
+    /// RSASSA-PSS key restricted to SHA-256 with MGF1-SHA-256 and a 32-byte
+    /// salt.
+    pub const RSA_PSS_SHA256: Self = Self {
+        name: "RSA-PSS-SHA256",
+        kind: AlgorithmKind::RsaPss,
+        parameters: KnownParameters::Sequence(&[
+            0xA0, 0x0F, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x01, 0x05, 0x00, 0xA1, 0x1C, 0x30, 0x1A, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7,
+            0x0D, 0x01, 0x01, 0x08, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03,
+            0x04, 0x02, 0x01, 0x05, 0x00, 0xA2, 0x03, 0x02, 0x01, 0x20,
+        ]),
+    };
+
+    /// RSASSA-PSS key restricted to SHA-384 with MGF1-SHA-384 and a 48-byte
+    /// salt.
+    pub const RSA_PSS_SHA384: Self = Self {
+        name: "RSA-PSS-SHA384",
+        kind: AlgorithmKind::RsaPss,
+        parameters: KnownParameters::Sequence(&[
+            0xA0, 0x0F, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x02, 0x05, 0x00, 0xA1, 0x1C, 0x30, 0x1A, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7,
+            0x0D, 0x01, 0x01, 0x08, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03,
+            0x04, 0x02, 0x02, 0x05, 0x00, 0xA2, 0x03, 0x02, 0x01, 0x30,
+        ]),
+    };
+
+    /// RSASSA-PSS key restricted to SHA-512 with MGF1-SHA-512 and a 64-byte
+    /// salt.
+    pub const RSA_PSS_SHA512: Self = Self {
+        name: "RSA-PSS-SHA512",
+        kind: AlgorithmKind::RsaPss,
+        parameters: KnownParameters::Sequence(&[
+            0xA0, 0x0F, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02,
+            0x03, 0x05, 0x00, 0xA1, 0x1C, 0x30, 0x1A, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7,
+            0x0D, 0x01, 0x01, 0x08, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03,
+            0x04, 0x02, 0x03, 0x05, 0x00, 0xA2, 0x03, 0x02, 0x01, 0x40,
+        ]),
+    };
+
+    /// Elliptic curve key on NIST P-256.
+    pub const EC_P256: Self = Self {
+        name: "P-256",
+        kind: AlgorithmKind::Ec,
+        parameters: KnownParameters::NamedCurve(&ObjectIdentifier::new_unwrap(
+            "1.2.840.10045.3.1.7",
+        )),
+    };
+
+    /// Elliptic curve key on NIST P-384.
+    pub const EC_P384: Self = Self {
+        name: "P-384",
+        kind: AlgorithmKind::Ec,
+        parameters: KnownParameters::NamedCurve(&ObjectIdentifier::new_unwrap("1.3.132.0.34")),
+    };
+
+    /// Elliptic curve key on NIST P-521.
+    pub const EC_P521: Self = Self {
+        name: "P-521",
+        kind: AlgorithmKind::Ec,
+        parameters: KnownParameters::NamedCurve(&ObjectIdentifier::new_unwrap("1.3.132.0.35")),
+    };
+
+    /// Elliptic curve key on secp256k1.
+    pub const EC_SECP256K1: Self = Self {
+        name: "secp256k1",
+        kind: AlgorithmKind::Ec,
+        parameters: KnownParameters::NamedCurve(&ObjectIdentifier::new_unwrap("1.3.132.0.10")),
+    };
+
+    /// X25519 key, as specified in [RFC 8410 § 3].
+    ///
+    /// [RFC 8410 § 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+    pub const X25519: Self = Self {
+        name: "X25519",
+        kind: AlgorithmKind::X25519,
+        parameters: KnownParameters::Absent,
+    };
+
+    /// X448 key, as specified in [RFC 8410 § 3].
+    ///
+    /// [RFC 8410 § 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+    pub const X448: Self = Self {
+        name: "X448",
+        kind: AlgorithmKind::X448,
+        parameters: KnownParameters::Absent,
+    };
+
+    /// Ed25519 key, as specified in [RFC 8410 § 3].
+    ///
+    /// [RFC 8410 § 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+    pub const ED25519: Self = Self {
+        name: "Ed25519",
+        kind: AlgorithmKind::Ed25519,
+        parameters: KnownParameters::Absent,
+    };
+
+    /// Ed448 key, as specified in [RFC 8410 § 3].
+    ///
+    /// [RFC 8410 § 3]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+    pub const ED448: Self = Self {
+        name: "Ed448",
+        kind: AlgorithmKind::Ed448,
+        parameters: KnownParameters::Absent,
+    };
+
+    /// All algorithms in the registry.
+    pub const ALL: &'static [Self] = &[
+        Self::RSA,
+        Self::RSA_PSS,
+        Self::RSA_PSS_SHA256,
+        Self::RSA_PSS_SHA384,
+        Self::RSA_PSS_SHA512,
+        Self::EC_P256,
+        Self::EC_P384,
+        Self::EC_P521,
+        Self::EC_SECP256K1,
+        Self::X25519,
+        Self::X448,
+        Self::ED25519,
+        Self::ED448,
+    ];
+
+    /// Look up the algorithm matching an [`AlgorithmIdentifier`].
+    pub fn from_algorithm_identifier(algorithm: &AlgorithmIdentifier<'_>) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|known| known.matches(algorithm))
+            .copied()
+    }
+
+    /// Look up an algorithm by name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|known| known.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Get the `algorithm` OID.
+    pub fn oid(&self) -> ObjectIdentifier {
+        self.kind.oid()
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this algorithm.
+    pub fn algorithm_identifier(&self) -> Result<AlgorithmIdentifier<'static>> {
+        let parameters = match self.parameters {
+            KnownParameters::Absent => None,
+            KnownParameters::Null => Some(Any::NULL),
+            KnownParameters::NamedCurve(oid) => Some(Any::from(oid)),
+            KnownParameters::Sequence(body) => Some(Any::new(Tag::Sequence, body)?),
+        };
+
+        Ok(AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters,
+        })
+    }
+
+    /// Does the given [`AlgorithmIdentifier`] match this algorithm?
+    pub fn matches(&self, algorithm: &AlgorithmIdentifier<'_>) -> bool {
+        if algorithm.oid != self.oid() {
+            return false;
+        }
+
+        match (self.parameters, algorithm.parameters) {
+            (KnownParameters::Absent, params) => params.is_none(),
+            (KnownParameters::Null, params) => params.map_or(true, Any::is_null),
+            (KnownParameters::NamedCurve(oid), Some(params)) => params.oid().ok() == Some(*oid),
+            (KnownParameters::Sequence(body), Some(params)) => {
+                params.tag() == Tag::Sequence && params.value() == body
+            }
+            (_, None) => false,
+        }
+    }
+}
@@ -35,15 +35,23 @@ extern crate std;
 
 mod algorithm;
 mod error;
+mod known;
 mod spki;
 mod traits;
 
-#[cfg(feature = "fingerprint")]
+#[cfg(feature = "digest")]
 mod fingerprint;
 
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+mod dane;
+
+#[cfg(feature = "openssh")]
+mod openssh;
+
 pub use crate::{
-    algorithm::AlgorithmIdentifier,
+    algorithm::{AlgorithmIdentifier, AlgorithmParameters, MatchMode},
     error::{Error, Result},
+    known::{AlgorithmKind, KnownAlgorithm, KnownParameters},
     spki::SubjectPublicKeyInfo,
     traits::DecodePublicKey,
 };
@@ -52,5 +60,11 @@ pub use der::{self, asn1::ObjectIdentifier};
 #[cfg(feature = "alloc")]
 pub use {crate::traits::EncodePublicKey, der::Document};
 
+#[cfg(feature = "digest")]
+pub use {crate::fingerprint::Fingerprint, digest};
+
 #[cfg(feature = "fingerprint")]
 pub use crate::fingerprint::FingerprintBytes;
+
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+pub use crate::dane::{TlsaMatchingType, TlsaRecord, TlsaSelector, TlsaUsage};
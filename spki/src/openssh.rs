@@ -0,0 +1,159 @@
+//! Conversion of OpenSSH public keys into `SubjectPublicKeyInfo`.
+
+use crate::{Error, KnownAlgorithm, Result, SubjectPublicKeyInfo};
+use base64ct::{Base64, Encoding};
+use der::{asn1::UIntBytes, Document, Encode};
+
+/// `ssh-ed25519` key type.
+const SSH_ED25519: &str = "ssh-ed25519";
+
+/// `ssh-rsa` key type.
+const SSH_RSA: &str = "ssh-rsa";
+
+/// ECDSA key types along with their curve identifiers and algorithms.
+const SSH_ECDSA: &[(&str, &str, KnownAlgorithm)] = &[
+    ("ecdsa-sha2-nistp256", "nistp256", KnownAlgorithm::EC_P256),
+    ("ecdsa-sha2-nistp384", "nistp384", KnownAlgorithm::EC_P384),
+    ("ecdsa-sha2-nistp521", "nistp521", KnownAlgorithm::EC_P521),
+];
+
+impl SubjectPublicKeyInfo<'_> {
+    /// Convert an OpenSSH public key into a [`Document`] containing the
+    /// equivalent DER-encoded [`SubjectPublicKeyInfo`].
+    ///
+    /// Accepts either a public key in the `<type> <base64> [comment]` format
+    /// used by `.pub` files, or a line from an `authorized_keys` file, which
+    /// may additionally be prefixed by options.
+    ///
+    /// Supported key types are `ssh-ed25519`, `ecdsa-sha2-nistp{256,384,521}`
+    /// and `ssh-rsa`, and [`Error::OpenSsh`] is returned for any others.
+    #[cfg_attr(docsrs, doc(cfg(feature = "openssh")))]
+    pub fn from_openssh(line: &str) -> Result<Document> {
+        let (key_type, key_data) = split_line(line.trim()).ok_or(Error::OpenSsh)?;
+        let key_data = Base64::decode_vec(key_data).map_err(|_| Error::OpenSsh)?;
+        let mut reader = WireReader(&key_data);
+
+        if reader.string()? != key_type.as_bytes() {
+            return Err(Error::OpenSsh);
+        }
+
+        let doc = if key_type == SSH_ED25519 {
+            let public_key = reader.string()?;
+
+            if public_key.len() != 32 {
+                return Err(Error::KeyMalformed);
+            }
+
+            encode_spki(KnownAlgorithm::ED25519, public_key)?
+        } else if key_type == SSH_RSA {
+            let public_exponent = reader.mpint()?;
+            let modulus = reader.mpint()?;
+
+            // PKCS#1 `RSAPublicKey`
+            let public_key = [modulus, public_exponent].to_vec()?;
+
+            encode_spki(KnownAlgorithm::RSA, &public_key)?
+        } else {
+            let (_, curve, algorithm) = SSH_ECDSA
+                .iter()
+                .find(|(name, _, _)| *name == key_type)
+                .ok_or(Error::OpenSsh)?;
+
+            if reader.string()? != curve.as_bytes() {
+                return Err(Error::OpenSsh);
+            }
+
+            encode_spki(*algorithm, reader.string()?)?
+        };
+
+        if !reader.0.is_empty() {
+            return Err(Error::OpenSsh);
+        }
+
+        Ok(doc)
+    }
+}
+
+/// Split a public key line into its key type and Base64-encoded key data,
+/// skipping any `authorized_keys` options.
+fn split_line(line: &str) -> Option<(&str, &str)> {
+    let mut fields = line.split_ascii_whitespace();
+    let key_type = fields.next()?;
+
+    if is_key_type(key_type) {
+        return Some((key_type, fields.next()?));
+    }
+
+    // Options may contain quoted whitespace, so skip to the first unquoted
+    // whitespace character.
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut options_end = None;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => quoted = !quoted,
+            c if c.is_ascii_whitespace() && !quoted => {
+                options_end = Some(i);
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    let mut fields = line[options_end?..].split_ascii_whitespace();
+    let key_type = fields.next().filter(|key_type| is_key_type(key_type))?;
+    Some((key_type, fields.next()?))
+}
+
+/// Is this a supported key type?
+fn is_key_type(s: &str) -> bool {
+    s == SSH_ED25519 || s == SSH_RSA || SSH_ECDSA.iter().any(|(name, _, _)| *name == s)
+}
+
+/// Encode a `SubjectPublicKeyInfo` for the given algorithm and public key.
+fn encode_spki(algorithm: KnownAlgorithm, subject_public_key: &[u8]) -> Result<Document> {
+    let spki = SubjectPublicKeyInfo {
+        algorithm: algorithm.algorithm_identifier()?,
+        subject_public_key,
+    };
+
+    Ok(Document::encode_msg(&spki)?)
+}
+
+/// Reader for the SSH wire format described in [RFC 4251 § 5].
+///
+/// [RFC 4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
+struct WireReader<'a>(&'a [u8]);
+
+impl<'a> WireReader<'a> {
+    /// Read a length-prefixed `string`.
+    fn string(&mut self) -> Result<&'a [u8]> {
+        if self.0.len() < 4 {
+            return Err(Error::OpenSsh);
+        }
+
+        let (len, rest) = self.0.split_at(4);
+        let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+
+        if rest.len() < len {
+            return Err(Error::OpenSsh);
+        }
+
+        let (string, rest) = rest.split_at(len);
+        self.0 = rest;
+        Ok(string)
+    }
+
+    /// Read a non-negative `mpint`.
+    fn mpint(&mut self) -> Result<UIntBytes<'a>> {
+        let bytes = self.string()?;
+
+        match bytes.first() {
+            Some(byte) if byte & 0x80 != 0 => Err(Error::KeyMalformed),
+            _ => Ok(UIntBytes::new(bytes)?),
+        }
+    }
+}
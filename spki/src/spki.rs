@@ -1,20 +1,23 @@
 //! X.509 `SubjectPublicKeyInfo`
 
-use crate::{AlgorithmIdentifier, Error, Result};
+use crate::{AlgorithmIdentifier, AlgorithmKind, Error, Result};
 use core::cmp::Ordering;
-use der::{asn1::BitString, Decode, Decoder, DerOrd, Encode, Sequence, ValueOrd};
+use der::{asn1::BitString, Decode, Decoder, DerOrd, Encode, Sequence, Tagged, ValueOrd};
 
 #[cfg(feature = "alloc")]
 use der::Document;
 
+#[cfg(feature = "digest")]
+use {
+    crate::{fingerprint, Fingerprint},
+    digest::Digest,
+};
+
 #[cfg(feature = "fingerprint")]
-use crate::{fingerprint, FingerprintBytes};
+use {crate::FingerprintBytes, sha2::Sha256};
 
 #[cfg(all(feature = "alloc", feature = "fingerprint"))]
-use {
-    alloc::string::String,
-    base64ct::{Base64, Encoding},
-};
+use alloc::{format, string::String};
 
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
@@ -41,6 +44,19 @@ pub struct SubjectPublicKeyInfo<'a> {
 }
 
 impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Calculate the fingerprint of this [`SubjectPublicKeyInfo`] using the
+    /// hash function `D`, i.e. the digest of its DER encoding.
+    ///
+    /// The returned [`Fingerprint`] can be formatted as hex or Base64 as
+    /// needed for e.g. HPKP pins, DANE records or key identifiers.
+    #[cfg(feature = "digest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+    pub fn fingerprint<D: Digest>(&self) -> Result<Fingerprint<D>> {
+        let mut builder = fingerprint::Builder::new();
+        self.encode(&mut builder)?;
+        Ok(builder.finish())
+    }
+
     /// Calculate the SHA-256 fingerprint of this [`SubjectPublicKeyInfo`] and
     /// encode it as a Base64 string.
     ///
@@ -50,7 +66,19 @@ impl<'a> SubjectPublicKeyInfo<'a> {
     #[cfg(all(feature = "fingerprint", feature = "alloc"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "fingerprint", feature = "alloc"))))]
     pub fn fingerprint_base64(&self) -> Result<String> {
-        Ok(Base64::encode_string(&self.fingerprint_bytes()?))
+        Ok(self.fingerprint::<Sha256>()?.to_base64())
+    }
+
+    /// Compute the `pin-sha256` directive for this [`SubjectPublicKeyInfo`],
+    /// i.e. `pin-sha256="<Base64 SHA-256 fingerprint>"`.
+    ///
+    /// See [RFC7469 § 2.1] for more information.
+    ///
+    /// [RFC7469 § 2.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1
+    #[cfg(all(feature = "fingerprint", feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "fingerprint", feature = "alloc"))))]
+    pub fn to_public_key_pin(&self) -> Result<String> {
+        Ok(format!("pin-sha256=\"{}\"", self.fingerprint_base64()?))
     }
 
     /// Calculate the SHA-256 fingerprint of this [`SubjectPublicKeyInfo`] as
@@ -62,9 +90,56 @@ impl<'a> SubjectPublicKeyInfo<'a> {
     #[cfg(feature = "fingerprint")]
     #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
     pub fn fingerprint_bytes(&self) -> Result<FingerprintBytes> {
-        let mut builder = fingerprint::Builder::new();
-        self.encode(&mut builder)?;
-        Ok(builder.finish())
+        Ok(self.fingerprint::<Sha256>()?.into_bytes().into())
+    }
+
+    /// Create a [`SubjectPublicKeyInfo`] for an X25519, X448, Ed25519 or
+    /// Ed448 public key, as specified in [RFC 8410 § 4].
+    ///
+    /// Returns [`Error::KeyMalformed`] if the key has the wrong length.
+    ///
+    /// [RFC 8410 § 4]: https://datatracker.ietf.org/doc/html/rfc8410#section-4
+    pub fn from_rfc8410(kind: AlgorithmKind, public_key: &'a [u8]) -> Result<Self> {
+        let key_len = kind
+            .rfc8410_key_len()
+            .ok_or(Error::OidUnknown { oid: kind.oid() })?;
+
+        if public_key.len() != key_len {
+            return Err(Error::KeyMalformed);
+        }
+
+        Ok(Self {
+            algorithm: AlgorithmIdentifier {
+                oid: kind.oid(),
+                parameters: None,
+            },
+            subject_public_key: public_key,
+        })
+    }
+
+    /// Check that this is a valid [RFC 8410] public key of the `expected`
+    /// kind, returning the raw public key.
+    ///
+    /// The `algorithm` OID must match, `parameters` must be absent, and the
+    /// key must have the correct length.
+    ///
+    /// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410
+    pub fn rfc8410_public_key(&self, expected: AlgorithmKind) -> Result<&'a [u8]> {
+        let key_len = expected.rfc8410_key_len().ok_or(Error::OidUnknown {
+            oid: expected.oid(),
+        })?;
+
+        self.algorithm.assert_algorithm_oid(expected.oid())?;
+
+        if let Some(params) = self.algorithm.parameters {
+            return Err(params.tag().unexpected_error(None).into());
+        }
+
+        if self.subject_public_key.len() != key_len {
+            return Err(Error::KeyMalformed);
+        }
+
+        Ok(self.subject_public_key)
     }
 
     /// Get a [`BitString`] representing the `subject_public_key`
@@ -5,11 +5,11 @@ use crate::{Error, Result, SubjectPublicKeyInfo};
 #[cfg(feature = "alloc")]
 use der::Document;
 
+#[cfg(any(all(feature = "alloc", feature = "fingerprint"), feature = "pem"))]
+use alloc::string::String;
+
 #[cfg(feature = "pem")]
-use {
-    alloc::string::String,
-    der::pem::{LineEnding, PemLabel},
-};
+use der::pem::{LineEnding, PemLabel};
 
 #[cfg(feature = "std")]
 use std::path::Path;
@@ -73,6 +73,17 @@ pub trait EncodePublicKey {
         Ok(doc.to_pem(SubjectPublicKeyInfo::PEM_LABEL, line_ending)?)
     }
 
+    /// Compute the `pin-sha256="..."` directive for this public key.
+    ///
+    /// See [`SubjectPublicKeyInfo::to_public_key_pin`].
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    fn to_public_key_pin(&self) -> Result<String> {
+        let doc = self.to_public_key_der()?;
+        doc.decode_msg::<SubjectPublicKeyInfo<'_>>()?
+            .to_public_key_pin()
+    }
+
     /// Write ASN.1 DER-encoded public key to the given path
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -0,0 +1,145 @@
+//! `AlgorithmIdentifier` tests.
+
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Tag,
+};
+use hex_literal::hex;
+use spki::{AlgorithmIdentifier, AlgorithmParameters, MatchMode};
+
+/// `rsaEncryption` OID
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `id-RSASSA-PSS` OID
+const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+
+/// `id-ecPublicKey` OID
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// NIST P-256 OID
+const SECP256R1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// RSASSA-PSS (SHA-256) `AlgorithmIdentifier` encoded as ASN.1 DER
+const RSA_PSS_SHA256_DER_EXAMPLE: &[u8] = &hex!("304106092A864886F70D01010A3034A00F300D06096086480165030402010500A11C301A06092A864886F70D010108300D06096086480165030402010500A203020120");
+
+#[test]
+fn matches_rsa_null_and_absent() {
+    let null = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: Some(Any::NULL),
+    };
+    let absent = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: None,
+    };
+
+    assert!(null.matches(&null, MatchMode::Exact));
+    assert!(!null.matches(&absent, MatchMode::Exact));
+    assert!(null.matches(&absent, MatchMode::RsaNullEqualsAbsent));
+    assert!(absent.matches(&null, MatchMode::RsaNullEqualsAbsent));
+    assert!(absent.matches(&absent, MatchMode::RsaNullEqualsAbsent));
+}
+
+#[test]
+fn matches_rsa_other_parameters() {
+    let null = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: Some(Any::NULL),
+    };
+    let oid = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: Some(Any::from(&SECP256R1_OID)),
+    };
+
+    assert!(!null.matches(&oid, MatchMode::RsaNullEqualsAbsent));
+    assert!(oid.matches(&oid, MatchMode::RsaNullEqualsAbsent));
+}
+
+#[test]
+fn matches_non_rsa() {
+    let null = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some(Any::NULL),
+    };
+    let absent = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: None,
+    };
+    let rsa = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: None,
+    };
+
+    assert!(!null.matches(&absent, MatchMode::RsaNullEqualsAbsent));
+    assert!(!absent.matches(&rsa, MatchMode::RsaNullEqualsAbsent));
+    assert_eq!(MatchMode::default(), MatchMode::Exact);
+}
+
+#[test]
+fn typed_parameters() {
+    let ec = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some(Any::from(&SECP256R1_OID)),
+    };
+    assert_eq!(
+        ec.typed_parameters().unwrap(),
+        AlgorithmParameters::NamedCurve(SECP256R1_OID)
+    );
+
+    let rsa = AlgorithmIdentifier {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: Some(Any::NULL),
+    };
+    assert_eq!(rsa.typed_parameters().unwrap(), AlgorithmParameters::Null);
+
+    let absent = AlgorithmIdentifier {
+        oid: RSASSA_PSS_OID,
+        parameters: None,
+    };
+    assert_eq!(
+        absent.typed_parameters().unwrap(),
+        AlgorithmParameters::None
+    );
+}
+
+#[test]
+fn typed_parameters_rsa_pss() {
+    let algorithm = AlgorithmIdentifier::try_from(RSA_PSS_SHA256_DER_EXAMPLE).unwrap();
+    let params = algorithm.parameters.unwrap();
+    assert_eq!(
+        algorithm.typed_parameters().unwrap(),
+        AlgorithmParameters::RsaPss(params)
+    );
+    assert_eq!(
+        AlgorithmParameters::try_from(&algorithm).unwrap(),
+        AlgorithmParameters::RsaPss(params)
+    );
+}
+
+#[test]
+fn typed_parameters_other() {
+    let other_oid = ObjectIdentifier::new_unwrap("1.2.3.4");
+    let other = AlgorithmIdentifier {
+        oid: other_oid,
+        parameters: Some(Any::from(&SECP256R1_OID)),
+    };
+    assert_eq!(
+        other.typed_parameters().unwrap(),
+        AlgorithmParameters::Any(Any::from(&SECP256R1_OID))
+    );
+}
+
+#[test]
+fn typed_parameters_wrong_type() {
+    let ec = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some(Any::new(Tag::Integer, &[1]).unwrap()),
+    };
+    assert!(ec.typed_parameters().is_err());
+
+    let rsa_pss = AlgorithmIdentifier {
+        oid: RSASSA_PSS_OID,
+        parameters: Some(Any::from(&SECP256R1_OID)),
+    };
+    assert!(rsa_pss.typed_parameters().is_err());
+}
@@ -0,0 +1,67 @@
+//! DANE TLSA record tests.
+
+#![cfg(all(feature = "alloc", feature = "fingerprint"))]
+
+use hex_literal::hex;
+use spki::{SubjectPublicKeyInfo, TlsaMatchingType, TlsaRecord, TlsaSelector, TlsaUsage};
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// SHA-256 digest of `EC_P256_DER_EXAMPLE`
+///
+/// Generated using `cat p256-pub.der | openssl dgst -sha256`
+const EC_P256_SHA256: [u8; 32] =
+    hex!("b9968d56ed8d6aa3fb43b15fa01e355d7a3a0203b1408b3fd2733637c4d1642c");
+
+#[test]
+fn spki_sha256() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let record = TlsaRecord::from_spki(TlsaUsage::DaneEe, TlsaMatchingType::Sha256, &spki).unwrap();
+
+    assert_eq!(record.selector, TlsaSelector::Spki);
+    assert_eq!(record.data, EC_P256_SHA256);
+    assert_eq!(
+        record.to_string(),
+        "3 1 1 B9968D56ED8D6AA3FB43B15FA01E355D7A3A0203B1408B3FD2733637C4D1642C"
+    );
+    assert_eq!(&record.to_rdata()[..3], &[3, 1, 1]);
+    assert_eq!(&record.to_rdata()[3..], EC_P256_SHA256);
+}
+
+#[test]
+fn spki_sha512() {
+    // Generated using `cat p256-pub.der | openssl dgst -sha512`
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let record = TlsaRecord::from_spki(TlsaUsage::DaneTa, TlsaMatchingType::Sha512, &spki).unwrap();
+
+    assert_eq!(record.to_string(), "2 1 2 451B8C6CE7383111AFF5D831A94587060F13E720ABF685AC466DE6228D65F89667C95E4DC2E46C2025ED0DC4BFF35C726995D4C67FC928747CDF3FAAFCBFFE9E");
+}
+
+#[test]
+fn spki_full() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let record = TlsaRecord::from_spki(TlsaUsage::PkixEe, TlsaMatchingType::Full, &spki).unwrap();
+
+    assert_eq!(record.data, EC_P256_DER_EXAMPLE);
+    assert_eq!(
+        record,
+        TlsaRecord::new(
+            TlsaUsage::PkixEe,
+            TlsaSelector::Spki,
+            TlsaMatchingType::Full,
+            EC_P256_DER_EXAMPLE
+        )
+    );
+}
+
+#[test]
+fn new_sha256() {
+    let record = TlsaRecord::new(
+        TlsaUsage::DaneEe,
+        TlsaSelector::Spki,
+        TlsaMatchingType::Sha256,
+        EC_P256_DER_EXAMPLE,
+    );
+    assert_eq!(record.data, EC_P256_SHA256);
+}
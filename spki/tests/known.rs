@@ -0,0 +1,100 @@
+//! Well-known algorithm registry tests.
+
+use hex_literal::hex;
+use spki::{AlgorithmIdentifier, AlgorithmKind, KnownAlgorithm, SubjectPublicKeyInfo};
+
+#[cfg(feature = "alloc")]
+use der::Encode;
+
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
+
+/// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
+
+/// RSASSA-PSS (SHA-256) `AlgorithmIdentifier` encoded as ASN.1 DER
+///
+/// Generated using `openssl genpkey -algorithm RSA-PSS -pkeyopt rsa_pss_keygen_md:sha256`
+/// with MGF1-SHA-256 and a 32-byte salt.
+const RSA_PSS_SHA256_DER_EXAMPLE: &[u8] = &hex!("304106092A864886F70D01010A3034A00F300D06096086480165030402010500A11C301A06092A864886F70D010108300D06096086480165030402010500A203020120");
+
+#[test]
+fn from_spki() {
+    for (der, known) in [
+        (EC_P256_DER_EXAMPLE, KnownAlgorithm::EC_P256),
+        (ED25519_DER_EXAMPLE, KnownAlgorithm::ED25519),
+        (RSA_2048_DER_EXAMPLE, KnownAlgorithm::RSA),
+    ] {
+        let spki = SubjectPublicKeyInfo::try_from(der).unwrap();
+        assert_eq!(spki.algorithm.kind(), Some(known.kind));
+        assert_eq!(
+            KnownAlgorithm::from_algorithm_identifier(&spki.algorithm),
+            Some(known)
+        );
+    }
+}
+
+#[test]
+fn from_rsa_pss_sha256() {
+    let algorithm = AlgorithmIdentifier::try_from(RSA_PSS_SHA256_DER_EXAMPLE).unwrap();
+    assert_eq!(algorithm.kind(), Some(AlgorithmKind::RsaPss));
+    assert_eq!(
+        KnownAlgorithm::from_algorithm_identifier(&algorithm),
+        Some(KnownAlgorithm::RSA_PSS_SHA256)
+    );
+}
+
+#[test]
+fn round_trip() {
+    for known in KnownAlgorithm::ALL {
+        let algorithm = known.algorithm_identifier().unwrap();
+        assert_eq!(algorithm.kind(), Some(known.kind));
+        assert_eq!(
+            KnownAlgorithm::from_algorithm_identifier(&algorithm),
+            Some(*known)
+        );
+        assert_eq!(KnownAlgorithm::from_name(known.name), Some(*known));
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_rsa_pss_sha256() {
+    let algorithm = KnownAlgorithm::RSA_PSS_SHA256
+        .algorithm_identifier()
+        .unwrap();
+    assert_eq!(algorithm.to_vec().unwrap(), RSA_PSS_SHA256_DER_EXAMPLE);
+}
+
+#[test]
+fn rsa_null_parameters_optional() {
+    let algorithm = AlgorithmIdentifier {
+        oid: AlgorithmKind::Rsa.oid(),
+        parameters: None,
+    };
+    assert!(KnownAlgorithm::RSA.matches(&algorithm));
+}
+
+#[test]
+fn mismatched_parameters() {
+    let mut algorithm = KnownAlgorithm::EC_P256.algorithm_identifier().unwrap();
+    algorithm.parameters = None;
+    assert_eq!(KnownAlgorithm::from_algorithm_identifier(&algorithm), None);
+    assert_eq!(algorithm.kind(), Some(AlgorithmKind::Ec));
+}
+
+#[test]
+fn unique() {
+    for (i, a) in KnownAlgorithm::ALL.iter().enumerate() {
+        for b in &KnownAlgorithm::ALL[(i + 1)..] {
+            assert_ne!(a.name, b.name);
+            assert_ne!(
+                a.algorithm_identifier().unwrap(),
+                b.algorithm_identifier().unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! OpenSSH public key conversion tests.
+
+#![cfg(feature = "openssh")]
+
+use spki::{Error, SubjectPublicKeyInfo};
+
+/// Ed25519 OpenSSH public key
+const ED25519_SSH_EXAMPLE: &str = include_str!("examples/ssh-ed25519.pub");
+
+/// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated using `openssl pkey -pubout` from the corresponding private key
+const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ssh-ed25519-pub.der");
+
+/// ECDSA/P-256 OpenSSH public key
+const ECDSA_P256_SSH_EXAMPLE: &str = include_str!("examples/ssh-ecdsa-p256.pub");
+
+/// ECDSA/P-256 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated using `ssh-keygen -e -m PKCS8`
+const ECDSA_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/ssh-ecdsa-p256-pub.der");
+
+/// ECDSA/P-384 OpenSSH public key
+const ECDSA_P384_SSH_EXAMPLE: &str = include_str!("examples/ssh-ecdsa-p384.pub");
+
+/// ECDSA/P-384 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated using `ssh-keygen -e -m PKCS8`
+const ECDSA_P384_DER_EXAMPLE: &[u8] = include_bytes!("examples/ssh-ecdsa-p384-pub.der");
+
+/// RSA-3072 OpenSSH public key
+const RSA_3072_SSH_EXAMPLE: &str = include_str!("examples/ssh-rsa-3072.pub");
+
+/// RSA-3072 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated using `ssh-keygen -e -m PKCS8`
+const RSA_3072_DER_EXAMPLE: &[u8] = include_bytes!("examples/ssh-rsa-3072-pub.der");
+
+#[test]
+fn from_openssh_ed25519() {
+    let doc = SubjectPublicKeyInfo::from_openssh(ED25519_SSH_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), ED25519_DER_EXAMPLE);
+}
+
+#[test]
+fn from_openssh_ecdsa_p256() {
+    let doc = SubjectPublicKeyInfo::from_openssh(ECDSA_P256_SSH_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), ECDSA_P256_DER_EXAMPLE);
+}
+
+#[test]
+fn from_openssh_ecdsa_p384() {
+    let doc = SubjectPublicKeyInfo::from_openssh(ECDSA_P384_SSH_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), ECDSA_P384_DER_EXAMPLE);
+}
+
+#[test]
+fn from_openssh_rsa_3072() {
+    let doc = SubjectPublicKeyInfo::from_openssh(RSA_3072_SSH_EXAMPLE).unwrap();
+    assert_eq!(doc.as_bytes(), RSA_3072_DER_EXAMPLE);
+}
+
+#[test]
+fn from_authorized_keys_line() {
+    let line = format!(
+        "command=\"/usr/bin/echo hello world\",no-pty {}",
+        ECDSA_P256_SSH_EXAMPLE
+    );
+    let doc = SubjectPublicKeyInfo::from_openssh(&line).unwrap();
+    assert_eq!(doc.as_bytes(), ECDSA_P256_DER_EXAMPLE);
+}
+
+#[test]
+fn from_openssh_without_comment() {
+    let line = ED25519_SSH_EXAMPLE.rsplit_once(' ').unwrap().0;
+    let doc = SubjectPublicKeyInfo::from_openssh(line).unwrap();
+    assert_eq!(doc.as_bytes(), ED25519_DER_EXAMPLE);
+}
+
+#[test]
+fn from_openssh_mismatched_key_type() {
+    let line = ED25519_SSH_EXAMPLE.replacen("ssh-ed25519", "ssh-rsa", 1);
+    assert_eq!(
+        SubjectPublicKeyInfo::from_openssh(&line).err(),
+        Some(Error::OpenSsh)
+    );
+}
+
+#[test]
+fn from_openssh_unsupported() {
+    let line = "ssh-dss AAAAB3NzaC1kc3MAAACBANw9iSUO2UYhFMssjUgW46URqv8bBrDgHeF8HLBOWBvKuXF2 user@example.com";
+    assert_eq!(
+        SubjectPublicKeyInfo::from_openssh(line).err(),
+        Some(Error::OpenSsh)
+    );
+    assert_eq!(
+        SubjectPublicKeyInfo::from_openssh("").err(),
+        Some(Error::OpenSsh)
+    );
+}
@@ -1,7 +1,7 @@
 //! `SubjectPublicKeyInfo` tests.
 
 use hex_literal::hex;
-use spki::SubjectPublicKeyInfo;
+use spki::{AlgorithmKind, Error, SubjectPublicKeyInfo};
 
 #[cfg(feature = "alloc")]
 use der::Encode;
@@ -9,11 +9,13 @@ use der::Encode;
 #[cfg(feature = "pem")]
 use der::{pem::LineEnding, EncodePem};
 
+#[cfg(feature = "digest")]
+use sha2::Sha256;
+
 /// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
 
 /// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
-#[cfg(any(feature = "alloc", feature = "fingerprint"))]
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
 
 /// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
@@ -40,7 +42,7 @@ const ED25519_SPKI_FINGERPRINT_BASE64: &str = "Vd1MdLDkhTTi9OFzzs61DfjyenrCqomRz
 /// The SPKI fingerprint for `ED25519_SPKI_FINGERPRINT` as straight hash bytes
 ///
 /// Generated using `cat ed25519-pub.der | openssl dgst -sha256`
-#[cfg(all(feature = "fingerprint"))]
+#[cfg(feature = "digest")]
 const ED25519_SPKI_FINGERPRINT: &[u8] =
     &hex!("55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced");
 
@@ -98,6 +100,97 @@ fn decode_ed25519_and_fingerprint_base64() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "alloc"))]
+fn to_public_key_pin() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    assert_eq!(
+        spki.to_public_key_pin().unwrap(),
+        format!("pin-sha256=\"{}\"", ED25519_SPKI_FINGERPRINT_BASE64)
+    );
+}
+
+#[test]
+#[cfg(feature = "digest")]
+fn fingerprint_sha256_hex() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    let fingerprint = spki.fingerprint::<Sha256>().unwrap();
+
+    assert_eq!(fingerprint.as_bytes(), ED25519_SPKI_FINGERPRINT);
+    assert_eq!(
+        format!("{:x}", fingerprint),
+        "55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced"
+    );
+    assert_eq!(
+        format!("{:X}", fingerprint),
+        "55DD4C74B0E48534E2F4E173CECEB50DF8F27A7AC2AA8991CC7AE914E030BCED"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "digest", feature = "alloc"))]
+fn fingerprint_sha512_base64() {
+    // Generated using `cat ed25519-pub.der | openssl dgst -binary -sha512 | base64`
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    assert_eq!(
+        spki.fingerprint::<sha2::Sha512>().unwrap().to_base64(),
+        "wsYx+hIHP9RPYbf7hkTQOkJtOvV7KDh+We3hv6XQM/cRcMrkUJJkwK0vH3mJIb8JxLauAC3C5d/Vo7lseTaV6Q=="
+    );
+}
+
+#[test]
+fn rfc8410_public_key() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    let public_key = spki.rfc8410_public_key(AlgorithmKind::Ed25519).unwrap();
+
+    assert_eq!(
+        public_key,
+        &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D")
+    );
+    assert_eq!(
+        SubjectPublicKeyInfo::from_rfc8410(AlgorithmKind::Ed25519, public_key).unwrap(),
+        spki
+    );
+}
+
+#[test]
+fn rfc8410_public_key_invalid() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.rfc8410_public_key(AlgorithmKind::X25519),
+        Err(Error::OidUnknown {
+            oid: AlgorithmKind::X25519.oid()
+        })
+    );
+    assert_eq!(
+        spki.rfc8410_public_key(AlgorithmKind::Rsa),
+        Err(Error::OidUnknown {
+            oid: AlgorithmKind::Rsa.oid()
+        })
+    );
+
+    // Parameters must be absent
+    let mut with_params = spki;
+    with_params.algorithm.parameters = Some(der::asn1::Any::NULL);
+    assert!(with_params
+        .rfc8410_public_key(AlgorithmKind::Ed25519)
+        .is_err());
+
+    // Keys must have the right length
+    let mut truncated = spki;
+    truncated.subject_public_key = &spki.subject_public_key[1..];
+    assert_eq!(
+        truncated.rfc8410_public_key(AlgorithmKind::Ed25519),
+        Err(Error::KeyMalformed)
+    );
+    assert_eq!(
+        SubjectPublicKeyInfo::from_rfc8410(AlgorithmKind::Ed448, spki.subject_public_key),
+        Err(Error::KeyMalformed)
+    );
+}
+
 #[test]
 fn decode_rsa_2048_der() {
     let spki = SubjectPublicKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -81,6 +81,19 @@ fn to_public_key_pem() {
     assert_eq!(pem, ED25519_PEM_EXAMPLE);
 }
 
+#[cfg(feature = "fingerprint")]
+#[test]
+fn to_public_key_pin() {
+    let pin = MockKey(ED25519_DER_EXAMPLE.to_vec())
+        .to_public_key_pin()
+        .unwrap();
+
+    assert_eq!(
+        pin,
+        "pin-sha256=\"Vd1MdLDkhTTi9OFzzs61DfjyenrCqomRzHrpFOAwvO0=\""
+    );
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn write_public_key_der_file() {
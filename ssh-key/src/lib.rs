@@ -183,5 +183,8 @@ pub use sec1;
 #[cfg(feature = "fingerprint")]
 pub use crate::fingerprint::Fingerprint;
 
+#[cfg(feature = "pkcs8")]
+pub use pkcs8;
+
 #[cfg(feature = "rand_core")]
 pub use rand_core;
@@ -110,6 +110,8 @@ mod dsa;
 mod ecdsa;
 mod ed25519;
 mod keypair;
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+mod pkcs8;
 #[cfg(feature = "alloc")]
 mod rsa;
 #[cfg(feature = "alloc")]
@@ -110,6 +110,16 @@ impl<const SIZE: usize> Drop for EcdsaPrivateKey<SIZE> {
     }
 }
 
+impl<const SIZE: usize> TryFrom<&[u8]> for EcdsaPrivateKey<SIZE> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(EcdsaPrivateKey {
+            bytes: bytes.try_into()?,
+        })
+    }
+}
+
 #[cfg(feature = "p256")]
 #[cfg_attr(docsrs, doc(cfg(feature = "p256")))]
 impl From<p256::SecretKey> for EcdsaPrivateKey<32> {
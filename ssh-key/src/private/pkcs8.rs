@@ -0,0 +1,331 @@
+//! PKCS#8 private key conversions.
+//!
+//! Supports Ed25519, ECDSA (NIST P-256/P-384/P-521), and two-prime RSA keys.
+//! Other key types, as well as encrypted keys, can't be encoded as PKCS#8 and
+//! return [`pkcs8::Error::KeyMalformed`].
+
+use super::{rsa::RsaPrivateKey, Ed25519Keypair, KeypairData, PrivateKey, RsaKeypair};
+use crate::{public::RsaPublicKey, MPInt};
+use alloc::vec::Vec;
+use pkcs8::{
+    der::{
+        asn1::{ObjectIdentifier, OctetString, UIntBytes},
+        Decode, Encode,
+    },
+    embedded::RsaPrivateKey as Pkcs1RsaPrivateKey,
+    AlgorithmIdentifier, DecodePrivateKey, EncodePrivateKey, PrivateKeyInfo, SecretDocument,
+};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::{EcdsaKeypair, EcdsaPrivateKey},
+    crate::{public::EcdsaPublicKey, EcdsaCurve},
+    pkcs8::embedded::EcPrivateKey,
+};
+
+/// `id-Ed25519` Object Identifier (OID).
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// `id-ecPublicKey` Object Identifier (OID).
+#[cfg(feature = "ecdsa")]
+const EC_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `rsaEncryption` Object Identifier (OID).
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `secp256r1` curve Object Identifier (OID).
+#[cfg(feature = "ecdsa")]
+const NIST_P256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// `secp384r1` curve Object Identifier (OID).
+#[cfg(feature = "ecdsa")]
+const NIST_P384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// `secp521r1` curve Object Identifier (OID).
+#[cfg(feature = "ecdsa")]
+const NIST_P521_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
+impl TryFrom<PrivateKeyInfo<'_>> for KeypairData {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key: PrivateKeyInfo<'_>) -> pkcs8::Result<KeypairData> {
+        match private_key.algorithm.oid {
+            ED25519_OID => decode_ed25519(&private_key).map(Into::into),
+            #[cfg(feature = "ecdsa")]
+            EC_OID => decode_ecdsa(&private_key).map(Into::into),
+            RSA_OID => decode_rsa(&private_key).map(Into::into),
+            oid => Err(pkcs8::spki::Error::OidUnknown { oid }.into()),
+        }
+    }
+}
+
+impl DecodePrivateKey for KeypairData {}
+
+impl EncodePrivateKey for KeypairData {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        match self {
+            Self::Ed25519(keypair) => {
+                let private_key =
+                    Zeroizing::new(OctetString::new(keypair.private.as_ref())?.to_vec()?);
+                let mut private_key_info = PrivateKeyInfo::new(
+                    AlgorithmIdentifier {
+                        oid: ED25519_OID,
+                        parameters: None,
+                    },
+                    &private_key,
+                );
+
+                // Include the public key, as it can only be computed from the
+                // private key with the `ed25519` feature
+                private_key_info.public_key = Some(keypair.public.as_ref());
+                Ok(SecretDocument::encode_msg(&private_key_info)?)
+            }
+            #[cfg(feature = "ecdsa")]
+            Self::Ecdsa(keypair) => {
+                let curve_oid = match keypair.curve() {
+                    EcdsaCurve::NistP256 => NIST_P256_OID,
+                    EcdsaCurve::NistP384 => NIST_P384_OID,
+                    EcdsaCurve::NistP521 => NIST_P521_OID,
+                };
+
+                let private_key = Zeroizing::new(
+                    EcPrivateKey {
+                        private_key: keypair.private_key_bytes(),
+                        public_key: Some(keypair.public_key_bytes()),
+                    }
+                    .to_vec()?,
+                );
+
+                let private_key_info = PrivateKeyInfo::new(
+                    AlgorithmIdentifier {
+                        oid: EC_OID,
+                        parameters: Some((&curve_oid).into()),
+                    },
+                    &private_key,
+                );
+
+                Ok(SecretDocument::encode_msg(&private_key_info)?)
+            }
+            Self::Rsa(keypair) => {
+                let dp = crt_exponent(&keypair.private.d, &keypair.private.p)?;
+                let dq = crt_exponent(&keypair.private.d, &keypair.private.q)?;
+
+                let private_key = Zeroizing::new(
+                    Pkcs1RsaPrivateKey {
+                        n: mpint_to_uint(&keypair.public.n)?,
+                        e: mpint_to_uint(&keypair.public.e)?,
+                        d: mpint_to_uint(&keypair.private.d)?,
+                        p: mpint_to_uint(&keypair.private.p)?,
+                        q: mpint_to_uint(&keypair.private.q)?,
+                        dp: UIntBytes::new(&dp)?,
+                        dq: UIntBytes::new(&dq)?,
+                        qi: mpint_to_uint(&keypair.private.iqmp)?,
+                    }
+                    .to_vec()?,
+                );
+
+                let private_key_info = PrivateKeyInfo::new(
+                    AlgorithmIdentifier {
+                        oid: RSA_OID,
+                        parameters: Some(pkcs8::der::asn1::Any::NULL),
+                    },
+                    &private_key,
+                );
+
+                Ok(SecretDocument::encode_msg(&private_key_info)?)
+            }
+            _ => Err(pkcs8::Error::KeyMalformed),
+        }
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for PrivateKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key: PrivateKeyInfo<'_>) -> pkcs8::Result<PrivateKey> {
+        KeypairData::try_from(private_key)?
+            .try_into()
+            .map_err(|_| pkcs8::Error::KeyMalformed)
+    }
+}
+
+impl DecodePrivateKey for PrivateKey {}
+
+impl EncodePrivateKey for PrivateKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        self.key_data().to_pkcs8_der()
+    }
+}
+
+/// Decode an Ed25519 keypair from PKCS#8.
+///
+/// The public key is taken from PKCS#8 v2 keys, and is otherwise computed
+/// from the private key if the `ed25519` feature is enabled.
+fn decode_ed25519(private_key: &PrivateKeyInfo<'_>) -> pkcs8::Result<Ed25519Keypair> {
+    if private_key.algorithm.parameters.is_some() {
+        return Err(pkcs8::Error::ParametersMalformed);
+    }
+
+    let private = OctetString::from_der(private_key.private_key)?;
+    let private = private.as_bytes();
+
+    let public = match private_key.public_key {
+        Some(public) => public,
+        #[cfg(feature = "ed25519")]
+        None => {
+            let private = super::Ed25519PrivateKey::try_from(private)
+                .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+            return Ok(private.into());
+        }
+        #[cfg(not(feature = "ed25519"))]
+        None => return Err(pkcs8::Error::KeyMalformed),
+    };
+
+    let mut bytes = Zeroizing::new([0u8; Ed25519Keypair::BYTE_SIZE]);
+    let (private_bytes, public_bytes) = bytes.split_at_mut(Ed25519Keypair::BYTE_SIZE / 2);
+
+    if private.len() != private_bytes.len() || public.len() != public_bytes.len() {
+        return Err(pkcs8::Error::KeyMalformed);
+    }
+
+    private_bytes.copy_from_slice(private);
+    public_bytes.copy_from_slice(public);
+    Ed25519Keypair::from_bytes(&bytes).map_err(|_| pkcs8::Error::KeyMalformed)
+}
+
+/// Decode an ECDSA keypair from PKCS#8.
+///
+/// The public key is taken from the SEC1 `ECPrivateKey`, or from PKCS#8 v2
+/// keys.
+#[cfg(feature = "ecdsa")]
+fn decode_ecdsa(private_key: &PrivateKeyInfo<'_>) -> pkcs8::Result<EcdsaKeypair> {
+    let curve = match private_key.algorithm.parameters_oid()? {
+        NIST_P256_OID => EcdsaCurve::NistP256,
+        NIST_P384_OID => EcdsaCurve::NistP384,
+        NIST_P521_OID => EcdsaCurve::NistP521,
+        _ => return Err(pkcs8::Error::ParametersMalformed),
+    };
+
+    let ec_private_key = EcPrivateKey::from_der(private_key.private_key)?;
+    let public = ec_private_key
+        .public_key
+        .or(private_key.public_key)
+        .ok_or(pkcs8::Error::KeyMalformed)?;
+    let private = ec_private_key.private_key;
+
+    let keypair = match EcdsaPublicKey::from_sec1_bytes(public) {
+        Ok(EcdsaPublicKey::NistP256(public)) => EcdsaPrivateKey::try_from(private)
+            .map(|private| EcdsaKeypair::NistP256 { public, private }),
+        Ok(EcdsaPublicKey::NistP384(public)) => EcdsaPrivateKey::try_from(private)
+            .map(|private| EcdsaKeypair::NistP384 { public, private }),
+        Ok(EcdsaPublicKey::NistP521(public)) => EcdsaPrivateKey::try_from(private)
+            .map(|private| EcdsaKeypair::NistP521 { public, private }),
+        Err(err) => Err(err),
+    }
+    .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+    if keypair.curve() == curve {
+        Ok(keypair)
+    } else {
+        Err(pkcs8::Error::ParametersMalformed)
+    }
+}
+
+/// Decode an RSA keypair from PKCS#8.
+///
+/// The CRT exponents are discarded, as OpenSSH private keys don't include
+/// them.
+fn decode_rsa(private_key: &PrivateKeyInfo<'_>) -> pkcs8::Result<RsaKeypair> {
+    let key = Pkcs1RsaPrivateKey::from_der(private_key.private_key)?;
+
+    Ok(RsaKeypair {
+        public: RsaPublicKey {
+            n: uint_to_mpint(key.n)?,
+            e: uint_to_mpint(key.e)?,
+        },
+        private: RsaPrivateKey {
+            d: uint_to_mpint(key.d)?,
+            iqmp: uint_to_mpint(key.qi)?,
+            p: uint_to_mpint(key.p)?,
+            q: uint_to_mpint(key.q)?,
+        },
+    })
+}
+
+/// Convert a DER-encoded unsigned integer into an [`MPInt`].
+fn uint_to_mpint(uint: UIntBytes<'_>) -> pkcs8::Result<MPInt> {
+    MPInt::from_positive_bytes(uint.as_bytes()).map_err(|_| pkcs8::Error::KeyMalformed)
+}
+
+/// Convert an [`MPInt`] into a DER-encoded unsigned integer.
+fn mpint_to_uint(mpint: &MPInt) -> pkcs8::Result<UIntBytes<'_>> {
+    let bytes = mpint
+        .as_positive_bytes()
+        .ok_or(pkcs8::Error::KeyMalformed)?;
+
+    Ok(UIntBytes::new(bytes)?)
+}
+
+/// Compute the RSA CRT exponent `d mod (p - 1)` for the prime `p`.
+///
+/// OpenSSH private keys don't include the CRT exponents required by PKCS#1.
+/// The reduction is computed bit by bit, with a branchless conditional
+/// subtraction so the running time only depends on the lengths of the inputs.
+fn crt_exponent(d: &MPInt, p: &MPInt) -> pkcs8::Result<Zeroizing<Vec<u8>>> {
+    let d = d.as_positive_bytes().ok_or(pkcs8::Error::KeyMalformed)?;
+    let p = p.as_positive_bytes().ok_or(pkcs8::Error::KeyMalformed)?;
+
+    // Modulus `p - 1` with a leading zero byte, so the running remainder
+    // (which is always less than twice the modulus) never overflows. As `p`
+    // is an odd prime, decrementing it only clears its lowest bit.
+    let mut modulus = Zeroizing::new(Vec::with_capacity(p.len().saturating_add(1)));
+    modulus.push(0);
+    modulus.extend_from_slice(p);
+
+    match modulus.last_mut() {
+        Some(byte) if *byte & 1 == 1 => *byte ^= 1,
+        _ => return Err(pkcs8::Error::KeyMalformed),
+    }
+
+    let mut remainder = Zeroizing::new(vec![0u8; modulus.len()]);
+    let mut difference = Zeroizing::new(vec![0u8; modulus.len()]);
+
+    for byte in d {
+        for shift in (0..8).rev() {
+            // remainder = 2 * remainder + bit
+            let mut carry = byte.wrapping_shr(shift) & 1;
+
+            for r in remainder.iter_mut().rev() {
+                let next_carry = r.wrapping_shr(7);
+                *r = r.wrapping_shl(1) | carry;
+                carry = next_carry;
+            }
+
+            // difference = remainder - modulus
+            let mut borrow = 0u8;
+
+            for ((diff, r), m) in difference
+                .iter_mut()
+                .zip(remainder.iter())
+                .zip(modulus.iter())
+                .rev()
+            {
+                let (value, borrow1) = r.overflowing_sub(*m);
+                let (value, borrow2) = value.overflowing_sub(borrow);
+                *diff = value;
+                borrow = u8::from(borrow1 | borrow2);
+            }
+
+            // remainder = difference if remainder >= modulus
+            let mask = borrow.wrapping_sub(1);
+
+            for (r, diff) in remainder.iter_mut().zip(difference.iter()) {
+                *r = (*r & !mask) | (*diff & mask);
+            }
+        }
+    }
+
+    Ok(remainder)
+}
@@ -0,0 +1,183 @@
+//! PKCS#8 private key conversion tests.
+
+#![cfg(all(feature = "alloc", feature = "pkcs8"))]
+
+use ssh_key::{
+    pkcs8::{self, DecodePrivateKey, EncodePrivateKey, PrivateKeyInfo},
+    Algorithm, PrivateKey,
+};
+
+#[cfg(feature = "ecdsa")]
+use ssh_key::EcdsaCurve;
+
+/// DSA OpenSSH-formatted private key
+const OPENSSH_DSA_EXAMPLE: &str = include_str!("examples/id_dsa_1024");
+
+/// Ed25519 OpenSSH-formatted private key
+const OPENSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
+/// Ed25519 PKCS#8 v1 private key encoded as ASN.1 DER
+const PKCS8_ED25519_EXAMPLE: &[u8] = include_bytes!("examples/id_ed25519.pk8.der");
+
+/// ECDSA/P-256 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OPENSSH_ECDSA_P256_EXAMPLE: &str = include_str!("examples/id_ecdsa_p256");
+
+/// ECDSA/P-256 PKCS#8 private key encoded as ASN.1 DER
+#[cfg(feature = "ecdsa")]
+const PKCS8_ECDSA_P256_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p256.pk8.der");
+
+/// ECDSA/P-384 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OPENSSH_ECDSA_P384_EXAMPLE: &str = include_str!("examples/id_ecdsa_p384");
+
+/// ECDSA/P-384 PKCS#8 private key encoded as ASN.1 DER
+#[cfg(feature = "ecdsa")]
+const PKCS8_ECDSA_P384_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p384.pk8.der");
+
+/// ECDSA/P-521 OpenSSH-formatted private key
+#[cfg(feature = "ecdsa")]
+const OPENSSH_ECDSA_P521_EXAMPLE: &str = include_str!("examples/id_ecdsa_p521");
+
+/// ECDSA/P-521 PKCS#8 private key encoded as ASN.1 DER
+#[cfg(feature = "ecdsa")]
+const PKCS8_ECDSA_P521_EXAMPLE: &[u8] = include_bytes!("examples/id_ecdsa_p521.pk8.der");
+
+/// RSA (3072-bit) OpenSSH-formatted private key
+const OPENSSH_RSA_3072_EXAMPLE: &str = include_str!("examples/id_rsa_3072");
+
+/// RSA (3072-bit) PKCS#8 private key encoded as ASN.1 DER
+const PKCS8_RSA_3072_EXAMPLE: &[u8] = include_bytes!("examples/id_rsa_3072.pk8.der");
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p256_pkcs8() {
+    let key = pkcs8_decoding_test(OPENSSH_ECDSA_P256_EXAMPLE, PKCS8_ECDSA_P256_EXAMPLE);
+    assert_eq!(
+        Algorithm::Ecdsa {
+            curve: EcdsaCurve::NistP256
+        },
+        key.algorithm()
+    );
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p384_pkcs8() {
+    pkcs8_decoding_test(OPENSSH_ECDSA_P384_EXAMPLE, PKCS8_ECDSA_P384_EXAMPLE);
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn decode_ecdsa_p521_pkcs8() {
+    pkcs8_decoding_test(OPENSSH_ECDSA_P521_EXAMPLE, PKCS8_ECDSA_P521_EXAMPLE);
+}
+
+#[test]
+fn decode_rsa_3072_pkcs8() {
+    let key = pkcs8_decoding_test(OPENSSH_RSA_3072_EXAMPLE, PKCS8_RSA_3072_EXAMPLE);
+    assert_eq!(Algorithm::Rsa { hash: None }, key.algorithm());
+}
+
+#[test]
+fn decode_ed25519_pkcs8() {
+    let openssh_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+    let result = PrivateKey::from_pkcs8_der(PKCS8_ED25519_EXAMPLE);
+
+    // PKCS#8 v1 keys don't contain the public key, which can only be computed
+    // with the `ed25519` feature
+    if cfg!(feature = "ed25519") {
+        let key = result.unwrap();
+        assert_eq!(
+            openssh_key.public_key().key_data(),
+            key.public_key().key_data()
+        );
+    } else {
+        assert_eq!(result.err(), Some(pkcs8::Error::KeyMalformed));
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_ecdsa_p256_pkcs8() {
+    pkcs8_encoding_test(OPENSSH_ECDSA_P256_EXAMPLE, PKCS8_ECDSA_P256_EXAMPLE);
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_ecdsa_p384_pkcs8() {
+    pkcs8_encoding_test(OPENSSH_ECDSA_P384_EXAMPLE, PKCS8_ECDSA_P384_EXAMPLE);
+}
+
+#[cfg(feature = "ecdsa")]
+#[test]
+fn encode_ecdsa_p521_pkcs8() {
+    pkcs8_encoding_test(OPENSSH_ECDSA_P521_EXAMPLE, PKCS8_ECDSA_P521_EXAMPLE);
+}
+
+#[test]
+fn encode_rsa_3072_pkcs8() {
+    // Computes the CRT exponents, which OpenSSH keys don't include
+    pkcs8_encoding_test(OPENSSH_RSA_3072_EXAMPLE, PKCS8_RSA_3072_EXAMPLE);
+}
+
+#[test]
+fn encode_ed25519_pkcs8() {
+    let openssh_key = PrivateKey::from_openssh(OPENSSH_ED25519_EXAMPLE).unwrap();
+    let der = openssh_key.to_pkcs8_der().unwrap();
+
+    // Encoded as PKCS#8 v2, including the public key
+    let private_key_info: PrivateKeyInfo<'_> = der.decode_msg().unwrap();
+    let expected = PrivateKeyInfo::try_from(PKCS8_ED25519_EXAMPLE).unwrap();
+    assert_eq!(private_key_info.algorithm, expected.algorithm);
+    assert_eq!(private_key_info.private_key, expected.private_key);
+    assert_eq!(
+        private_key_info.public_key,
+        Some(
+            openssh_key
+                .public_key()
+                .key_data()
+                .ed25519()
+                .unwrap()
+                .as_ref()
+                .as_slice()
+        )
+    );
+
+    let key = PrivateKey::from_pkcs8_der(der.as_bytes()).unwrap();
+    assert_eq!(
+        openssh_key.public_key().key_data(),
+        key.public_key().key_data()
+    );
+}
+
+#[test]
+fn encode_dsa_pkcs8() {
+    let openssh_key = PrivateKey::from_openssh(OPENSSH_DSA_EXAMPLE).unwrap();
+    assert_eq!(
+        openssh_key.to_pkcs8_der().err(),
+        Some(pkcs8::Error::KeyMalformed)
+    );
+}
+
+/// Decode a PKCS#8 key, checking it against the equivalent OpenSSH key.
+fn pkcs8_decoding_test(openssh: &str, pkcs8: &[u8]) -> PrivateKey {
+    let openssh_key = PrivateKey::from_openssh(openssh).unwrap();
+    let key = PrivateKey::from_pkcs8_der(pkcs8).unwrap();
+    assert_eq!(
+        openssh_key.public_key().key_data(),
+        key.public_key().key_data()
+    );
+    assert_eq!(key.comment(), "");
+
+    // Ensure key round-trips
+    assert_eq!(key.to_pkcs8_der().unwrap().as_bytes(), pkcs8);
+    key
+}
+
+/// Encode an OpenSSH key as PKCS#8, checking it against the expected DER.
+fn pkcs8_encoding_test(openssh: &str, pkcs8: &[u8]) {
+    let key = PrivateKey::from_openssh(openssh).unwrap();
+    let der = key.to_pkcs8_der().unwrap();
+    assert_eq!(der.as_bytes(), pkcs8);
+}
@@ -3,10 +3,13 @@
 use alloc::vec::Vec;
 use core::fmt::{self, Write};
 
-use const_oid::db::DB;
-use der::asn1::{Any, ObjectIdentifier, SetOfVec};
+use const_oid::db::{rfc3280, rfc4519, DB};
+use der::asn1::{Any, Ia5String, ObjectIdentifier, PrintableString, SetOfVec};
 use der::{Decode, Encode, Error, ErrorKind, Sequence, Tag, Tagged, ValueOrd};
 
+#[cfg(feature = "stringprep")]
+use crate::name::prep::prepare;
+
 /// X.501 `AttributeType` as defined in [RFC 5280 Appendix A.1].
 ///
 /// ```text
@@ -140,7 +143,7 @@ impl Parser {
     }
 }
 
-impl AttributeTypeAndValue<'_> {
+impl<'a> AttributeTypeAndValue<'a> {
     /// Parses the hex value in the `OID=#HEX` format.
     fn encode_hex(oid: ObjectIdentifier, val: &str) -> Result<Vec<u8>, Error> {
         // Ensure an even number of hex bytes.
@@ -182,14 +185,72 @@ impl AttributeTypeAndValue<'_> {
         }
 
         // Serialize.
-        let value = Any::new(Tag::Utf8String, parser.as_bytes())?;
+        let value = match Self::string_tag(oid) {
+            Tag::PrintableString => PrintableString::new(parser.as_bytes())?.into(),
+            Tag::Ia5String => Ia5String::new(parser.as_bytes())?.into(),
+            tag => Any::new(tag, parser.as_bytes())?,
+        };
         let atv = AttributeTypeAndValue { oid, value };
         atv.to_vec()
     }
 
+    /// Selects the string type used to encode a value of the given attribute
+    /// type when parsing.
+    ///
+    /// [RFC 5280 Appendix A.1] requires `PrintableString` for `countryName`,
+    /// `serialNumber` and `dnQualifier`, and `IA5String` for
+    /// `domainComponent` and `emailAddress`. `UTF8String` is used for all
+    /// other attributes, as recommended by [RFC 5280 Section 4.1.2.4].
+    ///
+    /// [RFC 5280 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc5280#appendix-A.1
+    /// [RFC 5280 Section 4.1.2.4]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.4
+    fn string_tag(oid: ObjectIdentifier) -> Tag {
+        match oid {
+            rfc4519::C | rfc4519::SERIAL_NUMBER | rfc4519::DN_QUALIFIER => Tag::PrintableString,
+            rfc4519::DC | rfc3280::EMAIL_ADDRESS => Tag::Ia5String,
+            _ => Tag::Utf8String,
+        }
+    }
+
+    /// Returns the value if it is one of the supported string types.
+    pub(crate) fn value_str(&self) -> Option<&'a str> {
+        match self.value.tag() {
+            Tag::PrintableString => self.value.printable_string().ok().map(|s| s.as_str()),
+            Tag::Utf8String => self.value.utf8_string().ok().map(|s| s.as_str()),
+            Tag::Ia5String => self.value.ia5_string().ok().map(|s| s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Compares two attributes as described in [RFC 5280 Section 7.1].
+    ///
+    /// The attribute types must be equal. String values are compared after
+    /// [RFC 4518] string preparation, so values which differ only in case,
+    /// whitespace or string type match. Other values must be equal.
+    ///
+    /// [RFC 4518]: https://datatracker.ietf.org/doc/html/rfc4518
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    #[cfg(feature = "stringprep")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+    pub fn matches(&self, other: &AttributeTypeAndValue<'_>) -> bool {
+        if self.oid != other.oid {
+            return false;
+        }
+
+        match (self.value_str(), other.value_str()) {
+            (Some(a), Some(b)) => match (prepare(a), prepare(b)) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+            _ => self.value == other.value,
+        }
+    }
+
     /// Converts an AttributeTypeAndValue string into an encoded AttributeTypeAndValue
     ///
-    /// This function follows the rules in [RFC 4514].
+    /// This function follows the rules in [RFC 4514]. String values are
+    /// encoded using the type conventionally used for the attribute, e.g.
+    /// `PrintableString` for `countryName` and `UTF8String` for `commonName`.
     ///
     /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
     pub fn encode_from_string(s: &str) -> Result<Vec<u8>, Error> {
@@ -216,14 +277,7 @@ impl AttributeTypeAndValue<'_> {
 /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
 impl fmt::Display for AttributeTypeAndValue<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let val = match self.value.tag() {
-            Tag::PrintableString => self.value.printable_string().ok().map(|s| s.as_str()),
-            Tag::Utf8String => self.value.utf8_string().ok().map(|s| s.as_str()),
-            Tag::Ia5String => self.value.ia5_string().ok().map(|s| s.as_str()),
-            _ => None,
-        };
-
-        if let (Some(key), Some(val)) = (DB.by_oid(&self.oid), val) {
+        if let (Some(key), Some(val)) = (DB.by_oid(&self.oid), self.value_str()) {
             write!(f, "{}=", key.to_ascii_uppercase())?;
 
             let mut iter = val.char_indices().peekable();
@@ -0,0 +1,269 @@
+//! Certificate builder
+
+use crate::certificate::{Certificate, TbsCertificate, Version};
+use crate::ext::Extension;
+use crate::name::Name;
+use crate::time::Validity;
+
+use alloc::vec::Vec;
+
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::asn1::{BitString, UIntBytes};
+use der::{Encode, EncodeValue, Length, Tag};
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+#[cfg(feature = "sign")]
+use {
+    core::fmt,
+    signature::{Signature, Signer},
+};
+
+/// Maximum length of a serial number as defined in [RFC 5280 Section 4.1.2.2].
+///
+/// [RFC 5280 Section 4.1.2.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
+const MAX_SERIAL_NUMBER_LEN: u8 = 20;
+
+/// Builder for X.509 certificates.
+///
+/// The builder assembles a [`TbsCertificate`] from its components and leaves
+/// signing to a callback, which is passed the DER encoding of the
+/// `TBSCertificate` and returns the raw signature.
+///
+/// The issuer defaults to the subject, i.e. a self-signed certificate. The
+/// version is v3 if any extensions were added, and v1 otherwise.
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use der::{asn1::UtcTime, Decode, DateTime};
+/// use spki::{AlgorithmIdentifier, ObjectIdentifier, SubjectPublicKeyInfo};
+/// use x509_cert::ext::pkix::BasicConstraints;
+/// use x509_cert::name::NameOwned;
+/// use x509_cert::time::{Time, Validity};
+/// use x509_cert::{Certificate, CertificateBuilder};
+///
+/// # let public_key = [0u8; 32];
+/// let spki = SubjectPublicKeyInfo {
+///     algorithm: AlgorithmIdentifier {
+///         oid: ObjectIdentifier::new_unwrap("1.3.101.112"),
+///         parameters: None,
+///     },
+///     subject_public_key: &public_key,
+/// };
+///
+/// let subject: NameOwned = "CN=Test CA".parse()?;
+/// let validity = Validity {
+///     not_before: Time::UtcTime(UtcTime::from_date_time(DateTime::new(2022, 1, 1, 0, 0, 0)?)?),
+///     not_after: Time::UtcTime(UtcTime::from_date_time(DateTime::new(2032, 1, 1, 0, 0, 0)?)?),
+/// };
+/// let ca = BasicConstraints {
+///     ca: true,
+///     path_len_constraint: None,
+/// };
+///
+/// let builder = CertificateBuilder::new(&[1], validity, subject.to_name(), spki)?
+///     .extension(true, &ca)?;
+///
+/// let signature_algorithm = spki.algorithm;
+/// let der = builder.build(signature_algorithm, |tbs| {
+///     // Sign the DER-encoded `TBSCertificate` here.
+/// #   let _ = tbs;
+///     Ok::<_, der::Error>(vec![0u8; 64])
+/// })?;
+///
+/// let certificate = Certificate::from_der(&der)?;
+/// assert_eq!(certificate.tbs_certificate.issuer, certificate.tbs_certificate.subject);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CertificateBuilder<'a> {
+    serial_number: UIntBytes<'a>,
+    issuer: Option<Name<'a>>,
+    validity: Validity,
+    subject: Name<'a>,
+    subject_public_key_info: SubjectPublicKeyInfo<'a>,
+    extensions: Vec<(ObjectIdentifier, bool, Vec<u8>)>,
+}
+
+impl<'a> CertificateBuilder<'a> {
+    /// Create a builder for a certificate with the given big endian serial
+    /// number, validity period, subject name and subject public key.
+    ///
+    /// Returns an error if the serial number is zero or longer than the 20
+    /// octets allowed by [RFC 5280 Section 4.1.2.2], or if the validity
+    /// period ends before it starts.
+    ///
+    /// [RFC 5280 Section 4.1.2.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
+    pub fn new(
+        serial_number: &'a [u8],
+        validity: Validity,
+        subject: Name<'a>,
+        subject_public_key_info: SubjectPublicKeyInfo<'a>,
+    ) -> der::Result<Self> {
+        let serial_number = UIntBytes::new(serial_number)?;
+
+        if serial_number.as_bytes().iter().all(|&b| b == 0)
+            || serial_number.value_len()? > Length::from(MAX_SERIAL_NUMBER_LEN)
+        {
+            return Err(Tag::Integer.value_error());
+        }
+
+        if validity.not_after.to_unix_duration() < validity.not_before.to_unix_duration() {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        Ok(Self {
+            serial_number,
+            issuer: None,
+            validity,
+            subject,
+            subject_public_key_info,
+            extensions: Vec::new(),
+        })
+    }
+
+    /// Set the issuer name.
+    pub fn issuer(mut self, issuer: Name<'a>) -> Self {
+        self.issuer = Some(issuer);
+        self
+    }
+
+    /// Add an extension, encoding it from one of the typed extensions in
+    /// [`ext::pkix`][`crate::ext::pkix`] or any other type with an associated
+    /// OID.
+    ///
+    /// Returns an error if an extension with the same OID was already added,
+    /// as [RFC 5280 Section 4.2] prohibits duplicate extensions.
+    ///
+    /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+    pub fn extension<T: AssociatedOid + Encode>(
+        mut self,
+        critical: bool,
+        extension: &T,
+    ) -> der::Result<Self> {
+        if self.extensions.iter().any(|(oid, _, _)| *oid == T::OID) {
+            return Err(Tag::Sequence.value_error());
+        }
+
+        self.extensions
+            .push((T::OID, critical, extension.to_vec()?));
+        Ok(self)
+    }
+
+    /// Get the DER encoding of the `TBSCertificate`, using the given
+    /// `signature` algorithm.
+    ///
+    /// This is the message which is signed by the issuer.
+    pub fn build_tbs(&self, signature: AlgorithmIdentifier<'_>) -> der::Result<Vec<u8>> {
+        self.tbs_certificate(signature).to_vec()
+    }
+
+    /// Build and sign the certificate, returning its DER encoding.
+    ///
+    /// The `sign` callback is passed the DER encoding of the
+    /// `TBSCertificate` and must return a signature over it, computed with
+    /// the issuer's private key using `signature_algorithm`.
+    pub fn build<F, E>(
+        &self,
+        signature_algorithm: AlgorithmIdentifier<'_>,
+        sign: F,
+    ) -> Result<Vec<u8>, E>
+    where
+        F: FnOnce(&[u8]) -> Result<Vec<u8>, E>,
+        E: From<der::Error>,
+    {
+        let tbs_certificate = self.tbs_certificate(signature_algorithm);
+        let signature = sign(&tbs_certificate.to_vec()?)?;
+
+        let certificate = Certificate {
+            tbs_certificate,
+            signature_algorithm,
+            signature: BitString::from_bytes(&signature)?,
+        };
+
+        Ok(certificate.to_vec()?)
+    }
+
+    /// Build the certificate and sign it with `signer`, returning its DER
+    /// encoding.
+    ///
+    /// `signature_algorithm` must identify the algorithm used by `signer`,
+    /// whose signatures must be encoded as required by that algorithm, e.g.
+    /// as DER `Ecdsa-Sig-Value` for ECDSA.
+    #[cfg(feature = "sign")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+    pub fn build_and_sign<S: Signature>(
+        &self,
+        signer: &impl Signer<S>,
+        signature_algorithm: AlgorithmIdentifier<'_>,
+    ) -> Result<Vec<u8>, Error> {
+        self.build(signature_algorithm, |tbs| {
+            let signature = signer.try_sign(tbs).map_err(|_| Error::Signature)?;
+            Ok(signature.as_ref().to_vec())
+        })
+    }
+
+    /// Assemble the `TBSCertificate` for the given signature algorithm.
+    fn tbs_certificate<'b>(&'b self, signature: AlgorithmIdentifier<'b>) -> TbsCertificate<'b> {
+        let extensions = self
+            .extensions
+            .iter()
+            .map(|(oid, critical, value)| Extension {
+                extn_id: *oid,
+                critical: *critical,
+                extn_value: value,
+            })
+            .collect::<Vec<_>>();
+
+        let (version, extensions) = match extensions.is_empty() {
+            true => (Version::V1, None),
+            false => (Version::V3, Some(extensions)),
+        };
+
+        TbsCertificate {
+            version,
+            serial_number: self.serial_number,
+            signature,
+            issuer: self.issuer.as_ref().unwrap_or(&self.subject).clone(),
+            validity: self.validity,
+            subject: self.subject.clone(),
+            subject_public_key_info: self.subject_public_key_info,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions,
+        }
+    }
+}
+
+/// Error type for [`CertificateBuilder::build_and_sign`].
+#[cfg(feature = "sign")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Signing failed.
+    Signature,
+}
+
+#[cfg(feature = "sign")]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "X.509 ASN.1 error: {}", err),
+            Error::Signature => f.write_str("X.509 signing error"),
+        }
+    }
+}
+
+#[cfg(all(feature = "sign", feature = "std"))]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "sign")]
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
@@ -1,5 +1,10 @@
-use der::asn1::{PrintableString, Utf8String};
-use der::Choice;
+use core::fmt;
+
+use der::asn1::{Any, PrintableString, Utf8String};
+use der::{Choice, Tag, Tagged};
+
+#[cfg(feature = "stringprep")]
+use {crate::name::prep::prepare, alloc::string::String};
 
 /// DirectoryString as defined in [RFC 5280 Section 4.2.1.4].
 ///
@@ -37,7 +42,12 @@ use der::Choice;
 /// `UTF8String` are the new types and the other types are legacy. Until
 /// the need arises, we only support `PrintableString` and `UTF8String`.
 ///
+/// Use [`DirectoryString::as_str`] to access the value regardless of its
+/// string type. With the `stringprep` feature, [`DirectoryString::matches`]
+/// compares values the way [RFC 5280 Section 7.1] requires for names.
+///
 /// [RFC 5280 Section 4.2.1.4]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.4
+/// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
 #[derive(Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 pub enum DirectoryString<'a> {
@@ -47,3 +57,66 @@ pub enum DirectoryString<'a> {
     #[asn1(type = "UTF8String")]
     Utf8String(Utf8String<'a>),
 }
+
+impl<'a> DirectoryString<'a> {
+    /// Borrow the value as a `str`, regardless of its string type.
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            Self::PrintableString(s) => s.as_str(),
+            Self::Utf8String(s) => s.as_str(),
+        }
+    }
+
+    /// Prepare the value as described in [RFC 4518], returning a string
+    /// which compares equal for all values that [match][`Self::matches`].
+    ///
+    /// This is suitable as a key when deduplicating names. See
+    /// [`prepare`][`crate::name::prep::prepare`] for details.
+    ///
+    /// [RFC 4518]: https://datatracker.ietf.org/doc/html/rfc4518
+    #[cfg(feature = "stringprep")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+    pub fn prepare(&self) -> Option<String> {
+        prepare(self.as_str())
+    }
+
+    /// Compare two values ignoring case, insignificant whitespace and the
+    /// string type, as described in [RFC 5280 Section 7.1].
+    ///
+    /// Values which fail [RFC 4518] string preparation never match.
+    ///
+    /// [RFC 4518]: https://datatracker.ietf.org/doc/html/rfc4518
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    #[cfg(feature = "stringprep")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+    pub fn matches(&self, other: &DirectoryString<'_>) -> bool {
+        match (self.prepare(), other.prepare()) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl AsRef<str> for DirectoryString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for DirectoryString<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Self> {
+        match any.tag() {
+            Tag::PrintableString => any.printable_string().map(Self::PrintableString),
+            Tag::Utf8String => any.utf8_string().map(Self::Utf8String),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+impl fmt::Display for DirectoryString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -21,8 +21,13 @@ extern crate std;
 
 pub use der;
 
+#[cfg(feature = "sign")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sign")))]
+pub use signature;
+
 pub mod anchor;
 pub mod attr;
+pub mod builder;
 pub mod certificate;
 pub mod crl;
 pub mod ext;
@@ -30,4 +35,5 @@ pub mod name;
 pub mod request;
 pub mod time;
 
+pub use builder::CertificateBuilder;
 pub use certificate::{Certificate, PkiPath, TbsCertificate, Version};
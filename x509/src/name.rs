@@ -1,10 +1,17 @@
 //! Name-related definitions as defined in X.501 (and updated by RFC 5280).
 
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::str::FromStr;
 
-use crate::attr::AttributeTypeAndValue;
+use crate::attr::{AttributeType, AttributeTypeAndValue};
 
-use der::{asn1::SetOfVec, Decode, Encode, Newtype};
+use const_oid::db::{rfc3280, rfc4519};
+use der::{asn1::SetOfVec, Decode, Document, Encode, Newtype};
+
+#[cfg(feature = "stringprep")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+pub mod prep;
 
 /// X.501 Name as defined in [RFC 5280 Section 4.1.2.4]. X.501 Name is used to represent distinguished names.
 ///
@@ -54,10 +61,45 @@ fn split(s: &str, b: u8) -> impl '_ + Iterator<Item = &'_ str> {
     })
 }
 
-impl RdnSequence<'_> {
+impl<'a> RdnSequence<'a> {
+    /// Returns the value of the most specific attribute of the given type
+    /// which has a string value, searching RDNs in the same order as they
+    /// appear in [RFC 4514] strings.
+    ///
+    /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+    pub fn get_str(&self, oid: AttributeType) -> Option<&'a str> {
+        self.0.iter().rev().find_map(|rdn| rdn.get_str(oid))
+    }
+
+    /// Returns the `commonName` (`CN`) attribute value.
+    pub fn common_name(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::CN)
+    }
+
+    /// Returns the `organizationName` (`O`) attribute value.
+    pub fn organization(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::O)
+    }
+
+    /// Returns the `organizationalUnitName` (`OU`) attribute value.
+    pub fn organizational_unit(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::OU)
+    }
+
+    /// Returns the `countryName` (`C`) attribute value.
+    pub fn country(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::C)
+    }
+
+    /// Returns the `emailAddress` attribute value.
+    pub fn email_address(&self) -> Option<&'a str> {
+        self.get_str(rfc3280::EMAIL_ADDRESS)
+    }
+
     /// Converts an RDNSequence string into an encoded RDNSequence
     ///
-    /// This function follows the rules in [RFC 4514].
+    /// This function follows the rules in [RFC 4514]. Note that RDNs appear
+    /// in the string in the reverse order of their encoding.
     ///
     /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
     pub fn encode_from_string(s: &str) -> Result<Vec<u8>, der::Error> {
@@ -66,20 +108,44 @@ impl RdnSequence<'_> {
             .collect::<Result<Vec<_>, der::Error>>()?;
 
         let mut out = Vec::new();
-        for der in ders.iter() {
+        for der in ders.iter().rev() {
             out.push(RelativeDistinguishedName::from_der(der)?);
         }
 
         RdnSequence(out).to_vec()
     }
+
+    /// Serializes the structure according to the rules in [RFC 4514],
+    /// e.g. `CN=example.com,O=Example,C=US`.
+    ///
+    /// This is equivalent to the [`Display`][`core::fmt::Display`] impl.
+    ///
+    /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+    pub fn to_rfc4514_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Compares two names as described in [RFC 5280 Section 7.1].
+    ///
+    /// Names match if they contain the same number of RDNs and each RDN
+    /// [matches][`RelativeDistinguishedName::matches`] the RDN in the same
+    /// position of the other name.
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    #[cfg(feature = "stringprep")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+    pub fn matches(&self, other: &RdnSequence<'_>) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| a.matches(b))
+    }
 }
 
-/// Serializes the structure according to the rules in [RFC 4514].
+/// Serializes the structure according to the rules in [RFC 4514], which
+/// lists RDNs in the reverse order of their encoding.
 ///
 /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
 impl core::fmt::Display for RdnSequence<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for (i, atv) in self.0.iter().enumerate() {
+        for (i, atv) in self.0.iter().rev().enumerate() {
             match i {
                 0 => write!(f, "{}", atv)?,
                 _ => write!(f, ",{}", atv)?,
@@ -90,6 +156,86 @@ impl core::fmt::Display for RdnSequence<'_> {
     }
 }
 
+/// Owned X.501 [`Name`], stored as its DER encoding.
+///
+/// [`Name`] borrows its attribute values from the DER it was decoded from,
+/// so it can't be parsed from an [RFC 4514] string on its own. This type
+/// owns the encoding, so it implements [`FromStr`] using
+/// [`RdnSequence::encode_from_string`], and can be decoded as a [`Name`]
+/// with [`NameOwned::to_name`]:
+///
+/// ```
+/// # fn main() -> der::Result<()> {
+/// use x509_cert::name::NameOwned;
+///
+/// let name: NameOwned = "CN=example.com,O=Example,C=US".parse()?;
+/// assert_eq!(name.to_name().common_name(), Some("example.com"));
+/// assert_eq!(name.to_string(), "CN=example.com,O=Example,C=US");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NameOwned(Document);
+
+impl NameOwned {
+    /// Decode the [`Name`], borrowing its attribute values.
+    pub fn to_name(&self) -> Name<'_> {
+        self.0.decode_msg().expect("name is valid DER")
+    }
+
+    /// Borrow the DER encoding of the name.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for NameOwned {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Serializes the name according to the rules in [RFC 4514].
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+impl core::fmt::Display for NameOwned {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.to_name(), f)
+    }
+}
+
+/// Parses an [RFC 4514] string, as [`RdnSequence::encode_from_string`].
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+impl FromStr for NameOwned {
+    type Err = der::Error;
+
+    fn from_str(s: &str) -> der::Result<Self> {
+        Ok(Self(Document::try_from(RdnSequence::encode_from_string(
+            s,
+        )?)?))
+    }
+}
+
+impl TryFrom<&[u8]> for NameOwned {
+    type Error = der::Error;
+
+    fn try_from(der: &[u8]) -> der::Result<Self> {
+        Name::from_der(der)?;
+        Ok(Self(Document::try_from(der)?))
+    }
+}
+
+impl TryFrom<&Name<'_>> for NameOwned {
+    type Error = der::Error;
+
+    fn try_from(name: &Name<'_>) -> der::Result<Self> {
+        Ok(Self(Document::encode_msg(name)?))
+    }
+}
+
 /// X.501 DistinguishedName as defined in [RFC 5280 Section 4.1.2.4].
 ///
 /// ```text
@@ -126,7 +272,43 @@ pub type DistinguishedName<'a> = RdnSequence<'a>;
 #[derive(Clone, Debug, Default, PartialEq, Eq, Newtype)]
 pub struct RelativeDistinguishedName<'a>(pub SetOfVec<AttributeTypeAndValue<'a>>);
 
-impl RelativeDistinguishedName<'_> {
+impl<'a> RelativeDistinguishedName<'a> {
+    /// Returns the value of the first attribute of the given type which has
+    /// a string value.
+    ///
+    /// `PrintableString`, `UTF8String` and `IA5String` values are supported.
+    pub fn get_str(&self, oid: AttributeType) -> Option<&'a str> {
+        self.0
+            .iter()
+            .filter(|atv| atv.oid == oid)
+            .find_map(AttributeTypeAndValue::value_str)
+    }
+
+    /// Returns the `commonName` (`CN`) attribute value.
+    pub fn common_name(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::CN)
+    }
+
+    /// Returns the `organizationName` (`O`) attribute value.
+    pub fn organization(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::O)
+    }
+
+    /// Returns the `organizationalUnitName` (`OU`) attribute value.
+    pub fn organizational_unit(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::OU)
+    }
+
+    /// Returns the `countryName` (`C`) attribute value.
+    pub fn country(&self) -> Option<&'a str> {
+        self.get_str(rfc4519::C)
+    }
+
+    /// Returns the `emailAddress` attribute value.
+    pub fn email_address(&self) -> Option<&'a str> {
+        self.get_str(rfc3280::EMAIL_ADDRESS)
+    }
+
     /// Converts an RelativeDistinguishedName string into an encoded RelativeDistinguishedName
     ///
     /// This function follows the rules in [RFC 4514].
@@ -144,6 +326,19 @@ impl RelativeDistinguishedName<'_> {
 
         RelativeDistinguishedName(atvs.try_into()?).to_vec()
     }
+
+    /// Compares two RDNs as described in [RFC 5280 Section 7.1].
+    ///
+    /// RDNs match if they contain the same number of attributes and each
+    /// attribute [matches][`AttributeTypeAndValue::matches`] an attribute of
+    /// the other RDN.
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    #[cfg(feature = "stringprep")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stringprep")))]
+    pub fn matches(&self, other: &RelativeDistinguishedName<'_>) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().all(|a| other.0.iter().any(|b| a.matches(b)))
+    }
 }
 
 /// Serializes the structure according to the rules in [RFC 4514].
@@ -0,0 +1,134 @@
+//! LDAP internationalized string preparation as defined in [RFC 4518].
+//!
+//! [RFC 5280 Section 7.1] requires attribute values in distinguished names
+//! to be compared using the `caseIgnoreMatch` matching rule, after both
+//! values have been prepared as described here.
+//!
+//! [RFC 4518]: https://datatracker.ietf.org/doc/html/rfc4518
+//! [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+
+use alloc::string::String;
+use unicode_normalization::UnicodeNormalization;
+
+/// Prepares a string for `caseIgnoreMatch` comparison.
+///
+/// The following steps from [RFC 4518 Section 2] are applied:
+///
+/// 1. Map: control and zero-width characters are removed, all whitespace
+///    characters are mapped to `SPACE` and the string is case folded using
+///    the Unicode lowercase mapping.
+/// 2. Normalize: the string is normalized to Unicode Form KC.
+/// 3. Prohibit: `None` is returned if the string contains private use
+///    characters, non-characters or `REPLACEMENT CHARACTER`.
+/// 4. Insignificant space handling: leading and trailing spaces are removed
+///    and each internal run of spaces is replaced by a single space.
+///
+/// Two strings match if their prepared forms are equal. The output of the
+/// last step differs from the form given in the RFC, which surrounds
+/// characters with additional spaces, but compares equal in exactly the
+/// same cases. Unassigned code points and bidirectional text are not
+/// checked.
+///
+/// [RFC 4518 Section 2]: https://datatracker.ietf.org/doc/html/rfc4518#section-2
+pub fn prepare(s: &str) -> Option<String> {
+    let mapped = s
+        .chars()
+        .filter(|&c| !is_mapped_to_nothing(c))
+        .map(|c| if is_space(c) { ' ' } else { c })
+        .flat_map(char::to_lowercase);
+
+    let mut out = String::with_capacity(s.len());
+    let mut space = false;
+
+    for c in mapped.nfkc() {
+        if is_prohibited(c) {
+            return None;
+        }
+
+        // Normalization may produce new spaces, e.g. from U+00A0.
+        if is_space(c) {
+            space = !out.is_empty();
+            continue;
+        }
+
+        if space {
+            out.push(' ');
+            space = false;
+        }
+
+        out.push(c);
+    }
+
+    Some(out)
+}
+
+/// Characters which are mapped to nothing by [RFC 4518 Section 2.2].
+///
+/// [RFC 4518 Section 2.2]: https://datatracker.ietf.org/doc/html/rfc4518#section-2.2
+fn is_mapped_to_nothing(c: char) -> bool {
+    matches!(
+        c,
+        // Soft hyphens, joiners and variation selectors
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{1806}'
+            | '\u{180B}'..='\u{180D}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FFFC}'
+            // Control characters other than whitespace
+            | '\u{0000}'..='\u{0008}'
+            | '\u{000E}'..='\u{001F}'
+            | '\u{007F}'..='\u{0084}'
+            | '\u{0086}'..='\u{009F}'
+            // Format characters, including ZERO WIDTH SPACE
+            | '\u{06DD}'
+            | '\u{070F}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2063}'
+            | '\u{206A}'..='\u{206F}'
+            | '\u{FEFF}'
+            | '\u{FFF9}'..='\u{FFFB}'
+            | '\u{1D173}'..='\u{1D17A}'
+            | '\u{E0001}'
+            | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Characters which are mapped to `SPACE` by [RFC 4518 Section 2.2].
+///
+/// [RFC 4518 Section 2.2]: https://datatracker.ietf.org/doc/html/rfc4518#section-2.2
+fn is_space(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0009}'..='\u{000D}'
+            | '\u{0020}'
+            | '\u{0085}'
+            | '\u{00A0}'
+            | '\u{1680}'
+            | '\u{2000}'..='\u{200A}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{202F}'
+            | '\u{205F}'
+            | '\u{3000}'
+    )
+}
+
+/// Characters which are prohibited by [RFC 4518 Section 2.4].
+///
+/// [RFC 4518 Section 2.4]: https://datatracker.ietf.org/doc/html/rfc4518#section-2.4
+fn is_prohibited(c: char) -> bool {
+    matches!(
+        c,
+        // Private use
+        '\u{E000}'..='\u{F8FF}'
+            | '\u{F0000}'..='\u{FFFFD}'
+            | '\u{100000}'..='\u{10FFFD}'
+            // Non-characters
+            | '\u{FDD0}'..='\u{FDEF}'
+            // REPLACEMENT CHARACTER
+            | '\u{FFFD}'
+    ) || (c as u32 & 0xFFFE) == 0xFFFE
+}
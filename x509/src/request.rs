@@ -1,14 +1,15 @@
 //! PKCS#10 Certification Request types
 
+use crate::attr::{Attribute, Attributes};
 use crate::ext::Extension;
-use crate::{attr::Attributes, name::Name};
+use crate::name::Name;
 
 use alloc::vec::Vec;
 
 use const_oid::db::rfc5912::ID_EXTENSION_REQ;
 use const_oid::{AssociatedOid, ObjectIdentifier};
 use der::asn1::BitString;
-use der::{Decode, Enumerated, Newtype, Sequence};
+use der::{Decode, Enumerated, Error, ErrorKind, Newtype, Sequence};
 use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
 /// Version identifier for certification request information.
@@ -50,8 +51,53 @@ pub struct CertReqInfo<'a> {
     pub attributes: Attributes<'a>,
 }
 
+impl<'a> CertReqInfo<'a> {
+    /// Finds the attribute with the given OID.
+    ///
+    /// Returns `None` if the attribute is not present.
+    pub fn attribute(&self, oid: ObjectIdentifier) -> Option<&Attribute<'a>> {
+        self.attributes.iter().find(|attr| attr.oid == oid)
+    }
+
+    /// Decodes the extensions requested by the `extensionRequest` attribute.
+    ///
+    /// Returns `Ok(None)` if the attribute is not present. Returns an error
+    /// if the attribute doesn't contain exactly one value, or decoding
+    /// failed.
+    pub fn extension_req(&self) -> Result<Option<ExtensionReq<'a>>, Error> {
+        let attr = match self.attribute(ExtensionReq::OID) {
+            Some(attr) => attr,
+            None => return Ok(None),
+        };
+
+        match attr.values.as_slice() {
+            [value] => value.decode_into().map(Some),
+            _ => Err(ErrorKind::Failed.into()),
+        }
+    }
+
+    /// Decodes a single requested extension
+    ///
+    /// Returns an error if multiple of these extensions is present. Returns
+    /// `Ok(None)` if the extension is not present. Returns a decoding error
+    /// if decoding failed. Otherwise returns the extension.
+    pub fn get_extension<T: Decode<'a> + AssociatedOid>(&self) -> Result<Option<(bool, T)>, Error> {
+        let extension_req = match self.extension_req()? {
+            Some(extension_req) => extension_req,
+            None => return Ok(None),
+        };
+
+        let mut iter = extension_req.0.iter().filter(|e| e.extn_id == T::OID);
+        match (iter.next(), iter.next()) {
+            (None, _) => Ok(None),
+            (Some(..), Some(..)) => Err(ErrorKind::Failed.into()),
+            (Some(e), None) => Ok(Some((e.critical, T::from_der(e.extn_value)?))),
+        }
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for CertReqInfo<'a> {
-    type Error = der::Error;
+    type Error = Error;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::from_der(bytes)
@@ -82,7 +128,7 @@ pub struct CertReq<'a> {
 }
 
 impl<'a> TryFrom<&'a [u8]> for CertReq<'a> {
-    type Error = der::Error;
+    type Error = Error;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         Self::from_der(bytes)
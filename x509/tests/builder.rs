@@ -0,0 +1,236 @@
+//! Certificate builder tests
+
+use der::asn1::UtcTime;
+use der::{DateTime, Decode, Encode, Tag};
+use hex_literal::hex;
+use spki::{AlgorithmIdentifier, ObjectIdentifier, SubjectPublicKeyInfo};
+use x509_cert::ext::pkix::{BasicConstraints, KeyUsage, KeyUsages};
+use x509_cert::name::{Name, RdnSequence};
+use x509_cert::time::{Time, Validity};
+use x509_cert::{Certificate, CertificateBuilder, Version};
+
+const ED25519: AlgorithmIdentifier<'static> = AlgorithmIdentifier {
+    oid: ObjectIdentifier::new_unwrap("1.3.101.112"),
+    parameters: None,
+};
+
+const PUBLIC_KEY: [u8; 32] =
+    hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D");
+
+fn spki() -> SubjectPublicKeyInfo<'static> {
+    SubjectPublicKeyInfo {
+        algorithm: ED25519,
+        subject_public_key: &PUBLIC_KEY,
+    }
+}
+
+fn validity() -> Validity {
+    let time = |year| {
+        let date_time = DateTime::new(year, 1, 1, 0, 0, 0).unwrap();
+        Time::UtcTime(UtcTime::from_date_time(date_time).unwrap())
+    };
+
+    Validity {
+        not_before: time(2022),
+        not_after: time(2032),
+    }
+}
+
+#[test]
+fn build_self_signed() {
+    let subject = RdnSequence::encode_from_string("CN=Test CA,O=Example,C=US").unwrap();
+    let subject = Name::from_der(&subject).unwrap();
+
+    let builder = CertificateBuilder::new(&[0x01, 0x02], validity(), subject.clone(), spki())
+        .unwrap()
+        .extension(
+            true,
+            &BasicConstraints {
+                ca: true,
+                path_len_constraint: Some(0),
+            },
+        )
+        .unwrap()
+        .extension(true, &KeyUsage(KeyUsages::KeyCertSign | KeyUsages::CRLSign))
+        .unwrap();
+
+    let expected_tbs = builder.build_tbs(ED25519).unwrap();
+    let der = builder
+        .build(ED25519, |tbs| {
+            assert_eq!(tbs, expected_tbs);
+            Ok::<_, der::Error>(vec![0xAA; 64])
+        })
+        .unwrap();
+
+    let cert = Certificate::from_der(&der).unwrap();
+    let tbs = &cert.tbs_certificate;
+    assert_eq!(tbs.to_vec().unwrap(), expected_tbs);
+    assert_eq!(tbs.version, Version::V3);
+    assert_eq!(tbs.serial_number.as_bytes(), &[0x01, 0x02]);
+    assert_eq!(tbs.signature, ED25519);
+    assert_eq!(tbs.issuer, subject);
+    assert_eq!(tbs.subject, subject);
+    assert_eq!(tbs.validity, validity());
+    assert_eq!(tbs.subject_public_key_info, spki());
+    assert_eq!(cert.signature_algorithm, ED25519);
+    assert_eq!(cert.signature.raw_bytes(), &[0xAA; 64]);
+
+    let (critical, bc) = tbs.get::<BasicConstraints>().unwrap().unwrap();
+    assert!(critical);
+    assert!(bc.ca);
+    assert_eq!(bc.path_len_constraint, Some(0));
+
+    let (critical, ku) = tbs.get::<KeyUsage>().unwrap().unwrap();
+    assert!(critical);
+    assert_eq!(ku.0, KeyUsages::KeyCertSign | KeyUsages::CRLSign);
+}
+
+#[test]
+fn build_issued() {
+    let issuer = RdnSequence::encode_from_string("CN=Test CA").unwrap();
+    let subject = RdnSequence::encode_from_string("CN=example.com").unwrap();
+    let issuer = Name::from_der(&issuer).unwrap();
+    let subject = Name::from_der(&subject).unwrap();
+
+    let der = CertificateBuilder::new(&[0x00, 0x80], validity(), subject.clone(), spki())
+        .unwrap()
+        .issuer(issuer.clone())
+        .build(ED25519, |_| Ok::<_, der::Error>(vec![0xAA; 64]))
+        .unwrap();
+
+    let cert = Certificate::from_der(&der).unwrap();
+    assert_eq!(cert.tbs_certificate.version, Version::V1);
+    assert_eq!(cert.tbs_certificate.serial_number.as_bytes(), &[0x80]);
+    assert_eq!(cert.tbs_certificate.issuer, issuer);
+    assert_eq!(cert.tbs_certificate.subject, subject);
+    assert_eq!(cert.tbs_certificate.extensions, None);
+}
+
+#[test]
+fn build_errors() {
+    let subject = Name::default();
+
+    // Serial numbers must be positive and at most 20 octets long
+    for serial in [&[][..], &[0], &[0x80; 20], &[1; 21]] {
+        let err = CertificateBuilder::new(serial, validity(), subject.clone(), spki()).unwrap_err();
+        assert_eq!(err, Tag::Integer.value_error(), "{:?}", serial);
+    }
+    assert!(CertificateBuilder::new(&[0x7F; 20], validity(), subject.clone(), spki()).is_ok());
+
+    // Validity must not end before it starts
+    let mut backwards = validity();
+    core::mem::swap(&mut backwards.not_before, &mut backwards.not_after);
+    assert!(CertificateBuilder::new(&[1], backwards, subject.clone(), spki()).is_err());
+
+    // Extensions must not be duplicated
+    let bc = BasicConstraints {
+        ca: false,
+        path_len_constraint: None,
+    };
+    let builder = CertificateBuilder::new(&[1], validity(), subject, spki())
+        .unwrap()
+        .extension(false, &bc)
+        .unwrap();
+    assert!(builder.extension(true, &bc).is_err());
+
+    // Signing errors are passed through
+    let builder = CertificateBuilder::new(&[1], validity(), Name::default(), spki()).unwrap();
+    let err = builder
+        .build(ED25519, |_| Err(der::Error::from(der::ErrorKind::Failed)))
+        .unwrap_err();
+    assert_eq!(err.kind(), der::ErrorKind::Failed);
+}
+
+#[cfg(feature = "sign")]
+mod sign {
+    use super::validity;
+    use const_oid::db::rfc5912::ECDSA_WITH_SHA_256;
+    use der::{Decode, Encode};
+    use hex_literal::hex;
+    use p256::ecdsa::{DerSignature, SigningKey, VerifyingKey};
+    use spki::{AlgorithmIdentifier, KnownAlgorithm, SubjectPublicKeyInfo};
+    use x509_cert::builder::Error;
+    use x509_cert::ext::pkix::BasicConstraints;
+    use x509_cert::name::{Name, RdnSequence};
+    use x509_cert::signature::{self, Signer, Verifier};
+    use x509_cert::{Certificate, CertificateBuilder};
+
+    const EC_PRIVATE_KEY: [u8; 32] =
+        hex!("592063ae550d655f0eacd4aa2aa507a0e0aa64a5e2deae0e2ed7840346c2e98c");
+
+    const ECDSA_WITH_SHA256: AlgorithmIdentifier<'static> = AlgorithmIdentifier {
+        oid: ECDSA_WITH_SHA_256,
+        parameters: None,
+    };
+
+    /// ECDSA/P-256 signer producing the DER encoded signatures used by X.509.
+    struct EcSigner(SigningKey);
+
+    impl Signer<DerSignature> for EcSigner {
+        fn try_sign(&self, msg: &[u8]) -> Result<DerSignature, signature::Error> {
+            Ok(self.0.try_sign(msg)?.to_der())
+        }
+    }
+
+    /// Signer which always fails.
+    struct FailingSigner;
+
+    impl Signer<DerSignature> for FailingSigner {
+        fn try_sign(&self, _: &[u8]) -> Result<DerSignature, signature::Error> {
+            Err(signature::Error::new())
+        }
+    }
+
+    #[test]
+    fn build_and_sign_self_signed() {
+        let signer = EcSigner(SigningKey::from_bytes(&EC_PRIVATE_KEY).unwrap());
+        let verifying_key = VerifyingKey::from(&signer.0);
+        let public_key = verifying_key.to_encoded_point(false);
+
+        let spki = SubjectPublicKeyInfo {
+            algorithm: KnownAlgorithm::EC_P256.algorithm_identifier().unwrap(),
+            subject_public_key: public_key.as_bytes(),
+        };
+        let subject = RdnSequence::encode_from_string("CN=Test CA").unwrap();
+
+        let der =
+            CertificateBuilder::new(&[1], validity(), Name::from_der(&subject).unwrap(), spki)
+                .unwrap()
+                .extension(
+                    true,
+                    &BasicConstraints {
+                        ca: true,
+                        path_len_constraint: None,
+                    },
+                )
+                .unwrap()
+                .build_and_sign(&signer, ECDSA_WITH_SHA256)
+                .unwrap();
+
+        let cert = Certificate::from_der(&der).unwrap();
+        assert_eq!(cert.signature_algorithm, ECDSA_WITH_SHA256);
+        assert_eq!(cert.tbs_certificate.signature, ECDSA_WITH_SHA256);
+        assert_eq!(cert.tbs_certificate.subject_public_key_info, spki);
+
+        let signature = p256::ecdsa::Signature::from_der(cert.signature.raw_bytes()).unwrap();
+        let tbs = cert.tbs_certificate.to_vec().unwrap();
+        verifying_key.verify(&tbs, &signature).unwrap();
+        assert!(verifying_key.verify(&der, &signature).is_err());
+    }
+
+    #[test]
+    fn build_and_sign_error() {
+        let signer = EcSigner(SigningKey::from_bytes(&EC_PRIVATE_KEY).unwrap());
+        let spki = SubjectPublicKeyInfo {
+            algorithm: KnownAlgorithm::EC_P256.algorithm_identifier().unwrap(),
+            subject_public_key: &[4; 65],
+        };
+        let builder = CertificateBuilder::new(&[1], validity(), Name::default(), spki).unwrap();
+
+        assert!(builder.build_and_sign(&signer, ECDSA_WITH_SHA256).is_ok());
+        assert_eq!(
+            builder.build_and_sign(&FailingSigner, ECDSA_WITH_SHA256),
+            Err(Error::Signature)
+        );
+    }
+}
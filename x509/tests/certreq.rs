@@ -84,3 +84,52 @@ fn encode_rsa_2048_der() {
     let cr_encoded = cr.to_vec().unwrap();
     assert_eq!(RSA_2048_DER_EXAMPLE, cr_encoded.as_slice());
 }
+
+#[test]
+fn requested_extensions() {
+    use const_oid::db::rfc5912::ID_EXTENSION_REQ;
+    use x509_cert::ext::pkix::{BasicConstraints, KeyUsage, KeyUsages, SubjectKeyIdentifier};
+
+    let cr = CertReq::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+
+    let attribute = cr.info.attribute(ID_EXTENSION_REQ).unwrap();
+    assert_eq!(attribute.values.len(), 1);
+    assert!(cr
+        .info
+        .attribute("1.2.840.113549.1.9.7".parse().unwrap()) // challengePassword
+        .is_none());
+
+    let extension_req = cr.info.extension_req().unwrap().unwrap();
+    assert_eq!(extension_req.0.len(), EXTENSIONS.len());
+    for (ext, (oid, val)) in extension_req.0.iter().zip(EXTENSIONS) {
+        assert_eq!(ext.extn_id, oid.parse().unwrap());
+        assert_eq!(ext.extn_value, *val);
+    }
+
+    let (critical, bc) = cr
+        .info
+        .get_extension::<BasicConstraints>()
+        .unwrap()
+        .unwrap();
+    assert!(!critical);
+    assert!(!bc.ca);
+
+    let (critical, ku) = cr.info.get_extension::<KeyUsage>().unwrap().unwrap();
+    assert!(!critical);
+    assert_eq!(
+        ku.0,
+        KeyUsages::DigitalSignature | KeyUsages::KeyEncipherment
+    );
+
+    assert!(cr
+        .info
+        .get_extension::<SubjectKeyIdentifier<'_>>()
+        .unwrap()
+        .is_none());
+
+    // Requests without an `extensionRequest` attribute
+    let mut info = cr.info.clone();
+    info.attributes = Default::default();
+    assert!(info.extension_req().unwrap().is_none());
+    assert!(info.get_extension::<BasicConstraints>().unwrap().is_none());
+}
@@ -1,11 +1,11 @@
 //! Name tests
 
 use const_oid::ObjectIdentifier;
-use der::asn1::{OctetString, SetOfVec, Utf8String};
+use der::asn1::{Ia5String, OctetString, PrintableString, SetOfVec, Utf8String};
 use der::{Any, Decode, Encode, Tag, Tagged};
 use hex_literal::hex;
 use x509_cert::attr::AttributeTypeAndValue;
-use x509_cert::name::{Name, RdnSequence, RelativeDistinguishedName};
+use x509_cert::name::{Name, NameOwned, RdnSequence, RelativeDistinguishedName};
 
 #[test]
 fn decode_name() {
@@ -207,7 +207,7 @@ fn rdns_serde() {
                 &[
                     AttributeTypeAndValue {
                         oid: const_oid::db::rfc4519::C,
-                        value: Any::from(Utf8String::new("baz").unwrap()),
+                        value: Any::from(PrintableString::new("baz").unwrap()),
                     },
                     AttributeTypeAndValue {
                         oid: const_oid::db::rfc4519::L,
@@ -226,11 +226,11 @@ fn rdns_serde() {
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("example").unwrap()),
+                    value: Any::from(Ia5String::new("example").unwrap()),
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("net").unwrap()),
+                    value: Any::from(Ia5String::new("net").unwrap()),
                 }],
             ],
         ),
@@ -250,11 +250,11 @@ fn rdns_serde() {
                 ],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("example").unwrap()),
+                    value: Any::from(Ia5String::new("example").unwrap()),
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("net").unwrap()),
+                    value: Any::from(Ia5String::new("net").unwrap()),
                 }],
             ],
         ),
@@ -268,11 +268,11 @@ fn rdns_serde() {
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("example").unwrap()),
+                    value: Any::from(Ia5String::new("example").unwrap()),
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("net").unwrap()),
+                    value: Any::from(Ia5String::new("net").unwrap()),
                 }],
             ],
         ),
@@ -286,11 +286,11 @@ fn rdns_serde() {
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("example").unwrap()),
+                    value: Any::from(Ia5String::new("example").unwrap()),
                 }],
                 &[AttributeTypeAndValue {
                     oid: const_oid::db::rfc4519::DC,
-                    value: Any::from(Utf8String::new("net").unwrap()),
+                    value: Any::from(Ia5String::new("net").unwrap()),
                 }],
             ],
         ),
@@ -305,8 +305,9 @@ fn rdns_serde() {
     ];
 
     for (inputs, output, rdns) in values {
+        // RDNs are serialized in the reverse order of their encoding
         let mut brdns = RdnSequence::default();
-        for rdn in rdns.iter() {
+        for rdn in rdns.iter().rev() {
             let sofv = SetOfVec::try_from(rdn.to_vec()).unwrap();
             brdns.0.push(RelativeDistinguishedName::from(sofv));
         }
@@ -314,6 +315,7 @@ fn rdns_serde() {
         // Check that serialization matches the expected output.
         eprintln!("output: {}", output);
         assert_eq!(*output, format!("{}", brdns));
+        assert_eq!(*output, brdns.to_rfc4514_string());
 
         // Check that all inputs deserializize as expected.
         for input in inputs.iter() {
@@ -334,3 +336,203 @@ fn rdns_serde() {
         }
     }
 }
+
+#[test]
+fn to_rfc4514_string() {
+    // C=US, O=Test Certificates 2011, CN=Good CA
+    let name = Name::from_der(&hex!("3040310B3009060355040613025553311F301D060355040A1316546573742043657274696669636174657320323031313110300E06035504031307476F6F64204341")[..]).unwrap();
+    assert_eq!(
+        name.to_rfc4514_string(),
+        "CN=Good CA,O=Test Certificates 2011,C=US"
+    );
+
+    // Leading `#` and leading and trailing spaces must be escaped
+    let der = RdnSequence::encode_from_string("CN=\\#1\\, \\ two\\ ").unwrap();
+    let name = Name::from_der(&der).unwrap();
+    assert_eq!(
+        name.0[0]
+            .0
+            .get(0)
+            .unwrap()
+            .value
+            .utf8_string()
+            .unwrap()
+            .as_str(),
+        "#1,  two "
+    );
+    assert_eq!(name.to_string(), "CN=\\#1\\,  two\\ ");
+}
+
+#[test]
+fn encode_from_string_string_types() {
+    let der = RdnSequence::encode_from_string(
+        "CN=Good CA,emailAddress=ca@example.com,DC=example,serialNumber=1234,C=US",
+    )
+    .unwrap();
+    let name = Name::from_der(&der).unwrap();
+
+    let tags = name
+        .0
+        .iter()
+        .map(|rdn| rdn.0.get(0).unwrap().value.tag())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tags,
+        [
+            Tag::PrintableString,
+            Tag::PrintableString,
+            Tag::Ia5String,
+            Tag::Ia5String,
+            Tag::Utf8String,
+        ]
+    );
+    assert_eq!(
+        name.to_string(),
+        "CN=Good CA,EMAIL=ca@example.com,DC=example,SERIALNUMBER=1234,C=US"
+    );
+
+    // Values must be valid for the attribute's string type
+    assert!(RdnSequence::encode_from_string("C=U_S").is_err());
+    assert!(RdnSequence::encode_from_string("DC=exämple").is_err());
+}
+
+#[test]
+fn name_owned() {
+    let s = "CN=Good CA,O=Test Certificates 2011,C=US";
+    let name: NameOwned = s.parse().unwrap();
+    assert_eq!(name.as_bytes(), RdnSequence::encode_from_string(s).unwrap());
+    assert_eq!(name.to_name().common_name(), Some("Good CA"));
+    assert_eq!(name.to_string(), s);
+    assert_eq!(NameOwned::try_from(&name.to_name()).unwrap(), name);
+    assert!("C=U_S".parse::<NameOwned>().is_err());
+
+    // C=US, O=Test Certificates 2011, CN=Good CA
+    let der = hex!("3040310B3009060355040613025553311F301D060355040A1316546573742043657274696669636174657320323031313110300E06035504031307476F6F64204341");
+    let name = NameOwned::try_from(&der[..]).unwrap();
+    assert_eq!(name.to_name(), Name::from_der(&der).unwrap());
+    assert_eq!(name.to_string(), s);
+    assert!(NameOwned::try_from(&der[..der.len() - 1]).is_err());
+}
+
+#[cfg(feature = "stringprep")]
+#[test]
+fn prepare() {
+    use x509_cert::name::prep::prepare;
+
+    assert_eq!(prepare("  Good \t CA  ").unwrap(), "good ca");
+    assert_eq!(prepare("TEST\u{00A0}\u{00AD}Certs").unwrap(), "test certs");
+    assert_eq!(prepare("\u{FB01}le\u{200B}").unwrap(), "file");
+    assert_eq!(prepare("Cafe\u{0301}").unwrap(), "caf\u{00E9}");
+    assert_eq!(prepare("   ").unwrap(), "");
+    assert!(prepare("private\u{E000}").is_none());
+}
+
+#[cfg(feature = "stringprep")]
+#[test]
+fn name_matches() {
+    // C=US, O=Test Certificates 2011, CN=Good CA
+    let der = hex!("3040310B3009060355040613025553311F301D060355040A1316546573742043657274696669636174657320323031313110300E06035504031307476F6F64204341");
+    let name = Name::from_der(&der).unwrap();
+
+    for s in [
+        "CN=Good CA,O=Test Certificates 2011,C=US",
+        "CN=good ca,O=TEST  CERTIFICATES 2011,C=us",
+        "CN=\\ Good   CA\\ ,O=Test Certificates 2011,C=US",
+    ] {
+        let der = RdnSequence::encode_from_string(s).unwrap();
+        let other = Name::from_der(&der).unwrap();
+        assert_ne!(name, other);
+        assert!(name.matches(&other), "{}", s);
+        assert!(other.matches(&name), "{}", s);
+    }
+
+    for s in [
+        "CN=Bad CA,O=Test Certificates 2011,C=US",
+        "CN=Good CA,O=Test Certificates 2011",
+        "O=Test Certificates 2011,CN=Good CA,C=US",
+        "CN=Good CA,OU=Test Certificates 2011,C=US",
+        "CN=Good CA+O=Test Certificates 2011,C=US",
+    ] {
+        let der = RdnSequence::encode_from_string(s).unwrap();
+        let other = Name::from_der(&der).unwrap();
+        assert!(!name.matches(&other), "{}", s);
+    }
+
+    // Multi-valued RDNs match regardless of attribute order
+    let a = RdnSequence::encode_from_string("CN=J. Smith+OU=Sales,DC=example").unwrap();
+    let b = RdnSequence::encode_from_string("ou=sales+cn=j. smith,dc=EXAMPLE").unwrap();
+    assert!(Name::from_der(&a)
+        .unwrap()
+        .matches(&Name::from_der(&b).unwrap()));
+
+    // Non-string values are compared exactly
+    let a = RdnSequence::encode_from_string("1.2.3.4=#04024869").unwrap();
+    let b = RdnSequence::encode_from_string("1.2.3.4=#04026869").unwrap();
+    let a = Name::from_der(&a).unwrap();
+    assert!(a.matches(&a.clone()));
+    assert!(!a.matches(&Name::from_der(&b).unwrap()));
+}
+
+#[test]
+fn typed_accessors() {
+    let der = RdnSequence::encode_from_string(
+        "CN=www.example.com,emailAddress=admin@example.com,OU=Web+OU=Ops,O=Example,C=US",
+    )
+    .unwrap();
+    let name = Name::from_der(&der).unwrap();
+
+    assert_eq!(name.common_name(), Some("www.example.com"));
+    assert_eq!(name.email_address(), Some("admin@example.com"));
+    assert_eq!(name.organization(), Some("Example"));
+    assert_eq!(name.country(), Some("US"));
+    assert_eq!(name.get_str(const_oid::db::rfc4519::L), None);
+
+    let rdn = &name.0[2];
+    assert_eq!(rdn.common_name(), None);
+    assert_eq!(rdn.organizational_unit(), Some("Ops"));
+    assert_eq!(rdn.organization(), None);
+
+    // The most specific attribute is returned
+    let der = RdnSequence::encode_from_string("CN=leaf,CN=intermediate").unwrap();
+    let name = Name::from_der(&der).unwrap();
+    assert_eq!(name.common_name(), Some("leaf"));
+    assert_eq!(name.0[0].common_name(), Some("intermediate"));
+
+    // Values which aren't strings are skipped
+    let der = RdnSequence::encode_from_string("CN=#04024869").unwrap();
+    let name = Name::from_der(&der).unwrap();
+    assert_eq!(name.common_name(), None);
+}
+
+#[test]
+fn directory_string() {
+    use x509_cert::ext::pkix::name::DirectoryString;
+
+    let printable = Any::from(PrintableString::new("Test CA").unwrap());
+    let utf8 = Any::from(Utf8String::new("Test CA").unwrap());
+
+    let a = DirectoryString::try_from(printable).unwrap();
+    let b = DirectoryString::try_from(utf8).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(a.as_str(), b.as_str());
+    assert_eq!(b.to_string(), "Test CA");
+
+    let ia5 = Any::from(Ia5String::new("Test CA").unwrap());
+    let err = DirectoryString::try_from(ia5).unwrap_err();
+    assert_eq!(err.kind(), Tag::Ia5String.unexpected_error(None).kind());
+}
+
+#[cfg(feature = "stringprep")]
+#[test]
+fn directory_string_matches() {
+    use x509_cert::ext::pkix::name::DirectoryString;
+
+    let a = DirectoryString::PrintableString(PrintableString::new("Test  CA ").unwrap());
+    let b = DirectoryString::Utf8String(Utf8String::new("test ca").unwrap());
+    let c = DirectoryString::Utf8String(Utf8String::new("test-ca").unwrap());
+
+    assert!(a.matches(&b));
+    assert!(b.matches(&a));
+    assert!(!a.matches(&c));
+    assert_eq!(a.prepare(), b.prepare());
+}